@@ -0,0 +1,119 @@
+//! Times generating a spec the size of the Kubernetes or Azure APIs, thousands of definitions and
+//! operations across a hundred tags, on one thread and on all cores:
+//!
+//! ```sh
+//! cargo bench --bench generate
+//! ```
+
+use serde_json::{json, Map, Value};
+use std::time::{Duration, Instant};
+use swagger_generator::cli::Language;
+use swagger_generator::spec::SpecFormat;
+use swagger_generator::{parse_spec, Generator};
+
+const DEFINITIONS: usize = 4000;
+const PATHS: usize = 2000;
+const TAGS: usize = 100;
+const RUNS: usize = 3;
+
+/// A Swagger 2.0 document whose definitions reference each other and whose operations take and
+/// return them, so that every part of the emitters is exercised.
+fn large_spec() -> String {
+    let mut definitions = Map::new();
+    for i in 0..DEFINITIONS {
+        let mut properties = Map::new();
+        properties.insert("id".to_string(), json!({ "type": "string", "format": "uuid" }));
+        properties.insert("createdAt".to_string(), json!({ "type": "string", "format": "date-time" }));
+        properties.insert("count".to_string(), json!({ "type": "integer", "format": "int64" }));
+        properties.insert("status".to_string(), json!({ "type": "string", "enum": ["active", "deleted"] }));
+        properties
+            .insert("labels".to_string(), json!({ "type": "object", "additionalProperties": { "type": "string" } }));
+        properties.insert(
+            "parent".to_string(),
+            json!({ "$ref": format!("#/definitions/Resource{}", (i + 1) % DEFINITIONS) }),
+        );
+        properties.insert(
+            "children".to_string(),
+            json!({ "type": "array", "items": { "$ref": format!("#/definitions/Resource{}", (i + 7) % DEFINITIONS) } }),
+        );
+        definitions.insert(
+            format!("Resource{}", i),
+            json!({ "type": "object", "description": format!("Resource number {}.", i), "required": ["id"], "properties": properties }),
+        );
+    }
+
+    let mut paths = Map::new();
+    for i in 0..PATHS {
+        let tag = format!("group{}", i % TAGS);
+        let model = json!({ "$ref": format!("#/definitions/Resource{}", i % DEFINITIONS) });
+        paths.insert(
+            format!("/{}/resources{}/{{name}}", tag, i),
+            json!({
+                "get": {
+                    "tags": [tag],
+                    "operationId": format!("readResource{}", i),
+                    "parameters": [
+                        { "name": "name", "in": "path", "required": true, "type": "string" },
+                        { "name": "pretty", "in": "query", "type": "boolean" },
+                    ],
+                    "responses": { "200": { "description": "OK", "schema": model } },
+                },
+                "put": {
+                    "tags": [tag],
+                    "operationId": format!("replaceResource{}", i),
+                    "parameters": [
+                        { "name": "name", "in": "path", "required": true, "type": "string" },
+                        { "name": "body", "in": "body", "required": true, "schema": model },
+                    ],
+                    "responses": { "200": { "description": "OK", "schema": model } },
+                },
+            }),
+        );
+    }
+
+    let spec = json!({
+        "swagger": "2.0",
+        "info": { "title": "Large", "version": "1.0", "description": "Benchmark spec" },
+        "host": "api.example.com",
+        "basePath": "/",
+        "paths": Value::Object(paths),
+        "definitions": Value::Object(definitions),
+    });
+    spec.to_string()
+}
+
+/// The fastest of `RUNS` generations of `language` on `jobs` threads.
+fn time(spec: &str, language: Language, jobs: Option<usize>) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let generator = Generator::new(parse_spec(spec, Some(SpecFormat::Json)).expect("Invalid spec"))
+                .language(language)
+                .split_by_tag(true)
+                .jobs(jobs);
+            let start = Instant::now();
+            let files = generator.generate().expect("Generation failed");
+            let elapsed = start.elapsed();
+            assert!(files.len() > DEFINITIONS);
+            elapsed
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    let spec = large_spec();
+    let cores = std::thread::available_parallelism().map_or(1, |cores| cores.get());
+    println!("{} definitions, {} paths, {} tags, {} cores", DEFINITIONS, PATHS, TAGS, cores);
+    for language in [Language::TypeScript, Language::Java, Language::Kotlin, Language::CSharp] {
+        let serial = time(&spec, language, Some(1));
+        let parallel = time(&spec, language, None);
+        println!(
+            "{:<12} 1 thread {:>8.1?}   {} threads {:>8.1?}   {:.1}x",
+            format!("{:?}", language),
+            serial,
+            cores,
+            parallel,
+            serial.as_secs_f64() / parallel.as_secs_f64()
+        );
+    }
+}
@@ -1,15 +1,19 @@
 /*
- * This file was generated by swagger-genereator
+ * This file was generated by swagger-generator
  * Do not modify this file manually.
  * Version: 1.0.0
  * Title: Uber API
  * Description: Move your app forward with the Uber API
- * Author: Muhtalip Dede
- * Generated on: 2024-05-18 */
+ */
+
+import type { Activity } from './Activity';
 
 export interface Activities {
-    count: number;
-    history: any[];
+    /** Position in pagination. */
     offset: number;
+    /** Number of items to retrieve (100 max). */
     limit: number;
+    /** Total number of items available. */
+    count: number;
+    history: Activity[];
 }
@@ -1,18 +1,24 @@
 /*
- * This file was generated by swagger-genereator
+ * This file was generated by swagger-generator
  * Do not modify this file manually.
  * Version: 1.0.0
  * Title: Uber API
  * Description: Move your app forward with the Uber API
- * Author: Muhtalip Dede
- * Generated on: 2024-05-18 */
+ */
 
 export interface PriceEstimate {
-    surge_multiplier: any;
+    /** Unique identifier representing a specific product for a given latitude & longitude. For example, uberX in San Francisco will have a different product_id than uberX in Los Angeles */
     product_id: string;
-    estimate: string;
-    display_name: string;
-    low_estimate: any;
+    /** [ISO 4217](http://en.wikipedia.org/wiki/ISO_4217) currency code. */
     currency_code: string;
-    high_estimate: any;
+    /** Display name of product. */
+    display_name: string;
+    /** Formatted string of estimate in local currency of the start location. Estimate could be a range, a single number (flat rate) or "Metered" for TAXI. */
+    estimate: string;
+    /** Lower bound of the estimated price. */
+    low_estimate: number;
+    /** Upper bound of the estimated price. */
+    high_estimate: number;
+    /** Expected surge multiplier. Surge is active if surge_multiplier is greater than 1. Price estimate already factors in the surge multiplier. */
+    surge_multiplier: number;
 }
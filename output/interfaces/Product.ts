@@ -1,16 +1,20 @@
 /*
- * This file was generated by swagger-genereator
+ * This file was generated by swagger-generator
  * Do not modify this file manually.
  * Version: 1.0.0
  * Title: Uber API
  * Description: Move your app forward with the Uber API
- * Author: Muhtalip Dede
- * Generated on: 2024-05-18 */
+ */
 
 export interface Product {
+    /** Unique identifier representing a specific product for a given latitude & longitude. For example, uberX in San Francisco will have a different product_id than uberX in Los Angeles. */
     product_id: string;
+    /** Description of product. */
     description: string;
+    /** Display name of product. */
     display_name: string;
+    /** Capacity of product. For example, 4 people. */
     capacity: string;
+    /** Image URL representing the product. */
     image: string;
 }
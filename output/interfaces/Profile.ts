@@ -1,16 +1,20 @@
 /*
- * This file was generated by swagger-genereator
+ * This file was generated by swagger-generator
  * Do not modify this file manually.
  * Version: 1.0.0
  * Title: Uber API
  * Description: Move your app forward with the Uber API
- * Author: Muhtalip Dede
- * Generated on: 2024-05-18 */
+ */
 
 export interface Profile {
+    /** First name of the Uber user. */
+    first_name: string;
+    /** Last name of the Uber user. */
+    last_name: string;
+    /** Email address of the Uber user */
     email: string;
+    /** Image URL of the Uber user. */
     picture: string;
+    /** Promo code of the Uber user. */
     promo_code: string;
-    first_name: string;
-    last_name: string;
 }
@@ -0,0 +1,14 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Uber API
+ * Description: Move your app forward with the Uber API
+ */
+
+export * from './Activities';
+export * from './Activity';
+export * from './Error';
+export * from './PriceEstimate';
+export * from './Product';
+export * from './Profile';
@@ -1,45 +1,143 @@
 /*
- * This file was generated by swagger-genereator
+ * This file was generated by swagger-generator
  * Do not modify this file manually.
  * Version: 1.0.0
  * Title: Uber API
  * Description: Move your app forward with the Uber API
- * Author: Muhtalip Dede
- * Generated on: 2024-05-18 */
+ */
 
-import axios from 'axios';
+import type { AxiosError, AxiosResponse } from 'axios';
+import { client } from './client';
 
-axios.defaults.baseURL = 'https://api.uber.com/v1';
+import { Activities, Activity, Error, PriceEstimate, Product, Profile } from './interfaces';
 
-import { PriceEstimate } from './interfaces/PriceEstimate';
-import { Product } from './interfaces/Product';
-import { Activity } from './interfaces/Activity';
-import { Profile } from './interfaces/Profile';
-import { Error } from './interfaces/Error';
-import { Activities } from './interfaces/Activities';
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+/** An axios error whose response has the given status and body. */
+export type ResponseError<Status extends number, Body> = AxiosError<Body> & {
+    response: AxiosResponse<Body> & { status: Status };
+};
+
+export interface GetProductsParams {
+    /** Latitude component of location. */
+    latitude: number;
+    /** Longitude component of location. */
+    longitude: number;
+}
+
+export type GetProductsError = ResponseError<number, Error>;
 
-export async function getEstimatesPrice(config?: any): Promise<any> {
-    const response = await axios.get(`/estimates/price`, config);
+/**
+ * Product Types
+ *
+ * The Products endpoint returns information about the Uber products offered at a given location. The response includes the display name and other details about each product, and lists the products in the proper display order.
+ */
+export async function getProducts(params: GetProductsParams, config?: RequestOptions): Promise<Product[]> {
+    const response = await client.get(`/products`, { ...config, params: { ...config?.params, ...params } });
     return response.data;
 }
 
-export async function getProducts(config?: any): Promise<any> {
-    const response = await axios.get(`/products`, config);
+export interface GetEstimatesPriceParams {
+    /** Latitude component of start location. */
+    start_latitude: number;
+    /** Longitude component of start location. */
+    start_longitude: number;
+    /** Latitude component of end location. */
+    end_latitude: number;
+    /** Longitude component of end location. */
+    end_longitude: number;
+}
+
+export type GetEstimatesPriceError = ResponseError<number, Error>;
+
+/**
+ * Price Estimates
+ *
+ * The Price Estimates endpoint returns an estimated price range for each product offered at a given location. The price estimate is provided as a formatted string with the full price range and the localized currency symbol.<br><br>The response also includes low and high estimates, and the [ISO 4217](http://en.wikipedia.org/wiki/ISO_4217) currency code for situations requiring currency conversion. When surge is active for a particular product, its surge_multiplier will be greater than 1, but the price estimate already factors in this multiplier.
+ */
+export async function getEstimatesPrice(params: GetEstimatesPriceParams, config?: RequestOptions): Promise<PriceEstimate[]> {
+    const response = await client.get(`/estimates/price`, { ...config, params: { ...config?.params, ...params } });
     return response.data;
 }
 
-export async function getMe(config?: any): Promise<Profile> {
-    const response = await axios.get(`/me`, config);
+export interface GetEstimatesTimeParams {
+    /** Latitude component of start location. */
+    start_latitude: number;
+    /** Longitude component of start location. */
+    start_longitude: number;
+    /** Unique customer identifier to be used for experience customization. */
+    customer_uuid?: string;
+    /** Unique identifier representing a specific product for a given latitude & longitude. */
+    product_id?: string;
+}
+
+export type GetEstimatesTimeError = ResponseError<number, Error>;
+
+/**
+ * Time Estimates
+ *
+ * The Time Estimates endpoint returns ETAs for all products offered at a given location, with the responses expressed as integers in seconds. We recommend that this endpoint be called every minute to provide the most accurate, up-to-date ETAs.
+ */
+export async function getEstimatesTime(params: GetEstimatesTimeParams, config?: RequestOptions): Promise<Product[]> {
+    const response = await client.get(`/estimates/time`, { ...config, params: { ...config?.params, ...params } });
     return response.data;
 }
 
-export async function getHistory(config?: any): Promise<Activities> {
-    const response = await axios.get(`/history`, config);
+export type GetMeError = ResponseError<number, Error>;
+
+/**
+ * User Profile
+ *
+ * The User Profile endpoint returns information about the Uber user that has authorized with the application.
+ */
+export async function getMe(config?: RequestOptions): Promise<Profile> {
+    const response = await client.get(`/me`, config);
     return response.data;
 }
 
-export async function getEstimatesTime(config?: any): Promise<any> {
-    const response = await axios.get(`/estimates/time`, config);
+export interface GetHistoryParams {
+    /** Offset the list of returned results by this amount. Default is zero. */
+    offset?: number;
+    /** Number of items to retrieve. Default is 5, maximum is 100. */
+    limit?: number;
+}
+
+export type GetHistoryError = ResponseError<number, Error>;
+
+/**
+ * User Activity
+ *
+ * The User Activity endpoint returns data about a user's lifetime activity with Uber. The response will include pickup locations and times, dropoff locations and times, the distance of past requests, and information about which products were requested.<br><br>The history array in the response will have a maximum length based on the limit parameter. The response value count may exceed limit, therefore subsequent API requests may be necessary.
+ */
+export async function getHistory(params?: GetHistoryParams, config?: RequestOptions): Promise<Activities> {
+    const response = await client.get(`/history`, { ...config, params: { ...config?.params, ...params } });
     return response.data;
 }
 
+/** Walks every page of `getHistory`, yielding the items of each. */
+export async function* getHistoryAll(params?: GetHistoryParams, config?: RequestOptions): AsyncGenerator<NonNullable<Activities['history']>[number]> {
+    const limit = params?.limit;
+    let offset = params?.offset ?? 0;
+    while (true) {
+        const response = await getHistory({ ...params, offset }, config);
+        const items = response.history ?? [];
+        yield* items;
+        if (items.length === 0 || (limit !== undefined && items.length < limit)) {
+            return;
+        }
+        offset += items.length;
+    }
+}
+
+// <custom>
+// </custom>
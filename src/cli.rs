@@ -0,0 +1,361 @@
+use crate::spec::SpecFormat;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "swagger-generator",
+    version,
+    about = "Generates API clients from Swagger specs",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub generate: GenerateArgs,
+    /// Also log what was parsed and written, and which schemas have no type
+    #[arg(short, long, global = true, conflicts_with = "quiet")]
+    pub verbose: bool,
+    /// Only print errors
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Upgrade a Swagger 2.0 document to OpenAPI 3.0
+    Convert(ConvertArgs),
+    /// Compare two specs and report added, removed and breaking changes
+    Diff(DiffArgs),
+    /// Serve made-up responses for every operation of a spec over HTTP
+    Mock(MockArgs),
+    /// Write the reference documentation of a spec as Markdown or as a self-contained HTML page
+    Docs(DocsArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// Paths, glob patterns or http(s) URLs of the Swagger specs, or "-" to read one from stdin
+    #[arg(short, long = "input", value_name = "INPUT", num_args = 1.., default_value = "swagger.json")]
+    pub inputs: Vec<String>,
+    /// Directory the generated files are written to, or "-" to print them to stdout
+    #[arg(short, long, default_value = "output")]
+    pub output_dir: PathBuf,
+    /// Config file setting these options, `swagger-generator.toml` or `.json` when one exists
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+    /// Keep running and regenerate whenever a local spec, a template or the config file changes
+    #[arg(short, long)]
+    pub watch: bool,
+    /// Print which files would be created or modified, with a diff, instead of writing them
+    #[arg(long)]
+    pub dry_run: bool,
+    /// Remove the files an earlier generation wrote to the output directory that are no longer generated
+    #[arg(long)]
+    pub clean: bool,
+    /// Overwrite generated files that were edited since they were generated, which are otherwise kept
+    #[arg(long)]
+    pub force: bool,
+    /// Target language of the generated service
+    #[arg(short, long, value_enum, default_value_t = Language::TypeScript)]
+    pub language: Language,
+    /// Format of the spec, detected from the file extension or contents when omitted
+    #[arg(short, long, value_enum)]
+    pub format: Option<SpecFormat>,
+    /// Extra header sent when fetching a remote spec, e.g. "Authorization: Bearer <token>"
+    #[arg(short = 'H', long = "header", value_name = "HEADER")]
+    pub headers: Vec<String>,
+    /// Merge all input specs into a single client instead of one directory per spec
+    #[arg(long)]
+    pub merge: bool,
+    /// Only generate operations tagged with one of these tags
+    #[arg(long, value_name = "TAGS", value_delimiter = ',')]
+    pub include_tags: Vec<String>,
+    /// Skip paths matching these glob patterns, e.g. "/admin/*"
+    #[arg(long, value_name = "PATTERNS", value_delimiter = ',')]
+    pub exclude_paths: Vec<String>,
+    /// Only generate operations using these HTTP methods
+    #[arg(long, value_name = "METHODS", value_delimiter = ',')]
+    pub include_methods: Vec<String>,
+    /// Leave out operations and properties marked deprecated
+    #[arg(long)]
+    pub skip_deprecated: bool,
+    /// How methods are named: after the operationId, falling back to the method and path, or always after the path
+    #[arg(long, value_enum, default_value_t = NamingStrategy::OperationId)]
+    pub naming: NamingStrategy,
+    /// Whether the Python client uses blocking requests or async httpx
+    #[arg(long, value_enum, default_value_t = PythonStyle::Sync)]
+    pub python_style: PythonStyle,
+    /// Whether `--language docs` writes Markdown files or a single self-contained `index.html`
+    #[arg(long, value_enum, default_value_t = DocsFormat::Markdown)]
+    pub docs_format: DocsFormat,
+    /// HTTP library the TypeScript service is built on
+    #[arg(long, value_enum, default_value_t = HttpClient::Axios)]
+    pub http_client: HttpClient,
+    /// Whether the TypeScript service exports one function per operation or an `ApiClient` class
+    #[arg(long, value_enum, default_value_t = ServiceStyle::Functions)]
+    pub style: ServiceStyle,
+    /// How TypeScript interfaces type schemas restricted by `enum`
+    #[arg(long, value_enum, default_value_t = EnumStyle::Union)]
+    pub enum_style: EnumStyle,
+    /// Whether nullable TypeScript properties are typed `T | null` or made optional
+    #[arg(long, value_enum, default_value_t = NullableStyle::Union)]
+    pub nullable_style: NullableStyle,
+    /// TypeScript type of `date` and `date-time` strings
+    #[arg(long, value_enum, default_value_t = DateType::String)]
+    pub date_type: DateType,
+    /// TypeScript type of `int64` integers
+    #[arg(long, value_enum, default_value_t = Int64Type::Number)]
+    pub int64_type: Int64Type,
+    /// Also emit `<Name>Request` and `<Name>Response` types without readOnly / writeOnly fields
+    #[arg(long)]
+    pub model_variants: bool,
+    /// Bundle the TypeScript interfaces and service into a single `api.ts`
+    #[arg(long)]
+    pub single_file: bool,
+    /// Emit one service per tag, e.g. `PetService.ts`, instead of a single `service.ts`
+    #[arg(long)]
+    pub split_by_tag: bool,
+    /// Retry failed requests up to this many times with exponential backoff, on network errors,
+    /// 429 and 5xx responses, waiting as long as `Retry-After` asks when the server sends it
+    #[arg(long, value_name = "RETRIES")]
+    pub retries: Option<u32>,
+    /// Also emit runtime validators for every definition
+    #[arg(long, value_enum)]
+    pub validators: Option<Validators>,
+    /// Also emit `handlers.ts` with Mock Service Worker handlers answering every operation with
+    /// example data, typed with the generated interfaces
+    #[arg(long)]
+    pub msw: bool,
+    /// Also emit `__tests__/service.test.ts` with a Jest test per operation checking the request
+    /// the TypeScript service sends, against a Mock Service Worker server
+    #[arg(long)]
+    pub tests: bool,
+    /// Also emit `factories.ts` with a `mock<Name>(overrides)` function per definition making up
+    /// values that honor its formats, enums and bounds, typed with the generated interfaces
+    #[arg(long)]
+    pub factories: bool,
+    /// Directory of `.hbs` templates replacing the built-in ones of the same name: only
+    /// `header.hbs`, `typescript/axios-method.hbs`, `typescript/fetch-method.hbs`,
+    /// `typescript/service.hbs` and `python/method.hbs` exist, the rest of the code of every
+    /// language is not templated
+    #[arg(long, value_name = "DIR")]
+    pub templates: Option<PathBuf>,
+    /// Handlebars template of the comment at the top of every generated file, replacing
+    /// `header.hbs`; leave out `{{generated_on}}` for a banner that does not change every day
+    #[arg(long, value_name = "FILE")]
+    pub banner: Option<PathBuf>,
+    /// Leave the date of generation out of the banners, so that the same spec always gives the
+    /// same files
+    #[arg(long, visible_alias = "deterministic")]
+    pub no_timestamp: bool,
+    /// Program run on the generated files before they are written, given as a path or as the
+    /// name of a `swagger-generator-<NAME>` program on PATH; repeat to chain several
+    #[arg(long = "plugin", value_name = "NAME")]
+    pub plugins: Vec<String>,
+    /// Number of threads generating the files, one per core by default
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+    /// Path or http(s) URL of the Swagger 2.0 spec, or "-" to read it from stdin
+    pub input: String,
+    /// File the converted spec is written to, or "-" to print it to stdout
+    #[arg(short, long, default_value = "-")]
+    pub output: PathBuf,
+    /// Format of the converted spec, detected from the output extension when omitted
+    #[arg(short, long, value_enum)]
+    pub format: Option<SpecFormat>,
+    /// Extra header sent when fetching a remote spec, e.g. "Authorization: Bearer <token>"
+    #[arg(short = 'H', long = "header", value_name = "HEADER")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct DiffArgs {
+    /// Path or http(s) URL of the previous spec
+    pub old: String,
+    /// Path or http(s) URL of the new spec
+    pub new: String,
+    /// Exit with status 1 when breaking changes are found
+    #[arg(long)]
+    pub fail_on_breaking: bool,
+    /// How the changes are printed
+    #[arg(short, long, value_enum, default_value_t = DiffFormat::Text)]
+    pub format: DiffFormat,
+    /// Extra header sent when fetching a remote spec, e.g. "Authorization: Bearer <token>"
+    #[arg(short = 'H', long = "header", value_name = "HEADER")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct MockArgs {
+    /// Path or http(s) URL of the spec
+    #[arg(default_value = "swagger.json")]
+    pub input: String,
+    /// Address to listen on; 0.0.0.0 accepts requests from other machines
+    #[arg(long, default_value = "127.0.0.1")]
+    pub host: String,
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8080)]
+    pub port: u16,
+    /// Milliseconds to wait before answering each request
+    #[arg(long, value_name = "MS", default_value_t = 0)]
+    pub latency: u64,
+    /// Fraction of requests, from 0 to 1, answered with the first documented error response of
+    /// their operation, or with a 500 when it has none
+    #[arg(long, value_name = "RATE", default_value_t = 0.0)]
+    pub error_rate: f64,
+    /// Seed of the draws deciding which requests fail, so that the same ones fail on every run
+    #[arg(long)]
+    pub seed: Option<u64>,
+    /// Extra header sent when fetching a remote spec, e.g. "Authorization: Bearer <token>"
+    #[arg(short = 'H', long = "header", value_name = "HEADER")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Debug, Args)]
+pub struct DocsArgs {
+    /// Path or http(s) URL of the spec, or "-" to read it from stdin
+    #[arg(default_value = "swagger.json")]
+    pub input: String,
+    /// File the documentation is written to, or "-" to print it to stdout; a directory with
+    /// `--split-by-tag`
+    #[arg(short, long, default_value = "-")]
+    pub output: PathBuf,
+    /// Format of the documentation
+    #[arg(short, long, value_enum, default_value_t = DocsFormat::Markdown)]
+    pub format: DocsFormat,
+    /// Write the Markdown reference as a file per tag linked from `README.md`
+    #[arg(long)]
+    pub split_by_tag: bool,
+    /// Extra header sent when fetching a remote spec, e.g. "Authorization: Bearer <token>"
+    #[arg(short = 'H', long = "header", value_name = "HEADER")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Language {
+    #[value(name = "typescript")]
+    TypeScript,
+    #[value(name = "javascript")]
+    JavaScript,
+    Angular,
+    Vue,
+    #[value(name = "rtk-query")]
+    RtkQuery,
+    Python,
+    Go,
+    Rust,
+    Java,
+    Kotlin,
+    #[value(name = "csharp")]
+    CSharp,
+    Swift,
+    Dart,
+    Php,
+    Ruby,
+    #[value(name = "json-schema")]
+    JsonSchema,
+    /// An Express server answering every operation with example data
+    Mock,
+    /// An axum server with a trait per tag for the operations to implement
+    Axum,
+    /// An actix-web server with a trait per tag for the operations to implement
+    #[value(name = "actix-web")]
+    ActixWeb,
+    /// An Express router validating requests for an interface per tag to answer
+    Express,
+    /// NestJS controllers with class-validator DTOs delegating to a service per tag
+    #[value(name = "nestjs")]
+    Nest,
+    /// FastAPI routers and Pydantic models with an abstract class per tag to implement
+    #[value(name = "fastapi")]
+    FastApi,
+    /// A Markdown reference of the operations and schemas, split per tag with `--split-by-tag`,
+    /// or a single HTML page with `--docs-format html`
+    Docs,
+    /// A `requests.http` file with an example request per operation for the REST Client of VS
+    /// Code and the HTTP client of IntelliJ
+    Http,
+    /// A k6 load test with a scenario per tag sending example requests
+    #[value(name = "k6")]
+    K6,
+    /// Pact consumer tests with an interaction per operation, matching bodies by their schemas
+    Pact,
+    /// No client of its own, only the files of the `--plugin`s
+    Plugin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NamingStrategy {
+    OperationId,
+    Path,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PythonStyle {
+    Sync,
+    Async,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffFormat {
+    /// A list of the changes, breaking ones first
+    Text,
+    /// A Markdown changelog section for the release notes of the new version
+    Changelog,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DocsFormat {
+    Markdown,
+    /// A single page in the layout of Redoc, with its styles and script inline
+    Html,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HttpClient {
+    Axios,
+    Fetch,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ServiceStyle {
+    Functions,
+    Class,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EnumStyle {
+    Union,
+    Enum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum NullableStyle {
+    Union,
+    Optional,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DateType {
+    String,
+    Date,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Int64Type {
+    Number,
+    Bigint,
+    String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Validators {
+    Zod,
+    Guards,
+}
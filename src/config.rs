@@ -0,0 +1,137 @@
+use crate::error::{GeneratorError, Result};
+use crate::loader::is_remote;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command};
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config files looked for in the working directory when `--config` is not given.
+const DEFAULT_FILES: &[&str] = &["swagger-generator.toml", "swagger-generator.json"];
+
+/// The config file `config` names, or the first default one that exists.
+pub fn find(config: Option<&Path>) -> Result<Option<PathBuf>> {
+    match config {
+        Some(path) if path.is_file() => Ok(Some(path.to_path_buf())),
+        Some(path) => Err(GeneratorError::Options(format!("config file '{}' does not exist", path.display()))),
+        None => Ok(DEFAULT_FILES.iter().map(PathBuf::from).find(|path| path.is_file())),
+    }
+}
+
+/// Options naming files or directories, which the config file gives relative to where it is.
+const PATH_OPTIONS: &[&str] = &["input", "output-dir", "templates", "banner"];
+
+/// Reads the config file at `path` into the command line arguments of each run it declares.
+///
+/// Every key is the long name of an option of `command`. The keys at the top level apply to all
+/// runs, and each entry of `targets` is one run with its own keys on top, e.g. another language
+/// and output directory; without `targets` there is a single run. Options given on the command
+/// line, as `matches` records, win over the file, so their keys are left out. Relative paths of
+/// [`PATH_OPTIONS`] are resolved against the directory of the file, so that it works from
+/// anywhere. There is no key for overriding the generated types beyond what options such as
+/// `date-type` offer.
+pub fn target_arguments(path: &Path, command: &Command, matches: &ArgMatches) -> Result<Vec<Vec<String>>> {
+    let data = fs::read_to_string(path)
+        .map_err(|source| GeneratorError::Read { input: path.display().to_string(), source })?;
+    let invalid = |message: String| GeneratorError::Options(format!("{}: {}", path.display(), message));
+    let document = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str::<Value>(&data).map_err(|e| invalid(e.to_string()))?,
+        _ => {
+            let table: toml::Table = toml::from_str(&data).map_err(|e| invalid(e.to_string()))?;
+            serde_json::to_value(table).map_err(|e| invalid(e.to_string()))?
+        }
+    };
+    let Value::Object(mut shared) = document else {
+        return Err(invalid("expected a table of options".to_string()));
+    };
+
+    let targets = match shared.remove("targets") {
+        None => vec![Map::new()],
+        Some(Value::Array(targets)) => targets
+            .into_iter()
+            .map(|target| match target {
+                Value::Object(target) => Ok(target),
+                _ => Err(invalid("each of `targets` must be a table of options".to_string())),
+            })
+            .collect::<Result<_>>()?,
+        Some(_) => return Err(invalid("`targets` must be an array of tables".to_string())),
+    };
+
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let mut runs = Vec::new();
+    for target in targets {
+        let mut options = shared.clone();
+        options.extend(target);
+        let mut arguments = Vec::new();
+        for (key, value) in &options {
+            let arg = command
+                .get_arguments()
+                .find(|arg| arg.get_long() == Some(key.as_str()) && key != "config")
+                .ok_or_else(|| invalid(format!("unknown option '{}'", key)))?;
+            if matches.value_source(arg.get_id().as_str()) == Some(ValueSource::CommandLine) {
+                continue;
+            }
+            let values = match value {
+                Value::Array(values) => values.iter().collect(),
+                value => vec![value],
+            };
+            for value in values {
+                match value {
+                    Value::Bool(true) => arguments.push(format!("--{}", key)),
+                    Value::Bool(false) => {}
+                    Value::String(value) if PATH_OPTIONS.contains(&key.as_str()) => {
+                        arguments.push(format!("--{}={}", key, resolve(dir, value)))
+                    }
+                    Value::String(value) => arguments.push(format!("--{}={}", key, value)),
+                    Value::Number(value) => arguments.push(format!("--{}={}", key, value)),
+                    _ => return Err(invalid(format!("'{}' must be a string, number, boolean or array", key))),
+                }
+            }
+        }
+        runs.push(arguments);
+    }
+    Ok(runs)
+}
+
+/// `value` of a path option relative to `dir`, unless it is absolute, a URL or "-" for stdin/stdout.
+fn resolve(dir: &Path, value: &str) -> String {
+    if value == "-" || is_remote(value) || Path::new(value).is_absolute() {
+        value.to_string()
+    } else {
+        dir.join(value).display().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::target_arguments;
+    use crate::cli::Cli;
+    use clap::CommandFactory;
+    use std::fs;
+
+    #[test]
+    fn paths_resolve_against_the_directory_of_the_file() {
+        let dir = std::env::temp_dir().join(format!("swagger-generator-config-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("swagger-generator.toml");
+        fs::write(
+            &path,
+            "input = [\"api/swagger.yaml\", \"https://api.example.com/swagger.json\"]\ntemplates = \"/etc/templates\"\n\
+             naming = \"path\"\n\n[[targets]]\noutput-dir = \"web/api\"\n\n[[targets]]\noutput-dir = \"-\"\n",
+        )
+        .unwrap();
+
+        let command = Cli::command();
+        let matches = command.clone().get_matches_from(["swagger-generator"]);
+        let runs = target_arguments(&path, &command, &matches).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let input = format!("--input={}", dir.join("api/swagger.yaml").display());
+        let output_dir = format!("--output-dir={}", dir.join("web/api").display());
+        let remote = "--input=https://api.example.com/swagger.json";
+        let shared = [input.as_str(), remote, "--naming=path", "--templates=/etc/templates"];
+        assert_eq!(runs[0], [&shared[..], &[output_dir.as_str()]].concat());
+        assert_eq!(runs[1], [&shared[..], &["--output-dir=-"]].concat());
+    }
+}
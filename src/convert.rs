@@ -0,0 +1,506 @@
+use crate::error::{GeneratorError, Result};
+use serde_json::{json, Map, Value};
+
+const HTTP_METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+const SCHEMA_FIELDS: &[&str] = &[
+    "type",
+    "format",
+    "items",
+    "enum",
+    "default",
+    "maximum",
+    "exclusiveMaximum",
+    "minimum",
+    "exclusiveMinimum",
+    "maxLength",
+    "minLength",
+    "pattern",
+    "maxItems",
+    "minItems",
+    "uniqueItems",
+    "multipleOf",
+];
+
+struct Context<'a> {
+    parameters: &'a Map<String, Value>,
+    consumes: Vec<String>,
+    produces: Vec<String>,
+}
+
+/// Converts the Swagger 2.0 `document` read from `input`, which names it in errors.
+pub fn swagger2_to_openapi3(input: &str, document: Value) -> Result<Value> {
+    let swagger = match document {
+        Value::Object(swagger) if swagger.get("swagger").and_then(Value::as_str) == Some("2.0") => swagger,
+        _ => {
+            return Err(GeneratorError::Document {
+                input: input.to_string(),
+                message: "only Swagger 2.0 documents can be converted".to_string(),
+            })
+        }
+    };
+
+    let empty = Map::new();
+    let root_parameters = swagger.get("parameters").and_then(Value::as_object).unwrap_or(&empty);
+    let context = Context {
+        parameters: root_parameters,
+        consumes: media_types(swagger.get("consumes")).unwrap_or_else(|| vec!["application/json".to_string()]),
+        produces: media_types(swagger.get("produces")).unwrap_or_else(|| vec!["application/json".to_string()]),
+    };
+
+    let mut openapi = Map::new();
+    openapi.insert("openapi".to_string(), json!("3.0.3"));
+    if let Some(info) = swagger.get("info") {
+        openapi.insert("info".to_string(), info.clone());
+    }
+    openapi.insert("servers".to_string(), servers(&swagger));
+    for key in ["tags", "externalDocs", "security"] {
+        if let Some(value) = swagger.get(key) {
+            openapi.insert(key.to_string(), value.clone());
+        }
+    }
+    copy_extensions(&swagger, &mut openapi);
+
+    let mut paths = Map::new();
+    if let Some(items) = swagger.get("paths").and_then(Value::as_object) {
+        for (path, item) in items {
+            let item = match item.as_object() {
+                Some(item) => convert_path_item(item, &context),
+                None => item.clone(),
+            };
+            paths.insert(path.clone(), item);
+        }
+    }
+    openapi.insert("paths".to_string(), Value::Object(paths));
+
+    let mut components = Map::new();
+    if let Some(definitions) = swagger.get("definitions").and_then(Value::as_object) {
+        let schemas = definitions
+            .iter()
+            .map(|(name, schema)| (name.clone(), convert_schema(schema)))
+            .collect();
+        components.insert("schemas".to_string(), Value::Object(schemas));
+    }
+
+    let mut parameters = Map::new();
+    let mut request_bodies = Map::new();
+    for (name, parameter) in root_parameters {
+        match parameter.get("in").and_then(Value::as_str) {
+            Some("body") => {
+                request_bodies.insert(name.clone(), request_body(parameter, &context.consumes));
+            }
+            Some("formData") => {
+                let body = form_request_body(std::slice::from_ref(parameter), &context.consumes);
+                request_bodies.insert(name.clone(), body);
+            }
+            _ => {
+                parameters.insert(name.clone(), convert_parameter(parameter));
+            }
+        }
+    }
+    if !parameters.is_empty() {
+        components.insert("parameters".to_string(), Value::Object(parameters));
+    }
+    if !request_bodies.is_empty() {
+        components.insert("requestBodies".to_string(), Value::Object(request_bodies));
+    }
+
+    if let Some(responses) = swagger.get("responses").and_then(Value::as_object) {
+        let responses = responses
+            .iter()
+            .map(|(name, response)| (name.clone(), convert_response(response, &context.produces)))
+            .collect();
+        components.insert("responses".to_string(), Value::Object(responses));
+    }
+
+    if let Some(definitions) = swagger.get("securityDefinitions").and_then(Value::as_object) {
+        let schemes = definitions
+            .iter()
+            .map(|(name, scheme)| (name.clone(), convert_security_scheme(scheme)))
+            .collect();
+        components.insert("securitySchemes".to_string(), Value::Object(schemes));
+    }
+
+    if !components.is_empty() {
+        openapi.insert("components".to_string(), Value::Object(components));
+    }
+
+    let mut openapi = Value::Object(openapi);
+    rewrite_refs(&mut openapi);
+    Ok(openapi)
+}
+
+fn media_types(value: Option<&Value>) -> Option<Vec<String>> {
+    let types: Vec<String> = value?
+        .as_array()?
+        .iter()
+        .filter_map(Value::as_str)
+        .map(str::to_string)
+        .collect();
+    if types.is_empty() {
+        None
+    } else {
+        Some(types)
+    }
+}
+
+fn copy_extensions(from: &Map<String, Value>, to: &mut Map<String, Value>) {
+    for (key, value) in from {
+        if key.starts_with("x-") {
+            to.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn servers(swagger: &Map<String, Value>) -> Value {
+    let base_path = swagger.get("basePath").and_then(Value::as_str).unwrap_or("");
+    let Some(host) = swagger.get("host").and_then(Value::as_str) else {
+        let url = if base_path.is_empty() { "/" } else { base_path };
+        return json!([{ "url": url }]);
+    };
+
+    let schemes = media_types(swagger.get("schemes")).unwrap_or_else(|| vec!["https".to_string()]);
+    let servers = schemes
+        .iter()
+        .map(|scheme| json!({ "url": format!("{}://{}{}", scheme, host, base_path) }))
+        .collect();
+    Value::Array(servers)
+}
+
+fn convert_path_item(item: &Map<String, Value>, context: &Context) -> Value {
+    let mut converted = Map::new();
+    let mut shared_body_parameters = Vec::new();
+
+    if let Some(parameters) = item.get("parameters").and_then(Value::as_array) {
+        let mut path_parameters = Vec::new();
+        for parameter in parameters {
+            let (resolved, _) = resolve_parameter(parameter, context);
+            match resolved.get("in").and_then(Value::as_str) {
+                Some("body") | Some("formData") => shared_body_parameters.push(parameter.clone()),
+                _ if parameter.get("$ref").is_some() => path_parameters.push(parameter.clone()),
+                _ => path_parameters.push(convert_parameter(parameter)),
+            }
+        }
+        if !path_parameters.is_empty() {
+            converted.insert("parameters".to_string(), Value::Array(path_parameters));
+        }
+    }
+
+    for (key, value) in item {
+        if HTTP_METHODS.contains(&key.as_str()) {
+            if let Some(operation) = value.as_object() {
+                let operation = convert_operation(operation, &shared_body_parameters, context);
+                converted.insert(key.clone(), operation);
+            }
+        } else if key != "parameters" {
+            converted.insert(key.clone(), value.clone());
+        }
+    }
+
+    Value::Object(converted)
+}
+
+fn resolve_parameter<'a>(parameter: &'a Value, context: &'a Context) -> (&'a Value, Option<&'a str>) {
+    let name = parameter
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix("#/parameters/"));
+    match name.and_then(|name| context.parameters.get(name)) {
+        Some(resolved) => (resolved, name),
+        None => (parameter, None),
+    }
+}
+
+fn convert_operation(operation: &Map<String, Value>, shared: &[Value], context: &Context) -> Value {
+    let mut converted = Map::new();
+    for key in ["tags", "summary", "description", "externalDocs", "operationId", "deprecated", "security"] {
+        if let Some(value) = operation.get(key) {
+            converted.insert(key.to_string(), value.clone());
+        }
+    }
+    copy_extensions(operation, &mut converted);
+
+    let consumes = media_types(operation.get("consumes")).unwrap_or_else(|| context.consumes.clone());
+    let produces = media_types(operation.get("produces")).unwrap_or_else(|| context.produces.clone());
+
+    let mut parameters = Vec::new();
+    let mut form_parameters = Vec::new();
+    let operation_parameters = operation.get("parameters").and_then(Value::as_array);
+    for parameter in shared.iter().chain(operation_parameters.into_iter().flatten()) {
+        let (resolved, name) = resolve_parameter(parameter, context);
+        match resolved.get("in").and_then(Value::as_str) {
+            Some("body") => {
+                let body = match name {
+                    Some(name) => json!({ "$ref": format!("#/components/requestBodies/{}", name) }),
+                    None => request_body(resolved, &consumes),
+                };
+                converted.insert("requestBody".to_string(), body);
+            }
+            Some("formData") => form_parameters.push(resolved.clone()),
+            _ if name.is_some() => parameters.push(parameter.clone()),
+            _ => parameters.push(convert_parameter(parameter)),
+        }
+    }
+    if !parameters.is_empty() {
+        converted.insert("parameters".to_string(), Value::Array(parameters));
+    }
+    if !form_parameters.is_empty() {
+        converted.insert("requestBody".to_string(), form_request_body(&form_parameters, &consumes));
+    }
+
+    if let Some(responses) = operation.get("responses").and_then(Value::as_object) {
+        let responses = responses
+            .iter()
+            .map(|(status, response)| (status.clone(), convert_response(response, &produces)))
+            .collect();
+        converted.insert("responses".to_string(), Value::Object(responses));
+    }
+
+    Value::Object(converted)
+}
+
+fn parameter_schema(parameter: &Map<String, Value>) -> Value {
+    let mut schema = Map::new();
+    for key in SCHEMA_FIELDS {
+        if let Some(value) = parameter.get(*key) {
+            schema.insert(key.to_string(), value.clone());
+        }
+    }
+    convert_schema(&Value::Object(schema))
+}
+
+fn convert_parameter(parameter: &Value) -> Value {
+    let Some(parameter) = parameter.as_object() else {
+        return parameter.clone();
+    };
+    if parameter.contains_key("$ref") {
+        return Value::Object(parameter.clone());
+    }
+
+    let mut converted = Map::new();
+    for key in ["name", "in", "description", "required", "allowEmptyValue"] {
+        if let Some(value) = parameter.get(key) {
+            converted.insert(key.to_string(), value.clone());
+        }
+    }
+    copy_extensions(parameter, &mut converted);
+    converted.insert("schema".to_string(), parameter_schema(parameter));
+
+    if parameter.get("type").and_then(Value::as_str) == Some("array") {
+        let location = parameter.get("in").and_then(Value::as_str).unwrap_or("query");
+        let (style, explode) = match parameter.get("collectionFormat").and_then(Value::as_str) {
+            Some("multi") => ("form", true),
+            Some("ssv") => ("spaceDelimited", false),
+            Some("pipes") => ("pipeDelimited", false),
+            _ if location == "query" || location == "cookie" => ("form", false),
+            _ => ("simple", false),
+        };
+        converted.insert("style".to_string(), json!(style));
+        converted.insert("explode".to_string(), json!(explode));
+    }
+
+    Value::Object(converted)
+}
+
+fn request_body(parameter: &Value, consumes: &[String]) -> Value {
+    let schema = parameter.get("schema").map(convert_schema).unwrap_or_else(|| json!({}));
+    let content: Map<String, Value> = consumes
+        .iter()
+        .map(|media_type| (media_type.clone(), json!({ "schema": schema })))
+        .collect();
+
+    let mut body = Map::new();
+    if let Some(description) = parameter.get("description") {
+        body.insert("description".to_string(), description.clone());
+    }
+    if let Some(required) = parameter.get("required") {
+        body.insert("required".to_string(), required.clone());
+    }
+    body.insert("content".to_string(), Value::Object(content));
+    if let Some(name) = parameter.get("name") {
+        body.insert("x-codegen-request-body-name".to_string(), name.clone());
+    }
+    Value::Object(body)
+}
+
+fn form_request_body(parameters: &[Value], consumes: &[String]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    let mut has_file = false;
+    for parameter in parameters.iter().filter_map(Value::as_object) {
+        let Some(name) = parameter.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        has_file |= parameter.get("type").and_then(Value::as_str) == Some("file");
+        let mut schema = parameter_schema(parameter);
+        if let (Some(description), Some(schema)) = (parameter.get("description"), schema.as_object_mut()) {
+            schema.insert("description".to_string(), description.clone());
+        }
+        properties.insert(name.to_string(), schema);
+        if parameter.get("required").and_then(Value::as_bool) == Some(true) {
+            required.push(json!(name));
+        }
+    }
+
+    let media_type = if has_file || consumes.iter().any(|c| c == "multipart/form-data") {
+        "multipart/form-data"
+    } else {
+        "application/x-www-form-urlencoded"
+    };
+
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required);
+    }
+    json!({ "content": { media_type: { "schema": schema } } })
+}
+
+fn convert_response(response: &Value, produces: &[String]) -> Value {
+    let Some(response) = response.as_object() else {
+        return response.clone();
+    };
+    if response.contains_key("$ref") {
+        return Value::Object(response.clone());
+    }
+
+    let mut converted = Map::new();
+    let description = response.get("description").cloned().unwrap_or_else(|| json!(""));
+    converted.insert("description".to_string(), description);
+    copy_extensions(response, &mut converted);
+
+    if let Some(headers) = response.get("headers").and_then(Value::as_object) {
+        let headers = headers
+            .iter()
+            .map(|(name, header)| {
+                let header = header.as_object().cloned().unwrap_or_default();
+                let mut converted = Map::new();
+                if let Some(description) = header.get("description") {
+                    converted.insert("description".to_string(), description.clone());
+                }
+                converted.insert("schema".to_string(), parameter_schema(&header));
+                (name.clone(), Value::Object(converted))
+            })
+            .collect();
+        converted.insert("headers".to_string(), Value::Object(headers));
+    }
+
+    if let Some(schema) = response.get("schema") {
+        let schema = convert_schema(schema);
+        let examples = response.get("examples").and_then(Value::as_object);
+        let content: Map<String, Value> = produces
+            .iter()
+            .map(|media_type| {
+                let mut media = json!({ "schema": schema });
+                if let Some(example) = examples.and_then(|e| e.get(media_type)) {
+                    media["example"] = example.clone();
+                }
+                (media_type.clone(), media)
+            })
+            .collect();
+        converted.insert("content".to_string(), Value::Object(content));
+    }
+
+    Value::Object(converted)
+}
+
+fn convert_schema(schema: &Value) -> Value {
+    let Some(schema) = schema.as_object() else {
+        return schema.clone();
+    };
+
+    let mut converted = Map::new();
+    for (key, value) in schema {
+        match key.as_str() {
+            "x-nullable" => {
+                converted.insert("nullable".to_string(), value.clone());
+            }
+            "type" if value.as_str() == Some("file") => {
+                converted.insert("type".to_string(), json!("string"));
+                converted.insert("format".to_string(), json!("binary"));
+            }
+            "discriminator" if value.is_string() => {
+                converted.insert(key.clone(), json!({ "propertyName": value }));
+            }
+            "properties" => {
+                let properties = value
+                    .as_object()
+                    .map(|properties| {
+                        properties
+                            .iter()
+                            .map(|(name, property)| (name.clone(), convert_schema(property)))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                converted.insert(key.clone(), Value::Object(properties));
+            }
+            "items" | "additionalProperties" | "not" => {
+                converted.insert(key.clone(), convert_schema(value));
+            }
+            "allOf" | "oneOf" | "anyOf" => {
+                let schemas = value
+                    .as_array()
+                    .map(|schemas| schemas.iter().map(convert_schema).collect())
+                    .unwrap_or_default();
+                converted.insert(key.clone(), Value::Array(schemas));
+            }
+            _ if key == "format" && converted.contains_key("format") => {}
+            _ => {
+                converted.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    Value::Object(converted)
+}
+
+fn convert_security_scheme(scheme: &Value) -> Value {
+    let Some(scheme) = scheme.as_object() else {
+        return scheme.clone();
+    };
+
+    let mut converted = match scheme.get("type").and_then(Value::as_str) {
+        Some("basic") => json!({ "type": "http", "scheme": "basic" }),
+        Some("apiKey") => json!({ "type": "apiKey", "name": scheme.get("name"), "in": scheme.get("in") }),
+        Some("oauth2") => {
+            let scopes = scheme.get("scopes").cloned().unwrap_or_else(|| json!({}));
+            let mut flow = json!({ "scopes": scopes });
+            for key in ["authorizationUrl", "tokenUrl"] {
+                if let Some(url) = scheme.get(key) {
+                    flow[key] = url.clone();
+                }
+            }
+            let flow_name = match scheme.get("flow").and_then(Value::as_str) {
+                Some("password") => "password",
+                Some("application") => "clientCredentials",
+                Some("accessCode") => "authorizationCode",
+                _ => "implicit",
+            };
+            json!({ "type": "oauth2", "flows": { flow_name: flow } })
+        }
+        _ => Value::Object(scheme.clone()),
+    };
+    if let Some(description) = scheme.get("description") {
+        converted["description"] = description.clone();
+    }
+    converted
+}
+
+fn rewrite_refs(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get_mut("$ref") {
+                for (from, to) in [
+                    ("#/definitions/", "#/components/schemas/"),
+                    ("#/parameters/", "#/components/parameters/"),
+                    ("#/responses/", "#/components/responses/"),
+                ] {
+                    if let Some(name) = reference.strip_prefix(from) {
+                        *reference = format!("{}{}", to, name);
+                        break;
+                    }
+                }
+            }
+            map.values_mut().for_each(rewrite_refs);
+        }
+        Value::Array(items) => items.iter_mut().for_each(rewrite_refs),
+        _ => {}
+    }
+}
@@ -0,0 +1,616 @@
+use crate::generators::own_fields;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+    Deprecated,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::Changed => write!(f, "changed"),
+            ChangeKind::Deprecated => write!(f, "deprecated"),
+        }
+    }
+}
+
+/// What a change belongs to, which the changelog groups changes by.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Subject {
+    /// An operation, e.g. `GET /pets`.
+    Operation(String),
+    /// A schema of the definitions, by name.
+    Schema(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Change {
+    pub subject: Subject,
+    pub kind: ChangeKind,
+    pub breaking: bool,
+    pub location: String,
+    pub message: String,
+}
+
+impl Change {
+    /// The part of the location below the subject, e.g. `query parameter 'limit'` for a
+    /// parameter of an operation or `name` for a property of a schema; `None` for the subject
+    /// itself.
+    pub fn detail(&self) -> Option<&str> {
+        let detail = match &self.subject {
+            Subject::Operation(operation) => self.location.strip_prefix(operation.as_str()).map(str::trim_start),
+            Subject::Schema(name) => self
+                .location
+                .strip_prefix("definitions.")
+                .and_then(|location| location.strip_prefix(name.as_str()))
+                .and_then(|location| location.strip_prefix('.')),
+        };
+        detail.filter(|detail| !detail.is_empty())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SpecDiff {
+    pub changes: Vec<Change>,
+}
+
+impl SpecDiff {
+    pub fn has_breaking_changes(&self) -> bool {
+        self.changes.iter().any(|change| change.breaking)
+    }
+
+    /// The changes as a Markdown changelog section for `version`, listing the endpoints and
+    /// schemas added, removed and changed and what was deprecated, with breaking changes marked.
+    pub fn changelog(&self, version: &str) -> String {
+        let mut markdown = format!("## {}\n", if version.is_empty() { "Unreleased" } else { version });
+        if self.changes.is_empty() {
+            markdown.push_str("\nNo changes to the API.\n");
+            return markdown;
+        }
+        let breaking = self.changes.iter().filter(|change| change.breaking).count();
+        if breaking > 0 {
+            let plural = if breaking == 1 { "" } else { "s" };
+            markdown.push_str(&format!("\n{} breaking change{}, marked **Breaking** below.\n", breaking, plural));
+        }
+
+        // Added, removed and changed endpoints, then schemas, then deprecations.
+        let mut sections: [Vec<String>; 7] = Default::default();
+        let mut changed: BTreeMap<&Subject, Vec<&Change>> = BTreeMap::new();
+        for change in &self.changes {
+            let (name, offset) = match &change.subject {
+                Subject::Operation(operation) => (operation, 0),
+                Subject::Schema(name) => (name, 3),
+            };
+            match (change.kind, change.detail()) {
+                (ChangeKind::Deprecated, Some(detail)) => sections[6].push(format!("`{}.{}`", name, detail)),
+                (ChangeKind::Deprecated, None) => sections[6].push(format!("`{}`", name)),
+                (ChangeKind::Added, None) => sections[offset].push(marked(change, format!("`{}`", name))),
+                (ChangeKind::Removed, None) => sections[offset + 1].push(marked(change, format!("`{}`", name))),
+                _ => changed.entry(&change.subject).or_default().push(change),
+            }
+        }
+        for (subject, changes) in changed {
+            let (mut entry, index) = match subject {
+                Subject::Operation(operation) => (format!("`{}`", operation), 2),
+                Subject::Schema(name) => (format!("`{}`", name), 5),
+            };
+            for change in changes {
+                let text = match (subject, change.detail()) {
+                    (Subject::Schema(_), Some(detail)) => format!("`{}`: {}", detail, change.message),
+                    (Subject::Operation(_), Some(detail)) => format!("{}: {}", detail, change.message),
+                    (_, None) => change.message.clone(),
+                };
+                entry.push_str(&format!("\n  - {}", marked(change, text)));
+            }
+            sections[index].push(entry);
+        }
+
+        let titles = [
+            "Added endpoints",
+            "Removed endpoints",
+            "Changed endpoints",
+            "Added schemas",
+            "Removed schemas",
+            "Changed schemas",
+            "Deprecated",
+        ];
+        for (title, entries) in titles.iter().zip(sections) {
+            if entries.is_empty() {
+                continue;
+            }
+            markdown.push_str(&format!("\n### {}\n\n", title));
+            for entry in entries {
+                markdown.push_str(&format!("- {}\n", entry));
+            }
+        }
+        markdown
+    }
+
+    fn push(
+        &mut self,
+        subject: &Subject,
+        kind: ChangeKind,
+        breaking: bool,
+        location: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        self.changes.push(Change {
+            subject: subject.clone(),
+            kind,
+            breaking,
+            location: location.into(),
+            message: message.into(),
+        });
+    }
+}
+
+impl fmt::Display for SpecDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.changes.is_empty() {
+            return writeln!(f, "No changes detected");
+        }
+
+        let (breaking, other): (Vec<&Change>, Vec<&Change>) =
+            self.changes.iter().partition(|change| change.breaking);
+        for (title, changes) in [("Breaking changes", breaking), ("Non-breaking changes", other)] {
+            if changes.is_empty() {
+                continue;
+            }
+            writeln!(f, "{} ({}):", title, changes.len())?;
+            for change in changes {
+                writeln!(f, "  - [{}] {}: {}", change.kind, change.location, change.message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn diff_specs(old: &Swagger, new: &Swagger) -> SpecDiff {
+    let mut diff = SpecDiff::default();
+    diff_operations(&mut diff, old, new);
+    diff_definitions(&mut diff, old, new);
+    diff.changes.sort_by(|a, b| (&a.location, a.kind).cmp(&(&b.location, b.kind)));
+    diff
+}
+
+/// `text` led by a **Breaking** mark when `change` breaks clients.
+fn marked(change: &Change, text: String) -> String {
+    if change.breaking {
+        format!("**Breaking:** {}", text)
+    } else {
+        text
+    }
+}
+
+fn operations(swagger: &Swagger) -> BTreeMap<String, &Operation> {
+    swagger
+        .paths
+        .iter()
+        .flat_map(|(path, item)| {
+            item.operations()
+                .into_iter()
+                .map(move |(method, operation)| (format!("{} {}", method.to_uppercase(), path), operation))
+        })
+        .collect()
+}
+
+fn diff_operations(diff: &mut SpecDiff, old: &Swagger, new: &Swagger) {
+    let old_operations = operations(old);
+    let new_operations = operations(new);
+
+    for (location, old_operation) in &old_operations {
+        let subject = Subject::Operation(location.clone());
+        match new_operations.get(location) {
+            Some(new_operation) => diff_operation(diff, location, old_operation, new_operation),
+            None => diff.push(&subject, ChangeKind::Removed, true, location, "Operation removed"),
+        }
+    }
+    for location in new_operations.keys() {
+        if !old_operations.contains_key(location) {
+            let subject = Subject::Operation(location.clone());
+            diff.push(&subject, ChangeKind::Added, false, location, "Operation added");
+        }
+    }
+}
+
+fn parameters(operation: &Operation) -> BTreeMap<(String, String), &Parameter> {
+    operation
+        .parameters
+        .iter()
+        .map(|parameter| ((parameter.location.clone(), parameter.name.clone()), parameter))
+        .collect()
+}
+
+fn diff_operation(diff: &mut SpecDiff, location: &str, old: &Operation, new: &Operation) {
+    let subject = Subject::Operation(location.to_string());
+    if !old.deprecated && new.deprecated {
+        diff.push(&subject, ChangeKind::Deprecated, false, location, "Operation deprecated");
+    }
+    let old_parameters = parameters(old);
+    let new_parameters = parameters(new);
+
+    for (key, old_parameter) in &old_parameters {
+        let parameter_location = format!("{} {} parameter '{}'", location, key.0, key.1);
+        let Some(new_parameter) = new_parameters.get(key) else {
+            diff.push(&subject, ChangeKind::Removed, true, parameter_location, "Parameter removed");
+            continue;
+        };
+
+        if !old_parameter.required && new_parameter.required {
+            diff.push(&subject, ChangeKind::Changed, true, &parameter_location, "Parameter became required");
+        }
+        let old_type = describe_parameter(old_parameter);
+        let new_type = describe_parameter(new_parameter);
+        if old_type != new_type {
+            let message = format!("Type changed from {} to {}", old_type, new_type);
+            diff.push(&subject, ChangeKind::Changed, true, &parameter_location, message);
+        }
+        let old_values = parameter_enum(old_parameter);
+        diff_enum(diff, &subject, &parameter_location, old_values, parameter_enum(new_parameter));
+    }
+    for (key, new_parameter) in &new_parameters {
+        if !old_parameters.contains_key(key) {
+            let parameter_location = format!("{} {} parameter '{}'", location, key.0, key.1);
+            if new_parameter.required {
+                diff.push(&subject, ChangeKind::Added, true, parameter_location, "Required parameter added");
+            } else {
+                diff.push(&subject, ChangeKind::Added, false, parameter_location, "Optional parameter added");
+            }
+        }
+    }
+
+    let statuses: BTreeSet<&String> = old.responses.keys().chain(new.responses.keys()).collect();
+    for status in statuses {
+        let response_location = format!("{} response {}", location, status);
+        match (old.responses.get(status), new.responses.get(status)) {
+            (Some(_), None) => diff.push(&subject, ChangeKind::Removed, true, response_location, "Response removed"),
+            (None, Some(_)) => diff.push(&subject, ChangeKind::Added, false, response_location, "Response added"),
+            (Some(old_response), Some(new_response)) => {
+                let old_type = describe_schema(old_response.response_schema.as_ref());
+                let new_type = describe_schema(new_response.response_schema.as_ref());
+                if old_type != new_type {
+                    let message = format!("Schema changed from {} to {}", old_type, new_type);
+                    diff.push(&subject, ChangeKind::Changed, true, response_location, message);
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn diff_definitions(diff: &mut SpecDiff, old: &Swagger, new: &Swagger) {
+    for (name, old_definition) in &old.definitions {
+        let subject = Subject::Schema(name.clone());
+        let location = format!("definitions.{}", name);
+        match new.definitions.get(name) {
+            Some(new_definition) => diff_definition(diff, &subject, &location, old_definition, new_definition),
+            None => diff.push(&subject, ChangeKind::Removed, true, location, "Schema removed"),
+        }
+    }
+    for name in new.definitions.keys() {
+        if !old.definitions.contains_key(name) {
+            let subject = Subject::Schema(name.clone());
+            diff.push(&subject, ChangeKind::Added, false, format!("definitions.{}", name), "Schema added");
+        }
+    }
+}
+
+fn diff_definition(diff: &mut SpecDiff, subject: &Subject, location: &str, old: &Definition, new: &Definition) {
+    if !old.deprecated && new.deprecated {
+        diff.push(subject, ChangeKind::Deprecated, false, location, "Schema deprecated");
+    }
+    let old_values = old.enum_values.as_deref().map(value_set);
+    diff_enum(diff, subject, location, old_values, new.enum_values.as_deref().map(value_set));
+    // The properties of the inline parts of an `allOf` are the schema's own; those of the schemas
+    // it refers to are compared with those schemas.
+    let old_properties: BTreeMap<&String, (&Property, bool)> =
+        own_fields(old).into_iter().map(|(name, property, required)| (name, (property, required))).collect();
+    let new_properties: BTreeMap<&String, (&Property, bool)> =
+        own_fields(new).into_iter().map(|(name, property, required)| (name, (property, required))).collect();
+
+    for (name, &(old_property, old_required)) in &old_properties {
+        let property_location = format!("{}.{}", location, name);
+        let Some(&(new_property, new_required)) = new_properties.get(name) else {
+            if old_required {
+                diff.push(subject, ChangeKind::Removed, true, property_location, "Required property removed");
+            } else {
+                diff.push(subject, ChangeKind::Removed, false, property_location, "Optional property removed");
+            }
+            continue;
+        };
+
+        if !old_required && new_required {
+            diff.push(subject, ChangeKind::Changed, true, &property_location, "Property became required");
+        }
+        let old_type = describe_property(old_property);
+        let new_type = describe_property(new_property);
+        if old_type != new_type {
+            let message = format!("Type changed from {} to {}", old_type, new_type);
+            diff.push(subject, ChangeKind::Changed, true, &property_location, message);
+        }
+        diff_enum(diff, subject, &property_location, enum_values(old_property), enum_values(new_property));
+        if !is_deprecated(old_property) && is_deprecated(new_property) {
+            diff.push(subject, ChangeKind::Deprecated, false, &property_location, "Property deprecated");
+        }
+    }
+    for (name, &(_, required)) in &new_properties {
+        if !old_properties.contains_key(name) {
+            let property_location = format!("{}.{}", location, name);
+            if required {
+                diff.push(subject, ChangeKind::Added, true, property_location, "Required property added");
+            } else {
+                diff.push(subject, ChangeKind::Added, false, property_location, "Optional property added");
+            }
+        }
+    }
+}
+
+fn value_set(values: &[Value]) -> BTreeSet<String> {
+    values.iter().map(Value::to_string).collect()
+}
+
+fn enum_values(property: &Property) -> Option<BTreeSet<String>> {
+    Some(value_set(property.additional.get("enum")?.as_array()?))
+}
+
+/// The values a parameter is limited to, for an array parameter those of its items.
+fn parameter_enum(parameter: &Parameter) -> Option<BTreeSet<String>> {
+    let schema = parameter.schema.as_ref().or(parameter.items.as_ref());
+    let values = parameter.enum_values.as_ref().or(schema.and_then(|schema| schema.enum_values.as_ref()))?;
+    Some(value_set(values))
+}
+
+fn is_deprecated(property: &Property) -> bool {
+    property.additional.get("deprecated") == Some(&Value::Bool(true))
+}
+
+fn diff_enum(
+    diff: &mut SpecDiff,
+    subject: &Subject,
+    location: &str,
+    old: Option<BTreeSet<String>>,
+    new: Option<BTreeSet<String>>,
+) {
+    match (old, new) {
+        (Some(old_values), Some(new_values)) => {
+            let removed: Vec<&String> = old_values.difference(&new_values).collect();
+            let added: Vec<&String> = new_values.difference(&old_values).collect();
+            if !removed.is_empty() {
+                let message = format!("Enum narrowed, removed {}", join(&removed));
+                diff.push(subject, ChangeKind::Changed, true, location, message);
+            }
+            if !added.is_empty() {
+                let message = format!("Enum widened, added {}", join(&added));
+                diff.push(subject, ChangeKind::Changed, false, location, message);
+            }
+        }
+        (None, Some(_)) => diff.push(subject, ChangeKind::Changed, true, location, "Enum constraint added"),
+        (Some(_), None) => diff.push(subject, ChangeKind::Changed, false, location, "Enum constraint removed"),
+        (None, None) => {}
+    }
+}
+
+fn join(values: &[&String]) -> String {
+    values.iter().map(|value| value.as_str()).collect::<Vec<&str>>().join(", ")
+}
+
+fn describe_type(schema_type: Option<&str>, format: Option<&str>) -> String {
+    match (schema_type, format) {
+        (Some(schema_type), Some(format)) => format!("{}({})", schema_type, format),
+        (Some(schema_type), None) => schema_type.to_string(),
+        (None, _) => "any".to_string(),
+    }
+}
+
+fn describe_value(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return ref_name(reference).to_string();
+    }
+    let schema_type = schema.get("type").and_then(Value::as_str);
+    if schema_type == Some("array") {
+        let items = schema.get("items").map(describe_value).unwrap_or_else(|| "any".to_string());
+        return format!("{}[]", items);
+    }
+    describe_type(schema_type, schema.get("format").and_then(Value::as_str))
+}
+
+fn describe_property(property: &Property) -> String {
+    if let Some(reference) = property.additional.get("$ref").and_then(Value::as_str) {
+        return ref_name(reference).to_string();
+    }
+    if property.property_type.as_deref() == Some("array") {
+        let items = property
+            .additional
+            .get("items")
+            .map(describe_value)
+            .unwrap_or_else(|| "any".to_string());
+        return format!("{}[]", items);
+    }
+    describe_type(property.property_type.as_deref(), property.format.as_deref())
+}
+
+fn describe_schema(schema: Option<&Schema>) -> String {
+    match schema {
+        Some(Schema { reference: Some(reference), .. }) => ref_name(reference).to_string(),
+        Some(Schema { schema_type: Some(schema_type), items, .. }) if schema_type == "array" => {
+            let items = items.as_deref().map_or_else(|| "any".to_string(), |items| describe_schema(Some(items)));
+            format!("{}[]", items)
+        }
+        Some(schema) => describe_type(schema.schema_type.as_deref(), schema.format.as_deref()),
+        None => "none".to_string(),
+    }
+}
+
+fn describe_parameter(parameter: &Parameter) -> String {
+    match &parameter.schema {
+        Some(schema) if parameter.parameter_type.is_none() => describe_schema(Some(schema)),
+        _ if parameter.parameter_type.as_deref() == Some("array") => {
+            let items =
+                parameter.items.as_ref().map_or_else(|| "any".to_string(), |items| describe_schema(Some(items)));
+            format!("{}[]", items)
+        }
+        _ => describe_type(parameter.parameter_type.as_deref(), parameter.format.as_deref()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_specs;
+    use crate::parse_spec;
+    use serde_json::{json, Value};
+
+    /// The changes from a document with `old` paths and definitions to one with `new`, as the
+    /// location, message and whether the change breaks clients.
+    fn changes(old: (Value, Value), new: (Value, Value)) -> Vec<(String, String, bool)> {
+        let spec = |(paths, definitions): (Value, Value)| {
+            let document = json!({ "swagger": "2.0", "info": {}, "paths": paths, "definitions": definitions });
+            parse_spec(&document.to_string(), None).unwrap()
+        };
+        let diff = diff_specs(&spec(old), &spec(new));
+        diff.changes.into_iter().map(|change| (change.location, change.message, change.breaking)).collect()
+    }
+
+    fn change(location: &str, message: &str, breaking: bool) -> (String, String, bool) {
+        (location.to_string(), message.to_string(), breaking)
+    }
+
+    fn list_pets(parameters: Value, schema: Value) -> Value {
+        let responses = json!({ "200": { "description": "ok", "schema": schema } });
+        json!({ "/pets": { "get": { "parameters": parameters, "responses": responses } } })
+    }
+
+    #[test]
+    fn operations_removed_break_and_added_do_not() {
+        let pets = list_pets(json!([]), json!({ "type": "string" }));
+        let stores = json!({ "/stores": { "get": { "responses": {} } } });
+        assert_eq!(
+            changes((pets, json!({})), (stores, json!({}))),
+            [change("GET /pets", "Operation removed", true), change("GET /stores", "Operation added", false)]
+        );
+    }
+
+    #[test]
+    fn parameters_removed_required_or_retyped_break() {
+        let old = json!([
+            { "name": "limit", "in": "query", "type": "integer" },
+            { "name": "tag", "in": "query", "type": "string" },
+            { "name": "sort", "in": "query", "type": "string" },
+        ]);
+        let new = json!([
+            { "name": "limit", "in": "query", "type": "string" },
+            { "name": "tag", "in": "query", "type": "string", "required": true },
+            { "name": "page", "in": "query", "type": "integer" },
+        ]);
+        let schema = json!({ "type": "string" });
+        assert_eq!(
+            changes((list_pets(old, schema.clone()), json!({})), (list_pets(new, schema), json!({}))),
+            [
+                change("GET /pets query parameter 'limit'", "Type changed from integer to string", true),
+                change("GET /pets query parameter 'page'", "Optional parameter added", false),
+                change("GET /pets query parameter 'sort'", "Parameter removed", true),
+                change("GET /pets query parameter 'tag'", "Parameter became required", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn parameter_enums_narrowed_break() {
+        let parameters = |values: Value| {
+            json!([
+                { "name": "status", "in": "query", "type": "string", "enum": values },
+                { "name": "tags", "in": "query", "type": "array", "items": { "type": "string", "enum": values } },
+            ])
+        };
+        let schema = json!({ "type": "string" });
+        let old = list_pets(parameters(json!(["available", "sold"])), schema.clone());
+        let new = list_pets(parameters(json!(["available", "pending"])), schema);
+        assert_eq!(
+            changes((old, json!({})), (new, json!({}))),
+            [
+                change("GET /pets query parameter 'status'", "Enum narrowed, removed \"sold\"", true),
+                change("GET /pets query parameter 'status'", "Enum widened, added \"pending\"", false),
+                change("GET /pets query parameter 'tags'", "Enum narrowed, removed \"sold\"", true),
+                change("GET /pets query parameter 'tags'", "Enum widened, added \"pending\"", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn response_schemas_changed_break_down_to_array_items() {
+        let pets = |name: &str| json!({ "type": "array", "items": { "$ref": format!("#/definitions/{}", name) } });
+        let definitions = json!({ "Pet": { "type": "object" }, "Animal": { "type": "object" } });
+        let old = list_pets(json!([]), pets("Pet"));
+        let new = list_pets(json!([]), pets("Animal"));
+        assert_eq!(
+            changes((old, definitions.clone()), (new, definitions)),
+            [change("GET /pets response 200", "Schema changed from Pet[] to Animal[]", true)]
+        );
+    }
+
+    #[test]
+    fn properties_removed_break_only_when_required() {
+        let old = json!({ "Pet": {
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" }, "name": { "type": "string" } },
+        } });
+        let new = json!({ "Pet": { "type": "object", "properties": { "tag": { "type": "string" } } } });
+        assert_eq!(
+            changes((json!({}), old), (json!({}), new)),
+            [
+                change("definitions.Pet.id", "Required property removed", true),
+                change("definitions.Pet.name", "Optional property removed", false),
+                change("definitions.Pet.tag", "Optional property added", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn properties_of_all_of_parts_are_compared() {
+        let pet = |id_type: &str| {
+            json!({
+                "Animal": { "type": "object" },
+                "Pet": { "allOf": [
+                    { "$ref": "#/definitions/Animal" },
+                    { "type": "object", "properties": { "id": { "type": id_type } } },
+                ] },
+            })
+        };
+        assert_eq!(
+            changes((json!({}), pet("integer")), (json!({}), pet("string"))),
+            [change("definitions.Pet.id", "Type changed from integer to string", true)]
+        );
+    }
+
+    #[test]
+    fn definition_enums_narrowed_break() {
+        let status = |values: Value| json!({ "Status": { "type": "string", "enum": values } });
+        assert_eq!(
+            changes((json!({}), status(json!(["available", "sold"]))), (json!({}), status(json!(["available"])))),
+            [change("definitions.Status", "Enum narrowed, removed \"sold\"", true)]
+        );
+        assert_eq!(
+            changes((json!({}), status(json!(["available"]))), (json!({}), status(json!(["available", "sold"])))),
+            [change("definitions.Status", "Enum widened, added \"sold\"", false)]
+        );
+    }
+
+    #[test]
+    fn schemas_removed_break_and_added_do_not() {
+        let pet = json!({ "Pet": { "type": "object" } });
+        let store = json!({ "Store": { "type": "object" } });
+        assert_eq!(
+            changes((json!({}), pet), (json!({}), store)),
+            [change("definitions.Pet", "Schema removed", true), change("definitions.Store", "Schema added", false)]
+        );
+    }
+}
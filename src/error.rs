@@ -0,0 +1,82 @@
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, GeneratorError>;
+
+/// What can go wrong between reading a spec and writing its client. Errors about a document name
+/// the file or URL it was read from as `input`.
+#[derive(Debug, Error)]
+pub enum GeneratorError {
+    /// The options, or the config file giving them, are invalid.
+    #[error("{0}")]
+    Options(String),
+    /// A spec, a document it references, or another input could not be read.
+    #[error("cannot read {input}: {source}")]
+    Read {
+        input: String,
+        #[source]
+        source: io::Error,
+    },
+    /// A document is not well-formed JSON or YAML.
+    #[error("{input}: invalid {format}: {message}")]
+    Syntax { input: String, format: &'static str, message: String },
+    /// A document is well-formed but not a valid spec of its kind; `path` is where in the
+    /// document the offending value is, e.g. `paths./pets.get.responses`.
+    #[error("{input}: invalid {kind} at {path}: {message}")]
+    Spec { input: String, kind: &'static str, path: String, message: String },
+    /// A document cannot be used as a whole, e.g. one of its references leads nowhere.
+    #[error("{input}: {message}")]
+    Document { input: String, message: String },
+    /// A template does not parse or fails to render.
+    #[error("template {name}: {message}")]
+    Template { name: String, message: String },
+    /// A plugin cannot be run or replied with something other than files.
+    #[error("plugin '{name}': {message}")]
+    Plugin { name: String, message: String },
+    /// A generated file could not be written.
+    #[error("cannot write {}: {source}", path.display())]
+    Write {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    /// The files watched for changes could not be.
+    #[error("cannot watch for changes: {0}")]
+    Watch(String),
+    /// The mock server cannot listen on its address.
+    #[error("cannot serve on {0}")]
+    Serve(String),
+    /// `diff --fail-on-breaking` found changes that break clients of the old document.
+    #[error("{0} breaking change(s) found")]
+    Breaking(usize),
+}
+
+impl GeneratorError {
+    /// The exit status of the command line for the error, following `sysexits.h`. Breaking changes
+    /// are a failed check rather than an error, so they exit with 1 like a failing test.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            GeneratorError::Breaking(_) => 1,
+            GeneratorError::Options(_) => 64,
+            GeneratorError::Syntax { .. } | GeneratorError::Spec { .. } | GeneratorError::Document { .. } => 65,
+            GeneratorError::Read { .. } => 66,
+            GeneratorError::Plugin { .. } | GeneratorError::Serve(_) => 69,
+            GeneratorError::Write { .. } => 73,
+            GeneratorError::Watch(_) => 74,
+            GeneratorError::Template { .. } => 78,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GeneratorError;
+
+    #[test]
+    fn breaking_changes_exit_apart_from_invalid_documents() {
+        let invalid = GeneratorError::Document { input: "old.json".to_string(), message: "invalid".to_string() };
+        assert_eq!(GeneratorError::Breaking(2).exit_code(), 1);
+        assert_eq!(invalid.exit_code(), 65);
+    }
+}
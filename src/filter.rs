@@ -0,0 +1,150 @@
+use crate::error::{GeneratorError, Result};
+use crate::spec::{Definition, Swagger};
+use glob::Pattern;
+use serde_json::Value;
+
+#[derive(Debug, Default)]
+pub struct Filter {
+    pub include_tags: Vec<String>,
+    pub exclude_paths: Vec<Pattern>,
+    pub include_methods: Vec<String>,
+    pub skip_deprecated: bool,
+}
+
+impl Filter {
+    pub fn new(
+        include_tags: &[String],
+        exclude_paths: &[String],
+        include_methods: &[String],
+        skip_deprecated: bool,
+    ) -> Result<Filter> {
+        let exclude_paths = exclude_paths
+            .iter()
+            .map(|pattern| {
+                Pattern::new(pattern).map_err(|e| {
+                    GeneratorError::Options(format!("invalid --exclude-paths pattern '{}': {}", pattern, e))
+                })
+            })
+            .collect::<Result<Vec<Pattern>>>()?;
+
+        Ok(Filter {
+            include_tags: include_tags.to_vec(),
+            exclude_paths,
+            include_methods: include_methods.iter().map(|method| method.to_lowercase()).collect(),
+            skip_deprecated,
+        })
+    }
+
+    pub fn apply(&self, swagger: &mut Swagger) {
+        swagger
+            .paths
+            .retain(|path, _| !self.exclude_paths.iter().any(|pattern| pattern.matches(path)));
+
+        for item in swagger.paths.values_mut() {
+            for (method, slot) in item.slots_mut() {
+                let keep = slot.as_ref().is_some_and(|operation| {
+                    let method_matches =
+                        self.include_methods.is_empty() || self.include_methods.iter().any(|m| m == method);
+                    let tag_matches = self.include_tags.is_empty()
+                        || operation.tags.iter().any(|tag| self.include_tags.contains(tag));
+                    method_matches && tag_matches && !(self.skip_deprecated && operation.deprecated)
+                });
+                if !keep {
+                    *slot = None;
+                }
+            }
+        }
+
+        swagger.paths.retain(|_, item| !item.is_empty());
+
+        if self.skip_deprecated {
+            swagger.definitions.values_mut().for_each(remove_deprecated_properties);
+        }
+    }
+}
+
+/// Drops the properties marked deprecated, along with their `required` entries. Deprecated definitions
+/// themselves are kept, since the operations left may still reference them.
+fn remove_deprecated_properties(definition: &mut Definition) {
+    if let Some(properties) = &mut definition.properties {
+        let deprecated: Vec<String> = properties
+            .iter()
+            .filter(|(_, property)| property.additional.get("deprecated") == Some(&Value::Bool(true)))
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in &deprecated {
+            properties.shift_remove(name);
+        }
+        if let Some(required) = &mut definition.required {
+            required.retain(|name| !deprecated.contains(name));
+        }
+    }
+    for members in [&mut definition.all_of, &mut definition.one_of, &mut definition.any_of].into_iter().flatten() {
+        members.iter_mut().for_each(remove_deprecated_properties);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+    use crate::error::GeneratorError;
+    use crate::parse_spec;
+    use crate::spec::Swagger;
+
+    const SPEC: &str = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "get": { "operationId": "listPets", "tags": ["pets"], "responses": {} },
+                "post": { "operationId": "createPet", "tags": ["pets"], "deprecated": true, "responses": {} }
+            },
+            "/stores": { "get": { "operationId": "listStores", "tags": ["stores"], "responses": {} } },
+            "/internal/health": { "get": { "operationId": "health", "tags": ["pets"], "responses": {} } }
+        },
+        "definitions": {
+            "Pet": {
+                "type": "object",
+                "required": ["name", "nickname"],
+                "properties": { "name": { "type": "string" }, "nickname": { "type": "string", "deprecated": true } }
+            }
+        }
+    }"##;
+
+    fn operations(filter: Filter) -> Vec<String> {
+        let mut swagger: Swagger = parse_spec(SPEC, None).unwrap();
+        filter.apply(&mut swagger);
+        let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+        operations.filter_map(|(_, operation)| operation.operation_id.clone()).collect()
+    }
+
+    #[test]
+    fn keeps_the_operations_of_the_tags_and_methods_asked_for() {
+        let filter = Filter::new(&["pets".to_string()], &[], &[], false).unwrap();
+        assert_eq!(operations(filter), ["listPets", "createPet", "health"]);
+        let filter = Filter::new(&[], &[], &["GET".to_string()], false).unwrap();
+        assert_eq!(operations(filter), ["listPets", "listStores", "health"]);
+    }
+
+    #[test]
+    fn drops_excluded_paths_and_the_paths_left_empty() {
+        let filter = Filter::new(&[], &["/internal/*".to_string()], &["post".to_string()], false).unwrap();
+        let mut swagger: Swagger = parse_spec(SPEC, None).unwrap();
+        filter.apply(&mut swagger);
+        assert_eq!(swagger.paths.keys().collect::<Vec<_>>(), ["/pets"]);
+
+        let error = Filter::new(&[], &["/pets/[".to_string()], &[], false).unwrap_err();
+        assert!(matches!(error, GeneratorError::Options(ref message) if message.contains("'/pets/['")), "{}", error);
+    }
+
+    #[test]
+    fn skipping_deprecated_drops_operations_and_properties() {
+        let filter = Filter::new(&[], &[], &[], true).unwrap();
+        let mut swagger: Swagger = parse_spec(SPEC, None).unwrap();
+        filter.apply(&mut swagger);
+        assert!(swagger.paths["/pets"].post.is_none());
+        let pet = &swagger.definitions["Pet"];
+        assert_eq!(pet.properties.as_ref().unwrap().keys().collect::<Vec<_>>(), ["name"]);
+        assert_eq!(pet.required.as_deref(), Some(&["name".to_string()][..]));
+    }
+}
@@ -0,0 +1,190 @@
+use super::rust::{field_name, generate_struct};
+use super::server::{
+    generate_manifest, push_banner, push_traits, push_use, server_operations, Content, ServerOperation, Usage,
+    DELIMITED_FUNCTIONS, STATUS_FUNCTION,
+};
+use super::{operation_name, snake_case, with_custom_region};
+use crate::output::GeneratedFiles;
+use crate::spec::Swagger;
+use std::path::PathBuf;
+
+/// Writes a crate with the definitions as serde structs, a trait per tag with a method per
+/// operation for the server to implement, and a `configure` registering every operation with an
+/// actix-web app.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("Cargo.toml"), generate_manifest(swagger, &dependencies(swagger)));
+    files.insert(PathBuf::from("src").join("lib.rs"), with_custom_region(generate_lib(swagger), "//"));
+    files
+}
+
+/// actix-web, with actix-multipart when an operation takes multipart bodies, and serde_html_form,
+/// which reads the values of an array from the repeated query parameter or form field.
+fn dependencies(swagger: &Swagger) -> String {
+    let usage = Usage::of(&server_operations(swagger));
+    let mut dependencies = "actix-web = \"4\"\n".to_string();
+    if usage.multipart {
+        dependencies.push_str("actix-multipart = \"0.7\"\n");
+    }
+    if usage.queries || usage.forms {
+        dependencies.push_str("serde_html_form = \"0.2\"\n");
+    }
+    dependencies
+}
+
+fn generate_lib(swagger: &Swagger) -> String {
+    let groups = server_operations(swagger);
+    let usage = Usage::of(&groups);
+    let models = !swagger.definitions.is_empty();
+
+    let mut rs_code = String::new();
+    push_banner(&mut rs_code, swagger);
+    push_use(&mut rs_code, "actix_web::body", &[("BoxBody", true)]);
+    push_use(&mut rs_code, "actix_web::error", &[("ErrorBadRequest", usage.queries || usage.forms)]);
+    let methods = usage.methods.iter().any(|method| matches!(*method, "options" | "trace"));
+    push_use(&mut rs_code, "actix_web::http", &[("Method", methods), ("StatusCode", true)]);
+    push_use(
+        &mut rs_code,
+        "actix_web",
+        &[("web", true), ("HttpRequest", true), ("HttpResponse", true), ("Responder", true)],
+    );
+    let deserialize = models || usage.paths || usage.queries || usage.forms;
+    push_use(&mut rs_code, "serde", &[("Deserialize", deserialize), ("Serialize", models)]);
+    push_use(&mut rs_code, "std::future", &[("Future", usage.handlers)]);
+
+    for (name, definition) in &swagger.definitions {
+        rs_code.push('\n');
+        rs_code.push_str(&generate_struct(swagger, name, definition));
+    }
+    for operation in groups.iter().flat_map(|(_, operations)| operations) {
+        operation.push_types(&mut rs_code);
+        push_response(&mut rs_code, operation);
+    }
+    rs_code.push('\n');
+    rs_code.push_str(STATUS_FUNCTION);
+    if usage.delimited {
+        rs_code.push_str(DELIMITED_FUNCTIONS);
+    }
+
+    // actix-web runs each worker on a thread of its own, so the futures need not be `Send`.
+    let traits = push_traits(&mut rs_code, &groups, "configure", false, "actix_multipart::Multipart", push_handler);
+    push_configure(&mut rs_code, swagger, &traits);
+    rs_code
+}
+
+/// The `Responder` of the enum of the responses of `operation`.
+fn push_response(rs_code: &mut String, operation: &ServerOperation<'_>) {
+    rs_code.push_str(&format!("\nimpl Responder for {} {{\n", operation.type_name("Response")));
+    rs_code.push_str("    type Body = BoxBody;\n\n");
+    rs_code.push_str("    fn respond_to(self, _: &HttpRequest) -> HttpResponse {\n");
+    rs_code.push_str("        match self {\n");
+    for variant in &operation.responses {
+        let status = variant.status.map_or("status".to_string(), |status| format!("status({})", status));
+        let mut bindings: Vec<&str> = Vec::new();
+        if variant.status.is_none() {
+            bindings.push("status");
+        }
+        let builder = format!("HttpResponse::build({})", status);
+        let response = match variant.body.as_ref().map(|(_, content)| *content) {
+            Some(Content::Json) => format!("{}.json(body)", builder),
+            Some(Content::Text) => format!("{}.content_type(\"text/plain; charset=utf-8\").body(body)", builder),
+            Some(Content::Binary) => format!("{}.content_type(\"application/octet-stream\").body(body)", builder),
+            None => format!("HttpResponse::new({})", status),
+        };
+        if variant.body.is_some() {
+            bindings.push("body");
+        }
+        let pattern = if bindings.is_empty() {
+            format!("Self::{}", variant.name)
+        } else {
+            format!("Self::{}({})", variant.name, bindings.join(", "))
+        };
+        rs_code.push_str(&format!("            {} => {},\n", pattern, response));
+    }
+    rs_code.push_str("        }\n    }\n}\n");
+}
+
+/// The actix-web handler of `operation`, which extracts the arguments of its method from the
+/// request and awaits the method. Queries and URL-encoded forms that do not parse are answered with
+/// `400 Bad Request`.
+fn push_handler(rs_code: &mut String, operation: &ServerOperation<'_>, trait_name: &str) {
+    let mut extractors = vec!["api: web::Data<A>".to_string()];
+    let mut parsed = Vec::new();
+    let mut arguments = Vec::new();
+    for (name, rs_type) in operation.arguments("actix_multipart::Multipart") {
+        let (extractor, argument) = match name {
+            "path" => (format!("path: web::Path<{}>", rs_type), "path.into_inner()"),
+            "query" => {
+                parsed.push(
+                    "    let query = serde_html_form::from_str(request.query_string()).map_err(ErrorBadRequest)?;\n",
+                );
+                ("request: HttpRequest".to_string(), "query")
+            }
+            "form" => {
+                parsed.push("    let form = serde_html_form::from_bytes(&form).map_err(ErrorBadRequest)?;\n");
+                ("form: web::Bytes".to_string(), "form")
+            }
+            "multipart" => (format!("multipart: {}", rs_type), "multipart"),
+            // An optional body is `None` when the request has none.
+            _ if !operation.body_required => {
+                let body = operation.body.as_deref().unwrap_or_default();
+                (format!("body: Option<web::Json<{}>>", body), "body.map(web::Json::into_inner)")
+            }
+            _ => (format!("body: web::Json<{}>", rs_type), "body.into_inner()"),
+        };
+        extractors.push(extractor);
+        arguments.push(argument);
+    }
+    let response = operation.type_name("Response");
+    let call = format!("api.{}({}).await", field_name(&operation.name), arguments.join(", "));
+    if parsed.is_empty() {
+        rs_code.push_str(&format!(
+            "\nasync fn handle_{}<A: {}>({}) -> {} {{\n    {}\n}}\n",
+            operation.name,
+            trait_name,
+            extractors.join(", "),
+            response,
+            call
+        ));
+    } else {
+        rs_code.push_str(&format!(
+            "\nasync fn handle_{}<A: {}>({}) -> actix_web::Result<{}> {{\n{}    Ok({})\n}}\n",
+            operation.name,
+            trait_name,
+            extractors.join(", "),
+            response,
+            parsed.concat(),
+            call
+        ));
+    }
+}
+
+/// `configure`, which registers the paths of the spec under its `basePath`.
+fn push_configure(rs_code: &mut String, swagger: &Swagger, traits: &[String]) {
+    let bound = if traits.is_empty() { "Send + Sync + 'static".to_string() } else { traits.join(" + ") };
+    rs_code.push_str(
+        "\n/// Registers every operation of the spec, answered by the `A` the app holds as `web::Data<A>`:\n",
+    );
+    rs_code.push_str("/// `App::new().app_data(web::Data::new(api)).configure(configure::<A>)`.\n");
+    rs_code.push_str(&format!("pub fn configure<A>(config: &mut web::ServiceConfig)\nwhere\n    A: {},\n{{\n", bound));
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    let indent = if base_path.is_empty() { "" } else { "    " };
+    if base_path.is_empty() {
+        rs_code.push_str("    config");
+    } else {
+        rs_code.push_str(&format!("    config.service(\n        web::scope(\"{}\")", base_path));
+    }
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let route = match method {
+                "options" => "web::route().method(Method::OPTIONS)".to_string(),
+                "trace" => "web::route().method(Method::TRACE)".to_string(),
+                method => format!("web::{}()", method),
+            };
+            let handler = format!("handle_{}::<A>", snake_case(&operation_name(method, path, operation)));
+            rs_code.push_str(&format!("\n{}        .route(\"{}\", {}.to({}))", indent, path, route, handler));
+        }
+    }
+    rs_code.push_str(if base_path.is_empty() { ";\n" } else { ",\n    );\n" });
+    rs_code.push_str("}\n");
+}
@@ -0,0 +1,410 @@
+use super::typescript::{
+    argument_order, body_argument, body_encoder, body_encoders, error_members, error_type_name, form_name,
+    form_parameters, generate_info_comment, generate_interfaces, has_header_parameters, header_overrides,
+    header_parameters, headers_name, import_interfaces, media_headers, non_json_response, operation_doc,
+    parameters_argument, parameters_required, path_arguments, path_template, push_body_encoders, push_doc,
+    push_error_type, push_parameters_interface, push_request_headers_helper, push_retry_helpers,
+    push_server_url_helper, query_parameters, query_params_name, query_values, request_body_reference,
+    request_data_expression,
+    response_references, response_schema_name, service_method_name, tag_service_name, MethodArgument, PathArgument,
+    TypeOptions,
+};
+use super::{
+    base_url, codecs, error_responses, has_request_body, has_server_choices, operations_by_tag, security_requirements,
+    security_schemes, with_custom_region, words,
+};
+use crate::cli::Language;
+use crate::output::GeneratedFiles;
+use crate::spec::{Operation, Swagger};
+use rayon::prelude::*;
+use std::path::PathBuf;
+
+pub fn generate(swagger: &Swagger, types: TypeOptions, retries: Option<u32>) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+
+    generate_interfaces(swagger, types, &mut files);
+    files.insert(PathBuf::from("variables.ts"), generate_variables(swagger, retries));
+    if !swagger.security_definitions.is_empty() {
+        files.insert(PathBuf::from("auth.ts"), generate_auth(swagger));
+    }
+    let tags = operations_by_tag(swagger);
+    files.par_extend(tags.par_iter().map(|(tag, operations)| {
+        let path = PathBuf::from("services").join(format!("{}.service.ts", file_name(tag)));
+        (path, with_custom_region(generate_tag_service(swagger, tag, operations, types, retries.is_some()), "//"))
+    }));
+    files.insert(PathBuf::from("api.module.ts"), generate_api_module(swagger, tags.keys()));
+
+    files
+}
+
+fn generate_variables(swagger: &Swagger, retries: Option<u32>) -> String {
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { InjectionToken } from '@angular/core';\n");
+    if retries.is_some() {
+        ts_code.push_str("import { HttpErrorResponse } from '@angular/common/http';\n");
+        ts_code.push_str("import { MonoTypeOperatorFunction, retry, throwError, timer } from 'rxjs';\n");
+    }
+    ts_code.push_str(&format!(
+        "
+export const DEFAULT_BASE_PATH = '{}';
+
+export const BASE_PATH = new InjectionToken<string>('BASE_PATH', {{
+    providedIn: 'root',
+    factory: () => DEFAULT_BASE_PATH,
+}});
+
+export type QueryParams = Record<string, string | number | boolean | readonly (string | number | boolean)[]>;
+
+export interface RequestOptions {{
+    headers?: Record<string, string>;
+    params?: QueryParams;
+}}
+
+/** Drops the parameters left `undefined` or `null`, which `HttpParams` would send as text. */
+export function queryParams(params: object = {{}}): QueryParams {{
+    return Object.fromEntries(Object.entries(params).filter(([, value]) => value !== undefined && value !== null));
+}}
+",
+        base_url(swagger)
+    ));
+    // Selected by providing `BASE_PATH`, e.g. `{ provide: BASE_PATH, useValue: serverUrl(url, variables) }`.
+    if has_server_choices(swagger) {
+        ts_code.push('\n');
+        push_server_url_helper(&mut ts_code, swagger, Language::TypeScript);
+        ts_code.pop();
+    }
+    if let Some(retries) = retries {
+        ts_code.push('\n');
+        push_retry_helpers(&mut ts_code, Language::TypeScript, retries, false);
+        ts_code.push_str(
+            "/** Retries requests that failed with a network error, 429 or 5xx. */
+export function retryRequests<T>(): MonoTypeOperatorFunction<T> {
+    return retry({
+        count: MAX_RETRIES,
+        delay: (error: unknown, retryCount: number) => {
+            const retryable =
+                error instanceof HttpErrorResponse && (error.status === 0 || error.status === 429 || error.status >= 500);
+            if (!retryable) {
+                return throwError(() => error);
+            }
+            return timer(retryDelay(retryCount - 1, error.headers.get('Retry-After')));
+        },
+    });
+}\n",
+        );
+    }
+    if has_header_parameters(swagger) {
+        ts_code.push('\n');
+        push_request_headers_helper(&mut ts_code, Language::TypeScript, true);
+        ts_code.pop();
+    }
+    if !body_encoders(swagger).is_empty() {
+        ts_code.push('\n');
+        push_body_encoders(&mut ts_code, swagger, Language::TypeScript, true);
+        ts_code.pop();
+    }
+    ts_code
+}
+
+fn generate_auth(swagger: &Swagger) -> String {
+    let schemes = security_schemes(swagger);
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { Injectable } from '@angular/core';\n\n");
+    ts_code.push_str("import { RequestOptions } from './variables';\n\n");
+
+    ts_code.push_str("export interface AuthConfig {\n");
+    for (name, scheme) in &schemes {
+        let auth_type = if scheme.scheme_type == "basic" {
+            "{ username: string; password: string }"
+        } else {
+            "string"
+        };
+        ts_code.push_str(&format!("    '{}'?: {};\n", name, auth_type));
+    }
+    ts_code.push_str("}\n\n");
+
+    ts_code.push_str(
+        "@Injectable({ providedIn: 'root' })
+export class ApiAuth {
+    private auth: AuthConfig = {};
+
+    setAuth(config: AuthConfig) {
+        this.auth = config;
+    }
+
+    withAuth(requirements: (keyof AuthConfig)[][], options: RequestOptions = {}): RequestOptions {
+        const auth = this.auth;
+        const requirement = requirements.find(names => names.every(name => auth[name] !== undefined));
+        if (!requirement) {
+            return options;
+        }
+        const headers = { ...options.headers };
+        const params = { ...options.params };
+        for (const name of requirement) {
+            switch (name) {\n",
+    );
+    for (name, scheme) in &schemes {
+        let key = scheme.name.as_deref().unwrap_or(name);
+        let apply = match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+            ("basic", _) => format!(
+                "headers['Authorization'] = `Basic ${{btoa(`${{auth['{0}']!.username}}:${{auth['{0}']!.password}}`)}}`;",
+                name
+            ),
+            ("apiKey", Some("query")) => format!("params['{}'] = auth['{}']!;", key, name),
+            ("apiKey", Some("cookie")) => format!(
+                "headers['Cookie'] = [headers['Cookie'], `{}=${{auth['{}']}}`].filter(Boolean).join('; ');",
+                key, name
+            ),
+            ("apiKey", _) => format!("headers['{}'] = auth['{}']!;", key, name),
+            _ => format!("headers['Authorization'] = `Bearer ${{auth['{}']}}`;", name),
+        };
+        ts_code.push_str(&format!(
+            "                case '{}':\n                    {}\n                    break;\n",
+            name, apply
+        ));
+    }
+    ts_code.push_str(
+        "            }
+        }
+        return { ...options, headers, params };
+    }
+}
+",
+    );
+    ts_code
+}
+
+fn generate_tag_service(
+    swagger: &Swagger,
+    tag: &str,
+    operations: &[(&str, &str, &Operation)],
+    types: TypeOptions,
+    retry: bool,
+) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { Inject, Injectable } from '@angular/core';\n");
+    let has_errors = operations.iter().any(|(_, _, operation)| !error_responses(operation).is_empty());
+    ts_code.push_str(&format!(
+        "import {{ HttpClient{} }} from '@angular/common/http';\n",
+        if has_errors { ", HttpErrorResponse" } else { "" }
+    ));
+    let decodes =
+        operations.iter().any(|(_, _, operation)| codecs::response_decoder(swagger, operation, types).is_some());
+    ts_code.push_str(&format!("import {{ {}Observable }} from 'rxjs';\n\n", if decodes { "map, " } else { "" }));
+    if has_auth {
+        ts_code.push_str("import { ApiAuth } from '../auth';\n");
+    }
+    let mut variables = vec!["BASE_PATH"];
+    let mut encoders: Vec<&str> = operations
+        .iter()
+        .filter(|(_, method, _)| has_request_body(method))
+        .filter_map(|(_, _, operation)| body_encoder(swagger, operation))
+        .collect();
+    encoders.sort();
+    encoders.dedup();
+    variables.extend(encoders);
+    if operations.iter().any(|(_, _, operation)| !query_parameters(operation).is_empty()) {
+        variables.push("queryParams");
+    }
+    if operations.iter().any(|(_, _, operation)| !header_parameters(operation).is_empty()) {
+        variables.push("requestHeaders");
+    }
+    variables.push("RequestOptions");
+    if retry {
+        variables.push("retryRequests");
+    }
+    ts_code.push_str(&format!("import {{ {} }} from '../variables';\n", variables.join(", ")));
+
+    let interfaces: Vec<String> = operations
+        .iter()
+        .flat_map(|(_, _, operation)| {
+            let body = request_body_reference(operation).map(str::to_string);
+            body.into_iter().chain(response_references(operation))
+        })
+        .collect();
+    ts_code.push('\n');
+    let tagged = operations.iter().map(|(_, method, operation)| (*method, *operation));
+    codecs::import_codecs(&mut ts_code, swagger, tagged, types, "../codecs");
+    import_interfaces(&mut ts_code, interfaces, "../interfaces");
+
+    for (path, method, operation) in operations {
+        let method_name = service_method_name(method, path, operation);
+        let form = form_parameters(operation);
+        if has_request_body(method) && !form.is_empty() {
+            push_parameters_interface(&mut ts_code, &form_name(&method_name), &form);
+        }
+        let query = query_parameters(operation);
+        if !query.is_empty() {
+            push_parameters_interface(&mut ts_code, &query_params_name(&method_name), &query);
+        }
+        let headers = header_parameters(operation);
+        if !headers.is_empty() {
+            push_parameters_interface(&mut ts_code, &headers_name(&method_name), &headers);
+        }
+        let errors = error_members(operation, |status, body| format!("{{ status: {}; error: {} }}", status, body));
+        if !errors.is_empty() {
+            let members = format!("HttpErrorResponse & ({})", errors.join(" | "));
+            push_error_type(&mut ts_code, &error_type_name(&method_name), &[members]);
+        }
+    }
+
+    ts_code.push_str(&format!("@Injectable()\nexport class {} {{\n", tag_service_name(tag)));
+    ts_code.push_str("    constructor(\n");
+    ts_code.push_str("        private readonly http: HttpClient,\n");
+    ts_code.push_str("        @Inject(BASE_PATH) private readonly basePath: string,\n");
+    if has_auth {
+        ts_code.push_str("        private readonly auth: ApiAuth,\n");
+    }
+    ts_code.push_str("    ) {}\n");
+
+    for (path, method, operation) in operations {
+        ts_code.push('\n');
+        ts_code.push_str(&generate_service_method(swagger, method, path, operation, types, retry));
+    }
+    ts_code.push_str("}\n");
+    ts_code
+}
+
+fn generate_service_method(
+    swagger: &Swagger,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+    types: TypeOptions,
+    retry: bool,
+) -> String {
+    let path_params = path_arguments(path, operation);
+    let has_body = has_request_body(method);
+    let response_schema = response_schema_name(swagger, operation);
+    let method_name = service_method_name(method, path, operation);
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+
+    let (data_declaration, data_required) = body_argument(operation, &method_name, "unknown");
+    let mut arguments: Vec<String> = path_params.iter().map(PathArgument::declaration).collect();
+    let order = argument_order(
+        has_body.then_some(data_required),
+        parameters_required(&query),
+        parameters_required(&headers),
+    );
+    for argument in order {
+        arguments.extend(match argument {
+            MethodArgument::Data => Some(data_declaration.clone()),
+            MethodArgument::Params => parameters_argument("params", &query_params_name(&method_name), &query),
+            MethodArgument::Headers => parameters_argument("headers", &headers_name(&method_name), &headers),
+        });
+    }
+    arguments.push("options?: RequestOptions".to_string());
+
+    let formatted_path = path_template(path, &path_params);
+
+    let requirements: Vec<String> = security_requirements(swagger, operation)
+        .iter()
+        .map(|names| {
+            let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+            format!("[{}]", names.join(", "))
+        })
+        .collect();
+    let mut overrides = Vec::new();
+    if !query.is_empty() {
+        overrides.push(format!("params: {{ ...options?.params, ...queryParams({}) }}", query_values("params", &query)));
+    }
+    let media = media_headers(swagger, method, operation);
+    if !headers.is_empty() || !media.is_empty() {
+        overrides.push(header_overrides("options", &headers, &media));
+    }
+    let options = if overrides.is_empty() {
+        "options".to_string()
+    } else {
+        format!("options, {}", overrides.join(", "))
+    };
+    let options = if swagger.security_definitions.is_empty() || requirements.is_empty() {
+        options
+    } else if overrides.is_empty() {
+        format!("this.auth.withAuth([{}], options)", requirements.join(", "))
+    } else {
+        format!("this.auth.withAuth([{}], {{ ...{} }})", requirements.join(", "), options)
+    };
+
+    let mut fields = String::new();
+    if has_body {
+        fields.push_str(&format!(", body: {}", request_data_expression(swagger, operation, "data", types)));
+    }
+    // HttpClient only types the body of JSON requests, so file downloads and text responses use
+    // the `Blob` and `string` overloads.
+    let type_argument = match non_json_response(swagger, operation) {
+        Some(response_type) => {
+            fields.push_str(&format!(", responseType: '{}'", response_type));
+            String::new()
+        }
+        None => format!("<{}>", response_schema),
+    };
+
+    let mut operators: Vec<String> = Vec::new();
+    if retry {
+        operators.push("retryRequests()".to_string());
+    }
+    operators.extend(codecs::response_decoder(swagger, operation, types).map(|decoder| format!("map({})", decoder)));
+    let pipe = if operators.is_empty() { String::new() } else { format!(".pipe({})", operators.join(", ")) };
+
+    let mut ts_code = String::new();
+    push_doc(&mut ts_code, &operation_doc(operation), "    ");
+    ts_code.push_str(&format!(
+        "    {}({}): Observable<{}> {{
+        return this.http.request{}('{}', `${{this.basePath}}{}`, {{ ...{}{} }}){};
+    }}\n",
+        method_name,
+        arguments.join(", "),
+        response_schema,
+        type_argument,
+        method.to_uppercase(),
+        formatted_path,
+        options,
+        fields,
+        pipe
+    ));
+    ts_code
+}
+
+fn generate_api_module<'a>(swagger: &Swagger, tags: impl Iterator<Item = &'a String>) -> String {
+    let tags: Vec<&String> = tags.collect();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { ModuleWithProviders, NgModule } from '@angular/core';\n\n");
+    for tag in &tags {
+        ts_code.push_str(&format!("import {{ {} }} from './services/{}.service';\n", tag_service_name(tag), file_name(tag)));
+    }
+    ts_code.push_str("import { BASE_PATH, DEFAULT_BASE_PATH } from './variables';\n\n");
+
+    let services: Vec<String> = tags.iter().map(|tag| tag_service_name(tag)).collect();
+    ts_code.push_str(&format!(
+        "@NgModule({{
+    providers: [{}],
+}})
+export class ApiModule {{
+    static forRoot(basePath: string = DEFAULT_BASE_PATH): ModuleWithProviders<ApiModule> {{
+        return {{
+            ngModule: ApiModule,
+            providers: [{{ provide: BASE_PATH, useValue: basePath }}],
+        }};
+    }}
+}}
+",
+        services.join(", ")
+    ));
+    ts_code
+}
+
+fn file_name(tag: &str) -> String {
+    match words(tag).join("-") {
+        name if name.is_empty() => "api".to_string(),
+        name => name,
+    }
+}
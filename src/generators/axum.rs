@@ -0,0 +1,176 @@
+use super::rust::{field_name, generate_struct};
+use super::server::{
+    generate_manifest, push_banner, push_traits, push_use, server_operations, Content, ServerOperation, Usage,
+    DELIMITED_FUNCTIONS, STATUS_FUNCTION,
+};
+use super::{operation_name, snake_case, with_custom_region};
+use crate::output::GeneratedFiles;
+use crate::spec::Swagger;
+use std::path::PathBuf;
+
+/// Writes a crate with the definitions as serde structs, a trait per tag with a method per
+/// operation for the server to implement, and a `router` routing every operation to them.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("Cargo.toml"), generate_manifest(swagger, &dependencies(swagger)));
+    files.insert(PathBuf::from("src").join("lib.rs"), with_custom_region(generate_lib(swagger), "//"));
+    files
+}
+
+/// axum, with multipart bodies when an operation takes them, and axum-extra, whose `Query` and `Form`
+/// read the values of an array from the repeated parameter.
+fn dependencies(swagger: &Swagger) -> String {
+    let usage = Usage::of(&server_operations(swagger));
+    let mut dependencies = match usage.multipart {
+        true => "axum = { version = \"0.8\", features = [\"multipart\"] }\n".to_string(),
+        false => "axum = \"0.8\"\n".to_string(),
+    };
+    let features: Vec<&str> = [("form", usage.forms), ("query", usage.queries)]
+        .iter()
+        .filter(|(_, used)| *used)
+        .map(|(feature, _)| *feature)
+        .collect();
+    if !features.is_empty() {
+        dependencies.push_str(&format!(
+            "axum-extra = {{ version = \"0.10\", default-features = false, features = [\"{}\"] }}\n",
+            features.join("\", \"")
+        ));
+    }
+    dependencies
+}
+
+fn generate_lib(swagger: &Swagger) -> String {
+    let groups = server_operations(swagger);
+    let usage = Usage::of(&groups);
+    let models = !swagger.definitions.is_empty();
+
+    let mut rs_code = String::new();
+    push_banner(&mut rs_code, swagger);
+    // The extractors and the response are named through their modules, which definitions called
+    // `State` or `Response` would otherwise clash with.
+    push_use(&mut rs_code, "axum", &[("extract", usage.handlers)]);
+    push_use(
+        &mut rs_code,
+        "axum::http",
+        &[("header", usage.contents.contains(&Content::Binary)), ("StatusCode", true)],
+    );
+    push_use(&mut rs_code, "axum::response", &[("self", true), ("IntoResponse", true)]);
+    let methods: Vec<(&str, bool)> = usage.methods.iter().map(|method| (*method, true)).collect();
+    push_use(&mut rs_code, "axum::routing", &methods);
+    let json = usage.bodies || usage.contents.contains(&Content::Json);
+    push_use(&mut rs_code, "axum", &[("Json", json), ("Router", true)]);
+    let deserialize = models || usage.paths || usage.queries || usage.forms;
+    push_use(&mut rs_code, "serde", &[("Deserialize", deserialize), ("Serialize", models)]);
+    push_use(&mut rs_code, "std::future", &[("Future", usage.handlers)]);
+    push_use(&mut rs_code, "std::sync", &[("Arc", true)]);
+
+    for (name, definition) in &swagger.definitions {
+        rs_code.push('\n');
+        rs_code.push_str(&generate_struct(swagger, name, definition));
+    }
+    for operation in groups.iter().flat_map(|(_, operations)| operations) {
+        operation.push_types(&mut rs_code);
+        push_response(&mut rs_code, operation);
+    }
+    rs_code.push('\n');
+    rs_code.push_str(STATUS_FUNCTION);
+    if usage.delimited {
+        rs_code.push_str(DELIMITED_FUNCTIONS);
+    }
+
+    let traits = push_traits(&mut rs_code, &groups, "router", true, "extract::Multipart", push_handler);
+    push_router(&mut rs_code, swagger, &traits);
+    rs_code
+}
+
+/// The `IntoResponse` of the enum of the responses of `operation`.
+fn push_response(rs_code: &mut String, operation: &ServerOperation<'_>) {
+    rs_code.push_str(&format!("\nimpl IntoResponse for {} {{\n", operation.type_name("Response")));
+    rs_code.push_str("    fn into_response(self) -> response::Response {\n");
+    rs_code.push_str("        match self {\n");
+    for variant in &operation.responses {
+        let status = variant.status.map_or("status".to_string(), |status| format!("status({})", status));
+        let mut bindings: Vec<&str> = Vec::new();
+        if variant.status.is_none() {
+            bindings.push("status");
+        }
+        let response = match variant.body.as_ref().map(|(_, content)| *content) {
+            Some(Content::Json) => format!("({}, Json(body))", status),
+            Some(Content::Text) => format!("({}, body)", status),
+            Some(Content::Binary) => {
+                format!("({}, [(header::CONTENT_TYPE, \"application/octet-stream\")], body)", status)
+            }
+            None => status,
+        };
+        if variant.body.is_some() {
+            bindings.push("body");
+        }
+        let pattern = if bindings.is_empty() {
+            format!("Self::{}", variant.name)
+        } else {
+            format!("Self::{}({})", variant.name, bindings.join(", "))
+        };
+        rs_code.push_str(&format!("            {} => {}.into_response(),\n", pattern, response));
+    }
+    rs_code.push_str("        }\n    }\n}\n");
+}
+
+/// The axum handler of `operation`, which extracts the arguments of its method from the request
+/// and awaits the method.
+fn push_handler(rs_code: &mut String, operation: &ServerOperation<'_>, trait_name: &str) {
+    let mut extractors = vec!["extract::State(api): extract::State<Arc<A>>".to_string()];
+    let mut arguments = Vec::new();
+    for (name, rs_type) in operation.arguments("extract::Multipart") {
+        let (extractor, argument) = match name {
+            "path" => (format!("extract::Path(path): extract::Path<{}>", rs_type), "path"),
+            "query" => (format!("axum_extra::extract::Query(query): axum_extra::extract::Query<{}>", rs_type), "query"),
+            "form" => (format!("axum_extra::extract::Form(form): axum_extra::extract::Form<{}>", rs_type), "form"),
+            "multipart" => (format!("multipart: {}", rs_type), "multipart"),
+            // An optional body is `None` when the request has no `Content-Type`.
+            _ if !operation.body_required => {
+                let body = operation.body.as_deref().unwrap_or_default();
+                (format!("body: Option<Json<{}>>", body), "body.map(|Json(body)| body)")
+            }
+            _ => (format!("Json(body): Json<{}>", rs_type), "body"),
+        };
+        extractors.push(extractor);
+        arguments.push(argument);
+    }
+    rs_code.push_str(&format!(
+        "\nasync fn handle_{}<A: {}>({}) -> {} {{\n",
+        operation.name,
+        trait_name,
+        extractors.join(", "),
+        operation.type_name("Response")
+    ));
+    rs_code.push_str(&format!("    api.{}({}).await\n}}\n", field_name(&operation.name), arguments.join(", ")));
+}
+
+/// `router`, which answers at the paths of the spec under its `basePath`.
+fn push_router(rs_code: &mut String, swagger: &Swagger, traits: &[String]) {
+    let bound = if traits.is_empty() { "Send + Sync + 'static".to_string() } else { traits.join(" + ") };
+    rs_code.push_str("\n/// Routes every operation of the spec to `api`.\n");
+    rs_code.push_str(&format!("pub fn router<A>(api: A) -> Router\nwhere\n    A: {},\n{{\n", bound));
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    rs_code.push_str(if base_path.is_empty() { "    Router::new()\n" } else { "    let routes = Router::new()\n" });
+    // axum takes all the methods of a path at once.
+    for (path, path_item) in &swagger.paths {
+        let handlers: Vec<String> = path_item
+            .operations()
+            .into_iter()
+            .map(|(method, operation)| {
+                format!("{}(handle_{}::<A>)", method, snake_case(&operation_name(method, path, operation)))
+            })
+            .collect();
+        if !handlers.is_empty() {
+            rs_code.push_str(&format!("        .route(\"{}\", {})\n", path, handlers.join(".")));
+        }
+    }
+    if base_path.is_empty() {
+        rs_code.push_str("        .with_state(Arc::new(api))\n");
+    } else {
+        rs_code.push_str("        .with_state(Arc::new(api));\n");
+        rs_code.push_str(&format!("    Router::new().nest(\"{}\", routes)\n", base_path));
+    }
+    rs_code.push_str("}\n");
+}
@@ -0,0 +1,353 @@
+use super::success_responses;
+use super::typescript::{
+    all_properties, body_encoder, generate_info_comment, import_interfaces, map_values, nested_definition,
+    non_json_response, property_enum, property_name, TypeOptions,
+};
+use crate::cli::{DateType, Int64Type, Language};
+use crate::spec::{ref_name, Definition, Operation, Property, Schema, Swagger};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// The helpers every `codecs` module starts with: codecs applied to the items of arrays, the
+/// values of maps and the fields of objects, each passing `null` and `undefined` through.
+const TS_HELPERS: &str = "type Codec = (value: any) => any;
+
+export function mapItems(codec: Codec): Codec {
+    return (values) => (Array.isArray(values) ? values.map(codec) : values);
+}
+
+export function mapValues(codec: Codec): Codec {
+    return (values) =>
+        typeof values === 'object' && values !== null
+            ? Object.fromEntries(Object.entries(values).map(([key, value]) => [key, codec(value)]))
+            : values;
+}
+
+export function mapFields(codecs: Record<string, Codec>): Codec {
+    return (value) => {
+        if (typeof value !== 'object' || value === null) {
+            return value;
+        }
+        const result = { ...value };
+        for (const [name, codec] of Object.entries(codecs)) {
+            if (result[name] !== undefined && result[name] !== null) {
+                result[name] = codec(result[name]);
+            }
+        }
+        return result;
+    };
+}
+";
+
+const JS_HELPERS: &str = "/** @typedef {(value: any) => any} Codec */
+
+/**
+ * @param {Codec} codec
+ * @returns {Codec}
+ */
+export function mapItems(codec) {
+    return (values) => (Array.isArray(values) ? values.map(codec) : values);
+}
+
+/**
+ * @param {Codec} codec
+ * @returns {Codec}
+ */
+export function mapValues(codec) {
+    return (values) =>
+        typeof values === 'object' && values !== null
+            ? Object.fromEntries(Object.entries(values).map(([key, value]) => [key, codec(value)]))
+            : values;
+}
+
+/**
+ * @param {Record<string, Codec>} codecs
+ * @returns {Codec}
+ */
+export function mapFields(codecs) {
+    return (value) => {
+        if (typeof value !== 'object' || value === null) {
+            return value;
+        }
+        const result = { ...value };
+        for (const [name, codec] of Object.entries(codecs)) {
+            if (result[name] !== undefined && result[name] !== null) {
+                result[name] = codec(result[name]);
+            }
+        }
+        return result;
+    };
+}
+";
+
+/// Whether the types declare values JSON carries as something else, `Date`s for date strings and
+/// `bigint`s or strings for int64 numbers, which responses and request bodies are converted for.
+pub fn converts(types: TypeOptions) -> bool {
+    types.date_type == DateType::Date || types.int64_type != Int64Type::Number
+}
+
+/// `codecs.ts`: a `decode<Name>` function per definition holding converted fields, turning the
+/// JSON of a response into the declared type, and an `encode<Name>` one turning a value of the
+/// type back into JSON for a request body. `None` when no definition holds such fields.
+pub fn generate_codecs(swagger: &Swagger, types: TypeOptions, lang: Language) -> Option<String> {
+    let codecs = Codecs::new(swagger, types);
+    if codecs.coded.is_empty() {
+        return None;
+    }
+    let typed = lang == Language::TypeScript;
+    let mut names: Vec<&str> = codecs.coded.iter().copied().collect();
+    names.sort();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    if typed {
+        import_interfaces(&mut ts_code, names.iter().map(|name| name.to_string()).collect(), "./interfaces");
+        ts_code.push_str(TS_HELPERS);
+    } else {
+        for name in &names {
+            ts_code.push_str(&format!("/** @typedef {{import('./types.js').{}}} {} */\n", name, name));
+        }
+        ts_code.push('\n');
+        ts_code.push_str(JS_HELPERS);
+    }
+    ts_code.push_str(&scalar_helpers(types, typed));
+
+    for name in names {
+        let definition = &swagger.definitions[name];
+        for encode in [false, true] {
+            let Some(codec) = codecs.top_level(definition, encode) else { continue };
+            let function = format!("{}{}", if encode { "encode" } else { "decode" }, name);
+            let (input, output) = if encode { (name, "any") } else { ("any", name) };
+            if typed {
+                ts_code.push_str(&format!("\nexport function {}(value: {}): {} {{\n", function, input, output));
+            } else {
+                ts_code.push_str(&format!("\n/**\n * @param {{{}}} value\n * @returns {{{}}}\n */\n", input, output));
+                ts_code.push_str(&format!("export function {}(value) {{\n", function));
+            }
+            ts_code.push_str(&format!("    return {}(value);\n}}\n", codec));
+        }
+    }
+    Some(ts_code)
+}
+
+/// The conversions of single values, for the types asked for.
+fn scalar_helpers(types: TypeOptions, typed: bool) -> String {
+    let mut helpers = Vec::new();
+    if types.date_type == DateType::Date {
+        helpers.push(("decodeDate", "value", "new Date(value)"));
+        helpers.push(("encodeDate", "value", "value instanceof Date ? value.toISOString() : value"));
+        helpers.push(("encodeDay", "value", "value instanceof Date ? value.toISOString().slice(0, 10) : value"));
+    }
+    match types.int64_type {
+        Int64Type::Number => {}
+        Int64Type::Bigint => helpers.push(("decodeInt64", "value", "BigInt(value)")),
+        Int64Type::String => helpers.push(("decodeInt64", "value", "String(value)")),
+    }
+
+    let mut ts_code = String::new();
+    for (name, parameter, body) in helpers {
+        if typed {
+            ts_code.push_str(&format!("\nexport function {}({}: any): any {{\n", name, parameter));
+        } else {
+            ts_code.push_str(&format!("\n/**\n * @param {{any}} {}\n * @returns {{any}}\n */\n", parameter));
+            ts_code.push_str(&format!("export function {}({}) {{\n", name, parameter));
+        }
+        ts_code.push_str(&format!("    return {};\n}}\n", body));
+    }
+    if types.int64_type != Int64Type::Number {
+        // JSON.stringify cannot write a bigint, and a string would change the type on the wire.
+        let raw_json = if typed {
+            "(JSON as { rawJSON?: (text: string) => unknown }).rawJSON"
+        } else {
+            "/** @type {any} */ (JSON).rawJSON"
+        };
+        ts_code.push_str(&format!(
+            "
+/**
+ * An int64 as the JSON number it stands for, written exactly where `JSON.rawJSON` is supported.{}
+ */
+export function encodeInt64(value{}){} {{
+    if (typeof value !== 'bigint' && typeof value !== 'string') {{
+        return value;
+    }}
+    const rawJSON = {};
+    return rawJSON ? rawJSON(String(value)) : Number(value);
+}}\n",
+            if typed { "" } else { "\n * @param {any} value\n * @returns {any}" },
+            if typed { ": any" } else { "" },
+            if typed { ": any" } else { "" },
+            raw_json
+        ));
+    }
+    ts_code
+}
+
+/// Imports the codecs the functions of `operations` use from the `codecs` module at `path`.
+pub fn import_codecs<'a>(
+    ts_code: &mut String,
+    swagger: &Swagger,
+    operations: impl IntoIterator<Item = (&'a str, &'a Operation)>,
+    types: TypeOptions,
+    path: &str,
+) {
+    let mut names: Vec<&str> = Vec::new();
+    let mut expressions = Vec::new();
+    for (method, operation) in operations {
+        expressions.extend(response_decoder(swagger, operation, types));
+        expressions.extend(request_encoder(swagger, operation, types).filter(|_| super::has_request_body(method)));
+    }
+    for expression in &expressions {
+        let identifiers = expression.split(|c: char| !c.is_ascii_alphanumeric() && c != '_');
+        names.extend(identifiers.filter(|name| !name.is_empty()));
+    }
+    names.sort();
+    names.dedup();
+    if !names.is_empty() {
+        ts_code.push_str(&format!("import {{ {} }} from '{}';\n\n", names.join(", "), path));
+    }
+}
+
+/// The codec turning the JSON response of an operation into its declared type, when its success
+/// responses all need the same one.
+pub fn response_decoder(swagger: &Swagger, operation: &Operation, types: TypeOptions) -> Option<String> {
+    if !converts(types) || non_json_response(swagger, operation).is_some() {
+        return None;
+    }
+    let codecs = Codecs::new(swagger, types);
+    let mut decoders = success_responses(operation)
+        .into_iter()
+        .filter_map(|(_, response)| response.response_schema.as_ref())
+        .map(|schema| codecs.schema(schema, false));
+    let decoder = decoders.next()??;
+    decoders.all(|other| other.as_ref() == Some(&decoder)).then_some(decoder)
+}
+
+/// The codec turning the JSON request body of an operation from its declared type into JSON.
+pub fn request_encoder(swagger: &Swagger, operation: &Operation, types: TypeOptions) -> Option<String> {
+    if !converts(types) || body_encoder(swagger, operation).is_some() {
+        return None;
+    }
+    let body = operation.parameters.iter().find(|param| param.location == "body")?;
+    Codecs::new(swagger, types).schema(body.schema.as_ref()?, true)
+}
+
+struct Codecs<'a> {
+    swagger: &'a Swagger,
+    types: TypeOptions,
+    /// The definitions holding converted values, directly or through the ones they refer to.
+    coded: HashSet<&'a str>,
+}
+
+impl<'a> Codecs<'a> {
+    fn new(swagger: &'a Swagger, types: TypeOptions) -> Codecs<'a> {
+        let mut codecs = Codecs { swagger, types, coded: HashSet::new() };
+        if !converts(types) {
+            return codecs;
+        }
+        // A definition needs codecs once one of the definitions it refers to does, which settles
+        // after as many rounds as references are nested.
+        loop {
+            let coded: Vec<&str> = swagger
+                .definitions
+                .iter()
+                .filter(|(name, definition)| {
+                    !codecs.coded.contains(name.as_str()) && codecs.definition(definition, false).is_some()
+                })
+                .map(|(name, _)| name.as_str())
+                .collect();
+            if coded.is_empty() {
+                return codecs;
+            }
+            codecs.coded.extend(coded);
+        }
+    }
+
+    fn reference(&self, reference: &str, encode: bool) -> Option<String> {
+        let name = ref_name(reference);
+        self.coded.contains(name).then(|| format!("{}{}", if encode { "encode" } else { "decode" }, name))
+    }
+
+    /// The codec of a definition of its own, listing the fields of an object one per line.
+    fn top_level(&self, definition: &Definition, encode: bool) -> Option<String> {
+        let codec = self.definition(definition, encode)?;
+        match codec.strip_prefix("mapFields({ ").and_then(|fields| fields.strip_suffix(" })")) {
+            Some(_) if codec.len() <= 80 => Some(codec),
+            Some(_) => {
+                let fields = self.field_codecs(all_properties(self.swagger, definition, &mut Vec::new()), encode);
+                Some(format!("mapFields({{\n        {},\n    }})", fields.join(",\n        ")))
+            }
+            None => Some(codec),
+        }
+    }
+
+    fn definition(&self, definition: &Definition, encode: bool) -> Option<String> {
+        if let Some(reference) = &definition.reference {
+            return self.reference(reference, encode);
+        }
+        if definition.enum_values.is_some() || definition.one_of.is_some() || definition.any_of.is_some() {
+            return None;
+        }
+        if definition.properties.is_none() && definition.all_of.is_none() {
+            let values = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false))?;
+            return Some(format!("mapValues({})", self.value(values, encode)?));
+        }
+        self.fields(all_properties(self.swagger, definition, &mut Vec::new()), encode)
+    }
+
+    fn fields(&self, properties: Vec<(&String, &Property, bool)>, encode: bool) -> Option<String> {
+        let fields = self.field_codecs(properties, encode);
+        (!fields.is_empty()).then(|| format!("mapFields({{ {} }})", fields.join(", ")))
+    }
+
+    fn field_codecs(&self, properties: Vec<(&String, &Property, bool)>, encode: bool) -> Vec<String> {
+        properties
+            .into_iter()
+            .filter_map(|(name, prop, _)| Some(format!("{}: {}", property_name(name), self.property(prop, encode)?)))
+            .collect()
+    }
+
+    fn property(&self, prop: &Property, encode: bool) -> Option<String> {
+        if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference, encode);
+        }
+        if property_enum(prop).is_some() {
+            return None;
+        }
+        if let Some(nested) = nested_definition(prop) {
+            return self.definition(&nested, encode);
+        }
+        if let Some(values) = map_values(prop) {
+            return Some(format!("mapValues({})", self.value(values, encode)?));
+        }
+        match prop.property_type.as_deref() {
+            Some("array") => Some(format!("mapItems({})", self.value(prop.additional.get("items")?, encode)?)),
+            schema_type => self.scalar(schema_type, prop.format.as_deref(), encode),
+        }
+    }
+
+    fn value(&self, schema: &Value, encode: bool) -> Option<String> {
+        let prop = serde_json::from_value::<Property>(schema.clone()).ok()?;
+        self.property(&prop, encode)
+    }
+
+    fn schema(&self, schema: &Schema, encode: bool) -> Option<String> {
+        if let Some(reference) = &schema.reference {
+            return self.reference(reference, encode);
+        }
+        // Top-level scalars are declared as their JSON types, see `schema_type`.
+        match schema.schema_type.as_deref() {
+            Some("array") => Some(format!("mapItems({})", self.schema(schema.items.as_deref()?, encode)?)),
+            _ => None,
+        }
+    }
+
+    fn scalar(&self, schema_type: Option<&str>, format: Option<&str>, encode: bool) -> Option<String> {
+        let codec = match (schema_type?, format?) {
+            ("string", "date") if self.types.date_type == DateType::Date => ["decodeDate", "encodeDay"],
+            ("string", "date-time") if self.types.date_type == DateType::Date => ["decodeDate", "encodeDate"],
+            ("integer", "int64") if self.types.int64_type != Int64Type::Number => ["decodeInt64", "encodeInt64"],
+            _ => return None,
+        };
+        Some(codec[encode as usize].to_string())
+    }
+}
@@ -0,0 +1,856 @@
+use super::typescript::{enum_member, form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_binary_responses, has_request_body, info_lines, operation_name, package_version,
+    pascal_case, request_media_type, response_items, response_reference, security_requirements, security_schemes,
+    sends_raw_body, with_custom_region, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char", "checked", "class", "const",
+    "continue", "decimal", "default", "delegate", "do", "double", "else", "enum", "event", "explicit", "extern",
+    "false", "finally", "fixed", "float", "for", "foreach", "goto", "if", "implicit", "in", "int", "interface",
+    "internal", "is", "lock", "long", "namespace", "new", "null", "object", "operator", "out", "override", "params",
+    "private", "protected", "public", "readonly", "ref", "return", "sbyte", "sealed", "short", "sizeof",
+    "stackalloc", "static", "string", "struct", "switch", "this", "throw", "true", "try", "typeof", "uint", "ulong",
+    "unchecked", "unsafe", "ushort", "using", "virtual", "void", "volatile", "while",
+];
+
+const VALUE_TYPES: &[&str] = &["int", "long", "float", "double", "bool"];
+
+const READ_RESPONSE: &str = "        var content = await response.Content.ReadAsStringAsync(cancellationToken);
+        if (!response.IsSuccessStatusCode)
+        {
+            throw new ApiException((int)response.StatusCode, content);
+        }
+        return string.IsNullOrEmpty(content) ? default : JsonSerializer.Deserialize<T>(content);
+";
+
+/// Hands file downloads their body as is, leaving failed ones to the error handling of `READ_RESPONSE`.
+const READ_BYTES: &str = "        if (typeof(T) == typeof(byte[]) && response.IsSuccessStatusCode)
+        {
+            return (T)(object)await response.Content.ReadAsByteArrayAsync(cancellationToken);
+        }
+";
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let namespace = namespace(swagger);
+    let project_dir = PathBuf::from(&namespace);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(project_dir.join(format!("{}.csproj", namespace)), generate_project(swagger, &namespace));
+    files.insert(project_dir.join("ApiException.cs"), generate_api_exception(swagger, &namespace));
+    let service = generate_service(swagger, &namespace, retries);
+    files.insert(project_dir.join("ApiService.cs"), with_custom_region(service, "//"));
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = project_dir.join("Models").join(format!("{}.cs", type_name(name)));
+        (path, generate_model(swagger, &namespace, name, definition))
+    }));
+    files
+}
+
+fn namespace(swagger: &Swagger) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    match pascal_case(title) {
+        namespace if namespace.starts_with(|c: char| c.is_ascii_alphabetic()) => namespace,
+        _ => "ApiClient".to_string(),
+    }
+}
+
+fn generate_header(swagger: &Swagger) -> String {
+    let mut cs_code = String::new();
+    for line in info_lines(swagger) {
+        cs_code.push_str(&format!("// {}\n", line));
+    }
+    cs_code.push('\n');
+    cs_code
+}
+
+fn generate_project(swagger: &Swagger, namespace: &str) -> String {
+    format!(
+        "<Project Sdk=\"Microsoft.NET.Sdk\">
+
+  <PropertyGroup>
+    <TargetFramework>net8.0</TargetFramework>
+    <ImplicitUsings>enable</ImplicitUsings>
+    <Nullable>enable</Nullable>
+    <RootNamespace>{}</RootNamespace>
+    <Version>{}</Version>
+  </PropertyGroup>
+
+</Project>
+",
+        namespace,
+        package_version(swagger)
+    )
+}
+
+fn generate_api_exception(swagger: &Swagger, namespace: &str) -> String {
+    let mut cs_code = generate_header(swagger);
+    cs_code.push_str(&format!(
+        "namespace {};
+
+public class ApiException : Exception
+{{
+    public int StatusCode {{ get; }}
+    public string ResponseBody {{ get; }}
+
+    public ApiException(int statusCode, string responseBody)
+        : base($\"Unexpected status {{statusCode}}: {{responseBody}}\")
+    {{
+        StatusCode = statusCode;
+        ResponseBody = responseBody;
+    }}
+}}
+",
+        namespace
+    ));
+    cs_code
+}
+
+fn generate_model(swagger: &Swagger, namespace: &str, name: &str, definition: &Definition) -> String {
+    let class_name = type_name(name);
+    let mut cs_code = generate_header(swagger);
+    let enum_code = definition.enum_values.as_ref().and_then(|values| generate_enum(&class_name, definition, values));
+    if enum_code.as_ref().is_some_and(|code| code.contains("JsonConverter")) {
+        cs_code.push_str("using System.Text.Json;\n");
+    }
+    cs_code.push_str("using System.Text.Json.Serialization;\n\n");
+    cs_code.push_str(&format!("namespace {}.Models;\n\n", namespace));
+    if let Some(enum_code) = enum_code {
+        cs_code.push_str(&enum_code);
+        return cs_code;
+    }
+    cs_code.push_str(&format!("public class {}\n{{\n", class_name));
+
+    let properties: Vec<String> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| {
+            let cs_type = property_type(prop);
+            let is_value_type = VALUE_TYPES.contains(&cs_type.as_str());
+            let mut property_name = type_name(prop_name);
+            if property_name == class_name {
+                property_name.push_str("Value");
+            }
+            // A required field that may be null is nullable, though it still has to be present.
+            let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+            let declaration = match (required && !nullable, is_value_type) {
+                (true, true) => format!("public {} {} {{ get; set; }}", cs_type, property_name),
+                (true, false) => format!("public {} {} {{ get; set; }} = default!;", cs_type, property_name),
+                (false, _) => format!("public {}? {} {{ get; set; }}", cs_type, property_name),
+            };
+            let mut attributes = format!("    [JsonPropertyName(\"{}\")]\n", prop_name);
+            if required {
+                attributes.push_str("    [JsonRequired]\n");
+            }
+            format!("{}    {}\n", attributes, declaration)
+        })
+        .collect();
+    cs_code.push_str(&properties.join("\n"));
+    cs_code.push_str("}\n");
+    cs_code
+}
+
+/// An integer enum as a C# `enum` with the same values, and a string one as an `enum` with a
+/// converter reading and writing the value of each member.
+fn generate_enum(enum_name: &str, definition: &Definition, values: &[Value]) -> Option<String> {
+    let member = |words: &[String]| enum_member(&words.join("_"));
+    match definition.definition_type.as_deref() {
+        Some("string") | None => {
+            let strings: Vec<Value> = values.iter().filter(|value| value.is_string()).cloned().collect();
+            if strings.is_empty() {
+                return None;
+            }
+            let members = enum_members(&strings, member);
+            let literal = |value: &Value| {
+                format!("\"{}\"", value.as_str().unwrap_or_default().replace('\\', "\\\\").replace('"', "\\\""))
+            };
+            let names: Vec<String> = members.iter().map(|(name, _)| format!("    {},\n", name)).collect();
+            let reads: Vec<String> = members
+                .iter()
+                .map(|(name, value)| format!("            {} => {}.{},\n", literal(value), enum_name, name))
+                .collect();
+            let writes: Vec<String> = members
+                .iter()
+                .map(|(name, value)| format!("            {}.{} => {},\n", enum_name, name, literal(value)))
+                .collect();
+            Some(format!(
+                "[JsonConverter(typeof({0}Converter))]
+public enum {0}
+{{
+{1}}}
+
+public class {0}Converter : JsonConverter<{0}>
+{{
+    public override {0} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+        reader.GetString() switch
+        {{
+{2}            var value => throw new JsonException($\"Unknown {0} value {{value}}\"),
+        }};
+
+    public override void Write(Utf8JsonWriter writer, {0} value, JsonSerializerOptions options) =>
+        writer.WriteStringValue(value switch
+        {{
+{3}            _ => throw new JsonException($\"Unknown {0} value {{value}}\"),
+        }});
+}}
+",
+                enum_name,
+                names.concat(),
+                reads.concat(),
+                writes.concat()
+            ))
+        }
+        Some("integer") => {
+            let numbers: Vec<Value> = values.iter().filter(|value| value.is_i64()).cloned().collect();
+            if numbers.is_empty() {
+                return None;
+            }
+            let members: Vec<String> = enum_members(&numbers, member)
+                .into_iter()
+                .map(|(name, value)| format!("    {} = {},\n", name, value))
+                .collect();
+            Some(format!("public enum {} : long\n{{\n{}}}\n", enum_name, members.concat()))
+        }
+        _ => None,
+    }
+}
+
+const FORMAT_VALUE: &str = "
+    private static string FormatValue(object value) => value switch
+    {
+        bool flag => flag ? \"true\" : \"false\",
+        string text => text,
+        System.Collections.IEnumerable items => string.Join(\",\", items.Cast<object>().Select(FormatValue)),
+        _ => Convert.ToString(value, System.Globalization.CultureInfo.InvariantCulture) ?? \"\",
+    };
+";
+
+const QUERY_HELPER: &str = "
+    /// <summary>
+    /// Builds a query string from name and value pairs, leaving out the null values and repeating
+    /// the name for each item of a list.
+    /// </summary>
+    private static string Query(params (string Name, object? Value)[] parameters)
+    {
+        var query = new List<string>();
+        foreach (var (name, value) in parameters)
+        {
+            var values = value is System.Collections.IEnumerable items and not string
+                ? items.Cast<object?>()
+                : new[] { value };
+            foreach (var item in values.OfType<object>())
+            {
+                query.Add($\"{Uri.EscapeDataString(name)}={Uri.EscapeDataString(FormatValue(item))}\");
+            }
+        }
+        return query.Count == 0 ? \"\" : \"?\" + string.Join(\"&\", query);
+    }
+";
+
+const JOIN_HELPER: &str = "
+    /// <summary>Joins the items of a list with a delimiter, leaving a null list null.</summary>
+    private static string? Join(string delimiter, System.Collections.IEnumerable? items) =>
+        items == null ? null : string.Join(delimiter, items.Cast<object>().Select(FormatValue));
+";
+
+const FORM_FIELDS: &str = "
+    private static IEnumerable<(string Name, object Value)> FormFields((string Name, object? Value)[] fields)
+    {
+        foreach (var (name, value) in fields)
+        {
+            var values = value is System.Collections.IEnumerable items and not string
+                ? items.Cast<object?>()
+                : new[] { value };
+            foreach (var item in values.OfType<object>())
+            {
+                yield return (name, item);
+            }
+        }
+    }
+";
+
+const MULTIPART_FORM: &str = "
+    /// <summary>
+    /// Builds a multipart/form-data body from name and value pairs, leaving out the null values,
+    /// repeating the name for each item of a list and sending streams as files named after their field.
+    /// </summary>
+    private static Func<HttpContent> MultipartForm(params (string Name, object? Value)[] fields) => () =>
+    {
+        var content = new MultipartFormDataContent();
+        foreach (var (name, value) in FormFields(fields))
+        {
+            if (value is Stream stream)
+            {
+                content.Add(new StreamContent(stream), name, name);
+            }
+            else
+            {
+                content.Add(new StringContent(FormatValue(value)), name);
+            }
+        }
+        return content;
+    };
+";
+
+const URL_ENCODED_FORM: &str = "
+    /// <summary>
+    /// Builds a URL-encoded form from name and value pairs, leaving out the null values and repeating
+    /// the name for each item of a list.
+    /// </summary>
+    private static Func<HttpContent> UrlEncodedForm(params (string Name, object? Value)[] fields) => () =>
+        new FormUrlEncodedContent(
+            FormFields(fields).Select(field => new KeyValuePair<string?, string?>(field.Name, FormatValue(field.Value))));
+";
+
+const MEDIA_BODY: &str = "
+    /// <summary>
+    /// Builds a body of another media type than application/json: byte arrays as they are, strings as
+    /// they are unless the type is JSON, anything else as JSON.
+    /// </summary>
+    private static Func<HttpContent>? MediaBody(object? body, string mediaType)
+    {
+        if (body == null)
+        {
+            return null;
+        }
+        return () => body switch
+        {
+            byte[] bytes => new ByteArrayContent(bytes) { Headers = { ContentType = new MediaTypeHeaderValue(mediaType) } },
+            string text when !mediaType.Contains(\"json\") => new StringContent(text, System.Text.Encoding.UTF8, mediaType),
+            _ => JsonContent.Create(body, mediaType: new MediaTypeHeaderValue(mediaType)),
+        };
+    }
+";
+
+fn generate_service(swagger: &Swagger, namespace: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    let operations: Vec<&Operation> =
+        swagger.paths.values().flat_map(|path_item| path_item.operations()).map(|(_, operation)| operation).collect();
+    let has_query = operations.iter().any(|operation| !query_parameters(operation).is_empty());
+    let has_headers = has_header_parameters(swagger);
+    let formats_path = swagger.paths.iter().any(|(path, path_item)| {
+        path_item.operations().into_iter().any(|(_, operation)| {
+            extract_path_params(path).iter().any(|param| path_parameter_type(operation, param) != "string")
+        })
+    });
+    let joins_arrays = operations.iter().any(|operation| {
+        let mut params = query_parameters(operation).into_iter().chain(header_parameters(operation));
+        params.any(|param| collection_delimiter(param).is_some())
+    });
+    // Forms are built anew for every attempt, since a request disposes of its content.
+    let form_media_types: Vec<&str> = operations
+        .iter()
+        .filter(|operation| !form_parameters(operation).is_empty())
+        .map(|operation| request_media_type(swagger, operation))
+        .collect();
+    let has_multipart = form_media_types.contains(&MULTIPART);
+    let has_url_encoded = form_media_types.iter().any(|media_type| *media_type != MULTIPART);
+    let has_forms = !form_media_types.is_empty();
+    let has_media_types = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(method, operation)| body_media_type(swagger, method, operation).is_some());
+
+    let mut cs_code = generate_header(swagger);
+    cs_code.push_str("using System.Net.Http.Headers;\n");
+    cs_code.push_str("using System.Net.Http.Json;\n");
+    if has_auth {
+        cs_code.push_str("using System.Text;\n");
+    }
+    cs_code.push_str("using System.Text.Json;\n");
+    if !swagger.definitions.is_empty() {
+        cs_code.push_str(&format!("using {}.Models;\n", namespace));
+    }
+    cs_code.push_str(&format!("\nnamespace {};\n\n", namespace));
+
+    cs_code.push_str("public class ApiService\n{\n");
+    cs_code.push_str(&format!("    public const string DefaultBaseUrl = \"{}\";\n\n", base_url(swagger)));
+    if let Some(retries) = retries {
+        cs_code.push_str(&format!("    public const int MaxRetries = {};\n\n", retries));
+    }
+    if has_auth {
+        cs_code.push_str(
+            "    private static readonly Dictionary<string, (string Type, string In, string Name)> SecuritySchemes = new()\n    {\n",
+        );
+        for (name, scheme) in security_schemes(swagger) {
+            cs_code.push_str(&format!(
+                "        [\"{}\"] = (\"{}\", \"{}\", \"{}\"),\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        cs_code.push_str("    };\n\n");
+    }
+    cs_code.push_str("    private readonly HttpClient _httpClient;\n");
+    cs_code.push_str("    private readonly string _baseUrl;\n");
+    if has_auth {
+        cs_code.push_str("    private readonly Dictionary<string, string> _credentials = new();\n");
+    }
+    cs_code.push_str(
+        "
+    public ApiService()
+        : this(new HttpClient())
+    {
+    }
+
+    public ApiService(HttpClient httpClient, string baseUrl = DefaultBaseUrl)
+    {
+        _httpClient = httpClient;
+        _baseUrl = baseUrl;
+    }\n",
+    );
+
+    if has_auth {
+        // The operations with query parameters put them in `path` already.
+        let (path_param, separator) = if has_query {
+            (", string path", "(path.Contains('?') ? \"&\" : \"?\")")
+        } else {
+            ("", "\"?\"")
+        };
+        cs_code.push_str(&format!(
+            "
+    /// <summary>Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.</summary>
+    public void SetAuth(string scheme, string credential) => _credentials[scheme] = credential;
+
+    private string ApplyAuth(HttpRequestMessage request, string[][] requirements{})
+    {{
+        var query = new List<string>();
+        foreach (var names in requirements)
+        {{
+            if (!names.All(_credentials.ContainsKey))
+            {{
+                continue;
+            }}
+            foreach (var name in names)
+            {{
+                var scheme = SecuritySchemes[name];
+                var credential = _credentials[name];
+                if (scheme.Type == \"basic\")
+                {{
+                    var encoded = Convert.ToBase64String(Encoding.UTF8.GetBytes(credential));
+                    request.Headers.Authorization = new AuthenticationHeaderValue(\"Basic\", encoded);
+                }}
+                else if (scheme.Type == \"apiKey\" && scheme.In == \"query\")
+                {{
+                    query.Add($\"{{scheme.Name}}={{Uri.EscapeDataString(credential)}}\");
+                }}
+                else if (scheme.Type == \"apiKey\" && scheme.In == \"cookie\")
+                {{
+                    request.Headers.Add(\"Cookie\", $\"{{scheme.Name}}={{credential}}\");
+                }}
+                else if (scheme.Type == \"apiKey\")
+                {{
+                    request.Headers.Add(scheme.Name, credential);
+                }}
+                else
+                {{
+                    request.Headers.Authorization = new AuthenticationHeaderValue(\"Bearer\", credential);
+                }}
+            }}
+            break;
+        }}
+        return query.Count == 0 ? \"\" : {} + string.Join(\"&\", query);
+    }}\n",
+            path_param, separator
+        ));
+    }
+    if has_query || has_headers || has_forms || formats_path {
+        cs_code.push_str(FORMAT_VALUE);
+    }
+    if has_query {
+        cs_code.push_str(QUERY_HELPER);
+    }
+    if joins_arrays {
+        cs_code.push_str(JOIN_HELPER);
+    }
+    if has_forms {
+        cs_code.push_str(FORM_FIELDS);
+    }
+    if has_multipart {
+        cs_code.push_str(MULTIPART_FORM);
+    }
+    if has_url_encoded {
+        cs_code.push_str(URL_ENCODED_FORM);
+    }
+    if has_media_types {
+        cs_code.push_str(MEDIA_BODY);
+    }
+
+    let requirements_param = if has_auth { "string[][] requirements, " } else { "" };
+    let headers_param = if has_headers { "(string Name, object? Value)[] headers, " } else { "" };
+    let auth_query = match (has_auth, has_query) {
+        (true, true) => " + ApplyAuth(request, requirements, path)",
+        (true, false) => " + ApplyAuth(request, requirements)",
+        (false, _) => "",
+    };
+    // Headers without a value are left out, like the query parameters.
+    let add_headers = |indent: &str| {
+        if !has_headers {
+            return String::new();
+        }
+        format!(
+            "{0}foreach (var (name, value) in headers)
+{0}{{
+{0}    if (value != null)
+{0}    {{
+{0}        request.Headers.TryAddWithoutValidation(name, FormatValue(value));
+{0}    }}
+{0}}}\n",
+            indent
+        )
+    };
+    let content = if has_forms || has_media_types {
+        "body is Func<HttpContent> factory ? factory() : JsonContent.Create(body)"
+    } else {
+        "JsonContent.Create(body)"
+    };
+    let read_response = if has_binary_responses(swagger) {
+        format!("{}{}", READ_BYTES, READ_RESPONSE)
+    } else {
+        READ_RESPONSE.to_string()
+    };
+    match retries {
+        None => cs_code.push_str(&format!(
+            "
+    private async Task<T?> SendAsync<T>(HttpMethod method, string path, {}object? body, {}CancellationToken cancellationToken)
+    {{
+        using var request = new HttpRequestMessage(method, (string?)null);
+        request.RequestUri = new Uri(_baseUrl + path{});
+        request.Headers.Accept.Add(new MediaTypeWithQualityHeaderValue(\"application/json\"));
+{}        if (body != null)
+        {{
+            request.Content = {};
+        }}
+
+        using var response = await _httpClient.SendAsync(request, cancellationToken);
+{}    }}\n",
+            headers_param,
+            requirements_param,
+            auth_query,
+            add_headers("        "),
+            content,
+            read_response
+        )),
+        // A request message can only be sent once, so every attempt builds a new one.
+        Some(_) => cs_code.push_str(&format!(
+            "
+    private async Task<T?> SendAsync<T>(HttpMethod method, string path, {}object? body, {}CancellationToken cancellationToken)
+    {{
+        HttpRequestMessage CreateRequest()
+        {{
+            var request = new HttpRequestMessage(method, (string?)null);
+            request.RequestUri = new Uri(_baseUrl + path{});
+            request.Headers.Accept.Add(new MediaTypeWithQualityHeaderValue(\"application/json\"));
+{}            if (body != null)
+            {{
+                request.Content = {};
+            }}
+            return request;
+        }}
+
+        using var response = await SendWithRetriesAsync(CreateRequest, cancellationToken);
+{}    }}
+
+    /// <summary>
+    /// Retries requests that failed with a network error, 429 or 5xx, waiting as long as
+    /// Retry-After asks or otherwise doubling the delay from half a second.
+    /// </summary>
+    private async Task<HttpResponseMessage> SendWithRetriesAsync(
+        Func<HttpRequestMessage> createRequest,
+        CancellationToken cancellationToken)
+    {{
+        for (var attempt = 0; ; attempt++)
+        {{
+            using var request = createRequest();
+            var delay = TimeSpan.FromMilliseconds(500 << attempt);
+            try
+            {{
+                var response = await _httpClient.SendAsync(request, cancellationToken);
+                var status = (int)response.StatusCode;
+                if ((status != 429 && status < 500) || attempt >= MaxRetries)
+                {{
+                    return response;
+                }}
+                var retryAfter = response.Headers.RetryAfter;
+                if (retryAfter?.Delta is TimeSpan seconds)
+                {{
+                    delay = seconds;
+                }}
+                else if (retryAfter?.Date is DateTimeOffset date)
+                {{
+                    delay = date > DateTimeOffset.UtcNow ? date - DateTimeOffset.UtcNow : TimeSpan.Zero;
+                }}
+                response.Dispose();
+            }}
+            catch (HttpRequestException) when (attempt < MaxRetries)
+            {{
+            }}
+            await Task.Delay(delay, cancellationToken);
+        }}
+    }}\n",
+            headers_param,
+            requirements_param,
+            auth_query,
+            add_headers("            "),
+            content,
+            read_response
+        )),
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            cs_code.push('\n');
+            cs_code.push_str(&generate_service_method(swagger, method, path, operation));
+        }
+    }
+    cs_code.push_str("}\n");
+    cs_code
+}
+
+fn generate_service_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = format!("{}Async", type_name(&operation_name(method, path, operation)));
+    let has_body = has_request_body(method);
+
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+    let form = form_parameters(operation);
+
+    // Optional parameters default to null, following the required ones and the body.
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{} {}", path_parameter_type(operation, param), argument_name(param)))
+        .collect();
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) =
+        query.iter().chain(&headers).chain(&form).partition(|param| param.required);
+    for param in required {
+        arguments.push(format!("{} {}", parameter_type(param), argument_name(&param.name)));
+    }
+    let body = if !form.is_empty() {
+        let fields: Vec<String> =
+            form.iter().map(|param| format!("(\"{}\", {})", param.name, argument_name(&param.name))).collect();
+        let multipart = request_media_type(swagger, operation) == MULTIPART;
+        format!("{}({})", if multipart { "MultipartForm" } else { "UrlEncodedForm" }, fields.join(", "))
+    } else if has_body {
+        let body = operation.parameters.iter().find(|param| param.location == "body");
+        let body_type = match body.and_then(|body| body.schema.as_ref()) {
+            Some(schema) if !sends_raw_body(swagger, method, operation) => spec_type(schema),
+            _ => "object".to_string(),
+        };
+        let optional = if body.is_some_and(|body| body.required) { "" } else { "?" };
+        arguments.push(format!("{}{} body", body_type, optional));
+        match body_media_type(swagger, method, operation) {
+            Some(media_type) => format!("MediaBody(body, \"{}\")", media_type),
+            None => "body".to_string(),
+        }
+    } else {
+        "null".to_string()
+    };
+    for param in optional {
+        arguments.push(format!("{}? {} = null", parameter_type(param), argument_name(&param.name)));
+    }
+    arguments.push("CancellationToken cancellationToken = default".to_string());
+
+    // Strings are escaped to stay within their segment, while numbers and booleans cannot leave it.
+    let cs_path = path_params.iter().fold(path.to_string(), |acc, param| {
+        let value = match path_parameter_type(operation, param) {
+            "string" => format!("{{Uri.EscapeDataString({})}}", argument_name(param)),
+            _ => format!("{{FormatValue({})}}", argument_name(param)),
+        };
+        acc.replace(&format!("{{{}}}", param), &value)
+    });
+    let mut cs_path = if path_params.is_empty() { format!("\"{}\"", cs_path) } else { format!("$\"{}\"", cs_path) };
+    if !query.is_empty() {
+        let pairs: Vec<String> =
+            query.iter().map(|param| format!("(\"{}\", {})", param.name, parameter_value(param))).collect();
+        cs_path = format!("{} + Query({})", cs_path, pairs.join(", "));
+    }
+    let headers_argument = if headers.is_empty() {
+        if has_header_parameters(swagger) { "Array.Empty<(string, object?)>(), ".to_string() } else { String::new() }
+    } else {
+        let pairs: Vec<String> = headers
+            .iter()
+            .map(|param| {
+                let value = argument_name(&param.name);
+                match param.location.as_str() {
+                    "cookie" => format!("(\"Cookie\", {0} == null ? null : $\"{1}={{{0}}}\")", value, param.name),
+                    _ => format!("(\"{}\", {})", param.name, parameter_value(param)),
+                }
+            })
+            .collect();
+        format!("new (string, object?)[] {{ {} }}, ", pairs.join(", "))
+    };
+
+    let requirements = if swagger.security_definitions.is_empty() {
+        String::new()
+    } else {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("new[] {{ {} }}", names.join(", "))
+            })
+            .collect();
+        if requirements.is_empty() {
+            "Array.Empty<string[]>(), ".to_string()
+        } else {
+            format!("new[] {{ {} }}, ", requirements.join(", "))
+        }
+    };
+
+    let (response_type, task_type) = match (response_reference(operation), response_items(operation)) {
+        _ if has_binary_response(swagger, operation) => ("byte[]".to_string(), "byte[]?".to_string()),
+        (Some(reference), _) => (type_name(ref_name(reference)), format!("{}?", type_name(ref_name(reference)))),
+        (None, Some(items)) => (format!("List<{}>", spec_type(items)), format!("List<{}>?", spec_type(items))),
+        (None, None) => ("JsonElement".to_string(), "JsonElement".to_string()),
+    };
+    let http_method = match method {
+        "get" => "HttpMethod.Get".to_string(),
+        "post" => "HttpMethod.Post".to_string(),
+        "put" => "HttpMethod.Put".to_string(),
+        "patch" => "HttpMethod.Patch".to_string(),
+        "delete" => "HttpMethod.Delete".to_string(),
+        "head" => "HttpMethod.Head".to_string(),
+        "options" => "HttpMethod.Options".to_string(),
+        other => format!("new HttpMethod(\"{}\")", other.to_uppercase()),
+    };
+
+    let mut cs_code = String::new();
+    if let Some(summary) = &operation.summary {
+        cs_code.push_str(&format!("    /// <summary>{}</summary>\n", summary));
+    }
+    if operation.deprecated {
+        cs_code.push_str("    [Obsolete]\n");
+    }
+    cs_code.push_str(&format!(
+        "    public Task<{}> {}({}) =>
+        SendAsync<{}>({}, {}, {}{}, {}cancellationToken);\n",
+        task_type,
+        method_name,
+        arguments.join(", "),
+        response_type,
+        http_method,
+        cs_path,
+        headers_argument,
+        body,
+        requirements
+    ));
+    cs_code
+}
+
+fn has_header_parameters(swagger: &Swagger) -> bool {
+    swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(_, operation)| !header_parameters(operation).is_empty())
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            format!("List<{}>", items_type)
+        }
+        Some("file") => "Stream".to_string(),
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `string` for strings and
+/// parameters the operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(cs_type) if VALUE_TYPES.contains(&cs_type) => cs_type,
+        _ => "string",
+    }
+}
+
+fn spec_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return type_name(ref_name(reference));
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => format!("List<{}>", schema.items.as_deref().map_or("object".to_string(), spec_type)),
+        other => primitive_type(other, schema.format.as_deref()).to_string(),
+    }
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => format!("List<{}>", prop.additional.get("items").map_or("object".to_string(), schema_type)),
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("List<{}>", schema.get("items").map_or("object".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), Some("int32")) => "int",
+        (Some("integer"), _) => "long",
+        (Some("number"), Some("float")) => "float",
+        (Some("number"), _) => "double",
+        (Some("string"), _) => "string",
+        (Some("boolean"), _) => "bool",
+        (Some("object"), _) => "Dictionary<string, object>",
+        _ => "object",
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+/// The value a query or header parameter is sent as: its argument, with a list joined by the delimiter
+/// of its `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    match collection_delimiter(param) {
+        Some(delimiter) => format!("Join({:?}, {})", delimiter, argument_name(&param.name)),
+        None => argument_name(&param.name),
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    let type_name = type_name(name);
+    let mut chars = type_name.chars();
+    let argument: String = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    };
+    match argument.as_str() {
+        "body" | "cancellationToken" => format!("{}Param", argument),
+        _ if KEYWORDS.contains(&argument.as_str()) => format!("@{}", argument),
+        _ => argument,
+    }
+}
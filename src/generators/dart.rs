@@ -0,0 +1,828 @@
+use super::typescript::{enum_member, form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_binary_responses, has_request_body, info_lines, operation_name, package_name,
+    package_version, pascal_case, request_media_type, response_items, response_reference, security_requirements,
+    security_schemes, sends_raw_body, snake_case, with_custom_region, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else", "enum", "extends",
+    "false", "final", "finally", "for", "if", "in", "is", "new", "null", "rethrow", "return", "super", "switch",
+    "this", "throw", "true", "try", "var", "void", "while", "with",
+];
+
+/// Members every generated enum has, which its constants cannot be named after.
+const ENUM_MEMBERS: &[&str] =
+    &["hashCode", "index", "noSuchMethod", "runtimeType", "toJson", "toString", "value", "values"];
+
+/// How a value of a schema is represented in Dart, which decides how it is read from and written to JSON.
+enum DartType {
+    Primitive(&'static str),
+    Model(String),
+    Enum(String),
+    List(Box<DartType>),
+    Dynamic,
+}
+
+impl DartType {
+    fn name(&self) -> String {
+        match self {
+            DartType::Primitive(name) => name.to_string(),
+            DartType::Model(name) | DartType::Enum(name) => name.clone(),
+            DartType::List(item) => format!("List<{}>", item.name()),
+            DartType::Dynamic => "dynamic".to_string(),
+        }
+    }
+
+    fn models(&self) -> Vec<String> {
+        match self {
+            DartType::Model(name) | DartType::Enum(name) => vec![name.clone()],
+            DartType::List(item) => item.models(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Expression converting the decoded JSON `value` into this type.
+    fn decode(&self, value: &str, nullable: bool) -> String {
+        let optional = if nullable { "?" } else { "" };
+        match self {
+            DartType::Primitive("int") => format!("({} as num{}){}.toInt()", value, optional, optional),
+            DartType::Primitive("double") => format!("({} as num{}){}.toDouble()", value, optional, optional),
+            DartType::Primitive(name) => format!("{} as {}{}", value, name, optional),
+            DartType::Model(name) if nullable => {
+                format!("{} == null ? null : {}.fromJson({} as Map<String, dynamic>)", value, name, value)
+            }
+            DartType::Model(name) => format!("{}.fromJson({} as Map<String, dynamic>)", name, value),
+            DartType::Enum(name) if nullable => format!("{} == null ? null : {}.fromJson({})", value, name, value),
+            DartType::Enum(name) => format!("{}.fromJson({})", name, value),
+            DartType::List(item) => format!(
+                "({} as List<dynamic>{}){}.map((e) => {}).toList()",
+                value,
+                optional,
+                optional,
+                item.decode("e", false)
+            ),
+            DartType::Dynamic => value.to_string(),
+        }
+    }
+
+    /// Expression converting the non-null `value` of this type into encodable JSON.
+    fn encode(&self, value: &str) -> String {
+        match self {
+            DartType::Model(_) | DartType::Enum(_) => format!("{}.toJson()", value),
+            DartType::List(item) if !item.models().is_empty() => {
+                format!("{}.map((e) => {}).toList()", value, item.encode("e"))
+            }
+            _ => value.to_string(),
+        }
+    }
+}
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let package = package_name(swagger);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("pubspec.yaml"), generate_pubspec(swagger, &package, retries.is_some()));
+    files.insert(PathBuf::from("lib").join(format!("{}.dart", package)), generate_library(swagger));
+    let client = with_custom_region(generate_client(swagger, retries), "//");
+    files.insert(PathBuf::from("lib").join("src").join("api_client.dart"), client);
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = PathBuf::from("lib").join("src").join("models").join(format!("{}.dart", file_name(name)));
+        (path, generate_model(swagger, name, definition))
+    }));
+    files
+}
+
+fn generate_header(swagger: &Swagger) -> String {
+    let mut dart_code = String::new();
+    for line in info_lines(swagger) {
+        dart_code.push_str(&format!("// {}\n", line));
+    }
+    dart_code.push('\n');
+    dart_code
+}
+
+/// With `retries`, the client also depends on `http_parser` to read a `Retry-After` sent as a date.
+fn generate_pubspec(swagger: &Swagger, package: &str, retries: bool) -> String {
+    let description = swagger.info.get("title").and_then(Value::as_str).unwrap_or(package);
+    format!(
+        "name: {}
+description: Generated client for {}
+version: {}
+
+environment:
+  sdk: '>=3.0.0 <4.0.0'
+
+dependencies:
+  dio: ^5.4.0
+{}",
+        package,
+        description,
+        package_version(swagger),
+        if retries { "  http_parser: ^4.0.0\n" } else { "" }
+    )
+}
+
+fn generate_library(swagger: &Swagger) -> String {
+    let mut dart_code = generate_header(swagger);
+    dart_code.push_str("export 'src/api_client.dart';\n");
+    for name in swagger.definitions.keys() {
+        dart_code.push_str(&format!("export 'src/models/{}.dart';\n", file_name(name)));
+    }
+    dart_code
+}
+
+fn generate_model(swagger: &Swagger, name: &str, definition: &Definition) -> String {
+    let class_name = type_name(name);
+    let mut dart_code = generate_header(swagger);
+    if let Some((value_type, values)) = enum_values(definition) {
+        dart_code.push_str(&generate_enum(&class_name, value_type, &values));
+        return dart_code;
+    }
+    // A required field that may be null is nullable, though it is still asked for and always written.
+    let properties: Vec<(&String, String, DartType, bool, bool)> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| {
+            let nullable = !required || has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+            (prop_name, field_name(prop_name), property_type(swagger, prop), required, nullable)
+        })
+        .collect();
+
+    let mut imports: Vec<String> = properties.iter().flat_map(|(_, _, dart_type, _, _)| dart_type.models()).collect();
+    imports.sort();
+    imports.dedup();
+    imports.retain(|model| *model != class_name);
+    for model in &imports {
+        dart_code.push_str(&format!("import '{}.dart';\n", file_name(model)));
+    }
+    if !imports.is_empty() {
+        dart_code.push('\n');
+    }
+
+    dart_code.push_str(&format!("class {} {{\n", class_name));
+    if properties.is_empty() {
+        dart_code.push_str(&format!(
+            "  const {0}();
+
+  factory {0}.fromJson(Map<String, dynamic> json) => const {0}();
+
+  Map<String, dynamic> toJson() => {{}};
+}}\n",
+            class_name
+        ));
+        return dart_code;
+    }
+
+    let arguments: Vec<String> = properties
+        .iter()
+        .map(|(_, field, _, required, _)| match required {
+            true => format!("required this.{}", field),
+            false => format!("this.{}", field),
+        })
+        .collect();
+    dart_code.push_str(&format!("  {}({{{}}});\n\n", class_name, arguments.join(", ")));
+
+    dart_code.push_str(&format!("  factory {}.fromJson(Map<String, dynamic> json) => {}(\n", class_name, class_name));
+    for (prop_name, field, dart_type, _, nullable) in &properties {
+        let value = format!("json['{}']", prop_name);
+        dart_code.push_str(&format!("        {}: {},\n", field, dart_type.decode(&value, *nullable)));
+    }
+    dart_code.push_str("      );\n\n");
+
+    for (_, field, dart_type, _, nullable) in &properties {
+        let optional = if !nullable || matches!(dart_type, DartType::Dynamic) { "" } else { "?" };
+        dart_code.push_str(&format!("  final {}{} {};\n", dart_type.name(), optional, field));
+    }
+
+    dart_code.push_str("\n  Map<String, dynamic> toJson() => {\n");
+    for (prop_name, field, dart_type, required, nullable) in &properties {
+        // Public fields are not promoted by the null check, so converted values need `!`.
+        let value = if dart_type.models().is_empty() { field.clone() } else { format!("{}!", field) };
+        if !nullable {
+            dart_code.push_str(&format!("        '{}': {},\n", prop_name, dart_type.encode(field)));
+        } else if *required {
+            let value = match dart_type.models().is_empty() {
+                true => field.clone(),
+                false => format!("{} == null ? null : {}", field, dart_type.encode(&value)),
+            };
+            dart_code.push_str(&format!("        '{}': {},\n", prop_name, value));
+        } else {
+            dart_code.push_str(&format!("        if ({} != null) '{}': {},\n", field, prop_name, dart_type.encode(&value)));
+        }
+    }
+    dart_code.push_str("      };\n}\n");
+    dart_code
+}
+
+/// The Dart type of the values of an enum definition and the values of that type, for the
+/// string, integer and number enums, which become Dart enums.
+fn enum_values(definition: &Definition) -> Option<(&'static str, Vec<Value>)> {
+    let values = definition.enum_values.as_ref()?;
+    let (value_type, values): (&str, Vec<Value>) = match definition.definition_type.as_deref() {
+        Some("string") | None => ("String", values.iter().filter(|value| value.is_string()).cloned().collect()),
+        Some("integer") => ("int", values.iter().filter(|value| value.is_i64()).cloned().collect()),
+        Some("number") => ("double", values.iter().filter(|value| value.is_number()).cloned().collect()),
+        _ => return None,
+    };
+    Some((value_type, values)).filter(|(_, values)| !values.is_empty())
+}
+
+fn generate_enum(enum_name: &str, value_type: &str, values: &[Value]) -> String {
+    let constant = |words: &[String]| {
+        let member = enum_member(&words.join("_"));
+        let mut chars = member.chars();
+        let first = chars.next().map(|first| first.to_ascii_lowercase().to_string()).unwrap_or_default();
+        match first + chars.as_str() {
+            constant if KEYWORDS.contains(&constant.as_str()) || ENUM_MEMBERS.contains(&constant.as_str()) => {
+                format!("{}_", constant)
+            }
+            constant => constant,
+        }
+    };
+    let constants: Vec<String> = enum_members(values, constant)
+        .into_iter()
+        .map(|(constant, value)| match value {
+            Value::String(text) => {
+                let literal = text.replace('\\', "\\\\").replace('\'', "\\'").replace('$', "\\$");
+                format!("  {}('{}')", constant, literal)
+            }
+            number => format!("  {}({})", constant, number),
+        })
+        .collect();
+    format!(
+        "enum {0} {{
+{1};
+
+  const {0}(this.value);
+
+  final {2} value;
+
+  factory {0}.fromJson(dynamic json) => values.firstWhere(
+        (member) => member.value == json,
+        orElse: () => throw ArgumentError.value(json, 'json', 'Unknown {0} value'),
+      );
+
+  {2} toJson() => value;
+}}\n",
+        enum_name,
+        constants.join(",\n"),
+        value_type
+    )
+}
+
+fn generate_client(swagger: &Swagger, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    // File downloads ask dio for the body as bytes instead of decoded JSON.
+    let binary = has_binary_responses(swagger);
+    let has_query = has_parameters(swagger, "query");
+    let has_headers = has_parameters(swagger, "header");
+    let has_cookies = has_parameters(swagger, "cookie");
+    // Form bodies and bodies declared with a non-JSON media type pick their own `Content-Type`.
+    let has_content_types = has_parameters(swagger, "formData")
+        || swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(method, operation)| {
+            body_media_type(swagger, method, operation).is_some()
+        });
+
+    let mut dart_code = generate_header(swagger);
+    if has_auth {
+        dart_code.push_str("import 'dart:convert';\n\n");
+    }
+    dart_code.push_str("import 'package:dio/dio.dart';\n");
+    if retries.is_some() {
+        dart_code.push_str("import 'package:http_parser/http_parser.dart';\n");
+    }
+    // The models the methods return, as themselves or in lists, and take as bodies.
+    let mut models: Vec<String> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .flat_map(|(method, operation)| [response_type(swagger, operation), body_type(swagger, method, operation)])
+        .flatten()
+        .flat_map(|dart_type| dart_type.models())
+        .collect();
+    models.sort();
+    models.dedup();
+    if !models.is_empty() {
+        dart_code.push('\n');
+    }
+    for model in models {
+        dart_code.push_str(&format!("import 'models/{}.dart';\n", file_name(&model)));
+    }
+
+    dart_code.push_str(
+        "
+class ApiClient {
+  ApiClient({String baseUrl = defaultBaseUrl, Dio? dio}) : dio = dio ?? Dio(BaseOptions(baseUrl: baseUrl))",
+    );
+    match retries {
+        Some(_) => {
+            dart_code.push_str(" {\n    this.dio.interceptors.add(InterceptorsWrapper(onError: _retry));\n  }\n")
+        }
+        None => dart_code.push_str(";\n"),
+    }
+    dart_code.push_str(&format!("\n  static const defaultBaseUrl = '{}';\n", base_url(swagger)));
+    if let Some(retries) = retries {
+        dart_code.push_str(&format!("  static const maxRetries = {};\n", retries));
+    }
+    if has_auth {
+        dart_code.push_str("\n  static const _securitySchemes = <String, (String, String, String)>{\n");
+        for (name, scheme) in security_schemes(swagger) {
+            dart_code.push_str(&format!(
+                "    '{}': ('{}', '{}', '{}'),\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        dart_code.push_str("  };\n");
+    }
+    dart_code.push_str("\n  final Dio dio;\n");
+    if has_auth {
+        dart_code.push_str("  final Map<String, String> _credentials = {};\n");
+        dart_code.push_str(
+            "
+  /// Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.
+  void setAuth(String scheme, String credential) {
+    _credentials[scheme] = credential;
+  }\n",
+        );
+    }
+    if has_auth || has_query || has_headers || has_cookies || has_content_types {
+        dart_code.push_str("\n  Future<dynamic> _request(\n    String method,\n    String path, {\n");
+        if has_query {
+            dart_code.push_str("    Map<String, dynamic> queryParameters = const {},\n");
+        }
+        if has_headers {
+            dart_code.push_str("    Map<String, dynamic> headerParameters = const {},\n");
+        }
+        if has_cookies {
+            dart_code.push_str("    Map<String, dynamic> cookieParameters = const {},\n");
+        }
+        dart_code.push_str("    Object? body,\n");
+        if has_content_types {
+            dart_code.push_str("    String? contentType,\n");
+        }
+        if binary {
+            dart_code.push_str("    ResponseType? responseType,\n");
+        }
+        if has_auth {
+            dart_code.push_str("    List<List<String>> requirements = const [],\n");
+        }
+        dart_code.push_str(
+            "  }) async {
+    final headers = <String, dynamic>{};
+    final query = <String, dynamic>{};\n",
+        );
+    }
+    // Parameters left out are null and not sent.
+    if has_query {
+        dart_code.push_str(
+            "    for (final entry in queryParameters.entries) {
+      if (entry.value != null) {
+        query[entry.key] = entry.value;
+      }
+    }\n",
+        );
+    }
+    if has_headers {
+        dart_code.push_str(
+            "    for (final entry in headerParameters.entries) {
+      final value = entry.value;
+      if (value != null) {
+        headers[entry.key] = value is List ? value.join(',') : '$value';
+      }
+    }\n",
+        );
+    }
+    if has_auth {
+        dart_code.push_str(
+            "    for (final names in requirements) {
+      if (!names.every(_credentials.containsKey)) {
+        continue;
+      }
+      for (final name in names) {
+        final (type, location, key) = _securitySchemes[name]!;
+        final credential = _credentials[name]!;
+        if (type == 'basic') {
+          headers['Authorization'] = 'Basic ${base64Encode(utf8.encode(credential))}';
+        } else if (type == 'apiKey' && location == 'query') {
+          query[key] = credential;
+        } else if (type == 'apiKey' && location == 'cookie') {
+          headers['Cookie'] = '$key=$credential';
+        } else if (type == 'apiKey') {
+          headers[key] = credential;
+        } else {
+          headers['Authorization'] = 'Bearer $credential';
+        }
+      }
+      break;
+    }\n",
+        );
+    }
+    if has_cookies {
+        dart_code.push_str(
+            "    final cookies = [
+      if (headers['Cookie'] != null) headers['Cookie'],
+      for (final entry in cookieParameters.entries)
+        if (entry.value != null) '${entry.key}=${entry.value}',
+    ];
+    if (cookies.isNotEmpty) {
+      headers['Cookie'] = cookies.join('; ');
+    }\n",
+        );
+    }
+    if has_auth || has_query || has_headers || has_cookies || has_content_types {
+        dart_code.push_str(
+            "    final response = await dio.request<dynamic>(
+      path,
+      data: body,
+      queryParameters: query,
+      options: Options(method: method, headers: headers",
+        );
+        if binary {
+            dart_code.push_str(", responseType: responseType");
+        }
+        if has_content_types {
+            dart_code.push_str(", contentType: contentType");
+        }
+        dart_code.push_str(
+            "),
+    );
+    return response.data;
+  }\n",
+        );
+    } else if binary {
+        dart_code.push_str(
+            "
+  Future<dynamic> _request(String method, String path, {Object? body, ResponseType? responseType}) async {
+    final response = await dio.request<dynamic>(
+      path,
+      data: body,
+      options: Options(method: method, responseType: responseType),
+    );
+    return response.data;
+  }\n",
+        );
+    } else {
+        dart_code.push_str(
+            "
+  Future<dynamic> _request(String method, String path, {Object? body}) async {
+    final response = await dio.request<dynamic>(path, data: body, options: Options(method: method));
+    return response.data;
+  }\n",
+        );
+    }
+
+    if retries.is_some() {
+        dart_code.push_str(
+            "
+  /// Retries requests that failed with a network error, 429 or 5xx, waiting as long as Retry-After asks
+  /// or otherwise doubling the delay from half a second.
+  Future<void> _retry(DioException error, ErrorInterceptorHandler handler) async {
+    final options = error.requestOptions;
+    final attempt = (options.extra['retryAttempt'] as int?) ?? 0;
+    final status = error.response?.statusCode;
+    final retryable = error.type == DioExceptionType.connectionError ||
+        error.type == DioExceptionType.connectionTimeout ||
+        status == 429 ||
+        (status != null && status >= 500);
+    if (!retryable || attempt >= maxRetries) {
+      return handler.next(error);
+    }
+    await Future<void>.delayed(_retryDelay(attempt, error.response?.headers.value('retry-after')));
+    options.extra['retryAttempt'] = attempt + 1;
+    try {
+      handler.resolve(await dio.fetch<dynamic>(options));
+    } on DioException catch (retryError) {
+      handler.next(retryError);
+    }
+  }
+
+  /// How long Retry-After asks to wait, in seconds or until a date, or otherwise half a second doubled per attempt.
+  static Duration _retryDelay(int attempt, String? retryAfter) {
+    final seconds = int.tryParse(retryAfter ?? '');
+    if (seconds != null) {
+      return Duration(seconds: seconds);
+    }
+    try {
+      final delay = parseHttpDate(retryAfter ?? '').difference(DateTime.now());
+      return delay.isNegative ? Duration.zero : delay;
+    } on FormatException {
+      return Duration(milliseconds: 500 << attempt);
+    }
+  }\n",
+        );
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            dart_code.push('\n');
+            dart_code.push_str(&generate_client_method(swagger, method, path, operation));
+        }
+    }
+    dart_code.push_str("}\n");
+    dart_code
+}
+
+/// Whether any operation takes a parameter in `location`, which `_request` then accepts.
+fn has_parameters(swagger: &Swagger, location: &str) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        let params = [query_parameters(operation), header_parameters(operation), form_parameters(operation)];
+        params.iter().flatten().any(|param| param.location == location)
+    })
+}
+
+fn generate_client_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = field_name(&operation_name(method, path, operation));
+    let form = form_parameters(operation);
+    let has_body = has_request_body(method) && form.is_empty();
+
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{} {}", path_parameter_type(operation, param), argument_name(param)))
+        .collect();
+    let body = body_type(swagger, method, operation);
+    let body_required = operation.parameters.iter().any(|param| param.location == "body" && param.required);
+    if has_body {
+        arguments.push(match &body {
+            Some(body) if body_required => format!("{} body", body.name()),
+            Some(DartType::Dynamic) => "dynamic body".to_string(),
+            Some(body) => format!("{}? body", body.name()),
+            None => "Object? body".to_string(),
+        });
+    }
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation));
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) =
+        params.iter().chain(&form).partition(|param| param.required);
+    let mut named: Vec<String> = required
+        .iter()
+        .map(|param| format!("required {} {}", parameter_type(param), argument_name(&param.name)))
+        .collect();
+    named.extend(optional.iter().map(|param| match parameter_type(param).as_str() {
+        "dynamic" => format!("dynamic {}", argument_name(&param.name)),
+        other => format!("{}? {}", other, argument_name(&param.name)),
+    }));
+    if !named.is_empty() {
+        arguments.push(format!("{{{}}}", named.join(", ")));
+    }
+
+    // Strings are encoded to stay within their segment, while numbers and booleans cannot leave it.
+    let dart_path = path_params.iter().fold(path.to_string(), |acc, param| {
+        let value = match path_parameter_type(operation, param) {
+            "String" => format!("${{Uri.encodeComponent({})}}", argument_name(param)),
+            _ => format!("${{{}}}", argument_name(param)),
+        };
+        acc.replace(&format!("{{{}}}", param), &value)
+    });
+    let mut request_arguments = vec![format!("'{}'", method.to_uppercase()), format!("'{}'", dart_path)];
+    let locations = [("queryParameters", "query"), ("headerParameters", "header"), ("cookieParameters", "cookie")];
+    for (label, location) in locations {
+        let values: Vec<String> = params
+            .iter()
+            .filter(|param| param.location == location)
+            .map(|param| format!("'{}': {}", param.name, parameter_value(param)))
+            .collect();
+        if !values.is_empty() {
+            request_arguments.push(format!("{}: {{{}}}", label, values.join(", ")));
+        }
+    }
+    if has_body {
+        request_arguments.push(match body {
+            Some(body) if body.models().is_empty() => "body: body".to_string(),
+            Some(body) if body_required => format!("body: {}", body.encode("body")),
+            Some(body) => format!("body: body == null ? null : {}", body.encode("body")),
+            None => "body: body".to_string(),
+        });
+        if let Some(media_type) = body_media_type(swagger, method, operation) {
+            request_arguments.push(format!("contentType: '{}'", media_type));
+        }
+    }
+    if !form.is_empty() {
+        // Fields left out are null and not sent.
+        let fields: Vec<String> = form
+            .iter()
+            .map(|param| match param.required {
+                true => format!("'{}': {}", param.name, argument_name(&param.name)),
+                false => format!("if ({0} != null) '{1}': {0}", argument_name(&param.name), param.name),
+            })
+            .collect();
+        if request_media_type(swagger, operation) == MULTIPART {
+            request_arguments.push(format!("body: FormData.fromMap({{{}}}, ListFormat.multi)", fields.join(", ")));
+        } else {
+            request_arguments.push(format!("body: <String, dynamic>{{{}}}", fields.join(", ")));
+            request_arguments.push("contentType: Headers.formUrlEncodedContentType".to_string());
+        }
+    }
+    let binary = has_binary_response(swagger, operation);
+    if binary {
+        request_arguments.push("responseType: ResponseType.bytes".to_string());
+    }
+    if !swagger.security_definitions.is_empty() {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+                format!("[{}]", names.join(", "))
+            })
+            .collect();
+        if !requirements.is_empty() {
+            request_arguments.push(format!("requirements: [{}]", requirements.join(", ")));
+        }
+    }
+    let request = format!("_request({})", request_arguments.join(", "));
+
+    let mut dart_code = String::new();
+    if let Some(summary) = &operation.summary {
+        dart_code.push_str(&format!("  /// {}\n", summary));
+    }
+    if operation.deprecated {
+        dart_code.push_str("  @deprecated\n");
+    }
+    match response_type(swagger, operation) {
+        _ if binary => {
+            dart_code.push_str(&format!(
+                "  Future<List<int>> {}({}) async {{
+    final data = await {};
+    return data as List<int>;
+  }}\n",
+                method_name,
+                arguments.join(", "),
+                request
+            ));
+        }
+        Some(model) => {
+            dart_code.push_str(&format!(
+                "  Future<{}> {}({}) async {{
+    final data = await {};
+    return {};
+  }}\n",
+                model.name(),
+                method_name,
+                arguments.join(", "),
+                request,
+                model.decode("data", false)
+            ));
+        }
+        None => {
+            dart_code.push_str(&format!(
+                "  Future<dynamic> {}({}) => {};\n",
+                method_name,
+                arguments.join(", "),
+                request
+            ));
+        }
+    }
+    dart_code
+}
+
+/// The value a parameter is sent as: its argument, with a list joined by the delimiter of its
+/// `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    let argument = argument_name(&param.name);
+    match collection_delimiter(param) {
+        Some(delimiter) => {
+            let optional = if param.required { "" } else { "?" };
+            format!("{}{}.join('{}')", argument, optional, delimiter.replace('\t', "\\t"))
+        }
+        None => argument,
+    }
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("file") => "MultipartFile".to_string(),
+        Some("array") => {
+            let items = param.items.as_ref().and_then(|items| items.schema_type.as_deref());
+            format!("List<{}>", primitive_type(items).name())
+        }
+        other => primitive_type(other).name(),
+    }
+}
+
+/// The type of the definition `reference` points to: an enum for the definitions that become one,
+/// a model class otherwise.
+fn reference_type(swagger: &Swagger, reference: &str) -> DartType {
+    let name = ref_name(reference);
+    match swagger.definitions.get(name).and_then(enum_values) {
+        Some(_) => DartType::Enum(type_name(name)),
+        None => DartType::Model(type_name(name)),
+    }
+}
+
+/// The type a method returns: the model of the first 2xx response or a list of its items.
+fn response_type(swagger: &Swagger, operation: &Operation) -> Option<DartType> {
+    match (response_reference(operation), response_items(operation)) {
+        (Some(reference), _) => Some(reference_type(swagger, reference)),
+        (None, Some(items)) => Some(DartType::List(Box::new(spec_type(swagger, items)))),
+        (None, None) => None,
+    }
+}
+
+/// The type of the body a method sends as JSON, from its schema.
+fn body_type(swagger: &Swagger, method: &str, operation: &Operation) -> Option<DartType> {
+    let body = operation.parameters.iter().find(|param| param.location == "body")?;
+    let json = has_request_body(method) && form_parameters(operation).is_empty();
+    let schema = body.schema.as_ref().filter(|_| json && !sends_raw_body(swagger, method, operation))?;
+    Some(spec_type(swagger, schema))
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `String` for strings and
+/// parameters the operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref())) {
+        Some(DartType::Primitive(dart_type @ ("int" | "double" | "bool"))) => dart_type,
+        _ => "String",
+    }
+}
+
+fn spec_type(swagger: &Swagger, schema: &Schema) -> DartType {
+    if let Some(reference) = &schema.reference {
+        return reference_type(swagger, reference);
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => {
+            let items = schema.items.as_deref();
+            DartType::List(Box::new(items.map_or(DartType::Dynamic, |items| spec_type(swagger, items))))
+        }
+        other => primitive_type(other),
+    }
+}
+
+fn property_type(swagger: &Swagger, prop: &Property) -> DartType {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => DartType::List(Box::new(
+            prop.additional.get("items").map_or(DartType::Dynamic, |items| schema_type(swagger, items)),
+        )),
+        other => primitive_type(other),
+    }
+}
+
+fn schema_type(swagger: &Swagger, schema: &Value) -> DartType {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => {
+            DartType::List(Box::new(schema.get("items").map_or(DartType::Dynamic, |items| schema_type(swagger, items))))
+        }
+        other => primitive_type(other),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>) -> DartType {
+    match schema_type {
+        Some("integer") => DartType::Primitive("int"),
+        Some("number") => DartType::Primitive("double"),
+        Some("string") => DartType::Primitive("String"),
+        Some("boolean") => DartType::Primitive("bool"),
+        Some("object") => DartType::Primitive("Map<String, dynamic>"),
+        _ => DartType::Dynamic,
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn file_name(name: &str) -> String {
+    snake_case(&type_name(name))
+}
+
+fn field_name(name: &str) -> String {
+    let type_name = type_name(name);
+    let mut chars = type_name.chars();
+    let field: String = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    };
+    if KEYWORDS.contains(&field.as_str()) {
+        format!("{}_", field)
+    } else {
+        field
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    match field_name(name) {
+        argument if argument == "body" => "bodyParam".to_string(),
+        argument => argument,
+    }
+}
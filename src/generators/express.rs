@@ -0,0 +1,378 @@
+use super::mock::express_path;
+use super::typescript::{
+    generate_info_comment, generate_interfaces, import_interfaces, non_json_response, parameter_type, property_name,
+    schema_definition, schema_type, service_method_name, string_literal, TypeOptions,
+};
+use super::{collection_delimiter, operations_by_tag, pascal_case, with_custom_region};
+use crate::cli::Validators;
+use crate::output::GeneratedFiles;
+use crate::spec::{Operation, Parameter, Swagger};
+use std::path::PathBuf;
+
+/// What the router does with every request before the operation sees it: converting the path
+/// and query parameters to their types and checking them and the body against the spec.
+const VALIDATION: &str = "type ParameterType = 'string' | 'integer' | 'number' | 'boolean' | 'array';
+
+interface ParameterSpec {
+    name: string;
+    in: 'path' | 'query';
+    type: ParameterType;
+    /** The type of the items of an array, which are sent repeated unless joined by `delimiter`. */
+    items?: ParameterType;
+    /** The delimiter the items of an array are joined with by its `collectionFormat`. */
+    delimiter?: string;
+    required: boolean;
+    enum?: unknown[];
+}
+
+interface BodySpec {
+    required: boolean;
+    check?: (value: unknown) => boolean;
+}
+
+/** The status and body an operation answers with. */
+interface Reply {
+    status: number;
+    body?: unknown;
+}
+
+function convert(value: string, type: ParameterType): unknown {
+    switch (type) {
+        case 'integer':
+            return /^-?\\d+$/.test(value) ? Number(value) : undefined;
+        case 'number':
+            return value.trim() !== '' && !Number.isNaN(Number(value)) ? Number(value) : undefined;
+        case 'boolean':
+            return value === 'true' ? true : value === 'false' ? false : undefined;
+        default:
+            return value;
+    }
+}
+
+/**
+ * Converts the parameters of each request and checks them and its body against the spec,
+ * answering 400 with the problems found or leaving the converted request in `res.locals.request`.
+ */
+function validate(parameters: ParameterSpec[], body?: BodySpec): RequestHandler {
+    return (req, res, next) => {
+        const errors: string[] = [];
+        const request: { path: Record<string, unknown>; query: Record<string, unknown>; body?: unknown } = {
+            path: {},
+            query: {},
+        };
+        for (const parameter of parameters) {
+            const raw = parameter.in === 'path' ? req.params[parameter.name.replace(/\\W/g, '_')] : req.query[parameter.name];
+            if (raw === undefined || raw === '') {
+                if (parameter.required) {
+                    errors.push(`${parameter.in} parameter ${parameter.name} is required`);
+                }
+                continue;
+            }
+            const texts = (Array.isArray(raw) ? raw : [raw]).map(String);
+            const items = parameter.type !== 'array'
+                ? texts.slice(-1)
+                : texts.flatMap((text) => (parameter.delimiter === undefined ? [text] : text.split(parameter.delimiter)));
+            const values = items.map((item) => convert(item, parameter.items ?? parameter.type));
+            if (values.some((value) => value === undefined)) {
+                errors.push(`${parameter.in} parameter ${parameter.name} must be of type ${parameter.items ?? parameter.type}`);
+            } else if (parameter.enum && values.some((value) => !parameter.enum?.includes(value))) {
+                errors.push(`${parameter.in} parameter ${parameter.name} must be one of ${parameter.enum.join(', ')}`);
+            } else {
+                request[parameter.in][parameter.name] = parameter.type === 'array' ? values : values[0];
+            }
+        }
+        if (body && req.is('json')) {
+            if (body.check && !body.check(req.body)) {
+                errors.push('request body does not match its schema');
+            }
+            request.body = req.body;
+        } else if (body?.required) {
+            errors.push('request body is required');
+        }
+        if (errors.length > 0) {
+            res.status(400).json({ message: 'invalid request', errors });
+            return;
+        }
+        res.locals.request = request;
+        next();
+    };
+}
+
+/** Answers each request with the reply of `operation`, passing its failures on to the error handlers. */
+function handle<T>(operation: (request: T) => Promise<Reply>, content: 'json' | 'text' = 'json'): RequestHandler {
+    return (_req, res, next) => {
+        operation(res.locals.request).then((reply) => {
+            res.status(reply.status);
+            if (reply.body === undefined) {
+                res.end();
+            } else if (Buffer.isBuffer(reply.body)) {
+                res.type('application/octet-stream').send(reply.body);
+            } else if (content === 'text') {
+                res.type('text/plain').send(String(reply.body));
+            } else {
+                res.json(reply.body);
+            }
+        }, next);
+    };
+}
+";
+
+/// Writes the interfaces, with a validator per definition, and `server.ts`: the request and
+/// response types of every operation, an interface per tag for the server to implement and a
+/// `createRouter` routing the validated requests to it. The validators are the type guards of
+/// `--validators guards` unless Zod schemas are asked for.
+pub fn generate(swagger: &Swagger, types: TypeOptions) -> GeneratedFiles {
+    let validators = types.validators.unwrap_or(Validators::Guards);
+    let mut files = GeneratedFiles::new();
+    generate_interfaces(swagger, TypeOptions { validators: Some(validators), ..types }, &mut files);
+    files.insert(PathBuf::from("server.ts"), with_custom_region(generate_server(swagger, validators), "//"));
+    files
+}
+
+struct Route<'a> {
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+    /// The name of the method implementing the operation.
+    name: String,
+    content: Option<&'static str>,
+}
+
+impl Route<'_> {
+    fn type_name(&self, suffix: &str) -> String {
+        format!("{}{}", pascal_case(&self.name), suffix)
+    }
+
+    fn parameters(&self, location: &str) -> Vec<&Parameter> {
+        self.operation.parameters.iter().filter(|param| param.location == location).collect()
+    }
+
+    fn body(&self) -> Option<&Parameter> {
+        self.operation.parameters.iter().find(|param| param.location == "body")
+    }
+
+    /// Whether the method takes a request, which is when the operation has path or query
+    /// parameters or a body.
+    fn has_request(&self) -> bool {
+        self.operation.parameters.iter().any(|param| matches!(param.location.as_str(), "path" | "query" | "body"))
+    }
+
+    /// The request and response types of the operation.
+    fn push_types(&self, ts_code: &mut String) {
+        if self.has_request() {
+            ts_code.push_str(&format!(
+                "\n/** The request of `{}`, with its parameters converted to their types. */\n",
+                self.name
+            ));
+            ts_code.push_str(&format!("export interface {} {{\n", self.type_name("Request")));
+            for location in ["path", "query"] {
+                let params = self.parameters(location);
+                if params.is_empty() {
+                    continue;
+                }
+                let members: Vec<String> = params
+                    .iter()
+                    .map(|param| {
+                        let optional = if param.required || location == "path" { "" } else { "?" };
+                        format!("{}{}: {}", property_name(&param.name), optional, parameter_type(param))
+                    })
+                    .collect();
+                ts_code.push_str(&format!("    {}: {{ {} }};\n", location, members.join("; ")));
+            }
+            if let Some(body) = self.body() {
+                let body_type = body.schema.as_ref().map_or("unknown".to_string(), schema_type);
+                ts_code.push_str(&format!("    body{}: {};\n", if body.required { "" } else { "?" }, body_type));
+            }
+            ts_code.push_str("}\n");
+        }
+
+        let mut responses: Vec<_> = self.operation.responses.iter().collect();
+        responses.sort_by_key(|(status, _)| !status.starts_with('2'));
+        let mut replies: Vec<String> = responses
+            .into_iter()
+            .map(|(status, response)| {
+                let status_type = if status.parse::<u16>().is_ok() { status.as_str() } else { "number" };
+                let body = match (&response.response_schema, status.starts_with('2'), self.content) {
+                    (_, true, Some("blob")) => Some("Buffer".to_string()),
+                    (_, true, Some(_)) => Some("string".to_string()),
+                    (Some(schema), _, _) => Some(schema_type(schema)),
+                    (None, _, _) => None,
+                };
+                match body {
+                    Some(body) => format!("{{ status: {}; body: {} }}", status_type, body),
+                    None => format!("{{ status: {} }}", status_type),
+                }
+            })
+            .collect();
+        if replies.is_empty() {
+            replies.push("{ status: 200 }".to_string());
+        }
+        ts_code.push_str(&format!("\n/** The responses of `{}`. */\n", self.name));
+        ts_code.push_str(&format!("export type {} =\n", self.type_name("Response")));
+        ts_code.push_str(&format!("    | {};\n", replies.join("\n    | ")));
+    }
+
+    /// The specs of the parameters `validate` converts and checks.
+    fn push_parameter_specs(&self, ts_code: &mut String) {
+        ts_code.push_str(&format!("\nconst {}Parameters: ParameterSpec[] = [", self.name));
+        let params: Vec<&Parameter> = self.parameters("path").into_iter().chain(self.parameters("query")).collect();
+        if params.is_empty() {
+            ts_code.push_str("];\n");
+            return;
+        }
+        ts_code.push('\n');
+        for param in params {
+            let mut fields = vec![
+                format!("name: {}", string_literal(&param.name)),
+                format!("in: '{}'", param.location),
+                format!("type: '{}'", spec_type(param.parameter_type.as_deref())),
+            ];
+            if param.parameter_type.as_deref() == Some("array") {
+                let items = param.items.as_ref().and_then(|items| items.schema_type.as_deref());
+                fields.push(format!("items: '{}'", spec_type(items)));
+            }
+            if let Some(delimiter) = collection_delimiter(param) {
+                fields.push(format!("delimiter: {}", string_literal(&delimiter.escape_default().to_string())));
+            }
+            fields.push(format!("required: {}", param.required || param.location == "path"));
+            let values =
+                param.enum_values.as_ref().or(param.items.as_ref().and_then(|items| items.enum_values.as_ref()));
+            if let Some(values) = values {
+                let values: Vec<String> = values.iter().map(|value| value.to_string().replace('"', "'")).collect();
+                fields.push(format!("enum: [{}]", values.join(", ")));
+            }
+            ts_code.push_str(&format!("    {{ {} }},\n", fields.join(", ")));
+        }
+        ts_code.push_str("];\n");
+    }
+
+    /// The spec of the body `validate` checks, if the operation takes one.
+    fn body_spec(&self, validators: Validators) -> Option<String> {
+        let body = self.body()?;
+        let schema = body.schema.as_ref();
+        let definition = schema.and_then(schema_definition);
+        let array = schema.is_some_and(|schema| schema.reference.is_none());
+        let check = definition.map(|name| match (validators, array) {
+            (Validators::Guards, false) => format!("is{}", name),
+            (Validators::Guards, true) => format!("(value) => Array.isArray(value) && value.every(is{})", name),
+            (Validators::Zod, false) => format!("(value) => {}Schema.safeParse(value).success", name),
+            (Validators::Zod, true) => format!("(value) => {}Schema.array().safeParse(value).success", name),
+        });
+        Some(match check {
+            Some(check) => format!("{{ required: {}, check: {} }}", body.required, check),
+            None => format!("{{ required: {} }}", body.required),
+        })
+    }
+}
+
+/// The type `validate` converts a parameter of `schema_type` to.
+fn spec_type(schema_type: Option<&str>) -> &'static str {
+    match schema_type {
+        Some("integer") => "integer",
+        Some("number") => "number",
+        Some("boolean") => "boolean",
+        Some("array") => "array",
+        _ => "string",
+    }
+}
+
+fn generate_server(swagger: &Swagger, validators: Validators) -> String {
+    let groups: Vec<(String, Vec<Route>)> = operations_by_tag(swagger)
+        .into_iter()
+        .map(|(tag, operations)| {
+            let routes = operations
+                .into_iter()
+                .map(|(path, method, operation)| Route {
+                    method,
+                    path,
+                    operation,
+                    name: service_method_name(method, path, operation),
+                    content: non_json_response(swagger, operation),
+                })
+                .collect();
+            (tag, routes)
+        })
+        .collect();
+    let routes = || groups.iter().flat_map(|(_, routes)| routes);
+
+    let mut interfaces = Vec::new();
+    let mut checks = Vec::new();
+    for route in routes() {
+        let schemas = route.operation.responses.values().filter_map(|response| response.response_schema.as_ref());
+        let body = route.body().and_then(|body| body.schema.as_ref());
+        interfaces.extend(schemas.chain(body).filter_map(schema_definition).map(str::to_string));
+        checks.extend(body.and_then(schema_definition).map(|name| match validators {
+            Validators::Guards => format!("is{}", name),
+            Validators::Zod => format!("{}Schema", name),
+        }));
+    }
+    checks.sort();
+    checks.dedup();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { json, RequestHandler, Router } from 'express';\n");
+    if !checks.is_empty() {
+        let module = if validators == Validators::Zod { "./schemas" } else { "./guards" };
+        ts_code.push_str(&format!("import {{ {} }} from '{}';\n", checks.join(", "), module));
+    }
+    import_interfaces(&mut ts_code, interfaces, "./interfaces");
+    if !ts_code.ends_with("\n\n") {
+        ts_code.push('\n');
+    }
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    ts_code.push_str("/** The path to mount the router at: `app.use(BASE_PATH || '/', createRouter(api))`. */\n");
+    ts_code.push_str(&format!("export const BASE_PATH = '{}';\n", base_path));
+
+    for route in routes() {
+        route.push_types(&mut ts_code);
+    }
+    let mut traits = Vec::new();
+    for (tag, routes) in &groups {
+        let interface = format!("{}Api", pascal_case(tag));
+        ts_code
+            .push_str(&format!("\n/** The operations tagged `{}`, which `createRouter` routes requests to. */\n", tag));
+        ts_code.push_str(&format!("export interface {} {{\n", interface));
+        for route in routes {
+            ts_code.push_str(&format!("    /** {} {} */\n", route.method.to_uppercase(), route.path));
+            let request =
+                if route.has_request() { format!("request: {}", route.type_name("Request")) } else { String::new() };
+            ts_code.push_str(&format!("    {}({}): Promise<{}>;\n", route.name, request, route.type_name("Response")));
+        }
+        ts_code.push_str("}\n");
+        traits.push(interface);
+    }
+
+    ts_code.push('\n');
+    ts_code.push_str(VALIDATION);
+    for route in routes() {
+        route.push_parameter_specs(&mut ts_code);
+    }
+
+    let api = if traits.is_empty() { "unknown".to_string() } else { traits.join(" & ") };
+    ts_code.push_str("\n/** Routes every operation of the spec to `api`, answering the invalid requests itself. */\n");
+    ts_code.push_str(&format!("export function createRouter(api: {}): Router {{\n", api));
+    ts_code.push_str("    const router = Router();\n");
+    ts_code.push_str("    router.use(json());\n");
+    for route in routes() {
+        let mut validation = vec![format!("{}Parameters", route.name)];
+        validation.extend(route.body_spec(validators));
+        let call = if route.has_request() {
+            format!("handle<{}>((request) => api.{}(request)", route.type_name("Request"), route.name)
+        } else {
+            format!("handle(() => api.{}()", route.name)
+        };
+        let content = if route.content == Some("text") { ", 'text'" } else { "" };
+        ts_code.push_str(&format!(
+            "    router.{}('{}', validate({}), {}{}));\n",
+            route.method,
+            express_path(route.path),
+            validation.join(", "),
+            call,
+            content
+        ));
+    }
+    ts_code.push_str("    return router;\n");
+    ts_code.push_str("}\n");
+    ts_code
+}
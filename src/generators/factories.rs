@@ -0,0 +1,582 @@
+use super::pascal_case;
+use super::typescript::{
+    base_interfaces, discriminator_tag, enum_member, extends_definition, generate_info_comment, has_flag,
+    import_interfaces, inline_properties, map_values, nested_definition, property_name, string_literal, TypeOptions,
+};
+use crate::cli::{DateType, EnumStyle, Int64Type};
+use crate::spec::{ref_name, Definition, Property, Swagger};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+
+/// The helpers the factories make values up with, each with the helpers it calls, in the order
+/// they are written.
+const HELPERS: &[(&str, &[&str], &str)] = &[
+    (
+        "integer",
+        &[],
+        "/** An integer between `min` and `max`, both included. */
+function integer(min: number, max: number): number {
+    return min + Math.floor(random() * (max - min + 1));
+}
+",
+    ),
+    (
+        "decimal",
+        &[],
+        "/** A number between `min` and `max` with two decimals. */
+function decimal(min: number, max: number): number {
+    return Math.round((min + random() * (max - min)) * 100) / 100;
+}
+",
+    ),
+    (
+        "boolean",
+        &[],
+        "function boolean(): boolean {
+    return random() < 0.5;
+}
+",
+    ),
+    (
+        "pick",
+        &[],
+        "function pick<T>(values: readonly T[]): T {
+    return values[Math.floor(random() * values.length)];
+}
+",
+    ),
+    (
+        "word",
+        &["pick"],
+        "const WORDS = [
+    'amber', 'birch', 'cedar', 'delta', 'ember', 'falcon', 'garnet', 'harbor', 'indigo', 'juniper', 'kestrel', 'lumen',
+    'meadow', 'nimbus', 'orchid', 'pepper', 'quartz', 'river', 'sierra', 'tundra', 'umber', 'violet', 'willow', 'zephyr',
+] as const;
+
+function word(): string {
+    return pick(WORDS);
+}
+",
+    ),
+    (
+        "text",
+        &["integer", "word"],
+        "/** Words joined into a string between `min` and `max` characters long. */
+function text(min: number, max: number): string {
+    const length = integer(min, max);
+    let value = word();
+    while (value.length < length) {
+        value += ' ' + word();
+    }
+    return value.slice(0, length);
+}
+",
+    ),
+    (
+        "dateTime",
+        &[],
+        "/** A moment between 2020 and 2024 in ISO 8601, e.g. `2022-03-14T09:26:53Z`. */
+function dateTime(): string {
+    const seconds = Math.floor(random() * 5 * 365 * 24 * 60 * 60);
+    return new Date(Date.UTC(2020, 0, 1) + seconds * 1000).toISOString().replace('.000Z', 'Z');
+}
+",
+    ),
+    (
+        "date",
+        &["dateTime"],
+        "function date(): string {
+    return dateTime().slice(0, 10);
+}
+",
+    ),
+    (
+        "time",
+        &["dateTime"],
+        "function time(): string {
+    return dateTime().slice(11, 19);
+}
+",
+    ),
+    (
+        "uuid",
+        &["integer"],
+        "function uuid(): string & { readonly __brand: 'uuid' } {
+    const digits = 'xxxxxxxx-xxxx-4xxx-yxxx-xxxxxxxxxxxx'.replace(/[xy]/g, (digit) =>
+        (digit === 'x' ? integer(0, 15) : integer(8, 11)).toString(16),
+    );
+    return digits as string & { readonly __brand: 'uuid' };
+}
+",
+    ),
+    (
+        "email",
+        &["word"],
+        "function email(): string {
+    return `${word()}.${word()}@example.com`;
+}
+",
+    ),
+    (
+        "url",
+        &["word"],
+        "function url(): string {
+    return `https://example.com/${word()}`;
+}
+",
+    ),
+    (
+        "hostname",
+        &["word"],
+        "function hostname(): string {
+    return `${word()}.example.com`;
+}
+",
+    ),
+    (
+        "ipv4",
+        &["integer"],
+        "function ipv4(): string {
+    return `192.0.2.${integer(1, 254)}`;
+}
+",
+    ),
+    (
+        "ipv6",
+        &["integer"],
+        "function ipv6(): string {
+    return `2001:db8::${integer(1, 65535).toString(16)}`;
+}
+",
+    ),
+];
+
+/// Makes the values the factories return up from `seed`, the state of a mulberry32 generator.
+const RANDOM: &str = "let seed = 1;
+
+/** Restarts the values the factories make up from `value`, so that a test sees the same ones on every run. */
+export function seedMocks(value: number) {
+    seed = value;
+}
+
+/** A number from 0 included to 1 excluded. */
+function random(): number {
+    seed = (seed + 0x6d2b79f5) | 0;
+    let t = Math.imul(seed ^ (seed >>> 15), seed | 1);
+    t ^= t + Math.imul(t ^ (t >>> 7), t | 61);
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+}
+";
+
+/// `factories.ts`: a `mock<Name>` function per definition making up a value of its interface,
+/// with values of the formats, enums and bounds its schema declares, which objects merge the
+/// `overrides` given into. Definitions referencing themselves leave the properties closing the
+/// cycle out, or empty, so the factories end.
+pub fn generate_factories(swagger: &Swagger, types: TypeOptions, interfaces: &str) -> String {
+    let mut names: Vec<&String> = swagger.definitions.keys().collect();
+    names.sort();
+
+    let mut factories = Factories { swagger, types, helpers: BTreeSet::new(), imports: Vec::new() };
+    let mut functions = String::new();
+    for name in &names {
+        functions.push('\n');
+        functions.push_str(&factories.function(name, &swagger.definitions[name.as_str()]));
+    }
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    let mut imports: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+    imports.append(&mut factories.imports);
+    import_interfaces(&mut ts_code, imports, interfaces);
+    ts_code.push_str(RANDOM);
+    let mut used = factories.helpers;
+    for (name, calls, _) in HELPERS.iter().rev() {
+        if used.contains(name) {
+            used.extend(calls.iter());
+        }
+    }
+    for (name, _, code) in HELPERS {
+        if used.contains(name) {
+            ts_code.push('\n');
+            ts_code.push_str(code);
+        }
+    }
+    ts_code.push_str(&functions);
+    ts_code
+}
+
+/// The name of the factory of a definition, e.g. `mockPet`.
+fn factory_name(name: &str) -> String {
+    format!("mock{}", name)
+}
+
+/// Whether `from` reaches `to` through the `$ref`s of the definitions.
+fn references_definition(swagger: &Swagger, from: &str, to: &str) -> bool {
+    let mut pending = vec![from.to_string()];
+    let mut visited = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if name == to {
+            return true;
+        }
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        if let Some(definition) = swagger.definitions.get(&name).and_then(|d| serde_json::to_value(d).ok()) {
+            collect_references(&definition, &mut pending);
+        }
+    }
+    false
+}
+
+fn collect_references(schema: &Value, names: &mut Vec<String>) {
+    match schema {
+        Value::Object(fields) => {
+            for (key, value) in fields {
+                match value.as_str() {
+                    Some(reference) if key == "$ref" => names.push(ref_name(reference).to_string()),
+                    _ => collect_references(value, names),
+                }
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|item| collect_references(item, names)),
+        _ => {}
+    }
+}
+
+struct Factories<'a> {
+    swagger: &'a Swagger,
+    types: TypeOptions,
+    /// The helpers the factories written so far call.
+    helpers: BTreeSet<&'static str>,
+    /// The `enum`s the factories written so far name.
+    imports: Vec<String>,
+}
+
+/// What a value is made up for: the definition whose factory makes it, and the `enum` declared
+/// for it when it is a property with `enum` values.
+struct Context<'a> {
+    definition: &'a str,
+    enum_name: Option<String>,
+}
+
+impl Factories<'_> {
+    fn function(&mut self, name: &str, definition: &Definition) -> String {
+        let factory = factory_name(name);
+        let is_object = definition.reference.is_none()
+            && definition.enum_values.is_none()
+            && definition.one_of.is_none()
+            && definition.any_of.is_none()
+            && (definition.properties.is_some() || definition.all_of.is_some());
+        if !is_object {
+            let value = self.definition(name, definition, Some(name));
+            return format!("export function {}(): {} {{\n    return {};\n}}\n", factory, name, value);
+        }
+
+        let mut fields: Vec<String> = base_interfaces(definition)
+            .into_iter()
+            .filter(|base| !extends_definition(self.swagger, base, name))
+            .filter(|base| self.swagger.definitions.contains_key(*base))
+            .map(|base| format!("...{}()", factory_name(base)))
+            .collect();
+        let (properties, complete) = self.properties(definition, name, name, "        ");
+        fields.extend(properties);
+        fields.push("...overrides".to_string());
+
+        let mut ts_code = format!("export function {}(overrides: Partial<{}> = {{}}): {} {{\n", factory, name, name);
+        ts_code.push_str("    return {\n");
+        for field in fields {
+            ts_code.push_str(&format!("        {},\n", field));
+        }
+        // Required properties closing a cycle are left out, so the object is only cast to the interface.
+        ts_code.push_str(&if complete { "    };\n".to_string() } else { format!("    }} as {};\n", name) });
+        ts_code.push_str("}\n");
+        ts_code
+    }
+
+    /// The value of a definition that is not an object of properties, or of a member of one of
+    /// its unions. `enum_name` is the `enum` declared for it, which union members have none of.
+    fn definition(&mut self, name: &str, definition: &Definition, enum_name: Option<&str>) -> String {
+        if let Some(reference) = &definition.reference {
+            return self.reference(name, ref_name(reference)).unwrap_or_else(|| "null as never".to_string());
+        }
+        if let Some(values) = &definition.enum_values {
+            return self.pick(values, enum_name);
+        }
+        if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+            let Some(member) = members.first() else {
+                return "null".to_string();
+            };
+            let value = self.definition(name, member, None);
+            return match discriminator_tag(definition, member) {
+                Some((property, tag)) => {
+                    format!("{{ ...{}, {}: {} }}", value, property_name(property), string_literal(tag))
+                }
+                None => value,
+            };
+        }
+        if definition.properties.is_some() || definition.all_of.is_some() {
+            let (properties, _) = self.properties(definition, name, "", "        ");
+            return object_literal(properties, "    ");
+        }
+        if let Some(values) = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false)) {
+            return self.map(values, &Context { definition: name, enum_name: None }, "    ");
+        }
+        let property = Property {
+            property_type: definition.definition_type.clone(),
+            format: None,
+            additional: Default::default(),
+        };
+        self.property(&property, &Context { definition: name, enum_name: None }, "    ")
+    }
+
+    /// The fields of the inline properties of `definition`, and whether all its required ones are
+    /// there. `prefix` names the `enum`s declared for the properties, as the interfaces do.
+    fn properties(&mut self, definition: &Definition, name: &str, prefix: &str, indent: &str) -> (Vec<String>, bool) {
+        let mut fields = Vec::new();
+        let mut complete = true;
+        for (prop_name, prop, required) in inline_properties(definition) {
+            let hoisted_name = format!("{}{}", prefix, pascal_case(prop_name));
+            match self.field(prop, name, &hoisted_name, !prefix.is_empty(), indent) {
+                Some(value) => fields.push(format!("{}: {}", property_name(prop_name), value)),
+                None => complete &= !required,
+            }
+        }
+        (fields, complete)
+    }
+
+    /// The value of a property, or `None` when it would make the factory of `definition` call
+    /// itself and cannot be left empty.
+    fn field(
+        &mut self,
+        prop: &Property,
+        definition: &str,
+        hoisted_name: &str,
+        hoisted: bool,
+        indent: &str,
+    ) -> Option<String> {
+        if let Some(nested) = nested_definition(prop) {
+            let prefix = if hoisted { hoisted_name } else { "" };
+            let (properties, _) = self.properties(&nested, definition, prefix, &format!("{}    ", indent));
+            return Some(object_literal(properties, indent));
+        }
+        let enum_name = hoisted.then(|| hoisted_name.to_string());
+        let context = Context { definition, enum_name };
+        let cyclic = |reference: Option<&Value>| {
+            reference
+                .and_then(Value::as_str)
+                .is_some_and(|reference| references_definition(self.swagger, ref_name(reference), definition))
+        };
+        if cyclic(prop.additional.get("$ref")) {
+            let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+            return nullable.then(|| "null".to_string());
+        }
+        if prop.property_type.as_deref() == Some("array")
+            && cyclic(prop.additional.get("items").and_then(|i| i.get("$ref")))
+        {
+            return Some("[]".to_string());
+        }
+        if map_values(prop).is_some_and(|values| cyclic(values.get("$ref"))) {
+            return Some("{}".to_string());
+        }
+        Some(self.property(prop, &context, indent))
+    }
+
+    fn property(&mut self, prop: &Property, context: &Context, indent: &str) -> String {
+        let field = |key: &str| prop.additional.get(key).filter(|value| !value.is_null());
+        if let Some(reference) = field("$ref").and_then(Value::as_str) {
+            return self.reference(context.definition, ref_name(reference)).unwrap_or_else(|| "null".to_string());
+        }
+        if let Some(values) = field("enum").and_then(Value::as_array) {
+            return self.pick(values, context.enum_name.as_deref());
+        }
+        if let Some(example) = field("example").filter(|example| self.keeps_example(prop, example)) {
+            return literal(example);
+        }
+        if let Some(nested) = nested_definition(prop) {
+            let (properties, _) = self.properties(&nested, context.definition, "", &format!("{}    ", indent));
+            return object_literal(properties, indent);
+        }
+        if let Some(values) = map_values(prop) {
+            return self.map(values, context, indent);
+        }
+
+        let number = |key: &str| field(key).and_then(Value::as_f64);
+        let exclusive = |key: &str, bound: &str| match field(key) {
+            Some(Value::Bool(exclusive)) => number(bound).filter(|_| *exclusive).map(|bound| (bound, true)),
+            Some(Value::Number(bound)) => bound.as_f64().map(|bound| (bound, true)),
+            _ => None,
+        };
+        let minimum = exclusive("exclusiveMinimum", "minimum").or_else(|| number("minimum").map(|min| (min, false)));
+        let maximum = exclusive("exclusiveMaximum", "maximum").or_else(|| number("maximum").map(|max| (max, false)));
+        let format = prop.format.as_deref();
+        match prop.property_type.as_deref() {
+            Some("string") => {
+                let (min, max) = length_bounds(number("minLength"), number("maxLength"), 6, 24);
+                let helper = match format {
+                    Some("date") | Some("date-time") if self.types.date_type == DateType::Date => {
+                        let helper = if format == Some("date") { "date" } else { "dateTime" };
+                        return format!("new Date({})", self.call(helper, ""));
+                    }
+                    Some("date") => "date",
+                    Some("date-time") => "dateTime",
+                    Some("time") => "time",
+                    Some("uuid") => "uuid",
+                    Some("email") => "email",
+                    Some("uri") | Some("url") => "url",
+                    Some("hostname") => "hostname",
+                    Some("ipv4") => "ipv4",
+                    Some("ipv6") => "ipv6",
+                    Some("binary") => {
+                        return format!("new Blob([{}])", self.call("text", &format!("{}, {}", min, max)));
+                    }
+                    Some("byte") => {
+                        return format!(
+                            "new TextEncoder().encode({})",
+                            self.call("text", &format!("{}, {}", min, max))
+                        );
+                    }
+                    _ => return self.call("text", &format!("{}, {}", min, max)),
+                };
+                self.call(helper, "")
+            }
+            Some("integer") => {
+                let (min, max) = bounds(minimum, maximum, 1.0, 1000.0, 1.0);
+                let (min, max) = (min.ceil(), max.floor());
+                let value = match number("multipleOf").filter(|step| *step >= 1.0) {
+                    Some(step) => format!(
+                        "{} * {}",
+                        self.call("integer", &format!("{}, {}", (min / step).ceil(), (max / step).floor())),
+                        step
+                    ),
+                    None => self.call("integer", &format!("{}, {}", min, max)),
+                };
+                match (format, self.types.int64_type) {
+                    (Some("int64"), Int64Type::Bigint) => format!("BigInt({})", value),
+                    (Some("int64"), Int64Type::String) => format!("String({})", value),
+                    _ => value,
+                }
+            }
+            Some("number") => {
+                let (min, max) = bounds(minimum, maximum, 0.0, 1000.0, 0.01);
+                self.call("decimal", &format!("{}, {}", min, max))
+            }
+            Some("boolean") => self.call("boolean", ""),
+            Some("array") => {
+                let item = field("items")
+                    .and_then(|items| serde_json::from_value::<Property>(items.clone()).ok())
+                    .map(|items| self.property(&items, context, indent));
+                let (min, max) = length_bounds(number("minItems"), number("maxItems"), 1, 3);
+                match item {
+                    Some(item) => {
+                        format!(
+                            "Array.from({{ length: {} }}, () => {})",
+                            self.call("integer", &format!("{}, {}", min, max)),
+                            item
+                        )
+                    }
+                    None => "[]".to_string(),
+                }
+            }
+            Some("object") => "{}".to_string(),
+            _ => "null".to_string(),
+        }
+    }
+
+    /// Whether the `example` of a property is a value of its type as the interfaces spell it,
+    /// which those of the formats made into other types than strings and numbers are not.
+    fn keeps_example(&self, prop: &Property, example: &Value) -> bool {
+        match (prop.property_type.as_deref(), prop.format.as_deref()) {
+            (Some("string"), Some("date" | "date-time")) => {
+                self.types.date_type != DateType::Date && example.is_string()
+            }
+            (Some("string"), Some("binary" | "byte" | "uuid")) => false,
+            (Some("string"), _) => example.is_string(),
+            (Some("integer"), Some("int64")) => self.types.int64_type == Int64Type::Number && example.is_i64(),
+            (Some("integer"), _) => example.is_i64(),
+            (Some("number"), _) => example.is_number(),
+            (Some("boolean"), _) => example.is_boolean(),
+            _ => false,
+        }
+    }
+
+    /// A value picked from `values`, as members of the `enum` declared as `enum_name` when there
+    /// is one.
+    fn pick(&mut self, values: &[Value], enum_name: Option<&str>) -> String {
+        let members: Option<Vec<&str>> = values.iter().map(Value::as_str).collect();
+        let declared = self.types.enum_style == EnumStyle::Enum && !values.is_empty();
+        let literals = match (enum_name, members) {
+            (Some(enum_name), Some(members)) if declared => {
+                self.imports.push(enum_name.to_string());
+                let members: Vec<String> =
+                    members.iter().map(|member| format!("{}.{}", enum_name, enum_member(member))).collect();
+                return self.call("pick", &format!("[{}]", members.join(", ")));
+            }
+            _ => values.iter().map(literal).collect::<Vec<_>>(),
+        };
+        if literals.is_empty() {
+            return "null".to_string();
+        }
+        self.call("pick", &format!("[{}] as const", literals.join(", ")))
+    }
+
+    /// A call of the factory of the referenced definition, unless it leads back to `definition`.
+    fn reference(&mut self, definition: &str, name: &str) -> Option<String> {
+        let cyclic = references_definition(self.swagger, name, definition);
+        (self.swagger.definitions.contains_key(name) && !cyclic).then(|| format!("{}()", factory_name(name)))
+    }
+
+    /// An object with a single key, for the definitions and properties declaring `additionalProperties`.
+    fn map(&mut self, values: &Value, context: &Context, indent: &str) -> String {
+        let value = match serde_json::from_value::<Property>(values.clone()) {
+            Ok(values) => self.property(&values, &Context { definition: context.definition, enum_name: None }, indent),
+            Err(_) => "null".to_string(),
+        };
+        format!("{{ [{}]: {} }}", self.call("word", ""), value)
+    }
+
+    fn call(&mut self, helper: &'static str, arguments: &str) -> String {
+        self.helpers.insert(helper);
+        format!("{}({})", helper, arguments)
+    }
+}
+
+/// The bounds of a number, moved inside by `step` when exclusive, and defaulting to `min` to `max`.
+fn bounds(minimum: Option<(f64, bool)>, maximum: Option<(f64, bool)>, min: f64, max: f64, step: f64) -> (f64, f64) {
+    let inside = |(bound, exclusive): (f64, bool), step: f64| if exclusive { bound + step } else { bound };
+    let low = minimum.map(|bound| inside(bound, step));
+    let high = maximum.map(|bound| inside(bound, -step));
+    match (low, high) {
+        (Some(low), Some(high)) => (low, high.max(low)),
+        (Some(low), None) => (low, low.max(max)),
+        (None, Some(high)) => (high.min(min), high),
+        (None, None) => (min, max),
+    }
+}
+
+/// The least and greatest length of a string or array, defaulting to `min` to `max`.
+fn length_bounds(minimum: Option<f64>, maximum: Option<f64>, min: u64, max: u64) -> (u64, u64) {
+    let low = minimum.map_or(min, |low| low as u64);
+    let high = maximum.map_or(low.max(max), |high| high as u64);
+    (low.min(high), high)
+}
+
+/// An object literal of `fields`, one per line, closed at `indent`.
+fn object_literal(fields: Vec<String>, indent: &str) -> String {
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    let inner = format!("{}    ", indent);
+    format!(
+        "{{\n{}\n{}}}",
+        fields.iter().map(|field| format!("{}{},", inner, field)).collect::<Vec<_>>().join("\n"),
+        indent
+    )
+}
+
+/// A value of the document as a TypeScript expression on a single line.
+fn literal(value: &Value) -> String {
+    match value {
+        Value::String(value) => string_literal(value),
+        value => value.to_string(),
+    }
+}
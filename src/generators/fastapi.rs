@@ -0,0 +1,495 @@
+use super::python::{identifier, primitive_type, property_type, schema_type};
+use super::typescript::{
+    base_interfaces, has_flag, inline_properties, non_json_response, property_enum, schema_definition,
+};
+use super::{
+    collection_delimiter, info_lines, operation_name, operations_by_tag, pascal_case, snake_case, success_responses,
+    with_custom_region,
+};
+use crate::cli::PythonStyle;
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Schema, Swagger};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Writes a package with the definitions as Pydantic models in `models.py` and, in
+/// `routers.py`, an abstract class per tag with a method per operation for the server to
+/// implement, an `APIRouter` per tag calling them and `create_app` including the routers. The
+/// methods are coroutines with `--python-style async`.
+pub fn generate(swagger: &Swagger, style: PythonStyle) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    let mut init = String::new();
+    push_banner(&mut init, swagger);
+    init.push_str("from .routers import create_app\n\n__all__ = [\"create_app\"]\n");
+    files.insert(PathBuf::from("__init__.py"), init);
+    files.insert(PathBuf::from("models.py"), with_custom_region(generate_models(swagger), "#"));
+    files.insert(PathBuf::from("routers.py"), with_custom_region(generate_routers(swagger, style), "#"));
+    files
+}
+
+fn push_banner(py_code: &mut String, swagger: &Swagger) {
+    for line in info_lines(swagger) {
+        py_code.push_str(&format!("# {}\n", line));
+    }
+    py_code.push('\n');
+}
+
+/// `values` as the arguments of a `Literal`.
+fn literal(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::Bool(true) => "True".to_string(),
+            Value::Bool(false) => "False".to_string(),
+            Value::Null => "None".to_string(),
+            value => value.to_string(),
+        })
+        .collect();
+    format!("Literal[{}]", values.join(", "))
+}
+
+/// The definitions as the models declare them: the enums and plain types as aliases first, the
+/// models each after the models it extends, and the unions last, as they name the models they
+/// join when they are declared.
+fn generate_models(swagger: &Swagger) -> String {
+    let mut aliases = String::new();
+    let mut models = String::new();
+    let mut unions = String::new();
+    let (mut ordered, mut seen) = (Vec::new(), Vec::new());
+    for name in swagger.definitions.keys() {
+        order_models(swagger, name, &mut ordered, &mut seen);
+    }
+    for (name, definition) in &swagger.definitions {
+        let members = definition.one_of.as_ref().or(definition.any_of.as_ref());
+        if let Some(values) = &definition.enum_values {
+            aliases.push_str(&format!("{} = {}\n", identifier(name), literal(values)));
+        } else if let Some(members) = members {
+            let members: Vec<String> = members.iter().map(member_type).collect();
+            unions.push_str(&format!("{} = Union[{}]\n", identifier(name), members.join(", ")));
+        } else if !is_model(definition) {
+            let alias = primitive_type(definition.definition_type.as_deref());
+            aliases.push_str(&format!("{} = {}\n", identifier(name), alias));
+        }
+    }
+    for name in ordered {
+        models.push_str("\n\n");
+        models.push_str(&generate_model(name, &swagger.definitions[name]));
+    }
+
+    let mut py_code = String::new();
+    push_banner(&mut py_code, swagger);
+    py_code.push_str("from __future__ import annotations\n\n");
+    py_code.push_str("from typing import Any, Dict, List, Literal, Optional, Union\n\n");
+    py_code.push_str("from pydantic import BaseModel, ConfigDict, Field\n");
+    if !aliases.is_empty() {
+        py_code.push('\n');
+        py_code.push_str(&aliases);
+    }
+    py_code.push_str(&models);
+    if !unions.is_empty() {
+        py_code.push_str("\n\n");
+        py_code.push_str(&unions);
+    }
+    py_code
+}
+
+/// Whether the definition is an object, which becomes a model, rather than an alias.
+fn is_model(definition: &Definition) -> bool {
+    let alias = definition.enum_values.is_some() || definition.one_of.is_some() || definition.any_of.is_some();
+    !alias && (definition.properties.is_some() || definition.all_of.is_some())
+}
+
+/// Puts the model `name` in `ordered` after the models it extends.
+fn order_models<'a>(swagger: &'a Swagger, name: &'a str, ordered: &mut Vec<&'a str>, seen: &mut Vec<&'a str>) {
+    if seen.contains(&name) {
+        return;
+    }
+    seen.push(name);
+    let Some(definition) = swagger.definitions.get(name).filter(|definition| is_model(definition)) else {
+        return;
+    };
+    for base in base_interfaces(definition) {
+        order_models(swagger, base, ordered, seen);
+    }
+    ordered.push(name);
+}
+
+fn member_type(member: &Definition) -> String {
+    match &member.reference {
+        Some(reference) => identifier(ref_name(reference)),
+        None => primitive_type(member.definition_type.as_deref()).to_string(),
+    }
+}
+
+fn generate_model(name: &str, definition: &Definition) -> String {
+    let bases: Vec<String> = base_interfaces(definition).into_iter().map(identifier).collect();
+    let bases = if bases.is_empty() { "BaseModel".to_string() } else { bases.join(", ") };
+    let mut py_code = format!("class {}({}):\n", identifier(name), bases);
+    if let Some(description) = &definition.description {
+        py_code.push_str(&format!("    \"\"\"{}\"\"\"\n\n", description.trim().replace("\"\"\"", "'''")));
+    }
+    let fields = inline_properties(definition);
+    let mut aliased = false;
+    for (prop_name, prop, _) in &fields {
+        let required = definition.required.as_ref().is_some_and(|required| required.contains(prop_name));
+        let py_type = match property_enum(prop) {
+            Some((values, false)) => literal(values),
+            Some((values, true)) => format!("List[{}]", literal(values)),
+            None => property_type(prop),
+        };
+        // A required field that may be null is typed optional, but still has to be given.
+        let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+        let py_type = if required && nullable { format!("Optional[{}]", py_type) } else { py_type };
+        let field_name = identifier(prop_name);
+        let alias = field_name != **prop_name;
+        aliased |= alias;
+        let line = match (required, alias) {
+            (true, false) => format!("    {}: {}\n", field_name, py_type),
+            (true, true) => format!("    {}: {} = Field(alias=\"{}\")\n", field_name, py_type, prop_name),
+            (false, false) => format!("    {}: Optional[{}] = None\n", field_name, py_type),
+            (false, true) => {
+                format!("    {}: Optional[{}] = Field(None, alias=\"{}\")\n", field_name, py_type, prop_name)
+            }
+        };
+        py_code.push_str(&line);
+    }
+    if aliased {
+        py_code.push_str("\n    model_config = ConfigDict(populate_by_name=True)\n");
+    } else if fields.is_empty() {
+        py_code.push_str("    pass\n");
+    }
+    py_code
+}
+
+/// The Python type of a schema of the paths.
+fn schema_py_type(schema: &Schema) -> String {
+    serde_json::to_value(schema).map_or("Any".to_string(), |schema| schema_type(&schema))
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    if param.parameter_type.as_deref() == Some("file") {
+        return "UploadFile".to_string();
+    }
+    if let Some(values) = &param.enum_values {
+        return literal(values);
+    }
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            match items.and_then(|items| items.enum_values.as_deref()) {
+                Some(values) => format!("List[{}]", literal(values)),
+                None => format!("List[{}]", primitive_type(items.and_then(|items| items.schema_type.as_deref()))),
+            }
+        }
+        None => param.schema.as_ref().map_or("str".to_string(), schema_py_type),
+        parameter_type => primitive_type(parameter_type).to_string(),
+    }
+}
+
+struct Route<'a> {
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+    name: String,
+    content: Option<&'static str>,
+}
+
+impl Route<'_> {
+    /// The path as Starlette matches it, whose parameters are identifiers.
+    fn route_path(&self) -> String {
+        self.path
+            .split('/')
+            .map(|segment| match segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')) {
+                Some(name) => format!("{{{}}}", identifier(&snake_case(name))),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// The parameters of the handler with their FastAPI annotations, as the method takes them, and
+    /// the values the handler passes the method.
+    fn arguments(&self) -> Vec<(String, String, String)> {
+        let mut arguments = Vec::new();
+        let params = self.operation.parameters.iter();
+        for param in params.clone().filter(|param| param.location == "path") {
+            let name = identifier(&snake_case(&param.name));
+            let py_type = parameter_type(param);
+            let annotated = format!("{}: Annotated[{}, Path()]", name, py_type);
+            arguments.push((annotated, format!("{}: {}", name, py_type), name));
+        }
+        let located = |location: &'static str| params.clone().filter(move |param| param.location == location);
+        let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) = located("query")
+            .chain(located("header"))
+            .chain(located("cookie"))
+            .chain(located("formData"))
+            .partition(|param| param.required);
+        let body = params.clone().find(|param| param.location == "body");
+        let body_required = body.is_some_and(|body| body.required);
+        // The handler takes a parameter whose `collectionFormat` joins its items as text, which it splits.
+        let handler_type = |param: &Parameter| match collection_delimiter(param) {
+            Some(_) => "str".to_string(),
+            None => parameter_type(param),
+        };
+        let value = |name: &str, param: &Parameter| match collection_delimiter(param) {
+            Some(delimiter) => format!("_split({}, {:?}, {})", name, delimiter, item_parser(param)),
+            None => name.to_string(),
+        };
+        for param in required {
+            let name = identifier(&snake_case(&param.name));
+            let annotated = format!("{}: Annotated[{}, {}]", name, handler_type(param), annotation(&name, param));
+            arguments.push((annotated, format!("{}: {}", name, parameter_type(param)), value(&name, param)));
+        }
+        if let Some(body) = body.filter(|_| body_required) {
+            let py_type = body.schema.as_ref().map_or("Any".to_string(), schema_py_type);
+            let annotated = format!("body: Annotated[{}, Body()]", py_type);
+            arguments.push((annotated, format!("body: {}", py_type), "body".to_string()));
+        }
+        for param in optional {
+            let name = identifier(&snake_case(&param.name));
+            let annotation = annotation(&name, param);
+            let annotated = format!("{}: Annotated[Optional[{}], {}] = None", name, handler_type(param), annotation);
+            let plain = format!("{}: Optional[{}] = None", name, parameter_type(param));
+            arguments.push((annotated, plain, value(&name, param)));
+        }
+        if let Some(body) = body.filter(|_| !body_required) {
+            let py_type = format!("Optional[{}]", body.schema.as_ref().map_or("Any".to_string(), schema_py_type));
+            let annotated = format!("body: Annotated[{}, Body()] = None", py_type);
+            arguments.push((annotated, format!("body: {} = None", py_type), "body".to_string()));
+        }
+        arguments
+    }
+
+    /// What the method returns: the body of the first success response.
+    fn return_type(&self) -> String {
+        match (self.content, success_responses(self.operation).first()) {
+            (Some("blob"), _) => "bytes".to_string(),
+            (Some(_), _) => "str".to_string(),
+            (None, Some((_, response))) => response.response_schema.as_ref().map_or("None".to_string(), schema_py_type),
+            (None, None) => "None".to_string(),
+        }
+    }
+
+    /// The arguments of the route decorator: its status and the other documented responses.
+    fn decorator_arguments(&self) -> Vec<String> {
+        let mut arguments = vec![format!("\"{}\"", self.route_path())];
+        let success = success_responses(self.operation);
+        if let Some(status) = success.first().and_then(|(status, _)| status.parse::<u16>().ok()) {
+            arguments.push(format!("status_code={}", status));
+        }
+        match self.content {
+            Some("blob") => arguments.push("response_class=Response".to_string()),
+            Some(_) => arguments.push("response_class=PlainTextResponse".to_string()),
+            None => {}
+        }
+        let others: Vec<String> = self
+            .operation
+            .responses
+            .iter()
+            .filter(|(status, _)| success.first().is_none_or(|(first, _)| first != status))
+            .map(|(status, response)| {
+                let key = status.parse::<u16>().map_or(format!("\"{}\"", status), |status| status.to_string());
+                let description = serde_json::to_string(&response.description).unwrap_or_default();
+                match &response.response_schema {
+                    Some(schema) => {
+                        format!("{}: {{\"model\": {}, \"description\": {}}}", key, schema_py_type(schema), description)
+                    }
+                    None => format!("{}: {{\"description\": {}}}", key, description),
+                }
+            })
+            .collect();
+        if !others.is_empty() {
+            arguments.push(format!("responses={{{}}}", others.join(", ")));
+        }
+        if let Some(summary) = &self.operation.summary {
+            arguments.push(format!("summary={}", serde_json::to_string(summary).unwrap_or_default()));
+        }
+        if self.operation.deprecated {
+            arguments.push("deprecated=True".to_string());
+        }
+        arguments
+    }
+}
+
+/// `Query()`, `Header()`, `Cookie()`, `Form()` or `File()` by where the parameter is sent, with its
+/// name in the request when it is not `name`.
+fn annotation(name: &str, param: &Parameter) -> String {
+    let kind = match param.location.as_str() {
+        "query" => "Query",
+        "header" => "Header",
+        "cookie" => "Cookie",
+        _ if param.parameter_type.as_deref() == Some("file") => "File",
+        _ => "Form",
+    };
+    if name == param.name {
+        format!("{}()", kind)
+    } else {
+        format!("{}(alias=\"{}\")", kind, param.name)
+    }
+}
+
+/// Splits the parameters whose `collectionFormat` joins their items into one value, answering 422
+/// when an item is not of the type of the array.
+const SPLIT_FUNCTION: &str = r#"
+
+def _split(value: Optional[str], delimiter: str, item: Callable[[str], Any]) -> Optional[List[Any]]:
+    if value is None:
+        return None
+    try:
+        return [item(part) for part in value.split(delimiter) if part]
+    except ValueError as error:
+        raise HTTPException(status_code=422, detail=str(error)) from error
+"#;
+
+/// The builtin parsing an item of an array parameter: `int`, `float` or `str`.
+fn item_parser(param: &Parameter) -> &'static str {
+    match param.items.as_ref().and_then(|items| items.schema_type.as_deref()) {
+        Some("integer") => "int",
+        Some("number") => "float",
+        _ => "str",
+    }
+}
+
+fn generate_routers(swagger: &Swagger, style: PythonStyle) -> String {
+    let groups: Vec<(String, Vec<Route>)> = operations_by_tag(swagger)
+        .into_iter()
+        .map(|(tag, operations)| {
+            let routes = operations
+                .into_iter()
+                .map(|(path, method, operation)| Route {
+                    method,
+                    path,
+                    operation,
+                    name: identifier(&snake_case(&operation_name(method, path, operation))),
+                    content: non_json_response(swagger, operation),
+                })
+                .collect();
+            (tag, routes)
+        })
+        .collect();
+    let (def, call) = match style {
+        PythonStyle::Sync => ("def", ""),
+        PythonStyle::Async => ("async def", "await "),
+    };
+
+    let mut code = String::new();
+    let mut uses = BTreeSet::new();
+    let mut references = BTreeSet::new();
+    for (tag, routes) in &groups {
+        let class = format!("{}Api", pascal_case(tag));
+        code.push_str(&format!("\n\nclass {}(ABC):\n", class));
+        code.push_str(&format!(
+            "    \"\"\"The operations tagged `{}`, which `{}_router` routes requests to.\"\"\"\n",
+            tag,
+            snake_case(tag)
+        ));
+        for route in routes {
+            let parameters: Vec<String> = std::iter::once("self".to_string())
+                .chain(route.arguments().into_iter().map(|(_, plain, _)| plain))
+                .collect();
+            code.push_str("\n    @abstractmethod\n");
+            code.push_str(&format!(
+                "    {} {}({}) -> {}:\n",
+                def,
+                route.name,
+                parameters.join(", "),
+                route.return_type()
+            ));
+            code.push_str(&format!("        \"\"\"{} {}\"\"\"\n", route.method.to_uppercase(), route.path));
+        }
+
+        code.push_str(&format!("\n\ndef {}_router(api: {}) -> APIRouter:\n", snake_case(tag), class));
+        code.push_str(&format!("    router = APIRouter(tags=[\"{}\"])\n", tag));
+        for route in routes {
+            let arguments = route.arguments();
+            for (annotated, _, value) in &arguments {
+                for name in ["Path", "Query", "Header", "Cookie", "Form", "File", "Body"] {
+                    if annotated.contains(&format!(", {}(", name)) {
+                        uses.insert(name);
+                    }
+                }
+                if annotated.contains("[UploadFile") {
+                    uses.insert("UploadFile");
+                }
+                if value.starts_with("_split(") {
+                    uses.insert("HTTPException");
+                }
+            }
+            match route.content {
+                Some("blob") => uses.insert("Response"),
+                Some(_) => uses.insert("PlainTextResponse"),
+                None => false,
+            };
+            let decorator = route.decorator_arguments();
+            let responses = route.operation.responses.values().filter_map(|response| response.response_schema.as_ref());
+            let schemas = route.operation.parameters.iter().filter_map(|param| param.schema.as_ref()).chain(responses);
+            references.extend(schemas.filter_map(schema_definition).map(identifier));
+            let handler: Vec<String> = arguments.iter().map(|(annotated, _, _)| annotated.clone()).collect();
+            let values: Vec<String> = arguments.iter().map(|(_, _, value)| value.clone()).collect();
+            let call = format!("{}api.{}({})", call, route.name, values.join(", "));
+            code.push_str(&format!("\n    @router.{}({})\n", route.method, decorator.join(", ")));
+            if route.content == Some("blob") {
+                code.push_str(&format!("    {} {}({}) -> Response:\n", def, route.name, handler.join(", ")));
+                code.push_str(&format!("        return Response({}, media_type=\"application/octet-stream\")\n", call));
+            } else {
+                code.push_str(&format!(
+                    "    {} {}({}) -> {}:\n",
+                    def,
+                    route.name,
+                    handler.join(", "),
+                    route.return_type()
+                ));
+                code.push_str(&format!("        return {}\n", call));
+            }
+        }
+        code.push_str("\n    return router\n");
+    }
+
+    let parameters: Vec<String> =
+        groups.iter().map(|(tag, _)| format!("{}: {}Api", identifier(&snake_case(tag)), pascal_case(tag))).collect();
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or("API");
+    let version = swagger.info.get("version").and_then(Value::as_str).unwrap_or("1.0.0");
+    code.push_str(&format!("\n\ndef create_app({}) -> FastAPI:\n", parameters.join(", ")));
+    code.push_str(
+        "    \"\"\"An app routing every operation of the spec, under BASE_PATH, to the given implementations.\"\"\"\n",
+    );
+    code.push_str(&format!(
+        "    app = FastAPI(title={}, version={})\n",
+        serde_json::to_string(title).unwrap_or_default(),
+        serde_json::to_string(version).unwrap_or_default()
+    ));
+    for (tag, _) in &groups {
+        code.push_str(&format!(
+            "    app.include_router({}_router({}), prefix=BASE_PATH)\n",
+            snake_case(tag),
+            identifier(&snake_case(tag))
+        ));
+    }
+    code.push_str("    return app\n");
+
+    let mut py_code = String::new();
+    push_banner(&mut py_code, swagger);
+    py_code.push_str("from abc import ABC, abstractmethod\n");
+    let split = uses.contains("HTTPException");
+    let callable = if split { "Callable, " } else { "" };
+    py_code.push_str(&format!("from typing import Annotated, Any, {}Dict, List, Literal, Optional\n\n", callable));
+    let mut fastapi = vec!["APIRouter", "FastAPI"];
+    fastapi.extend(uses.iter().filter(|name| **name != "PlainTextResponse"));
+    fastapi.sort();
+    py_code.push_str(&format!("from fastapi import {}\n", fastapi.join(", ")));
+    if uses.contains("PlainTextResponse") {
+        py_code.push_str("from fastapi.responses import PlainTextResponse\n");
+    }
+    if !references.is_empty() {
+        let references: Vec<String> = references.into_iter().collect();
+        py_code.push_str(&format!("\nfrom .models import {}\n", references.join(", ")));
+    }
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    py_code.push_str(&format!("\nBASE_PATH = \"{}\"\n", base_path));
+    if split {
+        py_code.push_str(SPLIT_FUNCTION);
+    }
+    py_code.push_str(&code);
+    py_code
+}
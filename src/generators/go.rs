@@ -0,0 +1,817 @@
+use super::typescript::{form_parameters, header_parameters, query_parameters};
+use super::{
+    base_url, binary_body, body_media_type, collection_delimiter, enum_members, extract_path_params,
+    has_binary_response, has_binary_responses, has_request_body, info_lines, operation_name, package_name,
+    pascal_case, request_media_type, response_reference, security_requirements, security_schemes, sends_raw_body,
+    success_responses, with_custom_region, words, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "body", "break", "case", "chan", "const", "continue", "ctx", "default", "defer", "else", "fallthrough", "files",
+    "for", "form", "func", "go", "goto", "header", "if", "import", "interface", "map", "package", "params", "query",
+    "range", "result", "return", "select", "struct", "switch", "type", "var",
+];
+
+const INITIALISMS: &[&str] = &["api", "http", "https", "id", "ip", "json", "uri", "url", "uuid", "xml"];
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let package = package_name(swagger);
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("go").join("go.mod"), format!("module {}\n\ngo 1.21\n", package));
+    files.insert(PathBuf::from("go").join("models.go"), generate_models(swagger, &package));
+    let client = with_custom_region(generate_client(swagger, &package, retries), "//");
+    files.insert(PathBuf::from("go").join("client.go"), client);
+    files
+}
+
+fn generate_header(swagger: &Swagger, package: &str) -> String {
+    let mut go_code = String::new();
+    for line in info_lines(swagger) {
+        go_code.push_str(&format!("// {}\n", line));
+    }
+    go_code.push_str(&format!("\npackage {}\n\n", package));
+    go_code
+}
+
+fn generate_models(swagger: &Swagger, package: &str) -> String {
+    let mut go_code = generate_header(swagger, package);
+    for (name, definition) in &swagger.definitions {
+        go_code.push_str(&generate_definition(name, definition));
+        go_code.push('\n');
+    }
+    go_code.truncate(go_code.trim_end().len() + 1);
+    go_code
+}
+
+/// Named type for a definition: a string or number type with a constant per value for an enum, a
+/// struct for an object and the matching Go type for other schemas.
+fn generate_definition(name: &str, definition: &Definition) -> String {
+    let type_name = identifier(name);
+    if let Some(values) = &definition.enum_values {
+        return generate_enum(&type_name, definition, values);
+    }
+    if definition.properties.is_some() || definition.all_of.is_some() {
+        return generate_struct(&type_name, definition);
+    }
+    match (definition.definition_type.as_deref(), &definition.additional_properties) {
+        (Some("object") | None, Some(schema @ Value::Object(_))) => {
+            format!("type {} map[string]{}\n", type_name, schema_type(schema))
+        }
+        (Some("object") | None, Some(Value::Bool(true))) => format!("type {} map[string]interface{{}}\n", type_name),
+        (Some("object") | None, _) => generate_struct(&type_name, definition),
+        (Some("array"), _) => format!("type {} []interface{{}}\n", type_name),
+        (definition_type, _) => format!("type {} {}\n", type_name, primitive_type(definition_type, None)),
+    }
+}
+
+fn generate_enum(type_name: &str, definition: &Definition, values: &[Value]) -> String {
+    let base_type = match definition.definition_type.as_deref() {
+        Some("integer" | "number" | "boolean") => primitive_type(definition.definition_type.as_deref(), None),
+        _ => "string",
+    };
+    // Booleans are named after the words they read as.
+    let values: Vec<Value> = values
+        .iter()
+        .map(|value| match value {
+            Value::Bool(flag) => Value::String(flag.to_string()),
+            value => value.clone(),
+        })
+        .collect();
+    let mut constants = Vec::new();
+    for (suffix, value) in enum_members(&values, |words| words.iter().map(|word| capitalize(word)).collect()) {
+        let literal = match (base_type, value) {
+            ("bool", Value::String(flag)) => flag.clone(),
+            _ => value.to_string(),
+        };
+        constants.push(vec![format!("{}{}", type_name, suffix), type_name.to_string(), format!("= {}", literal)]);
+    }
+
+    let mut go_code = format!("type {} {}\n", type_name, base_type);
+    if !constants.is_empty() {
+        go_code.push_str("\nconst (\n");
+        for line in aligned_columns(&constants) {
+            go_code.push_str(&format!("\t{}\n", line));
+        }
+        go_code.push_str(")\n");
+    }
+    go_code
+}
+
+/// Struct for an object definition, embedding the definitions its `allOf` refers to and declaring the
+/// properties of its inline parts as its own.
+fn generate_struct(type_name: &str, definition: &Definition) -> String {
+    let mut fields: Vec<Vec<String>> = definition
+        .all_of
+        .iter()
+        .flatten()
+        .filter_map(|part| part.reference.as_deref())
+        .map(|reference| vec![identifier(ref_name(reference))])
+        .collect();
+    push_fields(definition, &mut fields);
+    generate_struct_type(type_name, &fields)
+}
+
+fn push_fields(definition: &Definition, fields: &mut Vec<Vec<String>>) {
+    for (prop_name, prop) in definition.properties.iter().flatten() {
+        let required = definition.required.as_ref().is_some_and(|required| required.contains(prop_name));
+        let go_type = property_type(prop);
+        let (go_type, tag) = if required {
+            (go_type, format!("json:\"{}\"", prop_name))
+        } else if go_type.starts_with("[]") || go_type.starts_with("map[") || go_type == "interface{}" {
+            (go_type, format!("json:\"{},omitempty\"", prop_name))
+        } else {
+            (format!("*{}", go_type), format!("json:\"{},omitempty\"", prop_name))
+        };
+        fields.push(vec![identifier(prop_name), go_type, format!("`{}`", tag)]);
+    }
+    for part in definition.all_of.iter().flatten().filter(|part| part.reference.is_none()) {
+        push_fields(part, fields);
+    }
+}
+
+/// Struct declaration with its columns aligned the way gofmt would print them.
+fn generate_struct_type(name: &str, fields: &[Vec<String>]) -> String {
+    let mut go_code = format!("type {} struct {{\n", name);
+    for line in aligned_columns(fields) {
+        go_code.push_str(&format!("\t{}\n", line));
+    }
+    go_code.push_str("}\n");
+    go_code
+}
+
+/// Lines padding each column to its widest value. Embedded fields, the rows of a single column, are
+/// left out of the widths as gofmt leaves them out of the alignment.
+fn aligned_columns(rows: &[Vec<String>]) -> Vec<String> {
+    let mut widths = Vec::new();
+    for row in rows.iter().filter(|row| row.len() > 1) {
+        for (column, value) in row.iter().enumerate() {
+            if widths.len() <= column {
+                widths.push(0);
+            }
+            widths[column] = widths[column].max(value.len());
+        }
+    }
+
+    rows.iter()
+        .map(|row| {
+            if row.len() == 1 {
+                return row[0].clone();
+            }
+            let columns: Vec<String> = row
+                .iter()
+                .enumerate()
+                .map(|(column, value)| format!("{:width$}", value, width = widths[column]))
+                .collect();
+            columns.join(" ").trim_end().to_string()
+        })
+        .collect()
+}
+
+fn generate_client(swagger: &Swagger, package: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    let form_media_types: Vec<&str> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .filter(|(_, operation)| !form_parameters(operation).is_empty())
+        .map(|(_, operation)| request_media_type(swagger, operation))
+        .collect();
+    let has_multipart = form_media_types.contains(&MULTIPART);
+    let has_url_encoded = form_media_types.iter().any(|media_type| *media_type != MULTIPART);
+    let raw_bodies: Vec<bool> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .filter(|(method, operation)| sends_raw_body(swagger, method, operation))
+        .map(|(_, operation)| binary_body(operation))
+        .collect();
+
+    let mut imports = vec!["bytes", "context", "encoding/json", "fmt", "io", "net/http", "net/url"];
+    if has_multipart {
+        imports.push("mime/multipart");
+    }
+    let joins_arrays = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(_, operation)| operation.parameters.iter().any(|param| collection_delimiter(param).is_some()));
+    if has_url_encoded || raw_bodies.contains(&false) || joins_arrays {
+        imports.push("strings");
+    }
+    if retries.is_some() {
+        imports.extend(["strconv", "time"]);
+    }
+    imports.sort_unstable();
+
+    let mut go_code = generate_header(swagger, package);
+    go_code.push_str("import (\n");
+    for import in imports {
+        go_code.push_str(&format!("\t\"{}\"\n", import));
+    }
+    go_code.push_str(")\n\n");
+
+    go_code.push_str(&format!("const DefaultBaseURL = \"{}\"\n\n", base_url(swagger)));
+    if let Some(retries) = retries {
+        go_code.push_str(&format!("const MaxRetries = {}\n\n", retries));
+    }
+    go_code.push_str("type Client struct {\n\tBaseURL    string\n\tHTTPClient *http.Client\n");
+    if has_auth {
+        go_code.push_str("\tAuth       AuthConfig\n");
+    }
+    go_code.push_str("}\n\n");
+    go_code.push_str(
+        "func NewClient() *Client {
+\treturn &Client{BaseURL: DefaultBaseURL, HTTPClient: http.DefaultClient}
+}
+
+type APIError struct {
+\tStatusCode int
+\tBody       []byte
+}
+
+func (e *APIError) Error() string {
+\treturn fmt.Sprintf(\"unexpected status %d: %s\", e.StatusCode, e.Body)
+}\n\n",
+    );
+
+    if has_auth {
+        go_code.push_str(&generate_auth(swagger));
+    }
+
+    if has_multipart {
+        go_code.push_str(
+            "// multipartBody is a multipart/form-data request body, encoded from its fields and files.
+type multipartBody struct {
+\tfields url.Values
+\tfiles  map[string][]byte
+}
+
+func (b *multipartBody) encode() ([]byte, string, error) {
+\tvar buf bytes.Buffer
+\twriter := multipart.NewWriter(&buf)
+\tfor name, values := range b.fields {
+\t\tfor _, value := range values {
+\t\t\tif err := writer.WriteField(name, value); err != nil {
+\t\t\t\treturn nil, \"\", err
+\t\t\t}
+\t\t}
+\t}
+\tfor name, data := range b.files {
+\t\tpart, err := writer.CreateFormFile(name, name)
+\t\tif err != nil {
+\t\t\treturn nil, \"\", err
+\t\t}
+\t\tif _, err := part.Write(data); err != nil {
+\t\t\treturn nil, \"\", err
+\t\t}
+\t}
+\tif err := writer.Close(); err != nil {
+\t\treturn nil, \"\", err
+\t}
+\treturn buf.Bytes(), writer.FormDataContentType(), nil
+}\n\n",
+        );
+    }
+
+    // Form fields come as `url.Values` when URL-encoded and as a `*multipartBody` otherwise, and raw
+    // bodies as an `io.Reader` with their Content-Type in the header; any other body is sent as JSON.
+    let mut body_cases = String::new();
+    if !raw_bodies.is_empty() {
+        body_cases.push_str("\tcase io.Reader:\n\t\treader = body\n");
+    }
+    if has_url_encoded {
+        body_cases.push_str(
+            "\tcase url.Values:\n\t\treader = strings.NewReader(body.Encode())\n\t\tcontentType = \"application/x-www-form-urlencoded\"\n",
+        );
+    }
+    if has_multipart {
+        body_cases.push_str(
+            "\tcase *multipartBody:
+\t\tdata, formContentType, err := body.encode()
+\t\tif err != nil {
+\t\t\treturn err
+\t\t}
+\t\treader = bytes.NewReader(data)
+\t\tcontentType = formContentType\n",
+        );
+    }
+    let requirements_param = if has_auth { ", requirements [][]string" } else { "" };
+    go_code.push_str(&format!(
+        "func (c *Client) do(ctx context.Context, method, path string, query url.Values, header http.Header, body, result interface{{}}{}) error {{
+\tvar reader io.Reader
+\tcontentType := \"application/json\"
+\tswitch body := body.(type) {{
+\tcase nil:
+{}\tdefault:
+\t\tdata, err := json.Marshal(body)
+\t\tif err != nil {{
+\t\t\treturn err
+\t\t}}
+\t\treader = bytes.NewReader(data)
+\t}}
+\ttarget := c.BaseURL + path
+\tif len(query) > 0 {{
+\t\ttarget += \"?\" + query.Encode()
+\t}}
+\treq, err := http.NewRequestWithContext(ctx, method, target, reader)
+\tif err != nil {{
+\t\treturn err
+\t}}
+\tfor name, values := range header {{
+\t\tfor _, value := range values {{
+\t\t\treq.Header.Add(name, value)
+\t\t}}
+\t}}
+\tif reader != nil && req.Header.Get(\"Content-Type\") == \"\" {{
+\t\treq.Header.Set(\"Content-Type\", contentType)
+\t}}
+\treq.Header.Set(\"Accept\", \"application/json\")
+{}\tresp, err := c.{}(req)
+\tif err != nil {{
+\t\treturn err
+\t}}
+\tdefer resp.Body.Close()
+\tif resp.StatusCode < 200 || resp.StatusCode >= 300 {{
+\t\tdata, _ := io.ReadAll(resp.Body)
+\t\treturn &APIError{{StatusCode: resp.StatusCode, Body: data}}
+\t}}
+\tif result == nil {{
+\t\treturn nil
+\t}}
+{}\tif err := json.NewDecoder(resp.Body).Decode(result); err != nil && err != io.EOF {{
+\t\treturn err
+\t}}
+\treturn nil
+}}\n",
+        requirements_param,
+        body_cases,
+        if has_auth { "\tc.applyAuth(req, requirements)\n" } else { "" },
+        if retries.is_some() { "send" } else { "HTTPClient.Do" },
+        // File downloads pass a `*[]byte` to receive the body as is.
+        if has_binary_responses(swagger) {
+            "\tif data, ok := result.(*[]byte); ok {\n\t\t*data, err = io.ReadAll(resp.Body)\n\t\treturn err\n\t}\n"
+        } else {
+            ""
+        }
+    ));
+
+    if retries.is_some() {
+        go_code.push_str(
+            "
+// send retries requests that failed with a network error, 429 or 5xx, waiting as long as
+// Retry-After asks, in seconds or as a date, or otherwise doubling the delay from half a second.
+func (c *Client) send(req *http.Request) (*http.Response, error) {
+\tfor attempt := 0; ; attempt++ {
+\t\tif attempt > 0 && req.GetBody != nil {
+\t\t\tbody, err := req.GetBody()
+\t\t\tif err != nil {
+\t\t\t\treturn nil, err
+\t\t\t}
+\t\t\treq.Body = body
+\t\t}
+\t\tresp, err := c.HTTPClient.Do(req)
+\t\tif err == nil && resp.StatusCode != http.StatusTooManyRequests && resp.StatusCode < 500 {
+\t\t\treturn resp, nil
+\t\t}
+\t\tif attempt >= MaxRetries || req.Context().Err() != nil {
+\t\t\treturn resp, err
+\t\t}
+\t\tdelay := time.Duration(500<<attempt) * time.Millisecond
+\t\tif err == nil {
+\t\t\tretryAfter := resp.Header.Get(\"Retry-After\")
+\t\t\tif seconds, err := strconv.Atoi(retryAfter); err == nil {
+\t\t\t\tdelay = time.Duration(seconds) * time.Second
+\t\t\t} else if date, err := http.ParseTime(retryAfter); err == nil {
+\t\t\t\tdelay = max(time.Until(date), 0)
+\t\t\t}
+\t\t\tresp.Body.Close()
+\t\t}
+\t\tselect {
+\t\tcase <-time.After(delay):
+\t\tcase <-req.Context().Done():
+\t\t\treturn nil, req.Context().Err()
+\t\t}
+\t}
+}\n",
+        );
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            go_code.push('\n');
+            go_code.push_str(&generate_client_method(swagger, method, path, operation));
+        }
+    }
+
+    go_code
+}
+
+fn generate_auth(swagger: &Swagger) -> String {
+    let schemes = security_schemes(swagger);
+    let mut go_code = String::new();
+    if schemes.iter().any(|(_, scheme)| scheme.scheme_type == "basic") {
+        go_code.push_str("type BasicAuth struct {\n\tUsername string\n\tPassword string\n}\n\n");
+    }
+
+    let fields: Vec<Vec<String>> = schemes
+        .iter()
+        .map(|(name, scheme)| {
+            let auth_type = if scheme.scheme_type == "basic" { "*BasicAuth" } else { "string" };
+            vec![identifier(name), auth_type.to_string()]
+        })
+        .collect();
+    go_code.push_str(&generate_struct_type("AuthConfig", &fields));
+    go_code.push('\n');
+
+    go_code.push_str("func (c *Client) hasAuth(name string) bool {\n\tswitch name {\n");
+    for (name, scheme) in &schemes {
+        let empty = if scheme.scheme_type == "basic" { "nil" } else { "\"\"" };
+        go_code.push_str(&format!("\tcase \"{}\":\n\t\treturn c.Auth.{} != {}\n", name, identifier(name), empty));
+    }
+    go_code.push_str("\t}\n\treturn false\n}\n\n");
+
+    go_code.push_str(
+        "func (c *Client) applyAuth(req *http.Request, requirements [][]string) {
+\tfor _, names := range requirements {
+\t\tsatisfied := true
+\t\tfor _, name := range names {
+\t\t\tsatisfied = satisfied && c.hasAuth(name)
+\t\t}
+\t\tif !satisfied {
+\t\t\tcontinue
+\t\t}
+\t\tquery := req.URL.Query()
+\t\tfor _, name := range names {
+\t\t\tswitch name {\n",
+    );
+    for (name, scheme) in &schemes {
+        let field = format!("c.Auth.{}", identifier(name));
+        let key = scheme.name.as_deref().unwrap_or(name);
+        let apply = match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+            ("basic", _) => format!("req.SetBasicAuth({0}.Username, {0}.Password)", field),
+            ("apiKey", Some("query")) => format!("query.Set(\"{}\", {})", key, field),
+            ("apiKey", Some("cookie")) => format!("req.AddCookie(&http.Cookie{{Name: \"{}\", Value: {}}})", key, field),
+            ("apiKey", _) => format!("req.Header.Set(\"{}\", {})", key, field),
+            _ => format!("req.Header.Set(\"Authorization\", \"Bearer \"+{})", field),
+        };
+        go_code.push_str(&format!("\t\t\tcase \"{}\":\n\t\t\t\t{}\n", name, apply));
+    }
+    go_code.push_str("\t\t\t}\n\t\t}\n\t\treq.URL.RawQuery = query.Encode()\n\t\treturn\n\t}\n}\n\n");
+    go_code
+}
+
+fn generate_client_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = identifier(&operation_name(method, path, operation));
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+    let form = form_parameters(operation);
+    let params: Vec<&Parameter> = query.iter().chain(&headers).chain(&form).copied().collect();
+    let params_name = format!("{}Params", method_name);
+
+    let mut arguments = vec!["ctx context.Context".to_string()];
+    arguments.extend(
+        path_params.iter().map(|param| format!("{} {}", argument_name(param), path_parameter_type(operation, param))),
+    );
+    if !params.is_empty() {
+        arguments.push(format!("params {}", params_name));
+    }
+    let has_body = has_request_body(method) && form.is_empty();
+    let raw_body = sends_raw_body(swagger, method, operation);
+    let body_param = operation.parameters.iter().find(|param| param.location == "body");
+    let body_type =
+        body_param.and_then(|param| param.schema.as_ref()).map_or_else(|| "interface{}".to_string(), response_type);
+    let required_body = body_param.is_some_and(|param| param.required);
+    // An optional model is taken by pointer; slices, maps and interfaces can be nil already.
+    let pointer_body =
+        !required_body && !body_type.starts_with("[]") && !body_type.starts_with("map[") && body_type != "interface{}";
+    if has_body {
+        arguments.push(match raw_body {
+            true if binary_body(operation) => "body []byte".to_string(),
+            true => "body string".to_string(),
+            false if pointer_body => format!("body *{}", body_type),
+            false => format!("body {}", body_type),
+        });
+    }
+
+    let mut format_args = Vec::new();
+    let mut go_path = path.to_string();
+    for param in &path_params {
+        if path_parameter_type(operation, param) == "string" {
+            go_path = go_path.replace(&format!("{{{}}}", param), "%s");
+            format_args.push(format!("url.PathEscape({})", argument_name(param)));
+        } else {
+            go_path = go_path.replace(&format!("{{{}}}", param), "%v");
+            format_args.push(argument_name(param));
+        }
+    }
+    let go_path = if format_args.is_empty() {
+        format!("\"{}\"", go_path)
+    } else {
+        format!("fmt.Sprintf(\"{}\", {})", go_path, format_args.join(", "))
+    };
+
+    let auth_argument = if swagger.security_definitions.is_empty() {
+        String::new()
+    } else {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("{{{}}}", names.join(", "))
+            })
+            .collect();
+        if requirements.is_empty() {
+            ", nil".to_string()
+        } else {
+            format!(", [][]string{{{}}}", requirements.join(", "))
+        }
+    };
+
+    // Statements filling the query, the headers and the form from `params`.
+    let mut request = String::new();
+    if !query.is_empty() {
+        request.push_str("\tquery := url.Values{}\n");
+        for param in &query {
+            request.push_str(&set_parameter(param, "query"));
+        }
+    }
+    let media_type = body_media_type(swagger, method, operation);
+    if !headers.is_empty() || media_type.is_some() {
+        request.push_str("\theader := http.Header{}\n");
+    }
+    if let Some(media_type) = media_type {
+        request.push_str(&format!("\theader.Set(\"Content-Type\", \"{}\")\n", media_type));
+    }
+    for param in &headers {
+        request.push_str(&set_parameter(param, "header"));
+    }
+    let multipart = !form.is_empty() && request_media_type(swagger, operation) == MULTIPART;
+    let has_files = form.iter().any(|param| param.parameter_type.as_deref() == Some("file"));
+    if !form.is_empty() {
+        request.push_str("\tform := url.Values{}\n");
+        if has_files {
+            request.push_str("\tfiles := map[string][]byte{}\n");
+        }
+        for param in &form {
+            request.push_str(&set_parameter(param, "form"));
+        }
+    }
+    // A nil pointer or slice in an interface is not nil, so an absent body is left unset.
+    let nullable_body = has_body && !raw_body && !required_body && body_type != "interface{}";
+    if nullable_body {
+        request.push_str("\tvar payload interface{}\n\tif body != nil {\n\t\tpayload = body\n\t}\n");
+    }
+    let body = match () {
+        _ if multipart && has_files => "&multipartBody{fields: form, files: files}",
+        _ if multipart => "&multipartBody{fields: form}",
+        _ if !form.is_empty() => "form",
+        _ if raw_body && binary_body(operation) => "bytes.NewReader(body)",
+        _ if raw_body => "strings.NewReader(body)",
+        _ if nullable_body => "payload",
+        _ if has_body => "body",
+        _ => "nil",
+    };
+
+    let call = format!(
+        "c.do(ctx, \"{}\", {}, {}, {}, {}, &result{})",
+        method.to_uppercase(),
+        go_path,
+        if query.is_empty() { "nil" } else { "query" },
+        if headers.is_empty() && media_type.is_none() { "nil" } else { "header" },
+        body,
+        auth_argument
+    );
+
+    let mut go_code = String::new();
+    if !params.is_empty() {
+        let fields: Vec<Vec<String>> =
+            params.iter().map(|param| vec![identifier(&param.name), parameter_field_type(param)]).collect();
+        go_code
+            .push_str(&format!("// {} holds the query, header and form parameters of {}.\n", params_name, method_name));
+        go_code.push_str(&generate_struct_type(&params_name, &fields));
+        go_code.push('\n');
+    }
+    if let Some(summary) = &operation.summary {
+        go_code.push_str(&format!("// {} {}\n", method_name, summary));
+    }
+    // A paragraph of its own, as gopls and staticcheck expect.
+    if operation.deprecated {
+        if operation.summary.is_some() {
+            go_code.push_str("//\n");
+        }
+        go_code.push_str(&format!("// Deprecated: {} is deprecated by the API.\n", method_name));
+    }
+    let result_type = match response_reference(operation) {
+        _ if has_binary_response(swagger, operation) => "[]byte".to_string(),
+        Some(reference) => {
+            let response_type = identifier(ref_name(reference));
+            go_code.push_str(&format!(
+                "func (c *Client) {}({}) (*{}, error) {{
+{}\tvar result {}
+\tif err := {}; err != nil {{
+\t\treturn nil, err
+\t}}
+\treturn &result, nil
+}}\n",
+                method_name,
+                arguments.join(", "),
+                response_type,
+                request,
+                response_type,
+                call
+            ));
+            return go_code;
+        }
+        None => success_responses(operation)
+            .into_iter()
+            .find_map(|(_, response)| response.response_schema.as_ref())
+            .filter(|schema| schema.schema_type.as_deref() == Some("array"))
+            .map_or_else(|| "interface{}".to_string(), response_type),
+    };
+    go_code.push_str(&format!(
+        "func (c *Client) {}({}) ({}, error) {{
+{}\tvar result {}
+\terr := {}
+\treturn result, err
+}}\n",
+        method_name,
+        arguments.join(", "),
+        result_type,
+        request,
+        result_type,
+        call
+    ));
+    go_code
+}
+
+/// Go type of a parameter, before the optional ones become pointers.
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            format!("[]{}", items_type)
+        }
+        Some("file") => "[]byte".to_string(),
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+fn parameter_field_type(param: &Parameter) -> String {
+    let go_type = parameter_type(param);
+    if param.required || go_type.starts_with("[]") || go_type.starts_with("map[") || go_type == "interface{}" {
+        go_type
+    } else {
+        format!("*{}", go_type)
+    }
+}
+
+/// Statement copying the field of `params` for a parameter into `target`, the `query`, `header` or
+/// `form` of the request, skipping it when unset. Cookies go in the `Cookie` header and files in
+/// the `files` of the multipart body.
+fn set_parameter(param: &Parameter, target: &str) -> String {
+    let field = format!("params.{}", identifier(&param.name));
+    let go_type = parameter_type(param);
+    let text = |value: &str, value_type: &str| {
+        if value_type == "string" {
+            value.to_string()
+        } else {
+            format!("fmt.Sprint({})", value)
+        }
+    };
+    let add = |method: &str, value: &str| match param.location.as_str() {
+        "cookie" => {
+            format!("header.Add(\"Cookie\", (&http.Cookie{{Name: \"{}\", Value: {}}}).String())", param.name, value)
+        }
+        _ => format!("{}.{}(\"{}\", {})", target, method, param.name, value),
+    };
+
+    if go_type == "[]byte" {
+        let set = format!("files[\"{}\"] = {}", param.name, field);
+        return if param.required {
+            format!("\t{}\n", set)
+        } else {
+            format!("\tif {} != nil {{\n\t\t{}\n\t}}\n", field, set)
+        };
+    }
+    // Arrays are sent a value at a time unless their `collectionFormat` joins them into one.
+    match (go_type.strip_prefix("[]"), collection_delimiter(param)) {
+        (Some("string"), Some(delimiter)) => {
+            let joined = format!("strings.Join({}, {:?})", field, delimiter);
+            return format!("\tif len({}) > 0 {{\n\t\t{}\n\t}}\n", field, add("Set", &joined));
+        }
+        (Some(item_type), Some(delimiter)) => {
+            return format!(
+                "\tif len({0}) > 0 {{\n\t\tvalues := make([]string, 0, len({0}))\n\t\tfor _, value := range {0} {{\n\t\t\t\
+                 values = append(values, {1})\n\t\t}}\n\t\t{2}\n\t}}\n",
+                field,
+                text("value", item_type),
+                add("Set", &format!("strings.Join(values, {:?})", delimiter))
+            );
+        }
+        (Some(item_type), None) => {
+            let add = add("Add", &text("value", item_type));
+            return format!("\tfor _, value := range {} {{\n\t\t{}\n\t}}\n", field, add);
+        }
+        (None, _) => {}
+    }
+    if parameter_field_type(param).starts_with('*') {
+        let value = text(&format!("*{}", field), &go_type);
+        format!("\tif {} != nil {{\n\t\t{}\n\t}}\n", field, add("Set", &value))
+    } else {
+        format!("\t{}\n", add("Set", &text(&field, &go_type)))
+    }
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `string` for parameters the
+/// operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(go_type @ ("int32" | "int64" | "float32" | "float64" | "bool")) => go_type,
+        _ => "string",
+    }
+}
+
+/// Go type of a response schema, with the definitions it refers to by value.
+fn response_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return identifier(ref_name(reference));
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => format!("[]{}", schema.items.as_deref().map_or("interface{}".to_string(), response_type)),
+        other => primitive_type(other, schema.format.as_deref()).to_string(),
+    }
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return identifier(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => format!("[]{}", prop.additional.get("items").map_or("interface{}".to_string(), schema_type)),
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return identifier(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("[]{}", schema.get("items").map_or("interface{}".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), Some("int32")) => "int32",
+        (Some("integer"), _) => "int64",
+        (Some("number"), Some("float")) => "float32",
+        (Some("number"), _) => "float64",
+        (Some("string"), _) => "string",
+        (Some("boolean"), _) => "bool",
+        (Some("object"), _) => "map[string]interface{}",
+        _ => "interface{}",
+    }
+}
+
+/// Exported Go identifier, upper-casing common initialisms such as `ID` and `URL`.
+fn identifier(name: &str) -> String {
+    let identifier: String = words(name).iter().map(|word| capitalize(word)).collect();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("X{}", identifier)
+    } else {
+        identifier
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    let words = words(name);
+    let argument: String = match words.split_first() {
+        Some((first, rest)) => first.clone() + &rest.iter().map(|word| capitalize(word)).collect::<String>(),
+        None => "param".to_string(),
+    };
+    if argument.starts_with(|c: char| c.is_ascii_digit()) || KEYWORDS.contains(&argument.as_str()) {
+        format!("{}Param", argument)
+    } else {
+        argument
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    if INITIALISMS.contains(&word) {
+        word.to_uppercase()
+    } else {
+        pascal_case(word)
+    }
+}
@@ -0,0 +1,227 @@
+use super::typescript::{
+    base_interfaces, discriminator_tag, extends_definition, generate_info_comment, has_flag, import_interfaces,
+    inline_properties, map_values, nested_definition, property_enum, string_literal, TypeOptions,
+};
+use crate::cli::{DateType, Int64Type, NullableStyle};
+use crate::spec::{ref_name, Definition, Property, Swagger};
+use serde_json::Value;
+
+/// `guards.ts`: an `is<Name>` type guard per definition, checking required fields and the types
+/// of the fields that are present without depending on a validation library.
+pub fn generate_guards(swagger: &Swagger, types: TypeOptions) -> String {
+    let mut names: Vec<&String> = swagger.definitions.keys().collect();
+    names.sort();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    import_interfaces(&mut ts_code, names.iter().map(|name| name.to_string()).collect(), "./interfaces");
+    ts_code.push_str(
+        "function isRecord(value: unknown): value is Record<string, unknown> {
+    return typeof value === 'object' && value !== null && !Array.isArray(value);
+}\n",
+    );
+
+    for name in names {
+        let guards = Guards { swagger, name, types };
+        let conditions = guards.definition(&swagger.definitions[name], "value");
+        ts_code.push_str(&format!(
+            "\nexport function is{}(value: unknown): value is {} {{\n    return {};\n}}\n",
+            name,
+            name,
+            join_conditions(&conditions)
+        ));
+    }
+    ts_code
+}
+
+struct Guards<'a> {
+    swagger: &'a Swagger,
+    name: &'a str,
+    types: TypeOptions,
+}
+
+impl Guards<'_> {
+    /// The conditions `expr` has to meet to be of the definition's type, all of which must hold.
+    fn definition(&self, definition: &Definition, expr: &str) -> Vec<String> {
+        if let Some(reference) = &definition.reference {
+            return self.reference(reference, expr).into_iter().collect();
+        }
+        if let Some(values) = &definition.enum_values {
+            return vec![enum_check(values, expr)];
+        }
+        if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+            let members: Vec<String> = members
+                .iter()
+                .map(|member| {
+                    let mut conditions = Vec::new();
+                    if let Some((property_name, tag)) = discriminator_tag(definition, member) {
+                        conditions.push(format!("isRecord({})", expr));
+                        conditions.push(format!("{} === {}", property_access(expr, property_name), string_literal(tag)));
+                    }
+                    conditions.extend(self.definition(member, expr));
+                    match conditions.as_slice() {
+                        [] => "true".to_string(),
+                        [condition] => condition.clone(),
+                        _ => format!("({})", conditions.join(" && ")),
+                    }
+                })
+                .collect();
+            return match members.as_slice() {
+                [] => Vec::new(),
+                [member] => vec![member.clone()],
+                _ => vec![format!("({})", members.join(" || "))],
+            };
+        }
+        if definition.properties.is_none() && definition.all_of.is_none() {
+            if let Some(values) = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false)) {
+                return vec![self.record(values, expr)];
+            }
+        }
+        if definition.properties.is_some() || definition.all_of.is_some() {
+            let mut conditions: Vec<String> = base_interfaces(definition)
+                .into_iter()
+                .filter(|base| !extends_definition(self.swagger, base, self.name))
+                .filter_map(|base| self.reference(base, expr))
+                .collect();
+            conditions.extend(self.object(inline_properties(definition), expr));
+            return conditions;
+        }
+        match definition.definition_type.as_deref() {
+            Some("string") => vec![format!("typeof {} === 'string'", expr)],
+            Some("integer") | Some("number") => vec![format!("typeof {} === 'number'", expr)],
+            Some("boolean") => vec![format!("typeof {} === 'boolean'", expr)],
+            Some("array") => vec![format!("Array.isArray({})", expr)],
+            _ => Vec::new(),
+        }
+    }
+
+    fn reference(&self, reference: &str, expr: &str) -> Option<String> {
+        let name = ref_name(reference);
+        self.swagger.definitions.contains_key(name).then(|| format!("is{}({})", name, expr))
+    }
+
+    fn object(&self, properties: Vec<(&String, &Property, bool)>, expr: &str) -> Vec<String> {
+        let mut conditions = vec![format!("isRecord({})", expr)];
+        for (prop_name, prop, required) in properties {
+            if let Some(condition) = self.field(prop, required, &property_access(expr, prop_name)) {
+                conditions.push(condition);
+            }
+        }
+        conditions
+    }
+
+    fn record(&self, values: &Value, expr: &str) -> String {
+        let value_check = serde_json::from_value::<Property>(values.clone())
+            .ok()
+            .and_then(|prop| self.property(&prop, "item"));
+        match value_check {
+            Some(check) => format!("isRecord({0}) && Object.values({0}).every((item) => {1})", expr, check),
+            None => format!("isRecord({})", expr),
+        }
+    }
+
+    /// The check for a property value, allowing `undefined` and `null` where the type does.
+    fn field(&self, prop: &Property, required: bool, expr: &str) -> Option<String> {
+        let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+        let optional = !required || (nullable && self.types.nullable_style == NullableStyle::Optional);
+        let check = self.property(prop, expr);
+        if check.is_none() && optional {
+            return None;
+        }
+        let mut alternatives = Vec::new();
+        if optional {
+            alternatives.push(format!("{} === undefined", expr));
+        }
+        if nullable {
+            alternatives.push(format!("{} === null", expr));
+        }
+        match check {
+            None => Some(format!("{} !== undefined", expr)),
+            Some(check) if alternatives.is_empty() => Some(check),
+            Some(check) if check.contains(" && ") => {
+                Some(format!("({} || ({}))", alternatives.join(" || "), check))
+            }
+            Some(check) => Some(format!("({} || {})", alternatives.join(" || "), check)),
+        }
+    }
+
+    /// A check for a property value, or `None` when any value is accepted.
+    fn property(&self, prop: &Property, expr: &str) -> Option<String> {
+        if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference, expr);
+        }
+        if let Some((values, is_array)) = property_enum(prop) {
+            return Some(if is_array {
+                format!("Array.isArray({}) && {}.every((item) => {})", expr, expr, enum_check(values, "item"))
+            } else {
+                enum_check(values, expr)
+            });
+        }
+        if let Some(nested) = nested_definition(prop) {
+            return Some(self.object(inline_properties(&nested), expr).join(" && "));
+        }
+        if let Some(values) = map_values(prop) {
+            return Some(self.record(values, expr));
+        }
+
+        let type_of = |name: &str| Some(format!("typeof {} === '{}'", expr, name));
+        match prop.property_type.as_deref() {
+            Some("string") => match prop.format.as_deref() {
+                Some("date") | Some("date-time") if self.types.date_type == DateType::Date => {
+                    Some(format!("{} instanceof Date", expr))
+                }
+                Some("binary") => Some(format!("{} instanceof Blob", expr)),
+                Some("byte") => Some(format!("{} instanceof Uint8Array", expr)),
+                _ => type_of("string"),
+            },
+            Some("integer") if prop.format.as_deref() == Some("int64") => match self.types.int64_type {
+                Int64Type::Number => type_of("number"),
+                Int64Type::Bigint => type_of("bigint"),
+                Int64Type::String => type_of("string"),
+            },
+            Some("integer") | Some("number") => type_of("number"),
+            Some("boolean") => type_of("boolean"),
+            Some("array") => {
+                let items = prop
+                    .additional
+                    .get("items")
+                    .and_then(|items| serde_json::from_value::<Property>(items.clone()).ok())
+                    .and_then(|items| self.property(&items, "item"));
+                Some(match items {
+                    Some(check) => format!("Array.isArray({0}) && {0}.every((item) => {1})", expr, check),
+                    None => format!("Array.isArray({})", expr),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+fn enum_check(values: &[Value], expr: &str) -> String {
+    let literals: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::String(value) => string_literal(value),
+            value => value.to_string(),
+        })
+        .collect();
+    format!("([{}] as unknown[]).includes({})", literals.join(", "), expr)
+}
+
+fn property_access(expr: &str, name: &str) -> String {
+    let identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        format!("{}.{}", expr, name)
+    } else {
+        format!("{}[{}]", expr, string_literal(name))
+    }
+}
+
+fn join_conditions(conditions: &[String]) -> String {
+    match conditions {
+        [] => "true".to_string(),
+        [condition] => condition.clone(),
+        _ => format!("(\n        {}\n    )", conditions.join(" &&\n        ")),
+    }
+}
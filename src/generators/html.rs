@@ -0,0 +1,423 @@
+use super::mock::{to_js, Examples};
+use super::{
+    base_url, consumes, info_lines, operation_name, operations_by_tag, produces, request_media_type,
+    security_requirements, success_responses, words,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Operation, Parameter, Swagger};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// The page is laid out as Redoc lays out its own: the navigation on the left, and for each
+/// operation its documentation next to a dark column of examples.
+const STYLE: &str = "* { box-sizing: border-box; }
+body { margin: 0; font: 15px/1.5 -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; color: #333; }
+code, pre { font-family: SFMono-Regular, Consolas, 'Liberation Mono', monospace; font-size: 13px; }
+a { color: #3b6fb6; text-decoration: none; }
+nav { position: fixed; top: 0; bottom: 0; left: 0; width: 280px; overflow-y: auto; padding: 16px; background: #fafafa; border-right: 1px solid #e4e4e4; }
+nav input { width: 100%; padding: 6px 8px; margin-bottom: 12px; border: 1px solid #ccc; border-radius: 4px; }
+nav .title { display: block; font-weight: 600; font-size: 17px; margin-bottom: 12px; color: #333; }
+nav p { margin: 16px 0 4px; font-size: 12px; font-weight: 600; text-transform: uppercase; color: #888; }
+nav ul { list-style: none; margin: 0; padding: 0; }
+nav li a { display: flex; gap: 8px; align-items: center; padding: 3px 0; color: #333; word-break: break-all; }
+main { margin-left: 280px; }
+header, section > h2, .schema { padding: 0 40px; max-width: 1000px; }
+header { padding-top: 24px; }
+section > h2 { margin: 40px 0 0; padding-top: 16px; border-top: 1px solid #e4e4e4; }
+.operation { display: grid; grid-template-columns: minmax(0, 3fr) minmax(0, 2fr); border-bottom: 1px solid #e4e4e4; }
+.operation .doc { padding: 16px 40px 32px; }
+.operation .examples { padding: 16px 24px 32px; background: #263238; color: #eee; }
+.examples h5 { margin: 16px 0 8px; font-size: 13px; color: #bbb; font-weight: 600; }
+.examples pre { margin: 0; padding: 12px; overflow-x: auto; background: #11171a; border-radius: 4px; }
+h3 { display: flex; gap: 10px; align-items: center; font-size: 18px; word-break: break-all; }
+h4 { margin: 24px 0 8px; font-size: 13px; text-transform: uppercase; color: #666; }
+table { width: 100%; border-collapse: collapse; }
+th, td { text-align: left; vertical-align: top; padding: 6px 8px; border-bottom: 1px solid #eee; }
+th { font-size: 12px; color: #888; font-weight: 600; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 4px 16px; margin: 12px 0; }
+dt { color: #888; }
+dd { margin: 0; }
+.method { display: inline-block; min-width: 52px; padding: 1px 6px; border-radius: 3px; color: #fff; font-size: 11px; font-weight: 700; text-align: center; text-transform: uppercase; }
+.get { background: #2f8132; } .post { background: #186faf; } .put { background: #95507c; } .patch { background: #bf581d; }
+.delete { background: #cc3333; } .head, .options, .trace { background: #777; }
+.deprecated { text-decoration: line-through; }
+.badge { padding: 1px 6px; border-radius: 3px; background: #f6e7c8; color: #8a5a00; font-size: 12px; }
+.required { color: #cc3333; font-size: 12px; }
+@media (max-width: 900px) {
+    nav { position: static; width: auto; border-right: 0; }
+    main { margin-left: 0; }
+    .operation { grid-template-columns: 1fr; }
+}
+";
+
+/// Leaves in the navigation only the operations and schemas whose text holds the filter.
+const SCRIPT: &str = "document.getElementById('filter').addEventListener('input', (event) => {
+    const query = event.target.value.trim().toLowerCase();
+    for (const item of document.querySelectorAll('nav li')) {
+        item.hidden = query !== '' && !item.textContent.toLowerCase().includes(query);
+    }
+});
+";
+
+/// Writes `index.html`: a single self-contained page documenting the operations, grouped by tag,
+/// with their parameters, bodies, responses and examples, and the schemas they refer to.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("index.html"), Page { swagger, examples: Examples::new(swagger) }.render());
+    files
+}
+
+/// `text` escaped for the content and the attributes of HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+/// Free text as paragraphs, which blank lines separate.
+fn paragraphs(text: &str) -> String {
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| format!("<p>{}</p>\n", escape(paragraph).replace('\n', "<br>")))
+        .collect()
+}
+
+fn operation_id(method: &str, path: &str, operation: &Operation) -> String {
+    format!("operation-{}", words(&operation_name(method, path, operation)).join("-"))
+}
+
+fn schema_id(name: &str) -> String {
+    format!("schema-{}", escape(name))
+}
+
+fn enum_values(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::String(value) => format!("<code>{}</code>", escape(value)),
+            value => format!("<code>{}</code>", escape(&value.to_string())),
+        })
+        .collect();
+    format!("One of {}.", values.join(", "))
+}
+
+/// A description followed by the values it is restricted to.
+fn described(description: Option<&str>, values: Option<&Vec<Value>>) -> String {
+    let mut html = description.map(|description| escape(description.trim()).replace('\n', "<br>")).unwrap_or_default();
+    if let Some(values) = values {
+        if !html.is_empty() {
+            html.push(' ');
+        }
+        html.push_str(&enum_values(values));
+    }
+    html
+}
+
+fn schema_enum(schema: &Value) -> Option<&Vec<Value>> {
+    schema.get("enum").or_else(|| schema.get("items")?.get("enum"))?.as_array()
+}
+
+struct Page<'a> {
+    swagger: &'a Swagger,
+    examples: Examples,
+}
+
+impl Page<'_> {
+    fn render(&self) -> String {
+        let info = |key: &str| self.swagger.info.get(key).and_then(Value::as_str);
+        let title = info("title").unwrap_or("API");
+        let groups = operations_by_tag(self.swagger);
+
+        let mut html = String::from("<!DOCTYPE html>\n<!--\n");
+        for line in info_lines(self.swagger) {
+            html.push_str(&format!("  {}\n", escape(&line).replace("--", "&#45;&#45;")));
+        }
+        html.push_str("-->\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+        html.push_str("<meta name=\"generator\" content=\"swagger-generator\">\n");
+        html.push_str(&format!("<title>{}</title>\n", escape(title)));
+        html.push_str(&format!("<style>\n{}</style>\n</head>\n<body>\n", STYLE));
+
+        html.push_str("<nav>\n<input id=\"filter\" type=\"search\" placeholder=\"Filter\" aria-label=\"Filter\">\n");
+        html.push_str(&format!("<a class=\"title\" href=\"#top\">{}</a>\n", escape(title)));
+        for (tag, operations) in &groups {
+            html.push_str(&format!("<p>{}</p>\n<ul>\n", escape(tag)));
+            for (path, method, operation) in operations {
+                html.push_str(&format!(
+                    "<li><a href=\"#{}\"><span class=\"method {}\">{}</span><span{}>{}</span></a></li>\n",
+                    operation_id(method, path, operation),
+                    method,
+                    method,
+                    if operation.deprecated { " class=\"deprecated\"" } else { "" },
+                    escape(operation.summary.as_deref().unwrap_or(path))
+                ));
+            }
+            html.push_str("</ul>\n");
+        }
+        if !self.swagger.definitions.is_empty() {
+            html.push_str("<p>Schemas</p>\n<ul>\n");
+            for name in self.swagger.definitions.keys() {
+                html.push_str(&format!("<li><a href=\"#{}\">{}</a></li>\n", schema_id(name), escape(name)));
+            }
+            html.push_str("</ul>\n");
+        }
+        html.push_str("</nav>\n<main>\n");
+
+        html.push_str("<header id=\"top\">\n");
+        html.push_str(&format!("<h1>{}</h1>\n", escape(title)));
+        html.push_str(&paragraphs(info("description").unwrap_or_default()));
+        html.push_str("<dl>\n");
+        if let Some(version) = info("version") {
+            html.push_str(&format!("<dt>Version</dt><dd>{}</dd>\n", escape(version)));
+        }
+        html.push_str(&format!("<dt>Base URL</dt><dd><code>{}</code></dd>\n", escape(&base_url(self.swagger))));
+        for server in self.swagger.servers.iter().skip(1) {
+            let description = server.description.as_deref().map(|d| format!(" {}", escape(d))).unwrap_or_default();
+            html.push_str(&format!("<dt>Server</dt><dd><code>{}</code>{}</dd>\n", escape(&server.url), description));
+        }
+        html.push_str("</dl>\n</header>\n");
+
+        for (tag, operations) in &groups {
+            html.push_str(&format!("<section>\n<h2>{}</h2>\n", escape(tag)));
+            for (path, method, operation) in operations {
+                html.push_str(&self.operation(method, path, operation));
+            }
+            html.push_str("</section>\n");
+        }
+        if !self.swagger.definitions.is_empty() {
+            html.push_str("<section id=\"schemas\">\n<h2>Schemas</h2>\n");
+            for name in self.swagger.definitions.keys() {
+                html.push_str(&self.schema(name));
+            }
+            html.push_str("</section>\n");
+        }
+        html.push_str(&format!("</main>\n<script>\n{}</script>\n</body>\n</html>\n", SCRIPT));
+        html
+    }
+
+    fn operation(&self, method: &str, path: &str, operation: &Operation) -> String {
+        let mut doc = String::new();
+        let mut examples = String::new();
+        let deprecated = if operation.deprecated { " <span class=\"badge\">Deprecated</span>" } else { "" };
+        doc.push_str(&format!(
+            "<h3><span class=\"method {}\">{}</span><code{}>{}</code>{}</h3>\n",
+            method,
+            method,
+            if operation.deprecated { " class=\"deprecated\"" } else { "" },
+            escape(path),
+            deprecated
+        ));
+        if let Some(summary) = &operation.summary {
+            doc.push_str(&paragraphs(summary));
+        }
+        if let Some(description) = operation.description.as_deref().filter(|d| Some(*d) != operation.summary.as_deref())
+        {
+            doc.push_str(&paragraphs(description));
+        }
+
+        let mut facts = Vec::new();
+        if let Some(id) = &operation.operation_id {
+            facts.push(("Operation ID", format!("<code>{}</code>", escape(id))));
+        }
+        let requirements = security_requirements(self.swagger, operation);
+        if !requirements.is_empty() {
+            let requirements: Vec<String> = requirements
+                .iter()
+                .map(|names| {
+                    let names: Vec<String> =
+                        names.iter().map(|name| format!("<code>{}</code>", escape(name))).collect();
+                    names.join(" + ")
+                })
+                .collect();
+            facts.push(("Security", requirements.join(" or ")));
+        }
+        let produces = produces(self.swagger, operation);
+        if !produces.is_empty() {
+            let produces: Vec<String> =
+                produces.iter().map(|media| format!("<code>{}</code>", escape(media))).collect();
+            facts.push(("Produces", produces.join(", ")));
+        }
+        if !facts.is_empty() {
+            doc.push_str("<dl>\n");
+            for (term, definition) in facts {
+                doc.push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", term, definition));
+            }
+            doc.push_str("</dl>\n");
+        }
+
+        let parameters: Vec<&Parameter> =
+            operation.parameters.iter().filter(|param| param.location != "body").collect();
+        if !parameters.is_empty() {
+            doc.push_str("<h4>Parameters</h4>\n<table>\n");
+            doc.push_str("<tr><th>Name</th><th>In</th><th>Type</th><th>Description</th></tr>\n");
+            for param in parameters {
+                let required = param.required || param.location == "path";
+                let values =
+                    param.enum_values.as_ref().or(param.items.as_ref().and_then(|items| items.enum_values.as_ref()));
+                doc.push_str(&format!(
+                    "<tr><td><code>{}</code>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape(&param.name),
+                    if required { " <span class=\"required\">required</span>" } else { "" },
+                    escape(&param.location),
+                    self.parameter_type(param),
+                    described(param.description.as_deref(), values)
+                ));
+            }
+            doc.push_str("</table>\n");
+        }
+
+        if let Some(body) = operation.parameters.iter().find(|param| param.location == "body") {
+            let schema = body.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok()).unwrap_or(json!({}));
+            let media = if consumes(self.swagger, operation).is_empty() {
+                "application/json"
+            } else {
+                request_media_type(self.swagger, operation)
+            };
+            doc.push_str("<h4>Request body</h4>\n");
+            doc.push_str(&format!(
+                "<p><code>{}</code> {}{}</p>\n",
+                escape(media),
+                self.value_type(&schema),
+                if body.required { " <span class=\"required\">required</span>" } else { "" }
+            ));
+            if let Some(description) = &body.description {
+                doc.push_str(&paragraphs(description));
+            }
+            if media.contains("json") {
+                examples.push_str("<h5>Request body</h5>\n");
+                examples.push_str(&format!("<pre>{}</pre>\n", escape(&to_js(&self.examples.example(&schema)))));
+            }
+        }
+
+        if !operation.responses.is_empty() {
+            doc.push_str("<h4>Responses</h4>\n<table>\n");
+            doc.push_str("<tr><th>Status</th><th>Description</th><th>Type</th></tr>\n");
+            for (status, response) in &operation.responses {
+                let schema = response.response_schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok());
+                doc.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape(status),
+                    escape(response.description.trim()),
+                    schema.as_ref().map_or(String::new(), |schema| self.value_type(schema))
+                ));
+            }
+            doc.push_str("</table>\n");
+        }
+        for (status, response) in success_responses(operation) {
+            let Some(schema) = response.response_schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok())
+            else {
+                continue;
+            };
+            let example = self.examples.example(&schema);
+            if !example.is_null() {
+                examples.push_str(&format!("<h5>Response {}</h5>\n", escape(status)));
+                examples.push_str(&format!("<pre>{}</pre>\n", escape(&to_js(&example))));
+            }
+        }
+
+        format!(
+            "<article class=\"operation\" id=\"{}\">\n<div class=\"doc\">\n{}</div>\n<div class=\"examples\">\n{}</div>\n</article>\n",
+            operation_id(method, path, operation),
+            doc,
+            examples
+        )
+    }
+
+    fn schema(&self, name: &str) -> String {
+        let Some(definition) = self.swagger.definitions.get(name).and_then(|d| serde_json::to_value(d).ok()) else {
+            return String::new();
+        };
+        let mut html = format!("<article class=\"schema\" id=\"{}\">\n<h3>{}</h3>\n", schema_id(name), escape(name));
+        if let Some(description) = definition.get("description").and_then(Value::as_str) {
+            html.push_str(&paragraphs(description));
+        }
+        let parts = definition.get("allOf").and_then(Value::as_array);
+        let bases: Vec<String> = parts
+            .into_iter()
+            .flatten()
+            .filter(|part| part.get("$ref").is_some())
+            .map(|part| self.value_type(part))
+            .collect();
+        if !bases.is_empty() {
+            html.push_str(&format!("<p>Extends {}.</p>\n", bases.join(", ")));
+        }
+        if let Some(members) = definition.get("oneOf").or_else(|| definition.get("anyOf")).and_then(Value::as_array) {
+            let members: Vec<String> = members.iter().map(|member| self.value_type(member)).collect();
+            html.push_str(&format!("<p>One of {}.</p>\n", members.join(", ")));
+        }
+        if let Some(values) = schema_enum(&definition) {
+            html.push_str(&format!("<p>{}</p>\n", enum_values(values)));
+        }
+
+        let own =
+            std::iter::once(&definition).chain(parts.into_iter().flatten().filter(|part| part.get("$ref").is_none()));
+        let mut rows = String::new();
+        for part in own {
+            let required: Vec<&str> = part
+                .get("required")
+                .and_then(Value::as_array)
+                .map(|required| required.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+            for (prop_name, prop) in part.get("properties").and_then(Value::as_object).into_iter().flatten() {
+                rows.push_str(&format!(
+                    "<tr><td><code>{}</code>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    escape(prop_name),
+                    if required.contains(&prop_name.as_str()) {
+                        " <span class=\"required\">required</span>"
+                    } else {
+                        ""
+                    },
+                    self.value_type(prop),
+                    described(prop.get("description").and_then(Value::as_str), schema_enum(prop))
+                ));
+            }
+        }
+        if !rows.is_empty() {
+            html.push_str("<table>\n<tr><th>Property</th><th>Type</th><th>Description</th></tr>\n");
+            html.push_str(&rows);
+            html.push_str("</table>\n");
+        }
+        html.push_str("</article>\n");
+        html
+    }
+
+    fn parameter_type(&self, param: &Parameter) -> String {
+        if let Some(schema) = &param.schema {
+            return serde_json::to_value(schema).map_or("any".to_string(), |schema| self.value_type(&schema));
+        }
+        let mut schema = json!({ "type": param.parameter_type, "format": param.format });
+        if let Some(items) = &param.items {
+            schema["items"] = serde_json::to_value(items).unwrap_or(Value::Null);
+        }
+        self.value_type(&schema)
+    }
+
+    /// The type of a schema, linking to the schemas it refers to.
+    fn value_type(&self, schema: &Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = ref_name(reference);
+            return format!("<a href=\"#{}\">{}</a>", schema_id(name), escape(name));
+        }
+        for (key, separator) in [("oneOf", " or "), ("anyOf", " or "), ("allOf", " and ")] {
+            if let Some(members) = schema.get(key).and_then(Value::as_array) {
+                let members: Vec<String> = members.iter().map(|member| self.value_type(member)).collect();
+                return members.join(separator);
+            }
+        }
+        let format = schema.get("format").and_then(Value::as_str);
+        match schema.get("type").and_then(Value::as_str) {
+            Some("array") => match schema.get("items") {
+                Some(items) => format!("array of {}", self.value_type(items)),
+                None => "array".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties").filter(|values| values.is_object()) {
+                Some(values) => format!("map of {}", self.value_type(values)),
+                None => "object".to_string(),
+            },
+            Some(schema_type) => match format {
+                Some(format) => format!("{} ({})", escape(schema_type), escape(format)),
+                None => escape(schema_type),
+            },
+            None => "any".to_string(),
+        }
+    }
+}
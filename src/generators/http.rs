@@ -0,0 +1,220 @@
+use super::mock::{to_js, Examples};
+use super::{
+    accept_header, base_url, collection_delimiter, info_lines, operation_name, request_media_type,
+    security_requirements, with_custom_region, MULTIPART, URL_ENCODED,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{Operation, Parameter, Swagger};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Separates the parts of multipart bodies.
+const BOUNDARY: &str = "boundary";
+
+/// Writes `requests.http`: a request per operation in the format the REST Client extension of
+/// VS Code and the HTTP client of IntelliJ share, with example values in its path, query, headers
+/// and body and a variable per security scheme to fill in.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let examples = Examples::new(swagger);
+    let mut http = String::new();
+    for line in info_lines(swagger) {
+        http.push_str(&format!("{}\n", format!("# {}", line).trim_end()));
+    }
+    http.push_str(&format!("\n@baseUrl = {}\n", base_url(swagger)));
+    for (name, scheme) in &swagger.security_definitions {
+        if scheme.scheme_type == "basic" {
+            http.push_str(&format!("@{0}_username =\n@{0}_password =\n", variable(name)));
+        } else {
+            http.push_str(&format!("@{} =\n", variable(name)));
+        }
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let request = Request { swagger, examples: &examples, method, path, operation };
+            http.push('\n');
+            http.push_str(&request.generate());
+        }
+    }
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("requests.http"), with_custom_region(http, "#"));
+    files
+}
+
+/// The name of the variable holding the credential of a security scheme, which the clients only
+/// allow word characters in.
+pub fn variable(scheme: &str) -> String {
+    scheme.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect()
+}
+
+/// `value` percent-encoded for a path segment or a query string.
+pub fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// An example value as the text of a parameter or a form field, with the items of arrays
+/// separated by commas.
+pub fn text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        Value::Array(items) => items.iter().map(text).collect::<Vec<_>>().join(","),
+        value => value.to_string(),
+    }
+}
+
+/// An example value as the texts a query parameter is sent as: one, with the items of an array
+/// joined by the delimiter of its `collectionFormat`, or one per item when they are repeated.
+pub fn query_texts(param: &Parameter, value: &Value) -> Vec<String> {
+    match (value, collection_delimiter(param)) {
+        (Value::Array(items), Some(delimiter)) => vec![items.iter().map(text).collect::<Vec<_>>().join(delimiter)],
+        (Value::Array(items), None) => items.iter().map(text).collect(),
+        (value, _) => vec![text(value)],
+    }
+}
+
+struct Request<'a> {
+    swagger: &'a Swagger,
+    examples: &'a Examples,
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+}
+
+impl Request<'_> {
+    fn generate(&self) -> String {
+        let title = self.operation.summary.as_deref().map(str::trim).filter(|summary| !summary.is_empty());
+        let mut http = format!("### {}\n", title.unwrap_or(&format!("{} {}", self.method.to_uppercase(), self.path)));
+        http.push_str(&format!("# @name {}\n", operation_name(self.method, self.path, self.operation)));
+        if self.operation.deprecated {
+            http.push_str("# Deprecated\n");
+        }
+
+        let mut url = self.path.to_string();
+        let mut query = Vec::new();
+        let mut headers = Vec::new();
+        for param in &self.operation.parameters {
+            let example = self.examples.example(&parameter_schema(param));
+            let value = text(&example);
+            match param.location.as_str() {
+                "path" => url = url.replace(&format!("{{{}}}", param.name), &encode(&value)),
+                "query" => query.extend(
+                    query_texts(param, &example).iter().map(|value| format!("{}={}", encode(&param.name), encode(value))),
+                ),
+                "header" => headers.push(format!("{}: {}", param.name, value)),
+                "cookie" => headers.push(format!("Cookie: {}={}", param.name, value)),
+                _ => {}
+            }
+        }
+        let requirement = security_requirements(self.swagger, self.operation).into_iter().next().unwrap_or_default();
+        for name in requirement {
+            let Some(scheme) = self.swagger.security_definitions.get(&name) else {
+                continue;
+            };
+            let key = scheme.name.as_deref().unwrap_or(&name);
+            match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+                ("basic", _) => headers
+                    .push(format!("Authorization: Basic {{{{{0}_username}}}} {{{{{0}_password}}}}", variable(&name))),
+                ("apiKey", Some("query")) => query.push(format!("{}={{{{{}}}}}", encode(key), variable(&name))),
+                ("apiKey", Some("cookie")) => headers.push(format!("Cookie: {}={{{{{}}}}}", key, variable(&name))),
+                ("apiKey", _) => headers.push(format!("{}: {{{{{}}}}}", key, variable(&name))),
+                _ => headers.push(format!("Authorization: Bearer {{{{{}}}}}", variable(&name))),
+            }
+        }
+        if let Some(accept) = accept_header(self.swagger, self.operation) {
+            headers.push(format!("Accept: {}", accept));
+        }
+
+        http.push_str(&format!("{} {{{{baseUrl}}}}{}", self.method.to_uppercase(), url));
+        for (i, pair) in query.iter().enumerate() {
+            http.push_str(&format!("\n    {}{}", if i == 0 { '?' } else { '&' }, pair));
+        }
+        http.push('\n');
+        let body = self.body();
+        if let Some((content_type, _)) = &body {
+            headers.push(format!("Content-Type: {}", content_type));
+        }
+        for header in headers {
+            http.push_str(&format!("{}\n", header));
+        }
+        if let Some((_, body)) = body {
+            http.push_str(&format!("\n{}\n", body));
+        }
+        http
+    }
+
+    /// The content type and the example of the body of the operation, if it sends one.
+    fn body(&self) -> Option<(String, String)> {
+        let media = request_media_type(self.swagger, self.operation);
+        let mut fields: Vec<(String, Option<String>)> = Vec::new();
+        for param in &self.operation.parameters {
+            match param.location.as_str() {
+                "formData" if param.parameter_type.as_deref() == Some("file") => {
+                    fields.push((param.name.clone(), None))
+                }
+                "formData" => fields.push((param.name.clone(), Some(self.parameter_example(param)))),
+                "body" => {
+                    let schema = param.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok());
+                    let example = self.examples.example(&schema.unwrap_or(json!({})));
+                    match example {
+                        Value::Object(properties) if [MULTIPART, URL_ENCODED].contains(&media) => {
+                            fields.extend(properties.iter().map(|(name, value)| (name.clone(), Some(text(value)))));
+                        }
+                        example if media.contains("json") => return Some((media.to_string(), to_js(&example))),
+                        example => return Some((media.to_string(), text(&example))),
+                    }
+                }
+                _ => {}
+            }
+        }
+        if fields.is_empty() {
+            return None;
+        }
+        if media == URL_ENCODED {
+            let pairs: Vec<String> = fields
+                .iter()
+                .map(|(name, value)| format!("{}={}", encode(name), encode(value.as_deref().unwrap_or_default())))
+                .collect();
+            return Some((media.to_string(), pairs.join("&")));
+        }
+        let mut body = String::new();
+        for (name, value) in fields {
+            body.push_str(&format!("--{}\n", BOUNDARY));
+            match value {
+                Some(value) => {
+                    body.push_str(&format!("Content-Disposition: form-data; name=\"{}\"\n\n{}\n", name, value))
+                }
+                None => body.push_str(&format!(
+                    "Content-Disposition: form-data; name=\"{0}\"; filename=\"{0}\"\nContent-Type: application/octet-stream\n\n< ./{0}\n",
+                    name
+                )),
+            }
+        }
+        body.push_str(&format!("--{}--", BOUNDARY));
+        Some((format!("{}; boundary={}", MULTIPART, BOUNDARY), body))
+    }
+
+    fn parameter_example(&self, param: &Parameter) -> String {
+        text(&self.examples.example(&parameter_schema(param)))
+    }
+}
+
+/// The schema of a parameter as JSON, from its `schema` or from its own type, format, items and
+/// values.
+pub fn parameter_schema(param: &Parameter) -> Value {
+    if let Some(schema) = param.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok()) {
+        return schema;
+    }
+    let mut schema = json!({ "type": param.parameter_type, "format": param.format, "enum": param.enum_values });
+    if let Some(items) = &param.items {
+        schema["items"] = serde_json::to_value(items).unwrap_or(Value::Null);
+    }
+    schema
+}
@@ -0,0 +1,937 @@
+use super::typescript::{form_parameters, header_parameters, query_parameters};
+use super::{
+    base_url, binary_body, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_binary_responses, has_request_body, info_block, item_reference, operation_name,
+    operations_by_tag, package_name, package_version, pascal_case, request_media_type, response_items,
+    response_reference, security_requirements, security_schemes, with_custom_region, words, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class", "const", "continue",
+    "default", "do", "double", "else", "enum", "extends", "false", "final", "finally", "float", "for", "goto", "if",
+    "implements", "import", "instanceof", "int", "interface", "long", "native", "new", "null", "package", "private",
+    "protected", "public", "return", "short", "static", "strictfp", "super", "switch", "synchronized", "this",
+    "throw", "throws", "transient", "true", "try", "void", "volatile", "while", "body", "client",
+];
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let package = package_name(swagger);
+    let source_dir = PathBuf::from("src").join("main").join("java").join(&package);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("pom.xml"), generate_pom(swagger, &package));
+    let client = with_custom_region(generate_api_client(swagger, &package, retries), "//");
+    files.insert(source_dir.join("ApiClient.java"), client);
+    files.insert(source_dir.join("ApiException.java"), generate_api_exception(swagger, &package));
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = source_dir.join("model").join(format!("{}.java", type_name(name)));
+        (path, generate_record(swagger, &package, name, definition))
+    }));
+    files.par_extend(operations_by_tag(swagger).into_par_iter().map(|(tag, operations)| {
+        let tag_package = package_segment(&tag);
+        let path = source_dir.join(&tag_package).join(format!("{}Service.java", type_name(&tag)));
+        (path, generate_service(swagger, &package, &tag_package, &tag, &operations))
+    }));
+    files
+}
+
+fn generate_header(swagger: &Swagger, package: &str) -> String {
+    format!("{}\n\npackage {};\n\n", info_block(swagger), package)
+}
+
+fn generate_pom(swagger: &Swagger, package: &str) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    let artifact = match words(title).join("-") {
+        name if name.is_empty() => "api-client".to_string(),
+        name => format!("{}-client", name),
+    };
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0"
+         xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"
+         xsi:schemaLocation="http://maven.apache.org/POM/4.0.0 http://maven.apache.org/xsd/maven-4.0.0.xsd">
+    <modelVersion>4.0.0</modelVersion>
+
+    <groupId>{}</groupId>
+    <artifactId>{}</artifactId>
+    <version>{}</version>
+
+    <properties>
+        <maven.compiler.release>17</maven.compiler.release>
+        <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+    </properties>
+
+    <dependencies>
+        <dependency>
+            <groupId>com.fasterxml.jackson.core</groupId>
+            <artifactId>jackson-databind</artifactId>
+            <version>2.17.0</version>
+        </dependency>
+    </dependencies>
+</project>
+"#,
+        package,
+        artifact,
+        package_version(swagger)
+    )
+}
+
+fn generate_api_exception(swagger: &Swagger, package: &str) -> String {
+    let mut java_code = generate_header(swagger, package);
+    java_code.push_str(
+        "public class ApiException extends RuntimeException {
+    private final int statusCode;
+    private final String responseBody;
+
+    public ApiException(int statusCode, String responseBody) {
+        super(\"Unexpected status \" + statusCode + \": \" + responseBody);
+        this.statusCode = statusCode;
+        this.responseBody = responseBody;
+    }
+
+    public ApiException(Throwable cause) {
+        super(cause);
+        this.statusCode = 0;
+        this.responseBody = null;
+    }
+
+    public int getStatusCode() {
+        return statusCode;
+    }
+
+    public String getResponseBody() {
+        return responseBody;
+    }
+}
+",
+    );
+    java_code
+}
+
+fn generate_api_client(swagger: &Swagger, package: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    // File downloads need the body as bytes, which the JSON responses then decode from as well.
+    let binary = has_binary_responses(swagger);
+    let (body_type, body_handler) = if binary { ("byte[]", "ofByteArray") } else { ("String", "ofString") };
+    let operations: Vec<&Operation> =
+        swagger.paths.values().flat_map(|path_item| path_item.operations()).map(|(_, operation)| operation).collect();
+    let has_query = operations.iter().any(|operation| !query_parameters(operation).is_empty());
+    let has_headers = operations.iter().any(|operation| !header_parameters(operation).is_empty());
+    let joins_arrays = operations.iter().any(|operation| {
+        let mut params = query_parameters(operation).into_iter().chain(header_parameters(operation));
+        params.any(|param| collection_delimiter(param).is_some())
+    });
+    let has_forms = operations.iter().any(|operation| !form_parameters(operation).is_empty());
+    let has_media_types = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(method, operation)| body_media_type(swagger, method, operation).is_some());
+    let has_encoded = has_forms || has_media_types;
+
+    let mut java_code = generate_header(swagger, package);
+    java_code.push_str("import com.fasterxml.jackson.core.type.TypeReference;\n");
+    java_code.push_str("import com.fasterxml.jackson.databind.DeserializationFeature;\n");
+    java_code.push_str("import com.fasterxml.jackson.databind.JavaType;\n");
+    java_code.push_str("import com.fasterxml.jackson.databind.ObjectMapper;\n");
+    if has_forms {
+        java_code.push_str("import java.io.ByteArrayOutputStream;\n");
+    }
+    java_code.push_str("import java.io.IOException;\n");
+    java_code.push_str("import java.net.URI;\n");
+    java_code.push_str("import java.net.URLEncoder;\n");
+    java_code.push_str("import java.net.http.HttpClient;\n");
+    java_code.push_str("import java.net.http.HttpRequest;\n");
+    java_code.push_str("import java.net.http.HttpResponse;\n");
+    java_code.push_str("import java.nio.charset.StandardCharsets;\n");
+    if retries.is_some() {
+        java_code.push_str("import java.time.Duration;\n");
+        java_code.push_str("import java.time.Instant;\n");
+        java_code.push_str("import java.time.ZonedDateTime;\n");
+        java_code.push_str("import java.time.format.DateTimeFormatter;\n");
+        java_code.push_str("import java.time.format.DateTimeParseException;\n");
+    }
+    if has_auth {
+        java_code.push_str("import java.util.Base64;\n");
+        java_code.push_str("import java.util.HashMap;\n");
+    }
+    if has_headers {
+        java_code.push_str("import java.util.LinkedHashMap;\n");
+    }
+    java_code.push_str("import java.util.List;\n");
+    java_code.push_str("import java.util.Map;\n");
+    if has_forms {
+        java_code.push_str("import java.util.UUID;\n");
+    }
+    java_code.push('\n');
+
+    java_code.push_str("public class ApiClient {\n");
+    java_code.push_str(&format!("    public static final String DEFAULT_BASE_URL = \"{}\";\n\n", base_url(swagger)));
+    if let Some(retries) = retries {
+        java_code.push_str(&format!("    public static final int MAX_RETRIES = {};\n\n", retries));
+    }
+    if has_auth {
+        java_code.push_str("    private static final Map<String, String[]> SECURITY_SCHEMES = Map.ofEntries(\n");
+        let entries: Vec<String> = security_schemes(swagger)
+            .iter()
+            .map(|(name, scheme)| {
+                format!(
+                    "        Map.entry(\"{}\", new String[] {{\"{}\", \"{}\", \"{}\"}})",
+                    name,
+                    scheme.scheme_type,
+                    scheme.location.as_deref().unwrap_or_default(),
+                    scheme.name.as_deref().unwrap_or(name)
+                )
+            })
+            .collect();
+        java_code.push_str(&entries.join(",\n"));
+        java_code.push_str("\n    );\n\n");
+    }
+    java_code.push_str(
+        "    private final String baseUrl;
+    private final HttpClient httpClient;
+    private final ObjectMapper mapper = new ObjectMapper()
+        .configure(DeserializationFeature.FAIL_ON_UNKNOWN_PROPERTIES, false);\n",
+    );
+    if has_auth {
+        java_code.push_str("    private final Map<String, String> credentials = new HashMap<>();\n");
+    }
+    java_code.push_str(
+        "
+    public ApiClient() {
+        this(DEFAULT_BASE_URL, HttpClient.newHttpClient());
+    }
+
+    public ApiClient(String baseUrl, HttpClient httpClient) {
+        this.baseUrl = baseUrl;
+        this.httpClient = httpClient;
+    }
+
+    public static String encode(String value) {
+        return URLEncoder.encode(value, StandardCharsets.UTF_8).replace(\"+\", \"%20\");
+    }\n",
+    );
+    if has_query {
+        java_code.push_str(
+            "
+    /**
+     * Builds a query string from name and value pairs, leaving out the null values and repeating
+     * the name for each item of a list.
+     */
+    public static String query(Object... params) {
+        StringBuilder query = new StringBuilder();
+        for (int i = 0; i + 1 < params.length; i += 2) {
+            Object value = params[i + 1];
+            Iterable<?> values = value instanceof Iterable<?> items ? items : value == null ? List.of() : List.of(value);
+            for (Object item : values) {
+                query.append(query.length() == 0 ? \"?\" : \"&\")
+                    .append(encode(String.valueOf(params[i])))
+                    .append('=')
+                    .append(encode(String.valueOf(item)));
+            }
+        }
+        return query.toString();
+    }\n",
+        );
+    }
+    if joins_arrays {
+        java_code.push_str(
+            "
+    /** Joins the items of a list with a delimiter, leaving a null list null. */
+    public static String join(String delimiter, List<?> values) {
+        return values == null ? null : String.join(delimiter, values.stream().map(String::valueOf).toList());
+    }\n",
+        );
+    }
+    if has_headers {
+        java_code.push_str(
+            "
+    /**
+     * Collects request headers from name and value pairs, leaving out the null values and joining
+     * the cookies into one Cookie header.
+     */
+    public static Map<String, String> headers(Object... params) {
+        Map<String, String> headers = new LinkedHashMap<>();
+        for (int i = 0; i + 1 < params.length; i += 2) {
+            if (params[i + 1] != null) {
+                String value = String.valueOf(params[i + 1]);
+                headers.merge(String.valueOf(params[i]), value, (first, second) -> first + \"; \" + second);
+            }
+        }
+        return headers;
+    }\n",
+        );
+    }
+
+    if has_encoded {
+        java_code.push_str(
+            "
+    /**
+     * A body that is sent as it is with its own Content-Type rather than as JSON: a form, or a
+     * body of another media type.
+     */
+    public record EncodedBody(String contentType, byte[] body) {
+    }\n",
+        );
+    }
+    if has_media_types {
+        java_code.push_str(
+            "
+    /**
+     * Encodes a body as the media type {@code contentType}: byte arrays as they are, strings as
+     * they are unless the type is JSON, anything else as JSON.
+     */
+    public EncodedBody encodeBody(String contentType, Object body) {
+        if (body == null) {
+            return null;
+        }
+        try {
+            byte[] bytes = body instanceof byte[] data ? data
+                : body instanceof String text && !contentType.contains(\"json\") ? text.getBytes(StandardCharsets.UTF_8)
+                : mapper.writeValueAsBytes(body);
+            return new EncodedBody(contentType, bytes);
+        } catch (IOException e) {
+            throw new ApiException(e);
+        }
+    }\n",
+        );
+    }
+    if has_forms {
+        java_code.push_str(
+            "
+
+    /**
+     * Builds a URL-encoded form from name and value pairs, leaving out the null values and
+     * repeating the name for each item of a list.
+     */
+    public static EncodedBody urlEncodedForm(Object... fields) {
+        StringBuilder body = new StringBuilder();
+        for (int i = 0; i + 1 < fields.length; i += 2) {
+            for (Object item : items(fields[i + 1])) {
+                body.append(body.length() == 0 ? \"\" : \"&\")
+                    .append(URLEncoder.encode(String.valueOf(fields[i]), StandardCharsets.UTF_8))
+                    .append('=')
+                    .append(URLEncoder.encode(String.valueOf(item), StandardCharsets.UTF_8));
+            }
+        }
+        return new EncodedBody(\"application/x-www-form-urlencoded\", body.toString().getBytes(StandardCharsets.UTF_8));
+    }
+
+    /**
+     * Builds a multipart/form-data body from name and value pairs, leaving out the null values and
+     * sending byte arrays as files named after their field.
+     */
+    public static EncodedBody multipartForm(Object... fields) {
+        String boundary = \"ApiClient-\" + UUID.randomUUID();
+        ByteArrayOutputStream body = new ByteArrayOutputStream();
+        for (int i = 0; i + 1 < fields.length; i += 2) {
+            String name = String.valueOf(fields[i]);
+            for (Object item : items(fields[i + 1])) {
+                String part = \"--\" + boundary + \"\\r\\nContent-Disposition: form-data; name=\\\"\" + name + \"\\\"\";
+                if (item instanceof byte[]) {
+                    part += \"; filename=\\\"\" + name + \"\\\"\\r\\nContent-Type: application/octet-stream\";
+                }
+                body.writeBytes((part + \"\\r\\n\\r\\n\").getBytes(StandardCharsets.UTF_8));
+                body.writeBytes(item instanceof byte[] bytes ? bytes : String.valueOf(item).getBytes(StandardCharsets.UTF_8));
+                body.writeBytes(\"\\r\\n\".getBytes(StandardCharsets.UTF_8));
+            }
+        }
+        body.writeBytes((\"--\" + boundary + \"--\\r\\n\").getBytes(StandardCharsets.UTF_8));
+        return new EncodedBody(\"multipart/form-data; boundary=\" + boundary, body.toByteArray());
+    }
+
+    private static Iterable<?> items(Object value) {
+        return value instanceof Iterable<?> items ? items : value == null ? List.of() : List.of(value);
+    }\n",
+        );
+    }
+
+    if has_auth {
+        java_code.push_str(
+            "
+    /**
+     * Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.
+     */
+    public void setAuth(String scheme, String credential) {
+        credentials.put(scheme, credential);
+    }
+
+    private String applyAuth(HttpRequest.Builder request, List<List<String>> requirements) {
+        StringBuilder query = new StringBuilder();
+        for (List<String> names : requirements) {
+            if (!credentials.keySet().containsAll(names)) {
+                continue;
+            }
+            for (String name : names) {
+                String[] scheme = SECURITY_SCHEMES.get(name);
+                String credential = credentials.get(name);
+                if (scheme[0].equals(\"basic\")) {
+                    String encoded = Base64.getEncoder().encodeToString(credential.getBytes(StandardCharsets.UTF_8));
+                    request.header(\"Authorization\", \"Basic \" + encoded);
+                } else if (scheme[0].equals(\"apiKey\") && scheme[1].equals(\"query\")) {
+                    query.append(query.length() == 0 ? \"?\" : \"&\").append(scheme[2]).append('=').append(encode(credential));
+                } else if (scheme[0].equals(\"apiKey\") && scheme[1].equals(\"cookie\")) {
+                    request.header(\"Cookie\", scheme[2] + \"=\" + credential);
+                } else if (scheme[0].equals(\"apiKey\")) {
+                    request.header(scheme[2], credential);
+                } else {
+                    request.header(\"Authorization\", \"Bearer \" + credential);
+                }
+            }
+            break;
+        }
+        return query.toString();
+    }\n",
+        );
+    }
+
+    if retries.is_some() {
+        java_code.push_str(&format!(
+            "
+    /**
+     * Retries requests that failed with a network error, 429 or 5xx, waiting as long as
+     * Retry-After asks or otherwise doubling the delay from half a second.
+     */
+    private HttpResponse<{0}> sendWithRetries(HttpRequest request) throws IOException, InterruptedException {{
+        for (int attempt = 0; ; attempt++) {{
+            long delay = 500L << attempt;
+            try {{
+                HttpResponse<{0}> response = httpClient.send(request, HttpResponse.BodyHandlers.{1}());
+                if ((response.statusCode() != 429 && response.statusCode() < 500) || attempt >= MAX_RETRIES) {{
+                    return response;
+                }}
+                String retryAfter = response.headers().firstValue(\"Retry-After\").orElse(\"\");
+                if (retryAfter.matches(\"\\\\d+\")) {{
+                    delay = Long.parseLong(retryAfter) * 1000;
+                }} else if (!retryAfter.isEmpty()) {{
+                    try {{
+                        ZonedDateTime date = ZonedDateTime.parse(retryAfter, DateTimeFormatter.RFC_1123_DATE_TIME);
+                        delay = Math.max(Duration.between(Instant.now(), date).toMillis(), 0);
+                    }} catch (DateTimeParseException e) {{
+                        // Not a date either, so the doubled delay stands.
+                    }}
+                }}
+            }} catch (IOException e) {{
+                if (attempt >= MAX_RETRIES) {{
+                    throw e;
+                }}
+            }}
+            Thread.sleep(delay);
+        }}
+    }}\n",
+            body_type, body_handler
+        ));
+    }
+
+    java_code.push_str(&format!(
+        "
+    public <T> T send(String method, String path, Object body, Class<T> responseType, List<List<String>> requirements) {{
+        return send(method, path, Map.of(), body, responseType, requirements);
+    }}
+
+    public <T> T send(
+        String method,
+        String path,
+        Map<String, String> headers,
+        Object body,
+        Class<T> responseType,
+        List<List<String>> requirements
+    ) {{
+        return send(method, path, headers, body, mapper.constructType(responseType), requirements);
+    }}
+
+    public <T> T send(
+        String method,
+        String path,
+        Object body,
+        TypeReference<T> responseType,
+        List<List<String>> requirements
+    ) {{
+        return send(method, path, Map.of(), body, responseType, requirements);
+    }}
+
+    public <T> T send(
+        String method,
+        String path,
+        Map<String, String> headers,
+        Object body,
+        TypeReference<T> responseType,
+        List<List<String>> requirements
+    ) {{
+        return send(method, path, headers, body, mapper.constructType(responseType), requirements);
+    }}
+
+    {}private <T> T send(
+        String method,
+        String path,
+        Map<String, String> headers,
+        Object body,
+        JavaType responseType,
+        List<List<String>> requirements
+    ) {{
+        try {{
+            {}
+            headers.forEach(request::header);
+            {}
+
+            HttpResponse<{}> response = {};
+            if (response.statusCode() < 200 || response.statusCode() >= 300) {{
+                throw new ApiException(response.statusCode(), {});
+            }}
+            {}if (response.body(){}) {{
+                return null;
+            }}
+            return mapper.readValue(response.body(), responseType);
+        }} catch (IOException e) {{
+            throw new ApiException(e);
+        }} catch (InterruptedException e) {{
+            Thread.currentThread().interrupt();
+            throw new ApiException(e);
+        }}
+    }}
+}}
+",
+        if binary { "@SuppressWarnings(\"unchecked\")\n    " } else { "" },
+        if has_encoded {
+            "HttpRequest.BodyPublisher publisher = body == null
+                ? HttpRequest.BodyPublishers.noBody()
+                : body instanceof EncodedBody encoded
+                ? HttpRequest.BodyPublishers.ofByteArray(encoded.body())
+                : HttpRequest.BodyPublishers.ofString(mapper.writeValueAsString(body));
+            HttpRequest.Builder request = HttpRequest.newBuilder()
+                .method(method, publisher)
+                .header(\"Accept\", \"application/json\");
+            if (body != null) {
+                request.header(\"Content-Type\", body instanceof EncodedBody encoded ? encoded.contentType() : \"application/json\");
+            }"
+        } else {
+            "HttpRequest.BodyPublisher publisher = body == null
+                ? HttpRequest.BodyPublishers.noBody()
+                : HttpRequest.BodyPublishers.ofString(mapper.writeValueAsString(body));
+            HttpRequest.Builder request = HttpRequest.newBuilder()
+                .method(method, publisher)
+                .header(\"Accept\", \"application/json\");
+            if (body != null) {
+                request.header(\"Content-Type\", \"application/json\");
+            }"
+        },
+        match (has_auth, has_query) {
+            (true, true) => "String query = applyAuth(request, requirements);
+            if (path.contains(\"?\") && !query.isEmpty()) {
+                query = \"&\" + query.substring(1);
+            }
+            request.uri(URI.create(baseUrl + path + query));",
+            (true, false) => "String query = applyAuth(request, requirements);
+            request.uri(URI.create(baseUrl + path + query));",
+            (false, _) => "request.uri(URI.create(baseUrl + path));",
+        },
+        body_type,
+        match retries {
+            Some(_) => "sendWithRetries(request.build())".to_string(),
+            None => format!("httpClient.send(request.build(), HttpResponse.BodyHandlers.{}())", body_handler),
+        },
+        if binary { "new String(response.body(), StandardCharsets.UTF_8)" } else { "response.body()" },
+        if binary {
+            "if (responseType.getRawClass() == byte[].class) {
+                return (T) response.body();
+            }
+            "
+        } else {
+            ""
+        },
+        if binary { ".length == 0" } else { ".isEmpty()" }
+    ));
+    java_code
+}
+
+fn generate_record(swagger: &Swagger, package: &str, name: &str, definition: &Definition) -> String {
+    let mut java_code = generate_header(swagger, &format!("{}.model", package));
+    if let Some(enum_code) = definition.enum_values.as_ref().and_then(|values| generate_enum(name, definition, values)) {
+        java_code.push_str(&enum_code);
+        return java_code;
+    }
+    let components: Vec<String> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, _)| {
+            format!("    @JsonProperty(\"{}\") {} {}", prop_name, property_type(prop), field_name(prop_name))
+        })
+        .collect();
+
+    java_code.push_str("import com.fasterxml.jackson.annotation.JsonProperty;\n");
+    if components.iter().any(|component| component.contains("List<")) {
+        java_code.push_str("import java.util.List;\n");
+    }
+    if components.iter().any(|component| component.contains("Map<")) {
+        java_code.push_str("import java.util.Map;\n");
+    }
+    java_code.push('\n');
+
+    if components.is_empty() {
+        java_code.push_str(&format!("public record {}() {{\n}}\n", type_name(name)));
+    } else {
+        java_code.push_str(&format!("public record {}(\n{}\n) {{\n}}\n", type_name(name), components.join(",\n")));
+    }
+    java_code
+}
+
+/// A string enum as a Java `enum` mapping each constant to its value with `@JsonProperty`, and a
+/// numeric one as an `enum` holding its value, which `@JsonValue` reads and writes.
+fn generate_enum(name: &str, definition: &Definition, values: &[Value]) -> Option<String> {
+    let constant = |words: &[String]| match words.join("_").to_uppercase() {
+        constant if constant.starts_with(|c: char| c.is_ascii_digit()) => format!("VALUE_{}", constant),
+        constant => constant,
+    };
+    let enum_name = type_name(name);
+    match definition.definition_type.as_deref() {
+        Some("string") | None => {
+            let strings: Vec<Value> = values.iter().filter(|value| value.is_string()).cloned().collect();
+            if strings.is_empty() {
+                return None;
+            }
+            let constants: Vec<String> = enum_members(&strings, constant)
+                .into_iter()
+                .map(|(constant, value)| {
+                    let value = value.as_str().unwrap_or_default().replace('\\', "\\\\").replace('"', "\\\"");
+                    format!("    @JsonProperty(\"{}\") {}", value, constant)
+                })
+                .collect();
+            Some(format!(
+                "import com.fasterxml.jackson.annotation.JsonProperty;\n\npublic enum {} {{\n{}\n}}\n",
+                enum_name,
+                constants.join(",\n")
+            ))
+        }
+        value_type @ (Some("integer") | Some("number")) => {
+            let java_type = primitive_type(value_type, None);
+            let numbers: Vec<Value> = values.iter().filter(|value| value.is_number()).cloned().collect();
+            if numbers.is_empty() {
+                return None;
+            }
+            let constants: Vec<String> = enum_members(&numbers, constant)
+                .into_iter()
+                .map(|(constant, value)| {
+                    let literal = match (java_type, value.to_string()) {
+                        ("Long", literal) => format!("{}L", literal),
+                        (_, literal) if literal.contains(['.', 'e', 'E']) => literal,
+                        (_, literal) => format!("{}.0", literal),
+                    };
+                    format!("    {}({})", constant, literal)
+                })
+                .collect();
+            Some(format!(
+                "import com.fasterxml.jackson.annotation.JsonValue;
+
+public enum {0} {{
+{1};
+
+    private final {2} value;
+
+    {0}({2} value) {{
+        this.value = value;
+    }}
+
+    @JsonValue
+    public {2} value() {{
+        return value;
+    }}
+}}\n",
+                enum_name,
+                constants.join(",\n"),
+                java_type
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn generate_service(
+    swagger: &Swagger,
+    package: &str,
+    tag_package: &str,
+    tag: &str,
+    operations: &[(&str, &str, &Operation)],
+) -> String {
+    let mut java_code = generate_header(swagger, &format!("{}.{}", package, tag_package));
+    if operations.iter().any(|(_, _, operation)| response_items(operation).is_some()) {
+        java_code.push_str("import com.fasterxml.jackson.core.type.TypeReference;\n");
+    }
+    java_code.push_str("import com.fasterxml.jackson.databind.JsonNode;\n");
+    java_code.push_str("import java.util.List;\n");
+    let map_body = operations
+        .iter()
+        .any(|(_, method, operation)| has_request_body(method) && body_type(operation).contains("Map<"));
+    if map_body {
+        java_code.push_str("import java.util.Map;\n");
+    }
+    java_code.push_str(&format!("import {}.ApiClient;\n", package));
+    let mut models: Vec<String> = operations
+        .iter()
+        .flat_map(|(_, _, operation)| {
+            let response =
+                response_reference(operation).or_else(|| response_items(operation).and_then(item_reference));
+            let body = operation.parameters.iter().filter(|param| param.location == "body");
+            response.into_iter().chain(body.filter_map(|param| param.schema.as_ref().and_then(item_reference)))
+        })
+        .map(|reference| type_name(ref_name(reference)))
+        .collect();
+    models.sort();
+    models.dedup();
+    for model in models {
+        java_code.push_str(&format!("import {}.model.{};\n", package, model));
+    }
+    java_code.push('\n');
+
+    let class_name = format!("{}Service", type_name(tag));
+    java_code.push_str(&format!("public class {} {{\n", class_name));
+    java_code.push_str("    private final ApiClient client;\n\n");
+    java_code.push_str(&format!(
+        "    public {}(ApiClient client) {{\n        this.client = client;\n    }}\n",
+        class_name
+    ));
+
+    for (path, method, operation) in operations {
+        java_code.push('\n');
+        java_code.push_str(&generate_service_method(swagger, method, path, operation));
+    }
+    java_code.push_str("}\n");
+    java_code
+}
+
+fn generate_service_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = field_name(&operation_name(method, path, operation));
+    let has_body = has_request_body(method);
+
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+    let form = form_parameters(operation);
+
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{} {}", path_parameter_type(operation, param), field_name(param)))
+        .collect();
+    for param in query.iter().chain(&headers).chain(&form) {
+        arguments.push(format!("{} {}", parameter_type(param), field_name(&param.name)));
+    }
+    let body = if !form.is_empty() {
+        let fields: Vec<String> =
+            form.iter().map(|param| format!("\"{}\", {}", param.name, field_name(&param.name))).collect();
+        let multipart = request_media_type(swagger, operation) == MULTIPART;
+        let builder = if multipart { "multipartForm" } else { "urlEncodedForm" };
+        format!("ApiClient.{}({})", builder, fields.join(", "))
+    } else if has_body {
+        arguments.push(format!("{} body", body_type(operation)));
+        match body_media_type(swagger, method, operation) {
+            Some(media_type) => format!("client.encodeBody(\"{}\", body)", media_type),
+            None => "body".to_string(),
+        }
+    } else {
+        "null".to_string()
+    };
+
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    for segment in path.split_inclusive('/') {
+        let param = segment.trim_end_matches('/');
+        if param.starts_with('{') && param.ends_with('}') {
+            segments.push(format!("\"{}\"", literal));
+            literal.clear();
+            let name = &param[1..param.len() - 1];
+            segments.push(match path_parameter_type(operation, name) {
+                "String" => format!("ApiClient.encode({})", field_name(name)),
+                _ => field_name(name),
+            });
+            literal.push_str(&segment[param.len()..]);
+        } else {
+            literal.push_str(segment);
+        }
+    }
+    if !literal.is_empty() {
+        segments.push(format!("\"{}\"", literal));
+    }
+    if !query.is_empty() {
+        let pairs: Vec<String> =
+            query.iter().map(|param| format!("\"{}\", {}", param.name, parameter_value(param))).collect();
+        segments.push(format!("ApiClient.query({})", pairs.join(", ")));
+    }
+    let headers_argument = if headers.is_empty() {
+        String::new()
+    } else {
+        let pairs: Vec<String> = headers
+            .iter()
+            .map(|param| {
+                let value = parameter_value(param);
+                match param.location.as_str() {
+                    "cookie" => format!("\"Cookie\", {0} == null ? null : \"{1}=\" + {0}", value, param.name),
+                    _ => format!("\"{}\", {}", param.name, value),
+                }
+            })
+            .collect();
+        format!("ApiClient.headers({}), ", pairs.join(", "))
+    };
+
+    let requirements: Vec<String> = security_requirements(swagger, operation)
+        .iter()
+        .map(|names| {
+            let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+            format!("List.of({})", names.join(", "))
+        })
+        .collect();
+
+    let (response_type, response_argument) = match (response_reference(operation), response_items(operation)) {
+        _ if has_binary_response(swagger, operation) => ("byte[]".to_string(), "byte[].class".to_string()),
+        (Some(reference), _) => (type_name(ref_name(reference)), format!("{}.class", type_name(ref_name(reference)))),
+        (None, Some(items)) => {
+            let list = format!("List<{}>", item_type(items));
+            (list.clone(), format!("new TypeReference<{}>() {{}}", list))
+        }
+        (None, None) => ("JsonNode".to_string(), "JsonNode.class".to_string()),
+    };
+
+    let mut java_code = String::new();
+    if let Some(summary) = &operation.summary {
+        java_code.push_str(&format!("    /**\n     * {}\n     */\n", summary));
+    }
+    if operation.deprecated {
+        java_code.push_str("    @Deprecated\n");
+    }
+    java_code.push_str(&format!(
+        "    public {} {}({}) {{
+        return client.send(\"{}\", {}, {}{}, {}, List.of({}));
+    }}\n",
+        response_type,
+        method_name,
+        arguments.join(", "),
+        method.to_uppercase(),
+        segments.join(" + "),
+        headers_argument,
+        body,
+        response_argument,
+        requirements.join(", ")
+    ));
+    java_code
+}
+
+/// The value a query or header parameter is sent as: its argument, with a list joined by the delimiter
+/// of its `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    match collection_delimiter(param) {
+        Some(delimiter) => format!("ApiClient.join({:?}, {})", delimiter, field_name(&param.name)),
+        None => field_name(&param.name),
+    }
+}
+
+/// The type of the body a method sends: bytes for a binary body, the type of its schema otherwise.
+fn body_type(operation: &Operation) -> String {
+    let body = operation.parameters.iter().find(|param| param.location == "body");
+    match body.and_then(|param| param.schema.as_ref()) {
+        _ if binary_body(operation) => "byte[]".to_string(),
+        Some(schema) => item_type(schema),
+        None => "Object".to_string(),
+    }
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `String` for parameters the
+/// operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(java_type @ ("Integer" | "Long" | "Float" | "Double" | "Boolean")) => java_type,
+        _ => "String",
+    }
+}
+
+fn item_type(items: &Schema) -> String {
+    if let Some(reference) = &items.reference {
+        return type_name(ref_name(reference));
+    }
+    match items.schema_type.as_deref() {
+        Some("array") => format!("List<{}>", items.items.as_deref().map_or("Object".to_string(), item_type)),
+        other => primitive_type(other, items.format.as_deref()).to_string(),
+    }
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            format!("List<{}>", items_type)
+        }
+        Some("file") => "byte[]".to_string(),
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => format!("List<{}>", prop.additional.get("items").map_or("Object".to_string(), schema_type)),
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("List<{}>", schema.get("items").map_or("Object".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), Some("int32")) => "Integer",
+        (Some("integer"), _) => "Long",
+        (Some("number"), Some("float")) => "Float",
+        (Some("number"), _) => "Double",
+        (Some("string"), _) => "String",
+        (Some("boolean"), _) => "Boolean",
+        (Some("object"), _) => "Map<String, Object>",
+        _ => "Object",
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn field_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    let mut chars = type_name.chars();
+    let field_name: String = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    };
+    if field_name.starts_with(|c: char| c.is_ascii_digit()) || KEYWORDS.contains(&field_name.as_str()) {
+        format!("{}Value", field_name)
+    } else {
+        field_name
+    }
+}
+
+fn package_segment(tag: &str) -> String {
+    let segment: String = words(tag).concat();
+    if segment.is_empty() || segment.starts_with(|c: char| c.is_ascii_digit()) || KEYWORDS.contains(&segment.as_str()) {
+        format!("{}api", segment)
+    } else {
+        segment
+    }
+}
@@ -0,0 +1,310 @@
+use super::http::{encode, parameter_schema, text};
+use super::mock::{success_reply, to_js, Examples, Reply};
+use super::typescript::{
+    argument_order, form_parameters, generate_info_comment, header_parameters, parameters_required, property_name,
+    query_parameters, request_data, service_method_name, string_literal, tag_service_name, MethodArgument,
+    ServiceOptions,
+};
+use super::{
+    base_url, collection_delimiter, extract_path_params, has_request_body, operations_by_tag, request_media_type,
+    MULTIPART, URL_ENCODED,
+};
+use crate::cli::ServiceStyle;
+use crate::spec::{Operation, Parameter, Swagger};
+use serde_json::{json, Value};
+
+/// Records every request and answers it as the test asked, failing on requests no test expects.
+const SERVER: &str = "const server = setupServer();
+
+/** The requests the service sent during the current test, as the server received them. */
+let requests: Request[] = [];
+
+beforeAll(() => server.listen({ onUnhandledRequest: 'error' }));
+afterEach(() => {
+    server.resetHandlers();
+    requests = [];
+});
+afterAll(() => server.close());
+
+/** Answers every request with `status` and `body` as JSON, or no body when it is `null`. */
+function reply(status: number, body: unknown = null) {
+    server.use(
+        http.all('*', ({ request }) => {
+            requests.push(request.clone());
+            return body === null ? new HttpResponse(null, { status }) : HttpResponse.json(body, { status });
+        }),
+    );
+}
+";
+
+/// `__tests__/service.test.ts`: a Jest test per operation calling the generated service with
+/// example arguments, against a Mock Service Worker server answering with an example response,
+/// and checking the method, URL, query, headers and body of the request it sent. `bundled` is
+/// whether the service is in `api.ts`.
+pub fn generate_tests(swagger: &Swagger, service: ServiceOptions, bundled: bool) -> String {
+    let examples = Examples::new(swagger);
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { http, HttpResponse } from 'msw';\n");
+    ts_code.push_str("import { setupServer } from 'msw/node';\n");
+
+    let mut suites = Vec::new();
+    if service.style == ServiceStyle::Class {
+        ts_code.push_str("import { ApiClient } from '../service';\n\n");
+        ts_code.push_str("const api = new ApiClient();\n");
+        suites.push(("ApiClient".to_string(), "api".to_string(), all_operations(swagger)));
+    } else if service.split_by_tag && !bundled {
+        for (tag, operations) in operations_by_tag(swagger) {
+            let name = tag_service_name(&tag);
+            ts_code.push_str(&format!("import * as {0} from '../{0}';\n", name));
+            suites.push((name.clone(), name, operations));
+        }
+    } else {
+        let module = if bundled { "api" } else { "service" };
+        ts_code.push_str(&format!("import * as api from '../{}';\n", module));
+        suites.push(("service".to_string(), "api".to_string(), all_operations(swagger)));
+    }
+    ts_code.push('\n');
+    ts_code.push_str(SERVER);
+
+    for (suite, receiver, operations) in suites {
+        ts_code.push_str(&format!("\ndescribe('{}', () => {{\n", suite));
+        let tests: Vec<String> = operations
+            .iter()
+            .map(|(path, method, operation)| {
+                Test { swagger, examples: &examples, method, path, operation }.generate(&receiver)
+            })
+            .collect();
+        ts_code.push_str(&tests.join("\n"));
+        ts_code.push_str("});\n");
+    }
+    ts_code
+}
+
+fn all_operations(swagger: &Swagger) -> Vec<(&str, &str, &Operation)> {
+    swagger
+        .paths
+        .iter()
+        .flat_map(|(path, path_item)| {
+            path_item.operations().into_iter().map(move |(method, operation)| (path.as_str(), method, operation))
+        })
+        .collect()
+}
+
+/// The path of the base URL, which the request paths follow, e.g. `/v1` for `https://api.io/v1`.
+fn base_path(swagger: &Swagger) -> String {
+    let url = base_url(swagger);
+    let path = match url.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("", |start| &rest[start..]),
+        None => url.as_str(),
+    };
+    path.to_string()
+}
+
+/// `value` as a TypeScript expression nested in a test.
+fn literal(value: &Value) -> String {
+    to_js(value).replace('\n', "\n        ")
+}
+
+/// `value` as a TypeScript expression on a single line, for the members of an argument.
+fn inline(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| "null".to_string())
+}
+
+struct Test<'a> {
+    swagger: &'a Swagger,
+    examples: &'a Examples,
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+}
+
+impl Test<'_> {
+    fn generate(&self, receiver: &str) -> String {
+        let method_name = service_method_name(self.method, self.path, self.operation);
+        let method = self.method.to_uppercase();
+        let mut checks = vec![
+            "expect(requests).toHaveLength(1);".to_string(),
+            "const [request] = requests;".to_string(),
+            "const url = new URL(request.url);".to_string(),
+            format!("expect(request.method).toBe('{}');", method),
+        ];
+
+        let mut arguments = Vec::new();
+        let mut path = self.path.to_string();
+        for name in extract_path_params(self.path) {
+            let param = self.operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+            let example = param.map_or(Value::from("string"), |param| self.example(param));
+            let value = text(&example);
+            path = path.replace(&format!("{{{}}}", name), &encode(&value));
+            // Numbers and booleans are passed as themselves, which the service types them as.
+            arguments.push(match example {
+                Value::Number(_) | Value::Bool(_) => inline(&example),
+                _ => string_literal(&value),
+            });
+        }
+        checks.push(format!(
+            "expect(url.pathname).toBe({});",
+            string_literal(&format!("{}{}", base_path(self.swagger), path))
+        ));
+
+        let query = query_parameters(self.operation);
+        for param in &query {
+            match (self.example(param), collection_delimiter(param)) {
+                // Joined into one value by its `collectionFormat`, or repeated for each item.
+                (Value::Array(items), Some(delimiter)) => checks.push(format!(
+                    "expect(url.searchParams.get({})).toBe({});",
+                    string_literal(&param.name),
+                    string_literal(&items.iter().map(text).collect::<Vec<_>>().join(delimiter))
+                )),
+                (Value::Array(items), None) => checks.push(format!(
+                    "expect(url.searchParams.getAll({})).toEqual([{}]);",
+                    string_literal(&param.name),
+                    items.iter().map(|item| string_literal(&text(item))).collect::<Vec<_>>().join(", ")
+                )),
+                (value, _) => checks.push(format!(
+                    "expect(url.searchParams.get({})).toBe({});",
+                    string_literal(&param.name),
+                    string_literal(&text(&value))
+                )),
+            }
+        }
+        let headers = header_parameters(self.operation);
+        for param in &headers {
+            let value = text(&self.example(param));
+            checks.push(if param.location == "cookie" {
+                format!(
+                    "expect(request.headers.get('Cookie')).toContain({});",
+                    string_literal(&format!("{}={}", param.name, encode(&value)))
+                )
+            } else {
+                format!(
+                    "expect(request.headers.get({})).toBe({});",
+                    string_literal(&param.name),
+                    string_literal(&value)
+                )
+            });
+        }
+
+        let has_body = has_request_body(self.method);
+        let (_, data_required) = request_data(self.operation, &method_name);
+        let data = if has_body { self.data(&mut checks) } else { None };
+        let order = argument_order(
+            has_body.then_some(data_required),
+            parameters_required(&query),
+            parameters_required(&headers),
+        );
+        for argument in order {
+            arguments.push(match argument {
+                MethodArgument::Data => data.clone().unwrap_or_else(|| "undefined".to_string()),
+                MethodArgument::Params => self.parameters(&query),
+                MethodArgument::Headers => self.parameters(&headers),
+            });
+        }
+        while arguments.last().is_some_and(|argument| argument == "undefined") {
+            arguments.pop();
+        }
+
+        let (status, reply) = success_reply(self.swagger, self.examples, self.operation);
+        let reply = match reply {
+            Reply::Json(example) if self.method != "head" => format!("reply({}, {});", status, literal(&example)),
+            _ => format!("reply({});", status),
+        };
+
+        let mut ts_code = format!("    it('{} sends {} {}', async () => {{\n", method_name, method, self.path);
+        ts_code.push_str(&format!("        {}\n", reply));
+        ts_code.push_str(&format!("        await {}.{}({});\n\n", receiver, method_name, arguments.join(", ")));
+        for check in checks {
+            ts_code.push_str(&format!("        {}\n", check));
+        }
+        ts_code.push_str("    });\n");
+        ts_code
+    }
+
+    fn example(&self, param: &Parameter) -> Value {
+        self.examples.example(&parameter_schema(param))
+    }
+
+    /// An object of example values of `params`, keyed by their names.
+    fn parameters(&self, params: &[&Parameter]) -> String {
+        let values: Vec<String> = params
+            .iter()
+            .map(|param| format!("{}: {}", property_name(&param.name), inline(&self.example(param))))
+            .collect();
+        format!("{{ {} }}", values.join(", "))
+    }
+
+    /// The example `data` argument of the method, adding the checks of the body it sends to
+    /// `checks`.
+    fn data(&self, checks: &mut Vec<String>) -> Option<String> {
+        let media = request_media_type(self.swagger, self.operation);
+        let form = form_parameters(self.operation);
+        let mut fields: Vec<(String, Option<Value>)> = Vec::new();
+        let data = if !form.is_empty() {
+            let values: Vec<String> = form
+                .iter()
+                .map(|param| {
+                    let value = if param.parameter_type.as_deref() == Some("file") {
+                        fields.push((param.name.clone(), None));
+                        format!("new Blob([{}])", string_literal(&param.name))
+                    } else {
+                        let example = self.example(param);
+                        fields.push((param.name.clone(), Some(example.clone())));
+                        inline(&example)
+                    };
+                    format!("{}: {}", property_name(&param.name), value)
+                })
+                .collect();
+            format!("{{ {} }}", values.join(", "))
+        } else {
+            let body = self.operation.parameters.iter().find(|param| param.location == "body")?;
+            let schema = body.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok());
+            let example = self.examples.example(&schema.unwrap_or(json!({})));
+            match &example {
+                _ if media.contains("json") => {
+                    checks.push(format!("expect(await request.json()).toEqual({});", literal(&example)))
+                }
+                Value::Object(properties) => {
+                    fields.extend(properties.iter().map(|(name, value)| (name.clone(), Some(value.clone()))));
+                }
+                _ => {}
+            }
+            literal(&example)
+        };
+
+        if !fields.is_empty() && [MULTIPART, URL_ENCODED].contains(&media) {
+            checks.push(if media == MULTIPART {
+                "const form = await request.formData();".to_string()
+            } else {
+                "const form = new URLSearchParams(await request.text());".to_string()
+            });
+            for (name, value) in fields {
+                checks.push(match value {
+                    None => format!("expect(form.has({})).toBe(true);", string_literal(&name)),
+                    Some(Value::Null) => format!("expect(form.has({})).toBe(false);", string_literal(&name)),
+                    Some(Value::Array(items)) => format!(
+                        "expect(form.getAll({})).toEqual([{}]);",
+                        string_literal(&name),
+                        items.iter().map(|item| string_literal(&field_text(item))).collect::<Vec<_>>().join(", ")
+                    ),
+                    Some(value) => {
+                        format!(
+                            "expect(form.get({})).toBe({});",
+                            string_literal(&name),
+                            string_literal(&field_text(&value))
+                        )
+                    }
+                });
+            }
+        }
+        Some(data)
+    }
+}
+
+/// A value as the form helpers of the service send it: objects as JSON, anything else as text.
+fn field_text(value: &Value) -> String {
+    match value {
+        Value::Object(_) => value.to_string(),
+        value => text(value),
+    }
+}
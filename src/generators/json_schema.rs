@@ -0,0 +1,214 @@
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Property, Swagger};
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+const DRAFT_07: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Writes each definition as a standalone draft-07 `<Name>.schema.json`, carrying the
+/// definitions it refers to under `definitions`.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let schemas: BTreeMap<&str, Value> = swagger
+        .definitions
+        .iter()
+        .map(|(name, definition)| (name.as_str(), convert_schema(&definition_value(definition))))
+        .collect();
+
+    let mut files = GeneratedFiles::new();
+    for (name, schema) in &schemas {
+        let mut document = Map::new();
+        document.insert("$schema".to_string(), json!(DRAFT_07));
+        document.insert("title".to_string(), json!(name));
+        if let Value::Object(schema) = schema {
+            document.extend(schema.clone());
+        }
+
+        let mut definitions = Map::new();
+        let mut pending = references(schema);
+        let mut visited = HashSet::from([name.to_string()]);
+        while let Some(target) = pending.pop() {
+            if !visited.insert(target.clone()) {
+                continue;
+            }
+            match schemas.get(target.as_str()) {
+                Some(target_schema) => {
+                    pending.extend(references(target_schema));
+                    definitions.insert(target, target_schema.clone());
+                }
+                None => log::warn!("{} refers to missing definition {}", name, target),
+            }
+        }
+        if !definitions.is_empty() {
+            document.insert("definitions".to_string(), Value::Object(definitions));
+        }
+
+        let mut document = Value::Object(document);
+        point_self_refs(&mut document, &format!("#/definitions/{}", name));
+        let contents = serde_json::to_string_pretty(&document).unwrap_or_default();
+        files.insert(PathBuf::from(format!("{}.schema.json", name)), contents + "\n");
+    }
+    files
+}
+
+fn definition_value(definition: &Definition) -> Value {
+    let mut schema = Map::new();
+    if let Some(definition_type) = &definition.definition_type {
+        schema.insert("type".to_string(), json!(definition_type));
+    }
+    if let Some(description) = &definition.description {
+        schema.insert("description".to_string(), json!(description));
+    }
+    if let Some(reference) = &definition.reference {
+        schema.insert("$ref".to_string(), json!(reference));
+    }
+    if let Some(values) = &definition.enum_values {
+        schema.insert("enum".to_string(), json!(values));
+    }
+    if let Some(properties) = &definition.properties {
+        let properties = properties.iter().map(|(name, prop)| (name.clone(), property_value(prop))).collect();
+        schema.insert("properties".to_string(), Value::Object(properties));
+    }
+    if let Some(required) = &definition.required {
+        schema.insert("required".to_string(), json!(required));
+    }
+    for (key, parts) in [("allOf", &definition.all_of), ("oneOf", &definition.one_of), ("anyOf", &definition.any_of)] {
+        if let Some(parts) = parts {
+            schema.insert(key.to_string(), Value::Array(parts.iter().map(definition_value).collect()));
+        }
+    }
+    if let Some(values) = &definition.additional_properties {
+        schema.insert("additionalProperties".to_string(), values.clone());
+    }
+    if let Some(example) = &definition.example {
+        schema.insert("example".to_string(), example.clone());
+    }
+    Value::Object(schema)
+}
+
+fn property_value(prop: &Property) -> Value {
+    let mut schema: Map<String, Value> = prop.additional.iter().map(|(key, value)| (key.clone(), value.clone())).collect();
+    if let Some(property_type) = &prop.property_type {
+        schema.insert("type".to_string(), json!(property_type));
+    }
+    if let Some(format) = &prop.format {
+        schema.insert("format".to_string(), json!(format));
+    }
+    Value::Object(schema)
+}
+
+/// Rewrites the OpenAPI-specific keywords of a schema into their draft-07 equivalents.
+fn convert_schema(schema: &Value) -> Value {
+    let Some(schema) = schema.as_object() else {
+        return schema.clone();
+    };
+
+    let mut converted = Map::new();
+    for (key, value) in schema {
+        match key.as_str() {
+            "$ref" => {
+                let reference = value.as_str().map(ref_name).unwrap_or_default();
+                converted.insert(key.clone(), json!(format!("#/definitions/{}", reference)));
+            }
+            "nullable" | "x-nullable" | "discriminator" | "xml" => {}
+            "exclusiveMinimum" | "exclusiveMaximum" if value.is_boolean() => {}
+            "type" if value.as_str() == Some("file") => {
+                converted.insert("type".to_string(), json!("string"));
+                converted.insert("format".to_string(), json!("binary"));
+            }
+            "example" => {
+                converted.insert("examples".to_string(), json!([value]));
+            }
+            "properties" => {
+                let properties = value
+                    .as_object()
+                    .map(|properties| {
+                        properties
+                            .iter()
+                            .map(|(name, property)| (name.clone(), convert_schema(property)))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                converted.insert(key.clone(), Value::Object(properties));
+            }
+            "items" | "additionalProperties" | "not" => {
+                converted.insert(key.clone(), convert_schema(value));
+            }
+            "allOf" | "oneOf" | "anyOf" => {
+                let schemas = value
+                    .as_array()
+                    .map(|schemas| schemas.iter().map(convert_schema).collect())
+                    .unwrap_or_default();
+                converted.insert(key.clone(), Value::Array(schemas));
+            }
+            _ if key == "format" && converted.contains_key("format") => {}
+            _ => {
+                converted.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    // Draft-07 gives exclusive bounds as numbers of their own rather than flags on minimum/maximum.
+    for (bound, exclusive) in [("minimum", "exclusiveMinimum"), ("maximum", "exclusiveMaximum")] {
+        if schema.get(exclusive) == Some(&Value::Bool(true)) {
+            if let Some(value) = converted.remove(bound) {
+                converted.insert(exclusive.to_string(), value);
+            }
+        }
+    }
+
+    let nullable = ["nullable", "x-nullable"].iter().any(|key| schema.get(*key) == Some(&Value::Bool(true)));
+    if nullable {
+        match converted.get("type").cloned() {
+            Some(Value::String(schema_type)) => {
+                converted.insert("type".to_string(), json!([schema_type, "null"]));
+            }
+            _ if converted.contains_key("$ref") => {
+                return json!({ "anyOf": [Value::Object(converted), { "type": "null" }] });
+            }
+            _ => {}
+        }
+        if let Some(Value::Array(values)) = converted.get_mut("enum") {
+            if !values.contains(&Value::Null) {
+                values.push(Value::Null);
+            }
+        }
+    }
+    Value::Object(converted)
+}
+
+/// Names of the definitions a converted schema refers to.
+fn references(schema: &Value) -> Vec<String> {
+    let mut targets = Vec::new();
+    collect_references(schema, &mut targets);
+    targets
+}
+
+fn collect_references(value: &Value, targets: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(reference) = map.get("$ref").and_then(Value::as_str) {
+                targets.push(ref_name(reference).to_string());
+            }
+            map.values().for_each(|value| collect_references(value, targets));
+        }
+        Value::Array(values) => values.iter().for_each(|value| collect_references(value, targets)),
+        _ => {}
+    }
+}
+
+/// Points references to the document's own definition at its root.
+fn point_self_refs(value: &mut Value, own: &str) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get_mut("$ref") {
+                if reference == own {
+                    *reference = "#".to_string();
+                }
+            }
+            map.values_mut().for_each(|value| point_self_refs(value, own));
+        }
+        Value::Array(values) => values.iter_mut().for_each(|value| point_self_refs(value, own)),
+        _ => {}
+    }
+}
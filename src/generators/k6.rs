@@ -0,0 +1,211 @@
+use super::http::{encode, parameter_schema, query_texts, text, variable};
+use super::mock::{to_js, Examples};
+use super::typescript::{generate_info_comment, property_name, string_literal};
+use super::{
+    base_url, operation_name, operations_by_tag, pascal_case, request_media_type, security_requirements,
+    success_responses, with_custom_region, words, MULTIPART, URL_ENCODED,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{Operation, Swagger};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Writes `script.js`: a k6 load test with a scenario per tag, each of whose iterations sends the
+/// requests of the operations of the tag, with example payloads, and checks they answer with their
+/// first success status.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let examples = Examples::new(swagger);
+    let groups = operations_by_tag(swagger);
+    let basic = swagger.security_definitions.values().any(|scheme| scheme.scheme_type == "basic");
+
+    let mut js_code = String::new();
+    generate_info_comment(swagger, &mut js_code);
+    js_code.push_str("import http from 'k6/http';\n");
+    if basic {
+        js_code.push_str("import encoding from 'k6/encoding';\n");
+    }
+    js_code.push_str("import { check, group, sleep } from 'k6';\n\n");
+    js_code.push_str(
+        "/** Overridden with `k6 run -e BASE_URL=http://localhost:3000 script.js`, as are `VUS` and `DURATION`. */\n",
+    );
+    js_code.push_str(&format!("const BASE_URL = __ENV.BASE_URL || {};\n", string_literal(&base_url(swagger))));
+    js_code.push_str("const VUS = Number(__ENV.VUS || 1);\n");
+    js_code.push_str("const DURATION = __ENV.DURATION || '30s';\n\n");
+
+    js_code.push_str("export const options = {\n");
+    js_code.push_str("    scenarios: {\n");
+    for tag in groups.keys() {
+        js_code.push_str(&format!(
+            "        {}: {{ executor: 'constant-vus', exec: '{}', vus: VUS, duration: DURATION }},\n",
+            property_name(&words(tag).join("-")),
+            scenario_function(tag)
+        ));
+    }
+    js_code.push_str("    },\n");
+    js_code.push_str("    thresholds: {\n");
+    js_code.push_str("        http_req_failed: ['rate<0.01'],\n");
+    js_code.push_str("        http_req_duration: ['p(95)<500'],\n");
+    js_code.push_str("    },\n");
+    js_code.push_str("};\n");
+
+    for (tag, operations) in &groups {
+        js_code.push_str(&format!("\n/** The operations tagged `{}`. */\n", tag));
+        js_code.push_str(&format!("export function {}() {{\n", scenario_function(tag)));
+        for (path, method, operation) in operations {
+            let request = Request { swagger, examples: &examples, method, path, operation };
+            js_code.push_str(&request.generate());
+        }
+        js_code.push_str("    sleep(1);\n");
+        js_code.push_str("}\n");
+    }
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("script.js"), with_custom_region(js_code, "//"));
+    files
+}
+
+/// The exported function a scenario runs, e.g. `petScenario` for the tag `pet`.
+fn scenario_function(tag: &str) -> String {
+    let name = pascal_case(&format!("{}_scenario", words(tag).join("_")));
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        _ => format!("scenario{}", name),
+    }
+}
+
+/// The credential of a security scheme, read from the environment variable named after it.
+fn credential(scheme: &str) -> String {
+    format!("__ENV.{}", variable(scheme).to_uppercase())
+}
+
+struct Request<'a> {
+    swagger: &'a Swagger,
+    examples: &'a Examples,
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+}
+
+impl Request<'_> {
+    fn generate(&self) -> String {
+        let name = operation_name(self.method, self.path, self.operation);
+        let mut url = self.path.to_string();
+        let mut query = Vec::new();
+        let mut headers = Vec::new();
+        for param in &self.operation.parameters {
+            let example = self.examples.example(&parameter_schema(param));
+            let value = text(&example);
+            match param.location.as_str() {
+                "path" => url = url.replace(&format!("{{{}}}", param.name), &encode(&value)),
+                "query" => query.extend(
+                    query_texts(param, &example).iter().map(|value| format!("{}={}", encode(&param.name), encode(value))),
+                ),
+                "header" => headers.push((param.name.clone(), string_literal(&value))),
+                "cookie" => headers.push(("Cookie".to_string(), string_literal(&format!("{}={}", param.name, value)))),
+                _ => {}
+            }
+        }
+        let requirement = security_requirements(self.swagger, self.operation).into_iter().next().unwrap_or_default();
+        for scheme_name in requirement {
+            let Some(scheme) = self.swagger.security_definitions.get(&scheme_name) else {
+                continue;
+            };
+            let key = scheme.name.as_deref().unwrap_or(&scheme_name);
+            let credential = credential(&scheme_name);
+            match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+                ("basic", _) => headers.push((
+                    "Authorization".to_string(),
+                    format!("`Basic ${{encoding.b64encode(`${{{0}_USERNAME}}:${{{0}_PASSWORD}}`)}}`", credential),
+                )),
+                ("apiKey", Some("query")) => {
+                    query.push(format!("{}=${{encodeURIComponent({})}}", encode(key), credential))
+                }
+                ("apiKey", Some("cookie")) => {
+                    headers.push(("Cookie".to_string(), format!("`{}=${{{}}}`", key, credential)))
+                }
+                ("apiKey", _) => headers.push((key.to_string(), credential)),
+                _ => headers.push(("Authorization".to_string(), format!("`Bearer ${{{}}}`", credential))),
+            }
+        }
+
+        let body = self.body();
+        if let Some((Some(content_type), _)) = &body {
+            headers.push(("Content-Type".to_string(), string_literal(content_type)));
+        }
+        let query = if query.is_empty() { String::new() } else { format!("?{}", query.join("&")) };
+        let mut params = Vec::new();
+        if !headers.is_empty() {
+            let headers: Vec<String> =
+                headers.iter().map(|(name, value)| format!("{}: {}", property_name(name), value)).collect();
+            params.push(format!("headers: {{ {} }}", headers.join(", ")));
+        }
+        params.push(format!("tags: {{ name: {} }}", string_literal(&name)));
+
+        let check = match success_responses(self.operation).first().and_then(|(status, _)| status.parse::<u16>().ok()) {
+            Some(status) => format!("'{} answers {}': (r) => r.status === {}", name, status, status),
+            None => format!("'{} succeeds': (r) => r.status < 400", name),
+        };
+
+        let mut js_code = format!(
+            "    group({}, () => {{\n",
+            string_literal(&format!("{} {}", self.method.to_uppercase(), self.path))
+        );
+        js_code.push_str(&format!(
+            "        const res = http.request('{}', `${{BASE_URL}}{}{}`, {}, {{\n",
+            self.method.to_uppercase(),
+            url,
+            query,
+            body.map_or("null".to_string(), |(_, body)| body)
+        ));
+        for param in params {
+            js_code.push_str(&format!("            {},\n", param));
+        }
+        js_code.push_str("        });\n");
+        js_code.push_str(&format!("        check(res, {{ {} }});\n", check));
+        js_code.push_str("    });\n");
+        js_code
+    }
+
+    /// The body of the request, with the content type to send it as unless k6 sets it itself, as
+    /// it does for the forms it encodes from objects.
+    fn body(&self) -> Option<(Option<String>, String)> {
+        let media = request_media_type(self.swagger, self.operation);
+        let mut fields = Vec::new();
+        for param in &self.operation.parameters {
+            match param.location.as_str() {
+                "formData" if param.parameter_type.as_deref() == Some("file") => fields.push(format!(
+                    "{}: http.file('', {})",
+                    property_name(&param.name),
+                    string_literal(&param.name)
+                )),
+                "formData" => {
+                    let value = text(&self.examples.example(&parameter_schema(param)));
+                    fields.push(format!("{}: {}", property_name(&param.name), string_literal(&value)));
+                }
+                "body" => {
+                    let schema = param.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok());
+                    let example = self.examples.example(&schema.unwrap_or(json!({})));
+                    match example {
+                        Value::Object(properties) if [MULTIPART, URL_ENCODED].contains(&media) => {
+                            fields.extend(properties.iter().map(|(name, value)| {
+                                format!("{}: {}", property_name(name), string_literal(&text(value)))
+                            }));
+                        }
+                        example if media.contains("json") => {
+                            let json = to_js(&example).replace('\n', "\n        ");
+                            return Some((Some(media.to_string()), format!("JSON.stringify({})", json)));
+                        }
+                        example => return Some((Some(media.to_string()), string_literal(&text(&example)))),
+                    }
+                }
+                _ => {}
+            }
+        }
+        if fields.is_empty() {
+            None
+        } else {
+            Some((None, format!("{{ {} }}", fields.join(", "))))
+        }
+    }
+}
@@ -0,0 +1,605 @@
+use super::typescript::{form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, binary_body, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_request_body, info_block, item_reference, operation_name, operations_by_tag, package_name,
+    package_version, pascal_case, request_media_type, response_items, response_reference, security_requirements,
+    security_schemes, sends_raw_body, with_custom_region, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "class", "continue", "do", "else", "false", "for", "fun", "if", "in", "interface", "is", "null",
+    "object", "package", "return", "super", "this", "throw", "true", "try", "typealias", "typeof", "val", "var",
+    "when", "while",
+];
+
+const SECURITY_HEADER: &str = "X-Security-Requirements";
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let package = package_name(swagger);
+    let source_dir = PathBuf::from("src").join("main").join("kotlin").join(&package);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("build.gradle.kts"), generate_build_script(swagger, &package));
+    let client = with_custom_region(generate_api_client(swagger, &package, retries), "//");
+    files.insert(source_dir.join("ApiClient.kt"), client);
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = source_dir.join("model").join(format!("{}.kt", type_name(name)));
+        (path, generate_data_class(swagger, &package, name, definition))
+    }));
+    files.par_extend(operations_by_tag(swagger).into_par_iter().map(|(tag, operations)| {
+        let path = source_dir.join("api").join(format!("{}Api.kt", type_name(&tag)));
+        (path, generate_api_interface(swagger, &package, &tag, &operations))
+    }));
+    files
+}
+
+fn generate_header(swagger: &Swagger, package: &str) -> String {
+    format!("{}\n\npackage {}\n\n", info_block(swagger), package)
+}
+
+/// Whether an operation sends a `multipart/form-data` body or a raw text body, whose text goes through
+/// the scalars converter rather than being encoded as a JSON string.
+fn uses_scalars(swagger: &Swagger) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(method, operation)| {
+        let multipart = !form_parameters(operation).is_empty() && request_media_type(swagger, operation) == MULTIPART;
+        multipart || (sends_raw_body(swagger, method, operation) && !binary_body(operation))
+    })
+}
+
+fn generate_build_script(swagger: &Swagger, package: &str) -> String {
+    format!(
+        "plugins {{
+    kotlin(\"jvm\") version \"1.9.24\"
+    kotlin(\"plugin.serialization\") version \"1.9.24\"
+}}
+
+group = \"{}\"
+version = \"{}\"
+
+repositories {{
+    mavenCentral()
+}}
+
+dependencies {{
+    implementation(\"com.squareup.retrofit2:retrofit:2.11.0\")
+    implementation(\"com.squareup.retrofit2:converter-kotlinx-serialization:2.11.0\")
+{}    implementation(\"com.squareup.okhttp3:okhttp:4.12.0\")
+    implementation(\"org.jetbrains.kotlinx:kotlinx-serialization-json:1.6.3\")
+}}
+",
+        package,
+        package_version(swagger),
+        if uses_scalars(swagger) {
+            "    implementation(\"com.squareup.retrofit2:converter-scalars:2.11.0\")\n"
+        } else {
+            ""
+        }
+    )
+}
+
+fn generate_api_client(swagger: &Swagger, package: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    let tags: Vec<String> = operations_by_tag(swagger).into_keys().collect();
+
+    let mut kt_code = generate_header(swagger, package);
+    for tag in &tags {
+        kt_code.push_str(&format!("import {}.api.{}Api\n", package, type_name(tag)));
+    }
+    if retries.is_some() {
+        kt_code.push_str("import java.io.IOException\n");
+    }
+    kt_code.push_str("import kotlinx.serialization.json.Json\n");
+    kt_code.push_str("import okhttp3.MediaType.Companion.toMediaType\n");
+    kt_code.push_str("import okhttp3.OkHttpClient\n");
+    if has_auth {
+        kt_code.push_str("import okhttp3.Credentials\n");
+    }
+    if has_auth || retries.is_some() {
+        kt_code.push_str("import okhttp3.Interceptor\n");
+        kt_code.push_str("import okhttp3.Response\n");
+    }
+    kt_code.push_str("import retrofit2.Retrofit\n");
+    kt_code.push_str("import retrofit2.converter.kotlinx.serialization.asConverterFactory\n");
+    if uses_scalars(swagger) {
+        kt_code.push_str("import retrofit2.converter.scalars.ScalarsConverterFactory\n");
+    }
+    kt_code.push('\n');
+
+    kt_code.push_str("class ApiClient(baseUrl: String = DEFAULT_BASE_URL, httpClient: OkHttpClient = OkHttpClient()) {\n");
+    if has_auth {
+        kt_code.push_str("    private val credentials = mutableMapOf<String, String>()\n\n");
+    }
+    let mut interceptors = Vec::new();
+    if has_auth {
+        interceptors.push(".addInterceptor { chain -> authorize(chain) }");
+    }
+    if retries.is_some() {
+        interceptors.push(".addInterceptor { chain -> retry(chain) }");
+    }
+    let client = if interceptors.is_empty() {
+        "httpClient".to_string()
+    } else {
+        format!("httpClient.newBuilder(){}.build()", interceptors.concat())
+    };
+    kt_code.push_str(&format!(
+        "    private val retrofit: Retrofit = Retrofit.Builder()
+        .baseUrl(baseUrl)
+        .client({})
+{}        .addConverterFactory(Json {{ ignoreUnknownKeys = true }}.asConverterFactory(\"application/json\".toMediaType()))
+        .build()\n",
+        client,
+        // Ahead of the JSON converter, which would otherwise quote the text parts and bodies.
+        if uses_scalars(swagger) { "        .addConverterFactory(ScalarsConverterFactory.create())\n" } else { "" }
+    ));
+    for tag in &tags {
+        kt_code.push_str(&format!(
+            "\n    val {}: {}Api by lazy {{ retrofit.create({}Api::class.java) }}\n",
+            argument_name(tag),
+            type_name(tag),
+            type_name(tag)
+        ));
+    }
+
+    if has_auth {
+        kt_code.push_str(&format!(
+            "
+    /** Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth. */
+    fun setAuth(scheme: String, credential: String) {{
+        credentials[scheme] = credential
+    }}
+
+    private fun authorize(chain: Interceptor.Chain): Response {{
+        val request = chain.request()
+        val security = request.header(\"{0}\") ?: return chain.proceed(request)
+        val builder = request.newBuilder().removeHeader(\"{0}\")
+        val url = request.url.newBuilder()
+        val names = security.split('|').map {{ it.split(',') }}.firstOrNull {{ names -> names.all {{ it in credentials }} }}
+        names?.forEach {{ name ->
+            val (type, location, key) = SECURITY_SCHEMES.getValue(name)
+            val credential = credentials.getValue(name)
+            when {{
+                type == \"basic\" -> builder.header(
+                    \"Authorization\",
+                    Credentials.basic(credential.substringBefore(':'), credential.substringAfter(':')),
+                )
+                type == \"apiKey\" && location == \"query\" -> url.addQueryParameter(key, credential)
+                type == \"apiKey\" && location == \"cookie\" -> builder.addHeader(\"Cookie\", \"$key=$credential\")
+                type == \"apiKey\" -> builder.header(key, credential)
+                else -> builder.header(\"Authorization\", \"Bearer $credential\")
+            }}
+        }}
+        return chain.proceed(builder.url(url.build()).build())
+    }}\n",
+            SECURITY_HEADER
+        ));
+    }
+
+    if retries.is_some() {
+        kt_code.push_str(
+            "
+    /**
+     * Retries requests that failed with a network error, 429 or 5xx, waiting as long as
+     * Retry-After asks or otherwise doubling the delay from half a second.
+     */
+    private fun retry(chain: Interceptor.Chain): Response {
+        var attempt = 0
+        while (true) {
+            val delay = try {
+                val response = chain.proceed(chain.request())
+                if ((response.code != 429 && response.code < 500) || attempt >= MAX_RETRIES) {
+                    return response
+                }
+                val retryAfter = response.header(\"Retry-After\")?.toLongOrNull()?.times(1000)
+                    ?: response.headers.getDate(\"Retry-After\")
+                        ?.let { maxOf(it.time - System.currentTimeMillis(), 0L) }
+                response.close()
+                retryAfter ?: (500L shl attempt)
+            } catch (e: IOException) {
+                if (attempt >= MAX_RETRIES || chain.call().isCanceled()) {
+                    throw e
+                }
+                500L shl attempt
+            }
+            Thread.sleep(delay)
+            attempt++
+        }
+    }\n",
+        );
+    }
+
+    kt_code.push_str("\n    companion object {\n");
+    let mut base = base_url(swagger);
+    if !base.ends_with('/') {
+        base.push('/');
+    }
+    kt_code.push_str(&format!("        const val DEFAULT_BASE_URL = \"{}\"\n", base));
+    if let Some(retries) = retries {
+        kt_code.push_str(&format!("        const val MAX_RETRIES = {}\n", retries));
+    }
+    if has_auth {
+        kt_code.push_str("\n        private val SECURITY_SCHEMES = mapOf(\n");
+        for (name, scheme) in security_schemes(swagger) {
+            kt_code.push_str(&format!(
+                "            \"{}\" to Triple(\"{}\", \"{}\", \"{}\"),\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        kt_code.push_str("        )\n");
+    }
+    kt_code.push_str("    }\n}\n");
+
+    // Retrofit repeats a list for each item, so the ones the API takes as one value are wrapped.
+    for (list, delimiter) in joined_lists(swagger.paths.values().flat_map(|path_item| path_item.operations())) {
+        kt_code.push_str(&format!(
+            "\n/** Items sent as one query or header value, joined by {:?}. */
+class {}<T>(val items: List<T>) {{
+    override fun toString() = items.joinToString({:?})
+}}\n",
+            delimiter, list, delimiter
+        ));
+    }
+    kt_code
+}
+
+/// The lists the query and header parameters of `operations` are joined into, as the name of the
+/// class wrapping each and its delimiter.
+fn joined_lists<'a>(operations: impl Iterator<Item = (&'a str, &'a Operation)>) -> Vec<(&'static str, &'static str)> {
+    let mut lists: Vec<(&str, &str)> = operations
+        .flat_map(|(_, operation)| query_parameters(operation).into_iter().chain(header_parameters(operation)))
+        .filter_map(collection_delimiter)
+        .map(|delimiter| (joined_list(delimiter), delimiter))
+        .collect();
+    lists.sort_unstable();
+    lists.dedup();
+    lists
+}
+
+/// The class wrapping a list joined by `delimiter`, named after its `collectionFormat`.
+fn joined_list(delimiter: &str) -> &'static str {
+    match delimiter {
+        " " => "SsvList",
+        "\t" => "TsvList",
+        "|" => "PipesList",
+        _ => "CsvList",
+    }
+}
+
+fn generate_data_class(swagger: &Swagger, package: &str, name: &str, definition: &Definition) -> String {
+    let mut kt_code = generate_header(swagger, &format!("{}.model", package));
+    if let Some(values) = &definition.enum_values {
+        kt_code.push_str(&generate_enum(name, definition, values));
+        return kt_code;
+    }
+    let properties: Vec<String> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| {
+            let kt_type = property_type(prop);
+            let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+            // A required field that may be null is still decoded from a value that is present.
+            let declaration = if required && nullable {
+                format!("val {}: {}?", argument_name(prop_name), kt_type)
+            } else if required {
+                format!("val {}: {}", argument_name(prop_name), kt_type)
+            } else {
+                format!("val {}: {}? = null", argument_name(prop_name), kt_type)
+            };
+            format!("    @SerialName(\"{}\") {}", prop_name, declaration)
+        })
+        .collect();
+
+    kt_code.push_str("import kotlinx.serialization.SerialName\n");
+    kt_code.push_str("import kotlinx.serialization.Serializable\n");
+    for json_type in ["JsonElement", "JsonObject"] {
+        if properties.iter().any(|property| property.contains(json_type)) {
+            kt_code.push_str(&format!("import kotlinx.serialization.json.{}\n", json_type));
+        }
+    }
+    kt_code.push('\n');
+    kt_code.push_str("@Serializable\n");
+    if properties.is_empty() {
+        kt_code.push_str(&format!("class {}\n", type_name(name)));
+    } else {
+        kt_code.push_str(&format!("data class {}(\n{},\n)\n", type_name(name), properties.join(",\n")));
+    }
+    kt_code
+}
+
+/// A string enum as an `enum class` naming the value of each constant with `@SerialName`, and
+/// other enums as an alias of their primitive type.
+fn generate_enum(name: &str, definition: &Definition, values: &[Value]) -> String {
+    let strings: Vec<Value> = values.iter().filter(|value| value.is_string()).cloned().collect();
+    if !matches!(definition.definition_type.as_deref(), Some("string") | None) || strings.is_empty() {
+        let primitive = primitive_type(definition.definition_type.as_deref(), None);
+        return format!("typealias {} = {}\n", type_name(name), primitive);
+    }
+    let constant = |words: &[String]| match words.join("_").to_uppercase() {
+        constant if constant.starts_with(|c: char| c.is_ascii_digit()) => format!("VALUE_{}", constant),
+        constant => constant,
+    };
+    let constants: Vec<String> = enum_members(&strings, constant)
+        .into_iter()
+        .map(|(constant, value)| {
+            let value = value.as_str().unwrap_or_default();
+            let value = value.replace('\\', "\\\\").replace('"', "\\\"").replace('$', "\\$");
+            format!("    @SerialName(\"{}\") {}", value, constant)
+        })
+        .collect();
+    format!(
+        "import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+enum class {} {{
+{},
+}}\n",
+        type_name(name),
+        constants.join(",\n")
+    )
+}
+
+fn generate_api_interface(
+    swagger: &Swagger,
+    package: &str,
+    tag: &str,
+    operations: &[(&str, &str, &Operation)],
+) -> String {
+    let mut kt_code = generate_header(swagger, &format!("{}.api", package));
+    // The models the methods return, as themselves or in lists, and take as bodies.
+    let mut models: Vec<String> = operations
+        .iter()
+        .flat_map(|(_, method, operation)| {
+            let response = response_reference(operation).or_else(|| response_items(operation).and_then(item_reference));
+            [response, json_body(swagger, method, operation).and_then(item_reference)]
+        })
+        .flatten()
+        .map(|reference| type_name(ref_name(reference)))
+        .collect();
+    models.sort();
+    models.dedup();
+    for model in models {
+        kt_code.push_str(&format!("import {}.model.{}\n", package, model));
+    }
+    for (list, _) in joined_lists(operations.iter().map(|(_, method, operation)| (*method, *operation))) {
+        kt_code.push_str(&format!("import {}.{}\n", package, list));
+    }
+    let methods: Vec<String> = operations
+        .iter()
+        .map(|(path, method, operation)| generate_api_method(swagger, method, path, operation))
+        .collect();
+    kt_code.push_str("import kotlinx.serialization.json.JsonElement\n");
+    if methods.iter().any(|method| method.contains("JsonObject")) {
+        kt_code.push_str("import kotlinx.serialization.json.JsonObject\n");
+    }
+    let uploads = operations.iter().any(|(_, _, operation)| {
+        form_parameters(operation).iter().any(|param| param.parameter_type.as_deref() == Some("file"))
+    });
+    if uploads {
+        kt_code.push_str("import okhttp3.MultipartBody\n");
+    }
+    let binary_bodies = operations
+        .iter()
+        .any(|(_, method, operation)| sends_raw_body(swagger, method, operation) && binary_body(operation));
+    if binary_bodies {
+        kt_code.push_str("import okhttp3.RequestBody\n");
+    }
+    if operations.iter().any(|(_, _, operation)| has_binary_response(swagger, operation)) {
+        kt_code.push_str("import okhttp3.ResponseBody\n");
+    }
+    kt_code.push_str("import retrofit2.http.*\n\n");
+
+    kt_code.push_str(&format!("interface {}Api {{\n", type_name(tag)));
+    kt_code.push_str(&methods.join("\n"));
+    kt_code.push_str("}\n");
+    kt_code
+}
+
+fn generate_api_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let function_name = argument_name(&operation_name(method, path, operation));
+    let has_body = has_request_body(method);
+
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| {
+            format!("@Path(\"{}\") {}: {}", param, argument_name(param), path_parameter_type(operation, param))
+        })
+        .collect();
+    // Cookies are left to the cookie jar of the OkHttp client, as Retrofit has no annotation for them.
+    let form = form_parameters(operation);
+    let multipart = !form.is_empty() && request_media_type(swagger, operation) == MULTIPART;
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation).into_iter().filter(|param| param.location == "header"));
+    params.extend(&form);
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) = params.into_iter().partition(|param| param.required);
+    for param in required {
+        let argument = argument_name(&param.name);
+        arguments.push(format!("{} {}: {}", parameter_annotation(param, multipart), argument, parameter_type(param)));
+    }
+    // Strings and bytes of other media types go as they are, through the scalars converter or as a
+    // `RequestBody`, and take the Content-Type of the operation from `@Headers`.
+    if has_body && form.is_empty() {
+        arguments.push(match sends_raw_body(swagger, method, operation) {
+            true if binary_body(operation) => "@Body body: RequestBody".to_string(),
+            true => "@Body body: String".to_string(),
+            false => format!("@Body body: {}", json_body(swagger, method, operation).map_or("JsonElement".to_string(), spec_type)),
+        });
+    }
+    for param in optional {
+        let argument = argument_name(&param.name);
+        let annotation = parameter_annotation(param, multipart);
+        arguments.push(format!("{} {}: {}? = null", annotation, argument, parameter_type(param)));
+    }
+
+    let relative_path = match path.trim_start_matches('/') {
+        "" => ".",
+        relative_path => relative_path,
+    };
+    let binary = has_binary_response(swagger, operation);
+    let response_type = match (response_reference(operation), response_items(operation)) {
+        _ if binary => "ResponseBody".to_string(),
+        (Some(reference), _) => type_name(ref_name(reference)),
+        (None, Some(items)) => format!("List<{}>", spec_type(items)),
+        (None, None) => "JsonElement".to_string(),
+    };
+
+    let mut kt_code = String::new();
+    if let Some(summary) = &operation.summary {
+        kt_code.push_str(&format!("    /** {} */\n", summary));
+    }
+    if operation.deprecated {
+        kt_code.push_str("    @Deprecated(\"Deprecated by the API\")\n");
+    }
+    kt_code.push_str(&format!("    @{}(\"{}\")\n", method.to_uppercase(), relative_path));
+    if multipart {
+        kt_code.push_str("    @Multipart\n");
+    } else if !form.is_empty() {
+        kt_code.push_str("    @FormUrlEncoded\n");
+    }
+    // Reads file downloads as they arrive instead of buffering them in memory.
+    if binary {
+        kt_code.push_str("    @Streaming\n");
+    }
+    let mut headers = Vec::new();
+    if let Some(media_type) = body_media_type(swagger, method, operation) {
+        headers.push(format!("\"Content-Type: {}\"", media_type));
+    }
+    if !swagger.security_definitions.is_empty() {
+        let requirements: Vec<String> =
+            security_requirements(swagger, operation).iter().map(|names| names.join(",")).collect();
+        if !requirements.is_empty() {
+            headers.push(format!("\"{}: {}\"", SECURITY_HEADER, requirements.join("|")));
+        }
+    }
+    if !headers.is_empty() {
+        kt_code.push_str(&format!("    @Headers({})\n", headers.join(", ")));
+    }
+    kt_code.push_str(&format!(
+        "    suspend fun {}({}): {}\n",
+        function_name,
+        arguments.join(", "),
+        response_type
+    ));
+    kt_code
+}
+
+/// The annotation of a parameter; a file of a multipart body is a part naming itself.
+fn parameter_annotation(param: &Parameter, multipart: bool) -> String {
+    match param.location.as_str() {
+        "query" => format!("@Query(\"{}\")", param.name),
+        "formData" if param.parameter_type.as_deref() == Some("file") => "@Part".to_string(),
+        "formData" if multipart => format!("@Part(\"{}\")", param.name),
+        "formData" => format!("@Field(\"{}\")", param.name),
+        _ => format!("@Header(\"{}\")", param.name),
+    }
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            match collection_delimiter(param) {
+                Some(delimiter) => format!("{}<{}>", joined_list(delimiter), items_type),
+                None => format!("List<{}>", items_type),
+            }
+        }
+        Some("file") => "MultipartBody.Part".to_string(),
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+/// The schema of the body an operation sends as JSON, when it is not a form.
+fn json_body<'a>(swagger: &Swagger, method: &str, operation: &'a Operation) -> Option<&'a Schema> {
+    let body = operation.parameters.iter().find(|param| param.location == "body")?;
+    let json = has_request_body(method) && form_parameters(operation).is_empty();
+    body.schema.as_ref().filter(|_| json && !sends_raw_body(swagger, method, operation))
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `String` for parameters the
+/// operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(kt_type @ ("Int" | "Long" | "Float" | "Double" | "Boolean")) => kt_type,
+        _ => "String",
+    }
+}
+
+fn spec_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return type_name(ref_name(reference));
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => format!("List<{}>", schema.items.as_deref().map_or("JsonElement".to_string(), spec_type)),
+        other => primitive_type(other, schema.format.as_deref()).to_string(),
+    }
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => {
+            format!("List<{}>", prop.additional.get("items").map_or("JsonElement".to_string(), schema_type))
+        }
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("List<{}>", schema.get("items").map_or("JsonElement".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), Some("int32")) => "Int",
+        (Some("integer"), _) => "Long",
+        (Some("number"), Some("float")) => "Float",
+        (Some("number"), _) => "Double",
+        (Some("string"), _) => "String",
+        (Some("boolean"), _) => "Boolean",
+        (Some("object"), _) => "JsonObject",
+        _ => "JsonElement",
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    let type_name = type_name(name);
+    let mut chars = type_name.chars();
+    let argument: String = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    };
+    match argument.as_str() {
+        "body" => "bodyParam".to_string(),
+        _ if KEYWORDS.contains(&argument.as_str()) => format!("`{}`", argument),
+        _ => argument,
+    }
+}
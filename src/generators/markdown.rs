@@ -0,0 +1,403 @@
+use super::mock::{to_js, Examples};
+use super::{
+    base_url, consumes, operations_by_tag, produces, request_media_type, security_requirements, success_responses,
+    words, TaggedOperation,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Operation, Parameter, Swagger};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+/// Writes the reference of the API: its operations grouped by tag, with their parameters,
+/// bodies, responses and examples, and its schemas. It is `API.md`, or with `split_by_tag` a
+/// `README.md` linking to a file per tag and to `schemas.md`.
+pub fn generate(swagger: &Swagger, split_by_tag: bool) -> GeneratedFiles {
+    let groups = operations_by_tag(swagger);
+    let reference =
+        Reference { swagger, examples: Examples::new(swagger), anchors: Anchors::new(swagger, &groups, split_by_tag) };
+    let mut files = GeneratedFiles::new();
+    if !split_by_tag {
+        let mut md = reference.overview(&groups);
+        for (tag, operations) in &groups {
+            md.push_str(&reference.tag_section(tag, operations, "##"));
+        }
+        md.push_str(&reference.schemas_section("##"));
+        files.insert(PathBuf::from("API.md"), md);
+        return files;
+    }
+
+    files.insert(PathBuf::from("README.md"), reference.overview(&groups));
+    for (tag, operations) in &groups {
+        let md = reference.tag_section(tag, operations, "#");
+        files.insert(PathBuf::from(tag_file(tag)), md.trim_start().to_string());
+    }
+    if !swagger.definitions.is_empty() {
+        files.insert(PathBuf::from("schemas.md"), reference.schemas_section("#").trim_start().to_string());
+    }
+    files
+}
+
+fn tag_file(tag: &str) -> String {
+    format!("{}.md", words(tag).join("-"))
+}
+
+/// What a heading of the reference is about.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Heading {
+    Section(&'static str),
+    Tag(String),
+    Operation(String, String),
+    Schema(String),
+}
+
+/// The links to the headings of the reference, made as GitHub makes the anchors of headings:
+/// their lowercase text without punctuation, numbered when a file repeats one.
+struct Anchors {
+    links: BTreeMap<Heading, String>,
+}
+
+impl Anchors {
+    fn new(swagger: &Swagger, groups: &BTreeMap<String, Vec<TaggedOperation<'_>>>, split_by_tag: bool) -> Anchors {
+        let mut links = BTreeMap::new();
+        let mut files: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        let mut add = |file: &str, heading: Heading, text: &str| {
+            let slugs = files.entry(file.to_string()).or_default();
+            let slug = slug(text);
+            let count = slugs.entry(slug.clone()).or_insert(0);
+            let anchor = if *count == 0 { slug } else { format!("{}-{}", slug, count) };
+            *count += 1;
+            links.insert(heading, format!("{}#{}", file, anchor));
+        };
+        let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or("API");
+        let overview = if split_by_tag { "README.md" } else { "" };
+        add(overview, Heading::Section("Title"), title);
+        add(overview, Heading::Section("Operations"), "Operations");
+        for (tag, operations) in groups {
+            let file = if split_by_tag { tag_file(tag) } else { String::new() };
+            add(&file, Heading::Tag(tag.clone()), tag);
+            for (path, method, _) in operations {
+                add(&file, Heading::Operation(method.to_string(), path.to_string()), &format!("{} {}", method, path));
+            }
+        }
+        let file = if split_by_tag { "schemas.md" } else { "" };
+        add(file, Heading::Section("Schemas"), "Schemas");
+        for name in swagger.definitions.keys() {
+            add(file, Heading::Schema(name.clone()), name);
+        }
+        Anchors { links }
+    }
+
+    fn link(&self, heading: &Heading) -> &str {
+        self.links.get(heading).map_or("#", String::as_str)
+    }
+}
+
+fn slug(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == ' ')
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Text fit for a table cell, which ends at a line break and splits at a `|`.
+fn cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").lines().collect::<Vec<_>>().join("<br>")
+}
+
+fn json_block(value: &Value) -> String {
+    format!("```json\n{}\n```\n", to_js(value))
+}
+
+struct Reference<'a> {
+    swagger: &'a Swagger,
+    examples: Examples,
+    anchors: Anchors,
+}
+
+impl Reference<'_> {
+    /// The title, description and servers of the API and the list of its operations.
+    fn overview(&self, groups: &BTreeMap<String, Vec<TaggedOperation<'_>>>) -> String {
+        let info = |key: &str| self.swagger.info.get(key).and_then(Value::as_str);
+        let mut md = format!("# {}\n\n", info("title").unwrap_or("API"));
+        if let Some(description) = info("description").filter(|description| !description.trim().is_empty()) {
+            md.push_str(&format!("{}\n\n", description.trim()));
+        }
+        if let Some(version) = info("version") {
+            md.push_str(&format!("- **Version:** {}\n", version));
+        }
+        md.push_str(&format!("- **Base URL:** `{}`\n", base_url(self.swagger)));
+        for server in self.swagger.servers.iter().skip(1) {
+            md.push_str(&format!("- **Server:** `{}`", server.url));
+            match &server.description {
+                Some(description) => md.push_str(&format!(" — {}\n", description)),
+                None => md.push('\n'),
+            }
+        }
+
+        md.push_str("\n## Operations\n\n");
+        for (tag, operations) in groups {
+            md.push_str(&format!("- [{}]({})\n", tag, self.anchors.link(&Heading::Tag(tag.clone()))));
+            for (path, method, operation) in operations {
+                let heading = Heading::Operation(method.to_string(), path.to_string());
+                md.push_str(&format!("  - [`{} {}`]({})", method.to_uppercase(), path, self.anchors.link(&heading)));
+                match &operation.summary {
+                    Some(summary) => md.push_str(&format!(" — {}\n", summary.trim())),
+                    None => md.push('\n'),
+                }
+            }
+        }
+        if !self.swagger.definitions.is_empty() {
+            md.push_str(&format!("- [Schemas]({})\n", self.anchors.link(&Heading::Section("Schemas"))));
+        }
+        md
+    }
+
+    fn tag_section(&self, tag: &str, operations: &[TaggedOperation<'_>], level: &str) -> String {
+        let mut md = format!("\n{} {}\n", level, tag);
+        for (path, method, operation) in operations {
+            md.push_str(&self.operation_section(method, path, operation, &format!("{}#", level)));
+        }
+        md
+    }
+
+    fn operation_section(&self, method: &str, path: &str, operation: &Operation, level: &str) -> String {
+        let mut md = format!("\n{} `{} {}`\n\n", level, method.to_uppercase(), path);
+        if operation.deprecated {
+            md.push_str("> **Deprecated**\n\n");
+        }
+        if let Some(summary) = &operation.summary {
+            md.push_str(&format!("{}\n\n", summary.trim()));
+        }
+        if let Some(description) = operation.description.as_deref().filter(|d| Some(*d) != operation.summary.as_deref())
+        {
+            md.push_str(&format!("{}\n\n", description.trim()));
+        }
+        if let Some(id) = &operation.operation_id {
+            md.push_str(&format!("- **Operation ID:** `{}`\n", id));
+        }
+        let requirements = security_requirements(self.swagger, operation);
+        if !requirements.is_empty() {
+            let requirements: Vec<String> = requirements
+                .iter()
+                .map(|names| names.iter().map(|name| format!("`{}`", name)).collect::<Vec<_>>().join(" + "))
+                .collect();
+            md.push_str(&format!("- **Security:** {}\n", requirements.join(" or ")));
+        }
+        let produces = produces(self.swagger, operation);
+        if !produces.is_empty() {
+            let produces: Vec<String> = produces.iter().map(|media| format!("`{}`", media)).collect();
+            md.push_str(&format!("- **Produces:** {}\n", produces.join(", ")));
+        }
+        if !md.ends_with("\n\n") {
+            md.push('\n');
+        }
+
+        let parameters: Vec<&Parameter> =
+            operation.parameters.iter().filter(|param| param.location != "body").collect();
+        if !parameters.is_empty() {
+            md.push_str(&format!("{}# Parameters\n\n", level));
+            md.push_str("| Name | In | Type | Required | Description |\n");
+            md.push_str("| --- | --- | --- | --- | --- |\n");
+            for param in parameters {
+                md.push_str(&format!(
+                    "| `{}` | {} | {} | {} | {} |\n",
+                    param.name,
+                    param.location,
+                    self.parameter_type(param),
+                    if param.required || param.location == "path" { "yes" } else { "no" },
+                    cell(&self.described(param.description.as_deref(), parameter_enum(param)))
+                ));
+            }
+            md.push('\n');
+        }
+
+        if let Some(body) = operation.parameters.iter().find(|param| param.location == "body") {
+            md.push_str(&format!("{}# Request body\n\n", level));
+            let schema = body.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok()).unwrap_or(json!({}));
+            let media = if consumes(self.swagger, operation).is_empty() {
+                "application/json"
+            } else {
+                request_media_type(self.swagger, operation)
+            };
+            md.push_str(&format!(
+                "`{}` — {}{}\n\n",
+                media,
+                self.value_type(&schema),
+                if body.required { " (required)" } else { "" }
+            ));
+            if let Some(description) = &body.description {
+                md.push_str(&format!("{}\n\n", description.trim()));
+            }
+            if media.contains("json") {
+                md.push_str(&json_block(&self.examples.example(&schema)));
+                md.push('\n');
+            }
+        }
+
+        if !operation.responses.is_empty() {
+            md.push_str(&format!("{}# Responses\n\n", level));
+            md.push_str("| Status | Description | Type |\n");
+            md.push_str("| --- | --- | --- |\n");
+            for (status, response) in &operation.responses {
+                let schema = response.response_schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok());
+                md.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    status,
+                    cell(&response.description),
+                    schema.as_ref().map_or(String::new(), |schema| self.value_type(schema))
+                ));
+            }
+            md.push('\n');
+            let example = success_responses(operation).into_iter().find_map(|(status, response)| {
+                let schema = serde_json::to_value(response.response_schema.as_ref()?).ok()?;
+                Some((status, self.examples.example(&schema)))
+            });
+            if let Some((status, example)) = example {
+                md.push_str(&format!("Example `{}` response:\n\n", status));
+                md.push_str(&json_block(&example));
+            }
+        }
+        md.trim_end().to_string() + "\n"
+    }
+
+    fn schemas_section(&self, level: &str) -> String {
+        if self.swagger.definitions.is_empty() {
+            return String::new();
+        }
+        let mut md = format!("\n{} Schemas\n", level);
+        for (name, definition) in &self.swagger.definitions {
+            let Ok(definition) = serde_json::to_value(definition) else {
+                continue;
+            };
+            md.push_str(&format!("\n{}# {}\n\n", level, name));
+            if let Some(description) = definition.get("description").and_then(Value::as_str) {
+                md.push_str(&format!("{}\n\n", description.trim()));
+            }
+            let parts = definition.get("allOf").and_then(Value::as_array);
+            let bases: Vec<String> = parts
+                .into_iter()
+                .flatten()
+                .filter(|part| part.get("$ref").is_some())
+                .map(|part| self.value_type(part))
+                .collect();
+            if !bases.is_empty() {
+                md.push_str(&format!("Extends {}.\n\n", bases.join(", ")));
+            }
+            let members = definition.get("oneOf").or_else(|| definition.get("anyOf")).and_then(Value::as_array);
+            if let Some(members) = members {
+                let members: Vec<String> = members.iter().map(|member| self.value_type(member)).collect();
+                md.push_str(&format!("One of {}.\n\n", members.join(", ")));
+            }
+            if let Some(values) = schema_enum(&definition) {
+                md.push_str(&format!("{}.\n\n", values));
+            }
+
+            let own = std::iter::once(&definition)
+                .chain(parts.into_iter().flatten().filter(|part| part.get("$ref").is_none()));
+            let mut rows = String::new();
+            for part in own {
+                let required: Vec<&str> = part
+                    .get("required")
+                    .and_then(Value::as_array)
+                    .map(|required| required.iter().filter_map(Value::as_str).collect())
+                    .unwrap_or_default();
+                for (prop_name, prop) in part.get("properties").and_then(Value::as_object).into_iter().flatten() {
+                    let description = prop.get("description").and_then(Value::as_str);
+                    rows.push_str(&format!(
+                        "| `{}` | {} | {} | {} |\n",
+                        prop_name,
+                        self.value_type(prop),
+                        if required.contains(&prop_name.as_str()) { "yes" } else { "no" },
+                        cell(&self.described(description, schema_enum(prop)))
+                    ));
+                }
+            }
+            if !rows.is_empty() {
+                md.push_str("| Property | Type | Required | Description |\n");
+                md.push_str("| --- | --- | --- | --- |\n");
+                md.push_str(&rows);
+                md.push('\n');
+            }
+            let example = self.examples.example(&json!({ "$ref": format!("#/definitions/{}", name) }));
+            if !example.is_null() {
+                md.push_str(&json_block(&example));
+            }
+        }
+        md
+    }
+
+    /// A description followed by the values it is restricted to.
+    fn described(&self, description: Option<&str>, values: Option<String>) -> String {
+        let description = description.unwrap_or_default().trim();
+        match values {
+            Some(values) if description.is_empty() => format!("{}.", values),
+            Some(values) => format!("{} {}.", description, values),
+            None => description.to_string(),
+        }
+    }
+
+    fn parameter_type(&self, param: &Parameter) -> String {
+        if let Some(schema) = &param.schema {
+            return serde_json::to_value(schema).map_or("any".to_string(), |schema| self.value_type(&schema));
+        }
+        let mut schema = json!({ "type": param.parameter_type, "format": param.format });
+        if let Some(items) = &param.items {
+            schema["items"] = serde_json::to_value(items).unwrap_or(Value::Null);
+        }
+        self.value_type(&schema)
+    }
+
+    /// The type of a schema, linking to the schemas it refers to.
+    fn value_type(&self, schema: &Value) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = ref_name(reference);
+            return format!("[{}]({})", name, self.anchors.link(&Heading::Schema(name.to_string())));
+        }
+        for (key, separator) in [("oneOf", " or "), ("anyOf", " or "), ("allOf", " and ")] {
+            if let Some(members) = schema.get(key).and_then(Value::as_array) {
+                let members: Vec<String> = members.iter().map(|member| self.value_type(member)).collect();
+                return members.join(separator);
+            }
+        }
+        let format = schema.get("format").and_then(Value::as_str);
+        match schema.get("type").and_then(Value::as_str) {
+            Some("array") => match schema.get("items") {
+                Some(items) => format!("array of {}", self.value_type(items)),
+                None => "array".to_string(),
+            },
+            Some("object") => match schema.get("additionalProperties").filter(|values| values.is_object()) {
+                Some(values) => format!("map of {}", self.value_type(values)),
+                None => "object".to_string(),
+            },
+            Some(schema_type) => match format {
+                Some(format) => format!("{} ({})", schema_type, format),
+                None => schema_type.to_string(),
+            },
+            None => "any".to_string(),
+        }
+    }
+}
+
+fn parameter_enum(param: &Parameter) -> Option<String> {
+    let values = param.enum_values.as_ref().or(param.items.as_ref().and_then(|items| items.enum_values.as_ref()))?;
+    Some(enum_list(values))
+}
+
+/// The values a schema, or the items of an array schema, is restricted to.
+fn schema_enum(schema: &Value) -> Option<String> {
+    let values = schema.get("enum").or_else(|| schema.get("items")?.get("enum"))?.as_array()?;
+    Some(enum_list(values))
+}
+
+fn enum_list(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::String(value) => format!("`{}`", value),
+            value => format!("`{}`", value),
+        })
+        .collect();
+    format!("One of {}", values.join(", "))
+}
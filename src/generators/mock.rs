@@ -0,0 +1,258 @@
+use super::typescript::generate_info_comment;
+use super::{
+    error_responses, has_binary_response, operation_name, package_name, package_version, success_responses,
+    with_custom_region,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Operation, Swagger};
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
+use serde_json::{json, Map, Value};
+use std::path::PathBuf;
+
+const EXPRESS_VERSION: &str = "^4.21.2";
+
+/// Lets a frontend served from another origin call the mock server, answering preflights itself.
+const CORS_MIDDLEWARE: &str = "app.use((req, res, next) => {
+    res.set('Access-Control-Allow-Origin', '*');
+    res.set('Access-Control-Allow-Headers', '*');
+    res.set('Access-Control-Allow-Methods', 'GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS');
+    if (req.method === 'OPTIONS') {
+        res.sendStatus(204);
+        return;
+    }
+    next();
+});
+";
+
+/// Writes an Express server with a route for every operation, answering with its first success
+/// response and a body made from the examples of its schema, or from placeholder values of the
+/// right types where it has none.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("package.json"), generate_package(swagger));
+    files.insert(PathBuf::from("server.js"), with_custom_region(generate_server(swagger), "//"));
+    files
+}
+
+fn generate_package(swagger: &Swagger) -> String {
+    let package = json!({
+        "name": format!("{}-mock", package_name(swagger)),
+        "version": package_version(swagger),
+        "private": true,
+        "main": "server.js",
+        "scripts": { "start": "node server.js" },
+        "dependencies": { "express": EXPRESS_VERSION },
+    });
+    serde_json::to_string_pretty(&package).unwrap_or_default() + "\n"
+}
+
+fn generate_server(swagger: &Swagger) -> String {
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    let examples = Examples::new(swagger);
+
+    let mut js_code = String::new();
+    generate_info_comment(swagger, &mut js_code);
+    js_code.push_str("const express = require('express');\n\n");
+    js_code.push_str(&format!("const BASE_PATH = '{}';\n", base_path));
+    js_code.push_str("const PORT = process.env.PORT || 3000;\n\n");
+    js_code.push_str("const app = express();\n");
+    js_code.push_str("app.use(express.json());\n");
+    js_code.push_str(CORS_MIDDLEWARE);
+    js_code.push_str("\nconst router = express.Router();\n");
+    js_code.push_str("app.use(BASE_PATH || '/', router);\n");
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            js_code.push('\n');
+            push_route(&mut js_code, swagger, &examples, method, path, operation);
+        }
+    }
+    js_code.push_str("\napp.listen(PORT, () => {\n");
+    js_code.push_str("    console.log(`Mock server listening on http://localhost:${PORT}${BASE_PATH}`);\n");
+    js_code.push_str("});\n");
+    js_code
+}
+
+fn push_route(
+    js_code: &mut String,
+    swagger: &Swagger,
+    examples: &Examples,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+) {
+    let (status, reply) = success_reply(swagger, examples, operation);
+    let reply = match reply {
+        Reply::Empty => "end()".to_string(),
+        Reply::Binary => "type('application/octet-stream').send(Buffer.alloc(0))".to_string(),
+        Reply::Json(example) => format!("json({})", to_js(&example).replace('\n', "\n    ")),
+    };
+
+    js_code.push_str(&format!("// {}: {} {}\n", operation_name(method, path, operation), method.to_uppercase(), path));
+    js_code.push_str(&format!("router.{}('{}', (req, res) => {{\n", method, express_path(path)));
+    js_code.push_str(&format!("    res.status({}).{};\n", status, reply));
+    js_code.push_str("});\n");
+}
+
+/// What a mock answers a request with.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reply {
+    Empty,
+    /// An empty file.
+    Binary,
+    Json(Value),
+}
+
+/// The status and body of the first success response of `operation`, or an empty 200 when it
+/// documents none.
+pub fn success_reply(swagger: &Swagger, examples: &Examples, operation: &Operation) -> (u16, Reply) {
+    let success = success_responses(operation).into_iter().next();
+    let status = success.and_then(|(status, _)| status.parse().ok()).unwrap_or(200);
+    let schema = success.and_then(|(_, response)| response.response_schema.as_ref());
+    if has_binary_response(swagger, operation) {
+        return (status, Reply::Binary);
+    }
+    match schema.map(serde_json::to_value) {
+        Some(Ok(schema)) => (status, Reply::Json(examples.example(&schema))),
+        _ => (status, Reply::Empty),
+    }
+}
+
+/// The status and body of the first error response `operation` documents a body for, with 500
+/// for `default`.
+pub fn error_reply(examples: &Examples, operation: &Operation) -> Option<(u16, Reply)> {
+    let (status, response) = error_responses(operation).into_iter().next()?;
+    let schema = serde_json::to_value(response.response_schema.as_ref()?).ok()?;
+    Some((status.parse().unwrap_or(500), Reply::Json(examples.example(&schema))))
+}
+
+/// `path` with its `{parameters}` in the `:parameter` form of Express routes, which only allows
+/// word characters in their names.
+pub fn express_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')) {
+            Some(name) => {
+                let name: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+                format!(":{}", name)
+            }
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `value` as JSON indented like the code around it.
+pub fn to_js(value: &Value) -> String {
+    let mut json = Vec::new();
+    let mut serializer = serde_json::Serializer::with_formatter(&mut json, PrettyFormatter::with_indent(b"    "));
+    match value.serialize(&mut serializer) {
+        Ok(()) => String::from_utf8(json).unwrap_or_default(),
+        Err(_) => "null".to_string(),
+    }
+}
+
+/// Makes up the values of schemas, following their references to the definitions of the
+/// document.
+pub struct Examples {
+    definitions: Map<String, Value>,
+}
+
+impl Examples {
+    pub fn new(swagger: &Swagger) -> Examples {
+        let definitions = swagger
+            .definitions
+            .iter()
+            .filter_map(|(name, definition)| serde_json::to_value(definition).ok().map(|value| (name.clone(), value)))
+            .collect();
+        Examples { definitions }
+    }
+
+    /// A value of `schema`, a schema of the document as JSON.
+    pub fn example(&self, schema: &Value) -> Value {
+        self.of(schema, &mut Vec::new())
+    }
+
+    /// A value of `schema`: its example, default or first enum value when it has one, and
+    /// otherwise one made up from its type. `visiting` holds the definitions being made up, which
+    /// a definition reached again inside itself ends at with `null`.
+    fn of(&self, schema: &Value, visiting: &mut Vec<String>) -> Value {
+        let Value::Object(schema) = schema else {
+            return Value::Null;
+        };
+        let field = |key: &str| schema.get(key).filter(|value| !value.is_null());
+
+        if let Some(example) = field("example").or_else(|| field("default")) {
+            return example.clone();
+        }
+        if let Some(first) = field("enum").and_then(Value::as_array).and_then(|values| values.first()) {
+            return first.clone();
+        }
+        if let Some(reference) = field("$ref").and_then(Value::as_str) {
+            let name = ref_name(reference);
+            return match self.definitions.get(name) {
+                Some(definition) if !visiting.iter().any(|visited| visited == name) => {
+                    visiting.push(name.to_string());
+                    let example = self.of(definition, visiting);
+                    visiting.pop();
+                    example
+                }
+                _ => Value::Null,
+            };
+        }
+        if let Some(parts) = field("allOf").and_then(Value::as_array) {
+            let mut merged = Map::new();
+            for part in parts {
+                if let Value::Object(part) = self.of(part, visiting) {
+                    merged.extend(part);
+                }
+            }
+            return Value::Object(merged);
+        }
+        if let Some(alternatives) = field("oneOf").or_else(|| field("anyOf")).and_then(Value::as_array) {
+            return alternatives.first().map_or(Value::Null, |first| self.of(first, visiting));
+        }
+
+        match field("type").and_then(Value::as_str) {
+            Some("array") => match field("items").map(|items| self.of(items, visiting)) {
+                Some(Value::Null) | None => json!([]),
+                Some(item) => json!([item]),
+            },
+            Some("string") => json!(string_example(field("format").and_then(Value::as_str))),
+            Some("integer") => json!(0),
+            Some("number") => json!(0.0),
+            Some("boolean") => json!(true),
+            Some("object") => self.object(field("properties"), field("additionalProperties"), visiting),
+            None if field("properties").is_some() => self.object(field("properties"), None, visiting),
+            _ => Value::Null,
+        }
+    }
+
+    fn object(&self, properties: Option<&Value>, values: Option<&Value>, visiting: &mut Vec<String>) -> Value {
+        let mut object = Map::new();
+        if let Some(Value::Object(properties)) = properties {
+            for (name, property) in properties {
+                object.insert(name.clone(), self.of(property, visiting));
+            }
+        } else if let Some(values @ Value::Object(_)) = values {
+            object.insert("key".to_string(), self.of(values, visiting));
+        }
+        Value::Object(object)
+    }
+}
+
+/// A string of `format`, e.g. a date for `date`.
+fn string_example(format: Option<&str>) -> &'static str {
+    match format {
+        Some("date") => "2024-01-01",
+        Some("date-time") => "2024-01-01T00:00:00Z",
+        Some("time") => "12:00:00",
+        Some("email") => "user@example.com",
+        Some("uuid") => "3fa85f64-5717-4562-b3fc-2c963f66afa6",
+        Some("uri" | "url") => "https://example.com",
+        Some("hostname") => "example.com",
+        Some("ipv4") => "192.0.2.1",
+        Some("ipv6") => "2001:db8::1",
+        Some("byte") => "c3RyaW5n",
+        _ => "string",
+    }
+}
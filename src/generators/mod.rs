@@ -0,0 +1,618 @@
+pub mod actix;
+pub mod angular;
+pub mod axum;
+pub mod codecs;
+pub mod csharp;
+pub mod dart;
+pub mod express;
+pub mod factories;
+pub mod fastapi;
+pub mod go;
+pub mod guards;
+pub mod html;
+pub mod http;
+pub mod java;
+pub mod jest;
+pub mod json_schema;
+pub mod k6;
+pub mod kotlin;
+pub mod markdown;
+pub mod mock;
+pub mod msw;
+pub mod nest;
+pub mod pact;
+pub mod php;
+pub mod python;
+pub mod ruby;
+pub mod rtk_query;
+pub mod rust;
+pub mod server;
+pub mod swift;
+pub mod typescript;
+pub mod vue;
+pub mod zod;
+
+use crate::output::{CUSTOM_END, CUSTOM_START};
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Response, Schema, SecurityScheme, Swagger};
+use crate::templates;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashSet};
+use typescript::base_interfaces;
+
+/// Lines of the banner placed at the top of every generated file, without comment markers.
+pub fn info_lines(swagger: &Swagger) -> Vec<String> {
+    let field = |name: &str| swagger.info.get(name).and_then(Value::as_str).unwrap_or_default();
+    let context = json!({
+        "title": field("title"),
+        "version": field("version"),
+        "description": field("description"),
+        "author": swagger.info.get("contact").and_then(|contact| contact.get("name")),
+        "info": swagger.info,
+        "generated_on": templates::generated_on(),
+    });
+    templates::render("header", &context).lines().map(str::to_string).collect()
+}
+
+/// The banner as a `/* */` block comment, with `*/` in the spec's text written as `*\/` so that it
+/// cannot end the comment early, and `/*` as `/\*` since Kotlin nests block comments.
+pub fn info_block(swagger: &Swagger) -> String {
+    let lines: Vec<String> = info_lines(swagger)
+        .iter()
+        .map(|line| format!(" * {}", line.replace("*/", "*\\/").replace("/*", "/\\*")))
+        .collect();
+    format!("/*\n{}\n */", lines.join("\n"))
+}
+
+/// `code` ending in an empty custom region, in line comments starting with `comment`, for code
+/// added by hand that generating the file again keeps.
+pub fn with_custom_region(code: String, comment: &str) -> String {
+    format!("{}\n\n{comment} {}\n{comment} {}\n", code.trim_end(), CUSTOM_START, CUSTOM_END)
+}
+
+/// Lowercase alphanumeric package name derived from the spec title, e.g. `Uber API` → `uberapi`.
+pub fn package_name(swagger: &Swagger) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    let package: String = title.chars().filter(char::is_ascii_alphanumeric).collect::<String>().to_lowercase();
+    if package.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        package
+    } else {
+        "api".to_string()
+    }
+}
+
+/// The spec version when it is a plain `major.minor.patch` version, `0.1.0` otherwise.
+pub fn package_version(swagger: &Swagger) -> &str {
+    let version = swagger.info.get("version").and_then(Value::as_str).unwrap_or_default();
+    let is_semver = version.split('.').count() == 3 && version.split('.').all(|part| part.parse::<u64>().is_ok());
+    if is_semver {
+        version
+    } else {
+        "0.1.0"
+    }
+}
+
+/// The URL the paths of the document are relative to, without a trailing slash, as they start with one.
+pub fn base_url(swagger: &Swagger) -> String {
+    let scheme = swagger.schemes.as_ref().and_then(|schemes| schemes.first()).map_or("https", String::as_str);
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+    match swagger.host.as_deref().filter(|host| !host.is_empty()) {
+        Some(host) => format!("{}://{}{}", scheme, host, base_path),
+        None => base_path.to_string(),
+    }
+}
+
+/// Whether the document lists more than one server or templates a server URL, so that clients
+/// are worth generating a `serverUrl` helper for.
+pub fn has_server_choices(swagger: &Swagger) -> bool {
+    swagger.servers.len() > 1 || swagger.servers.iter().any(|server| !server.variables.is_empty())
+}
+
+pub fn security_schemes(swagger: &Swagger) -> Vec<(&String, &SecurityScheme)> {
+    swagger.security_definitions.iter().collect()
+}
+
+/// Security requirements of an operation, falling back to the document-wide ones, with each
+/// requirement given as the names of the schemes it combines.
+pub fn security_requirements(swagger: &Swagger, operation: &Operation) -> Vec<Vec<String>> {
+    operation
+        .security
+        .as_ref()
+        .unwrap_or(&swagger.security)
+        .iter()
+        .filter(|requirement| requirement.keys().all(|name| swagger.security_definitions.contains_key(name)))
+        .map(|requirement| requirement.keys().cloned().collect())
+        .collect()
+}
+
+/// Media types an operation accepts, falling back to the document-wide ones.
+pub fn consumes<'a>(swagger: &'a Swagger, operation: &'a Operation) -> &'a [String] {
+    operation.consumes.as_deref().unwrap_or(&swagger.consumes)
+}
+
+pub const MULTIPART: &str = "multipart/form-data";
+pub const URL_ENCODED: &str = "application/x-www-form-urlencoded";
+
+/// Media type an operation sends its body as. Form fields go multipart when the operation consumes
+/// `multipart/form-data` or uploads a file and URL-encoded otherwise, as in the OpenAPI 3 conversion; other
+/// bodies take the first JSON type consumed, then a form type, then the first one, with JSON implied
+/// when none is named.
+pub fn request_media_type<'a>(swagger: &'a Swagger, operation: &'a Operation) -> &'a str {
+    let consumes = consumes(swagger, operation);
+    let consumed = |media: &str| consumes.iter().any(|media_type| essence(media_type) == media);
+    let form: Vec<_> = operation.parameters.iter().filter(|param| param.location == "formData").collect();
+    if !form.is_empty() {
+        let uploads = form.iter().any(|param| param.parameter_type.as_deref() == Some("file"));
+        return if uploads || consumed(MULTIPART) { MULTIPART } else { URL_ENCODED };
+    }
+    match consumes.iter().find(|media_type| media_type.contains("json")) {
+        Some(media_type) => media_type,
+        None if consumed(MULTIPART) => MULTIPART,
+        None if consumed(URL_ENCODED) => URL_ENCODED,
+        None => consumes.first().map_or("application/json", String::as_str),
+    }
+}
+
+/// The `Accept` header of an operation that produces no JSON, listing the media types it does produce.
+pub fn accept_header(swagger: &Swagger, operation: &Operation) -> Option<String> {
+    let produces = produces(swagger, operation);
+    if produces.is_empty() || produces.iter().any(|media_type| media_type.contains("json")) {
+        return None;
+    }
+    Some(produces.join(", "))
+}
+
+/// The media type a body without form fields is labelled with when it is not plain `application/json`,
+/// which the clients encoding only JSON themselves then set as the `Content-Type`.
+pub fn body_media_type<'a>(swagger: &'a Swagger, method: &str, operation: &'a Operation) -> Option<&'a str> {
+    let form = operation.parameters.iter().any(|param| param.location == "formData");
+    let media_type = request_media_type(swagger, operation);
+    (has_request_body(method) && !form && media_type != "application/json").then_some(media_type)
+}
+
+/// Whether a body goes as it is rather than JSON-encoded: a string, as text or bytes, sent as a media
+/// type other than JSON. Bodies of other schemas are JSON-encoded whatever they are labelled with.
+pub fn sends_raw_body(swagger: &Swagger, method: &str, operation: &Operation) -> bool {
+    let string_body = operation.parameters.iter().any(|param| {
+        param.location == "body"
+            && param.schema.as_ref().is_some_and(|schema| schema.schema_type.as_deref() == Some("string"))
+    });
+    string_body && body_media_type(swagger, method, operation).is_some_and(|media_type| !media_type.contains("json"))
+}
+
+/// Whether the raw body of an operation is binary, which clients take as bytes rather than as a string.
+pub fn binary_body(operation: &Operation) -> bool {
+    operation.parameters.iter().filter(|param| param.location == "body").any(|param| {
+        param.schema.as_ref().is_some_and(|schema| schema.format.as_deref() == Some("binary"))
+    })
+}
+
+/// A media type without its parameters, such as `charset`.
+fn essence(media_type: &str) -> &str {
+    media_type.split(';').next().unwrap_or_default().trim()
+}
+
+/// Media types an operation responds with, falling back to the document-wide ones.
+pub fn produces<'a>(swagger: &'a Swagger, operation: &'a Operation) -> &'a [String] {
+    operation.produces.as_deref().unwrap_or(&swagger.produces)
+}
+
+/// Whether an operation responds with a file rather than JSON: its success response is a `file`
+/// or `binary` string schema, or it produces `application/octet-stream` and no JSON.
+pub fn has_binary_response(swagger: &Swagger, operation: &Operation) -> bool {
+    let schema = success_responses(operation).into_iter().find_map(|(_, response)| response.response_schema.as_ref());
+    if let Some(schema) = schema {
+        let schema_type = schema.schema_type.as_deref();
+        if schema_type == Some("file") || (schema_type == Some("string") && schema.format.as_deref() == Some("binary")) {
+            return true;
+        }
+    }
+    let produces = produces(swagger, operation);
+    produces.iter().any(|media_type| media_type == "application/octet-stream")
+        && !produces.iter().any(|media_type| media_type.contains("json"))
+}
+
+/// Whether any operation of the document responds with a file.
+pub fn has_binary_responses(swagger: &Swagger) -> bool {
+    swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(_, operation)| has_binary_response(swagger, operation))
+}
+
+/// The name of an operation: the one settled for it before generation, else its operationId, or the
+/// name derived from its method and path when it is missing.
+pub fn operation_name(method: &str, path: &str, operation: &Operation) -> String {
+    let name = operation.name.as_ref().or(operation.operation_id.as_ref());
+    name.cloned().unwrap_or_else(|| path_operation_name(method, path))
+}
+
+/// The method followed by the static segments of the path and then its parameters, in camelCase:
+/// `getPetsPhotosByPetIdAndPhotoId` for `GET /pets/{petId}/photos/{photoId}`.
+pub fn path_operation_name(method: &str, path: &str) -> String {
+    let mut segments = vec![method.to_string()];
+    segments.extend(path.split('/').filter(|s| !s.is_empty() && !s.starts_with('{')).map(str::to_string));
+    let params = extract_path_params(path);
+    if !params.is_empty() {
+        segments.push(format!("by_{}", params.join("_and_")));
+    }
+    let name = pascal_case(&segments.join("_"));
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// The definition the first documented 2xx response of an operation refers to.
+pub fn response_reference(operation: &Operation) -> Option<&str> {
+    success_responses(operation)
+        .into_iter()
+        .find_map(|(_, response)| response.response_schema.as_ref().and_then(|s| s.reference.as_deref()))
+}
+
+/// The items of the first 2xx response that is an array, which the clients return as a typed list.
+pub fn response_items(operation: &Operation) -> Option<&Schema> {
+    success_responses(operation).into_iter().find_map(|(_, response)| {
+        let schema = response.response_schema.as_ref()?;
+        match schema.schema_type.as_deref() {
+            Some("array") => schema.items.as_deref(),
+            _ => None,
+        }
+    })
+}
+
+/// The model a schema refers to, through any nested arrays.
+pub fn item_reference(items: &Schema) -> Option<&str> {
+    match &items.items {
+        Some(nested) if items.schema_type.as_deref() == Some("array") => item_reference(nested),
+        _ => items.reference.as_deref(),
+    }
+}
+
+/// The 2xx responses of an operation, in status order.
+pub fn success_responses(operation: &Operation) -> Vec<(&str, &Response)> {
+    let mut responses: Vec<(&str, &Response)> = operation
+        .responses
+        .iter()
+        .filter(|(status, _)| status.starts_with('2'))
+        .map(|(status, response)| (status.as_str(), response))
+        .collect();
+    responses.sort_by_key(|(status, _)| *status);
+    responses
+}
+
+/// The 4xx, 5xx and `default` responses of an operation that document a body, in status order
+/// with `default` last.
+pub fn error_responses(operation: &Operation) -> Vec<(&str, &Response)> {
+    let mut responses: Vec<(&str, &Response)> = operation
+        .responses
+        .iter()
+        .filter(|(status, response)| {
+            let is_error = status.starts_with('4') || status.starts_with('5') || *status == "default";
+            is_error && response.response_schema.is_some()
+        })
+        .map(|(status, response)| (status.as_str(), response))
+        .collect();
+    responses.sort_by_key(|(status, _)| (*status == "default", *status));
+    responses
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaginationStyle {
+    Page,
+    Offset,
+    Cursor,
+}
+
+/// How a `GET` operation walks through its results: the query parameter advancing it, the one
+/// limiting the page size, the path of the item array in a response (empty when the response
+/// is the array) and, for cursors, the path of the next cursor.
+#[derive(Debug)]
+pub struct Pagination {
+    pub style: PaginationStyle,
+    pub param: String,
+    pub limit: Option<String>,
+    pub items: Vec<String>,
+    pub cursor: Vec<String>,
+}
+
+const ITEM_FIELDS: &[&str] = &["items", "data", "results", "content", "records"];
+const CURSOR_FIELDS: &[&str] = &["nextCursor", "next_cursor", "cursor"];
+
+/// The pagination of an operation, given by its `x-pagination` extension or detected from a
+/// `page`, `offset` or `cursor` query parameter, when its response has an item array to walk.
+pub fn pagination(swagger: &Swagger, method: &str, operation: &Operation) -> Option<Pagination> {
+    if method != "get" {
+        return None;
+    }
+    let query: Vec<&str> = operation
+        .parameters
+        .iter()
+        .filter(|param| param.location == "query")
+        .map(|param| param.name.as_str())
+        .collect();
+    let extension = operation.pagination.as_ref();
+    if extension == Some(&Value::Bool(false)) {
+        return None;
+    }
+    let setting = |key: &str| extension.and_then(|extension| extension.get(key)).and_then(Value::as_str);
+    let field_path = |path: &str| path.split('.').map(str::to_string).collect::<Vec<String>>();
+
+    let style = match setting("style") {
+        Some("page") => PaginationStyle::Page,
+        Some("offset") => PaginationStyle::Offset,
+        Some("cursor") => PaginationStyle::Cursor,
+        _ if query.contains(&"cursor") => PaginationStyle::Cursor,
+        _ if query.contains(&"offset") => PaginationStyle::Offset,
+        _ if query.contains(&"page") => PaginationStyle::Page,
+        _ => return None,
+    };
+    let param = setting("param").unwrap_or(match style {
+        PaginationStyle::Page => "page",
+        PaginationStyle::Offset => "offset",
+        PaginationStyle::Cursor => "cursor",
+    });
+    if !query.contains(&param) {
+        return None;
+    }
+    let limit = setting("limit").or(query.contains(&"limit").then_some("limit"));
+
+    let fields = response_fields(swagger, operation)?;
+    let items = match setting("items") {
+        Some(path) => field_path(path),
+        None => match fields {
+            ResponseFields::Array => Vec::new(),
+            ResponseFields::Object(ref fields) => {
+                let arrays: Vec<&String> = fields.iter().filter(|(_, is_array)| *is_array).map(|(name, _)| name).collect();
+                let name = match arrays[..] {
+                    [only] => only,
+                    _ => *arrays.iter().find(|name| ITEM_FIELDS.contains(&name.as_str()))?,
+                };
+                vec![name.clone()]
+            }
+        },
+    };
+    let cursor = match (style, setting("cursor"), &fields) {
+        (PaginationStyle::Cursor, Some(path), _) => field_path(path),
+        (PaginationStyle::Cursor, None, ResponseFields::Object(fields)) => {
+            let name = CURSOR_FIELDS.iter().find(|name| fields.iter().any(|(field, _)| field == *name))?;
+            vec![name.to_string()]
+        }
+        (PaginationStyle::Cursor, None, ResponseFields::Array) => return None,
+        _ => Vec::new(),
+    };
+
+    Some(Pagination {
+        style,
+        param: param.to_string(),
+        limit: limit.map(str::to_string),
+        items,
+        cursor,
+    })
+}
+
+enum ResponseFields {
+    Array,
+    Object(Vec<(String, bool)>),
+}
+
+/// Whether the first documented success response is an array, or else its fields and whether
+/// each one is an array.
+fn response_fields(swagger: &Swagger, operation: &Operation) -> Option<ResponseFields> {
+    let schema = success_responses(operation).into_iter().find_map(|(_, response)| response.response_schema.as_ref())?;
+    if schema.schema_type.as_deref() == Some("array") {
+        return Some(ResponseFields::Array);
+    }
+    let mut fields: Vec<(String, bool)> = match &schema.reference {
+        Some(reference) => {
+            let definition = swagger.definitions.get(ref_name(reference))?;
+            if definition.definition_type.as_deref() == Some("array") {
+                return Some(ResponseFields::Array);
+            }
+            let properties = definition.properties.as_ref()?;
+            properties
+                .iter()
+                .map(|(name, prop)| (name.clone(), prop.property_type.as_deref() == Some("array")))
+                .collect()
+        }
+        None => schema
+            .properties
+            .as_ref()?
+            .iter()
+            .map(|(name, prop)| (name.clone(), prop.schema_type.as_deref() == Some("array")))
+            .collect(),
+    };
+    fields.sort();
+    Some(ResponseFields::Object(fields))
+}
+
+/// Whether definition `from` contains `to` by value, directly or through other definitions,
+/// following `$ref` properties, including those its `allOf` parts bring in, but not arrays or
+/// maps. Languages storing such a field inline need indirection (a box or a class) to break the cycle.
+pub fn contains_by_value(swagger: &Swagger, from: &str, to: &str) -> bool {
+    let mut pending = vec![from];
+    let mut visited = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !visited.insert(name) {
+            continue;
+        }
+        let Some((name, definition)) = swagger.definitions.get_key_value(name) else {
+            continue;
+        };
+        for (_, prop, _) in flattened_fields(swagger, name, definition) {
+            if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+                let target = ref_name(reference);
+                if target == to {
+                    return true;
+                }
+                pending.push(target);
+            }
+        }
+    }
+    false
+}
+
+/// Properties a class declares itself, from its definition and the inline parts of its `allOf`,
+/// with whether each is required.
+pub fn own_fields(definition: &Definition) -> Vec<(&String, &Property, bool)> {
+    let mut fields = Vec::new();
+    for (prop_name, prop) in definition.properties.iter().flatten() {
+        let required = definition.required.as_ref().is_some_and(|required| required.contains(prop_name));
+        fields.push((prop_name, prop, required));
+    }
+    for part in definition.all_of.iter().flatten().filter(|part| part.reference.is_none()) {
+        fields.extend(own_fields(part));
+    }
+    fields
+}
+
+/// Fields a class inherits from the bases of its `allOf`, in the order dataclasses declare them.
+pub fn inherited_fields<'a>(
+    swagger: &'a Swagger,
+    definition: &'a Definition,
+    seen: &mut Vec<&'a str>,
+) -> Vec<(&'a String, &'a Property, bool)> {
+    let mut fields = Vec::new();
+    for base in base_interfaces(definition) {
+        let Some(base_definition) = swagger.definitions.get(base) else { continue };
+        if seen.contains(&base) {
+            continue;
+        }
+        seen.push(base);
+        fields.extend(inherited_fields(swagger, base_definition, seen));
+        fields.extend(own_fields(base_definition));
+    }
+    fields
+}
+
+/// Fields of a model with the bases of its `allOf` flattened in, theirs first, for languages whose
+/// models do not inherit. A field declared again replaces the earlier one in its place.
+pub fn flattened_fields<'a>(
+    swagger: &'a Swagger,
+    name: &'a str,
+    definition: &'a Definition,
+) -> Vec<(&'a String, &'a Property, bool)> {
+    let mut fields: Vec<(&String, &Property, bool)> = Vec::new();
+    let mut inherited = inherited_fields(swagger, definition, &mut vec![name]);
+    inherited.extend(own_fields(definition));
+    for field in inherited {
+        match fields.iter_mut().find(|(prop_name, _, _)| *prop_name == field.0) {
+            Some(existing) => *existing = field,
+            None => fields.push(field),
+        }
+    }
+    fields
+}
+
+/// Members of an enum with `values`, each named by `name` from the words of its value: a leading
+/// minus sign becomes `neg`, a value without words gets `value`, and a name already taken gets a
+/// number appended, e.g. `AB`, `AB2`. Values other than strings and numbers get no member.
+pub fn enum_members(values: &[Value], name: impl Fn(&[String]) -> String) -> Vec<(String, &Value)> {
+    let mut members: Vec<(String, &Value)> = Vec::new();
+    for value in values {
+        let label = match value {
+            Value::String(text) => text.clone(),
+            Value::Number(number) => number.to_string(),
+            _ => continue,
+        };
+        let mut value_words = words(&label);
+        if label.starts_with('-') && label[1..].starts_with(|c: char| c.is_ascii_digit()) {
+            value_words.insert(0, "neg".to_string());
+        }
+        if value_words.is_empty() {
+            value_words.push("value".to_string());
+        }
+        let member = name(&value_words);
+        let mut unique = member.clone();
+        let mut suffix = 2;
+        while members.iter().any(|(taken, _)| *taken == unique) {
+            unique = format!("{}{}", member, suffix);
+            suffix += 1;
+        }
+        members.push((unique, value));
+    }
+    members
+}
+
+/// Whether the generated clients send a request body for the HTTP `method`.
+/// The delimiter the values of an array parameter are joined with by its `collectionFormat`, or `None`
+/// when it is not an array or is repeated for each value: a `multi` query, or a form field, which the
+/// form encoders of the clients repeat.
+pub fn collection_delimiter(param: &Parameter) -> Option<&'static str> {
+    match param.collection_format.as_deref() {
+        _ if param.parameter_type.as_deref() != Some("array") || param.location == "formData" => None,
+        Some("multi") if param.location == "query" => None,
+        Some("ssv") => Some(" "),
+        Some("tsv") => Some("\t"),
+        Some("pipes") => Some("|"),
+        _ => Some(","),
+    }
+}
+
+pub fn has_request_body(method: &str) -> bool {
+    !matches!(method, "get" | "delete" | "head" | "options")
+}
+
+pub fn extract_path_params(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|segment| segment.starts_with('{') && segment.ends_with('}'))
+        .map(|segment| segment[1..segment.len() - 1].to_string())
+        .collect()
+}
+
+/// Splits an identifier such as `findPetsByStatus`, `pet_id` or `X-Rate-Limit` into lowercase words.
+pub fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && previous_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+pub fn snake_case(name: &str) -> String {
+    words(name).join("_")
+}
+
+pub fn pascal_case(name: &str) -> String {
+    words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(c) => c.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub type TaggedOperation<'a> = (&'a str, &'static str, &'a Operation);
+
+/// Operations grouped by their first tag, with untagged operations under `default`.
+pub fn operations_by_tag(swagger: &Swagger) -> BTreeMap<String, Vec<TaggedOperation<'_>>> {
+    let mut groups: BTreeMap<String, Vec<TaggedOperation<'_>>> = BTreeMap::new();
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let tag = operation.tags.first().map_or("default", String::as_str);
+            groups.entry(tag.to_string()).or_default().push((path, method, operation));
+        }
+    }
+    groups
+}
@@ -0,0 +1,135 @@
+use super::mock::{express_path, success_reply, to_js, Examples, Reply};
+use super::typescript::{
+    generate_info_comment, import_interfaces, non_json_response, request_body_reference, schema_definition, schema_type,
+};
+use super::{base_url, operation_name, success_responses, with_custom_region};
+use crate::spec::{Operation, Schema, Swagger};
+use serde_json::Value;
+
+/// `handlers.ts`: a Mock Service Worker handler for every operation, typed with the generated
+/// interfaces it imports from `interfaces` and answering with the example of its first success
+/// response, as the mock server does.
+pub fn generate_handlers(swagger: &Swagger, interfaces: &str) -> String {
+    let examples = Examples::new(swagger);
+    let mut handlers = String::new();
+    let mut names = Vec::new();
+    let mut untyped_body = false;
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let handler = Handler { swagger, examples: &examples, method, path, operation };
+            names.extend(handler.references());
+            untyped_body |= handler.body_type() == "DefaultBodyType";
+            handlers.push_str(&handler.generate());
+        }
+    }
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { http, HttpResponse } from 'msw';\n");
+    if untyped_body {
+        ts_code.push_str("import type { DefaultBodyType } from 'msw';\n");
+    }
+    ts_code.push('\n');
+    import_interfaces(&mut ts_code, names, interfaces);
+    ts_code.push_str("/** The URL the handlers expect requests under, unless given another. */\n");
+    ts_code.push_str(&format!("export const BASE_URL = '{}';\n\n", base_url(swagger)));
+    ts_code.push_str("/** Handlers answering every operation under `baseUrl` with an example response. */\n");
+    ts_code.push_str("export function createHandlers(baseUrl: string = BASE_URL) {\n");
+    ts_code.push_str("    return [\n");
+    ts_code.push_str(&handlers);
+    ts_code.push_str("    ];\n");
+    ts_code.push_str("}\n\n");
+    ts_code.push_str("export const handlers = createHandlers();\n");
+    with_custom_region(ts_code, "//")
+}
+
+struct Handler<'a> {
+    swagger: &'a Swagger,
+    examples: &'a Examples,
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+}
+
+impl Handler<'_> {
+    /// The definitions the types of the handler name.
+    fn references(&self) -> Vec<String> {
+        let response = self.response_schema().and_then(schema_definition);
+        response.into_iter().chain(request_body_reference(self.operation)).map(str::to_string).collect()
+    }
+
+    fn response_schema(&self) -> Option<&Schema> {
+        success_responses(self.operation).into_iter().next().and_then(|(_, response)| response.response_schema.as_ref())
+    }
+
+    /// The type of the parameters MSW reads from the path, named as in the `:parameter` form of
+    /// the path it matches.
+    fn params_type(&self) -> String {
+        let path = express_path(self.path);
+        let params: Vec<String> = path
+            .split('/')
+            .filter_map(|segment| segment.strip_prefix(':'))
+            .map(|name| format!("{}: string", name))
+            .collect();
+        if params.is_empty() {
+            "never".to_string()
+        } else {
+            format!("{{ {} }}", params.join("; "))
+        }
+    }
+
+    fn body_type(&self) -> &str {
+        match request_body_reference(self.operation) {
+            Some(reference) => reference,
+            None if self.operation.parameters.iter().any(|param| param.location == "body") => "DefaultBodyType",
+            None => "never",
+        }
+    }
+
+    fn generate(&self) -> String {
+        let (status, reply) = success_reply(self.swagger, self.examples, self.operation);
+        let mut generics = vec![self.params_type(), self.body_type().to_string()];
+        let response = match (reply, non_json_response(self.swagger, self.operation)) {
+            (Reply::Empty, _) => format!("new HttpResponse(null, {{ status: {} }})", status),
+            (Reply::Binary, _) => {
+                let init =
+                    format!("{{ status: {}, headers: {{ 'Content-Type': 'application/octet-stream' }} }}", status);
+                format!("HttpResponse.arrayBuffer(new ArrayBuffer(0), {})", init)
+            }
+            (Reply::Json(example), Some("text")) => {
+                let text = match example {
+                    Value::String(text) => text,
+                    example => example.to_string(),
+                };
+                format!("HttpResponse.text({}, {{ status: {} }})", to_js(&Value::String(text)), status)
+            }
+            (Reply::Json(example), _) => {
+                let response_type = self.response_schema().map_or_else(|| "unknown".to_string(), schema_type);
+                let example = to_js(&without_nulls(example)).replace('\n', "\n            ");
+                generics.push(response_type.clone());
+                format!("HttpResponse.json<{}>({}, {{ status: {} }})", response_type, example, status)
+            }
+        };
+
+        let name = operation_name(self.method, self.path, self.operation);
+        let url = format!("`${{baseUrl}}{}`", express_path(self.path));
+        let mut ts_code = format!("        // {}: {} {}\n", name, self.method.to_uppercase(), self.path);
+        ts_code.push_str(&format!("        http.{}<{}>({}, () =>\n", self.method, generics.join(", "), url));
+        ts_code.push_str(&format!("            {},\n", response));
+        ts_code.push_str("        ),\n");
+        ts_code
+    }
+}
+
+/// `value` without the members of its objects that are `null`, which the examples leave where a
+/// definition refers to itself and which the optional fields of the interfaces do not accept.
+fn without_nulls(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let members = object.into_iter().filter(|(_, value)| !value.is_null());
+            Value::Object(members.map(|(name, value)| (name, without_nulls(value))).collect())
+        }
+        Value::Array(items) => Value::Array(items.into_iter().map(without_nulls).collect()),
+        value => value,
+    }
+}
@@ -0,0 +1,527 @@
+use super::typescript::{
+    all_properties, generate_info_comment, generate_interfaces, import_interfaces, item_reference, non_json_response,
+    operation_doc, parameter_type, property_enum, property_name, property_type, push_doc, schema_definition,
+    schema_type, service_method_name, string_literal, TypeOptions,
+};
+use super::{collection_delimiter, operations_by_tag, pascal_case, success_responses, with_custom_region, words};
+use crate::cli::{DateType, Int64Type};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Swagger};
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+/// Writes the interfaces, `dto.ts` with a class-validator DTO per object definition and per
+/// query string, a controller per tag delegating to an abstract service for the server to
+/// implement, and `api.module.ts` registering the controllers with the services given to it.
+pub fn generate(swagger: &Swagger, types: TypeOptions) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    generate_interfaces(swagger, TypeOptions { validators: None, ..types }, &mut files);
+    // The DTOs hold the JSON of the request, where dates stay strings and int64s are numbers.
+    let types = TypeOptions { date_type: DateType::String, int64_type: Int64Type::Number, validators: None, ..types };
+    let dtos = dto_definitions(swagger);
+    let groups: Vec<(String, Vec<Route>)> = operations_by_tag(swagger)
+        .into_iter()
+        .map(|(tag, operations)| {
+            let routes = operations
+                .into_iter()
+                .map(|(path, method, operation)| Route {
+                    method,
+                    path,
+                    operation,
+                    name: service_method_name(method, path, operation),
+                    content: non_json_response(swagger, operation),
+                })
+                .collect();
+            (tag, routes)
+        })
+        .collect();
+
+    files.insert(PathBuf::from("dto.ts"), with_custom_region(generate_dtos(swagger, types, &dtos, &groups), "//"));
+    for (tag, routes) in &groups {
+        let controller = generate_controller(swagger, &dtos, tag, routes);
+        files.insert(PathBuf::from("controllers").join(controller_file(tag)), with_custom_region(controller, "//"));
+    }
+    files.insert(PathBuf::from("api.module.ts"), generate_module(swagger, &groups));
+    files
+}
+
+struct Route<'a> {
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+    name: String,
+    content: Option<&'static str>,
+}
+
+impl Route<'_> {
+    fn query_class(&self) -> Option<String> {
+        let has_query = self.operation.parameters.iter().any(|param| param.location == "query");
+        has_query.then(|| format!("{}Query", pascal_case(&self.name)))
+    }
+
+    fn body(&self) -> Option<&Parameter> {
+        self.operation.parameters.iter().find(|param| param.location == "body")
+    }
+
+    /// The path of the route as Nest writes it, relative to the `basePath` of the controller.
+    fn nest_path(&self) -> String {
+        super::mock::express_path(self.path).trim_start_matches('/').to_string()
+    }
+
+    /// The type the method resolves to: the body of the first success response.
+    fn return_type(&self) -> String {
+        match (self.content, success_responses(self.operation).first()) {
+            (Some("blob"), _) => "StreamableFile".to_string(),
+            (Some(_), _) => "string".to_string(),
+            (None, Some((_, response))) => response.response_schema.as_ref().map_or("void".to_string(), schema_type),
+            (None, None) => "void".to_string(),
+        }
+    }
+
+    /// The status Nest answers with, when it is not its default of 201 for POST and 200 otherwise.
+    fn http_code(&self) -> Option<u16> {
+        let status = success_responses(self.operation).first()?.0.parse::<u16>().ok()?;
+        let default = if self.method == "post" { 201 } else { 200 };
+        (status != default).then_some(status)
+    }
+
+    /// The arguments of the method with their Nest decorators, and those of the service call.
+    fn arguments(&self, dtos: &[&str]) -> Vec<(String, String)> {
+        let mut arguments = Vec::new();
+        for param in self.operation.parameters.iter().filter(|param| param.location == "path") {
+            let name: String = param.name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+            let pipe = match param.parameter_type.as_deref() {
+                Some("integer") => ", ParseIntPipe",
+                Some("number") => ", ParseFloatPipe",
+                Some("boolean") => ", ParseBoolPipe",
+                _ => "",
+            };
+            let argument = argument_name(&param.name);
+            let decorated = format!("@Param('{}'{}) {}: {}", name, pipe, argument, parameter_type(param));
+            arguments.push((decorated, argument));
+        }
+        if let Some(query) = self.query_class() {
+            arguments.push((format!("@Query() query: {}", query), "query".to_string()));
+        }
+        if let Some(body) = self.body() {
+            let schema = body.schema.as_ref();
+            let definition = schema.and_then(schema_definition).filter(|name| dtos.contains(name));
+            let array = schema.is_some_and(|schema| schema.reference.is_none());
+            let decorated = match (definition, array) {
+                (Some(name), false) => format!("@Body() body: {}Dto", name),
+                (Some(name), true) => {
+                    let optional = if body.required { "" } else { ", optional: true" };
+                    format!("@Body(new ParseArrayPipe({{ items: {}Dto{} }})) body: {}Dto[]", name, optional, name)
+                }
+                (None, _) => format!("@Body() body: {}", schema.map_or("unknown".to_string(), schema_type)),
+            };
+            arguments.push((decorated, "body".to_string()));
+        }
+        arguments
+    }
+}
+
+/// `name` as a camelCase argument.
+fn argument_name(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_digit() => format!("_{}", pascal),
+        Some(c) => c.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    }
+}
+
+fn controller_file(tag: &str) -> String {
+    format!("{}.controller.ts", words(tag).join("-"))
+}
+
+/// The definitions that become DTO classes, which are those describing objects, each after the
+/// definitions it refers to: the metadata of a property names its class as soon as the class
+/// holding it is declared.
+fn dto_definitions(swagger: &Swagger) -> Vec<&str> {
+    fn visit<'a>(swagger: &'a Swagger, name: &'a str, ordered: &mut Vec<&'a str>, seen: &mut Vec<&'a str>) {
+        if seen.contains(&name) {
+            return;
+        }
+        seen.push(name);
+        let Some(definition) = swagger.definitions.get(name).filter(|definition| is_object(definition)) else {
+            return;
+        };
+        for (_, prop, _) in all_properties(swagger, definition, &mut Vec::new()) {
+            if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+                visit(swagger, ref_name(reference), ordered, seen);
+            }
+        }
+        ordered.push(name);
+    }
+
+    let mut ordered = Vec::new();
+    let mut seen = Vec::new();
+    for name in swagger.definitions.keys() {
+        visit(swagger, name, &mut ordered, &mut seen);
+    }
+    ordered
+}
+
+fn is_object(definition: &Definition) -> bool {
+    definition.enum_values.is_none()
+        && definition.one_of.is_none()
+        && definition.any_of.is_none()
+        && (definition.properties.is_some() || definition.all_of.is_some())
+}
+
+/// The decorators and the type of a DTO field, with the names they import.
+struct Field {
+    decorators: Vec<String>,
+    ts_type: String,
+}
+
+impl Field {
+    fn new(ts_type: String) -> Field {
+        Field { decorators: Vec::new(), ts_type }
+    }
+
+    fn with(mut self, decorator: impl Into<String>) -> Field {
+        self.decorators.push(decorator.into());
+        self
+    }
+
+    fn push(&self, ts_code: &mut String, name: &str, required: bool, imports: &mut BTreeSet<String>) {
+        let mut decorators = self.decorators.clone();
+        if !required {
+            decorators.insert(0, "IsOptional()".to_string());
+        }
+        for decorator in &decorators {
+            imports.insert(decorator[..decorator.find('(').unwrap_or(decorator.len())].to_string());
+            ts_code.push_str(&format!("    @{}\n", decorator));
+        }
+        let marker = if required { "!" } else { "?" };
+        ts_code.push_str(&format!("    {}{}: {};\n", property_name(name), marker, self.ts_type));
+    }
+}
+
+/// The field validating a property of a definition.
+fn property_field(prop: &Property, dtos: &[&str], types: TypeOptions, interfaces: &mut Vec<String>) -> Field {
+    if let Some((values, each)) = property_enum(prop) {
+        let each = if each { ", { each: true }" } else { "" };
+        return Field::new(property_type(prop, types)).with(format!("IsIn({}{})", enum_list(values), each));
+    }
+    if let Some(name) = prop.additional.get("$ref").and_then(Value::as_str).map(ref_name) {
+        if dtos.contains(&name) {
+            return Field::new(format!("{}Dto", name))
+                .with("ValidateNested()")
+                .with(format!("Type(() => {}Dto)", name));
+        }
+        interfaces.push(name.to_string());
+        return Field::new(name.to_string()).with("Allow()");
+    }
+    if let Some(name) = item_reference(prop) {
+        if dtos.contains(&name) {
+            return Field::new(format!("{}Dto[]", name))
+                .with("IsArray()")
+                .with("ValidateNested({ each: true })")
+                .with(format!("Type(() => {}Dto)", name));
+        }
+        interfaces.push(name.to_string());
+        return Field::new(format!("{}[]", name)).with("IsArray()");
+    }
+    let field = Field::new(property_type(prop, types));
+    match prop.property_type.as_deref() {
+        Some("array") => {
+            let items = prop.additional.get("items");
+            let item_type = items.and_then(|items| items.get("type")).and_then(Value::as_str);
+            let item_format = items.and_then(|items| items.get("format")).and_then(Value::as_str);
+            let field = field.with("IsArray()");
+            match type_check(item_type, item_format, true) {
+                Some(check) => field.with(check),
+                None => field,
+            }
+        }
+        Some("object") => field.with("IsObject()"),
+        property_type => match type_check(property_type, prop.format.as_deref(), false) {
+            Some(check) => field.with(check),
+            None => field.with("Allow()"),
+        },
+    }
+}
+
+/// The class-validator check of a value of `schema_type`, or of each of the items of an array.
+fn type_check(schema_type: Option<&str>, format: Option<&str>, each: bool) -> Option<String> {
+    let (options, validation) = if each { ("{ each: true }", "{}, { each: true }") } else { ("", "") };
+    match (schema_type?, format) {
+        ("integer", _) => Some(format!("IsInt({})", options)),
+        ("number", _) => Some(format!("IsNumber({})", validation)),
+        ("boolean", _) => Some(format!("IsBoolean({})", options)),
+        ("string", Some("date" | "date-time")) => Some(format!("IsDateString({})", validation)),
+        ("string", _) => Some(format!("IsString({})", options)),
+        _ => None,
+    }
+}
+
+fn enum_list(values: &[Value]) -> String {
+    let values: Vec<String> = values
+        .iter()
+        .map(|value| match value {
+            Value::String(value) => string_literal(value),
+            value => value.to_string(),
+        })
+        .collect();
+    format!("[{}]", values.join(", "))
+}
+
+/// The field converting a query parameter from the string it arrives as and validating it.
+fn query_field(param: &Parameter) -> Field {
+    let field = Field::new(parameter_type(param));
+    let each = param.parameter_type.as_deref() == Some("array");
+    let item_type = if each {
+        param.items.as_ref().and_then(|items| items.schema_type.as_deref())
+    } else {
+        param.parameter_type.as_deref()
+    };
+    let field = if each {
+        // Repeated parameters arrive as an array, the ones their `collectionFormat` joins as text.
+        let items = match collection_delimiter(param) {
+            Some(delimiter) => format!(
+                "(Array.isArray(value) ? value : String(value).split({}))",
+                string_literal(&delimiter.escape_default().to_string())
+            ),
+            None => "(Array.isArray(value) ? value : [value])".to_string(),
+        };
+        let items = match conversion(item_type, "item") {
+            Some(conversion) => format!("{}.map((item) => {})", items, conversion),
+            None => items.to_string(),
+        };
+        field.with(format!("Transform(({{ value }}) => (value === undefined ? value : {}))", items)).with("IsArray()")
+    } else {
+        match item_type {
+            Some("integer" | "number") => field.with("Type(() => Number)"),
+            _ => match conversion(item_type, "value") {
+                Some(conversion) => field.with(format!("Transform(({{ value }}) => ({}))", conversion)),
+                None => field,
+            },
+        }
+    };
+    let values = param.enum_values.as_ref().or(param.items.as_ref().and_then(|items| items.enum_values.as_ref()));
+    match (values, type_check(item_type, None, each)) {
+        (Some(values), _) => {
+            field.with(format!("IsIn({}{})", enum_list(values), if each { ", { each: true }" } else { "" }))
+        }
+        (None, Some(check)) => field.with(check),
+        (None, None) => field.with("Allow()"),
+    }
+}
+
+/// The expression converting `text`, a query parameter of `schema_type`, leaving what does not
+/// convert for the validators to reject.
+fn conversion(schema_type: Option<&str>, text: &str) -> Option<String> {
+    match schema_type {
+        Some("integer" | "number") => Some(format!("({} === '' ? {} : Number({}))", text, text, text)),
+        Some("boolean") => Some(format!("{} === 'true' ? true : {} === 'false' ? false : {}", text, text, text)),
+        _ => None,
+    }
+}
+
+fn generate_dtos(swagger: &Swagger, types: TypeOptions, dtos: &[&str], groups: &[(String, Vec<Route>)]) -> String {
+    let mut classes = String::new();
+    let mut imports = BTreeSet::new();
+    let mut interfaces = Vec::new();
+    for name in dtos {
+        let definition = &swagger.definitions[*name];
+        classes.push('\n');
+        classes.push_str(&format!("export class {}Dto {{\n", name));
+        for (index, (prop_name, prop, required)) in
+            all_properties(swagger, definition, &mut Vec::new()).into_iter().enumerate()
+        {
+            if index > 0 {
+                classes.push('\n');
+            }
+            property_field(prop, dtos, types, &mut interfaces).push(&mut classes, prop_name, required, &mut imports);
+        }
+        classes.push_str("}\n");
+    }
+    for route in groups.iter().flat_map(|(_, routes)| routes) {
+        let Some(query) = route.query_class() else {
+            continue;
+        };
+        classes.push_str(&format!("\n/** The query string of `{}`. */\n", route.name));
+        classes.push_str(&format!("export class {} {{\n", query));
+        let params = route.operation.parameters.iter().filter(|param| param.location == "query");
+        for (index, param) in params.enumerate() {
+            if index > 0 {
+                classes.push('\n');
+            }
+            query_field(param).push(&mut classes, &param.name, param.required, &mut imports);
+        }
+        classes.push_str("}\n");
+    }
+
+    let (transformer, validator): (Vec<String>, Vec<String>) =
+        imports.into_iter().partition(|name| name == "Type" || name == "Transform");
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    if !transformer.is_empty() {
+        ts_code.push_str(&format!("import {{ {} }} from 'class-transformer';\n", transformer.join(", ")));
+    }
+    if !validator.is_empty() {
+        ts_code.push_str(&format!("import {{ {} }} from 'class-validator';\n", validator.join(", ")));
+    }
+    if !transformer.is_empty() || !validator.is_empty() {
+        ts_code.push('\n');
+    }
+    import_interfaces(&mut ts_code, interfaces, "./interfaces");
+    ts_code.push_str(classes.trim_start_matches('\n'));
+    ts_code
+}
+
+fn generate_controller(swagger: &Swagger, dtos: &[&str], tag: &str, routes: &[Route]) -> String {
+    let service = format!("{}Service", pascal_case(tag));
+    let mut common = BTreeSet::from(["Controller".to_string()]);
+    let mut dto_imports = Vec::new();
+    let mut interfaces = Vec::new();
+    let mut abstract_methods = String::new();
+    let mut methods = String::new();
+    for (index, route) in routes.iter().enumerate() {
+        let arguments = route.arguments(dtos);
+        let return_type = route.return_type();
+        for (decorated, _) in &arguments {
+            let decorator = &decorated[1..decorated.find('(').unwrap_or(1)];
+            common.insert(decorator.to_string());
+            for pipe in ["ParseIntPipe", "ParseFloatPipe", "ParseBoolPipe", "ParseArrayPipe"] {
+                if decorated.contains(pipe) {
+                    common.insert(pipe.to_string());
+                }
+            }
+        }
+        dto_imports.extend(route.query_class());
+        if let Some(name) = route.body().and_then(|body| body.schema.as_ref()).and_then(schema_definition) {
+            if dtos.contains(&name) {
+                dto_imports.push(format!("{}Dto", name));
+            } else {
+                interfaces.push(name.to_string());
+            }
+        }
+        if route.content.is_none() {
+            let success = success_responses(route.operation).into_iter().next();
+            let schema = success.and_then(|(_, response)| response.response_schema.as_ref());
+            interfaces.extend(schema.and_then(schema_definition).map(str::to_string));
+        }
+        if route.content == Some("blob") {
+            common.insert("StreamableFile".to_string());
+        }
+
+        // The service takes the values the controller reads, without their decorators.
+        let parameters: Vec<String> = arguments
+            .iter()
+            .map(|(decorated, _)| decorated.rsplit_once(") ").map_or(decorated.as_str(), |(_, rest)| rest).to_string())
+            .collect();
+        if index > 0 {
+            abstract_methods.push('\n');
+            methods.push('\n');
+        }
+        push_doc(&mut abstract_methods, &operation_doc(route.operation), "    ");
+        abstract_methods.push_str(&format!(
+            "    abstract {}({}): Promise<{}>;\n",
+            route.name,
+            parameters.join(", "),
+            return_type
+        ));
+
+        let method = pascal_case(route.method);
+        common.insert(method.clone());
+        methods.push_str(&format!("    @{}({})\n", method, string_literal(&route.nest_path())));
+        if let Some(status) = route.http_code() {
+            common.insert("HttpCode".to_string());
+            methods.push_str(&format!("    @HttpCode({})\n", status));
+        }
+        if route.content == Some("text") {
+            common.insert("Header".to_string());
+            methods.push_str("    @Header('Content-Type', 'text/plain')\n");
+        }
+        let decorated: Vec<&str> = arguments.iter().map(|(decorated, _)| decorated.as_str()).collect();
+        let values: Vec<&str> = arguments.iter().map(|(_, value)| value.as_str()).collect();
+        methods.push_str(&format!("    {}({}): Promise<{}> {{\n", route.name, decorated.join(", "), return_type));
+        methods.push_str(&format!("        return this.service.{}({});\n", route.name, values.join(", ")));
+        methods.push_str("    }\n");
+    }
+    dto_imports.sort();
+    dto_imports.dedup();
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    let common: Vec<String> = common.into_iter().collect();
+    ts_code.push_str(&format!("import {{ {} }} from '@nestjs/common';\n", common.join(", ")));
+    if !dto_imports.is_empty() {
+        ts_code.push_str(&format!("import {{ {} }} from '../dto';\n", dto_imports.join(", ")));
+    }
+    import_interfaces(&mut ts_code, interfaces, "../interfaces");
+    if !ts_code.ends_with("\n\n") {
+        ts_code.push('\n');
+    }
+    ts_code.push_str(&format!(
+        "/** The operations tagged `{}`, answering the requests `{}Controller` validated. */\n",
+        tag,
+        pascal_case(tag)
+    ));
+    ts_code.push_str(&format!("export abstract class {} {{\n", service));
+    ts_code.push_str(&abstract_methods);
+    ts_code.push_str("}\n\n");
+    let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_matches('/');
+    if base_path.is_empty() {
+        ts_code.push_str("@Controller()\n");
+    } else {
+        ts_code.push_str(&format!("@Controller({})\n", string_literal(base_path)));
+    }
+    ts_code.push_str(&format!("export class {}Controller {{\n", pascal_case(tag)));
+    ts_code.push_str(&format!("    constructor(private readonly service: {}) {{}}\n\n", service));
+    ts_code.push_str(&methods);
+    ts_code.push_str("}\n");
+    ts_code
+}
+
+fn generate_module(swagger: &Swagger, groups: &[(String, Vec<Route>)]) -> String {
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { DynamicModule, Module, Type } from '@nestjs/common';\n");
+    for (tag, _) in groups {
+        let name = pascal_case(tag);
+        let file = controller_file(tag);
+        ts_code.push_str(&format!(
+            "import {{ {}Controller, {}Service }} from './controllers/{}';\n",
+            name,
+            name,
+            file.trim_end_matches(".ts")
+        ));
+    }
+    ts_code.push_str("\n/** The classes implementing the services of the controllers, by tag. */\n");
+    ts_code.push_str("export interface ApiServices {\n");
+    for (tag, _) in groups {
+        ts_code.push_str(&format!("    {}: Type<{}Service>;\n", argument_name(tag), pascal_case(tag)));
+    }
+    ts_code.push_str("}\n\n");
+    ts_code.push_str("/**\n");
+    ts_code.push_str(" * The controllers of every operation of the spec, answered by the given services. The DTOs\n");
+    ts_code.push_str(" * are only checked with `app.useGlobalPipes(new ValidationPipe({ transform: true }))`.\n");
+    ts_code.push_str(" */\n");
+    ts_code.push_str("@Module({})\n");
+    ts_code.push_str("export class ApiModule {\n");
+    ts_code.push_str("    static register(services: ApiServices): DynamicModule {\n");
+    ts_code.push_str("        return {\n");
+    ts_code.push_str("            module: ApiModule,\n");
+    let controllers: Vec<String> = groups.iter().map(|(tag, _)| format!("{}Controller", pascal_case(tag))).collect();
+    ts_code.push_str(&format!("            controllers: [{}],\n", controllers.join(", ")));
+    ts_code.push_str("            providers: [\n");
+    for (tag, _) in groups {
+        ts_code.push_str(&format!(
+            "                {{ provide: {}Service, useClass: services.{} }},\n",
+            pascal_case(tag),
+            argument_name(tag)
+        ));
+    }
+    ts_code.push_str("            ],\n");
+    ts_code.push_str("        };\n");
+    ts_code.push_str("    }\n");
+    ts_code.push_str("}\n");
+    ts_code
+}
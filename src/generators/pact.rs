@@ -0,0 +1,375 @@
+use super::http::{encode, parameter_schema, query_texts, text};
+use super::mock::{to_js, Examples};
+use super::typescript::{generate_info_comment, property_name, string_literal};
+use super::{
+    consumes, operations_by_tag, package_name, package_version, produces, request_media_type, security_requirements,
+    success_responses, with_custom_region, words, TaggedOperation, URL_ENCODED,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Operation, Swagger};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+const PACT_VERSION: &str = "^13.1.4";
+const JEST_VERSION: &str = "^29.7.0";
+const TS_JEST_VERSION: &str = "^29.2.5";
+const TYPES_JEST_VERSION: &str = "^29.5.14";
+const TYPESCRIPT_VERSION: &str = "^5.6.3";
+
+/// Writes Pact consumer tests, a `<tag>.pact.test.ts` per tag with an interaction per operation:
+/// the request its example arguments make and the first success response, whose body is matched
+/// by the types and formats of its schema rather than by value. Each test sends the request with
+/// `fetch` as a starting point for calling it through the consumer's own client instead.
+pub fn generate(swagger: &Swagger) -> GeneratedFiles {
+    let examples = Examples::new(swagger);
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("package.json"), generate_package(swagger));
+    for (tag, operations) in operations_by_tag(swagger) {
+        let path = PathBuf::from(format!("{}.pact.test.ts", words(&tag).join("-")));
+        files.insert(path, with_custom_region(generate_tests(swagger, &examples, &tag, &operations), "//"));
+    }
+    files
+}
+
+fn generate_package(swagger: &Swagger) -> String {
+    let package = json!({
+        "name": format!("{}-pact", package_name(swagger)),
+        "version": package_version(swagger),
+        "private": true,
+        "scripts": { "test": "jest" },
+        "jest": { "preset": "ts-jest", "testEnvironment": "node", "testTimeout": 30000 },
+        "devDependencies": {
+            "@pact-foundation/pact": PACT_VERSION,
+            "@types/jest": TYPES_JEST_VERSION,
+            "jest": JEST_VERSION,
+            "ts-jest": TS_JEST_VERSION,
+            "typescript": TYPESCRIPT_VERSION,
+        },
+    });
+    serde_json::to_string_pretty(&package).unwrap_or_default() + "\n"
+}
+
+fn generate_tests(swagger: &Swagger, examples: &Examples, tag: &str, operations: &[TaggedOperation<'_>]) -> String {
+    let mut matchers = BTreeSet::new();
+    let mut tests = Vec::new();
+    for (path, method, operation) in operations {
+        let interaction = Interaction { swagger, examples, method, path, operation };
+        tests.push(interaction.generate(&mut matchers));
+    }
+
+    let provider = swagger.info.get("title").and_then(Value::as_str).unwrap_or("provider");
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { MatchersV3, PactV3 } from '@pact-foundation/pact';\n\n");
+    if !matchers.is_empty() {
+        let matchers: Vec<&str> = matchers.into_iter().collect();
+        ts_code.push_str(&format!("const {{ {} }} = MatchersV3;\n\n", matchers.join(", ")));
+    }
+    ts_code.push_str("const provider = new PactV3({\n");
+    ts_code.push_str("    consumer: process.env.PACT_CONSUMER ?? 'consumer',\n");
+    ts_code.push_str(&format!("    provider: {},\n", string_literal(provider)));
+    ts_code.push_str("    dir: 'pacts',\n");
+    ts_code.push_str("});\n\n");
+    ts_code.push_str(&format!("describe({}, () => {{\n", string_literal(tag)));
+    ts_code.push_str(&tests.join("\n"));
+    ts_code.push_str("});\n");
+    ts_code
+}
+
+/// `pattern` matching `text` and nothing else.
+fn exact_pattern(text: &str) -> String {
+    text.chars().fold(String::new(), |mut pattern, c| {
+        if "\\^$.|?*+()[]{}/".contains(c) {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+        pattern
+    })
+}
+
+/// `code` indented by `indent` more levels of four spaces after its first line.
+fn indented(code: &str, indent: usize) -> String {
+    code.replace('\n', &format!("\n{}", "    ".repeat(indent)))
+}
+
+struct Interaction<'a> {
+    swagger: &'a Swagger,
+    examples: &'a Examples,
+    method: &'a str,
+    path: &'a str,
+    operation: &'a Operation,
+}
+
+impl Interaction<'_> {
+    fn generate(&self, matchers: &mut BTreeSet<&'static str>) -> String {
+        let method = self.method.to_uppercase();
+        let base_path = self.swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/');
+        let mut path = format!("{}{}", base_path, self.path);
+        let mut query = Map::new();
+        let mut headers = Vec::new();
+        for param in &self.operation.parameters {
+            let example = self.examples.example(&parameter_schema(param));
+            match param.location.as_str() {
+                "path" => path = path.replace(&format!("{{{}}}", param.name), &encode(&text(&example))),
+                "query" => {
+                    let value = match (query_texts(param, &example).as_slice(), example) {
+                        (texts, Value::Array(_)) => json!(texts),
+                        ([text], _) => json!(text),
+                        (texts, _) => json!(texts),
+                    };
+                    query.insert(param.name.clone(), value);
+                }
+                "header" => headers.push((param.name.clone(), text(&example))),
+                "cookie" => headers.push(("Cookie".to_string(), format!("{}={}", param.name, encode(&text(&example))))),
+                _ => {}
+            }
+        }
+        let requirement = security_requirements(self.swagger, self.operation).into_iter().next().unwrap_or_default();
+        for name in requirement {
+            let Some(scheme) = self.swagger.security_definitions.get(&name) else {
+                continue;
+            };
+            let key = scheme.name.clone().unwrap_or_else(|| name.clone());
+            match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+                ("basic", _) => headers.push(("Authorization".to_string(), "Basic dXNlcjpwYXNzd29yZA==".to_string())),
+                ("apiKey", Some("query")) => {
+                    query.insert(key, json!("key"));
+                }
+                ("apiKey", Some("cookie")) => headers.push(("Cookie".to_string(), format!("{}=key", key))),
+                ("apiKey", _) => headers.push((key, "key".to_string())),
+                _ => headers.push(("Authorization".to_string(), "Bearer token".to_string())),
+            }
+        }
+        let body = self.request_body(matchers);
+        if let Some((media, _, _)) = &body {
+            headers.push(("Content-Type".to_string(), media.clone()));
+        }
+
+        let headers_literal: Vec<String> =
+            headers.iter().map(|(name, value)| format!("{}: {}", property_name(name), string_literal(value))).collect();
+        let headers_literal = format!("{{ {} }}", headers_literal.join(", "));
+        let mut request = vec![format!("method: '{}'", method), format!("path: {}", string_literal(&path))];
+        if !query.is_empty() {
+            let query: Vec<String> = query
+                .iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        Value::Array(items) => {
+                            let items: Vec<String> = items.iter().map(|item| string_literal(&text(item))).collect();
+                            format!("[{}]", items.join(", "))
+                        }
+                        value => string_literal(&text(value)),
+                    };
+                    format!("{}: {}", property_name(name), value)
+                })
+                .collect();
+            request.push(format!("query: {{ {} }}", query.join(", ")));
+        }
+        if !headers.is_empty() {
+            request.push(format!("headers: {}", headers_literal));
+        }
+        if let Some((_, matcher, _)) = &body {
+            request.push(format!("body: {}", indented(matcher, 4)));
+        }
+
+        let success = success_responses(self.operation).into_iter().next();
+        let status = success.and_then(|(status, _)| status.parse::<u16>().ok()).unwrap_or(200);
+        let mut response = vec![format!("status: {}", status)];
+        let schema = success.and_then(|(_, response)| response.response_schema.as_ref());
+        let json_response = produces(self.swagger, self.operation).iter().all(|media| media.contains("json"));
+        if let (Some(schema), true) = (schema.and_then(|schema| serde_json::to_value(schema).ok()), json_response) {
+            if let Some(matcher) = self.matcher(&schema, &mut Vec::new(), matchers) {
+                matchers.insert("regex");
+                response
+                    .push("headers: { 'Content-Type': regex('application/json.*', 'application/json') }".to_string());
+                response.push(format!("body: {}", indented(&matcher, 4)));
+            }
+        }
+
+        let description = self.operation.summary.as_deref().map(str::trim).filter(|summary| !summary.is_empty());
+        let description = description.map_or_else(|| format!("{} {}", method, self.path), str::to_string);
+        let url = if query.is_empty() {
+            path.clone()
+        } else {
+            let pairs: Vec<String> = query
+                .iter()
+                .flat_map(|(name, value)| match value {
+                    Value::Array(items) => items.iter().map(|item| (name, text(item))).collect::<Vec<_>>(),
+                    value => vec![(name, text(value))],
+                })
+                .map(|(name, value)| format!("{}={}", encode(name), encode(&value)))
+                .collect();
+            format!("{}?{}", path, pairs.join("&"))
+        };
+
+        let mut ts_code = format!("    it({}, () => {{\n", string_literal(&description));
+        ts_code.push_str("        provider\n");
+        ts_code.push_str(&format!("            .uponReceiving({})\n", string_literal(&description)));
+        ts_code.push_str("            .withRequest({\n");
+        for field in request {
+            ts_code.push_str(&format!("                {},\n", field));
+        }
+        ts_code.push_str("            })\n");
+        ts_code.push_str("            .willRespondWith({\n");
+        for field in response {
+            ts_code.push_str(&format!("                {},\n", field));
+        }
+        ts_code.push_str("            });\n\n");
+        ts_code.push_str("        return provider.executeTest(async (mockServer) => {\n");
+        ts_code.push_str(&format!("            const response = await fetch(`${{mockServer.url}}{}`, {{\n", url));
+        ts_code.push_str(&format!("                method: '{}',\n", method));
+        if !headers.is_empty() {
+            ts_code.push_str(&format!("                headers: {},\n", headers_literal));
+        }
+        if let Some((_, _, sent)) = &body {
+            ts_code.push_str(&format!("                body: {},\n", indented(sent, 4)));
+        }
+        ts_code.push_str("            });\n");
+        ts_code.push_str(&format!("            expect(response.status).toBe({});\n", status));
+        ts_code.push_str("        });\n");
+        ts_code.push_str("    });\n");
+        ts_code
+    }
+
+    /// The media type of the request body, the body the interaction expects and the body the
+    /// test sends, for bodies sent as JSON or as URL-encoded forms.
+    fn request_body(&self, matchers: &mut BTreeSet<&'static str>) -> Option<(String, String, String)> {
+        if consumes(self.swagger, self.operation).is_empty()
+            && !self.operation.parameters.iter().any(|param| param.location == "body")
+        {
+            return None;
+        }
+        let media = request_media_type(self.swagger, self.operation);
+        if media == URL_ENCODED {
+            let fields: Vec<String> = self
+                .operation
+                .parameters
+                .iter()
+                .filter(|param| param.location == "formData")
+                .map(|param| {
+                    format!(
+                        "{}={}",
+                        encode(&param.name),
+                        encode(&text(&self.examples.example(&parameter_schema(param))))
+                    )
+                })
+                .collect();
+            if fields.is_empty() {
+                return None;
+            }
+            let body = string_literal(&fields.join("&"));
+            return Some((media.to_string(), body.clone(), body));
+        }
+        let body = self.operation.parameters.iter().find(|param| param.location == "body")?;
+        if !media.contains("json") {
+            return None;
+        }
+        let schema = body.schema.as_ref().and_then(|schema| serde_json::to_value(schema).ok()).unwrap_or(json!({}));
+        let matcher = self.matcher(&schema, &mut Vec::new(), matchers)?;
+        let sent = format!("JSON.stringify({})", to_js(&self.examples.example(&schema)));
+        Some((media.to_string(), matcher, sent))
+    }
+
+    /// A Pact matcher of the values of `schema`, following its references to the definitions of
+    /// the document, or `None` for a definition reached again inside itself, which the matcher
+    /// leaves out. The matchers it uses are added to `matchers`.
+    fn matcher(
+        &self,
+        schema: &Value,
+        visiting: &mut Vec<String>,
+        matchers: &mut BTreeSet<&'static str>,
+    ) -> Option<String> {
+        let Value::Object(fields) = schema else {
+            return None;
+        };
+        let field = |key: &str| fields.get(key).filter(|value| !value.is_null());
+        if let Some(reference) = field("$ref").and_then(Value::as_str) {
+            let name = ref_name(reference).to_string();
+            if visiting.contains(&name) {
+                return None;
+            }
+            let definition = serde_json::to_value(self.swagger.definitions.get(&name)?).ok()?;
+            visiting.push(name);
+            let matcher = self.matcher(&definition, visiting, matchers);
+            visiting.pop();
+            return matcher;
+        }
+        if let Some(alternatives) = field("oneOf").or_else(|| field("anyOf")).and_then(Value::as_array) {
+            return self.matcher(alternatives.first()?, visiting, matchers);
+        }
+
+        let example = self.examples.example(schema);
+        if let Some(values) = field("enum").and_then(Value::as_array) {
+            if values.iter().all(Value::is_string) {
+                let alternatives: Vec<String> = values.iter().map(|value| exact_pattern(&text(value))).collect();
+                matchers.insert("regex");
+                return Some(format!(
+                    "regex({}, {})",
+                    string_literal(&format!("^({})$", alternatives.join("|"))),
+                    to_js(&example)
+                ));
+            }
+        }
+        if field("allOf").is_some() || field("properties").is_some() {
+            return Some(self.object(schema, visiting, matchers));
+        }
+        let format = field("format").and_then(Value::as_str);
+        let (name, arguments) = match field("type").and_then(Value::as_str) {
+            Some("array") => {
+                let item = match field("items") {
+                    Some(items) => self.matcher(items, visiting, matchers)?,
+                    None => return Some("[]".to_string()),
+                };
+                ("eachLike", item)
+            }
+            Some("object") => ("like", to_js(&example)),
+            Some("string") => match format {
+                Some("date-time") => ("datetime", format!("\"yyyy-MM-dd'T'HH:mm:ssXXX\", {}", to_js(&example))),
+                Some("date") => ("date", format!("'yyyy-MM-dd', {}", to_js(&example))),
+                Some("time") => ("time", format!("'HH:mm:ss', {}", to_js(&example))),
+                Some("uuid") => ("uuid", to_js(&example)),
+                _ => ("string", to_js(&example)),
+            },
+            Some("integer") => ("integer", to_js(&example)),
+            Some("number") => ("decimal", to_js(&example)),
+            Some("boolean") => ("boolean", to_js(&example)),
+            _ if example.is_null() => return None,
+            _ => ("like", to_js(&example)),
+        };
+        matchers.insert(name);
+        Some(format!("{}({})", name, arguments))
+    }
+
+    /// The matcher of an object schema: an object of the matchers of its properties, those of
+    /// the schemas it extends included.
+    fn object(&self, schema: &Value, visiting: &mut Vec<String>, matchers: &mut BTreeSet<&'static str>) -> String {
+        let mut properties = Vec::new();
+        let mut parts = vec![schema.clone()];
+        let mut extended = Vec::new();
+        while let Some(part) = parts.pop() {
+            if let Some(reference) = part.get("$ref").and_then(Value::as_str) {
+                let name = ref_name(reference);
+                if extended.contains(&name.to_string()) {
+                    continue;
+                }
+                extended.push(name.to_string());
+                if let Some(definition) = self.swagger.definitions.get(name).and_then(|d| serde_json::to_value(d).ok())
+                {
+                    parts.push(definition);
+                }
+                continue;
+            }
+            for (name, property) in part.get("properties").and_then(Value::as_object).into_iter().flatten() {
+                if let Some(matcher) = self.matcher(property, visiting, matchers) {
+                    properties.push(format!("{}: {},", property_name(name), indented(&matcher, 1)));
+                }
+            }
+            parts.extend(part.get("allOf").and_then(Value::as_array).into_iter().flatten().rev().cloned());
+        }
+        if properties.is_empty() {
+            return "{}".to_string();
+        }
+        format!("{{\n    {}\n}}", properties.join("\n    "))
+    }
+}
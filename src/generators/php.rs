@@ -0,0 +1,806 @@
+use super::typescript::{enum_member, form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_binary_responses, has_request_body, info_block, operation_name, package_name,
+    package_version, pascal_case, request_media_type, response_items, response_reference, security_requirements,
+    security_schemes, sends_raw_body, with_custom_region, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// How a value of a schema is represented in PHP, which decides how it is read from and written to arrays.
+enum PhpType {
+    Scalar(&'static str),
+    Model(String),
+    Enum(String),
+    List(Box<PhpType>),
+    Mixed,
+}
+
+impl PhpType {
+    /// The native type declaration, which for lists is a plain `array`.
+    fn declaration(&self) -> String {
+        match self {
+            PhpType::Scalar(name) => name.to_string(),
+            PhpType::Model(name) | PhpType::Enum(name) => name.clone(),
+            PhpType::List(_) => "array".to_string(),
+            PhpType::Mixed => "mixed".to_string(),
+        }
+    }
+
+    /// The type as written in docblocks, e.g. `Activity[]`.
+    fn doc_type(&self) -> String {
+        match self {
+            PhpType::List(item) => format!("{}[]", item.doc_type()),
+            other => other.declaration(),
+        }
+    }
+
+    fn has_models(&self) -> bool {
+        match self {
+            PhpType::Model(_) | PhpType::Enum(_) => true,
+            PhpType::List(item) => item.has_models(),
+            _ => false,
+        }
+    }
+
+    /// Expression converting the decoded array `value` into this type.
+    fn decode(&self, value: &str) -> String {
+        match self {
+            PhpType::Model(name) => format!("{}::fromArray({})", name, value),
+            PhpType::Enum(name) => format!("{}::from({})", name, value),
+            PhpType::List(item) if item.has_models() => {
+                format!("array_map(fn ($item) => {}, {})", item.decode("$item"), value)
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// Expression converting the non-null `value` of this type into a JSON-encodable array.
+    fn encode(&self, value: &str) -> String {
+        match self {
+            PhpType::Model(_) => format!("{}->toArray()", value),
+            PhpType::Enum(_) => format!("{}->value", value),
+            PhpType::List(item) if item.has_models() => {
+                format!("array_map(fn ($item) => {}, {})", item.encode("$item"), value)
+            }
+            _ => value.to_string(),
+        }
+    }
+}
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let namespace = namespace(swagger);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("composer.json"), generate_composer(swagger, &namespace));
+    let service = with_custom_region(generate_service(swagger, &namespace, retries), "//");
+    files.insert(PathBuf::from("src").join("ApiService.php"), service);
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = PathBuf::from("src").join("Model").join(format!("{}.php", type_name(name)));
+        (path, generate_model(swagger, &namespace, name, definition))
+    }));
+    files
+}
+
+fn namespace(swagger: &Swagger) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    match pascal_case(title) {
+        namespace if namespace.starts_with(|c: char| c.is_ascii_alphabetic()) => namespace,
+        _ => "ApiClient".to_string(),
+    }
+}
+
+fn generate_header(swagger: &Swagger, namespace: &str) -> String {
+    format!("<?php\n\n{}\n\ndeclare(strict_types=1);\n\nnamespace {};\n\n", info_block(swagger), namespace)
+}
+
+fn generate_composer(swagger: &Swagger, namespace: &str) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or(namespace);
+    format!(
+        "{{
+    \"name\": \"{}/client\",
+    \"description\": \"Generated client for {}\",
+    \"version\": \"{}\",
+    \"type\": \"library\",
+    \"require\": {{
+        \"php\": \">=8.1\",
+        \"guzzlehttp/guzzle\": \"^7.8\"
+    }},
+    \"autoload\": {{
+        \"psr-4\": {{
+            \"{}\\\\\": \"src/\"
+        }}
+    }}
+}}
+",
+        package_name(swagger),
+        title.replace('"', "\\\""),
+        package_version(swagger),
+        namespace
+    )
+}
+
+fn generate_model(swagger: &Swagger, namespace: &str, name: &str, definition: &Definition) -> String {
+    let class_name = type_name(name);
+    if let Some((backing, values)) = enum_values(definition) {
+        let mut php_code = generate_header(swagger, &format!("{}\\Model", namespace));
+        php_code.push_str(&generate_enum(&class_name, backing, &values));
+        return php_code;
+    }
+    let mut properties: Vec<(&String, String, PhpType, bool, bool)> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| {
+            let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+            (prop_name, variable_name(prop_name), property_type(swagger, prop), required, nullable)
+        })
+        .collect();
+    // Optional parameters must follow the required ones.
+    properties.sort_by_key(|(_, _, _, required, _)| !required);
+
+    let mut php_code = generate_header(swagger, &format!("{}\\Model", namespace));
+    php_code.push_str(&format!("final class {}\n{{\n", class_name));
+
+    let lists: Vec<String> = properties
+        .iter()
+        .filter(|(_, _, php_type, _, _)| matches!(php_type, PhpType::List(_)))
+        .map(|(_, variable, php_type, required, nullable)| {
+            let nullable = if *required && !nullable { "" } else { "|null" };
+            format!("     * @param {}{} ${}\n", php_type.doc_type(), nullable, variable)
+        })
+        .collect();
+    if !lists.is_empty() {
+        php_code.push_str(&format!("    /**\n{}     */\n", lists.concat()));
+    }
+
+    let parameters: Vec<String> = properties
+        .iter()
+        .map(|(_, variable, php_type, required, nullable)| match (required, php_type) {
+            (_, PhpType::Mixed) if *required => format!("        public mixed ${},", variable),
+            (true, _) if *nullable => format!("        public ?{} ${},", php_type.declaration(), variable),
+            (true, _) => format!("        public {} ${},", php_type.declaration(), variable),
+            (false, PhpType::Mixed) => format!("        public mixed ${} = null,", variable),
+            (false, _) => format!("        public ?{} ${} = null,", php_type.declaration(), variable),
+        })
+        .collect();
+    if parameters.is_empty() {
+        php_code.push_str("    public function __construct()\n    {\n    }\n");
+    } else {
+        php_code.push_str(&format!("    public function __construct(\n{}\n    ) {{\n    }}\n", parameters.join("\n")));
+    }
+
+    php_code.push_str("\n    public static function fromArray(array $data): self\n    {\n        return new self(\n");
+    for (prop_name, variable, php_type, required, nullable) in &properties {
+        let value = format!("$data['{}']", prop_name);
+        // A required field that may be null is read like an optional one.
+        let decoded = match *required && !nullable {
+            true => php_type.decode(&value),
+            false if !php_type.has_models() => format!("{} ?? null", value),
+            false => format!("isset({}) ? {} : null", value, php_type.decode(&value)),
+        };
+        php_code.push_str(&format!("            {}: {},\n", variable, decoded));
+    }
+    php_code.push_str("        );\n    }\n");
+
+    php_code.push_str("\n    public function toArray(): array\n    {\n");
+    php_code.push_str("        return array_filter([\n");
+    for (prop_name, variable, php_type, required, nullable) in &properties {
+        let value = format!("$this->{}", variable);
+        let encoded = match *required && !nullable {
+            _ if !php_type.has_models() => value,
+            true => php_type.encode(&value),
+            false => format!("{} === null ? null : {}", value, php_type.encode(&value)),
+        };
+        php_code.push_str(&format!("            '{}' => {},\n", prop_name, encoded));
+    }
+    // Required fields that may be null are sent as null rather than left out.
+    let kept: Vec<String> = properties
+        .iter()
+        .filter(|(_, _, _, required, nullable)| *required && *nullable)
+        .map(|(prop_name, ..)| format!("'{}'", prop_name))
+        .collect();
+    if kept.is_empty() {
+        php_code.push_str("        ], fn ($value) => $value !== null);\n    }\n}\n");
+    } else {
+        php_code.push_str(&format!(
+            "        ], fn ($value, $key) => $value !== null || in_array($key, [{}], true), \
+             ARRAY_FILTER_USE_BOTH);\n    }}\n}}\n",
+            kept.join(", ")
+        ));
+    }
+    php_code
+}
+
+/// The backing type of an enum definition and its values of that type, for the string and
+/// integer enums, which become backed enums.
+fn enum_values(definition: &Definition) -> Option<(&'static str, Vec<Value>)> {
+    let values = definition.enum_values.as_ref()?;
+    let (backing, values): (&str, Vec<Value>) = match definition.definition_type.as_deref() {
+        Some("string") | None => ("string", values.iter().filter(|value| value.is_string()).cloned().collect()),
+        Some("integer") => ("int", values.iter().filter(|value| value.is_i64()).cloned().collect()),
+        _ => return None,
+    };
+    Some((backing, values)).filter(|(_, values)| !values.is_empty())
+}
+
+fn generate_enum(enum_name: &str, backing: &str, values: &[Value]) -> String {
+    // `class` is the one name a case cannot take, as `Status::class` names the enum itself.
+    let case_name = |words: &[String]| match enum_member(&words.join("_")) {
+        case_name if case_name.eq_ignore_ascii_case("class") => format!("{}Value", case_name),
+        case_name => case_name,
+    };
+    let mut php_code = format!("enum {}: {}\n{{\n", enum_name, backing);
+    for (case_name, value) in enum_members(values, case_name) {
+        let literal = match value {
+            Value::String(text) => format!("'{}'", text.replace('\\', "\\\\").replace('\'', "\\'")),
+            number => number.to_string(),
+        };
+        php_code.push_str(&format!("    case {} = {};\n", case_name, literal));
+    }
+    php_code.push_str("}\n");
+    php_code
+}
+
+fn generate_service(swagger: &Swagger, namespace: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    let has_query = has_parameters(swagger, "query");
+    let has_headers = has_parameters(swagger, "header");
+    let has_cookies = has_parameters(swagger, "cookie");
+    let has_forms = has_parameters(swagger, "formData");
+    let has_media_types = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(method, operation)| body_media_type(swagger, method, operation).is_some());
+
+    let mut php_code = generate_header(swagger, namespace);
+    let mut models: Vec<String> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .filter_map(|(_, operation)| response_reference(operation))
+        .map(|reference| type_name(ref_name(reference)))
+        .collect();
+    models.sort();
+    models.dedup();
+    php_code.push_str("use GuzzleHttp\\Client;\n");
+    if has_query {
+        php_code.push_str("use GuzzleHttp\\Psr7\\Query;\n");
+    }
+    if retries.is_some() {
+        php_code.push_str("use GuzzleHttp\\Exception\\ConnectException;\n");
+        php_code.push_str("use GuzzleHttp\\Exception\\RequestException;\n");
+        php_code.push_str("use Psr\\Http\\Message\\ResponseInterface;\n");
+    }
+    if has_binary_responses(swagger) {
+        php_code.push_str("use Psr\\Http\\Message\\StreamInterface;\n");
+    }
+    for model in &models {
+        php_code.push_str(&format!("use {}\\Model\\{};\n", namespace, model));
+    }
+
+    let mut base = base_url(swagger);
+    if !base.ends_with('/') {
+        base.push('/');
+    }
+    php_code.push_str("\nfinal class ApiService\n{\n");
+    php_code.push_str(&format!("    public const DEFAULT_BASE_URL = '{}';\n", base));
+    if let Some(retries) = retries {
+        php_code.push_str(&format!("    public const MAX_RETRIES = {};\n", retries));
+    }
+    if has_auth {
+        php_code.push_str("\n    private const SECURITY_SCHEMES = [\n");
+        for (name, scheme) in security_schemes(swagger) {
+            php_code.push_str(&format!(
+                "        '{}' => ['{}', '{}', '{}'],\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        php_code.push_str("    ];\n");
+    }
+    php_code.push_str("\n    private Client $client;\n");
+    if has_auth {
+        php_code.push_str("\n    /** @var array<string, string> */\n    private array $credentials = [];\n");
+    }
+    php_code.push_str(
+        "
+    public function __construct(string $baseUrl = self::DEFAULT_BASE_URL, ?Client $client = null)
+    {
+        $this->client = $client ?? new Client(['base_uri' => $baseUrl]);
+    }\n",
+    );
+
+    // File downloads return the body stream instead of decoded JSON.
+    let binary = has_binary_responses(swagger);
+    let mut extra_params = String::new();
+    let mut extra_docs = String::new();
+    if binary {
+        extra_params.push_str(", bool $binary = false");
+    }
+    for (name, present) in [("query", has_query), ("headers", has_headers), ("cookies", has_cookies)] {
+        if present {
+            extra_params.push_str(&format!(", array ${} = []", name));
+            extra_docs.push_str(&format!("     * @param array<string, mixed> ${}\n", name));
+        }
+    }
+    if has_forms {
+        extra_params.push_str(", array $form = [], bool $multipart = false");
+        extra_docs.push_str("     * @param array<string, mixed> $form\n");
+    }
+    if has_media_types {
+        extra_params.push_str(", string $contentType = 'application/json'");
+    }
+    // Bodies of other media types carry their own Content-Type: strings and streams go as they are, the rest as JSON.
+    let body = if has_media_types {
+        "        if ($body !== null && $contentType === 'application/json') {
+            $options['json'] = $body;
+        } elseif ($body !== null) {
+            $options['headers']['Content-Type'] = $contentType;
+            $options['body'] = !str_contains($contentType, 'json') && (is_string($body) || is_resource($body))
+                ? $body
+                : json_encode($body, JSON_THROW_ON_ERROR);
+        }\n"
+    } else {
+        "        if ($body !== null) {
+            $options['json'] = $body;
+        }\n"
+    };
+    if has_auth {
+        php_code.push_str(
+            "
+    /**
+     * Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.
+     */
+    public function setAuth(string $scheme, string $credential): void
+    {
+        $this->credentials[$scheme] = $credential;
+    }\n",
+        );
+        php_code.push_str(&format!(
+            "
+    /**
+     * @param string[][] $requirements
+{}     */
+    private function request(string $method, string $path, mixed $body = null, array $requirements = []{}): mixed\n",
+            extra_docs, extra_params
+        ));
+        php_code.push_str("    {\n        $options = ['headers' => ['Accept' => 'application/json']];\n");
+        php_code.push_str(body);
+        php_code.push_str(
+            "        foreach ($requirements as $names) {
+            if (array_diff($names, array_keys($this->credentials)) !== []) {
+                continue;
+            }
+            foreach ($names as $name) {
+                [$type, $in, $key] = self::SECURITY_SCHEMES[$name];
+                $credential = $this->credentials[$name];
+                if ($type === 'basic') {
+                    $options['auth'] = explode(':', $credential, 2) + [1 => ''];
+                } elseif ($type === 'apiKey' && $in === 'query') {
+                    $options['query'][$key] = $credential;
+                } elseif ($type === 'apiKey' && $in === 'cookie') {
+                    $options['headers']['Cookie'] = $key . '=' . $credential;
+                } elseif ($type === 'apiKey') {
+                    $options['headers'][$key] = $credential;
+                } else {
+                    $options['headers']['Authorization'] = 'Bearer ' . $credential;
+                }
+            }
+            break;
+        }\n",
+        );
+    } else {
+        if !extra_docs.is_empty() {
+            php_code.push_str(&format!("\n    /**\n{}     */", extra_docs));
+        }
+        php_code.push_str(&format!(
+            "
+    private function request(string $method, string $path, mixed $body = null{}): mixed
+    {{
+        $options = ['headers' => ['Accept' => 'application/json']];\n",
+            extra_params
+        ));
+        php_code.push_str(body);
+    }
+    // Parameters left out are null and not sent.
+    if has_query {
+        php_code.push_str(
+            "        $query = array_filter($query, fn ($value) => $value !== null);
+        if ($query !== []) {
+            $options['query'] = Query::build(($options['query'] ?? []) + $query);
+        }\n",
+        );
+    }
+    if has_headers {
+        php_code.push_str(
+            "        foreach (array_filter($headers, fn ($value) => $value !== null) as $name => $value) {
+            $options['headers'][$name] = is_array($value) ? implode(',', $value) : (string) $value;
+        }\n",
+        );
+    }
+    if has_cookies {
+        php_code.push_str(
+            "        $pairs = [];
+        foreach (array_filter($cookies, fn ($value) => $value !== null) as $name => $value) {
+            $pairs[] = $name . '=' . $value;
+        }
+        if ($pairs !== []) {
+            $cookie = $options['headers']['Cookie'] ?? null;
+            $options['headers']['Cookie'] = implode('; ', $cookie === null ? $pairs : [$cookie, ...$pairs]);
+        }\n",
+        );
+    }
+    // Guzzle sets the Content-Type of `multipart` and `form_params` bodies itself.
+    if has_forms {
+        php_code.push_str(
+            "        $form = array_filter($form, fn ($value) => $value !== null);
+        if ($multipart) {
+            foreach ($form as $name => $value) {
+                foreach (is_array($value) ? $value : [$value] as $item) {
+                    $options['multipart'][] = is_scalar($item)
+                        ? ['name' => $name, 'contents' => (string) $item]
+                        : ['name' => $name, 'contents' => $item, 'filename' => $name];
+                }
+            }
+        } elseif ($form !== []) {
+            $options['form_params'] = $form;
+        }\n",
+        );
+    }
+    php_code.push_str(&format!(
+        "
+        $response = $this->{}($method, ltrim($path, '/'), $options);
+        {}$contents = (string) $response->getBody();
+        return $contents === '' ? null : json_decode($contents, true, 512, JSON_THROW_ON_ERROR);
+    }}\n",
+        if retries.is_some() { "sendWithRetries" } else { "client->request" },
+        if binary {
+            "if ($binary) {
+            return $response->getBody();
+        }
+        "
+        } else {
+            ""
+        }
+    ));
+
+    if retries.is_some() {
+        php_code.push_str(
+            "
+    /**
+     * Retries requests that failed with a network error, 429 or 5xx, waiting as long as Retry-After asks
+     * or otherwise doubling the delay from half a second.
+     *
+     * @param array<string, mixed> $options
+     */
+    private function sendWithRetries(string $method, string $path, array $options): ResponseInterface
+    {
+        for ($attempt = 0; ; $attempt++) {
+            $response = null;
+            try {
+                return $this->client->request($method, $path, $options);
+            } catch (ConnectException $error) {
+            } catch (RequestException $error) {
+                $response = $error->getResponse();
+                $status = $response?->getStatusCode() ?? 0;
+                if ($status !== 429 && $status < 500) {
+                    throw $error;
+                }
+            }
+            if ($attempt >= self::MAX_RETRIES) {
+                throw $error;
+            }
+            $retryAfter = $response?->getHeaderLine('Retry-After') ?? '';
+            $date = $retryAfter === '' ? false : strtotime($retryAfter);
+            usleep(match (true) {
+                ctype_digit($retryAfter) => (int) $retryAfter * 1000000,
+                $date !== false => max($date - time(), 0) * 1000000,
+                default => 500000 << $attempt,
+            });
+        }
+    }\n",
+        );
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            php_code.push('\n');
+            php_code.push_str(&generate_service_method(swagger, method, path, operation));
+        }
+    }
+    php_code.push_str("}\n");
+    php_code
+}
+
+/// Whether any operation takes a parameter in `location`, which `request` then accepts.
+fn has_parameters(swagger: &Swagger, location: &str) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        let params = [query_parameters(operation), header_parameters(operation), form_parameters(operation)];
+        params.iter().flatten().any(|param| param.location == location)
+    })
+}
+
+fn generate_service_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = variable_name(&operation_name(method, path, operation));
+    let form = form_parameters(operation);
+    let has_body = has_request_body(method) && form.is_empty();
+
+    let body_type = body_type(swagger, method, operation);
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{} ${}", path_parameter_type(operation, param), argument_name(param)))
+        .collect();
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation));
+    // Optional parameters default to null, so the required ones come before the body.
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) =
+        params.iter().chain(&form).partition(|param| param.required);
+    for param in required {
+        arguments.push(format!("{} ${}", parameter_type(param).declaration(), argument_name(&param.name)));
+    }
+    if has_body {
+        arguments.push(match &body_type {
+            Some(PhpType::Mixed) | None => "mixed $body = null".to_string(),
+            Some(body_type) => format!("?{} $body = null", body_type.declaration()),
+        });
+    }
+    for param in optional {
+        let argument = match parameter_type(param) {
+            PhpType::Mixed => format!("mixed ${} = null", argument_name(&param.name)),
+            other => format!("?{} ${} = null", other.declaration(), argument_name(&param.name)),
+        };
+        arguments.push(argument);
+    }
+
+    let php_path = path_params.iter().fold(format!("'{}'", path), |acc, param| {
+        let argument = argument_name(param);
+        let value = match path_parameter_type(operation, param) {
+            "string" => format!("rawurlencode(${})", argument),
+            "bool" => format!("(${} ? 'true' : 'false')", argument),
+            _ => format!("${}", argument),
+        };
+        acc.replace(&format!("{{{}}}", param), &format!("' . {} . '", value))
+    });
+    let php_path = php_path.trim_end_matches(" . ''").to_string();
+    let requirements: Vec<String> = if swagger.security_definitions.is_empty() {
+        Vec::new()
+    } else {
+        security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+                format!("[{}]", names.join(", "))
+            })
+            .collect()
+    };
+    let mut request_arguments = vec![format!("'{}'", method.to_uppercase()), php_path];
+    if has_body || !requirements.is_empty() {
+        request_arguments.push(match &body_type {
+            _ if !has_body => "null".to_string(),
+            Some(body_type) if body_type.has_models() => {
+                format!("$body === null ? null : {}", body_type.encode("$body"))
+            }
+            _ => "$body".to_string(),
+        });
+    }
+    if !requirements.is_empty() {
+        request_arguments.push(format!("[{}]", requirements.join(", ")));
+    }
+    if has_binary_response(swagger, operation) {
+        request_arguments.push("binary: true".to_string());
+    }
+    for (label, location) in [("query", "query"), ("headers", "header"), ("cookies", "cookie")] {
+        let values: Vec<String> = params
+            .iter()
+            .filter(|param| param.location == location)
+            .map(|param| format!("'{}' => {}", param.name, parameter_value(param)))
+            .collect();
+        if !values.is_empty() {
+            request_arguments.push(format!("{}: [{}]", label, values.join(", ")));
+        }
+    }
+    if let Some(media_type) = body_media_type(swagger, method, operation) {
+        request_arguments.push(format!("contentType: '{}'", media_type));
+    }
+    if !form.is_empty() {
+        let fields: Vec<String> =
+            form.iter().map(|param| format!("'{}' => ${}", param.name, argument_name(&param.name))).collect();
+        request_arguments.push(format!("form: [{}]", fields.join(", ")));
+        if request_media_type(swagger, operation) == MULTIPART {
+            request_arguments.push("multipart: true".to_string());
+        }
+    }
+    let request = format!("$this->request({})", request_arguments.join(", "));
+
+    let mut php_code = String::new();
+    let mut doc: Vec<String> = operation.summary.iter().cloned().collect();
+    if operation.deprecated {
+        doc.push("@deprecated".to_string());
+    }
+    if let Some(PhpType::List(items)) = body_type.as_ref().filter(|_| has_body) {
+        doc.push(format!("@param {}[]|null $body", items.doc_type()));
+    }
+    let response_type = response_type(swagger, operation).filter(|_| !has_binary_response(swagger, operation));
+    if let Some(PhpType::List(items)) = &response_type {
+        doc.push(format!("@return {}[]", items.doc_type()));
+    }
+    if !doc.is_empty() {
+        let lines: Vec<String> = doc.iter().map(|line| format!("     * {}\n", line)).collect();
+        php_code.push_str(&format!("    /**\n{}     */\n", lines.concat()));
+    }
+    match response_type {
+        _ if has_binary_response(swagger, operation) => {
+            php_code.push_str(&format!(
+                "    public function {}({}): StreamInterface\n    {{\n        return {};\n    }}\n",
+                method_name,
+                arguments.join(", "),
+                request
+            ));
+        }
+        Some(model) => {
+            php_code.push_str(&format!(
+                "    public function {}({}): {}\n    {{\n        return {};\n    }}\n",
+                method_name,
+                arguments.join(", "),
+                model.declaration(),
+                model.decode(&request)
+            ));
+        }
+        None => {
+            php_code.push_str(&format!(
+                "    public function {}({}): mixed\n    {{\n        return {};\n    }}\n",
+                method_name,
+                arguments.join(", "),
+                request
+            ));
+        }
+    }
+    php_code
+}
+
+/// The value a parameter is sent as: its argument, with an array joined by the delimiter of its
+/// `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    let argument = format!("${}", argument_name(&param.name));
+    match collection_delimiter(param) {
+        // Single quotes leave escapes as they are, so a tab goes in double quotes.
+        Some(delimiter) => {
+            let delimiter = if delimiter == "\t" { "\"\\t\"".to_string() } else { format!("'{}'", delimiter) };
+            let joined = format!("implode({}, {})", delimiter, argument);
+            if param.required {
+                joined
+            } else {
+                format!("{} === null ? null : {}", argument, joined)
+            }
+        }
+        None => argument,
+    }
+}
+
+/// The type a method returns: the referenced model, or a list of the items of an array response.
+fn response_type(swagger: &Swagger, operation: &Operation) -> Option<PhpType> {
+    match (response_reference(operation), response_items(operation)) {
+        (Some(reference), _) => Some(reference_type(swagger, reference)),
+        (None, Some(items)) => Some(PhpType::List(Box::new(spec_type(swagger, items)))),
+        (None, None) => None,
+    }
+}
+
+/// The type of the body a method sends as JSON, from its schema.
+fn body_type(swagger: &Swagger, method: &str, operation: &Operation) -> Option<PhpType> {
+    let body = operation.parameters.iter().find(|param| param.location == "body")?;
+    let schema = body.schema.as_ref().filter(|_| !sends_raw_body(swagger, method, operation))?;
+    Some(spec_type(swagger, schema))
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `string` for strings and
+/// parameters the operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| scalar_type(param.parameter_type.as_deref())) {
+        Some(PhpType::Scalar(php_type @ ("int" | "float" | "bool"))) => php_type,
+        _ => "string",
+    }
+}
+
+fn spec_type(swagger: &Swagger, schema: &Schema) -> PhpType {
+    if let Some(reference) = &schema.reference {
+        return reference_type(swagger, reference);
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => {
+            let items = schema.items.as_deref();
+            PhpType::List(Box::new(items.map_or(PhpType::Mixed, |items| spec_type(swagger, items))))
+        }
+        other => scalar_type(other),
+    }
+}
+
+fn parameter_type(param: &Parameter) -> PhpType {
+    match param.parameter_type.as_deref() {
+        // A file is uploaded from a resource or a stream.
+        Some("file") => PhpType::Mixed,
+        Some("array") => {
+            let items = param.items.as_ref().and_then(|items| items.schema_type.as_deref());
+            PhpType::List(Box::new(scalar_type(items)))
+        }
+        other => scalar_type(other),
+    }
+}
+
+/// The type of the definition `reference` points to: an enum for the definitions that become one,
+/// a model class otherwise.
+fn reference_type(swagger: &Swagger, reference: &str) -> PhpType {
+    let name = ref_name(reference);
+    match swagger.definitions.get(name).and_then(enum_values) {
+        Some(_) => PhpType::Enum(type_name(name)),
+        None => PhpType::Model(type_name(name)),
+    }
+}
+
+fn property_type(swagger: &Swagger, prop: &Property) -> PhpType {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => PhpType::List(Box::new(
+            prop.additional.get("items").map_or(PhpType::Mixed, |items| schema_type(swagger, items)),
+        )),
+        other => scalar_type(other),
+    }
+}
+
+fn schema_type(swagger: &Swagger, schema: &Value) -> PhpType {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => {
+            PhpType::List(Box::new(schema.get("items").map_or(PhpType::Mixed, |items| schema_type(swagger, items))))
+        }
+        other => scalar_type(other),
+    }
+}
+
+fn scalar_type(schema_type: Option<&str>) -> PhpType {
+    match schema_type {
+        Some("integer") => PhpType::Scalar("int"),
+        Some("number") => PhpType::Scalar("float"),
+        Some("string") => PhpType::Scalar("string"),
+        Some("boolean") => PhpType::Scalar("bool"),
+        Some("object") => PhpType::Scalar("array"),
+        _ => PhpType::Mixed,
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn variable_name(name: &str) -> String {
+    let type_name = type_name(name);
+    let mut chars = type_name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    match variable_name(name) {
+        argument if argument == "body" || argument == "this" => format!("{}Param", argument),
+        argument => argument,
+    }
+}
@@ -0,0 +1,646 @@
+use super::typescript::{
+    base_interfaces, form_parameters, has_flag, header_parameters, operation_context, query_parameters,
+};
+use super::{
+    base_url, body_media_type, collection_delimiter, enum_members, extract_path_params, has_binary_response,
+    has_binary_responses, has_request_body, info_lines, inherited_fields, operation_name, own_fields,
+    request_media_type, security_requirements, security_schemes, sends_raw_body, snake_case, success_responses,
+    with_custom_region, MULTIPART,
+};
+use crate::cli::PythonStyle;
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use crate::templates;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue", "def",
+    "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import", "in", "is",
+    "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while", "with", "yield",
+];
+
+const HELPERS: &str = r#"def _parse(cls: Any, value: Any) -> Any:
+    if value is None:
+        return None
+    if isinstance(value, list):
+        return [_parse(cls, item) for item in value]
+    if issubclass(cls, Enum):
+        return cls(value)
+    return cls.from_dict(value)
+
+
+def _dump(value: Any) -> Any:
+    if isinstance(value, Enum):
+        return value.value
+    if hasattr(value, "to_dict"):
+        return value.to_dict()
+    if isinstance(value, list):
+        return [_dump(item) for item in value]
+    return value
+"#;
+
+const SYNC_REQUEST: &str = r#"    response = session.request(method, BASE_URL + path, **kwargs)
+    response.raise_for_status()
+"#;
+
+const ASYNC_REQUEST: &str = r#"    response = await client.request(method, path, **kwargs)
+    response.raise_for_status()
+"#;
+
+/// End of `_request` once the response is checked; file downloads return the raw bytes before the JSON decoding.
+const RETURN_BODY: &str = "    return response.json() if response.content else None\n";
+const RETURN_BYTES: &str = "    if binary:\n        return response.content\n";
+
+const RETRY_DELAY: &str = r#"def _retry_delay(attempt: int, retry_after: Optional[str]) -> float:
+    if retry_after and retry_after.strip().isdigit():
+        return float(retry_after)
+    try:
+        date = email.utils.parsedate_to_datetime(retry_after or "")
+    except (TypeError, ValueError):
+        return 0.5 * 2**attempt
+    return max((date - datetime.datetime.now(datetime.timezone.utc)).total_seconds(), 0.0)
+"#;
+
+const SYNC_RETRY_REQUEST: &str = r#"    attempt = 0
+    while True:
+        try:
+            response = session.request(method, BASE_URL + path, **kwargs)
+        except (requests.ConnectionError, requests.Timeout):
+            if attempt >= MAX_RETRIES:
+                raise
+            time.sleep(_retry_delay(attempt, None))
+        else:
+            if (response.status_code != 429 and response.status_code < 500) or attempt >= MAX_RETRIES:
+                break
+            time.sleep(_retry_delay(attempt, response.headers.get("Retry-After")))
+        attempt += 1
+    response.raise_for_status()
+"#;
+
+const ASYNC_RETRY_REQUEST: &str = r#"    attempt = 0
+    while True:
+        try:
+            response = await client.request(method, path, **kwargs)
+        except httpx.TransportError:
+            if attempt >= MAX_RETRIES:
+                raise
+            await asyncio.sleep(_retry_delay(attempt, None))
+        else:
+            if (response.status_code != 429 and response.status_code < 500) or attempt >= MAX_RETRIES:
+                break
+            await asyncio.sleep(_retry_delay(attempt, response.headers.get("Retry-After")))
+        attempt += 1
+    response.raise_for_status()
+"#;
+
+const PARAMS_HELPER: &str = r#"def _with_params(kwargs: Dict[str, Any], **fields: Dict[str, Any]) -> Dict[str, Any]:
+    for key, values in fields.items():
+        values = {name: value for name, value in values.items() if value is not None}
+        if values:
+            kwargs[key] = {**(kwargs.get(key) or {}), **values}
+    return kwargs
+"#;
+
+const PARTS_HELPER: &str = r#"def _parts(fields: Dict[str, Any]) -> List[Any]:
+    return [
+        (name, (None, str(item)))
+        for name, value in fields.items()
+        if value is not None
+        for item in (value if isinstance(value, list) else [value])
+    ]
+"#;
+
+const AUTH_HELPERS: &str = r#"_auth: Dict[str, Any] = {}
+
+
+def set_auth(config: Dict[str, Any]) -> None:
+    _auth.clear()
+    _auth.update(config)
+
+
+def _with_auth(requirements: List[List[str]], kwargs: Dict[str, Any]) -> Dict[str, Any]:
+    requirement = next((names for names in requirements if all(name in _auth for name in names)), None)
+    if requirement is None:
+        return kwargs
+    headers = dict(kwargs.pop("headers", None) or {})
+    params = dict(kwargs.pop("params", None) or {})
+    cookies = dict(kwargs.pop("cookies", None) or {})
+    for name in requirement:
+        scheme_type, location, key = _SECURITY_SCHEMES[name]
+        value = _auth[name]
+        if scheme_type == "basic":
+            kwargs["auth"] = tuple(value)
+        elif scheme_type == "apiKey" and location == "query":
+            params[key] = value
+        elif scheme_type == "apiKey" and location == "cookie":
+            cookies[key] = value
+        elif scheme_type == "apiKey":
+            headers[key] = value
+        else:
+            headers["Authorization"] = f"Bearer {value}"
+    return dict(kwargs, headers=headers, params=params, cookies=cookies)
+"#;
+
+pub fn generate(swagger: &Swagger, style: PythonStyle, retries: Option<u32>) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("service.py"), with_custom_region(generate_service(swagger, style, retries), "#"));
+    files
+}
+
+fn generate_service(swagger: &Swagger, style: PythonStyle, retries: Option<u32>) -> String {
+    let mut py_code = String::new();
+    for line in info_lines(swagger) {
+        py_code.push_str(&format!("# {}\n", line));
+    }
+    py_code.push('\n');
+    py_code.push_str("from __future__ import annotations\n\n");
+    py_code.push_str("from dataclasses import dataclass\n");
+    py_code.push_str("from enum import Enum\n");
+    py_code.push_str("from typing import Any, Dict, List, Optional\n\n");
+    let max_retries = match retries {
+        Some(retries) => format!("MAX_RETRIES = {}\n\n", retries),
+        None => String::new(),
+    };
+    // Path parameters are quoted with `urllib.parse`.
+    let has_path_params = swagger.paths.keys().any(|path| !extract_path_params(path).is_empty());
+    let standard_modules = |sleep: &str| {
+        let mut modules = Vec::new();
+        if retries.is_some() {
+            // `datetime` and `email.utils` read a `Retry-After` sent as a date.
+            modules.extend([sleep, "datetime", "email.utils"]);
+        }
+        if has_path_params {
+            modules.push("urllib.parse");
+        }
+        modules.sort_unstable();
+        if modules.is_empty() {
+            String::new()
+        } else {
+            format!("{}\n\n", modules.iter().map(|module| format!("import {}", module)).collect::<Vec<_>>().join("\n"))
+        }
+    };
+    match style {
+        PythonStyle::Sync => {
+            py_code.push_str(&standard_modules("time"));
+            py_code.push_str("import requests\n\n");
+            py_code.push_str(&format!("BASE_URL = \"{}\"\n{}\n", base_url(swagger), max_retries));
+            py_code.push_str("session = requests.Session()\n\n\n");
+        }
+        PythonStyle::Async => {
+            py_code.push_str(&standard_modules("asyncio"));
+            py_code.push_str("import httpx\n\n");
+            py_code.push_str(&format!("BASE_URL = \"{}\"\n{}\n", base_url(swagger), max_retries));
+            py_code.push_str("client = httpx.AsyncClient(base_url=BASE_URL)\n\n\n");
+        }
+    }
+    py_code.push_str(HELPERS);
+    py_code.push_str("\n\n");
+    if retries.is_some() {
+        py_code.push_str(RETRY_DELAY);
+        py_code.push_str("\n\n");
+    }
+    let binary = has_binary_responses(swagger);
+    py_code.push_str(&format!(
+        "{}def _request(method: str, path: str, {}**kwargs: Any) -> Any:\n",
+        if style == PythonStyle::Async { "async " } else { "" },
+        if binary { "binary: bool = False, " } else { "" }
+    ));
+    py_code.push_str(match (style, retries) {
+        (PythonStyle::Sync, None) => SYNC_REQUEST,
+        (PythonStyle::Async, None) => ASYNC_REQUEST,
+        (PythonStyle::Sync, Some(_)) => SYNC_RETRY_REQUEST,
+        (PythonStyle::Async, Some(_)) => ASYNC_RETRY_REQUEST,
+    });
+    if binary {
+        py_code.push_str(RETURN_BYTES);
+    }
+    py_code.push_str(RETURN_BODY);
+
+    let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+    let has_params = operations.clone().any(|(method, operation)| {
+        !query_parameters(operation).is_empty()
+            || !header_parameters(operation).is_empty()
+            || !form_parameters(operation).is_empty()
+            || body_media_type(swagger, method, operation).is_some()
+    });
+    if has_params {
+        py_code.push_str("\n\n");
+        py_code.push_str(PARAMS_HELPER);
+    }
+    if operations.clone().any(|(_, operation)| text_parts(swagger, operation)) {
+        py_code.push_str("\n\n");
+        py_code.push_str(PARTS_HELPER);
+    }
+
+    if !swagger.security_definitions.is_empty() {
+        py_code.push_str("\n\n_SECURITY_SCHEMES = {\n");
+        for (name, scheme) in security_schemes(swagger) {
+            py_code.push_str(&format!(
+                "    \"{}\": (\"{}\", \"{}\", \"{}\"),\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        py_code.push_str("}\n\n");
+        py_code.push_str(AUTH_HELPERS);
+    }
+
+    for (name, definition) in class_order(swagger) {
+        py_code.push_str("\n\n");
+        match &definition.enum_values {
+            Some(values) => py_code.push_str(&generate_enum(name, definition, values)),
+            None => py_code.push_str(&generate_dataclass(swagger, name, definition)),
+        }
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            py_code.push_str("\n\n");
+            py_code.push_str(&generate_service_function(swagger, style, method, path, operation));
+        }
+    }
+
+    py_code
+}
+
+/// Definitions in document order, moving each base class of an `allOf` ahead of the classes
+/// inheriting from it.
+fn class_order(swagger: &Swagger) -> Vec<(&String, &Definition)> {
+    fn visit<'a>(
+        swagger: &'a Swagger,
+        name: &'a String,
+        visited: &mut HashSet<&'a str>,
+        ordered: &mut Vec<(&'a String, &'a Definition)>,
+    ) {
+        let Some(definition) = swagger.definitions.get(name) else { return };
+        if !visited.insert(name) {
+            return;
+        }
+        for base in base_interfaces(definition) {
+            if let Some((base, _)) = swagger.definitions.get_key_value(base) {
+                visit(swagger, base, visited, ordered);
+            }
+        }
+        ordered.push((name, definition));
+    }
+
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::new();
+    for name in swagger.definitions.keys() {
+        visit(swagger, name, &mut visited, &mut ordered);
+    }
+    ordered
+}
+
+fn generate_enum(name: &str, definition: &Definition, values: &[Value]) -> String {
+    let base = match definition.definition_type.as_deref() {
+        Some("integer") => "int, ",
+        Some("number") => "float, ",
+        Some("string") | None => "str, ",
+        _ => "",
+    };
+    let mut py_code = format!("class {}({}Enum):\n", identifier(name), base);
+    let members = enum_members(values, |words| {
+        let member = words.join("_").to_uppercase();
+        if member.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("VALUE_{}", member)
+        } else {
+            member
+        }
+    });
+    for (member, value) in &members {
+        py_code.push_str(&format!("    {} = {}\n", member, value));
+    }
+    if members.is_empty() {
+        py_code.push_str("    pass\n");
+    }
+    py_code
+}
+
+/// A dataclass for an object definition, subclassing the definitions its `allOf` refers to.
+fn generate_dataclass(swagger: &Swagger, name: &str, definition: &Definition) -> String {
+    let class_name = identifier(name);
+    let mut own = own_fields(definition);
+    own.sort_by_key(|(_, _, required)| !required);
+    let inherited = inherited_fields(swagger, definition, &mut vec![name]);
+    let bases: Vec<String> = base_interfaces(definition)
+        .into_iter()
+        .filter(|base| swagger.definitions.contains_key(*base))
+        .map(identifier)
+        .collect();
+
+    // Required fields cannot follow the defaulted ones of a base class unless they are keyword-only.
+    let keyword_only =
+        inherited.iter().any(|(_, _, required)| !required) && own.iter().any(|(_, _, required)| *required);
+    let mut py_code = String::from(if keyword_only { "@dataclass(kw_only=True)\n" } else { "@dataclass\n" });
+    if bases.is_empty() {
+        py_code.push_str(&format!("class {}:\n", class_name));
+    } else {
+        py_code.push_str(&format!("class {}({}):\n", class_name, bases.join(", ")));
+    }
+    for (prop_name, prop, required) in &own {
+        // A required field that may be null still has no default.
+        if *required && (has_flag(prop, "nullable") || has_flag(prop, "x-nullable")) {
+            py_code.push_str(&format!("    {}: Optional[{}]\n", identifier(prop_name), property_type(prop)));
+        } else if *required {
+            py_code.push_str(&format!("    {}: {}\n", identifier(prop_name), property_type(prop)));
+        } else {
+            py_code.push_str(&format!("    {}: Optional[{}] = None\n", identifier(prop_name), property_type(prop)));
+        }
+    }
+    if !own.is_empty() {
+        py_code.push('\n');
+    }
+
+    let mut fields = inherited;
+    fields.extend(own);
+
+    py_code.push_str("    @classmethod\n");
+    py_code.push_str(&format!("    def from_dict(cls, data: Dict[str, Any]) -> {}:\n", class_name));
+    py_code.push_str("        return cls(\n");
+    for (prop_name, prop, _) in &fields {
+        let value = format!("data.get(\"{}\")", prop_name);
+        let value = match property_reference(prop) {
+            Some(reference) => format!("_parse({}, {})", identifier(ref_name(reference)), value),
+            None => value,
+        };
+        py_code.push_str(&format!("            {}={},\n", identifier(prop_name), value));
+    }
+    py_code.push_str("        )\n\n");
+
+    py_code.push_str("    def to_dict(self) -> Dict[str, Any]:\n");
+    py_code.push_str("        data = {\n");
+    for (prop_name, _, _) in &fields {
+        py_code.push_str(&format!("            \"{}\": _dump(self.{}),\n", prop_name, identifier(prop_name)));
+    }
+    py_code.push_str("        }\n");
+    // Required fields that may be null are sent as null rather than left out.
+    let kept: Vec<String> = fields
+        .iter()
+        .filter(|(_, prop, required)| *required && (has_flag(prop, "nullable") || has_flag(prop, "x-nullable")))
+        .map(|(prop_name, _, _)| format!("\"{}\", ", prop_name))
+        .collect();
+    if kept.is_empty() {
+        py_code.push_str("        return {key: value for key, value in data.items() if value is not None}\n");
+    } else {
+        py_code.push_str(&format!(
+            "        return {{key: value for key, value in data.items() if value is not None or key in ({})}}\n",
+            kept.concat().trim_end()
+        ));
+    }
+    py_code
+}
+
+fn generate_service_function(
+    swagger: &Swagger,
+    style: PythonStyle,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+) -> String {
+    let path_params = extract_path_params(path);
+    let function_name = identifier(&snake_case(&operation_name(method, path, operation)));
+
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+    let form = form_parameters(operation);
+
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{}: {}", identifier(param), path_parameter_type(operation, param)))
+        .collect();
+    let has_body = has_request_body(method) && form.is_empty();
+    let body = operation.parameters.iter().find(|param| param.location == "body");
+    match body.and_then(|body| Some((body.schema.as_ref()?, body.required))) {
+        Some((schema, required)) if has_body => {
+            let body_type = spec_type(schema);
+            if required {
+                arguments.push(format!("data: {}", body_type));
+            } else {
+                arguments.push(format!("data: Optional[{}] = None", body_type));
+            }
+        }
+        _ if has_body => arguments.push("data: Optional[Any] = None".to_string()),
+        _ => {}
+    }
+    // The query, header and form parameters are keyword-only, the required ones first.
+    let mut params: Vec<&Parameter> = query.iter().chain(&headers).chain(&form).copied().collect();
+    params.sort_by_key(|param| !param.required);
+    if !params.is_empty() {
+        arguments.push("*".to_string());
+    }
+    for param in &params {
+        let param_type = parameter_type(param);
+        if param.required {
+            arguments.push(format!("{}: {}", argument_name(&param.name), param_type));
+        } else {
+            arguments.push(format!("{}: Optional[{}] = None", argument_name(&param.name), param_type));
+        }
+    }
+    arguments.push("**kwargs: Any".to_string());
+
+    // Arrays are repeated for each value unless their `collectionFormat` joins them into one.
+    let values = |params: &[&Parameter]| -> Vec<String> {
+        params
+            .iter()
+            .map(|param| {
+                let argument = argument_name(&param.name);
+                match collection_delimiter(param) {
+                    Some(delimiter) => format!(
+                        "\"{}\": None if {} is None else {:?}.join(map(str, {}))",
+                        param.name, argument, delimiter, argument
+                    ),
+                    _ => format!("\"{}\": {}", param.name, argument),
+                }
+            })
+            .collect()
+    };
+    let (cookies, headers): (Vec<&Parameter>, Vec<&Parameter>) =
+        headers.into_iter().partition(|param| param.location == "cookie");
+    let text_parts = text_parts(swagger, operation);
+    let (files, form): (Vec<&Parameter>, Vec<&Parameter>) =
+        form.into_iter().partition(|param| param.parameter_type.as_deref() == Some("file"));
+    // Form fields go as `data=` and files as `files=`, which sends them multipart; a multipart form
+    // without files goes as `files=` of text parts.
+    let mut header_values = values(&headers);
+    if let Some(media_type) = body_media_type(swagger, method, operation) {
+        header_values.insert(0, format!("\"Content-Type\": \"{}\"", media_type));
+    }
+    let mut groups = Vec::new();
+    for (key, values) in [
+        ("params", values(&query)),
+        ("headers", header_values),
+        ("cookies", values(&cookies)),
+        ("data", if text_parts { Vec::new() } else { values(&form) }),
+        ("files", values(&files)),
+    ] {
+        if !values.is_empty() {
+            groups.push(format!("{}={{{}}}", key, values.join(", ")));
+        }
+    }
+    let kwargs =
+        if groups.is_empty() { "kwargs".to_string() } else { format!("_with_params(kwargs, {})", groups.join(", ")) };
+
+    let formatted_path = path_params.iter().fold(path.to_string(), |acc, param| {
+        acc.replace(&format!("{{{}}}", param), &format!("{{urllib.parse.quote(str({}), safe='')}}", identifier(param)))
+    });
+
+    let requirements = security_requirements(swagger, operation);
+    let kwargs = if requirements.is_empty() {
+        format!("**{}", kwargs)
+    } else {
+        let requirements: Vec<String> = requirements
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("[{}]", names.join(", "))
+            })
+            .collect();
+        format!("**_with_auth([{}], {})", requirements.join(", "), kwargs)
+    };
+    let binary = has_binary_response(swagger, operation);
+    let request = format!(
+        "{}_request(\"{}\", {}\"{}\", {}{}{})",
+        if style == PythonStyle::Async { "await " } else { "" },
+        method,
+        if path_params.is_empty() { "" } else { "f" },
+        formatted_path,
+        match () {
+            _ if text_parts => format!("files=_parts({{{}}}), ", values(&form).join(", ")),
+            // httpx takes raw content as `content=`, keeping `data=` for forms.
+            _ if has_body && sends_raw_body(swagger, method, operation) => match style {
+                PythonStyle::Async => "content=data, ".to_string(),
+                _ => "data=data, ".to_string(),
+            },
+            _ if has_body => "json=_dump(data), ".to_string(),
+            _ => String::new(),
+        },
+        if binary { "binary=True, " } else { "" },
+        kwargs
+    );
+
+    let response_schema =
+        success_responses(operation).into_iter().find_map(|(_, response)| response.response_schema.as_ref());
+    // `_parse` builds the class of the items of array responses too.
+    let mut item_schema = response_schema;
+    while let Some(items) = item_schema.and_then(|schema| schema.items.as_deref()) {
+        item_schema = Some(items);
+    }
+    let response_class =
+        item_schema.and_then(|schema| schema.reference.as_deref()).map(|r| identifier(ref_name(r))).filter(|_| !binary);
+    let return_type = match response_schema {
+        _ if binary => "bytes".to_string(),
+        Some(schema) => spec_type(schema),
+        None => "Any".to_string(),
+    };
+
+    let context = json!({
+        "async": style == PythonStyle::Async,
+        "name": function_name,
+        "arguments": arguments.join(", "),
+        "return_type": return_type,
+        "summary": operation.summary.as_ref().map(|summary| summary.replace("\"\"\"", "'''")),
+        "request": request,
+        "response_class": response_class,
+        "operation": operation_context(operation),
+    });
+    templates::render("python/method", &context)
+}
+
+/// Whether an operation sends a multipart form without files, whose fields `_parts` makes text parts of
+/// since `requests` and `httpx` only go multipart for files.
+fn text_parts(swagger: &Swagger, operation: &Operation) -> bool {
+    let form = form_parameters(operation);
+    !form.is_empty()
+        && request_media_type(swagger, operation) == MULTIPART
+        && form.iter().all(|param| param.parameter_type.as_deref() != Some("file"))
+}
+
+/// A keyword argument for a parameter, kept clear of the `data` and `kwargs` of the function.
+fn argument_name(name: &str) -> String {
+    let argument = identifier(&snake_case(name));
+    if argument == "data" || argument == "kwargs" {
+        format!("{}_", argument)
+    } else {
+        argument
+    }
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `str` for parameters the
+/// operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref())) {
+        Some(py_type @ ("int" | "float" | "bool")) => py_type,
+        _ => "str",
+    }
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref().and_then(|items| items.schema_type.as_deref());
+            format!("List[{}]", primitive_type(items))
+        }
+        Some("file") => "Any".to_string(),
+        other => primitive_type(other).to_string(),
+    }
+}
+
+fn property_reference(prop: &Property) -> Option<&str> {
+    prop.additional
+        .get("$ref")
+        .or_else(|| prop.additional.get("items").and_then(|items| items.get("$ref")))
+        .and_then(Value::as_str)
+}
+
+pub fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return identifier(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => format!("List[{}]", prop.additional.get("items").map_or("Any".to_string(), schema_type)),
+        other => primitive_type(other).to_string(),
+    }
+}
+
+/// Python type of a body or response schema.
+fn spec_type(schema: &Schema) -> String {
+    serde_json::to_value(schema).map_or_else(|_| "Any".to_string(), |schema| schema_type(&schema))
+}
+
+pub fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return identifier(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("List[{}]", schema.get("items").map_or("Any".to_string(), schema_type)),
+        other => primitive_type(other).to_string(),
+    }
+}
+
+pub fn primitive_type(schema_type: Option<&str>) -> &'static str {
+    match schema_type {
+        Some("integer") => "int",
+        Some("number") => "float",
+        Some("string") => "str",
+        Some("boolean") => "bool",
+        Some("object") => "Dict[str, Any]",
+        _ => "Any",
+    }
+}
+
+pub fn identifier(name: &str) -> String {
+    let mut identifier: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    if KEYWORDS.contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+    identifier
+}
@@ -0,0 +1,265 @@
+use super::typescript::{
+    error_members, error_type_name, form_name, form_parameters, generate_auth_config, generate_info_comment,
+    generate_interfaces, has_header_parameters, header_parameters, headers_name, import_interfaces, media_headers,
+    non_json_response, operation_doc, parameters_argument, path_arguments, path_template, push_body_encoders, push_doc,
+    push_error_type, push_parameters_interface, push_request_headers_helper, push_retry_helpers, query_parameters,
+    query_params_name, query_values, request_body_reference, request_data, request_data_expression, request_headers,
+    response_references, response_schema_name, service_method_name, PathArgument, TypeOptions,
+};
+use super::{
+    base_url, codecs, collection_delimiter, has_request_body, operations_by_tag, security_requirements,
+    with_custom_region,
+};
+use crate::cli::Language;
+use crate::output::GeneratedFiles;
+use crate::spec::{Operation, Swagger};
+use std::path::PathBuf;
+
+pub fn generate(swagger: &Swagger, types: TypeOptions, retries: Option<u32>) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+
+    generate_interfaces(swagger, types, &mut files);
+    files.insert(PathBuf::from("api.ts"), with_custom_region(generate_api_slice(swagger, types, retries), "//"));
+
+    files
+}
+
+fn generate_api_slice(swagger: &Swagger, types: TypeOptions, retries: Option<u32>) -> String {
+    let tags = operations_by_tag(swagger);
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { createApi, fetchBaseQuery } from '@reduxjs/toolkit/query/react';\n\n");
+
+    let interfaces = tags
+        .values()
+        .flatten()
+        .flat_map(|(_, _, operation)| {
+            let body = request_body_reference(operation).map(str::to_string);
+            body.into_iter().chain(response_references(operation))
+        })
+        .collect();
+    let operations = tags.values().flatten().map(|(_, method, operation)| (*method, *operation));
+    codecs::import_codecs(&mut ts_code, swagger, operations, types, "./codecs");
+    import_interfaces(&mut ts_code, interfaces, "./interfaces");
+
+    if !swagger.security_definitions.is_empty() {
+        ts_code.push_str(&generate_auth_config(swagger, Language::TypeScript, false, false));
+    }
+
+    if has_header_parameters(swagger) {
+        push_request_headers_helper(&mut ts_code, Language::TypeScript, false);
+    }
+    push_body_encoders(&mut ts_code, swagger, Language::TypeScript, false);
+    // `fetchBaseQuery` joins arrays with commas, so the ones sent a value at a time are repeated here.
+    let repeated = tags.values().flatten().flat_map(|(_, _, operation)| query_parameters(operation)).any(|param| {
+        param.parameter_type.as_deref() == Some("array") && collection_delimiter(param).is_none()
+    });
+    let base_query = if repeated {
+        ts_code.push_str(
+            "/** Serializes query parameters, repeating arrays for each value and leaving out unset ones. */
+function searchParams(params: Record<string, unknown>): string {
+    const search = new URLSearchParams();
+    for (const [name, value] of Object.entries(params)) {
+        for (const item of Array.isArray(value) ? value : [value]) {
+            if (item !== undefined && item !== null) {
+                search.append(name, String(item));
+            }
+        }
+    }
+    return search.toString();
+}\n\n",
+        );
+        format!("fetchBaseQuery({{ baseUrl: '{}', paramsSerializer: searchParams }})", base_url(swagger))
+    } else {
+        format!("fetchBaseQuery({{ baseUrl: '{}' }})", base_url(swagger))
+    };
+    let base_query = match retries {
+        Some(retries) => {
+            push_retry_helpers(&mut ts_code, Language::TypeScript, retries, true);
+            ts_code.push_str(&format!(
+                "const fetchQuery = {};
+
+/** Retries queries that failed with a network error, 429 or 5xx, unless they were aborted. */
+const retryingBaseQuery: typeof fetchQuery = async (args, api, extraOptions) => {{
+    for (let attempt = 0; ; attempt++) {{
+        const result = await fetchQuery(args, api, extraOptions);
+        const status = result.error?.status;
+        const retryable = status === 'FETCH_ERROR' || status === 429 || (typeof status === 'number' && status >= 500);
+        if (!retryable || api.signal.aborted || attempt >= MAX_RETRIES) {{
+            return result;
+        }}
+        const retryAfter = result.meta?.response?.headers.get('Retry-After');
+        try {{
+            await sleep(retryDelay(attempt, retryAfter), api.signal);
+        }} catch {{
+            return result;
+        }}
+    }}
+}};\n\n",
+                base_query
+            ));
+            "retryingBaseQuery".to_string()
+        }
+        None => base_query,
+    };
+
+    for (path, method, operation) in tags.values().flatten() {
+        let endpoint_name = service_method_name(method, path, operation);
+        let form = form_parameters(operation);
+        if has_request_body(method) && !form.is_empty() {
+            push_parameters_interface(&mut ts_code, &form_name(&endpoint_name), &form);
+        }
+        let query = query_parameters(operation);
+        if !query.is_empty() {
+            push_parameters_interface(&mut ts_code, &query_params_name(&endpoint_name), &query);
+        }
+        let headers = header_parameters(operation);
+        if !headers.is_empty() {
+            push_parameters_interface(&mut ts_code, &headers_name(&endpoint_name), &headers);
+        }
+        // Shaped like the `FetchBaseQueryError` the hooks report, for narrowing their `error`.
+        let errors = error_members(operation, |status, body| format!("{{ status: {}; data: {} }}", status, body));
+        if !errors.is_empty() {
+            push_error_type(&mut ts_code, &error_type_name(&endpoint_name), &errors);
+        }
+    }
+
+    let tag_types: Vec<String> = tags.keys().map(|tag| format!("'{}'", tag)).collect();
+    ts_code.push_str(&format!(
+        "export const api = createApi({{
+    reducerPath: 'api',
+    baseQuery: {},
+    tagTypes: [{}],
+    endpoints: builder => ({{\n",
+        base_query,
+        tag_types.join(", ")
+    ));
+
+    let mut hooks = Vec::new();
+    for (tag, operations) in &tags {
+        for (path, method, operation) in operations {
+            let (endpoint, hook) = generate_endpoint(swagger, tag, method, path, operation, types);
+            ts_code.push_str(&endpoint);
+            hooks.push(hook);
+        }
+    }
+    ts_code.push_str("    }),\n});\n\n");
+
+    let hooks: Vec<String> = hooks.iter().map(|hook| format!("    {},", hook)).collect();
+    ts_code.push_str(&format!("export const {{\n{}\n}} = api;\n", hooks.join("\n")));
+    ts_code
+}
+
+/// The endpoint definition of an operation and the name of the hook RTK Query derives for it.
+fn generate_endpoint(
+    swagger: &Swagger,
+    tag: &str,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+    types: TypeOptions,
+) -> (String, String) {
+    let path_params = path_arguments(path, operation);
+    let is_query = method == "get";
+    let has_body = has_request_body(method);
+    let endpoint_name = service_method_name(method, path, operation);
+
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+
+    let mut arg_fields: Vec<String> = path_params.iter().map(PathArgument::declaration).collect();
+    let mut arg_names: Vec<String> = path_params.iter().map(|param| param.argument.clone()).collect();
+    if let Some(field) = parameters_argument("params", &query_params_name(&endpoint_name), &query) {
+        arg_fields.push(field);
+        arg_names.push("params".to_string());
+    }
+    if let Some(field) = parameters_argument("headers", &headers_name(&endpoint_name), &headers) {
+        arg_fields.push(field);
+        arg_names.push("headers".to_string());
+    }
+    if has_body {
+        let (body_type, required) = request_data(operation, &endpoint_name);
+        let body_type = body_type.unwrap_or_else(|| "unknown".to_string());
+        arg_fields.push(format!("body{}: {}", if required { "" } else { "?" }, body_type));
+        arg_names.push("body".to_string());
+    }
+    let (arg_type, arg_pattern) = if arg_fields.is_empty() {
+        ("void".to_string(), "()".to_string())
+    } else {
+        (format!("{{ {} }}", arg_fields.join("; ")), format!("({{ {} }})", arg_names.join(", ")))
+    };
+
+    let formatted_path = path_template(path, &path_params);
+    let mut fetch_args = format!("{{ url: `{}`, method: '{}'", formatted_path, method.to_uppercase());
+    if !query.is_empty() {
+        match query_values("params", &query).as_str() {
+            "params" => fetch_args.push_str(", params"),
+            params => fetch_args.push_str(&format!(", params: {}", params)),
+        }
+    }
+    let media = media_headers(swagger, method, operation);
+    if !media.is_empty() {
+        let mut entries = media;
+        if !headers.is_empty() {
+            entries.push(format!("...{}", request_headers(&headers)));
+        }
+        fetch_args.push_str(&format!(", headers: {{ {} }}", entries.join(", ")));
+    } else if !headers.is_empty() {
+        fetch_args.push_str(&format!(", headers: {}", request_headers(&headers)));
+    }
+    if has_body {
+        match request_data_expression(swagger, operation, "body", types).as_str() {
+            "body" => fetch_args.push_str(", body"),
+            body => fetch_args.push_str(&format!(", body: {}", body)),
+        }
+    }
+    match non_json_response(swagger, operation) {
+        Some("blob") => fetch_args.push_str(", responseHandler: response => response.blob()"),
+        Some(_) => fetch_args.push_str(", responseHandler: 'text'"),
+        None => {}
+    }
+    fetch_args.push_str(" }");
+
+    let requirements: Vec<String> = security_requirements(swagger, operation)
+        .iter()
+        .map(|names| {
+            let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+            format!("[{}]", names.join(", "))
+        })
+        .collect();
+    let request = if swagger.security_definitions.is_empty() || requirements.is_empty() {
+        format!("({})", fetch_args)
+    } else {
+        format!("withAuth([{}], {})", requirements.join(", "), fetch_args)
+    };
+
+    let transform = codecs::response_decoder(swagger, operation, types)
+        .map(|decoder| format!("            transformResponse: {},\n", decoder))
+        .unwrap_or_default();
+
+    let mut ts_code = String::new();
+    push_doc(&mut ts_code, &operation_doc(operation), "        ");
+    ts_code.push_str(&format!(
+        "        {}: builder.{}<{}, {}>({{
+            query: {} => {},
+{}            {}: ['{}'],
+        }}),\n",
+        endpoint_name,
+        if is_query { "query" } else { "mutation" },
+        response_schema_name(swagger, operation),
+        arg_type,
+        arg_pattern,
+        request,
+        transform,
+        if is_query { "providesTags" } else { "invalidatesTags" },
+        tag
+    ));
+
+    let mut chars = endpoint_name.chars();
+    let hook = match chars.next() {
+        Some(c) => format!("use{}{}{}", c.to_ascii_uppercase(), chars.as_str(), if is_query { "Query" } else { "Mutation" }),
+        None => String::new(),
+    };
+    (ts_code, hook)
+}
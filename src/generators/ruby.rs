@@ -0,0 +1,618 @@
+use super::typescript::{form_parameters, header_parameters, query_parameters};
+use super::{
+    base_url, body_media_type, collection_delimiter, enum_members, extract_path_params, flattened_fields,
+    has_binary_response, has_request_body, info_lines, operation_name, operations_by_tag, package_version, pascal_case,
+    request_media_type, response_reference, security_requirements, security_schemes, snake_case, with_custom_region,
+    MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "alias", "and", "begin", "break", "case", "class", "def", "defined", "do", "else", "elsif", "end", "ensure",
+    "false", "for", "if", "in", "module", "next", "nil", "not", "or", "redo", "rescue", "retry", "return", "self",
+    "super", "then", "true", "undef", "unless", "until", "when", "while", "yield",
+];
+
+/// Methods every object has, which attributes and operations named like them would override.
+const OBJECT_METHODS: &[&str] = &[
+    "binding", "class", "clone", "display", "dup", "extend", "format", "freeze", "hash", "initialize", "inspect",
+    "instance_variables", "itself", "lambda", "loop", "method", "methods", "object_id", "p", "print", "proc",
+    "public_send", "puts", "raise", "require", "send", "singleton_class", "sleep", "tap", "to_h", "to_s",
+];
+
+/// Methods of the generated client that its operations must not replace.
+const CLIENT_METHODS: &[&str] = &["connection", "request", "set_auth"];
+
+/// How a value of a schema is represented in Ruby, which decides how it is read from and written to hashes.
+enum RubyType {
+    Model(String),
+    List(Box<RubyType>),
+    Plain,
+}
+
+impl RubyType {
+    fn has_models(&self) -> bool {
+        match self {
+            RubyType::Model(_) => true,
+            RubyType::List(item) => item.has_models(),
+            RubyType::Plain => false,
+        }
+    }
+
+    /// Expression converting the possibly nil parsed JSON `value` into this type.
+    fn decode(&self, value: &str) -> String {
+        match self {
+            RubyType::Model(name) => format!("{0} && {1}.from_h({0})", value, name),
+            RubyType::List(item) if item.has_models() => {
+                format!("{}&.map {{ |item| {} }}", value, item.decode("item"))
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// Expression converting the possibly nil `value` of this type into a JSON-encodable hash.
+    fn encode(&self, value: &str) -> String {
+        match self {
+            RubyType::Model(_) => format!("{}&.to_h", value),
+            RubyType::List(item) if item.has_models() => {
+                format!("{}&.map {{ |item| {} }}", value, item.encode("item"))
+            }
+            _ => value.to_string(),
+        }
+    }
+}
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let module = module_name(swagger);
+    let gem = snake_case(&module);
+    let lib_dir = PathBuf::from("lib").join(&gem);
+
+    let mut files = GeneratedFiles::new();
+    let gemspec = generate_gemspec(swagger, &module, &gem, retries.is_some());
+    files.insert(PathBuf::from(format!("{}.gemspec", gem)), gemspec);
+    files.insert(PathBuf::from("lib").join(format!("{}.rb", gem)), generate_entry(swagger, &gem, retries.is_some()));
+    files.insert(lib_dir.join("client.rb"), with_custom_region(generate_client(swagger, &module, retries), "#"));
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = lib_dir.join("models").join(format!("{}.rb", snake_case(name)));
+        (path, generate_model(swagger, &module, name, definition))
+    }));
+    files.par_extend(operations_by_tag(swagger).into_par_iter().map(|(tag, operations)| {
+        let path = lib_dir.join("api").join(format!("{}_api.rb", snake_case(&tag)));
+        (path, generate_api_module(swagger, &module, &tag, &operations))
+    }));
+    files
+}
+
+fn module_name(swagger: &Swagger) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    match pascal_case(title) {
+        module if module.starts_with(|c: char| c.is_ascii_alphabetic()) => module,
+        _ => "ApiClient".to_string(),
+    }
+}
+
+fn generate_header(swagger: &Swagger) -> String {
+    let mut rb_code = String::from("# frozen_string_literal: true\n\n");
+    for line in info_lines(swagger) {
+        rb_code.push_str(&format!("# {}\n", line));
+    }
+    rb_code.push('\n');
+    rb_code
+}
+
+/// Whether any operation sends a `multipart/form-data` body, which takes the faraday-multipart middleware.
+fn has_multipart(swagger: &Swagger) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        !form_parameters(operation).is_empty() && request_media_type(swagger, operation) == MULTIPART
+    })
+}
+
+fn generate_gemspec(swagger: &Swagger, module: &str, gem: &str, retry: bool) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or(module);
+    let mut dependencies = String::new();
+    if has_multipart(swagger) {
+        dependencies.push_str("  spec.add_dependency \"faraday-multipart\", \"~> 1.0\"\n");
+    }
+    if retry {
+        dependencies.push_str("  spec.add_dependency \"faraday-retry\", \"~> 2.0\"\n");
+    }
+    format!(
+        "# frozen_string_literal: true
+
+Gem::Specification.new do |spec|
+  spec.name = \"{}\"
+  spec.version = \"{}\"
+  spec.summary = \"Generated client for {}\"
+  spec.authors = [\"swagger-generator\"]
+  spec.files = Dir[\"lib/**/*.rb\"]
+  spec.required_ruby_version = \">= 3.2\"
+
+  spec.add_dependency \"faraday\", \"~> 2.0\"
+{}end
+",
+        gem,
+        package_version(swagger),
+        title.replace('"', "\\\""),
+        dependencies
+    )
+}
+
+fn generate_entry(swagger: &Swagger, gem: &str, retry: bool) -> String {
+    let mut rb_code = generate_header(swagger);
+    rb_code.push_str("require \"faraday\"\n");
+    if has_multipart(swagger) {
+        rb_code.push_str("require \"faraday/multipart\"\n");
+    }
+    if retry {
+        rb_code.push_str("require \"faraday/retry\"\n");
+    }
+    rb_code.push_str("require \"uri\"\n\n");
+    for name in swagger.definitions.keys() {
+        rb_code.push_str(&format!("require_relative \"{}/models/{}\"\n", gem, snake_case(name)));
+    }
+    for tag in operations_by_tag(swagger).keys() {
+        rb_code.push_str(&format!("require_relative \"{}/api/{}_api\"\n", gem, snake_case(tag)));
+    }
+    rb_code.push_str(&format!("require_relative \"{}/client\"\n", gem));
+    rb_code
+}
+
+fn generate_model(swagger: &Swagger, module: &str, name: &str, definition: &Definition) -> String {
+    let mut rb_code = generate_header(swagger);
+    if let Some(values) = enum_values(definition) {
+        rb_code.push_str(&generate_enum(module, name, &values));
+        return rb_code;
+    }
+    let properties: Vec<(&String, String, RubyType, bool)> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| (prop_name, identifier(prop_name), property_type(swagger, prop), required))
+        .collect();
+
+    rb_code.push_str(&format!("module {}\n  class {}\n", module, type_name(name)));
+    if properties.is_empty() {
+        rb_code.push_str(
+            "    def self.from_h(_hash)
+      new
+    end
+
+    def to_h
+      {}
+    end
+  end
+end
+",
+        );
+        return rb_code;
+    }
+
+    let attributes: Vec<String> = properties.iter().map(|(_, attribute, _, _)| format!(":{}", attribute)).collect();
+    rb_code.push_str(&format!("    attr_accessor {}\n\n", attributes.join(", ")));
+
+    let arguments: Vec<String> = properties
+        .iter()
+        .map(|(_, attribute, _, required)| match required {
+            true => format!("{}:", attribute),
+            false => format!("{}: nil", attribute),
+        })
+        .collect();
+    rb_code.push_str(&format!("    def initialize({})\n", arguments.join(", ")));
+    for (_, attribute, _, _) in &properties {
+        rb_code.push_str(&format!("      @{0} = {0}\n", attribute));
+    }
+    rb_code.push_str("    end\n\n");
+
+    rb_code.push_str("    def self.from_h(hash)\n      new(\n");
+    for (prop_name, attribute, ruby_type, _) in &properties {
+        let value = format!("hash[\"{}\"]", prop_name);
+        rb_code.push_str(&format!("        {}: {},\n", attribute, ruby_type.decode(&value)));
+    }
+    rb_code.push_str("      )\n    end\n\n");
+
+    rb_code.push_str("    def to_h\n      {\n");
+    for (prop_name, attribute, ruby_type, _) in &properties {
+        rb_code.push_str(&format!("        \"{}\" => {},\n", prop_name, ruby_type.encode(&format!("@{}", attribute))));
+    }
+    rb_code.push_str("      }.compact\n    end\n  end\nend\n");
+    rb_code
+}
+
+/// The string and number values of an enum definition, which become the constants of a module
+/// while the values themselves stay plain strings and numbers.
+fn enum_values(definition: &Definition) -> Option<Vec<Value>> {
+    let values = definition.enum_values.as_ref()?;
+    let values: Vec<Value> = values.iter().filter(|value| value.is_string() || value.is_number()).cloned().collect();
+    Some(values).filter(|values| !values.is_empty())
+}
+
+fn generate_enum(module: &str, name: &str, values: &[Value]) -> String {
+    let constant = |words: &[String]| match words.join("_").to_uppercase() {
+        constant if constant.starts_with(|c: char| c.is_ascii_digit()) => format!("VALUE_{}", constant),
+        constant => constant,
+    };
+    let mut rb_code = format!("module {}\n  module {}\n", module, type_name(name));
+    for (constant, value) in enum_members(values, constant) {
+        // JSON string literals are valid Ruby ones once `#` cannot start an interpolation.
+        let literal = value.to_string().replace('#', "\\#");
+        rb_code.push_str(&format!("    {} = {}\n", constant, literal));
+    }
+    rb_code.push_str("  end\nend\n");
+    rb_code
+}
+
+fn generate_client(swagger: &Swagger, module: &str, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    let has_query = has_parameters(swagger, "query");
+    let has_headers = has_parameters(swagger, "header");
+    let has_cookies = has_parameters(swagger, "cookie");
+    let has_forms = has_parameters(swagger, "formData");
+    let has_media_types = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(method, operation)| body_media_type(swagger, method, operation).is_some());
+    let mut base = base_url(swagger);
+    if !base.ends_with('/') {
+        base.push('/');
+    }
+
+    let mut rb_code = generate_header(swagger);
+    rb_code.push_str(&format!("module {}\n  class Client\n", module));
+    for tag in operations_by_tag(swagger).keys() {
+        rb_code.push_str(&format!("    include {}Api\n", type_name(tag)));
+    }
+    rb_code.push_str(&format!("\n    DEFAULT_BASE_URL = \"{}\"\n", base));
+    if let Some(retries) = retries {
+        rb_code.push_str(&format!("    MAX_RETRIES = {}\n", retries));
+    }
+    if has_auth {
+        rb_code.push_str("\n    SECURITY_SCHEMES = {\n");
+        for (name, scheme) in security_schemes(swagger) {
+            rb_code.push_str(&format!(
+                "      \"{}\" => [\"{}\", \"{}\", \"{}\"],\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        rb_code.push_str("    }.freeze\n");
+    }
+    rb_code.push_str(
+        "
+    attr_reader :connection
+
+    def initialize(base_url: DEFAULT_BASE_URL, connection: nil)
+      @connection = connection || Faraday.new(url: base_url) do |faraday|\n",
+    );
+    if has_multipart(swagger) {
+        rb_code.push_str("        faraday.request :multipart\n");
+    }
+    rb_code.push_str(
+        "        faraday.request :json
+        faraday.response :json
+        faraday.response :raise_error\n",
+    );
+    // Registered after raise_error so it sees 429 and 5xx responses before they are raised; faraday-retry waits
+    // as long as Retry-After asks on its own.
+    if retries.is_some() {
+        rb_code.push_str(
+            "        faraday.request :retry,
+                        max: MAX_RETRIES,
+                        interval: 0.5,
+                        backoff_factor: 2,
+                        methods: %i[delete get head options patch post put],
+                        retry_statuses: [429, *500..599],
+                        exceptions: [*Faraday::Retry::Middleware::DEFAULT_EXCEPTIONS, Faraday::ConnectionFailed]\n",
+        );
+    }
+    rb_code.push_str("      end\n");
+    if has_auth {
+        rb_code.push_str(
+            "      @credentials = {}
+    end
+
+    # Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.
+    def set_auth(scheme, credential)
+      @credentials[scheme] = credential
+    end\n",
+        );
+    } else {
+        rb_code.push_str("    end\n");
+    }
+
+    let mut request_params = vec!["method", "path", "body: nil"];
+    if has_query {
+        request_params.push("query: {}");
+    }
+    if has_headers {
+        request_params.push("headers: {}");
+    }
+    if has_cookies {
+        request_params.push("cookies: {}");
+    }
+    if has_forms {
+        request_params.push("form: nil");
+        request_params.push("multipart: false");
+    }
+    if has_media_types {
+        request_params.push("content_type: nil");
+    }
+    if has_auth {
+        request_params.push("requirements: []");
+    }
+    rb_code.push_str(&format!("\n    private\n\n    def request({})\n", request_params.join(", ")));
+    if !has_auth && !has_query && !has_headers && !has_cookies && !has_forms && !has_media_types {
+        rb_code.push_str(
+            "      connection.run_request(method, path.delete_prefix(\"/\"), body, nil).body
+    end
+  end
+end
+",
+        );
+        return rb_code;
+    }
+    rb_code
+        .push_str("      response = connection.run_request(method, path.delete_prefix(\"/\"), body, nil) do |req|\n");
+    // Parameters left out are nil and not sent; arrays repeat the query parameter for each element.
+    if has_query {
+        rb_code.push_str(
+            "        req.options.params_encoder = Faraday::FlatParamsEncoder
+        query.each { |name, value| req.params[name] = value unless value.nil? }\n",
+        );
+    }
+    if has_headers {
+        rb_code.push_str(
+            "        headers.each { |name, value| req.headers[name] = Array(value).join(\",\") unless value.nil? }\n",
+        );
+    }
+    if has_auth {
+        rb_code.push_str(
+            "        names = requirements.find { |requirement| requirement.all? { |name| @credentials.key?(name) } }
+        names&.each do |name|
+          type, location, key = SECURITY_SCHEMES.fetch(name)
+          credential = @credentials.fetch(name)
+          if type == \"basic\"
+            req.headers[\"Authorization\"] = \"Basic #{[credential].pack(\"m0\")}\"
+          elsif type == \"apiKey\" && location == \"query\"
+            req.params[key] = credential
+          elsif type == \"apiKey\" && location == \"cookie\"
+            req.headers[\"Cookie\"] = \"#{key}=#{credential}\"
+          elsif type == \"apiKey\"
+            req.headers[key] = credential
+          else
+            req.headers[\"Authorization\"] = \"Bearer #{credential}\"
+          end
+        end\n",
+        );
+    }
+    if has_cookies {
+        rb_code.push_str(
+            "        cookie = cookies.reject { |_, value| value.nil? }.map { |name, value| \"#{name}=#{value}\" }
+        req.headers[\"Cookie\"] = [req.headers[\"Cookie\"], *cookie].compact.join(\"; \") unless cookie.empty?\n",
+        );
+    }
+    // The json middleware only encodes application/json bodies, so other JSON media types are encoded here.
+    if has_media_types {
+        rb_code.push_str(
+            "        unless content_type.nil?
+          req.headers[\"Content-Type\"] = content_type
+          req.body = JSON.generate(body) if content_type.include?(\"json\") && !body.nil? && !body.is_a?(String)
+        end\n",
+        );
+    }
+    // Fields left out are nil and not sent; files are IO objects, which go up as file parts.
+    if has_forms {
+        rb_code.push_str(
+            "        unless form.nil?
+          fields = form.reject { |_, value| value.nil? }
+          if multipart
+            req.headers[\"Content-Type\"] = \"multipart/form-data\"
+            req.body = fields.transform_values do |value|
+              value.respond_to?(:read) ? Faraday::Multipart::FilePart.new(value, \"application/octet-stream\") : value
+            end
+          else
+            req.headers[\"Content-Type\"] = \"application/x-www-form-urlencoded\"
+            req.body = URI.encode_www_form(fields)
+          end
+        end\n",
+        );
+    }
+    rb_code.push_str(
+        "      end
+      response.body
+    end
+  end
+end
+",
+    );
+    rb_code
+}
+
+/// Whether any operation takes a parameter in `location`, which `request` then accepts.
+fn has_parameters(swagger: &Swagger, location: &str) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        let params = [query_parameters(operation), header_parameters(operation), form_parameters(operation)];
+        params.iter().flatten().any(|param| param.location == location)
+    })
+}
+
+fn generate_api_module(
+    swagger: &Swagger,
+    module: &str,
+    tag: &str,
+    operations: &[(&str, &str, &Operation)],
+) -> String {
+    let mut rb_code = generate_header(swagger);
+    rb_code.push_str(&format!("module {}\n  module {}Api\n", module, type_name(tag)));
+    let methods: Vec<String> = operations
+        .iter()
+        .map(|(path, method, operation)| generate_api_method(swagger, method, path, operation))
+        .collect();
+    rb_code.push_str(&methods.join("\n"));
+    rb_code.push_str("  end\nend\n");
+    rb_code
+}
+
+fn generate_api_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = method_name(&operation_name(method, path, operation));
+    let form = form_parameters(operation);
+    let has_body = has_request_body(method) && form.is_empty();
+
+    let mut arguments: Vec<String> = path_params.iter().map(|param| argument_name(param)).collect();
+    if has_body {
+        arguments.push("body = nil".to_string());
+    }
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation));
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) =
+        params.iter().chain(&form).partition(|param| param.required);
+    arguments.extend(required.iter().map(|param| format!("{}:", argument_name(&param.name))));
+    arguments.extend(optional.iter().map(|param| format!("{}: nil", argument_name(&param.name))));
+
+    let rb_path = path_params.iter().fold(path.to_string(), |acc, param| {
+        acc.replace(&format!("{{{}}}", param), &format!("#{{URI.encode_uri_component({})}}", argument_name(param)))
+    });
+    let mut request_arguments = vec![format!(":{}", method), format!("\"{}\"", rb_path)];
+    if has_body {
+        request_arguments.push("body: body".to_string());
+    }
+    if let Some(media_type) = body_media_type(swagger, method, operation) {
+        request_arguments.push(format!("content_type: \"{}\"", media_type));
+    }
+    for (label, location) in [("query", "query"), ("headers", "header"), ("cookies", "cookie")] {
+        let values: Vec<String> = params
+            .iter()
+            .filter(|param| param.location == location)
+            .map(|param| format!("\"{}\" => {}", param.name, parameter_value(param)))
+            .collect();
+        if !values.is_empty() {
+            request_arguments.push(format!("{}: {{ {} }}", label, values.join(", ")));
+        }
+    }
+    if !form.is_empty() {
+        let fields: Vec<String> =
+            form.iter().map(|param| format!("\"{}\" => {}", param.name, argument_name(&param.name))).collect();
+        request_arguments.push(format!("form: {{ {} }}", fields.join(", ")));
+        if request_media_type(swagger, operation) == MULTIPART {
+            request_arguments.push("multipart: true".to_string());
+        }
+    }
+    if !swagger.security_definitions.is_empty() {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("[{}]", names.join(", "))
+            })
+            .collect();
+        if !requirements.is_empty() {
+            request_arguments.push(format!("requirements: [{}]", requirements.join(", ")));
+        }
+    }
+    let request = format!("request({})", request_arguments.join(", "));
+    // Faraday only parses JSON responses, so file downloads come back as the raw body string.
+    let body = match response_reference(operation) {
+        _ if has_binary_response(swagger, operation) => request,
+        Some(reference) => match reference_type(swagger, reference) {
+            RubyType::Model(model) => format!("{}.from_h({})", model, request),
+            _ => request,
+        },
+        None => request,
+    };
+
+    let mut rb_code = String::new();
+    if let Some(summary) = &operation.summary {
+        rb_code.push_str(&format!("    # {}\n", summary));
+    }
+    if operation.deprecated {
+        rb_code.push_str("    # @deprecated\n");
+    }
+    let signature = if arguments.is_empty() { String::new() } else { format!("({})", arguments.join(", ")) };
+    rb_code.push_str(&format!("    def {}{}\n      {}\n    end\n", method_name, signature, body));
+    rb_code
+}
+
+/// The type of the definition `reference` points to, plain for the enums whose values are kept as is.
+fn reference_type(swagger: &Swagger, reference: &str) -> RubyType {
+    let name = ref_name(reference);
+    match swagger.definitions.get(name).and_then(enum_values) {
+        Some(_) => RubyType::Plain,
+        None => RubyType::Model(type_name(name)),
+    }
+}
+
+fn property_type(swagger: &Swagger, prop: &Property) -> RubyType {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => RubyType::List(Box::new(
+            prop.additional.get("items").map_or(RubyType::Plain, |items| schema_type(swagger, items)),
+        )),
+        _ => RubyType::Plain,
+    }
+}
+
+fn schema_type(swagger: &Swagger, schema: &Value) -> RubyType {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return reference_type(swagger, reference);
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => {
+            RubyType::List(Box::new(schema.get("items").map_or(RubyType::Plain, |items| schema_type(swagger, items))))
+        }
+        _ => RubyType::Plain,
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn identifier(name: &str) -> String {
+    match snake_case(name) {
+        identifier if identifier.is_empty() => "value".to_string(),
+        identifier if identifier.starts_with(|c: char| c.is_ascii_digit()) => format!("_{}", identifier),
+        identifier if KEYWORDS.contains(&identifier.as_str()) || OBJECT_METHODS.contains(&identifier.as_str()) => {
+            format!("{}_", identifier)
+        }
+        identifier => identifier,
+    }
+}
+
+fn method_name(name: &str) -> String {
+    match identifier(name) {
+        method_name if CLIENT_METHODS.contains(&method_name.as_str()) => format!("{}_", method_name),
+        method_name => method_name,
+    }
+}
+
+/// The value a parameter is sent as: its argument, with an array joined by the delimiter of its
+/// `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    let argument = argument_name(&param.name);
+    match collection_delimiter(param) {
+        Some(delimiter) => {
+            let call = if param.required { "." } else { "&." };
+            format!("{}{}join({:?})", argument, call, delimiter)
+        }
+        None => argument,
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    match identifier(name) {
+        argument if argument == "body" => "body_param".to_string(),
+        argument => argument,
+    }
+}
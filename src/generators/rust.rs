@@ -0,0 +1,615 @@
+use super::typescript::{enum_member, form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, body_media_type, collection_delimiter, contains_by_value, enum_members, extract_path_params,
+    flattened_fields, has_binary_response, has_binary_responses, info_lines, operation_name, package_version,
+    pascal_case, request_media_type, security_requirements, security_schemes, sends_raw_body, snake_case,
+    success_responses, with_custom_region, words, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct",
+    "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+const ERROR_TYPE: &str = r#"#[derive(Debug)]
+pub enum ApiError {
+    Request(reqwest::Error),
+    Decode(serde_json::Error),
+    Status { status: reqwest::StatusCode, body: String },
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::Request(err) => write!(f, "request failed: {}", err),
+            ApiError::Decode(err) => write!(f, "invalid response body: {}", err),
+            ApiError::Status { status, body } => write!(f, "unexpected status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(err: reqwest::Error) -> Self {
+        ApiError::Request(err)
+    }
+}
+"#;
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("Cargo.toml"), generate_manifest(swagger, retries.is_some()));
+    files.insert(PathBuf::from("src").join("lib.rs"), with_custom_region(generate_lib(swagger, retries), "//"));
+    files
+}
+
+fn generate_manifest(swagger: &Swagger, retry: bool) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    let name = match words(title).join("-") {
+        name if name.is_empty() => "api-client".to_string(),
+        name => format!("{}-client", name),
+    };
+    let version = package_version(swagger);
+    let multipart = swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        !form_parameters(operation).is_empty() && request_media_type(swagger, operation) == MULTIPART
+    });
+
+    format!(
+        "[package]
+name = \"{}\"
+version = \"{}\"
+edition = \"2021\"
+
+[dependencies]
+reqwest = {{ version = \"0.11\", features = [{}] }}
+serde = {{ version = \"1\", features = [\"derive\"] }}
+serde_json = \"1\"
+{}{}",
+        name,
+        version,
+        if multipart { "\"json\", \"multipart\"" } else { "\"json\"" },
+        if encodes_path(swagger) { "percent-encoding = \"2\"\n" } else { "" },
+        if retry { "httpdate = \"1\"\ntokio = { version = \"1\", features = [\"time\"] }\n" } else { "" }
+    )
+}
+
+fn generate_lib(swagger: &Swagger, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+
+    let mut rs_code = String::new();
+    for line in info_lines(swagger) {
+        rs_code.push_str(&format!("//! {}\n", line));
+    }
+    rs_code.push('\n');
+    if encodes_path(swagger) {
+        rs_code.push_str("use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};\n");
+    }
+    rs_code.push_str("use serde::de::DeserializeOwned;\n");
+    if !swagger.definitions.is_empty() {
+        rs_code.push_str("use serde::{Deserialize, Serialize};\n");
+    }
+    rs_code.push('\n');
+    rs_code.push_str(&format!("pub const DEFAULT_BASE_URL: &str = \"{}\";\n\n", base_url(swagger)));
+    if let Some(retries) = retries {
+        rs_code.push_str(&format!("pub const MAX_RETRIES: u32 = {};\n\n", retries));
+    }
+    rs_code.push_str(ERROR_TYPE);
+
+    for (name, definition) in &swagger.definitions {
+        rs_code.push('\n');
+        rs_code.push_str(&generate_struct(swagger, name, definition));
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            if let Some(params_struct) = generate_params_struct(method, path, operation) {
+                rs_code.push('\n');
+                rs_code.push_str(&params_struct);
+            }
+        }
+    }
+
+    if has_auth {
+        rs_code.push('\n');
+        rs_code.push_str(&generate_auth_config(swagger));
+    }
+
+    rs_code.push_str("\n#[derive(Debug, Clone)]\npub struct ApiClient {\n");
+    rs_code.push_str("    base_url: String,\n    client: reqwest::Client,\n");
+    if has_auth {
+        rs_code.push_str("    auth: AuthConfig,\n");
+    }
+    rs_code.push_str("}\n\n");
+
+    rs_code.push_str("impl Default for ApiClient {\n    fn default() -> Self {\n        Self::new()\n    }\n}\n\n");
+
+    rs_code.push_str("impl ApiClient {\n");
+    rs_code.push_str(
+        "    pub fn new() -> Self {
+        Self::with_client(DEFAULT_BASE_URL, reqwest::Client::new())
+    }
+
+    pub fn with_client(base_url: impl Into<String>, client: reqwest::Client) -> Self {
+        ApiClient {
+            base_url: base_url.into(),
+            client,\n",
+    );
+    if has_auth {
+        rs_code.push_str("            auth: AuthConfig::default(),\n");
+    }
+    rs_code.push_str("        }\n    }\n\n");
+    if has_auth {
+        rs_code.push_str(
+            "    pub fn with_auth(mut self, auth: AuthConfig) -> Self {
+        self.auth = auth;
+        self
+    }\n\n",
+        );
+        rs_code.push_str(&generate_authorize(swagger));
+    }
+    let send = if retries.is_some() { "self.send_with_retries(request)" } else { "request.send()" };
+    if has_binary_responses(swagger) {
+        // File downloads skip the JSON decoding and return the body as is.
+        rs_code.push_str(&format!(
+            "    async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T, ApiError> {{
+        let bytes = self.send_bytes(request).await?;
+        let bytes: &[u8] = if bytes.is_empty() {{ b\"null\" }} else {{ &bytes }};
+        serde_json::from_slice(bytes).map_err(ApiError::Decode)
+    }}
+
+    async fn send_bytes(&self, request: reqwest::RequestBuilder) -> Result<Vec<u8>, ApiError> {{
+        let response = {}.await?;
+        let status = response.status();
+        if !status.is_success() {{
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError::Status {{ status, body }});
+        }}
+        Ok(response.bytes().await?.to_vec())
+    }}\n",
+            send
+        ));
+    } else {
+        rs_code.push_str(&format!(
+            "    async fn send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder) -> Result<T, ApiError> {{
+        let response = {}.await?;
+        let status = response.status();
+        if !status.is_success() {{
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError::Status {{ status, body }});
+        }}
+        let bytes = response.bytes().await?;
+        let bytes: &[u8] = if bytes.is_empty() {{ b\"null\" }} else {{ &bytes }};
+        serde_json::from_slice(bytes).map_err(ApiError::Decode)
+    }}\n",
+            send
+        ));
+    }
+    if retries.is_some() {
+        rs_code.push_str(
+            "
+    /// Retries requests that failed with a network error, 429 or 5xx, waiting as long as
+    /// `Retry-After` asks or otherwise doubling the delay from half a second.
+    async fn send_with_retries(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, reqwest::Error> {
+        let mut attempt = 0;
+        loop {
+            let Some(retry) = request.try_clone().filter(|_| attempt < MAX_RETRIES) else {
+                return request.send().await;
+            };
+            let retry_after = match retry.send().await {
+                Ok(response) if response.status() != 429 && !response.status().is_server_error() => return Ok(response),
+                Ok(response) => response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| match value.parse() {
+                        Ok(seconds) => Some(std::time::Duration::from_secs(seconds)),
+                        Err(_) => {
+                            let date = httpdate::parse_http_date(value).ok()?;
+                            Some(date.duration_since(std::time::SystemTime::now()).unwrap_or_default())
+                        }
+                    }),
+                Err(err) if err.is_builder() => return Err(err),
+                Err(_) => None,
+            };
+            let delay = retry_after.unwrap_or(std::time::Duration::from_millis(500 << attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }\n",
+        );
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            rs_code.push('\n');
+            rs_code.push_str(&generate_client_method(swagger, method, path, operation));
+        }
+    }
+    rs_code.push_str("}\n");
+
+    rs_code
+}
+
+pub fn generate_struct(swagger: &Swagger, name: &str, definition: &Definition) -> String {
+    let struct_name = type_name(name);
+    if let Some(values) = &definition.enum_values {
+        return generate_enum(&struct_name, definition, values);
+    }
+    let mut rs_code = String::from("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n");
+    rs_code.push_str(&format!("pub struct {} {{\n", struct_name));
+    for (prop_name, prop, required) in flattened_fields(swagger, name, definition) {
+        let mut rs_type = property_type(prop);
+        let target = prop.additional.get("$ref").and_then(Value::as_str).map(ref_name);
+        if target.is_some_and(|target| contains_by_value(swagger, target, name)) {
+            rs_type = format!("Box<{}>", rs_type);
+        }
+        let field_name = field_name(prop_name);
+
+        let mut attributes = Vec::new();
+        if field_name.trim_start_matches("r#") != prop_name {
+            attributes.push(format!("rename = \"{}\"", prop_name));
+        }
+        if !required {
+            attributes.push("default".to_string());
+            attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+            rs_type = format!("Option<{}>", rs_type);
+        } else if has_flag(prop, "nullable") || has_flag(prop, "x-nullable") {
+            // A required field that may be null is always sent, as `null` when it is.
+            rs_type = format!("Option<{}>", rs_type);
+        }
+        if !attributes.is_empty() {
+            rs_code.push_str(&format!("    #[serde({})]\n", attributes.join(", ")));
+        }
+        rs_code.push_str(&format!("    pub {}: {},\n", field_name, rs_type));
+    }
+    rs_code.push_str("}\n");
+    rs_code
+}
+
+/// A string enum as a Rust enum, and other enums as an alias of their primitive type.
+fn generate_enum(enum_name: &str, definition: &Definition, values: &[Value]) -> String {
+    let strings: Vec<Value> = values.iter().filter(|value| value.is_string()).cloned().collect();
+    if !matches!(definition.definition_type.as_deref(), Some("string") | None) || strings.is_empty() {
+        let primitive = primitive_type(definition.definition_type.as_deref(), None);
+        return format!("pub type {} = {};\n", enum_name, primitive);
+    }
+    let mut rs_code = String::from("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]\n");
+    rs_code.push_str(&format!("pub enum {} {{\n", enum_name));
+    for (variant, value) in enum_members(&strings, |words| enum_member(&words.join("_"))) {
+        let value = value.as_str().unwrap_or_default();
+        if variant != value {
+            let literal = value.replace('\\', "\\\\").replace('"', "\\\"");
+            rs_code.push_str(&format!("    #[serde(rename = \"{}\")]\n", literal));
+        }
+        rs_code.push_str(&format!("    {},\n", variant));
+    }
+    rs_code.push_str("}\n");
+    rs_code
+}
+
+/// The struct holding the query, header, cookie and form parameters of an operation, if it takes any.
+fn generate_params_struct(method: &str, path: &str, operation: &Operation) -> Option<String> {
+    let params = parameters(operation);
+    if params.is_empty() {
+        return None;
+    }
+    let mut rs_code = String::from("#[derive(Debug, Clone, Default)]\n");
+    rs_code.push_str(&format!("pub struct {} {{\n", params_name(method, path, operation)));
+    for param in params {
+        let rs_type = parameter_type(param);
+        if param.required || param.parameter_type.as_deref() == Some("array") {
+            rs_code.push_str(&format!("    pub {}: {},\n", field_name(&param.name), rs_type));
+        } else {
+            rs_code.push_str(&format!("    pub {}: Option<{}>,\n", field_name(&param.name), rs_type));
+        }
+    }
+    rs_code.push_str("}\n");
+    Some(rs_code)
+}
+
+fn parameters(operation: &Operation) -> Vec<&Parameter> {
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation));
+    params.extend(form_parameters(operation));
+    params
+}
+
+fn params_name(method: &str, path: &str, operation: &Operation) -> String {
+    format!("{}Params", type_name(&operation_name(method, path, operation)))
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            format!("Vec<{}>", items_type)
+        }
+        Some("file") => "Vec<u8>".to_string(),
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+/// Whether a method takes a string path parameter, which it percent-encodes.
+fn encodes_path(swagger: &Swagger) -> bool {
+    swagger.paths.iter().any(|(path, path_item)| {
+        path_item.operations().into_iter().any(|(_, operation)| {
+            extract_path_params(path).iter().any(|param| path_parameter_type(operation, param) == "&str")
+        })
+    })
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `&str` for strings and
+/// parameters the operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(rs_type @ ("i32" | "i64" | "f32" | "f64" | "bool")) => rs_type,
+        _ => "&str",
+    }
+}
+
+/// Statement adding a parameter from `params` to the request, or to the `form` sent as its body,
+/// skipping it when unset.
+fn apply_parameter(param: &Parameter, multipart: bool) -> String {
+    let field = format!("params.{}", field_name(&param.name));
+    let rs_type = parameter_type(param);
+    let apply = |value: &str| match param.location.as_str() {
+        "query" => format!("request = request.query(&[(\"{}\", {})]);", param.name, value),
+        "formData" if rs_type == "Vec<u8>" => format!(
+            "form = form.part(\"{0}\", reqwest::multipart::Part::bytes({1}.clone()).file_name(\"{0}\"));",
+            param.name, value
+        ),
+        "formData" if multipart => format!("form = form.text(\"{}\", {}.to_string());", param.name, value),
+        "formData" => format!("form.push((\"{}\", {}.to_string()));", param.name, value),
+        "cookie" => {
+            format!("request = request.header(reqwest::header::COOKIE, format!(\"{}={{}}\", {}));", param.name, value)
+        }
+        _ if rs_type == "String" => format!("request = request.header(\"{}\", {});", param.name, value),
+        _ => format!("request = request.header(\"{}\", {}.to_string());", param.name, value),
+    };
+    // Arrays are sent a value at a time unless their `collectionFormat` joins them into one.
+    if let Some(delimiter) = collection_delimiter(param) {
+        format!(
+            "        if !{0}.is_empty() {{\n            \
+             let value = {0}.iter().map(ToString::to_string).collect::<Vec<_>>().join({1:?});\n            \
+             {2}\n        }}\n",
+            field,
+            delimiter,
+            apply("value")
+        )
+    } else if param.parameter_type.as_deref() == Some("array") {
+        format!("        for value in &{} {{\n            {}\n        }}\n", field, apply("value"))
+    } else if param.required && param.location == "formData" {
+        format!("        {}\n", apply(&field))
+    } else if param.required {
+        format!("        {}\n", apply(&format!("&{}", field)))
+    } else {
+        format!("        if let Some(value) = &{} {{\n            {}\n        }}\n", field, apply("value"))
+    }
+}
+
+fn generate_auth_config(swagger: &Swagger) -> String {
+    let mut rs_code = String::from("#[derive(Debug, Clone, Default)]\npub struct AuthConfig {\n");
+    for (name, scheme) in security_schemes(swagger) {
+        let auth_type = if scheme.scheme_type == "basic" { "(String, String)" } else { "String" };
+        rs_code.push_str(&format!("    pub {}: Option<{}>,\n", field_name(name), auth_type));
+    }
+    rs_code.push_str("}\n");
+    rs_code
+}
+
+fn generate_authorize(swagger: &Swagger) -> String {
+    let schemes = security_schemes(swagger);
+    let mut rs_code = String::from("    fn has_auth(&self, name: &str) -> bool {\n        match name {\n");
+    for (name, _) in &schemes {
+        rs_code.push_str(&format!("            \"{}\" => self.auth.{}.is_some(),\n", name, field_name(name)));
+    }
+    rs_code.push_str("            _ => false,\n        }\n    }\n\n");
+
+    rs_code.push_str(
+        "    fn authorize(&self, mut request: reqwest::RequestBuilder, requirements: &[&[&str]]) -> reqwest::RequestBuilder {
+        let Some(names) = requirements.iter().find(|names| names.iter().all(|name| self.has_auth(name))) else {
+            return request;
+        };
+        for name in names.iter() {
+            request = match *name {\n",
+    );
+    for (name, scheme) in &schemes {
+        let field = format!("self.auth.{}", field_name(name));
+        let key = scheme.name.as_deref().unwrap_or(name);
+        let apply = match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+            ("basic", _) => format!(
+                "{{\n                    let (username, password) = {}.clone().unwrap_or_default();\n                    request.basic_auth(username, Some(password))\n                }}",
+                field
+            ),
+            ("apiKey", Some("query")) => {
+                format!("request.query(&[(\"{}\", {}.as_deref().unwrap_or_default())])", key, field)
+            }
+            ("apiKey", Some("cookie")) => format!(
+                "request.header(\"Cookie\", format!(\"{}={{}}\", {}.as_deref().unwrap_or_default()))",
+                key, field
+            ),
+            ("apiKey", _) => format!("request.header(\"{}\", {}.as_deref().unwrap_or_default())", key, field),
+            _ => format!("request.bearer_auth({}.as_deref().unwrap_or_default())", field),
+        };
+        let separator = if apply.ends_with('}') { "" } else { "," };
+        rs_code.push_str(&format!("                \"{}\" => {}{}\n", name, apply, separator));
+    }
+    rs_code.push_str("                _ => request,\n            };\n        }\n        request\n    }\n\n");
+    rs_code
+}
+
+fn generate_client_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = snake_case(&operation_name(method, path, operation));
+    let form = form_parameters(operation);
+    let multipart = !form.is_empty() && request_media_type(swagger, operation) == MULTIPART;
+    let body = operation.parameters.iter().find(|param| param.location == "body").filter(|_| form.is_empty());
+
+    let mut arguments = vec!["&self".to_string()];
+    arguments.extend(
+        path_params.iter().map(|param| format!("{}: {}", field_name(param), path_parameter_type(operation, param))),
+    );
+    let params = parameters(operation);
+    if !params.is_empty() {
+        arguments.push(format!("params: &{}", params_name(method, path, operation)));
+    }
+    if let Some(body) = body {
+        arguments.push(format!("body: &{}", body.schema.as_ref().map_or("serde_json::Value".to_string(), spec_type)));
+    }
+
+    // Strings are percent-encoded to stay within their segment, while numbers and booleans cannot leave it.
+    let mut rs_path = path.to_string();
+    let mut path_values = vec!["self.base_url".to_string()];
+    for param in &path_params {
+        rs_path = rs_path.replace(&format!("{{{}}}", param), "{}");
+        path_values.push(match path_parameter_type(operation, param) {
+            "&str" => format!("utf8_percent_encode({}, NON_ALPHANUMERIC)", field_name(param)),
+            _ => field_name(param),
+        });
+    }
+
+    let binary = has_binary_response(swagger, operation);
+    let response_type = match success_responses(operation).into_iter().find_map(|(_, r)| r.response_schema.as_ref()) {
+        _ if binary => "Vec<u8>".to_string(),
+        Some(schema) => spec_type(schema),
+        None => "serde_json::Value".to_string(),
+    };
+
+    let mut rs_code = String::new();
+    if let Some(summary) = &operation.summary {
+        rs_code.push_str(&format!("    /// {}\n", summary));
+    }
+    if operation.deprecated {
+        rs_code.push_str("    #[deprecated]\n");
+    }
+    rs_code.push_str(&format!(
+        "    pub async fn {}({}) -> Result<{}, ApiError> {{\n",
+        field_name(&method_name),
+        arguments.join(", "),
+        response_type
+    ));
+    // reqwest has no shorthand for OPTIONS requests.
+    let builder = if method == "options" {
+        "request(reqwest::Method::OPTIONS, ".to_string()
+    } else {
+        format!("{}(", method)
+    };
+    rs_code.push_str(&format!(
+        "        let {}request = self.client.{}format!(\"{{}}{}\", {}))",
+        if params.len() == form.len() { "" } else { "mut " },
+        builder,
+        rs_path,
+        path_values.join(", ")
+    ));
+    // `json` keeps a Content-Type set before it.
+    if body.is_some() {
+        if let Some(media_type) = body_media_type(swagger, method, operation) {
+            rs_code.push_str(&format!(".header(reqwest::header::CONTENT_TYPE, \"{}\")", media_type));
+        }
+        if sends_raw_body(swagger, method, operation) {
+            rs_code.push_str(".body(body.clone())");
+        } else {
+            rs_code.push_str(".json(body)");
+        }
+    }
+    rs_code.push_str(";\n");
+    if multipart {
+        rs_code.push_str("        let mut form = reqwest::multipart::Form::new();\n");
+    } else if !form.is_empty() {
+        rs_code.push_str("        let mut form: Vec<(&str, String)> = Vec::new();\n");
+    }
+    for param in &params {
+        rs_code.push_str(&apply_parameter(param, multipart));
+    }
+    if multipart {
+        rs_code.push_str("        let request = request.multipart(form);\n");
+    } else if !form.is_empty() {
+        rs_code.push_str("        let request = request.form(&form);\n");
+    }
+
+    if !swagger.security_definitions.is_empty() {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("&[{}]", names.join(", "))
+            })
+            .collect();
+        if !requirements.is_empty() {
+            rs_code.push_str(&format!("        let request = self.authorize(request, &[{}]);\n", requirements.join(", ")));
+        }
+    }
+    rs_code.push_str(&format!("        self.{}(request).await\n    }}\n", if binary { "send_bytes" } else { "send" }));
+    rs_code
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => {
+            format!("Vec<{}>", prop.additional.get("items").map_or("serde_json::Value".to_string(), schema_type))
+        }
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+/// Rust type of a body or response schema.
+fn spec_type(schema: &Schema) -> String {
+    serde_json::to_value(schema).map_or_else(|_| "serde_json::Value".to_string(), |schema| schema_type(&schema))
+}
+
+pub fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("Vec<{}>", schema.get("items").map_or("serde_json::Value".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+pub fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), Some("int32")) => "i32",
+        (Some("integer"), _) => "i64",
+        (Some("number"), Some("float")) => "f32",
+        (Some("number"), _) => "f64",
+        (Some("string"), _) => "String",
+        (Some("boolean"), _) => "bool",
+        _ => "serde_json::Value",
+    }
+}
+
+pub fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+pub fn field_name(name: &str) -> String {
+    let field_name = snake_case(name);
+    match field_name.as_str() {
+        "" => "field".to_string(),
+        "self" | "super" | "crate" => format!("{}_", field_name),
+        _ if field_name.starts_with(|c: char| c.is_ascii_digit()) => format!("_{}", field_name),
+        _ if KEYWORDS.contains(&field_name.as_str()) => format!("r#{}", field_name),
+        _ => field_name,
+    }
+}
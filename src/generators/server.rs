@@ -0,0 +1,423 @@
+use super::rust::{field_name, primitive_type, schema_type, type_name};
+use super::{
+    accept_header, collection_delimiter, extract_path_params, has_binary_response, info_lines, operation_name,
+    operations_by_tag, package_version, request_media_type, snake_case, words, MULTIPART,
+};
+use crate::spec::{Operation, Parameter, Response, Schema, Swagger};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Turns the code of a response into the `StatusCode` the server answers with.
+pub const STATUS_FUNCTION: &str = "fn status(code: u16) -> StatusCode {
+    StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+}
+";
+
+/// Reads the query parameters whose `collectionFormat` joins their items into one value.
+pub const DELIMITED_FUNCTIONS: &str = "
+/// Splits a query parameter into the items joined by `DELIMITER`.
+fn delimited<'de, const DELIMITER: char, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let value = String::deserialize(deserializer)?;
+    value
+        .split(DELIMITER)
+        .filter(|item| !item.is_empty())
+        .map(|item| item.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
+
+/// Splits an optional query parameter into the items joined by `DELIMITER`.
+fn delimited_option<'de, const DELIMITER: char, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    delimited::<DELIMITER, D, T>(deserializer).map(Some)
+}
+";
+
+/// The operations of `swagger` grouped by their first tag, each group being a trait of the server.
+pub fn server_operations(swagger: &Swagger) -> Vec<(String, Vec<ServerOperation<'_>>)> {
+    operations_by_tag(swagger)
+        .into_iter()
+        .map(|(tag, operations)| {
+            let operations = operations
+                .into_iter()
+                .map(|(path, method, operation)| ServerOperation::new(swagger, path, method, operation))
+                .collect();
+            (tag, operations)
+        })
+        .collect()
+}
+
+/// The `Cargo.toml` of the server crate, with the `dependencies` of its framework.
+pub fn generate_manifest(swagger: &Swagger, dependencies: &str) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    let name = match words(title).join("-") {
+        name if name.is_empty() => "api-server".to_string(),
+        name => format!("{}-server", name),
+    };
+
+    format!(
+        "[package]
+name = \"{}\"
+version = \"{}\"
+edition = \"2021\"
+
+[dependencies]
+{}serde = {{ version = \"1\", features = [\"derive\"] }}
+serde_json = \"1\"
+",
+        name,
+        package_version(swagger),
+        dependencies
+    )
+}
+
+/// What the operations of a server use, by which its crate imports only what it needs.
+pub struct Usage<'a> {
+    pub methods: BTreeSet<&'a str>,
+    pub contents: BTreeSet<Content>,
+    pub handlers: bool,
+    pub paths: bool,
+    pub queries: bool,
+    pub bodies: bool,
+    /// Whether an operation takes URL-encoded form fields.
+    pub forms: bool,
+    /// Whether an operation takes a `multipart/form-data` body.
+    pub multipart: bool,
+    /// Whether a query parameter joins its items into one value.
+    pub delimited: bool,
+}
+
+impl<'a> Usage<'a> {
+    pub fn of(groups: &[(String, Vec<ServerOperation<'a>>)]) -> Usage<'a> {
+        let operations = || groups.iter().flat_map(|(_, operations)| operations);
+        Usage {
+            methods: operations().map(|operation| operation.method).collect(),
+            contents: operations()
+                .flat_map(|operation| &operation.responses)
+                .filter_map(|variant| variant.body.as_ref().map(|(_, content)| *content))
+                .collect(),
+            handlers: operations().next().is_some(),
+            paths: operations().any(|operation| !operation.path_params.is_empty()),
+            queries: operations().any(|operation| !operation.query.is_empty()),
+            bodies: operations().any(|operation| operation.body.is_some()),
+            forms: operations().any(|operation| !operation.form.is_empty() && !operation.multipart),
+            multipart: operations().any(|operation| operation.multipart),
+            delimited: operations()
+                .flat_map(|operation| &operation.query)
+                .any(|param| collection_delimiter(param).is_some()),
+        }
+    }
+}
+
+/// The banner of the crate as its inner doc comment.
+pub fn push_banner(rs_code: &mut String, swagger: &Swagger) {
+    for line in info_lines(swagger) {
+        rs_code.push_str(&format!("//! {}\n", line));
+    }
+    rs_code.push('\n');
+}
+
+/// A `use` of the `names` of `module` that are used, if any are.
+pub fn push_use(rs_code: &mut String, module: &str, names: &[(&str, bool)]) {
+    let names: Vec<&str> = names.iter().filter(|(_, used)| *used).map(|(name, _)| *name).collect();
+    match names.as_slice() {
+        [] => {}
+        [name] => rs_code.push_str(&format!("use {}::{};\n", module, name)),
+        names => rs_code.push_str(&format!("use {}::{{{}}};\n", module, names.join(", "))),
+    }
+}
+
+/// An operation as the server sees it: the types its handler extracts from the request and the
+/// responses it may answer with.
+pub struct ServerOperation<'a> {
+    pub method: &'a str,
+    pub path: &'a str,
+    pub operation: &'a Operation,
+    pub name: String,
+    /// The path parameters, with their names in the path and their Rust types.
+    pub path_params: Vec<(String, String)>,
+    pub query: Vec<&'a Parameter>,
+    pub body: Option<String>,
+    pub body_required: bool,
+    pub form: Vec<&'a Parameter>,
+    /// Whether the form fields come as `multipart/form-data`, which the implementation reads itself.
+    pub multipart: bool,
+    pub responses: Vec<Variant>,
+}
+
+/// A response of an operation: the variant of its enum, its status code, which the
+/// implementation picks for `default` and ranges such as `4XX`, and the type of its body.
+pub struct Variant {
+    pub name: String,
+    pub description: String,
+    pub status: Option<u16>,
+    pub body: Option<(String, Content)>,
+}
+
+/// How the body of a response is sent.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Content {
+    Json,
+    Text,
+    Binary,
+}
+
+impl<'a> ServerOperation<'a> {
+    pub fn new(swagger: &Swagger, path: &'a str, method: &'a str, operation: &'a Operation) -> ServerOperation<'a> {
+        let parameters = |location: &'static str| operation.parameters.iter().filter(move |p| p.location == location);
+        let path_params = extract_path_params(path)
+            .into_iter()
+            .map(|name| {
+                let param = parameters("path").find(|param| param.name == name);
+                let rs_type = param.map_or("String".to_string(), parameter_type);
+                (name, rs_type)
+            })
+            .collect();
+        let body_param = parameters("body").next();
+        let body = body_param.map(|param| param.schema.as_ref().map_or(json_value(), value_type));
+        let form: Vec<&Parameter> = parameters("formData").collect();
+
+        let binary = has_binary_response(swagger, operation);
+        let text = accept_header(swagger, operation).is_some();
+        let mut responses: Vec<Variant> = operation
+            .responses
+            .iter()
+            .map(|(status, response)| {
+                let success = status.starts_with('2');
+                let body = match &response.response_schema {
+                    _ if success && binary => Some(("Vec<u8>".to_string(), Content::Binary)),
+                    _ if success && text => Some(("String".to_string(), Content::Text)),
+                    Some(schema) => Some((value_type(schema), Content::Json)),
+                    None => None,
+                };
+                status_variant(status, response, body)
+            })
+            .collect();
+        // The successes first, as in the documentation of the operation.
+        responses.sort_by_key(|variant| (!variant.name.starts_with("Status2"), variant.name == "Default"));
+        if responses.is_empty() {
+            responses.push(Variant {
+                name: "Status200".to_string(),
+                description: String::new(),
+                status: Some(200),
+                body: None,
+            });
+        }
+
+        ServerOperation {
+            method,
+            path,
+            operation,
+            name: snake_case(&operation_name(method, path, operation)),
+            path_params,
+            query: parameters("query").collect(),
+            body,
+            body_required: body_param.is_some_and(|param| param.required),
+            multipart: !form.is_empty() && request_media_type(swagger, operation) == MULTIPART,
+            form,
+            responses,
+        }
+    }
+
+    pub fn type_name(&self, suffix: &str) -> String {
+        format!("{}{}", type_name(&self.name), suffix)
+    }
+
+    /// The structs of the path and query parameters and of the URL-encoded form fields, and the enum
+    /// of the responses.
+    pub fn push_types(&self, rs_code: &mut String) {
+        if !self.path_params.is_empty() {
+            rs_code.push_str(&format!("\n/// The path parameters of `{}`.\n", self.name));
+            rs_code.push_str("#[derive(Debug, Clone, PartialEq, Deserialize)]\n");
+            rs_code.push_str(&format!("pub struct {} {{\n", self.type_name("Path")));
+            for (name, rs_type) in &self.path_params {
+                push_field(rs_code, name, rs_type.clone(), None);
+            }
+            rs_code.push_str("}\n");
+        }
+        if !self.query.is_empty() {
+            rs_code.push_str(&format!("\n/// The query parameters of `{}`.\n", self.name));
+            push_parameters(rs_code, &self.type_name("Query"), &self.query);
+        }
+        if !self.form.is_empty() && !self.multipart {
+            rs_code.push_str(&format!("\n/// The form fields of `{}`.\n", self.name));
+            push_parameters(rs_code, &self.type_name("Form"), &self.form);
+        }
+
+        rs_code.push_str(&format!("\n/// The responses of `{}`.\n", self.name));
+        rs_code.push_str("#[derive(Debug, Clone, PartialEq)]\n");
+        rs_code.push_str(&format!("pub enum {} {{\n", self.type_name("Response")));
+        for variant in &self.responses {
+            if !variant.description.is_empty() {
+                rs_code.push_str(&format!("    /// {}\n", variant.description));
+            }
+            let mut fields: Vec<&str> = Vec::new();
+            if variant.status.is_none() {
+                fields.push("StatusCode");
+            }
+            fields.extend(variant.body.as_ref().map(|(body, _)| body.as_str()));
+            if fields.is_empty() {
+                rs_code.push_str(&format!("    {},\n", variant.name));
+            } else {
+                rs_code.push_str(&format!("    {}({}),\n", variant.name, fields.join(", ")));
+            }
+        }
+        rs_code.push_str("}\n");
+    }
+
+    /// The arguments of the method of the trait, with their types; `multipart` is the type of the
+    /// framework a `multipart/form-data` body is read from.
+    pub fn arguments(&self, multipart: &str) -> Vec<(&str, String)> {
+        let mut arguments = Vec::new();
+        if !self.path_params.is_empty() {
+            arguments.push(("path", self.type_name("Path")));
+        }
+        if !self.query.is_empty() {
+            arguments.push(("query", self.type_name("Query")));
+        }
+        match &self.body {
+            Some(body) if self.body_required => arguments.push(("body", body.clone())),
+            Some(body) => arguments.push(("body", format!("Option<{}>", body))),
+            None => {}
+        }
+        if self.multipart {
+            arguments.push(("multipart", multipart.to_string()));
+        } else if !self.form.is_empty() {
+            arguments.push(("form", self.type_name("Form")));
+        }
+        arguments
+    }
+
+    /// The method of the trait, returning a future that is `Send` when `send`.
+    pub fn push_method(&self, rs_code: &mut String, send: bool, multipart: &str) {
+        if let Some(summary) = &self.operation.summary {
+            rs_code.push_str(&format!("    /// {}\n", summary));
+        }
+        rs_code.push_str(&format!("    /// `{} {}`\n", self.method.to_uppercase(), self.path));
+        if self.multipart {
+            let fields: Vec<String> = self.form.iter().map(|param| format!("`{}`", param.name)).collect();
+            rs_code.push_str("    ///\n");
+            rs_code.push_str(&format!("    /// The `multipart` body holds the fields {}.\n", fields.join(", ")));
+        }
+        let mut arguments = vec!["&self".to_string()];
+        arguments.extend(self.arguments(multipart).into_iter().map(|(name, rs_type)| format!("{}: {}", name, rs_type)));
+        rs_code.push_str(&format!(
+            "    fn {}({}) -> impl Future<Output = {}>{};\n",
+            field_name(&self.name),
+            arguments.join(", "),
+            self.type_name("Response"),
+            if send { " + Send" } else { "" }
+        ));
+    }
+}
+
+pub fn push_field(rs_code: &mut String, name: &str, rs_type: String, description: Option<&str>) {
+    if let Some(description) = description.filter(|description| !description.is_empty()) {
+        rs_code.push_str(&format!("    /// {}\n", description));
+    }
+    let field_name = field_name(name);
+    if field_name.trim_start_matches("r#") != name {
+        rs_code.push_str(&format!("    #[serde(rename = \"{}\")]\n", name));
+    }
+    rs_code.push_str(&format!("    pub {}: {},\n", field_name, rs_type));
+}
+
+/// The variant of the response documented under `status`: `Status404` for a code, `Default` and
+/// `Status4XX` for the others, which carry the code.
+fn status_variant(status: &str, response: &Response, body: Option<(String, Content)>) -> Variant {
+    let code = status.parse().ok();
+    let name = match code {
+        Some(_) => format!("Status{}", status),
+        None if status == "default" => "Default".to_string(),
+        None => format!("Status{}", status.to_ascii_uppercase()),
+    };
+    Variant { name, description: response.description.clone(), status: code, body }
+}
+
+/// A struct named `name` with a field per parameter of `params`, optional unless required.
+fn push_parameters(rs_code: &mut String, name: &str, params: &[&Parameter]) {
+    rs_code.push_str("#[derive(Debug, Clone, PartialEq, Deserialize)]\n");
+    rs_code.push_str(&format!("pub struct {} {{\n", name));
+    for param in params {
+        // The clients repeat the parameter for each value of an array, unless its `collectionFormat`
+        // joins them into one.
+        if let Some(delimiter) = collection_delimiter(param) {
+            let (default, function) = if param.required { ("", "delimited") } else { ("default, ", "delimited_option") };
+            // The delimiter as a char literal inside a string, which escapes its backslash in turn.
+            let delimiter = delimiter.escape_default().to_string().escape_default().to_string();
+            rs_code.push_str(&format!(
+                "    #[serde({}deserialize_with = \"{}::<'{}', _, _>\")]\n",
+                default, function, delimiter
+            ));
+        }
+        let rs_type = match (param.parameter_type.as_deref(), &param.items) {
+            (Some("array"), Some(items)) => {
+                format!("Vec<{}>", text_type(items.schema_type.as_deref(), items.format.as_deref()))
+            }
+            (Some("array"), None) => "Vec<String>".to_string(),
+            _ => parameter_type(param),
+        };
+        let rs_type = if param.required { rs_type } else { format!("Option<{}>", rs_type) };
+        push_field(rs_code, &param.name, rs_type, param.description.as_deref());
+    }
+    rs_code.push_str("}\n");
+}
+
+/// The type of a path, query or form parameter, with the ones that are not primitives read as text.
+pub fn parameter_type(param: &Parameter) -> String {
+    text_type(param.parameter_type.as_deref(), param.format.as_deref())
+}
+
+fn text_type(schema_type: Option<&str>, format: Option<&str>) -> String {
+    match primitive_type(schema_type, format) {
+        "serde_json::Value" => "String".to_string(),
+        rs_type => rs_type.to_string(),
+    }
+}
+
+fn value_type(schema: &Schema) -> String {
+    serde_json::to_value(schema).map_or_else(|_| json_value(), |schema| schema_type(&schema))
+}
+
+fn json_value() -> String {
+    "serde_json::Value".to_string()
+}
+
+/// A trait per group of `groups` with a method per operation, each trait followed by the handlers
+/// `push_handler` writes for its operations, which `entry` routes requests to. `multipart` is the
+/// type the methods read `multipart/form-data` bodies from. Returns the names of the traits.
+pub fn push_traits(
+    rs_code: &mut String,
+    groups: &[(String, Vec<ServerOperation<'_>>)],
+    entry: &str,
+    send: bool,
+    multipart: &str,
+    mut push_handler: impl FnMut(&mut String, &ServerOperation<'_>, &str),
+) -> Vec<String> {
+    let mut traits = Vec::new();
+    for (tag, operations) in groups {
+        let trait_name = format!("{}Api", type_name(tag));
+        rs_code.push_str(&format!("\n/// The operations tagged `{}`, which [`{}`] routes requests to.\n", tag, entry));
+        rs_code.push_str(&format!("pub trait {}: Send + Sync + 'static {{\n", trait_name));
+        for (index, operation) in operations.iter().enumerate() {
+            if index > 0 {
+                rs_code.push('\n');
+            }
+            operation.push_method(rs_code, send, multipart);
+        }
+        rs_code.push_str("}\n");
+        for operation in operations {
+            push_handler(rs_code, operation, &trait_name);
+        }
+        traits.push(trait_name);
+    }
+    traits
+}
@@ -0,0 +1,784 @@
+use super::typescript::{enum_member, form_parameters, has_flag, header_parameters, query_parameters};
+use super::{
+    base_url, binary_body, body_media_type, collection_delimiter, contains_by_value, enum_members, extract_path_params,
+    flattened_fields, has_binary_response, has_binary_responses, has_request_body, info_lines, operation_name,
+    pascal_case, request_media_type, response_items, response_reference, security_requirements, security_schemes,
+    sends_raw_body, with_custom_region, MULTIPART,
+};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use rayon::prelude::*;
+use serde_json::Value;
+use std::path::PathBuf;
+
+const KEYWORDS: &[&str] = &[
+    "as", "break", "case", "catch", "class", "continue", "default", "defer", "do", "else", "enum", "extension",
+    "fallthrough", "false", "for", "func", "guard", "if", "import", "in", "init", "inout", "internal", "is", "let",
+    "nil", "operator", "private", "protocol", "public", "repeat", "return", "self", "static", "struct", "subscript",
+    "super", "switch", "throw", "throws", "true", "try", "var", "where", "while",
+];
+
+pub fn generate(swagger: &Swagger, retries: Option<u32>) -> GeneratedFiles {
+    let module = module_name(swagger);
+    let source_dir = PathBuf::from("Sources").join(&module);
+
+    let mut files = GeneratedFiles::new();
+    files.insert(PathBuf::from("Package.swift"), generate_package(&module));
+    files.insert(source_dir.join("APIClient.swift"), with_custom_region(generate_client(swagger, retries), "//"));
+    files.insert(source_dir.join("JSONValue.swift"), generate_json_value(swagger));
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let path = source_dir.join("Models").join(format!("{}.swift", type_name(name)));
+        (path, generate_model(swagger, name, definition))
+    }));
+    files
+}
+
+fn module_name(swagger: &Swagger) -> String {
+    let title = swagger.info.get("title").and_then(Value::as_str).unwrap_or_default();
+    match pascal_case(title) {
+        module if module.starts_with(|c: char| c.is_ascii_alphabetic()) => module,
+        _ => "APIClient".to_string(),
+    }
+}
+
+fn generate_header(swagger: &Swagger) -> String {
+    let mut swift_code = String::new();
+    for line in info_lines(swagger) {
+        swift_code.push_str(&format!("// {}\n", line));
+    }
+    swift_code.push('\n');
+    swift_code
+}
+
+fn generate_package(module: &str) -> String {
+    format!(
+        "// swift-tools-version:5.7
+import PackageDescription
+
+let package = Package(
+    name: \"{0}\",
+    platforms: [.iOS(.v15), .macOS(.v12)],
+    products: [
+        .library(name: \"{0}\", targets: [\"{0}\"]),
+    ],
+    targets: [
+        .target(name: \"{0}\"),
+    ]
+)
+",
+        module
+    )
+}
+
+fn generate_json_value(swagger: &Swagger) -> String {
+    let mut swift_code = generate_header(swagger);
+    swift_code.push_str(
+        "import Foundation
+
+/// An arbitrary JSON value, used where the spec does not describe a schema.
+public enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case .bool(let value):
+            try container.encode(value)
+        case .number(let value):
+            try container.encode(value)
+        case .string(let value):
+            try container.encode(value)
+        case .array(let value):
+            try container.encode(value)
+        case .object(let value):
+            try container.encode(value)
+        }
+    }
+}
+",
+    );
+    swift_code
+}
+
+fn generate_model(swagger: &Swagger, name: &str, definition: &Definition) -> String {
+    let struct_name = type_name(name);
+    let enum_code = definition.enum_values.as_ref().and_then(|values| generate_enum(&struct_name, definition, values));
+    if let Some(enum_code) = enum_code {
+        return format!("{}import Foundation\n\n{}", generate_header(swagger), enum_code);
+    }
+    // A required field that may be null is optional, though the initializer still asks for it.
+    let properties: Vec<(&String, String, bool)> = flattened_fields(swagger, name, definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| match has_flag(prop, "nullable") || has_flag(prop, "x-nullable") {
+            true if required => (prop_name, format!("{}?", property_type(prop)), true),
+            _ => (prop_name, property_type(prop), required),
+        })
+        .collect();
+    // A struct cannot contain itself, so recursive definitions become classes.
+    let kind = if contains_by_value(swagger, name, name) {
+        "final class"
+    } else {
+        "struct"
+    };
+
+    let mut swift_code = generate_header(swagger);
+    swift_code.push_str("import Foundation\n\n");
+    swift_code.push_str(&format!("public {} {}: Codable {{\n", kind, struct_name));
+    for (prop_name, swift_type, required) in &properties {
+        let optional = if *required { "" } else { "?" };
+        swift_code.push_str(&format!("    public var {}: {}{}\n", argument_name(prop_name), swift_type, optional));
+    }
+
+    let arguments: Vec<String> = properties
+        .iter()
+        .map(|(prop_name, swift_type, required)| match required {
+            true => format!("{}: {}", argument_name(prop_name), swift_type),
+            false => format!("{}: {}? = nil", argument_name(prop_name), swift_type),
+        })
+        .collect();
+    if !properties.is_empty() {
+        swift_code.push('\n');
+    }
+    swift_code.push_str(&format!("    public init({}) {{\n", arguments.join(", ")));
+    for (prop_name, _, _) in &properties {
+        let field = argument_name(prop_name);
+        swift_code.push_str(&format!("        self.{} = {}\n", field.trim_matches('`'), field));
+    }
+    swift_code.push_str("    }\n");
+
+    if properties.iter().any(|(prop_name, _, _)| argument_name(prop_name).trim_matches('`') != prop_name.as_str()) {
+        swift_code.push_str("\n    enum CodingKeys: String, CodingKey {\n");
+        for (prop_name, _, _) in &properties {
+            let field = argument_name(prop_name);
+            if field.trim_matches('`') == prop_name.as_str() {
+                swift_code.push_str(&format!("        case {}\n", field));
+            } else {
+                swift_code.push_str(&format!("        case {} = \"{}\"\n", field, prop_name));
+            }
+        }
+        swift_code.push_str("    }\n");
+    }
+    swift_code.push_str("}\n");
+    swift_code
+}
+
+/// An enum definition as an `enum` with the values as raw values, for the string, integer and
+/// number ones.
+fn generate_enum(enum_name: &str, definition: &Definition, values: &[Value]) -> Option<String> {
+    let (raw_type, values): (&str, Vec<Value>) = match definition.definition_type.as_deref() {
+        Some("string") | None => ("String", values.iter().filter(|value| value.is_string()).cloned().collect()),
+        Some("integer") => ("Int", values.iter().filter(|value| value.is_i64()).cloned().collect()),
+        Some("number") => ("Double", values.iter().filter(|value| value.is_number()).cloned().collect()),
+        _ => return None,
+    };
+    if values.is_empty() {
+        return None;
+    }
+    let case_name = |words: &[String]| {
+        let member = enum_member(&words.join("_"));
+        let mut chars = member.chars();
+        let first = chars.next().map(|first| first.to_ascii_lowercase().to_string()).unwrap_or_default();
+        let case_name = first + chars.as_str();
+        if KEYWORDS.contains(&case_name.as_str()) {
+            format!("`{}`", case_name)
+        } else {
+            case_name
+        }
+    };
+    let mut swift_code = format!("public enum {}: {}, Codable {{\n", enum_name, raw_type);
+    for (case_name, value) in enum_members(&values, case_name) {
+        match value {
+            Value::String(text) if text == case_name.trim_matches('`') => {
+                swift_code.push_str(&format!("    case {}\n", case_name));
+            }
+            Value::String(text) => swift_code.push_str(&format!(
+                "    case {} = \"{}\"\n",
+                case_name,
+                text.replace('\\', "\\\\").replace('"', "\\\"")
+            )),
+            number => swift_code.push_str(&format!("    case {} = {}\n", case_name, number)),
+        }
+    }
+    swift_code.push_str("}\n");
+    Some(swift_code)
+}
+
+fn generate_client(swagger: &Swagger, retries: Option<u32>) -> String {
+    let has_auth = !swagger.security_definitions.is_empty();
+    // Only string path parameters are percent-encoded; numbers and booleans cannot leave their segment.
+    let encodes_path = swagger.paths.iter().any(|(path, path_item)| {
+        path_item.operations().into_iter().any(|(_, operation)| {
+            extract_path_params(path).iter().any(|param| path_parameter_type(operation, param) == "String")
+        })
+    });
+    let has_query = has_parameters(swagger, "query");
+    let has_headers = has_parameters(swagger, "header");
+    let has_cookies = has_parameters(swagger, "cookie");
+    let has_forms = has_parameters(swagger, "formData");
+    // Form bodies and bodies declared with a non-JSON media type pick their own `Content-Type`.
+    let has_content_types = has_forms
+        || swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(method, operation)| {
+            body_media_type(swagger, method, operation).is_some()
+        });
+
+    let mut swift_code = generate_header(swagger);
+    swift_code.push_str(
+        "import Foundation
+
+public enum APIError: Error {
+    case invalidURL(String)
+    case invalidResponse
+    case status(Int, Data)
+}
+
+public final class APIClient {\n",
+    );
+    swift_code.push_str(&format!(
+        "    public static let defaultBaseURL = URL(string: \"{}\")!\n\n",
+        base_url(swagger)
+    ));
+    if let Some(retries) = retries {
+        swift_code.push_str(&format!("    public static let maxRetries = {}\n\n", retries));
+        swift_code.push_str(
+            "    /// Reads a `Retry-After` sent as a date.
+    private static let httpDate: DateFormatter = {
+        let formatter = DateFormatter()
+        formatter.locale = Locale(identifier: \"en_US_POSIX\")
+        formatter.timeZone = TimeZone(identifier: \"GMT\")
+        formatter.dateFormat = \"EEE, dd MMM yyyy HH:mm:ss zzz\"
+        return formatter
+    }()\n\n",
+        );
+    }
+    if has_auth {
+        swift_code.push_str("    private static let securitySchemes: [String: (type: String, location: String, name: String)] = [\n");
+        for (name, scheme) in security_schemes(swagger) {
+            swift_code.push_str(&format!(
+                "        \"{}\": (\"{}\", \"{}\", \"{}\"),\n",
+                name,
+                scheme.scheme_type,
+                scheme.location.as_deref().unwrap_or_default(),
+                scheme.name.as_deref().unwrap_or(name)
+            ));
+        }
+        swift_code.push_str("    ]\n\n");
+    }
+    swift_code.push_str("    public let baseURL: URL\n");
+    swift_code.push_str("    public let session: URLSession\n");
+    if has_auth {
+        swift_code.push_str("    private var credentials: [String: String] = [:]\n");
+    }
+    swift_code.push_str(
+        "
+    public init(baseURL: URL = APIClient.defaultBaseURL, session: URLSession = .shared) {
+        self.baseURL = baseURL
+        self.session = session
+    }\n",
+    );
+
+    if has_auth {
+        swift_code.push_str(
+            "
+    /// Sets the credential of a security scheme: the key or token, or \"username:password\" for basic auth.
+    public func setAuth(_ scheme: String, _ credential: String) {
+        credentials[scheme] = credential
+    }\n",
+        );
+    }
+
+    let mut send_parameters = vec!["_ method: String", "_ path: String"];
+    if has_query {
+        send_parameters.push("query: [(String, Any?)] = []");
+    }
+    if has_headers {
+        send_parameters.push("headers parameters: [(String, Any?)] = []");
+    }
+    if has_cookies {
+        send_parameters.push("cookies: [(String, Any?)] = []");
+    }
+    send_parameters.push("body: Data? = nil");
+    if has_content_types {
+        send_parameters.push("contentType: String = \"application/json\"");
+    }
+    if has_auth {
+        send_parameters.push("requirements: [[String]] = []");
+    }
+    if has_auth || has_query || has_headers || has_cookies || has_content_types {
+        swift_code.push_str(&format!(
+            "
+    private func send<T: Decodable>({}) async throws -> T {{
+        guard var components = URLComponents(string: baseURL.absoluteString + path) else {{
+            throw APIError.invalidURL(path)
+        }}
+        var headers = [\"Accept\": \"application/json\"]\n",
+            send_parameters.join(", ")
+        ));
+    }
+    if has_query {
+        swift_code.push_str(
+            "        let items = queryItems(query)
+        if !items.isEmpty {
+            components.queryItems = (components.queryItems ?? []) + items
+        }\n",
+        );
+    }
+    if has_headers {
+        swift_code.push_str(
+            "        for (field, value) in parameters {
+            switch value {
+            case let values as [Any]:
+                headers[field] = values.map { \"\\($0)\" }.joined(separator: \",\")
+            case let value?:
+                headers[field] = \"\\(value)\"
+            case nil:
+                break
+            }
+        }\n",
+        );
+    }
+    if has_auth {
+        swift_code.push_str(
+            "        if let names = requirements.first(where: { $0.allSatisfy { credentials[$0] != nil } }) {
+            for name in names {
+                guard let scheme = APIClient.securitySchemes[name], let credential = credentials[name] else {
+                    continue
+                }
+                switch (scheme.type, scheme.location) {
+                case (\"basic\", _):
+                    headers[\"Authorization\"] = \"Basic \" + Data(credential.utf8).base64EncodedString()
+                case (\"apiKey\", \"query\"):
+                    components.queryItems = (components.queryItems ?? []) + [URLQueryItem(name: scheme.name, value: credential)]
+                case (\"apiKey\", \"cookie\"):
+                    headers[\"Cookie\"] = \"\\(scheme.name)=\\(credential)\"
+                case (\"apiKey\", _):
+                    headers[scheme.name] = credential
+                default:
+                    headers[\"Authorization\"] = \"Bearer \\(credential)\"
+                }
+            }
+        }\n",
+        );
+    }
+    if has_cookies {
+        swift_code.push_str(
+            "        let cookie = cookies.compactMap { name, value in value.map { \"\\(name)=\\($0)\" } }
+        if !cookie.isEmpty {
+            headers[\"Cookie\"] = ([headers[\"Cookie\"]].compactMap { $0 } + cookie).joined(separator: \"; \")
+        }\n",
+        );
+    }
+    if has_auth || has_query || has_headers || has_cookies || has_content_types {
+        swift_code.push_str(
+            "        guard let url = components.url else {
+            throw APIError.invalidURL(path)
+        }
+
+        var request = URLRequest(url: url)
+        request.httpMethod = method
+        for (field, value) in headers {
+            request.setValue(value, forHTTPHeaderField: field)
+        }\n",
+        );
+    } else {
+        swift_code.push_str(
+            "
+    private func send<T: Decodable>(_ method: String, _ path: String, body: Data? = nil) async throws -> T {
+        guard let url = URL(string: baseURL.absoluteString + path) else {
+            throw APIError.invalidURL(path)
+        }
+
+        var request = URLRequest(url: url)
+        request.httpMethod = method
+        request.setValue(\"application/json\", forHTTPHeaderField: \"Accept\")\n",
+        );
+    }
+    swift_code.push_str(&format!(
+        "        if let body = body {{
+            request.httpBody = body
+            request.setValue({}, forHTTPHeaderField: \"Content-Type\")
+        }}
+
+        let (data, response) = try await {}(for: request)
+        guard let response = response as? HTTPURLResponse else {{
+            throw APIError.invalidResponse
+        }}
+        guard (200..<300).contains(response.statusCode) else {{
+            throw APIError.status(response.statusCode, data)
+        }}
+        {}if data.isEmpty, let empty = JSONValue.null as? T {{
+            return empty
+        }}
+        return try JSONDecoder().decode(T.self, from: data)
+    }}\n",
+        if has_content_types { "contentType" } else { "\"application/json\"" },
+        if retries.is_some() { "dataWithRetries" } else { "session.data" },
+        // File downloads ask for `Data`, which they get undecoded.
+        if has_binary_responses(swagger) {
+            "if let data = data as? T {
+            return data
+        }
+        "
+        } else {
+            ""
+        }
+    ));
+
+    if retries.is_some() {
+        swift_code.push_str(
+            "
+    /// Retries requests that failed with a network error, 429 or 5xx, waiting as long as Retry-After asks
+    /// or otherwise doubling the delay from half a second.
+    private func dataWithRetries(for request: URLRequest) async throws -> (Data, URLResponse) {
+        var attempt = 0
+        while true {
+            var delay = 0.5 * pow(2, Double(attempt))
+            do {
+                let (data, response) = try await session.data(for: request)
+                let status = (response as? HTTPURLResponse)?.statusCode ?? 0
+                if (status != 429 && status < 500) || attempt >= APIClient.maxRetries {
+                    return (data, response)
+                }
+                if let retryAfter = (response as? HTTPURLResponse)?.value(forHTTPHeaderField: \"Retry-After\") {
+                    if let seconds = Double(retryAfter) {
+                        delay = seconds
+                    } else if let date = APIClient.httpDate.date(from: retryAfter) {
+                        delay = max(date.timeIntervalSinceNow, 0)
+                    }
+                }
+            } catch is URLError where attempt < APIClient.maxRetries {
+            }
+            try await Task.sleep(nanoseconds: UInt64(delay * 1_000_000_000))
+            attempt += 1
+        }
+    }\n",
+        );
+    }
+
+    if encodes_path {
+        swift_code.push_str(
+            "
+    private func encode(_ value: String) -> String {
+        value.addingPercentEncoding(withAllowedCharacters: .urlPathAllowed.subtracting(CharacterSet(charactersIn: \"/\"))) ?? value
+    }\n",
+        );
+    }
+
+    if has_query {
+        swift_code.push_str(
+            "
+    /// Turns the query parameters that were given into query items, one for each element of an array.
+    private func queryItems(_ parameters: [(String, Any?)]) -> [URLQueryItem] {
+        parameters.flatMap { name, value -> [URLQueryItem] in
+            switch value {
+            case let values as [Any]:
+                return values.map { URLQueryItem(name: name, value: \"\\($0)\") }
+            case let value?:
+                return [URLQueryItem(name: name, value: \"\\(value)\")]
+            case nil:
+                return []
+            }
+        }
+    }\n",
+        );
+    }
+
+    if has_forms {
+        swift_code.push_str(
+            "
+    /// Encodes form fields as `multipart/form-data`, sending `Data` values as files named after their field.
+    private func multipartForm(_ fields: [(String, Any?)]) -> (Data, String) {
+        let boundary = \"APIClient-\\(UUID().uuidString)\"
+        var body = Data()
+        for (name, value) in formValues(fields) {
+            body.append(Data(\"--\\(boundary)\\r\\n\".utf8))
+            if let data = value as? Data {
+                body.append(Data(\"Content-Disposition: form-data; name=\\\"\\(name)\\\"; filename=\\\"\\(name)\\\"\\r\\n\".utf8))
+                body.append(Data(\"Content-Type: application/octet-stream\\r\\n\\r\\n\".utf8))
+                body.append(data)
+            } else {
+                body.append(Data(\"Content-Disposition: form-data; name=\\\"\\(name)\\\"\\r\\n\\r\\n\\(value)\".utf8))
+            }
+            body.append(Data(\"\\r\\n\".utf8))
+        }
+        body.append(Data(\"--\\(boundary)--\\r\\n\".utf8))
+        return (body, \"multipart/form-data; boundary=\\(boundary)\")
+    }
+
+    /// Encodes form fields as `application/x-www-form-urlencoded`.
+    private func urlEncodedForm(_ fields: [(String, Any?)]) -> (Data, String) {
+        let unreserved = CharacterSet(charactersIn: \"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~\")
+        let encode = { (text: String) in text.addingPercentEncoding(withAllowedCharacters: unreserved) ?? text }
+        let pairs = formValues(fields).map { name, value in \"\\(encode(name))=\\(encode(\"\\(value)\"))\" }
+        return (Data(pairs.joined(separator: \"&\").utf8), \"application/x-www-form-urlencoded\")
+    }
+
+    /// The form fields that were given, one for each element of an array.
+    private func formValues(_ fields: [(String, Any?)]) -> [(String, Any)] {
+        fields.flatMap { name, value -> [(String, Any)] in
+            switch value {
+            case let values as [Any]:
+                return values.map { (name, $0) }
+            case let value?:
+                return [(name, value)]
+            case nil:
+                return []
+            }
+        }
+    }\n",
+        );
+    }
+
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            swift_code.push('\n');
+            swift_code.push_str(&generate_client_method(swagger, method, path, operation));
+        }
+    }
+    swift_code.push_str("}\n");
+    swift_code
+}
+
+/// Whether any operation takes a parameter in `location`, which the `send` method then accepts.
+fn has_parameters(swagger: &Swagger, location: &str) -> bool {
+    swagger.paths.values().flat_map(|path_item| path_item.operations()).any(|(_, operation)| {
+        let params = [query_parameters(operation), header_parameters(operation), form_parameters(operation)];
+        params.iter().flatten().any(|param| param.location == location)
+    })
+}
+
+fn generate_client_method(swagger: &Swagger, method: &str, path: &str, operation: &Operation) -> String {
+    let path_params = extract_path_params(path);
+    let method_name = argument_name(&operation_name(method, path, operation));
+    let has_body = has_request_body(method);
+
+    let mut arguments: Vec<String> = path_params
+        .iter()
+        .map(|param| format!("{}: {}", argument_name(param), path_parameter_type(operation, param)))
+        .collect();
+    let form = form_parameters(operation);
+    let has_body = has_body && form.is_empty();
+    let mut params = query_parameters(operation);
+    params.extend(header_parameters(operation));
+    let (required, optional): (Vec<&Parameter>, Vec<&Parameter>) =
+        params.iter().chain(&form).partition(|param| param.required);
+    for param in required {
+        arguments.push(format!("{}: {}", argument_name(&param.name), parameter_type(param)));
+    }
+    let media_type = body_media_type(swagger, method, operation);
+    let raw_body = sends_raw_body(swagger, method, operation);
+    if has_body {
+        arguments.push(match raw_body {
+            true if binary_body(operation) => "body: Data".to_string(),
+            true => "body: String".to_string(),
+            false => "body: Body".to_string(),
+        });
+    }
+    for param in optional {
+        arguments.push(format!("{}: {}? = nil", argument_name(&param.name), parameter_type(param)));
+    }
+    let generics = if has_body && !raw_body { "<Body: Encodable>" } else { "" };
+
+    let swift_path = path_params.iter().fold(path.to_string(), |acc, param| {
+        let value = match path_parameter_type(operation, param) {
+            "String" => format!("\\(encode({}))", argument_name(param)),
+            _ => format!("\\({})", argument_name(param)),
+        };
+        acc.replace(&format!("{{{}}}", param), &value)
+    });
+    let mut send_arguments = vec![format!("\"{}\"", method.to_uppercase()), format!("\"{}\"", swift_path)];
+    for (label, location) in [("query", "query"), ("headers", "header"), ("cookies", "cookie")] {
+        let values: Vec<String> = params
+            .iter()
+            .filter(|param| param.location == location)
+            .map(|param| format!("(\"{}\", {})", param.name, parameter_value(param)))
+            .collect();
+        if !values.is_empty() {
+            send_arguments.push(format!("{}: [{}]", label, values.join(", ")));
+        }
+    }
+    if has_body {
+        send_arguments.push(match raw_body {
+            true if binary_body(operation) => "body: body".to_string(),
+            true => "body: Data(body.utf8)".to_string(),
+            false => "body: JSONEncoder().encode(body)".to_string(),
+        });
+        if let Some(media_type) = media_type {
+            send_arguments.push(format!("contentType: \"{}\"", media_type));
+        }
+    }
+    let mut encode_form = String::new();
+    if !form.is_empty() {
+        let fields: Vec<String> =
+            form.iter().map(|param| format!("(\"{}\", {})", param.name, argument_name(&param.name))).collect();
+        let encoder = if request_media_type(swagger, operation) == MULTIPART { "multipartForm" } else { "urlEncodedForm" };
+        encode_form = format!("        let (form, contentType) = {}([{}])\n", encoder, fields.join(", "));
+        send_arguments.push("body: form, contentType: contentType".to_string());
+    }
+    if !swagger.security_definitions.is_empty() {
+        let requirements: Vec<String> = security_requirements(swagger, operation)
+            .iter()
+            .map(|names| {
+                let names: Vec<String> = names.iter().map(|name| format!("\"{}\"", name)).collect();
+                format!("[{}]", names.join(", "))
+            })
+            .collect();
+        if !requirements.is_empty() {
+            send_arguments.push(format!("requirements: [{}]", requirements.join(", ")));
+        }
+    }
+
+    let response_type = match (response_reference(operation), response_items(operation)) {
+        _ if has_binary_response(swagger, operation) => "Data".to_string(),
+        (Some(reference), _) => type_name(ref_name(reference)),
+        (None, Some(items)) => format!("[{}]", spec_type(items)),
+        (None, None) => "JSONValue".to_string(),
+    };
+
+    let mut swift_code = String::new();
+    if let Some(summary) = &operation.summary {
+        swift_code.push_str(&format!("    /// {}\n", summary));
+    }
+    if operation.deprecated {
+        swift_code.push_str("    @available(*, deprecated)\n");
+    }
+    swift_code.push_str(&format!(
+        "    public func {}{}({}) async throws -> {} {{
+{}        {}try await send({})
+    }}\n",
+        method_name,
+        generics,
+        arguments.join(", "),
+        response_type,
+        encode_form,
+        if encode_form.is_empty() { "" } else { "return " },
+        send_arguments.join(", ")
+    ));
+    swift_code
+}
+
+/// The value a parameter is sent as: its argument, with an array joined by the delimiter of its
+/// `collectionFormat` unless it is repeated for each item.
+fn parameter_value(param: &Parameter) -> String {
+    let argument = argument_name(&param.name);
+    match collection_delimiter(param) {
+        Some(delimiter) => {
+            let optional = if param.required { "" } else { "?" };
+            format!("{}{}.map {{ \"\\($0)\" }}.joined(separator: {:?})", argument, optional, delimiter)
+        }
+        None => argument,
+    }
+}
+
+fn parameter_type(param: &Parameter) -> String {
+    match param.parameter_type.as_deref() {
+        Some("file") => "Data".to_string(),
+        Some("array") => {
+            let items = param.items.as_ref();
+            let items_type = primitive_type(
+                items.and_then(|items| items.schema_type.as_deref()),
+                items.and_then(|items| items.format.as_deref()),
+            );
+            format!("[{}]", items_type)
+        }
+        other => primitive_type(other, param.format.as_deref()).to_string(),
+    }
+}
+
+/// The argument type of the path parameter `name`: its scalar type, or `String` for strings and
+/// parameters the operation does not declare.
+fn path_parameter_type(operation: &Operation, name: &str) -> &'static str {
+    let param = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+    match param.map(|param| primitive_type(param.parameter_type.as_deref(), param.format.as_deref())) {
+        Some(swift_type @ ("Int" | "Float" | "Double" | "Bool")) => swift_type,
+        _ => "String",
+    }
+}
+
+fn spec_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return type_name(ref_name(reference));
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => format!("[{}]", schema.items.as_deref().map_or("JSONValue".to_string(), spec_type)),
+        other => primitive_type(other, schema.format.as_deref()).to_string(),
+    }
+}
+
+fn property_type(prop: &Property) -> String {
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => format!("[{}]", prop.additional.get("items").map_or("JSONValue".to_string(), schema_type)),
+        other => primitive_type(other, prop.format.as_deref()).to_string(),
+    }
+}
+
+fn schema_type(schema: &Value) -> String {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return type_name(ref_name(reference));
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => format!("[{}]", schema.get("items").map_or("JSONValue".to_string(), schema_type)),
+        other => primitive_type(other, schema.get("format").and_then(Value::as_str)).to_string(),
+    }
+}
+
+fn primitive_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match (schema_type, format) {
+        (Some("integer"), _) => "Int",
+        (Some("number"), Some("float")) => "Float",
+        (Some("number"), _) => "Double",
+        (Some("string"), _) => "String",
+        (Some("boolean"), _) => "Bool",
+        (Some("object"), _) => "[String: JSONValue]",
+        _ => "JSONValue",
+    }
+}
+
+fn type_name(name: &str) -> String {
+    let type_name = pascal_case(name);
+    if type_name.is_empty() || type_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("T{}", type_name)
+    } else {
+        type_name
+    }
+}
+
+fn argument_name(name: &str) -> String {
+    let type_name = type_name(name);
+    let mut chars = type_name.chars();
+    let argument: String = match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => "value".to_string(),
+    };
+    match argument.as_str() {
+        "body" => "bodyParam".to_string(),
+        _ if KEYWORDS.contains(&argument.as_str()) => format!("`{}`", argument),
+        _ => argument,
+    }
+}
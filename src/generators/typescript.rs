@@ -0,0 +1,3129 @@
+use super::{
+    accept_header, base_url, codecs, collection_delimiter, enum_members, error_responses, guards, has_binary_response,
+    has_request_body, has_server_choices, info_block, operation_name, operations_by_tag, pagination, pascal_case,
+    request_media_type, security_requirements, security_schemes, success_responses, with_custom_region, zod, Pagination,
+    PaginationStyle, TaggedOperation, MULTIPART, URL_ENCODED,
+};
+use crate::cli::{DateType, EnumStyle, HttpClient, Int64Type, Language, NullableStyle, ServiceStyle, Validators};
+use crate::output::GeneratedFiles;
+use crate::spec::{ref_name, Definition, Operation, Parameter, Property, Schema, Swagger};
+use crate::templates;
+use rayon::prelude::*;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// How schema details that TypeScript can express in more than one way are rendered.
+#[derive(Debug, Clone, Copy)]
+pub struct TypeOptions {
+    pub enum_style: EnumStyle,
+    pub nullable_style: NullableStyle,
+    pub date_type: DateType,
+    pub int64_type: Int64Type,
+    pub model_variants: bool,
+    pub validators: Option<Validators>,
+}
+
+/// How the generated service is laid out.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceOptions {
+    pub http_client: HttpClient,
+    pub split_by_tag: bool,
+    pub retries: Option<u32>,
+    pub style: ServiceStyle,
+}
+
+pub fn generate(swagger: &Swagger, lang: Language, service: ServiceOptions, types: TypeOptions) -> GeneratedFiles {
+    let mut files = GeneratedFiles::new();
+
+    // The JavaScript service is always built on fetch, which needs no dependency.
+    let (extension, http_client) = if lang == Language::JavaScript {
+        if !swagger.definitions.is_empty() {
+            files.insert(PathBuf::from("types.js"), generate_jsdoc_types(swagger, types));
+        }
+        if let Some(codecs) = codecs::generate_codecs(swagger, types, lang) {
+            files.insert(PathBuf::from("codecs.js"), codecs);
+        }
+        ("js", HttpClient::Fetch)
+    } else {
+        generate_interfaces(swagger, types, &mut files);
+        ("ts", service.http_client)
+    };
+
+    if service.style == ServiceStyle::Class {
+        let path = PathBuf::from(format!("service.{}", extension));
+        let service = generate_client_class(swagger, lang, http_client, service.retries, types);
+        files.insert(path, with_custom_region(service, "//"));
+        return files;
+    }
+
+    if service.split_by_tag {
+        let client = match http_client {
+            HttpClient::Axios => generate_axios_client(swagger, service),
+            HttpClient::Fetch => generate_fetch_client(swagger, lang, service.retries),
+        };
+        files.insert(PathBuf::from(format!("client.{}", extension)), client);
+        files.par_extend(operations_by_tag(swagger).into_par_iter().map(|(tag, operations)| {
+            let path = PathBuf::from(format!("{}.{}", tag_service_name(&tag), extension));
+            let service = generate_tag_service(swagger, lang, http_client, &tag, &operations, types);
+            (path, with_custom_region(service, "//"))
+        }));
+        return files;
+    }
+
+    let service = match http_client {
+        HttpClient::Axios => {
+            files.insert(PathBuf::from("client.ts"), generate_axios_client(swagger, service));
+            generate_service(swagger, lang, types)
+        }
+        HttpClient::Fetch => generate_fetch_service(swagger, lang, service.retries, types),
+    };
+    files.insert(PathBuf::from(format!("service.{}", extension)), with_custom_region(service, "//"));
+
+    files
+}
+
+/// What one module is imported as across the files being bundled.
+#[derive(Default)]
+struct ModuleImports<'a> {
+    default: Option<&'a str>,
+    /// The named imports, each with whether only its type is imported.
+    named: Vec<(&'a str, bool)>,
+}
+
+/// One import per module for the `imports` of several files, each name imported once and as a
+/// value if any file imports it as one, since importing a name twice does not compile. Imports
+/// of other forms, e.g. `import * as z from 'zod'`, are kept as they are.
+fn merge_imports(imports: &[&str]) -> Vec<String> {
+    let mut modules: Vec<(&str, ModuleImports)> = Vec::new();
+    let mut lines: Vec<String> = Vec::new();
+    for line in imports {
+        let Some((clause, module)) = line.strip_prefix("import ").and_then(|rest| rest.rsplit_once(" from ")) else {
+            lines.push(line.to_string());
+            continue;
+        };
+        let (type_only, clause) = clause.strip_prefix("type ").map_or((false, clause), |clause| (true, clause));
+        let (default, named) = match clause.split_once('{') {
+            Some((default, named)) => (default.trim().trim_end_matches(',').trim(), named.trim_end().strip_suffix('}')),
+            None => (clause.trim(), Some("")),
+        };
+        // Namespace imports and default imports of types only are left as they are.
+        let mergeable = !default.contains('*') && (!type_only || default.is_empty());
+        let Some(named) = named.filter(|_| mergeable) else {
+            if !lines.iter().any(|kept| kept == line) {
+                lines.push(line.to_string());
+            }
+            continue;
+        };
+        let index = match modules.iter().position(|(name, _)| *name == module) {
+            Some(index) => index,
+            None => {
+                modules.push((module, ModuleImports::default()));
+                lines.push(String::new());
+                modules.len() - 1
+            }
+        };
+        let entry = &mut modules[index].1;
+        if !default.is_empty() {
+            entry.default = Some(default);
+        }
+        for name in named.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            let (name, type_only) = name.strip_prefix("type ").map_or((name, type_only), |name| (name, true));
+            match entry.named.iter_mut().find(|(named, _)| *named == name) {
+                Some((_, only_type)) => *only_type &= type_only,
+                None => entry.named.push((name, type_only)),
+            }
+        }
+    }
+
+    // The empty lines hold the places of the modules, in the order they were first imported.
+    let mut modules = modules.into_iter();
+    for line in lines.iter_mut().filter(|line| line.is_empty()) {
+        let Some((module, imports)) = modules.next() else {
+            break;
+        };
+        let all_types = imports.default.is_none() && imports.named.iter().all(|(_, type_only)| *type_only);
+        let named: Vec<String> = imports
+            .named
+            .iter()
+            .map(|(name, type_only)| if *type_only && !all_types { format!("type {}", name) } else { name.to_string() })
+            .collect();
+        let clause = match (imports.default, named.is_empty()) {
+            (Some(default), true) => default.to_string(),
+            (Some(default), false) => format!("{}, {{ {} }}", default, named.join(", ")),
+            (None, _) => format!("{{ {} }}", named.join(", ")),
+        };
+        *line = format!("import {}{} from {}", if all_types { "type " } else { "" }, clause, module);
+    }
+    lines
+}
+
+/// The path the service modules import the codecs from.
+fn codecs_module(lang: Language) -> &'static str {
+    if lang == Language::TypeScript {
+        "./codecs"
+    } else {
+        "./codecs.js"
+    }
+}
+
+/// Name of the service module holding the operations of `tag`, e.g. `PetService`.
+pub fn tag_service_name(tag: &str) -> String {
+    match pascal_case(tag) {
+        name if name.starts_with(|c: char| c.is_ascii_alphabetic()) => format!("{}Service", name),
+        name => format!("Api{}Service", name),
+    }
+}
+
+/// Merges the generated interfaces, client and service into a single `api.ts`, dropping the
+/// imports between them, merging those of each package into one and keeping one banner. Zod
+/// schemas, when present, stand in for the interfaces since they export types of the same names.
+pub fn bundle(swagger: &Swagger, files: GeneratedFiles) -> GeneratedFiles {
+    let interfaces = Path::new("interfaces");
+    let has_schemas = files.contains_key(Path::new("schemas.ts"));
+    let mut paths: Vec<&PathBuf> = files
+        .keys()
+        .filter(|path| **path != interfaces.join("index.ts") && !(has_schemas && path.starts_with(interfaces)))
+        .collect();
+    paths.sort_by_key(|path| (!path.starts_with(interfaces), *path));
+
+    let mut imports: Vec<&str> = Vec::new();
+    let mut sections = Vec::new();
+    for path in paths {
+        let contents = &files[path];
+        let contents = contents.split_once(" */\n\n").map_or(contents.as_str(), |(_, rest)| rest);
+        let mut section = String::new();
+        for line in contents.lines() {
+            if line.starts_with("import ") {
+                let local = line.contains(" from './") || line.contains(" from '../");
+                if !local && !imports.contains(&line) {
+                    imports.push(line);
+                }
+            } else {
+                section.push_str(line);
+                section.push('\n');
+            }
+        }
+        sections.push(section.trim_matches('\n').to_string());
+    }
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    if !imports.is_empty() {
+        ts_code.push_str(&merge_imports(&imports).join("\n"));
+        ts_code.push_str("\n\n");
+    }
+    ts_code.push_str(&sections.join("\n\n"));
+    ts_code.push('\n');
+
+    let mut bundled = GeneratedFiles::new();
+    bundled.insert(PathBuf::from("api.ts"), ts_code);
+    bundled
+}
+
+/// Writes one file per definition under `interfaces/`, plus an `index.ts` re-exporting them all,
+/// and `schemas.ts` or `guards.ts` when validators are requested.
+pub fn generate_interfaces(swagger: &Swagger, types: TypeOptions, files: &mut GeneratedFiles) {
+    if swagger.definitions.is_empty() {
+        return;
+    }
+    match types.validators {
+        Some(Validators::Zod) => {
+            files.insert(PathBuf::from("schemas.ts"), zod::generate_schemas(swagger, types));
+        }
+        Some(Validators::Guards) => {
+            files.insert(PathBuf::from("guards.ts"), guards::generate_guards(swagger, types));
+        }
+        None => {}
+    }
+    if let Some(codecs) = codecs::generate_codecs(swagger, types, Language::TypeScript) {
+        files.insert(PathBuf::from("codecs.ts"), codecs);
+    }
+    files.par_extend(swagger.definitions.par_iter().map(|(name, definition)| {
+        let ts_interface = generate_typescript_interface(swagger, name, definition, types);
+        (PathBuf::from("interfaces").join(format!("{}.ts", name)), ts_interface)
+    }));
+
+    let mut names: Vec<&String> = swagger.definitions.keys().collect();
+    names.sort();
+    let mut index = String::new();
+    generate_info_comment(swagger, &mut index);
+    for name in names {
+        index.push_str(&format!("export * from './{}';\n", name));
+    }
+    files.insert(PathBuf::from("interfaces").join("index.ts"), index);
+}
+
+/// Imports the given interfaces from the `interfaces/index.ts` barrel at `path`.
+pub fn import_interfaces(ts_code: &mut String, mut names: Vec<String>, path: &str) {
+    names.sort();
+    names.dedup();
+    if !names.is_empty() {
+        ts_code.push_str(&format!("import {{ {} }} from '{}';\n\n", names.join(", "), path));
+    }
+}
+
+pub fn generate_typescript_interface(
+    swagger: &Swagger,
+    name: &str,
+    definition: &Definition,
+    types: TypeOptions,
+) -> String {
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    let doc = definition_doc(definition);
+
+    if let Some(values) = &definition.enum_values {
+        push_doc(&mut ts_code, &doc, "");
+        match enum_declaration(name, values, types.enum_style) {
+            Some(declaration) => ts_code.push_str(&declaration),
+            None => ts_code.push_str(&format!("export type {} = {};\n", name, literal_union(values))),
+        }
+        return ts_code;
+    }
+
+    if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+        push_imports(&mut ts_code, name, union_references(members));
+        push_doc(&mut ts_code, &doc, "");
+        ts_code.push_str(&format!("export type {} = {};\n", name, union_type(definition, members, types)));
+        return ts_code;
+    }
+
+    if definition.properties.is_none() && definition.all_of.is_none() {
+        if let Some(values) = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false)) {
+            let imports = schema_reference(values).map(str::to_string).into_iter().collect();
+            push_imports(&mut ts_code, name, imports);
+            push_doc(&mut ts_code, &doc, "");
+            ts_code.push_str(&format!("export type {} = {};\n", name, record_type(values, types)));
+            return ts_code;
+        }
+    }
+
+    let (bases, cyclic): (Vec<&str>, Vec<&str>) = base_interfaces(definition)
+        .into_iter()
+        .partition(|base| !extends_definition(swagger, base, name));
+    for base in cyclic {
+        log::warn!("{} extends itself through allOf; skipping base {}", name, base);
+    }
+    let mut imports: Vec<String> = bases.iter().map(|base| base.to_string()).collect();
+    let mut declarations = String::new();
+    push_interface(
+        &mut declarations,
+        &mut imports,
+        name,
+        &bases,
+        &doc,
+        inline_properties(definition),
+        types,
+    );
+    push_imports(&mut ts_code, name, imports);
+    ts_code.push_str(&declarations);
+
+    if types.model_variants {
+        let properties = all_properties(swagger, definition, &mut vec![name]);
+        for (variant, flag) in [("Request", "readOnly"), ("Response", "writeOnly")] {
+            let omitted: Vec<String> = properties
+                .iter()
+                .filter(|(_, prop, _)| has_flag(prop, flag))
+                .map(|(prop_name, _, _)| string_literal(prop_name))
+                .collect();
+            if !omitted.is_empty() {
+                ts_code.push_str(&format!(
+                    "\nexport type {}{} = Omit<{}, {}>;\n",
+                    name,
+                    variant,
+                    name,
+                    omitted.join(" | ")
+                ));
+            }
+        }
+    }
+    ts_code
+}
+
+pub fn has_flag(prop: &Property, flag: &str) -> bool {
+    prop.additional.get(flag) == Some(&Value::Bool(true))
+}
+
+/// Writes the interface after the enums and nested interfaces hoisted out of its properties.
+fn push_interface(
+    ts_code: &mut String,
+    imports: &mut Vec<String>,
+    name: &str,
+    bases: &[&str],
+    doc: &[String],
+    properties: Vec<(&String, &Property, bool)>,
+    types: TypeOptions,
+) {
+    let mut interface = String::new();
+    push_doc(&mut interface, doc, "");
+    interface.push_str(&if bases.is_empty() {
+        format!("export interface {} {{\n", name)
+    } else {
+        format!("export interface {} extends {} {{\n", name, bases.join(", "))
+    });
+    for (prop_name, prop, required) in properties {
+        imports.extend(property_reference(prop).map(str::to_string));
+        if let Some((union, _)) = property_union(prop) {
+            imports.extend(union_references(union.one_of.as_ref().or(union.any_of.as_ref()).into_iter().flatten()));
+        }
+        let hoisted_name = format!("{}{}", name, pascal_case(prop_name));
+        let ts_type = if let Some(nested) = nested_definition(prop) {
+            push_interface(ts_code, imports, &hoisted_name, &[], &[], inline_properties(&nested), types);
+            ts_code.push('\n');
+            hoisted_name
+        } else {
+            match property_enum(prop) {
+                Some((values, is_array)) => match enum_declaration(&hoisted_name, values, types.enum_style) {
+                    Some(declaration) => {
+                        ts_code.push_str(&declaration);
+                        ts_code.push('\n');
+                        if is_array { format!("{}[]", hoisted_name) } else { hoisted_name }
+                    }
+                    None => property_type(prop, types),
+                },
+                None => property_type(prop, types),
+            }
+        };
+        let (ts_type, required) = nullable_property(prop, ts_type, required, types);
+        let optional = if required { "" } else { "?" };
+        let readonly = if has_flag(prop, "readOnly") { "readonly " } else { "" };
+        push_doc(&mut interface, &property_doc(prop), "    ");
+        interface.push_str(&format!("    {}{}{}: {};\n", readonly, property_name(prop_name), optional, ts_type));
+    }
+    interface.push_str("}\n");
+    ts_code.push_str(&interface);
+}
+
+/// The `oneOf`/`anyOf` members joined into a union, each narrowed to its discriminator value if any.
+pub fn union_type(definition: &Definition, members: &[Definition], types: TypeOptions) -> String {
+    let types: Vec<String> = members
+        .iter()
+        .map(|member| match discriminator_tag(definition, member) {
+            Some((property_name, tag)) => format!(
+                "({} & {{ {}: {} }})",
+                member.reference.as_deref().map(ref_name).unwrap_or_default(),
+                property_name,
+                string_literal(tag)
+            ),
+            None => member_type(member, types),
+        })
+        .collect();
+    types.join(" | ")
+}
+
+/// The definitions the members of a union refer to, themselves or through their inline properties.
+fn union_references<'a>(members: impl IntoIterator<Item = &'a Definition>) -> Vec<String> {
+    let mut references = Vec::new();
+    for member in members {
+        references.extend(member.reference.as_deref().map(|reference| ref_name(reference).to_string()));
+        let properties = inline_properties(member);
+        references.extend(properties.iter().filter_map(|(_, prop, _)| property_reference(prop)).map(str::to_string));
+    }
+    references
+}
+
+/// The `oneOf`/`anyOf` schema of a property or of its array items, with its discriminator, and
+/// whether it applies to the items.
+fn property_union(prop: &Property) -> Option<(Definition, bool)> {
+    let union = |keyword: &dyn Fn(&str) -> Option<Value>| {
+        let mut schema = serde_json::Map::new();
+        for key in ["oneOf", "anyOf", "discriminator"] {
+            if let Some(value) = keyword(key) {
+                schema.insert(key.to_string(), value);
+            }
+        }
+        let union: Definition = serde_json::from_value(Value::Object(schema)).ok()?;
+        (union.one_of.is_some() || union.any_of.is_some()).then_some(union)
+    };
+    if let Some(union) = union(&|key| prop.additional.get(key).cloned()) {
+        return Some((union, false));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => {
+            let items = prop.additional.get("items")?;
+            union(&|key| items.get(key).cloned()).map(|union| (union, true))
+        }
+        _ => None,
+    }
+}
+
+/// The discriminator property and the value it takes for a referenced union member, from the
+/// discriminator `mapping` or else the member's definition name.
+pub fn discriminator_tag<'a>(definition: &'a Definition, member: &'a Definition) -> Option<(&'a str, &'a str)> {
+    let discriminator = definition.discriminator.as_ref()?;
+    let property_name = discriminator.get("propertyName").and_then(Value::as_str)?;
+    let member_name = ref_name(member.reference.as_deref()?);
+    let tag = discriminator
+        .get("mapping")
+        .and_then(Value::as_object)
+        .and_then(|mapping| {
+            mapping
+                .iter()
+                .find(|(_, target)| target.as_str().map(ref_name) == Some(member_name))
+        })
+        .map_or(member_name, |(tag, _)| tag.as_str());
+    Some((property_name, tag))
+}
+
+/// Applies `nullable`/`x-nullable` to a property's type and whether it is required.
+fn nullable_property(prop: &Property, ts_type: String, required: bool, types: TypeOptions) -> (String, bool) {
+    let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+    match types.nullable_style {
+        _ if !nullable => (ts_type, required),
+        NullableStyle::Union => (format!("{} | null", ts_type), required),
+        NullableStyle::Optional => (ts_type, false),
+    }
+}
+
+/// An inline object type listing the definition's properties.
+pub fn object_literal(definition: &Definition, types: TypeOptions) -> String {
+    let fields: Vec<String> = inline_properties(definition)
+        .into_iter()
+        .map(|(prop_name, prop, required)| {
+            let (ts_type, required) = nullable_property(prop, property_type(prop, types), required, types);
+            format!("{}{}: {}", property_name(prop_name), if required { "" } else { "?" }, ts_type)
+        })
+        .collect();
+    if fields.is_empty() {
+        return "{}".to_string();
+    }
+    format!("{{ {} }}", fields.join("; "))
+}
+
+/// The schema of an object property declaring its own `properties`.
+pub fn nested_definition(prop: &Property) -> Option<Definition> {
+    if prop.property_type.as_deref().is_some_and(|t| t != "object") {
+        return None;
+    }
+    let mut schema = serde_json::Map::new();
+    schema.insert("properties".to_string(), prop.additional.get("properties")?.clone());
+    if let Some(required) = prop.additional.get("required") {
+        schema.insert("required".to_string(), required.clone());
+    }
+    serde_json::from_value(Value::Object(schema)).ok()
+}
+
+/// The value schema of a map property declared through `additionalProperties`.
+pub fn map_values(prop: &Property) -> Option<&Value> {
+    if prop.property_type.as_deref().is_some_and(|t| t != "object") {
+        return None;
+    }
+    prop.additional
+        .get("additionalProperties")
+        .filter(|values| **values != Value::Bool(false))
+}
+
+pub fn schema_reference(schema: &Value) -> Option<&str> {
+    schema.get("$ref").and_then(Value::as_str).map(ref_name)
+}
+
+/// A `Record` keyed by string whose values follow the `additionalProperties` schema.
+pub fn record_type(values: &Value, types: TypeOptions) -> String {
+    let value_type = match schema_reference(values) {
+        Some(name) => name.to_string(),
+        None => serde_json::from_value::<Property>(values.clone())
+            .ok()
+            .map(|prop| property_type(&prop, types))
+            .filter(|value_type| value_type != "any")
+            .unwrap_or_else(|| "unknown".to_string()),
+    };
+    format!("Record<string, {}>", value_type)
+}
+
+/// The type of an inline schema appearing as a union member.
+fn member_type(member: &Definition, types: TypeOptions) -> String {
+    if let Some(reference) = &member.reference {
+        return ref_name(reference).to_string();
+    }
+    if let Some(values) = &member.enum_values {
+        return literal_union(values);
+    }
+    if member.properties.is_some() {
+        return object_literal(member, types);
+    }
+    match member.definition_type.as_deref() {
+        Some("integer") | Some("number") => "number",
+        Some("string") => "string",
+        Some("boolean") => "boolean",
+        Some("array") => "any[]",
+        _ => "any",
+    }
+    .to_string()
+}
+
+/// Imports the referenced interfaces from their sibling files, skipping the interface itself.
+fn push_imports(ts_code: &mut String, name: &str, mut imports: Vec<String>) {
+    imports.retain(|import| *import != name);
+    imports.sort();
+    imports.dedup();
+    for import in &imports {
+        ts_code.push_str(&format!("import type {{ {} }} from './{}';\n", import, import));
+    }
+    if !imports.is_empty() {
+        ts_code.push('\n');
+    }
+}
+
+/// The definition a property refers to directly, through its array items or through its map values.
+fn property_reference(prop: &Property) -> Option<&str> {
+    prop.additional
+        .get("$ref")
+        .and_then(Value::as_str)
+        .map(ref_name)
+        .or_else(|| item_reference(prop))
+        .or_else(|| map_values(prop).and_then(schema_reference))
+}
+
+/// The definition an array property's items refer to through `$ref`.
+pub fn item_reference(prop: &Property) -> Option<&str> {
+    match prop.property_type.as_deref() {
+        Some("array") => prop.additional.get("items")?.get("$ref")?.as_str().map(ref_name),
+        _ => None,
+    }
+}
+
+/// Definitions this one extends through `$ref` entries in its `allOf`.
+pub fn base_interfaces(definition: &Definition) -> Vec<&str> {
+    definition
+        .all_of
+        .iter()
+        .flatten()
+        .filter_map(|part| part.reference.as_deref())
+        .map(ref_name)
+        .collect()
+}
+
+/// Whether `from` extends `to`, directly or through other definitions' `allOf` bases.
+pub fn extends_definition(swagger: &Swagger, from: &str, to: &str) -> bool {
+    let mut pending = vec![from];
+    let mut visited = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if name == to {
+            return true;
+        }
+        if visited.insert(name) {
+            pending.extend(swagger.definitions.get(name).map(base_interfaces).unwrap_or_default());
+        }
+    }
+    false
+}
+
+/// Properties declared on the definition and its inline `allOf` parts, with whether each is required.
+pub fn inline_properties(definition: &Definition) -> Vec<(&String, &Property, bool)> {
+    let mut properties = Vec::new();
+    for (prop_name, prop) in definition.properties.iter().flatten() {
+        let required = definition.required.as_ref().is_none_or(|r| r.contains(prop_name));
+        properties.push((prop_name, prop, required));
+    }
+    for part in definition.all_of.iter().flatten().filter(|part| part.reference.is_none()) {
+        properties.extend(inline_properties(part));
+    }
+    properties
+}
+
+/// Like `inline_properties`, with the properties of every `allOf` base flattened in first.
+pub fn all_properties<'a>(
+    swagger: &'a Swagger,
+    definition: &'a Definition,
+    seen: &mut Vec<&'a str>,
+) -> Vec<(&'a String, &'a Property, bool)> {
+    let mut properties = Vec::new();
+    for base in base_interfaces(definition) {
+        if seen.contains(&base) {
+            continue;
+        }
+        seen.push(base);
+        if let Some(base_definition) = swagger.definitions.get(base) {
+            properties.extend(all_properties(swagger, base_definition, seen));
+        }
+    }
+    properties.extend(inline_properties(definition));
+    properties
+}
+
+pub fn property_type(prop: &Property, types: TypeOptions) -> String {
+    if let Some((values, is_array)) = property_enum(prop) {
+        let union = literal_union(values);
+        return if is_array { format!("({})[]", union) } else { union };
+    }
+    if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+        return ref_name(reference).to_string();
+    }
+    if let Some(item) = item_reference(prop) {
+        return format!("{}[]", item);
+    }
+    if let Some((union, is_array)) = property_union(prop) {
+        let members = union.one_of.as_ref().or(union.any_of.as_ref()).map(Vec::as_slice).unwrap_or_default();
+        let union = union_type(&union, members, types);
+        return if is_array { format!("({})[]", union) } else { union };
+    }
+    if let Some(nested) = nested_definition(prop) {
+        return object_literal(&nested, types);
+    }
+    if let Some(values) = map_values(prop) {
+        return record_type(values, types);
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => {
+            let items = prop.additional.get("items");
+            let item_format = items.and_then(|items| items.get("format")).and_then(Value::as_str);
+            match items.and_then(|items| items.get("type")).and_then(Value::as_str) {
+                Some(item_type) => match primitive_type(item_type, item_format, types) {
+                    item_type if item_type.contains(' ') => format!("({})[]", item_type),
+                    item_type => format!("{}[]", item_type),
+                },
+                None => "any[]".to_string(),
+            }
+        }
+        Some(schema_type) => primitive_type(schema_type, prop.format.as_deref(), types),
+        None => "any".to_string(),
+    }
+}
+
+/// The TypeScript spelling of a primitive schema type, refined by its `format`.
+fn primitive_type(schema_type: &str, format: Option<&str>, types: TypeOptions) -> String {
+    match (schema_type, format) {
+        ("integer", Some("int64")) => match types.int64_type {
+            Int64Type::Number => "number",
+            Int64Type::Bigint => "bigint",
+            Int64Type::String => "string",
+        },
+        ("integer" | "number", _) => "number",
+        ("string", Some("date" | "date-time")) if types.date_type == DateType::Date => "Date",
+        ("string", Some("binary")) => "Blob",
+        ("string", Some("byte")) => "Uint8Array",
+        ("string", Some("uuid")) => "string & { readonly __brand: 'uuid' }",
+        ("string", _) => "string",
+        ("boolean", _) => "boolean",
+        _ => "any",
+    }
+    .to_string()
+}
+
+/// The `enum` values restricting a property or its array items, and whether they apply to the items.
+pub fn property_enum(prop: &Property) -> Option<(&Vec<Value>, bool)> {
+    if let Some(values) = prop.additional.get("enum").and_then(Value::as_array) {
+        return Some((values, false));
+    }
+    match prop.property_type.as_deref() {
+        Some("array") => prop
+            .additional
+            .get("items")
+            .and_then(|items| items.get("enum"))
+            .and_then(Value::as_array)
+            .map(|values| (values, true)),
+        _ => None,
+    }
+}
+
+pub fn literal_union(values: &[Value]) -> String {
+    let literals: Vec<String> = values
+        .iter()
+        .filter_map(|value| match value {
+            Value::String(value) => Some(string_literal(value)),
+            Value::Number(_) | Value::Bool(_) | Value::Null => Some(value.to_string()),
+            _ => None,
+        })
+        .collect();
+    if literals.is_empty() {
+        "any".to_string()
+    } else {
+        literals.join(" | ")
+    }
+}
+
+/// An `enum` declaration for string values; `None` when the union form should be used instead.
+fn enum_declaration(name: &str, values: &[Value], enum_style: EnumStyle) -> Option<String> {
+    if enum_style != EnumStyle::Enum || values.is_empty() {
+        return None;
+    }
+    if !values.iter().all(Value::is_string) {
+        return None;
+    }
+
+    let mut ts_code = format!("export enum {} {{\n", name);
+    for (member, value) in enum_members(values, |words| enum_member(&words.join("_"))) {
+        let literal = string_literal(value.as_str().unwrap_or_default());
+        ts_code.push_str(&format!("    {} = {},\n", member, literal));
+    }
+    ts_code.push_str("}\n");
+    Some(ts_code)
+}
+
+/// The name of the member an `enum` declaration gives a value, e.g. `InStock` for `in_stock`.
+pub fn enum_member(value: &str) -> String {
+    match pascal_case(value) {
+        member if member.starts_with(|c: char| c.is_ascii_alphabetic()) => member,
+        member => format!("Value{}", member),
+    }
+}
+
+pub fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn generate_jsdoc_types(swagger: &Swagger, types: TypeOptions) -> String {
+    let mut js_code = String::new();
+    generate_info_comment(swagger, &mut js_code);
+
+    for (name, definition) in &swagger.definitions {
+        let mut doc = definition_doc(definition);
+        let alias = if let Some(values) = &definition.enum_values {
+            Some(literal_union(values))
+        } else if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+            Some(union_type(definition, members, types))
+        } else if definition.properties.is_none() && definition.all_of.is_none() {
+            definition
+                .additional_properties
+                .as_ref()
+                .filter(|v| **v != Value::Bool(false))
+                .map(|values| record_type(values, types))
+        } else {
+            None
+        };
+        if let Some(alias) = alias {
+            doc.push(format!("@typedef {{{}}} {}", alias, name));
+            push_doc(&mut js_code, &doc, "");
+            js_code.push('\n');
+            continue;
+        }
+
+        doc.push(format!("@typedef {{Object}} {}", name));
+        for (prop_name, prop, required) in all_properties(swagger, definition, &mut vec![name.as_str()]) {
+            let (js_type, required) = nullable_property(prop, property_type(prop, types), required, types);
+            // Names that are not identifiers, such as `X-Trace`, are quoted as JSDoc asks.
+            let prop_name = property_name(prop_name);
+            let prop_name = if required { prop_name } else { format!("[{}]", prop_name) };
+            let description = property_doc(prop).into_iter().next().filter(|line| !line.starts_with('@'));
+            match description {
+                Some(description) => doc.push(format!("@property {{{}}} {} - {}", js_type, prop_name, description)),
+                None => doc.push(format!("@property {{{}}} {}", js_type, prop_name)),
+            }
+        }
+        js_code.push_str("/**\n");
+        for line in &doc {
+            js_code.push_str(&format!(" * {}\n", line));
+        }
+        js_code.push_str(" */\n\n");
+    }
+    js_code.push_str("export {};\n");
+    js_code
+}
+
+/// JSDoc lines carrying a schema's description, example and deprecation.
+fn schema_doc(description: Option<&str>, example: Option<&Value>, deprecated: bool) -> Vec<String> {
+    let mut doc: Vec<String> = description.into_iter().flat_map(str::lines).map(doc_text).collect();
+    if let Some(example) = example {
+        doc.push(format!("@example {}", doc_text(&example.to_string())));
+    }
+    if deprecated {
+        doc.push("@deprecated".to_string());
+    }
+    doc
+}
+
+fn definition_doc(definition: &Definition) -> Vec<String> {
+    schema_doc(definition.description.as_deref(), definition.example.as_ref(), definition.deprecated)
+}
+
+fn property_doc(prop: &Property) -> Vec<String> {
+    schema_doc(
+        prop.additional.get("description").and_then(Value::as_str),
+        prop.additional.get("example"),
+        has_flag(prop, "deprecated"),
+    )
+}
+
+/// JSDoc lines carrying an operation's summary, description and deprecation.
+pub fn operation_doc(operation: &Operation) -> Vec<String> {
+    let mut doc: Vec<String> = operation.summary.iter().flat_map(|summary| summary.lines()).map(doc_text).collect();
+    if let Some(description) = operation.description.as_deref().filter(|d| Some(*d) != operation.summary.as_deref()) {
+        if !doc.is_empty() {
+            doc.push(String::new());
+        }
+        doc.extend(description.lines().map(doc_text));
+    }
+    if operation.deprecated {
+        doc.push("@deprecated".to_string());
+    }
+    doc
+}
+
+/// Keeps free text from closing the surrounding comment early.
+fn doc_text(text: &str) -> String {
+    text.trim_end().replace("*/", "*\\/")
+}
+
+/// What the method templates know about the operation itself, beyond the pieces of its method.
+pub fn operation_context(operation: &Operation) -> Value {
+    json!({
+        "summary": operation.summary,
+        "description": operation.description,
+        "tags": operation.tags,
+        "deprecated": operation.deprecated,
+    })
+}
+
+/// Writes JSDoc lines as a comment block, collapsed onto one line when there is only one.
+pub fn push_doc(ts_code: &mut String, doc: &[String], indent: &str) {
+    match doc {
+        [] => {}
+        [line] => ts_code.push_str(&format!("{}/** {} */\n", indent, line)),
+        lines => {
+            ts_code.push_str(&format!("{}/**\n", indent));
+            for line in lines {
+                ts_code.push_str(format!("{} * {}", indent, line).trim_end());
+                ts_code.push('\n');
+            }
+            ts_code.push_str(&format!("{} */\n", indent));
+        }
+    }
+}
+
+pub fn generate_info_comment(swagger: &Swagger, ts_code: &mut String) {
+    ts_code.push_str(&info_block(swagger));
+    ts_code.push_str("\n\n");
+}
+
+/// Sends the requests of the axios instance `client` through its adapter again while they fail with a
+/// network error, 429 or 5xx, so that the interceptors and hooks run once for all the attempts.
+const AXIOS_RETRY_ADAPTER: &str = "/** Retries requests that failed with a network error, 429 or 5xx, unless aborted. */
+const send = axios.getAdapter(client.defaults.adapter);
+client.defaults.adapter = async config => {
+    for (let attempt = 0; ; attempt++) {
+        try {
+            return await send(config);
+        } catch (error) {
+            const response = axios.isAxiosError(error) ? error.response : undefined;
+            const retryable = response ? response.status === 429 || response.status >= 500 : !axios.isCancel(error);
+            if (!retryable || attempt >= MAX_RETRIES) {
+                throw error;
+            }
+            const delay = retryDelay(attempt, response?.headers['retry-after']);
+            await sleep(delay, config.signal as AbortSignal | undefined);
+        }
+    }
+};\n";
+
+/// The axios instance used by the service, exported so interceptors and defaults can be
+/// configured without touching the global axios instance. When split by tag, the client also
+/// exports the helpers the per-tag services share.
+fn generate_axios_client(swagger: &Swagger, service: ServiceOptions) -> String {
+    let helpers = service.split_by_tag;
+    let mut ts_code = String::new();
+
+    generate_info_comment(swagger, &mut ts_code);
+
+    ts_code.push_str("import axios from 'axios';\n");
+    let mut types = Vec::new();
+    if helpers && has_error_responses(swagger) {
+        types.push("AxiosError");
+    }
+    types.extend(["AxiosResponse", "InternalAxiosRequestConfig"]);
+    ts_code.push_str(&format!("import type {{ {} }} from 'axios';\n", types.join(", ")));
+    ts_code.push_str(&format!(
+        "\nexport const client = axios.create({{ baseURL: '{}', paramsSerializer: {{ indexes: null }} }});\n",
+        base_url(swagger)
+    ));
+    if let Some(retries) = service.retries {
+        ts_code.push('\n');
+        push_retry_helpers(&mut ts_code, Language::TypeScript, retries, true);
+        ts_code.push_str(AXIOS_RETRY_ADAPTER);
+    }
+    ts_code.push('\n');
+    push_hook_types(&mut ts_code, Language::TypeScript, HttpClient::Axios);
+    ts_code.push_str(&hook_registrations(Language::TypeScript, HttpClient::Axios, ""));
+    ts_code.pop();
+    if has_server_choices(swagger) {
+        ts_code.push('\n');
+        push_server_url_helper(&mut ts_code, swagger, Language::TypeScript);
+        ts_code.push_str(
+            "/** Points `client` at one of the documented servers. */
+export function selectServer<Url extends keyof ServerVariables>(url: Url, variables?: ServerVariables[Url]) {
+    client.defaults.baseURL = serverUrl(url, variables);
+}\n",
+        );
+    }
+    if helpers {
+        ts_code.push('\n');
+        push_axios_helpers(&mut ts_code, swagger, Language::TypeScript, true, false);
+        ts_code.pop();
+    }
+
+    ts_code
+}
+
+/// Writes the types and helpers the axios service methods share, exported when they live in
+/// `client.ts` rather than next to the methods.
+fn push_axios_helpers(ts_code: &mut String, swagger: &Swagger, lang: Language, export: bool, class: bool) {
+    push_request_options(ts_code, lang);
+    if has_error_responses(swagger) {
+        ts_code.push_str(
+            "/** An axios error whose response has the given status and body. */
+export type ResponseError<Status extends number, Body> = AxiosError<Body> & {
+    response: AxiosResponse<Body> & { status: Status };
+};\n\n",
+        );
+    }
+
+    if !swagger.security_definitions.is_empty() {
+        ts_code.push_str(&generate_auth_config(swagger, lang, export, class));
+    }
+    if has_header_parameters(swagger) {
+        push_request_headers_helper(ts_code, lang, export);
+    }
+    push_body_encoders(ts_code, swagger, lang, export);
+}
+
+/// A module of service functions, laid out by the `typescript/service` template, which `tag`
+/// names for the services split by tag. Each of `methods` is the context of a method.
+fn render_service(
+    swagger: &Swagger,
+    tag: Option<&str>,
+    imports: String,
+    helpers: String,
+    methods: Vec<Value>,
+) -> String {
+    let context = json!({
+        "banner": info_block(swagger),
+        "tag": tag,
+        "imports": imports,
+        "helpers": helpers,
+        "methods": methods,
+    });
+    templates::render("typescript/service", &context)
+}
+
+/// The context of a service method in the `typescript/service` template: its name, HTTP method
+/// and path, its `code` as rendered by the method template, followed by the function walking its
+/// pages if it has any, and its operation.
+fn method_context(method: &str, path: &str, operation: &Operation, code: String) -> Value {
+    json!({
+        "name": service_method_name(method, path, operation),
+        "method": method,
+        "path": path,
+        "code": code,
+        "operation": operation_context(operation),
+    })
+}
+
+fn generate_service(swagger: &Swagger, lang: Language, types: TypeOptions) -> String {
+    let mut imports = String::new();
+    if has_error_responses(swagger) {
+        imports.push_str("import type { AxiosError, AxiosResponse } from 'axios';\n");
+    }
+    imports.push_str("import { client } from './client';\n\n");
+    let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+    codecs::import_codecs(&mut imports, swagger, operations, types, codecs_module(lang));
+    if lang == Language::TypeScript {
+        import_interfaces(&mut imports, swagger.definitions.keys().cloned().collect(), "./interfaces");
+    }
+
+    let mut helpers = String::new();
+    push_axios_helpers(&mut helpers, swagger, lang, false, false);
+
+    let mut methods = Vec::new();
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let code = generate_service_method(swagger, method, path, operation, lang, types);
+            methods.push(method_context(method, path, operation, code));
+        }
+    }
+
+    render_service(swagger, None, imports, helpers, methods)
+}
+
+/// A service holding only the operations of `tag`, importing the client and helpers from
+/// `client` and just the interfaces its methods refer to.
+fn generate_tag_service(
+    swagger: &Swagger,
+    lang: Language,
+    http_client: HttpClient,
+    tag: &str,
+    operations: &[TaggedOperation],
+    type_options: TypeOptions,
+) -> String {
+    let typed = lang == Language::TypeScript;
+    let mut values = vec![match http_client {
+        HttpClient::Axios => "client",
+        HttpClient::Fetch => "request",
+    }];
+    let mut types = vec!["RequestOptions"];
+    for (_, method, operation) in operations {
+        if uses_auth(swagger, operation) {
+            values.push("withAuth");
+        }
+        if !header_parameters(operation).is_empty() {
+            values.push("requestHeaders");
+        }
+        if let Some(encoder) = body_encoder(swagger, operation).filter(|_| has_request_body(method)) {
+            values.push(encoder);
+        }
+        if !error_responses(operation).is_empty() {
+            types.push(match http_client {
+                HttpClient::Axios => "ResponseError",
+                HttpClient::Fetch => "ApiError",
+            });
+        }
+    }
+    for names in [&mut values, &mut types] {
+        names.sort_by_key(|name| name.to_lowercase());
+        names.dedup();
+    }
+
+    let mut imports = String::new();
+    let client = if typed { "./client" } else { "./client.js" };
+    imports.push_str(&format!("import {{ {} }} from '{}';\n", values.join(", "), client));
+    if typed && !types.is_empty() {
+        imports.push_str(&format!("import type {{ {} }} from '{}';\n", types.join(", "), client));
+    }
+    imports.push('\n');
+    let tagged = operations.iter().map(|(_, method, operation)| (*method, *operation));
+    codecs::import_codecs(&mut imports, swagger, tagged, type_options, codecs_module(lang));
+
+    let mut interfaces: Vec<String> = operations
+        .iter()
+        .flat_map(|(_, _, operation)| {
+            let body = request_body_reference(operation).map(str::to_string);
+            body.into_iter().chain(response_references(operation))
+        })
+        .collect();
+    if typed {
+        import_interfaces(&mut imports, interfaces, "./interfaces");
+    } else {
+        interfaces.sort();
+        interfaces.dedup();
+        for name in &interfaces {
+            imports.push_str(&format!("/** @typedef {{import('./types.js').{}}} {} */\n", name, name));
+        }
+        if !interfaces.is_empty() {
+            imports.push('\n');
+        }
+    }
+
+    let mut methods = Vec::new();
+    for (path, method, operation) in operations {
+        let code = match http_client {
+            HttpClient::Axios => generate_service_method(swagger, method, path, operation, lang, type_options),
+            HttpClient::Fetch => generate_fetch_method(swagger, method, path, operation, lang, type_options),
+        };
+        methods.push(method_context(method, path, operation, code));
+    }
+
+    render_service(swagger, Some(tag), imports, String::new(), methods)
+}
+
+/// The `AuthConfig` of the security schemes and the `withAuth` applying it to a request. With
+/// `class`, each `ApiClient` keeps its own credentials and passes them to `withAuth` first.
+pub fn generate_auth_config(swagger: &Swagger, lang: Language, export: bool, class: bool) -> String {
+    let schemes = security_schemes(swagger);
+    let typed = lang == Language::TypeScript;
+    let export = if export { "export " } else { "" };
+
+    let mut ts_code = String::new();
+    if typed {
+        ts_code.push_str("export interface AuthConfig {\n");
+        for (name, scheme) in &schemes {
+            let auth_type = if scheme.scheme_type == "basic" {
+                "{ username: string; password: string }"
+            } else {
+                "string"
+            };
+            ts_code.push_str(&format!("    '{}'?: {};\n", name, auth_type));
+        }
+        ts_code.push_str("}\n\n");
+
+        if class {
+            ts_code.push_str(&format!(
+                "{}function withAuth(auth: AuthConfig, requirements: (keyof AuthConfig)[][], config: any = {{}}): any {{\n",
+                export
+            ));
+        } else {
+            ts_code.push_str("let auth: AuthConfig = {};\n\n");
+            ts_code.push_str("export function setAuth(config: AuthConfig) {\n    auth = config;\n}\n\n");
+            ts_code.push_str(&format!(
+                "{}function withAuth(requirements: (keyof AuthConfig)[][], config: any = {{}}): any {{\n",
+                export
+            ));
+        }
+    } else {
+        ts_code.push_str("/**\n * @typedef {Object} AuthConfig\n");
+        for (name, scheme) in &schemes {
+            let auth_type = if scheme.scheme_type == "basic" {
+                "{ username: string, password: string }"
+            } else {
+                "string"
+            };
+            ts_code.push_str(&format!(" * @property {{{}}} [{}]\n", auth_type, property_name(name)));
+        }
+        ts_code.push_str(" */\n\n");
+
+        if class {
+            ts_code.push_str("/**\n * @param {AuthConfig} auth\n * @param {(keyof AuthConfig)[][]} requirements\n * @param {any} [config]\n * @returns {any}\n */\n");
+            ts_code.push_str(&format!("{}function withAuth(auth, requirements, config = {{}}) {{\n", export));
+        } else {
+            ts_code.push_str("/** @type {AuthConfig} */\nlet auth = {};\n\n");
+            ts_code.push_str("/**\n * @param {AuthConfig} config\n */\nexport function setAuth(config) {\n    auth = config;\n}\n\n");
+            ts_code.push_str("/**\n * @param {(keyof AuthConfig)[][]} requirements\n * @param {any} [config]\n * @returns {any}\n */\n");
+            ts_code.push_str(&format!("{}function withAuth(requirements, config = {{}}) {{\n", export));
+        }
+    }
+    ts_code.push_str(
+        "    const requirement = requirements.find(names => names.every(name => auth[name] !== undefined));
+    if (!requirement) {
+        return config;
+    }
+    const headers = { ...config.headers };
+    const params = { ...config.params };
+    for (const name of requirement) {
+        switch (name) {\n",
+    );
+    for (name, scheme) in &schemes {
+        let apply = match (scheme.scheme_type.as_str(), scheme.location.as_deref()) {
+            ("basic", _) => format!(
+                "headers['Authorization'] = `Basic ${{btoa(`${{auth['{0}']{1}.username}}:${{auth['{0}']{1}.password}}`)}}`;",
+                name,
+                if typed { "!" } else { "" }
+            ),
+            ("apiKey", Some("query")) => {
+                format!("params['{}'] = auth['{}'];", scheme.name.as_deref().unwrap_or(name), name)
+            }
+            ("apiKey", Some("cookie")) => format!(
+                "headers['Cookie'] = [headers['Cookie'], `{}=${{auth['{}']}}`].filter(Boolean).join('; ');",
+                scheme.name.as_deref().unwrap_or(name),
+                name
+            ),
+            ("apiKey", _) => {
+                format!("headers['{}'] = auth['{}'];", scheme.name.as_deref().unwrap_or(name), name)
+            }
+            _ => format!("headers['Authorization'] = `Bearer ${{auth['{}']}}`;", name),
+        };
+        ts_code.push_str(&format!(
+            "            case '{}':\n                {}\n                break;\n",
+            name, apply
+        ));
+    }
+    ts_code.push_str(
+        "        }
+    }
+    return { ...config, headers, params };
+}\n\n",
+    );
+
+    ts_code
+}
+
+/// Whether the requests of an operation go through `withAuth`.
+fn uses_auth(swagger: &Swagger, operation: &Operation) -> bool {
+    !swagger.security_definitions.is_empty() && !security_requirements(swagger, operation).is_empty()
+}
+
+fn generate_auth_argument(swagger: &Swagger, operation: &Operation, config: &str, class: bool) -> String {
+    if !uses_auth(swagger, operation) {
+        return config.to_string();
+    }
+    let requirements: Vec<String> = security_requirements(swagger, operation)
+        .iter()
+        .map(|names| {
+            let names: Vec<String> = names.iter().map(|name| format!("'{}'", name)).collect();
+            format!("[{}]", names.join(", "))
+        })
+        .collect();
+    let auth = if class { "this.auth, " } else { "" };
+    format!("withAuth({}[{}], {})", auth, requirements.join(", "), config)
+}
+
+/// Words JavaScript reserves, which cannot name the exported service functions.
+const RESERVED_WORDS: &[&str] = &[
+    "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete", "do", "else",
+    "enum", "export", "extends", "false", "finally", "for", "function", "if", "implements", "import", "in",
+    "instanceof", "interface", "let", "new", "null", "package", "private", "protected", "public", "return", "static",
+    "super", "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+/// The operationId as it is when it is a valid identifier, otherwise the camelCase form of the operation name.
+pub fn service_method_name(method: &str, path: &str, operation: &Operation) -> String {
+    let name = operation_name(method, path, operation);
+    let name = if is_identifier(&name) {
+        name
+    } else {
+        let pascal = pascal_case(&name);
+        let mut chars = pascal.chars();
+        match chars.next() {
+            Some(c) if c.is_ascii_digit() => format!("_{}", pascal),
+            Some(c) => c.to_ascii_lowercase().to_string() + chars.as_str(),
+            None => method.to_string(),
+        }
+    };
+    if RESERVED_WORDS.contains(&name.as_str()) {
+        format!("{}Operation", name)
+    } else {
+        name
+    }
+}
+
+/// How an operation's response is read when it is not JSON: as a `blob` for file downloads, or
+/// as `text` when the operation produces other media types instead of JSON.
+pub fn non_json_response(swagger: &Swagger, operation: &Operation) -> Option<&'static str> {
+    if has_binary_response(swagger, operation) {
+        Some("blob")
+    } else if accept_header(swagger, operation).is_some() {
+        Some("text")
+    } else {
+        None
+    }
+}
+
+/// The ways the operations read responses that are not JSON.
+fn response_types(swagger: &Swagger) -> Vec<&'static str> {
+    let mut types: Vec<&str> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .filter_map(|(_, operation)| non_json_response(swagger, operation))
+        .collect();
+    types.sort();
+    types.dedup();
+    types
+}
+
+/// The type an operation resolves to: `Blob` for file downloads, `string` for other responses
+/// that are not JSON, else the union of its 2xx response bodies, with `void` for a
+/// `204 No Content`, or `any` when none of them is documented.
+pub fn response_schema_name(swagger: &Swagger, operation: &Operation) -> String {
+    match non_json_response(swagger, operation) {
+        Some("blob") => return "Blob".to_string(),
+        Some(_) => return "string".to_string(),
+        None => {}
+    }
+    let mut types: Vec<String> = Vec::new();
+    for (status, response) in success_responses(operation) {
+        let member = match &response.response_schema {
+            Some(schema) => schema_type(schema),
+            None if status == "204" => "void".to_string(),
+            None => continue,
+        };
+        if !types.contains(&member) {
+            types.push(member);
+        }
+    }
+    if types.is_empty() {
+        "any".to_string()
+    } else {
+        types.join(" | ")
+    }
+}
+
+/// The definitions the success and error response bodies of an operation refer to.
+pub fn response_references(operation: &Operation) -> Vec<String> {
+    success_responses(operation)
+        .into_iter()
+        .chain(error_responses(operation))
+        .filter_map(|(_, response)| response.response_schema.as_ref().and_then(schema_definition))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Name of the type uniting the error responses of the method `method_name`.
+pub fn error_type_name(method_name: &str) -> String {
+    format!("{}Error", pascal_case(method_name))
+}
+
+/// The members of an operation's error union, one per error response, built by `member` from
+/// the status literal (`number` for status ranges and `default`) and the type of the body.
+pub fn error_members(operation: &Operation, member: impl Fn(&str, &str) -> String) -> Vec<String> {
+    error_responses(operation)
+        .into_iter()
+        .filter_map(|(status, response)| {
+            let status = if status.parse::<u16>().is_ok() { status } else { "number" };
+            response.response_schema.as_ref().map(|schema| member(status, &schema_type(schema)))
+        })
+        .collect()
+}
+
+pub fn push_error_type(ts_code: &mut String, name: &str, members: &[String]) {
+    ts_code.push_str(&format!("export type {} = {};\n\n", name, members.join(" | ")));
+}
+
+/// The TypeScript type of an operation's request body and whether it is required, taken from the
+/// Swagger 2.0 `in: body` parameter (or the OpenAPI 3 `requestBody` it is converted from).
+fn request_body(operation: &Operation) -> (Option<String>, bool) {
+    match operation.parameters.iter().find(|param| param.location == "body") {
+        Some(param) => (param.schema.as_ref().map(schema_type), param.required),
+        None => (None, false),
+    }
+}
+
+/// The definition the request body of an operation refers to, directly or as array items.
+pub fn request_body_reference(operation: &Operation) -> Option<&str> {
+    operation
+        .parameters
+        .iter()
+        .find(|param| param.location == "body")
+        .and_then(|param| param.schema.as_ref())
+        .and_then(schema_definition)
+}
+
+/// The definition a schema refers to, directly or as array items.
+pub fn schema_definition(schema: &Schema) -> Option<&str> {
+    let mut schema = Some(schema);
+    while let Some(current) = schema {
+        if let Some(reference) = &current.reference {
+            return Some(ref_name(reference));
+        }
+        schema = current.items.as_deref();
+    }
+    None
+}
+
+/// The type of the `data` argument of the method `method_name` and whether it is required: the
+/// form interface when the operation takes `formData` parameters, the request body otherwise.
+pub fn request_data(operation: &Operation, method_name: &str) -> (Option<String>, bool) {
+    let form = form_parameters(operation);
+    if form.is_empty() {
+        request_body(operation)
+    } else {
+        (Some(form_name(method_name)), form.iter().any(|param| param.required))
+    }
+}
+
+/// The `data` argument of a method, typed `fallback` when the body has no schema.
+pub fn body_argument(operation: &Operation, method_name: &str, fallback: &str) -> (String, bool) {
+    let (body_type, required) = request_data(operation, method_name);
+    let body_type = body_type.unwrap_or_else(|| fallback.to_string());
+    (format!("data{}: {}", if required { "" } else { "?" }, body_type), required)
+}
+
+/// The `in: formData` parameters of an operation.
+pub fn form_parameters(operation: &Operation) -> Vec<&Parameter> {
+    operation.parameters.iter().filter(|param| param.location == "formData").collect()
+}
+
+/// Name of the interface typing the form fields of the method `method_name`.
+pub fn form_name(method_name: &str) -> String {
+    format!("{}Form", pascal_case(method_name))
+}
+
+/// The helper encoding the body of an operation that is not sent as JSON: `formData` for
+/// `multipart/form-data`, `urlEncoded` for URL-encoded forms and `blobBody` for other media types.
+pub fn body_encoder(swagger: &Swagger, operation: &Operation) -> Option<&'static str> {
+    match request_media_type(swagger, operation) {
+        MULTIPART => Some("formData"),
+        URL_ENCODED => Some("urlEncoded"),
+        media_type if media_type.contains("json") => None,
+        _ => Some("blobBody"),
+    }
+}
+
+/// The body encoding helpers the operations with a request body need.
+pub fn body_encoders(swagger: &Swagger) -> Vec<&'static str> {
+    let mut encoders: Vec<&str> = swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .filter(|(method, _)| has_request_body(method))
+        .filter_map(|(_, operation)| body_encoder(swagger, operation))
+        .collect();
+    encoders.sort();
+    encoders.dedup();
+    encoders
+}
+
+/// The request body expression of a method passing its `data` argument as `name`.
+pub fn request_data_expression(swagger: &Swagger, operation: &Operation, name: &str, types: TypeOptions) -> String {
+    match body_encoder(swagger, operation) {
+        Some("blobBody") => format!("blobBody({}, {})", name, string_literal(request_media_type(swagger, operation))),
+        Some(encoder) => format!("{}({})", encoder, name),
+        None => match codecs::request_encoder(swagger, operation, types) {
+            Some(encoder) => format!("{}({})", encoder, name),
+            None => name.to_string(),
+        },
+    }
+}
+
+/// The headers an operation's requests carry for their media types: the `Content-Type` of bodies
+/// other than JSON and multipart, whose boundary the HTTP client adds, and the `Accept` of
+/// operations producing no JSON.
+pub fn media_headers(swagger: &Swagger, method: &str, operation: &Operation) -> Vec<String> {
+    let mut headers = Vec::new();
+    let media_type = request_media_type(swagger, operation);
+    if has_request_body(method) && media_type != "application/json" && media_type != MULTIPART {
+        headers.push(format!("'Content-Type': {}", string_literal(media_type)));
+    }
+    if let Some(accept) = accept_header(swagger, operation) {
+        headers.push(format!("Accept: {}", string_literal(&accept)));
+    }
+    headers
+}
+
+/// Writes `MAX_RETRIES` and `retryDelay`, the milliseconds to wait before a retry: as long as
+/// `Retry-After` asks when the server sends it as seconds or as a date, otherwise doubling from half
+/// a second. With `sleep`, also writes `sleep`, which waits that long unless `signal` aborts first.
+pub fn push_retry_helpers(ts_code: &mut String, lang: Language, retries: u32, sleep: bool) {
+    ts_code.push_str(&format!("const MAX_RETRIES = {};\n\n", retries));
+    if lang == Language::TypeScript {
+        ts_code.push_str("function retryDelay(attempt: number, retryAfter: unknown): number {\n");
+    } else {
+        ts_code
+            .push_str("/**\n * @param {number} attempt\n * @param {unknown} retryAfter\n * @returns {number}\n */\n");
+        ts_code.push_str("function retryDelay(attempt, retryAfter) {\n");
+    }
+    ts_code.push_str(
+        "    if (typeof retryAfter === 'string' && retryAfter.trim() !== '') {
+        const seconds = Number(retryAfter);
+        const date = Date.parse(retryAfter);
+        if (Number.isFinite(seconds)) {
+            return seconds * 1000;
+        } else if (!Number.isNaN(date)) {
+            return Math.max(date - Date.now(), 0);
+        }
+    }
+    return 500 * 2 ** attempt;
+}\n\n",
+    );
+    if !sleep {
+        return;
+    }
+    if lang == Language::TypeScript {
+        ts_code.push_str("function sleep(ms: number, signal?: AbortSignal | null): Promise<void> {\n");
+    } else {
+        ts_code.push_str(
+            "/**\n * @param {number} ms\n * @param {AbortSignal | null} [signal]\n * @returns {Promise<void>}\n */\n",
+        );
+        ts_code.push_str("function sleep(ms, signal) {\n");
+    }
+    ts_code.push_str(
+        "    return new Promise((resolve, reject) => {
+        if (signal?.aborted) {
+            reject(signal.reason);
+            return;
+        }
+        const abort = () => {
+            clearTimeout(timer);
+            reject(signal?.reason);
+        };
+        const timer = setTimeout(() => {
+            signal?.removeEventListener('abort', abort);
+            resolve();
+        }, ms);
+        signal?.addEventListener('abort', abort, { once: true });
+    });
+}\n\n",
+    );
+}
+
+/// Writes `serverUrl`, which fills in the `{variables}` of one of the documented servers. In
+/// TypeScript, `ServerVariables` types the variables of each server, `enum` values included.
+pub fn push_server_url_helper(ts_code: &mut String, swagger: &Swagger, lang: Language) {
+    let defaults: Vec<String> = swagger
+        .servers
+        .iter()
+        .map(|server| {
+            let values: Vec<String> = server
+                .variables
+                .iter()
+                .map(|(name, variable)| format!("{}: {}", property_name(name), string_literal(&variable.default)))
+                .collect();
+            let values = if values.is_empty() { "{}".to_string() } else { format!("{{ {} }}", values.join(", ")) };
+            format!("    {}: {},\n", string_literal(&server.url), values)
+        })
+        .collect();
+
+    if lang == Language::TypeScript {
+        ts_code.push_str("/** The variables of each documented server, by its URL. */\nexport interface ServerVariables {\n");
+        for server in &swagger.servers {
+            if let Some(description) = &server.description {
+                push_doc(ts_code, &[doc_text(description)], "    ");
+            }
+            let variables = &server.variables;
+            if variables.is_empty() {
+                ts_code.push_str(&format!("    {}: {{}};\n", string_literal(&server.url)));
+                continue;
+            }
+            ts_code.push_str(&format!("    {}: {{\n", string_literal(&server.url)));
+            for (name, variable) in variables {
+                if let Some(description) = &variable.description {
+                    push_doc(ts_code, &[doc_text(description)], "        ");
+                }
+                let variable_type = match &variable.enum_values {
+                    Some(values) if !values.is_empty() => {
+                        values.iter().map(|value| string_literal(value)).collect::<Vec<String>>().join(" | ")
+                    }
+                    _ => "string".to_string(),
+                };
+                ts_code.push_str(&format!("        {}?: {};\n", property_name(name), variable_type));
+            }
+            ts_code.push_str("    };\n");
+        }
+        ts_code.push_str("}\n\n");
+        ts_code.push_str("const SERVER_DEFAULTS: Record<keyof ServerVariables, Record<string, string>> = {\n");
+    } else {
+        ts_code.push_str("/** @type {Record<string, Record<string, string>>} */\nconst SERVER_DEFAULTS = {\n");
+    }
+    for line in defaults {
+        ts_code.push_str(&line);
+    }
+    ts_code.push_str("};\n\n");
+
+    let summary = "The URL of a documented server, its `{variables}` filled in from `variables` or their defaults.";
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!("/** {} */\n", summary));
+        ts_code.push_str(
+            "export function serverUrl<Url extends keyof ServerVariables>(url: Url, variables?: ServerVariables[Url]): string {
+    const values: Record<string, string | undefined> = { ...SERVER_DEFAULTS[url], ...variables };
+    return url.replace(/\\{([^}]+)\\}/g, (_, name: string) => values[name] ?? '');
+}\n\n",
+        );
+    } else {
+        let urls: Vec<String> = swagger.servers.iter().map(|server| string_literal(&server.url)).collect();
+        ts_code.push_str(&format!(
+            "/**
+ * {}
+ * @param {{{}}} url
+ * @param {{Record<string, string>}} [variables]
+ * @returns {{string}}
+ */
+export function serverUrl(url, variables) {{
+    const values = {{ ...SERVER_DEFAULTS[url], ...variables }};
+    return url.replace(/\\{{([^}}]+)\\}}/g, (_, name) => values[name] ?? '');
+}}\n\n",
+            summary,
+            urls.join(" | ")
+        ));
+    }
+}
+
+/// Writes the `RequestOptions` every service function takes after its parameters.
+fn push_request_options(ts_code: &mut String, lang: Language) {
+    if lang == Language::TypeScript {
+        ts_code.push_str(
+            "/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}\n\n",
+        );
+    } else {
+        ts_code.push_str(
+            "/**
+ * Options every service function takes after its parameters.
+ * @typedef {Object} RequestOptions
+ * @property {Record<string, string>} [headers] Headers sent with the request, merged with the ones the operation sets.
+ * @property {AbortSignal} [signal] Aborts the request when it fires.
+ * @property {number} [timeout] Milliseconds to wait for the response before the request is aborted.
+ * @property {Record<string, unknown>} [params] Query parameters sent with the request, merged with the operation's.
+ */\n\n",
+        );
+    }
+}
+
+/// Writes the types of the hooks `onRequest`, `onResponse` and `onError` register and, for fetch,
+/// the `Hooks` lists they are kept in with the helpers adding and running them.
+fn push_hook_types(ts_code: &mut String, lang: Language, http_client: HttpClient) {
+    let (request, response) = match http_client {
+        HttpClient::Axios => ("(config: InternalAxiosRequestConfig)", "(response: AxiosResponse)"),
+        HttpClient::Fetch => ("(url: URL, init: RequestInit)", "(response: Response)"),
+    };
+    let request_doc = match http_client {
+        HttpClient::Axios => "Called with the config of every request before it is sent, which it can change in place.",
+        HttpClient::Fetch => "Called before every request is sent with its URL and init, which it can change in place.",
+    };
+    let response_doc = match http_client {
+        HttpClient::Axios => "Called with every successful response.",
+        HttpClient::Fetch => "Called with every response before its body is read.",
+    };
+    let error_doc =
+        "Called with every error a request fails with; returning another error rejects with that one instead.";
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!(
+            "/** {} */
+export type RequestHook = {} => void | Promise<void>;
+
+/** {} */
+export type ResponseHook = {} => void | Promise<void>;
+
+/** {} */
+export type ErrorHook = (error: unknown) => unknown;\n\n",
+            request_doc, request, response_doc, response, error_doc
+        ));
+    } else {
+        ts_code.push_str(&format!(
+            "/**
+ * {}
+ * @callback RequestHook
+ * @param {{URL}} url
+ * @param {{RequestInit}} init
+ * @returns {{void | Promise<void>}}
+ */
+
+/**
+ * {}
+ * @callback ResponseHook
+ * @param {{Response}} response
+ * @returns {{void | Promise<void>}}
+ */
+
+/**
+ * {}
+ * @callback ErrorHook
+ * @param {{unknown}} error
+ * @returns {{unknown}}
+ */\n\n",
+            request_doc, response_doc, error_doc
+        ));
+    }
+    if http_client == HttpClient::Axios {
+        return;
+    }
+
+    if lang == Language::TypeScript {
+        ts_code.push_str(
+            "interface Hooks {
+    request: RequestHook[];
+    response: ResponseHook[];
+    error: ErrorHook[];
+}
+
+/** Adds `hook` to `hooks`, returning a function removing it again. */
+function addHook<Hook>(hooks: Hook[], hook: Hook): () => void {\n",
+        );
+    } else {
+        ts_code.push_str(
+            "/**
+ * @typedef {Object} Hooks
+ * @property {RequestHook[]} request
+ * @property {ResponseHook[]} response
+ * @property {ErrorHook[]} error
+ */
+
+/**
+ * Adds `hook` to `hooks`, returning a function removing it again.
+ * @template Hook
+ * @param {Hook[]} hooks
+ * @param {Hook} hook
+ * @returns {() => void}
+ */
+function addHook(hooks, hook) {\n",
+        );
+    }
+    ts_code.push_str(
+        "    hooks.push(hook);
+    return () => {
+        const index = hooks.indexOf(hook);
+        if (index >= 0) {
+            hooks.splice(index, 1);
+        }
+    };
+}\n\n",
+    );
+    if lang == Language::TypeScript {
+        ts_code.push_str(
+            "/** Passes `error` through the error `hooks`, each of which can replace it. */
+async function applyErrorHooks(hooks: ErrorHook[], error: unknown): Promise<unknown> {\n",
+        );
+    } else {
+        ts_code.push_str(
+            "/**
+ * Passes `error` through the error `hooks`, each of which can replace it.
+ * @param {ErrorHook[]} hooks
+ * @param {unknown} error
+ * @returns {Promise<unknown>}
+ */
+async function applyErrorHooks(hooks, error) {\n",
+        );
+    }
+    ts_code.push_str(
+        "    for (const hook of hooks) {
+        error = (await hook(error)) ?? error;
+    }
+    return error;
+}\n\n",
+    );
+}
+
+/// The exported `onRequest`, `onResponse` and `onError` functions registering hooks on the
+/// client `receiver` leads to: the axios interceptors of `client`, or the fetch `hooks` lists.
+fn hook_registrations(lang: Language, http_client: HttpClient, receiver: &str) -> String {
+    let responses = match http_client {
+        HttpClient::Axios => "with every successful response",
+        HttpClient::Fetch => "with every response",
+    };
+    let registrations = [
+        ("onRequest", "RequestHook", "request", "before every request is sent"),
+        ("onResponse", "ResponseHook", "response", responses),
+        ("onError", "ErrorHook", "error", "with every error a request fails with"),
+    ];
+    let mut ts_code = String::new();
+    for (name, hook_type, list, when) in registrations {
+        if lang == Language::TypeScript {
+            ts_code.push_str(&format!(
+                "/** Registers a hook called {}. Returns a function removing it. */
+export function {}(hook: {}): () => void {{\n",
+                when, name, hook_type
+            ));
+        } else {
+            ts_code.push_str(&format!(
+                "/**
+ * Registers a hook called {}.
+ * @param {{{}}} hook
+ * @returns {{() => void}} A function removing the hook.
+ */
+export function {}(hook) {{\n",
+                when, hook_type, name
+            ));
+        }
+        if http_client == HttpClient::Fetch {
+            ts_code.push_str(&format!("    return addHook({}hooks.{}, hook);\n}}\n\n", receiver, list));
+            continue;
+        }
+        let interceptor = match list {
+            "request" => "async config => {\n        await hook(config);\n        return config;\n    }",
+            "response" => "async response => {\n        await hook(response);\n        return response;\n    }",
+            _ => "undefined, async error => {\n        throw (await hook(error)) ?? error;\n    }",
+        };
+        let interceptors = if list == "request" { "request" } else { "response" };
+        ts_code.push_str(&format!(
+            "    const id = {0}client.interceptors.{1}.use({2});
+    return () => {0}client.interceptors.{1}.eject(id);
+}}\n\n",
+            receiver, interceptors, interceptor
+        ));
+    }
+    ts_code
+}
+
+/// Writes the `ApiError` the fetch service rejects with when a response is not successful,
+/// carrying the status and the parsed body. It extends `globalThis.Error`, as the service may
+/// import a definition named `Error` too.
+fn push_api_error_class(js_code: &mut String, lang: Language) {
+    if lang == Language::TypeScript {
+        js_code.push_str(
+            "export class ApiError<Status extends number = number, Body = unknown> extends globalThis.Error {
+    constructor(
+        readonly status: Status,
+        readonly body: Body,
+        message: string,
+    ) {
+        super(message);
+        this.name = 'ApiError';
+    }
+}\n\n",
+        );
+    } else {
+        js_code.push_str(
+            "export class ApiError extends Error {
+    /**
+     * @param {number} status
+     * @param {unknown} body
+     * @param {string} message
+     */
+    constructor(status, body, message) {
+        super(message);
+        this.name = 'ApiError';
+        this.status = status;
+        this.body = body;
+    }
+}\n\n",
+        );
+    }
+}
+
+/// Writes the body encoding helpers the operations need.
+pub fn push_body_encoders(ts_code: &mut String, swagger: &Swagger, lang: Language, export: bool) {
+    for encoder in body_encoders(swagger) {
+        match encoder {
+            "formData" => push_form_data_helper(ts_code, lang, export),
+            "urlEncoded" => push_url_encoded_helper(ts_code, lang, export),
+            _ => push_blob_body_helper(ts_code, lang, export),
+        }
+    }
+}
+
+/// Writes the `formData` helper, which appends the set fields of an object to a `FormData`,
+/// repeating array fields and leaving files as they are.
+fn push_form_data_helper(ts_code: &mut String, lang: Language, export: bool) {
+    let export = if export { "export " } else { "" };
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!("{}function formData(values: object = {{}}): FormData {{\n", export));
+    } else {
+        ts_code.push_str("/**\n * @param {object} [values]\n * @returns {FormData}\n */\n");
+        ts_code.push_str(&format!("{}function formData(values = {{}}) {{\n", export));
+    }
+    ts_code.push_str(
+        "    const form = new FormData();
+    for (const [name, value] of Object.entries(values)) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        for (const item of Array.isArray(value) ? value : [value]) {
+            form.append(name, item instanceof Blob ? item : typeof item === 'object' ? JSON.stringify(item) : String(item));
+        }
+    }
+    return form;
+}\n\n",
+    );
+}
+
+/// Writes the `urlEncoded` helper, which appends the set fields of an object to `URLSearchParams`,
+/// repeating array fields.
+fn push_url_encoded_helper(ts_code: &mut String, lang: Language, export: bool) {
+    let export = if export { "export " } else { "" };
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!("{}function urlEncoded(values: object = {{}}): URLSearchParams {{\n", export));
+    } else {
+        ts_code.push_str("/**\n * @param {object} [values]\n * @returns {URLSearchParams}\n */\n");
+        ts_code.push_str(&format!("{}function urlEncoded(values = {{}}) {{\n", export));
+    }
+    ts_code.push_str(
+        "    const params = new URLSearchParams();
+    for (const [name, value] of Object.entries(values)) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        for (const item of Array.isArray(value) ? value : [value]) {
+            params.append(name, typeof item === 'object' ? JSON.stringify(item) : String(item));
+        }
+    }
+    return params;
+}\n\n",
+    );
+}
+
+/// Writes the `blobBody` helper, which sends strings and files as they are in a `Blob` of the
+/// operation's media type, and anything else as JSON text.
+fn push_blob_body_helper(ts_code: &mut String, lang: Language, export: bool) {
+    let export = if export { "export " } else { "" };
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!("{}function blobBody(value: unknown, type: string): Blob {{\n", export));
+    } else {
+        ts_code.push_str("/**\n * @param {unknown} value\n * @param {string} type\n * @returns {Blob}\n */\n");
+        ts_code.push_str(&format!("{}function blobBody(value, type) {{\n", export));
+    }
+    ts_code.push_str(
+        "    return new Blob([value instanceof Blob || typeof value === 'string' ? value : JSON.stringify(value)], { type });
+}\n\n",
+    );
+}
+
+pub fn schema_type(schema: &Schema) -> String {
+    if let Some(reference) = &schema.reference {
+        return ref_name(reference).to_string();
+    }
+    if let Some(values) = &schema.enum_values {
+        return literal_union(values);
+    }
+    match schema.schema_type.as_deref() {
+        Some("array") => match schema.items.as_deref().map(schema_type) {
+            Some(items) if items.contains(' ') => format!("({})[]", items),
+            Some(items) => format!("{}[]", items),
+            None => "unknown[]".to_string(),
+        },
+        Some("object") => "Record<string, unknown>".to_string(),
+        None => "unknown".to_string(),
+        schema_type => scalar_type(schema_type, schema.format.as_deref()).to_string(),
+    }
+}
+
+pub fn query_parameters(operation: &Operation) -> Vec<&Parameter> {
+    operation.parameters.iter().filter(|param| param.location == "query").collect()
+}
+
+/// The query parameters in `params` as the entries of an object literal, with the arrays the API
+/// takes as one value joined by the delimiter of their `collectionFormat`; the others are repeated
+/// for each value.
+pub fn query_entries(params: &str, query: &[&Parameter]) -> Vec<String> {
+    let joined = query.iter().filter_map(|param| {
+        let delimiter = collection_delimiter(param)?;
+        let name = property_name(&param.name);
+        let access = if name == param.name { format!("?.{}", name) } else { format!("?.[{}]", name) };
+        let delimiter = string_literal(&delimiter.escape_default().to_string());
+        Some(format!("{}: {}{}?.join({})", name, params, access, delimiter))
+    });
+    std::iter::once(format!("...{}", params)).chain(joined).collect()
+}
+
+/// The query parameters in `params` as an expression, from [`query_entries`].
+pub fn query_values(params: &str, query: &[&Parameter]) -> String {
+    match query_entries(params, query).as_slice() {
+        [_] => params.to_string(),
+        entries => format!("{{ {} }}", entries.join(", ")),
+    }
+}
+
+/// The `in: header` and `in: cookie` parameters of an operation, leaving out the headers that
+/// OpenAPI reserves for the client and the security schemes.
+pub fn header_parameters(operation: &Operation) -> Vec<&Parameter> {
+    operation
+        .parameters
+        .iter()
+        .filter(|param| match param.location.as_str() {
+            "header" => !["accept", "content-type", "authorization"].contains(&param.name.to_ascii_lowercase().as_str()),
+            "cookie" => true,
+            _ => false,
+        })
+        .collect()
+}
+
+/// Name of the interface typing the query parameters of the method `method_name`.
+pub fn query_params_name(method_name: &str) -> String {
+    format!("{}Params", pascal_case(method_name))
+}
+
+/// Name of the interface typing the header and cookie parameters of the method `method_name`.
+pub fn headers_name(method_name: &str) -> String {
+    format!("{}Headers", pascal_case(method_name))
+}
+
+/// The `argument` of a method taking the given parameters as an `interface`, if it takes any.
+pub fn parameters_argument(argument: &str, interface: &str, params: &[&Parameter]) -> Option<String> {
+    if params.is_empty() {
+        return None;
+    }
+    let optional = if params.iter().any(|param| param.required) { "" } else { "?" };
+    Some(format!("{}{}: {}", argument, optional, interface))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MethodArgument {
+    Data,
+    Params,
+    Headers,
+}
+
+/// The order of the `data`, `params` and `headers` arguments a method takes, given for each
+/// whether it is required when present. Required arguments come first so none follows an
+/// optional one.
+pub fn argument_order(data: Option<bool>, params: Option<bool>, headers: Option<bool>) -> Vec<MethodArgument> {
+    let required = [(MethodArgument::Data, data), (MethodArgument::Params, params), (MethodArgument::Headers, headers)];
+    let optional = [(MethodArgument::Params, params), (MethodArgument::Headers, headers), (MethodArgument::Data, data)];
+    let required = required.into_iter().filter(|(_, argument)| *argument == Some(true));
+    let optional = optional.into_iter().filter(|(_, argument)| *argument == Some(false));
+    required.chain(optional).map(|(argument, _)| argument).collect()
+}
+
+/// Whether a method takes the given parameters and, if so, whether any of them is required.
+pub fn parameters_required(params: &[&Parameter]) -> Option<bool> {
+    (!params.is_empty()).then(|| params.iter().any(|param| param.required))
+}
+
+/// Declares the interface typing a group of an operation's parameters.
+pub fn push_parameters_interface(ts_code: &mut String, interface: &str, params: &[&Parameter]) {
+    ts_code.push_str(&format!("export interface {} {{\n", interface));
+    for param in params {
+        if let Some(description) = &param.description {
+            push_doc(ts_code, &[doc_text(description)], "    ");
+        }
+        ts_code.push_str(&format!(
+            "    {}{}: {};\n",
+            property_name(&param.name),
+            if param.required { "" } else { "?" },
+            parameter_type(param)
+        ));
+    }
+    ts_code.push_str("}\n\n");
+}
+
+/// The `@typedef` documenting a group of an operation's parameters in JavaScript.
+fn push_parameters_typedef(js_code: &mut String, interface: &str, params: &[&Parameter]) {
+    js_code.push_str(&format!("/**\n * @typedef {{Object}} {}\n", interface));
+    for param in params {
+        let name = property_name(&param.name);
+        let name = if param.required { name } else { format!("[{}]", name) };
+        let line = match &param.description {
+            Some(description) => format!(" * @property {{{}}} {} - {}", parameter_type(param), name, doc_text(description)),
+            None => format!(" * @property {{{}}} {}", parameter_type(param), name),
+        };
+        js_code.push_str(&line);
+        js_code.push('\n');
+    }
+    js_code.push_str(" */\n\n");
+}
+
+/// Whether any operation documents an error response body.
+pub fn has_error_responses(swagger: &Swagger) -> bool {
+    swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(_, operation)| !error_responses(operation).is_empty())
+}
+
+/// Whether any operation takes header or cookie parameters, and so needs `requestHeaders`.
+pub fn has_header_parameters(swagger: &Swagger) -> bool {
+    swagger
+        .paths
+        .values()
+        .flat_map(|path_item| path_item.operations())
+        .any(|(_, operation)| !header_parameters(operation).is_empty())
+}
+
+/// The `requestHeaders` call turning a method's `headers` argument into request headers.
+pub fn request_headers(params: &[&Parameter]) -> String {
+    let cookies: Vec<String> = params
+        .iter()
+        .filter(|param| param.location == "cookie")
+        .map(|param| string_literal(&param.name))
+        .collect();
+    if cookies.is_empty() {
+        "requestHeaders(headers)".to_string()
+    } else {
+        format!("requestHeaders(headers, [{}])", cookies.join(", "))
+    }
+}
+
+/// Writes the `requestHeaders` helper, which drops unset header parameters and folds cookie
+/// parameters into a `Cookie` header.
+pub fn push_request_headers_helper(ts_code: &mut String, lang: Language, export: bool) {
+    if lang == Language::TypeScript {
+        ts_code.push_str(&format!(
+            "{}function requestHeaders(values: object = {{}}, cookies: string[] = []): Record<string, string> {{\n",
+            if export { "export " } else { "" }
+        ));
+        ts_code.push_str("    const headers: Record<string, string> = {};\n    const cookie: string[] = [];\n");
+    } else {
+        ts_code.push_str(
+            "/**\n * @param {object} [values]\n * @param {string[]} [cookies]\n * @returns {Record<string, string>}\n */\n",
+        );
+        ts_code.push_str(&format!(
+            "{}function requestHeaders(values = {{}}, cookies = []) {{\n",
+            if export { "export " } else { "" }
+        ));
+        ts_code.push_str("    /** @type {Record<string, string>} */\n    const headers = {};\n    const cookie = [];\n");
+    }
+    ts_code.push_str(
+        "    for (const [name, value] of Object.entries(values)) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        if (cookies.includes(name)) {
+            cookie.push(`${name}=${encodeURIComponent(String(value))}`);
+        } else {
+            headers[name] = String(value);
+        }
+    }
+    if (cookie.length > 0) {
+        headers['Cookie'] = cookie.join('; ');
+    }
+    return headers;
+}\n\n",
+    );
+}
+
+pub fn parameter_type(param: &Parameter) -> String {
+    if let Some(values) = &param.enum_values {
+        return literal_union(values);
+    }
+    match param.parameter_type.as_deref() {
+        Some("array") => {
+            let items = param.items.as_ref();
+            let item_type = match items.and_then(|items| items.enum_values.as_deref()) {
+                Some(values) => format!("({})", literal_union(values)),
+                None => scalar_type(
+                    items.and_then(|items| items.schema_type.as_deref()),
+                    items.and_then(|items| items.format.as_deref()),
+                )
+                .to_string(),
+            };
+            format!("{}[]", item_type)
+        }
+        schema_type => scalar_type(schema_type, param.format.as_deref()).to_string(),
+    }
+}
+
+fn scalar_type(schema_type: Option<&str>, format: Option<&str>) -> &'static str {
+    match schema_type {
+        Some("integer") | Some("number") => "number",
+        Some("boolean") => "boolean",
+        Some("file") => "Blob",
+        Some("string") if format == Some("binary") => "Blob",
+        _ => "string",
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// The name of the type of a definition: the name itself when it is an identifier, otherwise its
+/// PascalCase form.
+pub fn type_name(name: &str) -> String {
+    if is_identifier(name) && !RESERVED_WORDS.contains(&name) {
+        return name.to_string();
+    }
+    let pascal = pascal_case(name);
+    match pascal.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("_{}", pascal),
+        Some(_) => pascal,
+        None => "Model".to_string(),
+    }
+}
+
+pub fn property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+/// A path parameter as the argument of a method taking it.
+pub struct PathArgument {
+    /// The name of the parameter in the path, e.g. `pet_id`.
+    pub name: String,
+    /// The camelCase identifier of the argument, e.g. `petId`.
+    pub argument: String,
+    /// The type of the argument, from the schema of the parameter.
+    pub ts_type: String,
+}
+
+impl PathArgument {
+    pub fn declaration(&self) -> String {
+        format!("{}: {}", self.argument, self.ts_type)
+    }
+}
+
+/// Names the methods give their other arguments, which a path parameter cannot take.
+const METHOD_ARGUMENTS: &[&str] = &["body", "config", "data", "headers", "options", "params"];
+
+/// The path parameters of an operation in the order of the path.
+pub fn path_arguments(path: &str, operation: &Operation) -> Vec<PathArgument> {
+    let mut arguments: Vec<PathArgument> = Vec::new();
+    for name in extract_path_params(path) {
+        let pascal = pascal_case(&name);
+        let mut chars = pascal.chars();
+        let camel = match chars.next() {
+            Some(c) if c.is_ascii_digit() => format!("_{}", pascal),
+            Some(c) => c.to_ascii_lowercase().to_string() + chars.as_str(),
+            None => "param".to_string(),
+        };
+        let camel = if RESERVED_WORDS.contains(&camel.as_str()) || METHOD_ARGUMENTS.contains(&camel.as_str()) {
+            format!("{}Param", camel)
+        } else {
+            camel
+        };
+        let mut argument = camel.clone();
+        let mut suffix = 2;
+        while arguments.iter().any(|taken| taken.argument == argument) {
+            argument = format!("{}{}", camel, suffix);
+            suffix += 1;
+        }
+        let parameter = operation.parameters.iter().find(|param| param.location == "path" && param.name == name);
+        let ts_type = parameter.map_or_else(|| "string".to_string(), parameter_type);
+        arguments.push(PathArgument { name, argument, ts_type });
+    }
+    arguments
+}
+
+/// `path` as the body of a template literal, with its parameters replaced by their arguments,
+/// URL-encoded.
+pub fn path_template(path: &str, arguments: &[PathArgument]) -> String {
+    arguments.iter().fold(path.to_string(), |acc, param| {
+        acc.replace(&format!("{{{}}}", param.name), &format!("${{encodeURIComponent({})}}", param.argument))
+    })
+}
+
+/// The request config of a method, with its query and header parameters merged in and, for file
+/// downloads, the body read as a `Blob`.
+fn request_config(
+    config: &str,
+    query: &[&Parameter],
+    headers: &[&Parameter],
+    media: &[String],
+    response_type: Option<&str>,
+) -> String {
+    let mut fields = Vec::new();
+    if !query.is_empty() {
+        fields.push(format!("params: {{ ...{}?.params, {} }}", config, query_entries("params", query).join(", ")));
+    }
+    if !headers.is_empty() || !media.is_empty() {
+        fields.push(header_overrides(config, headers, media));
+    }
+    if let Some(response_type) = response_type {
+        fields.push(format!("responseType: '{}'", response_type));
+    }
+    if fields.is_empty() {
+        config.to_string()
+    } else {
+        format!("{{ ...{}, {} }}", config, fields.join(", "))
+    }
+}
+
+/// The `headers` of a request: the media type headers, which `config` can override, then the
+/// header parameters.
+pub fn header_overrides(config: &str, headers: &[&Parameter], media: &[String]) -> String {
+    let mut entries = media.to_vec();
+    entries.push(format!("...{}?.headers", config));
+    if !headers.is_empty() {
+        entries.push(format!("...{}", request_headers(headers)));
+    }
+    format!("headers: {{ {} }}", entries.join(", "))
+}
+
+/// Reads the field at `path` of `object`, chaining optionally past the first step.
+fn field_access(object: &str, path: &[String]) -> String {
+    let mut access = object.to_string();
+    for (index, field) in path.iter().enumerate() {
+        let name = property_name(field);
+        match (index, name == *field) {
+            (0, true) => access.push_str(&format!(".{}", name)),
+            (0, false) => access.push_str(&format!("[{}]", name)),
+            (_, true) => access.push_str(&format!("?.{}", name)),
+            (_, false) => access.push_str(&format!("?.[{}]", name)),
+        }
+    }
+    access
+}
+
+/// The type of the items found at `path` in a response of type `response`.
+fn item_type(response: &str, path: &[String]) -> String {
+    if path.is_empty() {
+        return match response.strip_suffix("[]") {
+            Some(item) if !response.contains(' ') => item.to_string(),
+            _ if response.contains(' ') => format!("({})[number]", response),
+            _ => format!("{}[number]", response),
+        };
+    }
+    let container = path.iter().fold(response.to_string(), |container, field| {
+        format!("NonNullable<{}[{}]>", container, string_literal(field))
+    });
+    format!("{}[number]", container)
+}
+
+/// An async generator walking every page of the method `method_name`, called on `receiver`, which
+/// takes `arguments` (declarations in TypeScript, names with `param_doc` in JavaScript), and
+/// yielding their items. TypeScript gets none when the items are in a response that is not a named
+/// type, such as an inline object typed `Record<string, unknown>`, as their type is unknown.
+fn generate_all_method(
+    method_name: &str,
+    receiver: &str,
+    arguments: &[String],
+    param_doc: &[String],
+    pagination: &Pagination,
+    response_schema: &str,
+    lang: Language,
+) -> String {
+    let typed = lang == Language::TypeScript;
+    if typed && !pagination.items.is_empty() && !is_identifier(response_schema) {
+        return String::new();
+    }
+    let item = item_type(response_schema, &pagination.items);
+    let (variable, start, step) = match pagination.style {
+        PaginationStyle::Page => ("page", "1", "1"),
+        PaginationStyle::Offset => ("offset", "0", "items.length"),
+        PaginationStyle::Cursor => ("cursor", "", ""),
+    };
+    let key = match property_name(&pagination.param) {
+        name if name == variable => name,
+        name => format!("{}: {}", name, variable),
+    };
+    let call_arguments: Vec<String> = arguments
+        .iter()
+        .map(|argument| match argument.split(':').next().unwrap_or_default().trim_end_matches('?') {
+            "params" => format!("{{ ...params, {} }}", key),
+            name => name.to_string(),
+        })
+        .collect();
+    let param = |name: &str| match property_name(name) {
+        property if property == name => format!("params?.{}", name),
+        property => format!("params?.[{}]", property),
+    };
+
+    let mut ts_code = String::new();
+    let mut doc = vec![format!("Walks every page of `{}`, yielding the items of each.", method_name)];
+    if !typed {
+        doc.extend(param_doc.iter().cloned());
+        doc.push(format!("@returns {{AsyncGenerator<{}>}}", item));
+    }
+    push_doc(&mut ts_code, &doc, "");
+    ts_code.push_str(&format!(
+        "export async function* {}All({}){} {{\n",
+        method_name,
+        arguments.join(", "),
+        if typed { format!(": AsyncGenerator<{}>", item) } else { String::new() }
+    ));
+    let call = format!("await {}{}({})", receiver, method_name, call_arguments.join(", "));
+    let items = if pagination.items.is_empty() {
+        "response".to_string()
+    } else {
+        format!("{} ?? []", field_access("response", &pagination.items))
+    };
+    if pagination.style == PaginationStyle::Cursor {
+        ts_code.push_str(&format!(
+            "    let cursor = {};
+    do {{
+        const response = {};
+        yield* {};
+        cursor = {} ?? undefined;
+    }} while (cursor);
+}}\n\n",
+            param(&pagination.param),
+            call,
+            items,
+            field_access("response", &pagination.cursor)
+        ));
+        return ts_code;
+    }
+    // A page shorter than the requested size is the last one, which saves a request for an empty page.
+    let last_page = match &pagination.limit {
+        Some(limit) => {
+            ts_code.push_str(&format!("    const limit = {};\n", param(limit)));
+            " || (limit !== undefined && items.length < limit)"
+        }
+        None => "",
+    };
+    ts_code.push_str(&format!(
+        "    let {} = {} ?? {};
+    while (true) {{
+        const response = {};
+        const items = {};
+        yield* items;
+        if (items.length === 0{}) {{
+            return;
+        }}
+        {} += {};
+    }}
+}}\n\n",
+        variable,
+        param(&pagination.param),
+        start,
+        call,
+        items,
+        last_page,
+        variable,
+        step
+    ));
+    ts_code
+}
+
+fn generate_service_method(
+    swagger: &Swagger,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+    lang: Language,
+    types: TypeOptions,
+) -> String {
+    let path_params = path_arguments(path, operation);
+    let method_name = service_method_name(method, path, operation);
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+
+    let has_body = has_request_body(method);
+    let (data_declaration, data_required) = body_argument(operation, &method_name, "any");
+    let mut arguments: Vec<String> = path_params.iter().map(PathArgument::declaration).collect();
+    let order = argument_order(
+        has_body.then_some(data_required),
+        parameters_required(&query),
+        parameters_required(&headers),
+    );
+    for argument in order {
+        arguments.extend(match argument {
+            MethodArgument::Data => Some(data_declaration.clone()),
+            MethodArgument::Params => parameters_argument("params", &query_params_name(&method_name), &query),
+            MethodArgument::Headers => parameters_argument("headers", &headers_name(&method_name), &headers),
+        });
+    }
+    arguments.push("config?: RequestOptions".to_string());
+
+    let formatted_path = path_template(path, &path_params);
+
+    let response_type = if lang == Language::TypeScript {
+        format!("Promise<{}>", response_schema_name(swagger, operation))
+    } else {
+        "Promise<any>".to_string()
+    };
+
+    let form = form_parameters(operation);
+    let mut method_code = String::new();
+    if has_body && !form.is_empty() {
+        push_parameters_interface(&mut method_code, &form_name(&method_name), &form);
+    }
+    if !query.is_empty() {
+        push_parameters_interface(&mut method_code, &query_params_name(&method_name), &query);
+    }
+    if !headers.is_empty() {
+        push_parameters_interface(&mut method_code, &headers_name(&method_name), &headers);
+    }
+    let errors = error_members(operation, |status, body| format!("ResponseError<{}, {}>", status, body));
+    if !errors.is_empty() {
+        push_error_type(&mut method_code, &error_type_name(&method_name), &errors);
+    }
+    let media = media_headers(swagger, method, operation);
+    let config = request_config("config", &query, &headers, &media, non_json_response(swagger, operation));
+    let mut doc = String::new();
+    push_doc(&mut doc, &operation_doc(operation), "");
+    let context = json!({
+        "doc": doc,
+        "name": method_name,
+        "arguments": arguments.join(", "),
+        "return_type": response_type,
+        "method": method,
+        "path": formatted_path,
+        "data": if has_body { request_data_expression(swagger, operation, "data", types) } else { String::new() },
+        "config": generate_auth_argument(swagger, operation, &config, false),
+        "decode": codecs::response_decoder(swagger, operation, types),
+        "operation": operation_context(operation),
+    });
+    method_code.push_str(&templates::render("typescript/axios-method", &context));
+    method_code.push('\n');
+    if let Some(pagination) = pagination(swagger, method, operation) {
+        let response_schema = response_schema_name(swagger, operation);
+        method_code.push_str(&generate_all_method(
+            &method_name,
+            "",
+            &arguments,
+            &[],
+            &pagination,
+            &response_schema,
+            lang,
+        ));
+    }
+
+    method_code
+}
+
+fn generate_fetch_service(swagger: &Swagger, lang: Language, retries: Option<u32>, types: TypeOptions) -> String {
+    let mut imports = String::new();
+    let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+    codecs::import_codecs(&mut imports, swagger, operations, types, codecs_module(lang));
+    if lang == Language::TypeScript {
+        import_interfaces(&mut imports, swagger.definitions.keys().cloned().collect(), "./interfaces");
+    } else {
+        for name in swagger.definitions.keys() {
+            imports.push_str(&format!("/** @typedef {{import('./types.js').{}}} {} */\n", name, name));
+        }
+        if !swagger.definitions.is_empty() {
+            imports.push('\n');
+        }
+    }
+
+    let mut helpers = String::new();
+    push_fetch_helpers(&mut helpers, swagger, lang, false, retries, false);
+
+    let mut methods = Vec::new();
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let code = generate_fetch_method(swagger, method, path, operation, lang, types);
+            methods.push(method_context(method, path, operation, code));
+        }
+    }
+
+    render_service(swagger, None, imports, helpers, methods)
+}
+
+/// The fetch client of the per-tag services: the base URL, `ApiError` and the exported helpers.
+fn generate_fetch_client(swagger: &Swagger, lang: Language, retries: Option<u32>) -> String {
+    let mut js_code = String::new();
+
+    generate_info_comment(swagger, &mut js_code);
+    push_fetch_helpers(&mut js_code, swagger, lang, true, retries, false);
+    js_code.pop();
+
+    js_code
+}
+
+/// Writes the base URL, `ApiError`, the helpers and the `request` function the fetch service
+/// methods share, exported when they live in their own client module. An `ApiClient` `class` has
+/// its own `request` and base URL, so the documented servers are only offered to its constructor.
+fn push_fetch_helpers(
+    js_code: &mut String,
+    swagger: &Swagger,
+    lang: Language,
+    export: bool,
+    retries: Option<u32>,
+    class: bool,
+) {
+    if !has_server_choices(swagger) {
+        js_code.push_str(&format!("export const BASE_URL = '{}';\n\n", base_url(swagger)));
+    } else if class {
+        js_code.push_str(&format!("export const BASE_URL = '{}';\n\n", base_url(swagger)));
+        push_server_url_helper(js_code, swagger, lang);
+    } else {
+        js_code.push_str(&format!("export let BASE_URL = '{}';\n\n", base_url(swagger)));
+        push_server_url_helper(js_code, swagger, lang);
+        if lang == Language::TypeScript {
+            js_code.push_str(
+                "/** Points the service at one of the documented servers. */
+export function selectServer<Url extends keyof ServerVariables>(url: Url, variables?: ServerVariables[Url]) {
+    BASE_URL = serverUrl(url, variables);
+}\n\n",
+            );
+        } else {
+            js_code.push_str(
+                "/**
+ * Points the service at one of the documented servers.
+ * @param {Parameters<typeof serverUrl>[0]} url
+ * @param {Record<string, string>} [variables]
+ */
+export function selectServer(url, variables) {
+    BASE_URL = serverUrl(url, variables);
+}\n\n",
+            );
+        }
+    }
+    push_api_error_class(js_code, lang);
+
+    if !swagger.security_definitions.is_empty() {
+        js_code.push_str(&generate_auth_config(swagger, lang, export, class));
+    }
+    if has_header_parameters(swagger) {
+        push_request_headers_helper(js_code, lang, export);
+    }
+    push_body_encoders(js_code, swagger, lang, export);
+
+    if let Some(retries) = retries {
+        push_retry_helpers(js_code, lang, retries, true);
+        if lang == Language::TypeScript {
+            js_code.push_str(
+                "/** Sends a request, retrying it on network errors, 429 and 5xx responses unless `signal` aborted it. */
+async function sendWithRetries(send: () => Promise<Response>, signal?: AbortSignal | null): Promise<Response> {\n",
+            );
+        } else {
+            js_code.push_str(
+                "/**
+ * Sends a request, retrying it on network errors, 429 and 5xx responses unless `signal` aborted it.
+ * @param {() => Promise<Response>} send
+ * @param {AbortSignal | null} [signal]
+ * @returns {Promise<Response>}
+ */
+async function sendWithRetries(send, signal) {\n",
+            );
+        }
+        js_code.push_str(
+            "    for (let attempt = 0; ; attempt++) {
+        let response;
+        try {
+            response = await send();
+        } catch (error) {
+            if (signal?.aborted || attempt >= MAX_RETRIES) {
+                throw error;
+            }
+            await sleep(retryDelay(attempt, null), signal);
+            continue;
+        }
+        if ((response.status !== 429 && response.status < 500) || attempt >= MAX_RETRIES) {
+            return response;
+        }
+        await sleep(retryDelay(attempt, response.headers.get('Retry-After')), signal);
+    }
+}\n\n",
+        );
+    }
+
+    push_hook_types(js_code, lang, HttpClient::Fetch);
+    if !class {
+        if lang == Language::TypeScript {
+            js_code.push_str("const hooks: Hooks = { request: [], response: [], error: [] };\n\n");
+        } else {
+            js_code.push_str("/** @type {Hooks} */\nconst hooks = { request: [], response: [], error: [] };\n\n");
+        }
+        js_code.push_str(&hook_registrations(lang, HttpClient::Fetch, ""));
+    }
+    push_request_options(js_code, lang);
+    if !class {
+        push_fetch_request(js_code, swagger, lang, export, retries.is_some(), false);
+    }
+}
+
+/// Writes the `request` function sending the fetch requests, or with `class` the `request` method
+/// of an `ApiClient`, which sends them with its own base URL, default headers and `fetch`.
+fn push_fetch_request(
+    js_code: &mut String,
+    swagger: &Swagger,
+    lang: Language,
+    export: bool,
+    retries: bool,
+    class: bool,
+) {
+    let export = if export { "export " } else { "" };
+    // File downloads and other responses that are not JSON ask `request` to resolve to the body
+    // as a `Blob` or as text rather than parsed JSON.
+    let response_types = response_types(swagger);
+    let binary = response_types.contains(&"blob");
+    let options_type = if response_types.is_empty() {
+        "RequestOptions".to_string()
+    } else {
+        let types: Vec<String> = response_types.iter().map(|response_type| format!("'{}'", response_type)).collect();
+        format!("RequestOptions & {{ responseType?: {} }}", types.join(" | "))
+    };
+    let (receiver, function) = if class { ("this.", "") } else { ("", "function ") };
+    if lang == Language::TypeScript {
+        js_code.push_str(if class { "private " } else { export });
+        js_code.push_str(&format!(
+            "async {}request<T>(method: string, path: string, body?: unknown, init: {} = {{}}): Promise<T> {{\n",
+            function, options_type
+        ));
+    } else {
+        js_code.push_str(&format!(
+            "/**
+ * @param {{string}} method
+ * @param {{string}} path
+ * @param {{unknown}} [body]
+ * @param {{{}}} [init]
+ * @returns {{Promise<any>}}
+ */\n",
+            options_type
+        ));
+        js_code.push_str(if class { "" } else { export });
+        js_code.push_str(&format!("async {}request(method, path, body, init = {{}}) {{\n", function));
+    }
+    let fields = if response_types.is_empty() { "params, timeout" } else { "params, timeout, responseType" };
+    // A client's default headers come first, under the ones of the request.
+    let init = if class { "{ ...init, headers: { ...this.headers, ...init.headers } }" } else { "init" };
+    js_code.push_str(&format!("    const {{ {}, ...options }} = {};\n", fields, init));
+    let base_url = if class { "this.baseURL" } else { "BASE_URL" };
+    js_code.push_str(&format!("    const url = new URL({} + path, globalThis.location?.href);\n", base_url));
+    js_code.push_str(
+        "    for (const [name, value] of Object.entries(params ?? {})) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        for (const item of Array.isArray(value) ? value : [value]) {
+            url.searchParams.append(name, String(item));
+        }
+    }
+    // Aborts on the caller's signal or once the timeout passes, whichever comes first.
+    const signal = timeout === undefined ? options.signal : AbortSignal.any([AbortSignal.timeout(timeout), ...(options.signal ? [options.signal] : [])]);\n",
+    );
+    // Form, URL-encoded and other media type bodies come as `FormData`, `URLSearchParams` and `Blob`,
+    // which also callers passing their own bodies may use.
+    js_code.push_str(
+        "    const encoded = body instanceof FormData || body instanceof URLSearchParams || body instanceof Blob;\n",
+    );
+    js_code.push_str(if lang == Language::TypeScript {
+        "    const requestInit: RequestInit = {\n"
+    } else {
+        "    /** @type {RequestInit} */\n    const requestInit = {\n"
+    });
+    js_code.push_str(
+        "        ...options,
+        method,
+        signal,
+        // Leaves the Content-Type of encoded bodies, a multipart boundary included, to them and fetch,
+        // and sends none without a body, which would make every cross-origin GET preflighted.
+        headers: encoded || body === undefined ? options.headers : { 'Content-Type': 'application/json', ...options.headers },
+        body: encoded || body === undefined ? body : JSON.stringify(body),\n",
+    );
+    js_code.push_str(&format!(
+        "    }};
+    for (const hook of {0}hooks.request) {{
+        await hook(url, requestInit);
+    }}
+    let response{1};
+    try {{
+        response = await {2};
+    }} catch (error) {{
+        throw await applyErrorHooks({0}hooks.error, error);
+    }}
+    for (const hook of {0}hooks.response) {{
+        await hook(response);
+    }}\n",
+        receiver,
+        if lang == Language::TypeScript { ": Response" } else { "" },
+        if retries {
+            format!("sendWithRetries(() => {}fetch(url, requestInit), signal)", receiver)
+        } else {
+            format!("{}fetch(url, requestInit)", receiver)
+        }
+    ));
+    if !binary {
+        js_code.push_str("    const text = await response.text();\n");
+    }
+    js_code.push_str("    if (!response.ok) {\n");
+    if binary {
+        js_code.push_str("        const text = await response.text();\n");
+    }
+    js_code.push_str(&format!(
+        "        let data = text;
+        try {{
+            data = JSON.parse(text);
+        }} catch {{
+            // Keeps bodies that are not JSON, such as proxy error pages, as text.
+        }}
+        throw await applyErrorHooks(
+            {}hooks.error,
+            new ApiError(response.status, data, `${{method}} ${{path}} failed with status ${{response.status}}`),
+        );
+    }}\n",
+        receiver
+    ));
+    if binary {
+        js_code.push_str(&format!(
+            "    if (responseType === 'blob') {{
+        return (await response.blob()){};
+    }}
+    const text = await response.text();\n",
+            if lang == Language::TypeScript { " as T" } else { "" }
+        ));
+    }
+    if response_types.contains(&"text") {
+        js_code.push_str(&format!(
+            "    if (responseType === 'text') {{
+        return text{};
+    }}\n",
+            if lang == Language::TypeScript { " as T" } else { "" }
+        ));
+    }
+    js_code.push_str("    return text ? JSON.parse(text) : undefined;\n}\n\n");
+}
+
+fn generate_fetch_method(
+    swagger: &Swagger,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+    lang: Language,
+    types: TypeOptions,
+) -> String {
+    let (declarations, functions) = fetch_method_parts(swagger, method, path, operation, lang, types, None);
+    declarations + &functions
+}
+
+/// The parameter interfaces and error type of an operation, then the function sending it through
+/// `request`, followed by the one walking its pages. For the methods of an `ApiClient` `class`, the
+/// functions call `this.request` and reject with the errors of that client.
+fn fetch_method_parts(
+    swagger: &Swagger,
+    method: &str,
+    path: &str,
+    operation: &Operation,
+    lang: Language,
+    types: TypeOptions,
+    class: Option<HttpClient>,
+) -> (String, String) {
+    let path_params = path_arguments(path, operation);
+    let has_body = has_request_body(method);
+    let typed = lang == Language::TypeScript;
+    let response_schema = response_schema_name(swagger, operation);
+
+    let method_name = service_method_name(method, path, operation);
+    let query = query_parameters(operation);
+    let headers = header_parameters(operation);
+
+    let mut doc = operation_doc(operation);
+    let mut arguments = Vec::new();
+    for param in &path_params {
+        if typed {
+            arguments.push(param.declaration());
+        } else {
+            doc.push(format!("@param {{{}}} {}", param.ts_type, param.argument));
+            arguments.push(param.argument.clone());
+        }
+    }
+    let (body_type, data_required) = request_data(operation, &method_name);
+    let order = argument_order(
+        has_body.then_some(data_required),
+        parameters_required(&query),
+        parameters_required(&headers),
+    );
+    for argument in order {
+        let (name, interface, required) = match argument {
+            MethodArgument::Data => ("data", body_type.clone().unwrap_or_else(|| "any".to_string()), data_required),
+            MethodArgument::Params => ("params", query_params_name(&method_name), parameters_required(&query) == Some(true)),
+            MethodArgument::Headers => {
+                ("headers", headers_name(&method_name), parameters_required(&headers) == Some(true))
+            }
+        };
+        if !typed {
+            doc.push(format!("@param {{{}}} {}", interface, if required { name.to_string() } else { format!("[{}]", name) }));
+            arguments.push(name.to_string());
+        } else if argument == MethodArgument::Data {
+            arguments.push(body_argument(operation, &method_name, "unknown").0);
+        } else {
+            arguments.push(format!("{}{}: {}", name, if required { "" } else { "?" }, interface));
+        }
+    }
+    if typed {
+        arguments.push("config?: RequestOptions".to_string());
+    } else {
+        doc.push("@param {RequestOptions} [config]".to_string());
+        arguments.push("config".to_string());
+        doc.push(format!("@returns {{Promise<{}>}}", response_schema));
+    }
+
+    let formatted_path = path_template(path, &path_params);
+    let media = media_headers(swagger, method, operation);
+    let config = request_config("config", &query, &headers, &media, non_json_response(swagger, operation));
+    let config = generate_auth_argument(swagger, operation, &config, class.is_some());
+
+    let form = form_parameters(operation).into_iter().filter(|_| has_body).collect();
+    let mut declarations = String::new();
+    let groups = [
+        (form_name(&method_name), &form),
+        (query_params_name(&method_name), &query),
+        (headers_name(&method_name), &headers),
+    ];
+    for (interface, params) in groups {
+        match typed {
+            _ if params.is_empty() => {}
+            true => push_parameters_interface(&mut declarations, &interface, params),
+            false => push_parameters_typedef(&mut declarations, &interface, params),
+        }
+    }
+    let error = if class == Some(HttpClient::Axios) { "ResponseError" } else { "ApiError" };
+    let errors = error_members(operation, |status, body| format!("{}<{}, {}>", error, status, body));
+    if typed && !errors.is_empty() {
+        push_error_type(&mut declarations, &error_type_name(&method_name), &errors);
+    }
+    let receiver = if class.is_some() { "this." } else { "" };
+    let mut js_code = String::new();
+    let mut comment = String::new();
+    if !doc.is_empty() {
+        let lines: Vec<String> = doc.iter().map(|line| format!(" * {}", line).trim_end().to_string()).collect();
+        comment = format!("/**\n{}\n */\n", lines.join("\n"));
+    }
+    let context = json!({
+        "doc": comment,
+        "name": method_name,
+        "arguments": arguments.join(", "),
+        "return_type": if typed { format!("Promise<{}>", response_schema) } else { String::new() },
+        "response_type": response_schema,
+        "receiver": receiver,
+        "method": method.to_uppercase(),
+        "path": formatted_path,
+        "data": if has_body {
+            request_data_expression(swagger, operation, "data", types)
+        } else {
+            "undefined".to_string()
+        },
+        "config": config,
+        "decode": codecs::response_decoder(swagger, operation, types),
+        "operation": operation_context(operation),
+    });
+    js_code.push_str(&templates::render("typescript/fetch-method", &context));
+    js_code.push('\n');
+    if let Some(pagination) = pagination(swagger, method, operation) {
+        let param_doc: Vec<String> = doc.iter().filter(|line| line.starts_with("@param")).cloned().collect();
+        js_code.push_str(&generate_all_method(
+            &method_name,
+            receiver,
+            &arguments,
+            &param_doc,
+            &pagination,
+            &response_schema,
+            lang,
+        ));
+    }
+    (declarations, js_code)
+}
+
+/// A service exporting an `ApiClient` class, whose instances each hold a base URL, default
+/// headers, credentials and the axios instance or `fetch` sending their requests.
+fn generate_client_class(
+    swagger: &Swagger,
+    lang: Language,
+    http_client: HttpClient,
+    retries: Option<u32>,
+    type_options: TypeOptions,
+) -> String {
+    let typed = lang == Language::TypeScript;
+    let auth = !swagger.security_definitions.is_empty();
+    let mut ts_code = String::new();
+
+    generate_info_comment(swagger, &mut ts_code);
+
+    if http_client == HttpClient::Axios {
+        ts_code.push_str("import axios from 'axios';\n");
+        let mut types = vec!["AxiosInstance", "AxiosRequestConfig", "AxiosResponse", "InternalAxiosRequestConfig"];
+        if has_error_responses(swagger) {
+            types.push("AxiosError");
+        }
+        types.sort();
+        ts_code.push_str(&format!("import type {{ {} }} from 'axios';\n\n", types.join(", ")));
+    }
+    let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+    codecs::import_codecs(&mut ts_code, swagger, operations, type_options, codecs_module(lang));
+    if typed {
+        import_interfaces(&mut ts_code, swagger.definitions.keys().cloned().collect(), "./interfaces");
+    } else {
+        for name in swagger.definitions.keys() {
+            ts_code.push_str(&format!("/** @typedef {{import('./types.js').{}}} {} */\n", name, name));
+        }
+        if !swagger.definitions.is_empty() {
+            ts_code.push('\n');
+        }
+    }
+
+    match http_client {
+        HttpClient::Axios => {
+            ts_code.push_str(&format!("export const BASE_URL = '{}';\n\n", base_url(swagger)));
+            if has_server_choices(swagger) {
+                push_server_url_helper(&mut ts_code, swagger, lang);
+            }
+            if let Some(retries) = retries {
+                push_retry_helpers(&mut ts_code, lang, retries, true);
+                let adapter: Vec<String> = AXIOS_RETRY_ADAPTER
+                    .lines()
+                    .map(|line| if line.is_empty() { String::new() } else { format!("    {}", line) })
+                    .collect();
+                ts_code.push_str(&format!(
+                    "/** The axios instance of a client that was not given one, retrying failed requests. */
+function createClient(): AxiosInstance {{
+    const client = axios.create({{ paramsSerializer: {{ indexes: null }} }});
+{}
+    return client;
+}}\n\n",
+                    adapter.join("\n")
+                ));
+            }
+            push_axios_helpers(&mut ts_code, swagger, lang, false, true);
+            push_hook_types(&mut ts_code, lang, http_client);
+        }
+        HttpClient::Fetch => push_fetch_helpers(&mut ts_code, swagger, lang, false, retries, true),
+    }
+
+    let (sender, sender_type, sender_doc) = match http_client {
+        HttpClient::Axios => {
+            ("axios", "AxiosInstance", "The axios instance sending the requests, a new one by default.")
+        }
+        HttpClient::Fetch => ("fetch", "typeof fetch", "The `fetch` sending the requests, the global one by default."),
+    };
+    let base_url_doc = "The URL the operation paths are resolved against, `BASE_URL` by default.";
+    let headers_doc = "Headers sent with every request, under the ones of the request itself.";
+    if typed {
+        ts_code.push_str(&format!(
+            "/** Options of a new `ApiClient`. */
+export interface ApiClientOptions {{
+    /** {} */
+    baseURL?: string;
+    /** {} */
+    headers?: Record<string, string>;
+    /** {} */
+    {}?: {};
+}}\n\n",
+            base_url_doc, headers_doc, sender_doc, sender, sender_type
+        ));
+    } else {
+        ts_code.push_str(&format!(
+            "/**
+ * Options of a new `ApiClient`.
+ * @typedef {{Object}} ApiClientOptions
+ * @property {{string}} [baseURL] {}
+ * @property {{Record<string, string>}} [headers] {}
+ * @property {{{}}} [{}] {}
+ */\n\n",
+            base_url_doc, headers_doc, sender_type, sender, sender_doc
+        ));
+    }
+
+    let mut methods = String::new();
+    for (path, path_item) in &swagger.paths {
+        for (method, operation) in path_item.operations() {
+            let (declarations, functions) =
+                fetch_method_parts(swagger, method, path, operation, lang, type_options, Some(http_client));
+            ts_code.push_str(&declarations);
+            methods.push_str(&functions);
+        }
+    }
+
+    let (field, default) = match http_client {
+        HttpClient::Axios if retries.is_some() => ("client", "createClient()".to_string()),
+        HttpClient::Axios => ("client", "axios.create({ paramsSerializer: { indexes: null } })".to_string()),
+        HttpClient::Fetch => ("fetch", "globalThis.fetch.bind(globalThis)".to_string()),
+    };
+    ts_code.push_str("/** A client of the API, sending requests with its own base URL, default headers");
+    ts_code.push_str(if auth { " and credentials. */\n" } else { ". */\n" });
+    ts_code.push_str("export class ApiClient {\n");
+    if typed {
+        ts_code.push_str(&format!(
+            "    private readonly baseURL: string;
+    private readonly headers: Record<string, string>;
+    private readonly {}: {};\n",
+            field, sender_type
+        ));
+        if http_client == HttpClient::Fetch {
+            ts_code.push_str("    private readonly hooks: Hooks = { request: [], response: [], error: [] };\n");
+        }
+        if auth {
+            ts_code.push_str("    private auth: AuthConfig = {};\n");
+        }
+        ts_code.push_str("\n    constructor(options: ApiClientOptions = {}) {\n");
+    } else {
+        ts_code.push_str(
+            "    /**\n     * @param {ApiClientOptions} [options]\n     */\n    constructor(options = {}) {\n",
+        );
+    }
+    ts_code.push_str(&format!(
+        "        this.baseURL = options.baseURL ?? BASE_URL;
+        this.headers = options.headers ?? {{}};
+        this.{} = options.{} ?? {};\n",
+        field, sender, default
+    ));
+    if !typed {
+        ts_code.push_str("        /** @type {Hooks} */\n");
+        ts_code.push_str("        this.hooks = { request: [], response: [], error: [] };\n");
+    }
+    if auth && !typed {
+        ts_code.push_str("        /** @type {AuthConfig} */\n        this.auth = {};\n");
+    }
+    ts_code.push_str("    }\n\n");
+    if auth {
+        if typed {
+            ts_code.push_str("    /** Sets the credentials sent with the operations that require them. */\n");
+            ts_code.push_str("    setAuth(auth: AuthConfig) {\n");
+        } else {
+            ts_code.push_str("    /**\n     * Sets the credentials sent with the operations that require them.\n");
+            ts_code.push_str("     * @param {AuthConfig} auth\n     */\n    setAuth(auth) {\n");
+        }
+        ts_code.push_str("        this.auth = auth;\n    }\n\n");
+    }
+    ts_code.push_str(&class_members(&hook_registrations(lang, http_client, "this.")));
+    ts_code.push_str(&class_members(&methods));
+
+    let mut request = String::new();
+    match http_client {
+        HttpClient::Axios => request.push_str(
+            "private async request<T>(method: string, url: string, data: unknown, config: AxiosRequestConfig = {}): Promise<T> {
+    const headers = { ...this.headers, ...config.headers };
+    const response = await this.client.request<T>({ ...config, baseURL: this.baseURL, method, url, data, headers });
+    return response.data;
+}\n",
+        ),
+        HttpClient::Fetch => {
+            push_fetch_request(&mut request, swagger, lang, false, retries.is_some(), true);
+            request.pop();
+        }
+    }
+    ts_code.push_str(&class_members(&request));
+    ts_code.push_str("}\n");
+
+    ts_code
+}
+
+/// Turns the exported service functions in `code` into methods, indented to sit in a class body.
+fn class_members(code: &str) -> String {
+    let mut members = String::new();
+    for line in code.lines() {
+        let line = match line.strip_prefix("export async function* ") {
+            Some(rest) => format!("async *{}", rest),
+            None => match line.strip_prefix("export async function ") {
+                Some(rest) => format!("async {}", rest),
+                None => line.strip_prefix("export function ").unwrap_or(line).to_string(),
+            },
+        };
+        if !line.is_empty() {
+            members.push_str("    ");
+        }
+        members.push_str(&line);
+        members.push('\n');
+    }
+    members
+}
+
+fn extract_path_params(path: &str) -> Vec<String> {
+    let mut params = Vec::new();
+    for segment in path.split('/') {
+        if segment.starts_with('{') && segment.ends_with('}') {
+            params.push(segment[1..segment.len() - 1].to_string());
+        }
+    }
+    params
+}
@@ -0,0 +1,85 @@
+use super::operations_by_tag;
+use super::typescript::{
+    self, generate_info_comment, operation_doc, push_doc, service_method_name, tag_service_name, ServiceOptions,
+    TypeOptions,
+};
+use crate::cli::{Language, ServiceStyle};
+use crate::output::GeneratedFiles;
+use crate::spec::Swagger;
+use std::path::PathBuf;
+
+pub fn generate(swagger: &Swagger, service: ServiceOptions, types: TypeOptions) -> GeneratedFiles {
+    // The composables call the service functions, so there is no class to construct.
+    let service = ServiceOptions { style: ServiceStyle::Functions, ..service };
+    let mut files = typescript::generate(swagger, Language::TypeScript, service, types);
+    files.insert(PathBuf::from("composables.ts"), generate_composables(swagger, service.split_by_tag));
+    files
+}
+
+/// The `use<Method>` composables, calling into `service.ts` or, when split by tag, into the
+/// service of each operation's tag.
+fn generate_composables(swagger: &Swagger, split_by_tag: bool) -> String {
+    let tags = operations_by_tag(swagger);
+    let modules: Vec<String> = if split_by_tag {
+        tags.keys().map(|tag| tag_service_name(tag)).collect()
+    } else {
+        vec!["service".to_string()]
+    };
+
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { ref, shallowRef, Ref } from 'vue';\n\n");
+    for module in &modules {
+        ts_code.push_str(&format!("import * as {0} from './{0}';\n", module));
+    }
+    ts_code.push_str(
+        "
+export interface UseRequest<T, A extends unknown[]> {
+    data: Ref<T | undefined>;
+    error: Ref<unknown>;
+    loading: Ref<boolean>;
+    execute: (...args: A) => Promise<T | undefined>;
+}
+
+function useRequest<T, A extends unknown[]>(request: (...args: A) => Promise<T>): UseRequest<T, A> {
+    const data = shallowRef<T | undefined>();
+    const error = shallowRef<unknown>();
+    const loading = ref(false);
+
+    async function execute(...args: A): Promise<T | undefined> {
+        loading.value = true;
+        error.value = undefined;
+        try {
+            data.value = await request(...args);
+            return data.value;
+        } catch (e) {
+            error.value = e;
+            return undefined;
+        } finally {
+            loading.value = false;
+        }
+    }
+
+    return { data, error, loading, execute };
+}\n",
+    );
+
+    for (tag, operations) in &tags {
+        let module = if split_by_tag { tag_service_name(tag) } else { "service".to_string() };
+        for (path, method, operation) in operations {
+            let method_name = service_method_name(method, path, operation);
+            let mut chars = method_name.chars();
+            let composable = match chars.next() {
+                Some(c) => format!("use{}{}", c.to_ascii_uppercase(), chars.as_str()),
+                None => continue,
+            };
+            ts_code.push('\n');
+            push_doc(&mut ts_code, &operation_doc(operation), "");
+            ts_code.push_str(&format!(
+                "export function {}() {{\n    return useRequest({}.{});\n}}\n",
+                composable, module, method_name
+            ));
+        }
+    }
+    ts_code
+}
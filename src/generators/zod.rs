@@ -0,0 +1,375 @@
+use super::typescript::{
+    base_interfaces, discriminator_tag, extends_definition, generate_info_comment, has_flag, inline_properties, map_values,
+    nested_definition, object_literal, property_enum, record_type, string_literal, union_type,
+    TypeOptions,
+};
+use crate::cli::{DateType, Int64Type, NullableStyle};
+use crate::spec::{ref_name, Definition, Property, Swagger};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// `schemas.ts`: a Zod schema per definition, each followed by the type inferred from it.
+pub fn generate_schemas(swagger: &Swagger, types: TypeOptions) -> String {
+    let mut ts_code = String::new();
+    generate_info_comment(swagger, &mut ts_code);
+    ts_code.push_str("import { z } from 'zod';\n");
+
+    let mut declared = HashSet::new();
+    for name in declaration_order(swagger) {
+        let definition = &swagger.definitions[name];
+        let schemas = Schemas { swagger, name, types, declared: &declared };
+        let schema = schemas.definition(definition, "");
+        ts_code.push('\n');
+        // Recursive schemas cannot infer their own type, so they are annotated with one instead.
+        if references(swagger, name, name) {
+            ts_code.push_str(&format!("export type {} = {};\n", name, schemas.recursive_type(definition)));
+            ts_code.push_str(&format!("export const {}Schema: z.ZodType<{}> = {};\n", name, name, schema));
+        } else {
+            ts_code.push_str(&format!("export const {}Schema = {};\n", name, schema));
+            ts_code.push_str(&format!("export type {} = z.infer<typeof {}Schema>;\n", name, name));
+        }
+        declared.insert(name);
+    }
+    ts_code
+}
+
+struct Schemas<'a> {
+    swagger: &'a Swagger,
+    name: &'a str,
+    types: TypeOptions,
+    declared: &'a HashSet<&'a str>,
+}
+
+impl Schemas<'_> {
+    fn reference(&self, reference: &str) -> String {
+        let name = ref_name(reference);
+        if !self.swagger.definitions.contains_key(name) {
+            "z.any()".to_string()
+        } else if self.declared.contains(name) {
+            format!("{}Schema", name)
+        } else {
+            format!("z.lazy(() => {}Schema)", name)
+        }
+    }
+
+    fn definition(&self, definition: &Definition, indent: &str) -> String {
+        if let Some(reference) = &definition.reference {
+            return self.reference(reference);
+        }
+        if let Some(values) = &definition.enum_values {
+            return enum_schema(values);
+        }
+        if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+            let members: Vec<String> = members
+                .iter()
+                .map(|member| match discriminator_tag(definition, member) {
+                    Some((property_name, tag)) => format!(
+                        "{}.and(z.object({{ {}: z.literal({}) }}))",
+                        self.definition(member, indent),
+                        property_key(property_name),
+                        string_literal(tag)
+                    ),
+                    None => self.definition(member, indent),
+                })
+                .collect();
+            return match members.as_slice() {
+                [member] => member.clone(),
+                _ => format!("z.union([{}])", members.join(", ")),
+            };
+        }
+        if definition.properties.is_none() && definition.all_of.is_none() {
+            if let Some(values) = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false)) {
+                return self.record(values, indent);
+            }
+        }
+        if definition.properties.is_some() || definition.all_of.is_some() {
+            let mut parts: Vec<String> = self.bases(definition).into_iter().map(|base| self.reference(base)).collect();
+            let properties = inline_properties(definition);
+            if !properties.is_empty() || parts.is_empty() {
+                parts.push(self.object(properties, indent));
+            }
+            return parts.join(".and(") + &")".repeat(parts.len() - 1);
+        }
+        match definition.definition_type.as_deref() {
+            Some("string") => "z.string()",
+            Some("integer") => "z.number().int()",
+            Some("number") => "z.number()",
+            Some("boolean") => "z.boolean()",
+            Some("array") => "z.array(z.any())",
+            _ => "z.any()",
+        }
+        .to_string()
+    }
+
+    /// The `allOf` bases, leaving out any that extend this definition in turn.
+    fn bases<'d>(&self, definition: &'d Definition) -> Vec<&'d str> {
+        base_interfaces(definition)
+            .into_iter()
+            .filter(|base| !extends_definition(self.swagger, base, self.name))
+            .collect()
+    }
+
+    /// The TypeScript type a recursive schema is annotated with.
+    fn recursive_type(&self, definition: &Definition) -> String {
+        if let Some(members) = definition.one_of.as_ref().or(definition.any_of.as_ref()) {
+            return union_type(definition, members, self.types);
+        }
+        if definition.properties.is_none() && definition.all_of.is_none() {
+            if let Some(values) = definition.additional_properties.as_ref().filter(|v| **v != Value::Bool(false)) {
+                return record_type(values, self.types);
+            }
+        }
+        let mut parts: Vec<String> = self.bases(definition).into_iter().map(str::to_string).collect();
+        if !inline_properties(definition).is_empty() || parts.is_empty() {
+            parts.push(object_literal(definition, self.types));
+        }
+        parts.join(" & ")
+    }
+
+    fn object(&self, properties: Vec<(&String, &Property, bool)>, indent: &str) -> String {
+        if properties.is_empty() {
+            return "z.object({})".to_string();
+        }
+        let field_indent = format!("{}    ", indent);
+        let mut schema = "z.object({\n".to_string();
+        for (prop_name, prop, required) in properties {
+            schema.push_str(&format!(
+                "{}{}: {},\n",
+                field_indent,
+                property_key(prop_name),
+                self.field(prop, required, &field_indent)
+            ));
+        }
+        schema.push_str(&format!("{}}})", indent));
+        schema
+    }
+
+    fn record(&self, values: &Value, indent: &str) -> String {
+        let value_schema = match serde_json::from_value::<Property>(values.clone()) {
+            Ok(prop) => self.property(&prop, indent),
+            Err(_) => "z.unknown()".to_string(),
+        };
+        format!("z.record(z.string(), {})", value_schema)
+    }
+
+    /// A property schema with `.nullable()` and `.optional()` applied.
+    fn field(&self, prop: &Property, required: bool, indent: &str) -> String {
+        let mut schema = self.property(prop, indent);
+        let nullable = has_flag(prop, "nullable") || has_flag(prop, "x-nullable");
+        if nullable {
+            schema.push_str(".nullable()");
+        }
+        if !required || (nullable && self.types.nullable_style == NullableStyle::Optional) {
+            schema.push_str(".optional()");
+        }
+        schema
+    }
+
+    fn property(&self, prop: &Property, indent: &str) -> String {
+        if let Some(reference) = prop.additional.get("$ref").and_then(Value::as_str) {
+            return self.reference(reference);
+        }
+        if let Some((values, is_array)) = property_enum(prop) {
+            return if is_array { format!("z.array({})", enum_schema(values)) } else { enum_schema(values) };
+        }
+        if let Some(nested) = nested_definition(prop) {
+            return self.object(inline_properties(&nested), indent);
+        }
+        if let Some(values) = map_values(prop) {
+            return self.record(values, indent);
+        }
+
+        let constraint = |key: &str| prop.additional.get(key).filter(|value| value.is_number());
+        let mut schema = match prop.property_type.as_deref() {
+            Some("string") => string_schema(prop.format.as_deref(), self.types),
+            Some("integer") if prop.format.as_deref() == Some("int64") => match self.types.int64_type {
+                Int64Type::Number => "z.number().int()".to_string(),
+                Int64Type::Bigint => return "z.coerce.bigint()".to_string(),
+                Int64Type::String => return "z.string()".to_string(),
+            },
+            Some("integer") => "z.number().int()".to_string(),
+            Some("number") => "z.number()".to_string(),
+            Some("boolean") => return "z.boolean()".to_string(),
+            Some("array") => {
+                let items = match prop.additional.get("items").map(|items| serde_json::from_value::<Property>(items.clone())) {
+                    Some(Ok(items)) => self.property(&items, indent),
+                    _ => "z.any()".to_string(),
+                };
+                let mut schema = format!("z.array({})", items);
+                if let Some(min) = constraint("minItems") {
+                    schema.push_str(&format!(".min({})", min));
+                }
+                if let Some(max) = constraint("maxItems") {
+                    schema.push_str(&format!(".max({})", max));
+                }
+                return schema;
+            }
+            _ => return "z.any()".to_string(),
+        };
+
+        if schema.starts_with("z.string()") {
+            if let Some(min) = constraint("minLength") {
+                schema.push_str(&format!(".min({})", min));
+            }
+            if let Some(max) = constraint("maxLength") {
+                schema.push_str(&format!(".max({})", max));
+            }
+            if let Some(pattern) = prop.additional.get("pattern").and_then(Value::as_str) {
+                schema.push_str(&format!(".regex(new RegExp({}))", string_literal(pattern)));
+            }
+        } else if schema.starts_with("z.number()") {
+            for (bound, exclusive, inclusive_method, exclusive_method) in
+                [("minimum", "exclusiveMinimum", "min", "gt"), ("maximum", "exclusiveMaximum", "max", "lt")]
+            {
+                match (constraint(bound), prop.additional.get(exclusive)) {
+                    (Some(value), Some(Value::Bool(true))) => {
+                        schema.push_str(&format!(".{}({})", exclusive_method, value))
+                    }
+                    (_, Some(value)) if value.is_number() => schema.push_str(&format!(".{}({})", exclusive_method, value)),
+                    (Some(value), _) => schema.push_str(&format!(".{}({})", inclusive_method, value)),
+                    _ => {}
+                }
+            }
+            if let Some(multiple) = constraint("multipleOf") {
+                schema.push_str(&format!(".multipleOf({})", multiple));
+            }
+        }
+        schema
+    }
+}
+
+fn string_schema(format: Option<&str>, types: TypeOptions) -> String {
+    match format {
+        Some("date-time") if types.date_type == DateType::Date => "z.coerce.date()",
+        Some("date") if types.date_type == DateType::Date => "z.coerce.date()",
+        Some("date-time") => "z.string().datetime({ offset: true })",
+        Some("date") => "z.string().date()",
+        Some("email") => "z.string().email()",
+        Some("uuid") => "z.string().uuid()",
+        Some("uri") | Some("url") => "z.string().url()",
+        Some("binary") => "z.instanceof(Blob)",
+        _ => "z.string()",
+    }
+    .to_string()
+}
+
+fn enum_schema(values: &[Value]) -> String {
+    if !values.is_empty() && values.iter().all(Value::is_string) {
+        let literals: Vec<String> = values.iter().filter_map(Value::as_str).map(string_literal).collect();
+        return format!("z.enum([{}])", literals.join(", "));
+    }
+    let literals: Vec<String> = values
+        .iter()
+        .filter_map(|value| match value {
+            Value::String(value) => Some(format!("z.literal({})", string_literal(value))),
+            Value::Number(_) | Value::Bool(_) => Some(format!("z.literal({})", value)),
+            Value::Null => Some("z.null()".to_string()),
+            _ => None,
+        })
+        .collect();
+    match literals.as_slice() {
+        [] => "z.never()".to_string(),
+        [literal] => literal.clone(),
+        _ => format!("z.union([{}])", literals.join(", ")),
+    }
+}
+
+fn property_key(name: &str) -> String {
+    let identifier = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if identifier {
+        name.to_string()
+    } else {
+        string_literal(name)
+    }
+}
+
+/// Definitions in an order where each comes after the ones it refers to, cycles aside.
+fn declaration_order(swagger: &Swagger) -> Vec<&str> {
+    fn visit<'a>(swagger: &'a Swagger, name: &'a str, visited: &mut HashSet<&'a str>, order: &mut Vec<&'a str>) {
+        if !visited.insert(name) {
+            return;
+        }
+        if let Some((name, definition)) = swagger.definitions.get_key_value(name) {
+            let mut targets = Vec::new();
+            definition_references(definition, &mut targets);
+            for target in targets {
+                if let Some((target, _)) = swagger.definitions.get_key_value(target.as_str()) {
+                    visit(swagger, target, visited, order);
+                }
+            }
+            order.push(name);
+        }
+    }
+
+    let mut names: Vec<&String> = swagger.definitions.keys().collect();
+    names.sort();
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in names {
+        visit(swagger, name, &mut visited, &mut order);
+    }
+    order
+}
+
+/// Whether definition `from` refers to `to`, directly or through other definitions.
+fn references(swagger: &Swagger, from: &str, to: &str) -> bool {
+    let mut pending = vec![from.to_string()];
+    let mut visited = HashSet::new();
+    while let Some(name) = pending.pop() {
+        if !visited.insert(name.clone()) {
+            continue;
+        }
+        let Some(definition) = swagger.definitions.get(&name) else {
+            continue;
+        };
+        let mut targets = Vec::new();
+        definition_references(definition, &mut targets);
+        if targets.iter().any(|target| target == to) {
+            return true;
+        }
+        pending.extend(targets);
+    }
+    false
+}
+
+fn definition_references(definition: &Definition, targets: &mut Vec<String>) {
+    if let Some(reference) = &definition.reference {
+        targets.push(ref_name(reference).to_string());
+    }
+    for prop in definition.properties.iter().flat_map(|properties| properties.values()) {
+        for (key, value) in &prop.additional {
+            match value.as_str() {
+                Some(reference) if key == "$ref" => targets.push(ref_name(reference).to_string()),
+                _ => value_references(value, targets),
+            }
+        }
+    }
+    for parts in [&definition.all_of, &definition.one_of, &definition.any_of] {
+        for part in parts.iter().flatten() {
+            definition_references(part, targets);
+        }
+    }
+    if let Some(values) = &definition.additional_properties {
+        value_references(values, targets);
+    }
+}
+
+fn value_references(value: &Value, targets: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(reference) = map.get("$ref").and_then(Value::as_str) {
+                targets.push(ref_name(reference).to_string());
+            }
+            for value in map.values() {
+                value_references(value, targets);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                value_references(value, targets);
+            }
+        }
+        _ => {}
+    }
+}
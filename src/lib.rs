@@ -0,0 +1,469 @@
+//! Generates API clients and types for TypeScript and a dozen other languages from Swagger 2.0,
+//! OpenAPI 3.x and Postman documents. The `swagger-generator` binary is a command line over this
+//! crate; build scripts and servers can embed it instead:
+//!
+//! ```no_run
+//! use swagger_generator::{parse_spec, Generator};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let spec = parse_spec(&std::fs::read_to_string("swagger.json")?, None)?;
+//! for (path, contents) in Generator::new(spec).generate()? {
+//!     println!("{}: {} bytes", path.display(), contents.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Everything that can fail returns a [`GeneratorError`] saying which input or output it failed on.
+
+pub mod cli;
+pub mod config;
+pub mod convert;
+pub mod diff;
+pub mod error;
+pub mod filter;
+pub mod generators;
+pub mod loader;
+pub mod manifest;
+pub mod merge;
+pub mod mock_server;
+pub mod naming;
+pub mod output;
+pub mod plugin;
+mod refs;
+pub mod spec;
+pub mod templates;
+
+use cli::{
+    DateType, DocsFormat, EnumStyle, GenerateArgs, HttpClient, Int64Type, Language, NamingStrategy, NullableStyle,
+    PythonStyle, ServiceStyle, Validators,
+};
+use error::Result;
+use filter::Filter;
+use generators::typescript::{ServiceOptions, TypeOptions};
+use manifest::SpecHashes;
+use plugin::{ExternalPlugin, Plugin};
+use spec::{SpecFormat, Swagger};
+use std::path::{Path, PathBuf};
+use templates::Templates;
+
+pub use error::GeneratorError;
+pub use output::GeneratedFiles;
+
+/// Parses a Swagger 2.0, OpenAPI 3.x or Postman document, detecting whether it is JSON or YAML
+/// unless `format` says. External `$ref`s are left alone; `loader::load_spec` resolves them for
+/// documents read from a file or URL.
+pub fn parse_spec(data: &str, format: Option<SpecFormat>) -> Result<Swagger> {
+    let format = format.unwrap_or_else(|| SpecFormat::detect(Path::new(""), data));
+    spec::from_document("spec", spec::parse_document("spec", data, format)?)
+}
+
+/// Generates the client of one document. `new` starts from the defaults of the command line
+/// flags, which the methods named after them change:
+///
+/// ```no_run
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use swagger_generator::cli::{HttpClient, Language};
+/// use swagger_generator::{parse_spec, Generator};
+///
+/// let spec = parse_spec(&std::fs::read_to_string("swagger.json")?, None)?;
+/// let files = Generator::new(spec)
+///     .language(Language::TypeScript)
+///     .output("dir")
+///     .http_client(HttpClient::Fetch)
+///     .generate()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Generator {
+    swagger: Swagger,
+    language: Language,
+    include_tags: Vec<String>,
+    exclude_paths: Vec<String>,
+    include_methods: Vec<String>,
+    skip_deprecated: bool,
+    naming: NamingStrategy,
+    python_style: PythonStyle,
+    docs_format: DocsFormat,
+    service: ServiceOptions,
+    types: TypeOptions,
+    single_file: bool,
+    msw: bool,
+    tests: bool,
+    factories: bool,
+    templates: Option<PathBuf>,
+    banner: Option<PathBuf>,
+    timestamp: bool,
+    jobs: Option<usize>,
+    plugins: Vec<Box<dyn Plugin>>,
+    output: Option<PathBuf>,
+    clean: bool,
+    force: bool,
+}
+
+impl Generator {
+    /// A generator of `swagger` with the defaults of the command line.
+    pub fn new(swagger: Swagger) -> Generator {
+        Generator {
+            swagger,
+            language: Language::TypeScript,
+            include_tags: Vec::new(),
+            exclude_paths: Vec::new(),
+            include_methods: Vec::new(),
+            skip_deprecated: false,
+            naming: NamingStrategy::OperationId,
+            python_style: PythonStyle::Sync,
+            docs_format: DocsFormat::Markdown,
+            service: ServiceOptions {
+                http_client: HttpClient::Axios,
+                split_by_tag: false,
+                retries: None,
+                style: ServiceStyle::Functions,
+            },
+            types: TypeOptions {
+                enum_style: EnumStyle::Union,
+                nullable_style: NullableStyle::Union,
+                date_type: DateType::String,
+                int64_type: Int64Type::Number,
+                model_variants: false,
+                validators: None,
+            },
+            single_file: false,
+            msw: false,
+            tests: false,
+            factories: false,
+            templates: None,
+            banner: None,
+            timestamp: true,
+            jobs: None,
+            plugins: Vec::new(),
+            output: None,
+            clean: false,
+            force: false,
+        }
+    }
+
+    /// A generator of `swagger` with the settings of the flags in `args`. The output directory is
+    /// left to the command line, which also prints to stdout and nests the clients of several specs.
+    pub fn from_args(args: &GenerateArgs, swagger: Swagger) -> Generator {
+        let generator = Generator::new(swagger)
+            .language(args.language)
+            .include_tags(&args.include_tags)
+            .exclude_paths(&args.exclude_paths)
+            .include_methods(&args.include_methods)
+            .skip_deprecated(args.skip_deprecated)
+            .naming(args.naming)
+            .python_style(args.python_style)
+            .docs_format(args.docs_format)
+            .http_client(args.http_client)
+            .style(args.style)
+            .enum_style(args.enum_style)
+            .nullable_style(args.nullable_style)
+            .date_type(args.date_type)
+            .int64_type(args.int64_type)
+            .model_variants(args.model_variants)
+            .single_file(args.single_file)
+            .split_by_tag(args.split_by_tag)
+            .retries(args.retries)
+            .validators(args.validators)
+            .msw(args.msw)
+            .tests(args.tests)
+            .factories(args.factories)
+            .templates(args.templates.clone())
+            .banner(args.banner.clone())
+            .timestamp(!args.no_timestamp)
+            .jobs(args.jobs)
+            .clean(args.clean)
+            .force(args.force);
+        args.plugins.iter().fold(generator, |generator, name| generator.plugin(ExternalPlugin::new(name)))
+    }
+
+    pub fn language(mut self, language: Language) -> Generator {
+        self.language = language;
+        self
+    }
+
+    /// Only generates the operations tagged with one of `tags`.
+    pub fn include_tags(mut self, tags: &[String]) -> Generator {
+        self.include_tags = tags.to_vec();
+        self
+    }
+
+    /// Skips the paths matching one of the glob `patterns`, which `generate` fails on when invalid.
+    pub fn exclude_paths(mut self, patterns: &[String]) -> Generator {
+        self.exclude_paths = patterns.to_vec();
+        self
+    }
+
+    /// Only generates the operations using one of the HTTP `methods`.
+    pub fn include_methods(mut self, methods: &[String]) -> Generator {
+        self.include_methods = methods.to_vec();
+        self
+    }
+
+    pub fn skip_deprecated(mut self, skip_deprecated: bool) -> Generator {
+        self.skip_deprecated = skip_deprecated;
+        self
+    }
+
+    pub fn naming(mut self, naming: NamingStrategy) -> Generator {
+        self.naming = naming;
+        self
+    }
+
+    pub fn python_style(mut self, python_style: PythonStyle) -> Generator {
+        self.python_style = python_style;
+        self
+    }
+
+    pub fn docs_format(mut self, docs_format: DocsFormat) -> Generator {
+        self.docs_format = docs_format;
+        self
+    }
+
+    pub fn http_client(mut self, http_client: HttpClient) -> Generator {
+        self.service.http_client = http_client;
+        self
+    }
+
+    pub fn style(mut self, style: ServiceStyle) -> Generator {
+        self.service.style = style;
+        self
+    }
+
+    pub fn enum_style(mut self, enum_style: EnumStyle) -> Generator {
+        self.types.enum_style = enum_style;
+        self
+    }
+
+    pub fn nullable_style(mut self, nullable_style: NullableStyle) -> Generator {
+        self.types.nullable_style = nullable_style;
+        self
+    }
+
+    pub fn date_type(mut self, date_type: DateType) -> Generator {
+        self.types.date_type = date_type;
+        self
+    }
+
+    pub fn int64_type(mut self, int64_type: Int64Type) -> Generator {
+        self.types.int64_type = int64_type;
+        self
+    }
+
+    pub fn model_variants(mut self, model_variants: bool) -> Generator {
+        self.types.model_variants = model_variants;
+        self
+    }
+
+    pub fn single_file(mut self, single_file: bool) -> Generator {
+        self.single_file = single_file;
+        self
+    }
+
+    pub fn split_by_tag(mut self, split_by_tag: bool) -> Generator {
+        self.service.split_by_tag = split_by_tag;
+        self
+    }
+
+    pub fn retries(mut self, retries: Option<u32>) -> Generator {
+        self.service.retries = retries;
+        self
+    }
+
+    pub fn validators(mut self, validators: Option<Validators>) -> Generator {
+        self.types.validators = validators;
+        self
+    }
+
+    /// Also generates `handlers.ts`, Mock Service Worker handlers for the TypeScript-based languages.
+    pub fn msw(mut self, msw: bool) -> Generator {
+        self.msw = msw;
+        self
+    }
+
+    /// Also generates `__tests__/service.test.ts`, Jest tests of the requests of the TypeScript service.
+    pub fn tests(mut self, tests: bool) -> Generator {
+        self.tests = tests;
+        self
+    }
+
+    /// Also generates `factories.ts`, mock data factories for the TypeScript-based languages.
+    pub fn factories(mut self, factories: bool) -> Generator {
+        self.factories = factories;
+        self
+    }
+
+    /// Renders from the `.hbs` files in `dir` instead of the built-in templates of the same names.
+    pub fn templates(mut self, dir: Option<PathBuf>) -> Generator {
+        self.templates = dir;
+        self
+    }
+
+    /// Renders the banner at the top of every generated file from the template at `path` instead
+    /// of `header.hbs`.
+    pub fn banner(mut self, path: Option<PathBuf>) -> Generator {
+        self.banner = path;
+        self
+    }
+
+    /// Whether the banners give the date of generation, which makes the files differ from one day
+    /// to the next.
+    pub fn timestamp(mut self, timestamp: bool) -> Generator {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Generates on `jobs` threads, or on as many as there are cores when `None`.
+    pub fn jobs(mut self, jobs: Option<usize>) -> Generator {
+        self.jobs = jobs;
+        self
+    }
+
+    /// Runs `plugin` on the generated files, after the plugins added before it.
+    pub fn plugin(mut self, plugin: impl Plugin + 'static) -> Generator {
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
+    /// Also writes the generated files to the directory `dir` when generating them.
+    pub fn output(mut self, dir: impl Into<PathBuf>) -> Generator {
+        self.output = Some(dir.into());
+        self
+    }
+
+    /// Removes the files an earlier generation wrote to the output directory that are no longer
+    /// generated, as its manifest lists them.
+    pub fn clean(mut self, clean: bool) -> Generator {
+        self.clean = clean;
+        self
+    }
+
+    /// Overwrites the generated files in the output directory that were edited since they were
+    /// generated, which are otherwise left as they are.
+    pub fn force(mut self, force: bool) -> Generator {
+        self.force = force;
+        self
+    }
+
+    /// Filters and names the operations of the document, then generates the files of its client,
+    /// keyed by their paths relative to the output directory and written to it when one is set.
+    pub fn generate(self) -> Result<GeneratedFiles> {
+        let filter = Filter::new(&self.include_tags, &self.exclude_paths, &self.include_methods, self.skip_deprecated)?;
+        let mut swagger = self.swagger;
+        let spec = self.output.as_ref().map(|_| manifest::spec_hash(&swagger));
+        let operations = swagger.operation_count();
+        filter.apply(&mut swagger);
+        if swagger.operation_count() < operations {
+            log::info!("kept {} of {} operations after filtering", swagger.operation_count(), operations);
+        }
+        if log::log_enabled!(log::Level::Info) {
+            for location in swagger.untyped_schemas() {
+                log::info!("{} has no type and falls back to any", location);
+            }
+        }
+        let typescript = [
+            Language::TypeScript,
+            Language::JavaScript,
+            Language::Angular,
+            Language::Vue,
+            Language::RtkQuery,
+            Language::Express,
+            Language::Nest,
+        ];
+        if typescript.contains(&self.language) {
+            swagger = naming::rename_definitions(swagger, generators::typescript::type_name)?;
+        }
+        // After the renaming, whose round trip through JSON leaves out the settled names.
+        naming::assign_operation_names(&mut swagger, self.naming);
+        let mut templates = match &self.templates {
+            Some(dir) => Templates::load(dir)?,
+            None => Templates::builtin(),
+        };
+        if let Some(path) = &self.banner {
+            templates = templates.with_banner(path)?;
+        }
+        if !self.timestamp {
+            templates = templates.without_timestamp();
+        }
+        // The emitters split definitions and tags across the threads of the pool, each of which
+        // renders from its own copy of the templates.
+        let rendered = templates.clone();
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.jobs.unwrap_or(0))
+            .start_handler(move |_| templates::install(templates.clone()))
+            .build()
+            .map_err(|e| GeneratorError::Options(format!("cannot start the generating threads: {}", e)))?;
+
+        let swagger = &swagger;
+        let (service, types, retries) = (self.service, self.types, self.service.retries);
+        let mut files = pool.install(|| match self.language {
+            Language::TypeScript if self.single_file => generators::typescript::bundle(
+                swagger,
+                generators::typescript::generate(swagger, self.language, service, types),
+            ),
+            Language::TypeScript | Language::JavaScript => {
+                generators::typescript::generate(swagger, self.language, service, types)
+            }
+            Language::Angular => generators::angular::generate(swagger, types, retries),
+            Language::Vue => generators::vue::generate(swagger, service, types),
+            Language::RtkQuery => generators::rtk_query::generate(swagger, types, retries),
+            Language::Python => generators::python::generate(swagger, self.python_style, retries),
+            Language::Go => generators::go::generate(swagger, retries),
+            Language::Rust => generators::rust::generate(swagger, retries),
+            Language::Java => generators::java::generate(swagger, retries),
+            Language::Kotlin => generators::kotlin::generate(swagger, retries),
+            Language::CSharp => generators::csharp::generate(swagger, retries),
+            Language::Swift => generators::swift::generate(swagger, retries),
+            Language::Dart => generators::dart::generate(swagger, retries),
+            Language::Php => generators::php::generate(swagger, retries),
+            Language::Ruby => generators::ruby::generate(swagger, retries),
+            Language::JsonSchema => generators::json_schema::generate(swagger),
+            Language::Mock => generators::mock::generate(swagger),
+            Language::Axum => generators::axum::generate(swagger),
+            Language::ActixWeb => generators::actix::generate(swagger),
+            Language::Express => generators::express::generate(swagger, types),
+            Language::Nest => generators::nest::generate(swagger, types),
+            Language::FastApi => generators::fastapi::generate(swagger, self.python_style),
+            Language::Docs => match self.docs_format {
+                DocsFormat::Markdown => generators::markdown::generate(swagger, service.split_by_tag),
+                DocsFormat::Html => generators::html::generate(swagger),
+            },
+            Language::Http => generators::http::generate(swagger),
+            Language::K6 => generators::k6::generate(swagger),
+            Language::Pact => generators::pact::generate(swagger),
+            Language::Plugin => GeneratedFiles::new(),
+        });
+        let typed = [Language::TypeScript, Language::Angular, Language::Vue, Language::RtkQuery];
+        if self.msw && typed.contains(&self.language) {
+            let interfaces = if files.contains_key(Path::new("api.ts")) { "./api" } else { "./interfaces" };
+            let handlers = pool.install(|| generators::msw::generate_handlers(swagger, interfaces));
+            files.insert(PathBuf::from("handlers.ts"), handlers);
+        }
+        if self.tests && self.language == Language::TypeScript {
+            let bundled = files.contains_key(Path::new("api.ts"));
+            let tests = pool.install(|| generators::jest::generate_tests(swagger, service, bundled));
+            files.insert(PathBuf::from("__tests__/service.test.ts"), tests);
+        }
+        if self.factories && typed.contains(&self.language) {
+            let interfaces = if files.contains_key(Path::new("api.ts")) { "./api" } else { "./interfaces" };
+            let factories = pool.install(|| generators::factories::generate_factories(swagger, types, interfaces));
+            files.insert(PathBuf::from("factories.ts"), factories);
+        }
+        if let Some(error) = rendered.take_failure() {
+            return Err(error);
+        }
+        log::info!("generated {} files", files.len());
+        for plugin in &self.plugins {
+            files = plugin.run(swagger, self.language, files)?;
+            log::info!("plugin {} returned {} files", plugin.name(), files.len());
+        }
+
+        if let Some(dir) = &self.output {
+            let specs = SpecHashes::from_iter(spec.map(|spec| (PathBuf::new(), spec)));
+            output::write_to_dir(dir, &files, &specs, self.clean, self.force)?;
+        }
+        Ok(files)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::error::{GeneratorError, Result};
+use crate::refs;
+use crate::spec::{from_document, parse_document, SpecFormat, Swagger};
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+pub fn is_remote(input: &str) -> bool {
+    input.starts_with("http://") || input.starts_with("https://")
+}
+
+pub fn expand_inputs(inputs: &[String]) -> Result<Vec<String>> {
+    let mut expanded = Vec::new();
+    for input in inputs {
+        if is_remote(input) || !input.contains(['*', '?', '[']) {
+            expanded.push(input.clone());
+            continue;
+        }
+
+        let paths = glob::glob(input)
+            .map_err(|e| GeneratorError::Options(format!("invalid pattern '{}': {}", input, e)))?
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| GeneratorError::Read { input: e.path().display().to_string(), source: e.into() })?;
+        if paths.is_empty() {
+            return Err(GeneratorError::Read {
+                input: input.clone(),
+                source: io::Error::new(io::ErrorKind::NotFound, "no file matches the pattern"),
+            });
+        }
+        expanded.extend(paths.into_iter().map(|path| path.to_string_lossy().into_owned()));
+    }
+    Ok(expanded)
+}
+
+pub fn input_name(input: &str) -> String {
+    let path = input.split(['?', '#']).next().unwrap_or(input);
+    Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "spec".to_string())
+}
+
+pub fn read_input(input: &str, headers: &[String]) -> Result<String> {
+    let read = |source| GeneratorError::Read { input: input.to_string(), source };
+    if input == "-" {
+        let mut data = String::new();
+        io::stdin()
+            .read_to_string(&mut data)
+            .map_err(|source| GeneratorError::Read { input: "stdin".to_string(), source })?;
+        Ok(data)
+    } else if is_remote(input) {
+        fetch(input, headers)?.map_err(|e| read(io::Error::other(e)))
+    } else {
+        fs::read_to_string(input).map_err(read)
+    }
+}
+
+pub fn load_spec(input: &str, format: Option<SpecFormat>, headers: &[String]) -> Result<Swagger> {
+    from_document(input, load_document(input, format, headers)?)
+}
+
+pub fn load_document(input: &str, format: Option<SpecFormat>, headers: &[String]) -> Result<Value> {
+    let data = read_input(input, headers)?;
+
+    let format = format.unwrap_or_else(|| detect_format(input, &data));
+    let mut document = parse_document(input, &data, format)?;
+    refs::resolve_external_refs(&mut document, input, headers)?;
+    Ok(document)
+}
+
+pub fn detect_format(input: &str, data: &str) -> SpecFormat {
+    let path = input.split(['?', '#']).next().unwrap_or(input);
+    SpecFormat::detect(Path::new(path), data)
+}
+
+/// Fetches `url`, failing outright on invalid `headers` and with the response error otherwise.
+fn fetch(url: &str, headers: &[String]) -> Result<reqwest::Result<String>> {
+    let client = reqwest::blocking::Client::new();
+    let mut request = client.get(url);
+    for header in headers {
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| GeneratorError::Options(format!("invalid header '{}', expected 'Name: value'", header)))?;
+        request = request.header(name.trim(), value.trim());
+    }
+
+    Ok(request.send().and_then(|response| response.error_for_status()).and_then(|response| response.text()))
+}
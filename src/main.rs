@@ -1,305 +1,389 @@
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
-use std::fs::{create_dir_all, File};
-use std::io::{self, Read, Write};
-
-#[derive(Debug, Deserialize)]
-struct Swagger {
-    info: HashMap<String, Value>,
-    definitions: HashMap<String, Definition>,
-    paths: HashMap<String, PathItem>,
-    schemes: Option<Vec<String>>,
-    host: Option<String>,
-    basePath: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-struct Definition {
-    #[serde(rename = "type")]
-    definition_type: Option<String>,
-    properties: Option<HashMap<String, Property>>,
-    required: Option<Vec<String>>,
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser};
+use glob::Pattern;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use notify::{RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::mpsc;
+use std::time::Duration;
+use swagger_generator::cli::{
+    Cli, Command, ConvertArgs, DiffArgs, DiffFormat, DocsArgs, DocsFormat, GenerateArgs, Language, MockArgs,
+    ServiceStyle,
+};
+use swagger_generator::error::Result;
+use swagger_generator::manifest::{self, SpecHashes};
+use swagger_generator::mock_server::{self, MockOptions};
+use swagger_generator::spec::{self, parse_document, SpecFormat};
+use swagger_generator::{config, convert, diff, loader, merge, output, GeneratedFiles, Generator, GeneratorError};
+
+/// Exits with the code of the error that ended the run, if any, after printing it.
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::from(error.exit_code())
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-struct Property {
-    #[serde(rename = "type")]
-    property_type: Option<String>,
-    format: Option<String>,
-    #[serde(flatten)]
-    additional: HashMap<String, Value>,
-    reference: Option<String>,
-}
+/// Prints the records of the generator to stderr as `level: message`, like the errors.
+struct Logger;
 
-#[derive(Debug, Deserialize)]
-struct PathItem {
-    get: Option<Operation>,
-    post: Option<Operation>,
-    put: Option<Operation>,
-    delete: Option<Operation>,
-}
+static LOGGER: Logger = Logger;
 
-#[derive(Debug, Deserialize)]
-struct Operation {
-    operation_id: Option<String>,
-    summary: Option<String>,
-    responses: HashMap<String, Response>,
-}
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level() && metadata.target().starts_with("swagger_generator")
+    }
 
-#[derive(Debug, Deserialize)]
-struct Response {
-    description: String,
-    #[serde(rename = "schema")]
-    response_schema: Option<Schema>,
-}
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let level = match record.level() {
+            Level::Error => "error",
+            Level::Warn => "warning",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        eprintln!("{}: {}", level, record.args());
+    }
 
-#[derive(Debug, Deserialize)]
-struct Schema {
-    #[serde(rename = "type")]
-    schema_type: Option<String>,
-    #[serde(rename = "$ref")]
-    reference: Option<String>,
+    fn flush(&self) {}
 }
 
-fn main() -> io::Result<()> {
-    let mut file = File::open("swagger.json")?;
-    let mut data = String::new();
-    file.read_to_string(&mut data)?;
-
-    let swagger: Swagger = serde_json::from_str(&data).expect("Invalid JSON");
-
-    create_dir_all("output/interfaces")?;
-
-    for (name, definition) in &swagger.definitions {
-        let ts_interface = generate_typescript_interface(&swagger, name, definition);
-        let mut file = File::create(format!("output/interfaces/{}.ts", name))?;
-        file.write_all(ts_interface.as_bytes())?;
+fn run() -> Result<()> {
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+    if log::set_logger(&LOGGER).is_ok() {
+        log::set_max_level(match (cli.quiet, cli.verbose) {
+            (true, _) => LevelFilter::Error,
+            (_, true) => LevelFilter::Info,
+            _ => LevelFilter::Warn,
+        });
     }
 
-    write_service(&swagger, "typescript", "output/service.ts")?;
-    // write_service(&swagger, "javascript", "output/service.js")?;
-    // write_service(&swagger, "python", "output/service.py")?;
-    // write_service(&swagger, "go", "output/service.go")?;
-    // write_service(&swagger, "rust", "output/service.rs")?;
-    // write_service(&swagger, "java", "output/Service.java")?;
-    // write_service(&swagger, "csharp", "output/Service.cs")?;
+    match &cli.command {
+        Some(Command::Convert(args)) => run_convert(args),
+        Some(Command::Diff(args)) => run_diff(args),
+        Some(Command::Mock(args)) => run_mock(args),
+        Some(Command::Docs(args)) => run_docs(args),
+        None => {
+            let config = config::find(cli.generate.config.as_deref())?;
+            let runs = generate_runs(config.as_deref(), &matches, false)?;
+            if !runs.iter().any(|args| args.watch) {
+                return runs.iter().try_for_each(run_generate);
+            }
+            watch(|| generate_runs(config.as_deref(), &matches, true), config.as_deref())
+        }
+    }
+}
 
-    Ok(())
+/// The options of every run: those of the command line, or one set for each target of the config
+/// file. Invalid options in the config file exit like invalid flags do, unless `watching`.
+fn generate_runs(config: Option<&Path>, matches: &ArgMatches, watching: bool) -> Result<Vec<GenerateArgs>> {
+    let Some(path) = config else {
+        return Ok(vec![Cli::from_arg_matches(matches).unwrap_or_else(|error| error.exit()).generate]);
+    };
+    let mut runs = Vec::new();
+    for arguments in config::target_arguments(path, &Cli::command(), matches)? {
+        // Each run of the config file parses its options ahead of the ones given on the command line.
+        let arguments =
+            env::args_os().take(1).chain(arguments.into_iter().map(OsString::from)).chain(env::args_os().skip(1));
+        match Cli::try_parse_from(arguments) {
+            Ok(cli) => runs.push(cli.generate),
+            Err(error) if watching => {
+                let message = error.to_string();
+                let message = message.trim_start_matches("error: ").trim_end();
+                return Err(GeneratorError::Options(format!("{}: {}", path.display(), message)));
+            }
+            Err(error) => error.exit(),
+        }
+    }
+    log::info!("read {} with {} runs", path.display(), runs.len());
+    Ok(runs)
 }
 
-fn write_service(swagger: &Swagger, language: &str, filename: &str) -> std::io::Result<()> {
-    let service = generate_service(swagger, language);
-    let mut file = File::create(filename)?;
-    file.write_all(service.as_bytes())?;
-    Ok(())
+/// The local files the runs of the generator read, which watch mode regenerates on changes of.
+#[derive(Default)]
+struct Sources {
+    files: HashSet<PathBuf>,
+    patterns: Vec<Pattern>,
+    dirs: Vec<PathBuf>,
+    /// The directories to watch, and whether to watch their subdirectories too. Watching the
+    /// directory rather than the file also sees editors that save by replacing the file.
+    watches: HashMap<PathBuf, bool>,
 }
 
-fn generate_typescript_interface(swagger: &Swagger, name: &str, definition: &Definition) -> String {
-    let mut ts_code = String::new();
-    generate_info_comment(swagger, &mut ts_code);
-    ts_code.push_str("export interface ");
-    ts_code.push_str(name);
-    ts_code.push_str(" {\n");
+impl Sources {
+    fn add_run(&mut self, args: &GenerateArgs) {
+        for input in &args.inputs {
+            if loader::is_remote(input) || input == "-" {
+                continue;
+            }
+            if !input.contains(['*', '?', '[']) {
+                self.add_file(Path::new(input));
+                continue;
+            }
+            let Ok(pattern) = std::path::absolute(input) else { continue };
+            // The directories before the first wildcard, watched down to wherever the pattern matches.
+            let dir: PathBuf = pattern
+                .components()
+                .take_while(|part| !part.as_os_str().to_string_lossy().contains(['*', '?', '[']))
+                .collect();
+            if let Ok(pattern) = Pattern::new(&pattern.to_string_lossy()) {
+                self.patterns.push(pattern);
+                *self.watches.entry(dir).or_default() = true;
+            }
+        }
+        if let Some(Ok(dir)) = args.templates.as_deref().map(std::path::absolute) {
+            self.dirs.push(dir.clone());
+            *self.watches.entry(dir).or_default() = true;
+        }
+        if let Some(banner) = &args.banner {
+            self.add_file(banner);
+        }
+    }
 
-    if let Some(properties) = &definition.properties {
-        for (prop_name, prop) in properties {
-            let ts_type = match prop.property_type.as_deref() {
-                Some("integer") => "number",
-                Some("string") => "string",
-                Some("boolean") => "boolean",
-                Some("array") => {
-                    let items = &prop.additional["items"];
-                    if let Some(item_type) = items.get("type").and_then(Value::as_str) {
-                        match item_type {
-                            "integer" => "number[]",
-                            "string" => "string[]",
-                            "boolean" => "boolean[]",
-                            _ => "any[]",
-                        }
-                    } else {
-                        "any[]"
-                    }
-                }
-                Some("object") => {
-                    if let Some(ref_name) = prop.reference.as_deref() {
-                        ref_name
-                    } else {
-                        "any"
-                    }
-                }
-                _ => "any",
-            };
-            let optional = if definition
-                .required
-                .as_ref()
-                .map_or(false, |r| !r.contains(prop_name))
-            {
-                "?"
-            } else {
-                ""
-            };
-            ts_code.push_str(&format!("    {}{}: {};\n", prop_name, optional, ts_type));
+    fn add_file(&mut self, path: &Path) {
+        let Ok(path) = std::path::absolute(path) else { return };
+        if let Some(dir) = path.parent() {
+            self.watches.entry(dir.to_path_buf()).or_default();
         }
+        self.files.insert(path);
     }
-    ts_code.push_str("}\n");
-    ts_code
-}
 
-fn generate_info_comment(swagger: &Swagger, ts_code: &mut String) {
-    let generated_date = chrono::Local::now().format("%Y-%m-%d").to_string();
-    ts_code.push_str("/*\n");
-    ts_code.push_str(" * This file was generated by swagger-genereator\n");
-    ts_code.push_str(" * Do not modify this file manually.\n");
-    ts_code.push_str(" * Version: "); ts_code.push_str(&swagger.info["version"].as_str().unwrap());
-    ts_code.push_str("\n");
-    ts_code.push_str(" * Title: "); ts_code.push_str(&swagger.info["title"].as_str().unwrap());
-    ts_code.push_str("\n");
-    ts_code.push_str(" * Description: "); ts_code.push_str(&swagger.info["description"].as_str().unwrap());
-    ts_code.push_str("\n");
-    ts_code.push_str(" * Author: Muhtalip Dede\n");
-    ts_code.push_str(" * Generated on: "); ts_code.push_str(&generated_date);
-    ts_code.push_str(" */\n\n");
+    fn contains(&self, path: &Path) -> bool {
+        self.files.contains(path)
+            || self.patterns.iter().any(|pattern| pattern.matches_path(path))
+            || self.dirs.iter().any(|dir| path.starts_with(dir))
+    }
 }
 
-fn generate_service(swagger: &Swagger, lang: &str) -> String {
-    let mut ts_code = String::new();
-
-    generate_info_comment(swagger, &mut ts_code);
-
-    ts_code.push_str("import axios from 'axios';\n\n");
-    ts_code.push_str("axios.defaults.baseURL = '");
-    ts_code.push_str(&swagger.schemes.as_ref().unwrap()[0]);
-    ts_code.push_str("://");
-    ts_code.push_str(&swagger.host.as_ref().unwrap());
-    ts_code.push_str(&swagger.basePath.as_ref().unwrap());
-    ts_code.push_str("';\n\n");
+/// Generates the clients of `runs`, then again whenever a local spec, template or the config file
+/// they come from changes, until interrupted. Failed runs are reported without ending the watch.
+fn watch(runs: impl Fn() -> Result<Vec<GenerateArgs>>, config: Option<&Path>) -> Result<()> {
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| GeneratorError::Watch(e.to_string()))?;
+    let mut watched: Vec<PathBuf> = Vec::new();
+    loop {
+        let mut sources = Sources::default();
+        match runs() {
+            Ok(runs) => {
+                for args in &runs {
+                    if let Err(error) = run_generate(args) {
+                        log::error!("{}", error);
+                    }
+                    sources.add_run(args);
+                }
+            }
+            Err(error) => log::error!("{}", error),
+        }
+        if let Some(config) = config {
+            sources.add_file(config);
+        }
 
-    if lang == "typescript" {
-        let interfaces = std::fs::read_dir("output/interfaces").unwrap();
+        for dir in watched.drain(..) {
+            // Fails only for directories that have been removed since.
+            let _ = watcher.unwatch(&dir);
+        }
+        for (dir, recursive) in &sources.watches {
+            let mode = if *recursive { RecursiveMode::Recursive } else { RecursiveMode::NonRecursive };
+            match watcher.watch(dir, mode) {
+                Ok(()) => watched.push(dir.clone()),
+                Err(error) => log::warn!("cannot watch {}: {}", dir.display(), error),
+            }
+        }
+        eprintln!("Watching for changes...");
 
-        for interface in interfaces {
-            let interface = interface.unwrap();
-            let interface_name = interface.file_name().into_string().unwrap().replace(".ts", "");
-            ts_code.push_str(&format!("import {{ {} }} from './interfaces/{}';\n", interface_name, interface_name));
+        loop {
+            match events.recv() {
+                Ok(Ok(event)) if !event.kind.is_access() && event.paths.iter().any(|path| sources.contains(path)) => {
+                    break
+                }
+                Ok(Ok(_)) => {}
+                Ok(Err(error)) => log::warn!("{}", error),
+                Err(_) => return Ok(()),
+            }
         }
-        ts_code.push_str("\n");
+        // Saving a file often takes several events; regenerate once they settle.
+        while events.recv_timeout(Duration::from_millis(200)).is_ok() {}
     }
+}
 
-    for (path, path_item) in &swagger.paths {
-        if let Some(operation) = &path_item.get {
-            ts_code.push_str(&generate_service_method("get", path, operation, lang));
-        }
-        if let Some(operation) = &path_item.post {
-            ts_code.push_str(&generate_service_method("post", path, operation, lang));
+fn run_generate(args: &GenerateArgs) -> Result<()> {
+    if args.single_file && args.language != Language::TypeScript {
+        log::warn!("--single-file only applies to --language typescript");
+    }
+    let split = [Language::TypeScript, Language::JavaScript, Language::Vue, Language::Docs];
+    if args.split_by_tag && !split.contains(&args.language) {
+        log::warn!("--split-by-tag only applies to --language typescript, javascript, vue and docs");
+    }
+    if args.style == ServiceStyle::Class {
+        if ![Language::TypeScript, Language::JavaScript].contains(&args.language) {
+            log::warn!("--style class only applies to --language typescript and javascript");
+        } else if args.split_by_tag {
+            log::warn!("--split-by-tag does not apply to --style class");
         }
-        if let Some(operation) = &path_item.put {
-            ts_code.push_str(&generate_service_method("put", path, operation, lang));
+    }
+    let typed = [Language::TypeScript, Language::Angular, Language::Vue, Language::RtkQuery];
+    if args.validators.is_some() && !typed.contains(&args.language) && args.language != Language::Express {
+        log::warn!("--validators only applies to the TypeScript-based languages");
+    }
+    if args.msw && !typed.contains(&args.language) {
+        log::warn!("--msw only applies to the TypeScript-based languages");
+    }
+    if args.tests && args.language != Language::TypeScript {
+        log::warn!("--tests only applies to --language typescript");
+    }
+    if args.factories && !typed.contains(&args.language) {
+        log::warn!("--factories only applies to the TypeScript-based languages");
+    }
+    let untimed = [
+        Language::JsonSchema,
+        Language::Mock,
+        Language::Axum,
+        Language::ActixWeb,
+        Language::Express,
+        Language::Nest,
+        Language::FastApi,
+        Language::Docs,
+        Language::Http,
+        Language::K6,
+        Language::Pact,
+    ];
+    if args.retries.is_some() && untimed.contains(&args.language) {
+        log::warn!("--retries does not apply to --language json-schema, mock and the server languages");
+    }
+    if args.language == Language::Plugin && args.plugins.is_empty() {
+        log::warn!("--language plugin generates no files without a --plugin");
+    }
+    let mut specs = SpecHashes::new();
+    let mut generate = |dir: &str, swagger| {
+        specs.insert(PathBuf::from(dir), manifest::spec_hash(&swagger));
+        Generator::from_args(args, swagger).generate()
+    };
+    let load = |input: &str| loader::load_spec(input, args.format, &args.headers);
+
+    let inputs = loader::expand_inputs(&args.inputs)?;
+    let files = match inputs.as_slice() {
+        [input] => generate("", load(input)?)?,
+        _ if args.merge => {
+            let mut documents = Vec::new();
+            for input in &inputs {
+                let document = loader::load_document(input, args.format, &args.headers)?;
+                documents.push((loader::input_name(input), document));
+            }
+            generate("", merge::merge_documents(documents)?)?
         }
-        if let Some(operation) = &path_item.delete {
-            ts_code.push_str(&generate_service_method("delete", path, operation, lang));
+        _ => {
+            let mut files = GeneratedFiles::new();
+            let mut names = HashSet::new();
+            for input in &inputs {
+                let stem = loader::input_name(input);
+                let mut name = stem.clone();
+                let mut suffix = 2;
+                while !names.insert(name.clone()) {
+                    name = format!("{}_{}", stem, suffix);
+                    suffix += 1;
+                }
+                for (path, contents) in generate(&name, load(input)?)? {
+                    files.insert(Path::new(&name).join(path), contents);
+                }
+            }
+            files
         }
-    }
+    };
 
-    ts_code
+    if args.output_dir == Path::new("-") {
+        output::write_to_stdout(&files)
+    } else if args.dry_run {
+        output::write_preview(&args.output_dir, &files, args.clean, args.force)
+    } else {
+        output::write_to_dir(&args.output_dir, &files, &specs, args.clean, args.force)
+    }
 }
 
-fn generate_service_method(method: &str, path: &str, operation: &Operation, lang: &str) -> String {
-    let operation_id = operation
-        .operation_id
-        .as_deref()
-        .unwrap_or("unknown")
-        .to_string();
-    let fallback_operation_id = path
-        .split('/')
-        .filter(|s| !s.is_empty() && !s.starts_with('{'))
-        .collect::<Vec<&str>>()
-        .join("_");
-    let final_operation_id = if operation_id == "unknown" {
-        fallback_operation_id
-    } else {
-        operation_id
-    };
+fn run_convert(args: &ConvertArgs) -> Result<()> {
+    let data = loader::read_input(&args.input, &args.headers)?;
+    let document = parse_document(&args.input, &data, loader::detect_format(&args.input, &data))?;
+    let converted = convert::swagger2_to_openapi3(&args.input, document)?;
 
-    let path_params = extract_path_params(path);
-    let params_declaration = if path_params.is_empty() {
-        "".to_string()
-    } else {
-        path_params
-            .iter()
-            .map(|param| format!("{}: string", param))
-            .collect::<Vec<String>>()
-            .join(", ")
-            + ", "
-    };
+    let format = args.format.unwrap_or(match args.output.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => SpecFormat::Yaml,
+        _ => SpecFormat::Json,
+    });
+    let contents = spec::write_document(&args.output, &converted, format)?;
 
-    let data_param = if method == "get" || method == "delete" {
-        ""
+    let written = if args.output == Path::new("-") {
+        io::stdout().write_all(contents.as_bytes())
     } else {
-        "data?: any, "
+        fs::write(&args.output, contents)
     };
+    written.map_err(|source| GeneratorError::Write { path: args.output.clone(), source })
+}
 
-    let formatted_path = path_params.iter().fold(path.to_string(), |acc, param| {
-        acc.replace(&format!("{{{}}}", param), &format!("${{{}}}", param))
-    });
+fn run_diff(args: &DiffArgs) -> Result<()> {
+    let old = loader::load_spec(&args.old, None, &args.headers)?;
+    let new = loader::load_spec(&args.new, None, &args.headers)?;
 
-    let mut method_name = method.to_lowercase()
-        + final_operation_id
-            .split('_')
-            .map(|s| {
-                let mut chars = s.chars();
-                match chars.next() {
-                    None => String::new(),
-                    Some(c) => c.to_uppercase().chain(chars).collect(),
-                }
-            })
-            .collect::<String>()
-            .as_str();
-
-    if !params_declaration.is_empty() {
-        method_name = format!("{}ById", method_name);
+    let diff = diff::diff_specs(&old, &new);
+    match args.format {
+        DiffFormat::Text => print!("{}", diff),
+        DiffFormat::Changelog => {
+            let version = new.info.get("version").and_then(|version| version.as_str()).unwrap_or_default();
+            print!("{}", diff.changelog(version));
+        }
     }
 
-    let mut response_schema = operation
-        .responses
-        .get("200")
-        .and_then(|r| r.response_schema.as_ref())
-        .and_then(|s| s.reference.as_ref())
-        .map_or_else(|| "any".to_string(), |r| r.to_string());
-
-    if response_schema.starts_with("#/definitions/") {
-        response_schema = response_schema.replace("#/definitions/", "");
+    let breaking = diff.changes.iter().filter(|change| change.breaking).count();
+    if args.fail_on_breaking && breaking > 0 {
+        return Err(GeneratorError::Breaking(breaking));
     }
+    Ok(())
+}
 
-    let response_type = if lang == "typescript" {
-        format!("Promise<{}>", response_schema)
+/// Writes the single file of the documentation to `--output`, or with `--split-by-tag` the
+/// Markdown files into it as a directory.
+fn run_docs(args: &DocsArgs) -> Result<()> {
+    let swagger = loader::load_spec(&args.input, None, &args.headers)?;
+    if args.split_by_tag && args.format == DocsFormat::Html {
+        log::warn!("--split-by-tag does not apply to --format html, which is a single page");
+    }
+    let files = Generator::new(swagger)
+        .language(Language::Docs)
+        .docs_format(args.format)
+        .split_by_tag(args.split_by_tag)
+        .generate()?;
+
+    let single = match files.values().next() {
+        Some(contents) if files.len() == 1 => contents,
+        _ if args.output == Path::new("-") => return output::write_to_stdout(&files),
+        _ => return output::write_to_dir(&args.output, &files, &SpecHashes::new(), false, false),
+    };
+    let written = if args.output == Path::new("-") {
+        io::stdout().write_all(single.as_bytes())
     } else {
-        "Promise<any>".to_string()
+        fs::write(&args.output, single)
     };
-
-    let method_code = format!(
-        "export async function {}({}{}config?: any): {} {{
-    const response = await axios.{}(`{}`, {}config);
-    return response.data;
-}}\n\n",
-        method_name,
-        params_declaration,
-        data_param,
-        response_type,
-        method,
-        formatted_path,
-        if data_param.is_empty() { "" } else { "data, " }
-    );
-
-    method_code
+    written.map_err(|source| GeneratorError::Write { path: args.output.clone(), source })
 }
 
-fn extract_path_params(path: &str) -> Vec<String> {
-    let mut params = Vec::new();
-    for segment in path.split('/') {
-        if segment.starts_with('{') && segment.ends_with('}') {
-            params.push(segment[1..segment.len() - 1].to_string());
-        }
-    }
-    params
+fn run_mock(args: &MockArgs) -> Result<()> {
+    let swagger = loader::load_spec(&args.input, None, &args.headers)?;
+    let options = MockOptions {
+        host: args.host.clone(),
+        port: args.port,
+        latency: Duration::from_millis(args.latency),
+        error_rate: args.error_rate,
+        seed: args.seed,
+    };
+    mock_server::serve(&swagger, options)
 }
@@ -0,0 +1,165 @@
+use crate::error::{GeneratorError, Result};
+use crate::output;
+use crate::spec::Swagger;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The file in the output directory listing the files generated into it, by which `--clean` tells
+/// the files of earlier generations from the ones written by hand.
+pub const MANIFEST: &str = ".swagger-generator.manifest.json";
+
+/// The hashes of the specs the files of an output directory are generated from, keyed by the
+/// directory the files of each are in. That is the output directory itself, the empty path,
+/// unless several specs are generated into subdirectories of it.
+pub type SpecHashes = BTreeMap<PathBuf, String>;
+
+/// What the output directory holds for each generated file: the hash of the spec it came from,
+/// so that builds can tell whether the spec changed since, and the hash of the contents written,
+/// so that the generator can tell whether it was edited since outside of its custom regions.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Keyed by paths relative to the output directory.
+    pub files: BTreeMap<PathBuf, Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub spec: String,
+    pub content: String,
+}
+
+/// The SHA-256 of `data`, in hex.
+pub fn hash(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+/// The hash of the contents of a generated file, leaving out what its custom regions hold.
+pub fn content_hash(contents: &str) -> String {
+    hash(output::without_custom_regions(contents).as_bytes())
+}
+
+/// The hash of the document the generators read `swagger` as, which covers the documents its
+/// external references were resolved from but not their formatting.
+pub fn spec_hash(swagger: &Swagger) -> String {
+    serde_json::to_vec(swagger).map(|json| hash(&json)).unwrap_or_default()
+}
+
+/// The hash in `specs` of the spec the file at `path` was generated from.
+pub fn spec_of(specs: &SpecHashes, path: &Path) -> String {
+    specs
+        .iter()
+        .filter(|(dir, _)| path.starts_with(dir))
+        .max_by_key(|(dir, _)| dir.components().count())
+        .map(|(_, spec)| spec.clone())
+        .unwrap_or_default()
+}
+
+impl Manifest {
+    /// The manifest in `dir`, or an empty one when there is none or it cannot be read.
+    pub fn read(dir: &Path) -> Manifest {
+        let path = dir.join(MANIFEST);
+        let Ok(data) = fs::read_to_string(&path) else {
+            return Manifest::default();
+        };
+        serde_json::from_str(&data).unwrap_or_else(|error| {
+            log::warn!("ignoring {}: {}", path.display(), error);
+            Manifest::default()
+        })
+    }
+
+    /// Writes the manifest to `dir`, unless it already holds this one.
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        if Manifest::read(dir) == *self {
+            return Ok(());
+        }
+        let path = dir.join(MANIFEST);
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other);
+        contents
+            .and_then(|contents| output::write_file(&path, &(contents + "\n")))
+            .map_err(|source| GeneratorError::Write { path, source })
+    }
+
+    /// Whether the generated file at `path`, which now holds `current`, is no longer what the
+    /// generator wrote there.
+    pub fn is_edited(&self, path: &Path, current: &str) -> bool {
+        self.files.get(path).is_some_and(|entry| entry.content != content_hash(current))
+    }
+
+    /// The files of the manifest that are still in `dir` but not among `generated` any more.
+    /// Paths leading out of `dir` are left out, so that an edited manifest cannot remove other files.
+    pub fn orphans(&self, dir: &Path, generated: &output::GeneratedFiles) -> Vec<(PathBuf, Entry)> {
+        self.files
+            .iter()
+            .filter(|(path, _)| !generated.contains_key(*path) && output::is_relative(path) && dir.join(path).is_file())
+            .map(|(path, entry)| (path.clone(), entry.clone()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_hash, Entry, Manifest, MANIFEST};
+    use crate::output::GeneratedFiles;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn output_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("swagger-generator-manifest-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn entry(contents: &str) -> Entry {
+        Entry { spec: String::new(), content: content_hash(contents) }
+    }
+
+    #[test]
+    fn missing_and_corrupt_manifests_read_as_empty() {
+        let dir = output_dir("corrupt");
+        assert_eq!(Manifest::read(&dir), Manifest::default());
+        fs::write(dir.join(MANIFEST), "{ \"files\": [").unwrap();
+        assert_eq!(Manifest::read(&dir), Manifest::default());
+
+        let manifest = Manifest { files: [(PathBuf::from("service.ts"), entry("v1\n"))].into() };
+        manifest.write(&dir).unwrap();
+        assert_eq!(Manifest::read(&dir), manifest);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn edits_outside_of_custom_regions_count() {
+        let generated = "a\n// <custom>\n// </custom>\n";
+        let manifest = Manifest { files: [(PathBuf::from("a.ts"), entry(generated))].into() };
+        assert!(!manifest.is_edited(Path::new("a.ts"), generated));
+        assert!(!manifest.is_edited(Path::new("a.ts"), "a\n// <custom>\nmine\n// </custom>\n"));
+        assert!(manifest.is_edited(Path::new("a.ts"), "b\n// <custom>\n// </custom>\n"));
+        assert!(!manifest.is_edited(Path::new("other.ts"), "b\n"));
+    }
+
+    #[test]
+    fn orphans_are_files_no_longer_generated_inside_the_directory() {
+        let dir = output_dir("orphans");
+        for file in ["kept.ts", "old.ts"] {
+            fs::write(dir.join(file), "x\n").unwrap();
+        }
+        let outside = dir.with_extension("outside");
+        fs::write(&outside, "x\n").unwrap();
+        let escaping = Path::new("..").join(outside.file_name().unwrap());
+        let files = [Path::new("kept.ts"), Path::new("old.ts"), Path::new("gone.ts"), &escaping, &outside]
+            .into_iter()
+            .map(|path| (path.to_path_buf(), entry("x\n")))
+            .collect();
+        let manifest = Manifest { files };
+        let generated: GeneratedFiles = [(PathBuf::from("kept.ts"), "x\n".to_string())].into();
+
+        let orphans: Vec<PathBuf> = manifest.orphans(&dir, &generated).into_iter().map(|(path, _)| path).collect();
+        assert_eq!(orphans, [PathBuf::from("old.ts")]);
+        fs::remove_dir_all(&dir).unwrap();
+        fs::remove_file(&outside).unwrap();
+    }
+}
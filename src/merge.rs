@@ -0,0 +1,285 @@
+use crate::error::{GeneratorError, Result};
+use crate::spec::{from_document, PathItem, Swagger};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+fn definitions_pointer(document: &Value) -> &'static str {
+    if document.get("openapi").is_some() {
+        "/components/schemas"
+    } else {
+        "/definitions"
+    }
+}
+
+fn security_schemes_pointer(document: &Value) -> &'static str {
+    if document.get("openapi").is_some() {
+        "/components/securitySchemes"
+    } else {
+        "/securityDefinitions"
+    }
+}
+
+fn prefix(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                None => String::new(),
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+            }
+        })
+        .collect()
+}
+
+/// The name `name` takes in the merged spec: itself unless another spec already uses it for something
+/// else, then prefixed with the spec name, then numbered when the prefix is empty or also taken.
+fn unique_name(name: &str, spec: &str, value: &Value, seen: &HashMap<String, Value>) -> String {
+    let taken = |candidate: &str| seen.get(candidate).is_some_and(|existing| existing != value);
+    let prefixed = format!("{}{}", prefix(spec), name);
+    if !taken(name) {
+        return name.to_string();
+    }
+    if prefixed != name && !taken(&prefixed) {
+        return prefixed;
+    }
+    (2..).map(|number| format!("{}{}", prefixed, number)).find(|candidate| !taken(candidate)).unwrap_or(prefixed)
+}
+
+/// Renames the schemes named in the `security` requirements of the document and its operations.
+fn rename_requirements(document: &mut Value, renames: &HashMap<String, String>) {
+    let mut requirements: Vec<&mut Value> = Vec::new();
+    for (key, value) in document.as_object_mut().into_iter().flatten() {
+        match (key.as_str(), value) {
+            ("security", security) => requirements.push(security),
+            ("paths", Value::Object(paths)) => {
+                for item in paths.values_mut().filter_map(Value::as_object_mut) {
+                    requirements.extend(item.values_mut().filter_map(|operation| operation.get_mut("security")));
+                }
+            }
+            _ => {}
+        }
+    }
+    for requirement in requirements.into_iter().filter_map(Value::as_array_mut).flatten() {
+        if let Some(requirement) = requirement.as_object_mut() {
+            *requirement = std::mem::take(requirement)
+                .into_iter()
+                .map(|(name, scopes)| (renames.get(&name).cloned().unwrap_or(name), scopes))
+                .collect();
+        }
+    }
+}
+
+fn rename_refs(value: &mut Value, renames: &HashMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get_mut("$ref") {
+                if let Some(renamed) = renames.get(reference.as_str()) {
+                    *reference = renamed.clone();
+                }
+            }
+            map.values_mut().for_each(|child| rename_refs(child, renames));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| rename_refs(item, renames)),
+        _ => {}
+    }
+}
+
+fn merge_path_item(target: &mut PathItem, mut item: PathItem, path: &str) {
+    for ((method, existing), (_, operation)) in target.slots_mut().into_iter().zip(item.slots_mut()) {
+        match (existing.is_some(), operation.take()) {
+            (false, Some(operation)) => *existing = Some(operation),
+            (true, Some(_)) => {
+                log::warn!("{} {} is defined by several specs, keeping the first one", method.to_uppercase(), path)
+            }
+            (_, None) => {}
+        }
+    }
+}
+
+/// Combines several specs into one, prefixing conflicting definitions and security schemes with the
+/// spec name.
+pub fn merge_documents(documents: Vec<(String, Value)>) -> Result<Swagger> {
+    let mut seen: HashMap<String, Value> = HashMap::new();
+    let mut seen_schemes: HashMap<String, Value> = HashMap::new();
+    let mut specs = Vec::new();
+
+    for (name, mut document) in documents {
+        let pointer = definitions_pointer(&document);
+        let mut renames = HashMap::new();
+
+        if let Some(definitions) = document.pointer_mut(pointer).and_then(Value::as_object_mut) {
+            // A definition referring to a renamed one differs from its namesake too, so the renaming
+            // repeats until no more definitions change.
+            let renamed = |definition: &Value, renames: &HashMap<String, String>| {
+                let mut definition = definition.clone();
+                rename_refs(&mut definition, renames);
+                definition
+            };
+            let mut unique_names: HashMap<String, String> = HashMap::new();
+            loop {
+                let count = unique_names.len();
+                for (definition_name, definition) in definitions.iter() {
+                    let definition = renamed(definition, &renames);
+                    let unique_name = unique_name(definition_name, &name, &definition, &seen);
+                    if unique_name != *definition_name && !unique_names.contains_key(definition_name) {
+                        renames.insert(
+                            format!("#{}/{}", pointer, definition_name),
+                            format!("#{}/{}", pointer, unique_name),
+                        );
+                        unique_names.insert(definition_name.clone(), unique_name);
+                    }
+                }
+                if unique_names.len() == count {
+                    break;
+                }
+            }
+            let mut merged = Map::new();
+            for (definition_name, definition) in std::mem::take(definitions) {
+                let unique_name = unique_names.remove(&definition_name).unwrap_or(definition_name);
+                seen.entry(unique_name.clone()).or_insert_with(|| renamed(&definition, &renames));
+                merged.insert(unique_name, definition);
+            }
+            *definitions = merged;
+        }
+        rename_refs(&mut document, &renames);
+
+        let mut scheme_renames = HashMap::new();
+        let schemes_pointer = security_schemes_pointer(&document);
+        if let Some(schemes) = document.pointer_mut(schemes_pointer).and_then(Value::as_object_mut) {
+            let mut merged = Map::new();
+            for (scheme_name, scheme) in std::mem::take(schemes) {
+                let unique_name = unique_name(&scheme_name, &name, &scheme, &seen_schemes);
+                if unique_name != scheme_name {
+                    log::warn!(
+                        "security scheme {} of {} differs from another spec's, renaming it to {}",
+                        scheme_name,
+                        name,
+                        unique_name
+                    );
+                    scheme_renames.insert(scheme_name, unique_name.clone());
+                }
+                seen_schemes.entry(unique_name.clone()).or_insert_with(|| scheme.clone());
+                merged.insert(unique_name, scheme);
+            }
+            *schemes = merged;
+        }
+        rename_requirements(&mut document, &scheme_renames);
+        specs.push(from_document(&name, document)?);
+    }
+
+    let mut specs = specs.into_iter();
+    let mut merged = specs.next().ok_or_else(|| GeneratorError::Options("no spec to merge".to_string()))?;
+    let base_path = merged.base_path.clone().unwrap_or_default();
+    let rebase = specs.as_slice().iter().any(|spec| spec.base_path.clone().unwrap_or_default() != base_path);
+    if rebase {
+        merged.paths = std::mem::take(&mut merged.paths)
+            .into_iter()
+            .map(|(path, item)| (format!("{}{}", base_path.trim_end_matches('/'), path), item))
+            .collect();
+        merged.base_path = Some(String::new());
+        // Server URLs end in the base path the paths now carry themselves.
+        merged.servers.clear();
+    }
+
+    for spec in specs {
+        if spec.host != merged.host {
+            log::warn!("merged specs use different hosts, using {}", merged.host.as_deref().unwrap_or("none"));
+        }
+        let spec_base_path = if rebase { spec.base_path.unwrap_or_default() } else { String::new() };
+        for (name, definition) in spec.definitions {
+            merged.definitions.entry(name).or_insert(definition);
+        }
+        for (name, scheme) in spec.security_definitions {
+            merged.security_definitions.entry(name).or_insert(scheme);
+        }
+        for (path, mut item) in spec.paths {
+            if spec.security != merged.security {
+                for (_, slot) in item.slots_mut() {
+                    if let Some(operation) = slot {
+                        operation.security.get_or_insert_with(|| spec.security.clone());
+                    }
+                }
+            }
+            let path = format!("{}{}", spec_base_path.trim_end_matches('/'), path);
+            match merged.paths.get_mut(&path) {
+                Some(existing) => merge_path_item(existing, item, &path),
+                None => {
+                    merged.paths.insert(path, item);
+                }
+            }
+        }
+    }
+
+    Ok(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_documents;
+    use serde_json::{json, Value};
+
+    fn spec(host: &str, pet: Value, scheme: Value) -> Value {
+        json!({
+            "swagger": "2.0",
+            "info": { "title": "Pets", "version": "1.0" },
+            "host": host,
+            "securityDefinitions": { "Auth": scheme },
+            "paths": {},
+            "definitions": {
+                "Pet": pet,
+                "Owner": { "type": "object", "properties": { "pet": { "$ref": "#/definitions/Pet" } } }
+            }
+        })
+    }
+
+    fn pet(property: &str) -> Value {
+        json!({ "type": "object", "properties": { property: { "type": "string" } } })
+    }
+
+    #[test]
+    fn conflicting_definitions_are_prefixed_with_the_spec_name() {
+        let api_key = json!({ "type": "apiKey", "name": "X-Key", "in": "header" });
+        let merged = merge_documents(vec![
+            ("pets".to_string(), spec("a.com", pet("name"), api_key.clone())),
+            ("store-api".to_string(), spec("a.com", pet("tag"), api_key.clone())),
+            ("other".to_string(), spec("a.com", pet("name"), api_key)),
+        ])
+        .unwrap();
+        let mut names: Vec<&String> = merged.definitions.keys().collect();
+        names.sort();
+        assert_eq!(names, ["Owner", "Pet", "StoreApiOwner", "StoreApiPet"]);
+        let owner = &merged.definitions["StoreApiOwner"].properties.as_ref().unwrap()["pet"];
+        assert_eq!(owner.additional["$ref"], "#/definitions/StoreApiPet");
+    }
+
+    #[test]
+    fn specs_without_a_prefix_number_their_conflicting_definitions() {
+        let api_key = json!({ "type": "apiKey", "name": "X-Key", "in": "header" });
+        let merged = merge_documents(vec![
+            ("pets".to_string(), spec("a.com", pet("name"), api_key.clone())),
+            ("__".to_string(), spec("a.com", pet("tag"), api_key.clone())),
+            ("--".to_string(), spec("a.com", pet("id"), api_key)),
+        ])
+        .unwrap();
+        assert!(merged.definitions.contains_key("Pet2"));
+        assert!(merged.definitions.contains_key("Pet3"));
+    }
+
+    #[test]
+    fn conflicting_security_schemes_are_renamed_with_their_requirements() {
+        let mut first = spec("a.com", pet("name"), json!({ "type": "apiKey", "name": "X-Key", "in": "header" }));
+        let mut second = spec("b.com", pet("name"), json!({ "type": "basic" }));
+        first["security"] = json!([{ "Auth": [] }]);
+        second["paths"] = json!({
+            "/owners": { "get": { "security": [{ "Auth": [] }], "responses": { "200": { "description": "ok" } } } }
+        });
+        let merged = merge_documents(vec![("pets".to_string(), first), ("owners".to_string(), second)]).unwrap();
+
+        assert_eq!(merged.host.as_deref(), Some("a.com"));
+        assert_eq!(merged.security_definitions["Auth"].scheme_type, "apiKey");
+        assert_eq!(merged.security_definitions["OwnersAuth"].scheme_type, "basic");
+        let operation = merged.paths["/owners"].get.as_ref().unwrap();
+        let requirement = &operation.security.as_ref().unwrap()[0];
+        assert!(requirement.contains_key("OwnersAuth"), "{:?}", requirement);
+    }
+}
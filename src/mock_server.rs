@@ -0,0 +1,227 @@
+use crate::error::{GeneratorError, Result};
+use crate::generators::mock::{error_reply, success_reply, Examples, Reply};
+use crate::spec::Swagger;
+use serde_json::json;
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tiny_http::{Header, Request, Response, Server};
+
+/// Where the mock server listens and how it misbehaves.
+#[derive(Debug, Clone)]
+pub struct MockOptions {
+    pub host: String,
+    pub port: u16,
+    /// How long to wait before answering each request.
+    pub latency: Duration,
+    /// The fraction of requests answered with an error response of their operation instead.
+    pub error_rate: f64,
+    /// Seeds the draws of the failing requests, which then fail the same on every run.
+    pub seed: Option<u64>,
+}
+
+/// An operation the server answers, with its path split into segments of which `None` are the
+/// parameters, matching any value.
+struct Route {
+    method: String,
+    segments: Vec<Option<String>>,
+    success: (u16, Reply),
+    error: (u16, Reply),
+}
+
+struct Routes {
+    base_path: String,
+    routes: Vec<Route>,
+}
+
+impl Routes {
+    fn new(swagger: &Swagger) -> Routes {
+        let examples = Examples::new(swagger);
+        let mut routes = Vec::new();
+        for (path, path_item) in &swagger.paths {
+            for (method, operation) in path_item.operations() {
+                let segments = segments(path)
+                    .map(|segment| Some(segment.to_string()).filter(|_| !segment.starts_with('{')))
+                    .collect();
+                let error = error_reply(&examples, operation)
+                    .unwrap_or_else(|| (500, Reply::Json(json!({ "message": "injected error" }))));
+                routes.push(Route {
+                    method: method.to_uppercase(),
+                    segments,
+                    success: success_reply(swagger, &examples, operation),
+                    error,
+                });
+            }
+        }
+        let base_path = swagger.base_path.as_deref().unwrap_or_default().trim_end_matches('/').to_string();
+        Routes { base_path, routes }
+    }
+
+    /// The route of the first operation matching `method` and `url`, in the order of the spec.
+    fn find(&self, method: &str, url: &str) -> Option<&Route> {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+        let path = path.strip_prefix(&self.base_path).filter(|rest| rest.is_empty() || rest.starts_with('/'))?;
+        let requested: Vec<&str> = segments(path).collect();
+        self.routes.iter().find(|route| {
+            route.method == method
+                && route.segments.len() == requested.len()
+                && route.segments.iter().zip(&requested).all(|(segment, requested)| match segment {
+                    Some(segment) => segment == requested,
+                    None => !requested.is_empty(),
+                })
+        })
+    }
+}
+
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Serves made-up responses for every operation of `swagger` until the process is stopped,
+/// answering each request on a thread of its own so that the latency does not queue them.
+pub fn serve(swagger: &Swagger, options: MockOptions) -> Result<()> {
+    if !(0.0..=1.0).contains(&options.error_rate) {
+        let message = format!("--error-rate must be between 0 and 1, not {}", options.error_rate);
+        return Err(GeneratorError::Options(message));
+    }
+    let address = format!("{}:{}", options.host, options.port);
+    let server = Server::http(&address).map_err(|e| GeneratorError::Serve(format!("{}: {}", address, e)))?;
+    let routes = Arc::new(Routes::new(swagger));
+    eprintln!(
+        "serving {} operations on http://{}{}",
+        routes.routes.len(),
+        address,
+        if routes.base_path.is_empty() { "/" } else { &routes.base_path }
+    );
+
+    listen(&server, routes, options);
+    Ok(())
+}
+
+/// Answers the requests of `server` until it is closed. Whether a request fails is drawn here, in the
+/// order the requests arrive, so that a seed gives the same answers whatever the threads do.
+fn listen(server: &Server, routes: Arc<Routes>, options: MockOptions) {
+    let mut rng = options.seed.map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed);
+    let options = Arc::new(options);
+    for request in server.incoming_requests() {
+        let fails = rng.f64() < options.error_rate;
+        let (routes, options) = (Arc::clone(&routes), Arc::clone(&options));
+        thread::spawn(move || respond(request, &routes, &options, fails));
+    }
+}
+
+/// Answers `request` with the error response of its operation when it `fails`, or else the
+/// successful one.
+fn respond(request: Request, routes: &Routes, options: &MockOptions, fails: bool) {
+    let method = request.method().to_string();
+    let url = request.url().to_string();
+    let (status, reply) = match routes.find(&method, &url) {
+        Some(route) if fails => route.error.clone(),
+        Some(route) => route.success.clone(),
+        // A preflight of a browser, which the CORS headers answer.
+        None if method == "OPTIONS" => (204, Reply::Empty),
+        None => (404, Reply::Json(json!({ "message": format!("no operation for {} {}", method, url) }))),
+    };
+    thread::sleep(options.latency);
+
+    let (content_type, body) = match reply {
+        Reply::Empty => (None, Vec::new()),
+        Reply::Binary => (Some("application/octet-stream"), Vec::new()),
+        Reply::Json(value) => (Some("application/json"), serde_json::to_vec(&value).unwrap_or_default()),
+    };
+    let mut response = Response::from_data(body).with_status_code(status);
+    let headers = [
+        ("Access-Control-Allow-Origin", "*"),
+        ("Access-Control-Allow-Headers", "*"),
+        ("Access-Control-Allow-Methods", "GET, POST, PUT, PATCH, DELETE, HEAD, OPTIONS"),
+    ];
+    for (name, value) in content_type.map(|value| ("Content-Type", value)).into_iter().chain(headers) {
+        if let Ok(header) = Header::from_bytes(name, value) {
+            response.add_header(header);
+        }
+    }
+    log::info!("{} {} {}", method, url, status);
+    if let Err(error) = request.respond(response) {
+        log::warn!("cannot answer {} {}: {}", method, url, error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{listen, MockOptions, Routes};
+    use crate::parse_spec;
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+    use tiny_http::Server;
+
+    const SPEC: &str = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "basePath": "/v1",
+        "paths": {
+            "/pets/{petId}": {
+                "get": {
+                    "parameters": [{ "name": "petId", "in": "path", "required": true, "type": "integer" }],
+                    "responses": {
+                        "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } },
+                        "404": { "description": "missing", "schema": { "$ref": "#/definitions/Error" } }
+                    }
+                }
+            }
+        },
+        "definitions": {
+            "Pet": { "type": "object", "properties": { "name": { "type": "string" } } },
+            "Error": { "type": "object", "properties": { "code": { "type": "integer" } } }
+        }
+    }"##;
+
+    /// Serves `SPEC` on a free port, asks for every path in turn and returns the statuses and bodies.
+    fn answers(error_rate: f64, seed: Option<u64>, paths: &[&str]) -> Vec<(u16, Value)> {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let address = server.server_addr().to_ip().unwrap();
+        let routes = Arc::new(Routes::new(&parse_spec(SPEC, None).unwrap()));
+        let options = MockOptions { host: String::new(), port: 0, latency: Duration::ZERO, error_rate, seed };
+        let listener = {
+            let server = Arc::clone(&server);
+            thread::spawn(move || listen(&server, routes, options))
+        };
+
+        let client = reqwest::blocking::Client::new();
+        let answers = paths
+            .iter()
+            .map(|path| {
+                let response = client.get(format!("http://{}{}", address, path)).send().unwrap();
+                assert_eq!(response.headers()["Access-Control-Allow-Origin"], "*");
+                (response.status().as_u16(), response.json().unwrap_or(Value::Null))
+            })
+            .collect();
+        server.unblock();
+        listener.join().unwrap();
+        answers
+    }
+
+    #[test]
+    fn answers_operations_with_made_up_bodies_and_others_with_404() {
+        let answers = answers(0.0, None, &["/v1/pets/1", "/v1/pets/1?expand=true", "/pets/1", "/v1/pets"]);
+        assert_eq!(answers[0], (200, json!({ "name": "string" })));
+        assert_eq!(answers[1], (200, json!({ "name": "string" })));
+        assert_eq!(answers[2], (404, json!({ "message": "no operation for GET /pets/1" })));
+        assert_eq!(answers[3].0, 404);
+    }
+
+    #[test]
+    fn failing_requests_get_the_documented_error_and_repeat_with_a_seed() {
+        assert_eq!(answers(1.0, None, &["/v1/pets/1"]), [(404, json!({ "code": 0 }))]);
+
+        let paths = ["/v1/pets/1"; 32];
+        let statuses = |seed| {
+            let answers = answers(0.5, Some(seed), &paths);
+            answers.into_iter().map(|(status, _)| status).collect::<Vec<_>>()
+        };
+        let first = statuses(7);
+        assert_eq!(first, statuses(7));
+        assert!(first.contains(&200) && first.contains(&404), "{:?}", first);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::cli::NamingStrategy;
+use crate::error::{GeneratorError, Result};
+use crate::generators::{pascal_case, path_operation_name, words};
+use crate::spec::Swagger;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// Settles the name of every operation before generation, so that all languages agree on it,
+/// leaving its `operation_id` as the document has it. Explicit operationIds claim their names
+/// before the ones derived from paths, each group in path and method order; a name that is already
+/// taken, ignoring case and separators, gets the first free numeric suffix in camelCase (`getPet2`
+/// for `get_pet`), so the languages case it like the name it repeats.
+pub fn assign_operation_names(swagger: &mut Swagger, strategy: NamingStrategy) {
+    let mut paths: Vec<String> = swagger.paths.keys().cloned().collect();
+    paths.sort();
+
+    let mut taken = HashSet::new();
+    for explicit in [true, false] {
+        for path in &paths {
+            let Some(item) = swagger.paths.get_mut(path) else { continue };
+            for (method, slot) in item.slots_mut() {
+                let Some(operation) = slot else { continue };
+                let operation_id = match strategy {
+                    NamingStrategy::OperationId => operation.operation_id.clone().filter(|id| !words(id).is_empty()),
+                    NamingStrategy::Path => None,
+                };
+                if operation_id.is_some() != explicit {
+                    continue;
+                }
+                let base = operation_id.unwrap_or_else(|| path_operation_name(method, path));
+                let mut name = base.clone();
+                let mut suffix = 2;
+                while !taken.insert(words(&name).concat()) {
+                    name = format!("{}{}", camel_case(&base), suffix);
+                    suffix += 1;
+                }
+                operation.name = Some(name);
+            }
+        }
+    }
+}
+
+fn camel_case(name: &str) -> String {
+    let name = pascal_case(name);
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Renames the definitions to what `rename` makes of their names, along with the references to
+/// them, for languages naming their types after the definitions. The names `rename` keeps claim
+/// their place first; a name that is already taken gets the first free numeric suffix (`Pet2`).
+pub fn rename_definitions(swagger: Swagger, rename: impl Fn(&str) -> String) -> Result<Swagger> {
+    let mut taken = HashSet::new();
+    let mut renames = HashMap::new();
+    for kept in [true, false] {
+        for name in swagger.definitions.keys() {
+            let base = rename(name);
+            if (base == *name) != kept {
+                continue;
+            }
+            let mut unique = base.clone();
+            let mut suffix = 2;
+            while !taken.insert(unique.clone()) {
+                unique = format!("{}{}", base, suffix);
+                suffix += 1;
+            }
+            if unique != *name {
+                renames.insert(name.clone(), unique);
+            }
+        }
+    }
+    if renames.is_empty() {
+        return Ok(swagger);
+    }
+
+    let invalid = |error: serde_json::Error| GeneratorError::Document {
+        input: "spec".to_string(),
+        message: format!("cannot rename its definitions: {}", error),
+    };
+    let mut document = serde_json::to_value(&swagger).map_err(invalid)?;
+    if let Some(Value::Object(definitions)) = document.get_mut("definitions") {
+        *definitions = std::mem::take(definitions)
+            .into_iter()
+            .map(|(name, definition)| (renames.get(&name).cloned().unwrap_or(name), definition))
+            .collect();
+    }
+    rename_references(&mut document, &renames);
+    serde_json::from_value(document).map_err(invalid)
+}
+
+/// The pointers under which documents keep the definitions that `$ref`s name.
+const DEFINITION_POINTERS: &[&str] = &["#/definitions/", "#/components/schemas/"];
+
+/// Points the `$ref`s to definitions and the discriminator mappings of `value` to the renamed
+/// definitions. References to parameters, responses and the like keep their names, and so do
+/// objects that are merely called `mapping`.
+fn rename_references(value: &mut Value, renames: &HashMap<String, String>) {
+    let rename = |reference: &mut String| {
+        for pointer in DEFINITION_POINTERS {
+            if let Some(renamed) = reference.strip_prefix(pointer).and_then(|name| renames.get(name)) {
+                *reference = format!("{}{}", pointer, renamed);
+                return;
+            }
+        }
+    };
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get_mut("$ref") {
+                rename(reference);
+            }
+            // A mapping names its targets by reference or, in OpenAPI 3, by the name of the schema.
+            if let Some(Value::Object(mapping)) = map.get_mut("discriminator").and_then(|d| d.get_mut("mapping")) {
+                for target in mapping.values_mut() {
+                    match target {
+                        Value::String(name) if !name.contains('/') => {
+                            if let Some(renamed) = renames.get(name.as_str()) {
+                                *name = renamed.clone();
+                            }
+                        }
+                        Value::String(reference) => rename(reference),
+                        _ => {}
+                    }
+                }
+            }
+            map.values_mut().for_each(|child| rename_references(child, renames));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| rename_references(item, renames)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assign_operation_names, rename_references};
+    use crate::cli::NamingStrategy;
+    use crate::parse_spec;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[test]
+    fn repeated_names_are_suffixed_in_camel_case_and_keep_their_operation_ids() {
+        let spec = json!({
+            "swagger": "2.0",
+            "info": { "title": "Items", "version": "1.0" },
+            "paths": {
+                "/a": { "get": { "operationId": "getItem", "responses": {} } },
+                "/b": { "get": { "operationId": "get_item", "responses": {} } },
+                "/c": { "get": { "operationId": "GetItem", "responses": {} } },
+                "/d": { "get": { "responses": {} } }
+            }
+        });
+        let mut swagger = parse_spec(&spec.to_string(), None).unwrap();
+        assign_operation_names(&mut swagger, NamingStrategy::OperationId);
+        let names: Vec<(Option<&str>, Option<&str>)> = swagger
+            .paths
+            .values()
+            .map(|item| {
+                let operation = item.get.as_ref().unwrap();
+                (operation.name.as_deref(), operation.operation_id.as_deref())
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (Some("getItem"), Some("getItem")),
+                (Some("getItem2"), Some("get_item")),
+                (Some("getItem3"), Some("GetItem")),
+                (Some("getD"), None)
+            ]
+        );
+    }
+
+    #[test]
+    fn only_references_to_definitions_and_discriminator_mappings_are_renamed() {
+        let renames = HashMap::from([("pet".to_string(), "Pet".to_string())]);
+        let mut document = json!({
+            "definitions": {
+                "owner": {
+                    "discriminator": { "propertyName": "kind", "mapping": { "a": "#/definitions/pet", "b": "pet" } },
+                    "properties": {
+                        "pet": { "$ref": "#/definitions/pet" },
+                        "other": { "$ref": "#/components/schemas/pet" },
+                        "mapping": { "type": "object", "example": { "mapping": { "a": "#/definitions/pet" } } }
+                    }
+                }
+            },
+            "paths": { "/pets": { "get": { "parameters": [{ "$ref": "#/parameters/pet" }] } } },
+            "responses": { "ok": { "$ref": "#/responses/pet" } }
+        });
+        rename_references(&mut document, &renames);
+        assert_eq!(
+            document,
+            json!({
+                "definitions": {
+                    "owner": {
+                        "discriminator": { "propertyName": "kind", "mapping": { "a": "#/definitions/Pet", "b": "Pet" } },
+                        "properties": {
+                            "pet": { "$ref": "#/definitions/Pet" },
+                            "other": { "$ref": "#/components/schemas/Pet" },
+                            "mapping": { "type": "object", "example": { "mapping": { "a": "#/definitions/pet" } } }
+                        }
+                    }
+                },
+                "paths": { "/pets": { "get": { "parameters": [{ "$ref": "#/parameters/pet" }] } } },
+                "responses": { "ok": { "$ref": "#/responses/pet" } }
+            })
+        );
+    }
+}
@@ -0,0 +1,467 @@
+use crate::error::{GeneratorError, Result};
+use crate::manifest::{self, Entry, Manifest, SpecHashes};
+use rayon::prelude::*;
+use similar::TextDiff;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{self, create_dir_all, File};
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+
+pub type GeneratedFiles = BTreeMap<PathBuf, String>;
+
+/// The first line of the built-in banner of generated files, as it is now and as it was once
+/// misspelt, by which stale ones are recognised even without a manifest.
+const GENERATED_MARKERS: &[&str] =
+    &["This file was generated by swagger-generator", "This file was generated by swagger-genereator"];
+/// The line of the banner that changes every day, even when the rest of the file does not.
+const GENERATED_ON: &str = "Generated on: ";
+
+/// The markers, each in a line comment of its own, of a region of a generated file whose contents
+/// are kept when the file is generated again.
+pub(crate) const CUSTOM_START: &str = "<custom>";
+pub(crate) const CUSTOM_END: &str = "</custom>";
+
+fn is_marker(line: &str, marker: &str) -> bool {
+    let line = line.trim();
+    let comment = line.strip_prefix("//").or_else(|| line.strip_prefix('#'));
+    comment.is_some_and(|text| text.trim() == marker)
+}
+
+/// `contents` with what is between the markers of each custom region replaced by what `fill`
+/// makes of it. A region that is never closed is left as it is.
+fn map_custom_regions(contents: &str, mut fill: impl FnMut(&str) -> String) -> String {
+    let mut result = String::with_capacity(contents.len());
+    let mut region: Option<String> = None;
+    for line in contents.split_inclusive('\n') {
+        match region.as_mut() {
+            Some(body) if is_marker(line, CUSTOM_END) => {
+                result.push_str(&fill(body));
+                result.push_str(line);
+                region = None;
+            }
+            Some(body) => body.push_str(line),
+            None => {
+                result.push_str(line);
+                if is_marker(line, CUSTOM_START) {
+                    region = Some(String::new());
+                }
+            }
+        }
+    }
+    result.push_str(&region.unwrap_or_default());
+    result
+}
+
+/// `contents` with its custom regions emptied, which is what the manifest hashes so that only
+/// edits outside of them count as changes to a generated file.
+pub fn without_custom_regions(contents: &str) -> String {
+    map_custom_regions(contents, |_| String::new())
+}
+
+/// Whether a custom region of `contents` holds something, which removing the file would lose.
+fn has_custom_code(contents: &str) -> bool {
+    let mut custom = false;
+    map_custom_regions(contents, |body| {
+        custom |= !body.trim().is_empty();
+        String::new()
+    });
+    custom
+}
+
+/// The `generated` contents of the file at `path` with its custom regions holding, in order, the
+/// contents of those in `current`.
+fn keep_custom_regions(path: &Path, current: &str, generated: &str) -> String {
+    let mut kept = Vec::new();
+    map_custom_regions(current, |body| {
+        kept.push(body.to_string());
+        String::new()
+    });
+    let mut kept = kept.into_iter();
+    let contents = map_custom_regions(generated, |body| kept.next().unwrap_or_else(|| body.to_string()));
+    let dropped = kept.filter(|body| !body.trim().is_empty()).count();
+    if dropped > 0 {
+        log::warn!("dropping {} custom regions of {}, which the generated file no longer has", dropped, path.display());
+    }
+    contents
+}
+
+/// Whether `current` is what writing `contents` would leave, apart from the date of generation.
+fn is_unchanged(current: &str, contents: &str) -> bool {
+    let current: Vec<&str> = current.split_inclusive('\n').collect();
+    let contents: Vec<&str> = contents.split_inclusive('\n').collect();
+    current.len() == contents.len()
+        && current
+            .iter()
+            .zip(&contents)
+            .all(|(old, new)| old == new || (old.contains(GENERATED_ON) && new.contains(GENERATED_ON)))
+}
+
+/// What writing the generated files to a directory would do to one of the files in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Create,
+    /// Overwrite it, as the unified diff from its current contents says.
+    Modify(String),
+    Unchanged,
+    /// Leave in place a file from an earlier generation that is no longer generated.
+    Stale,
+    /// Leave as it is a generated file that was edited since, which `--force` overwrites.
+    Keep,
+    /// Remove a file the manifest says an earlier generation wrote, which `--clean` does once it
+    /// is no longer generated.
+    Delete,
+}
+
+/// Compares `files` with the contents of `output_dir`, without writing to it, as writing them
+/// with `clean` and `force` would change it.
+pub fn changes(
+    output_dir: &Path,
+    files: &GeneratedFiles,
+    clean: bool,
+    force: bool,
+) -> Result<BTreeMap<PathBuf, Change>> {
+    let read = |path: &Path, source| GeneratorError::Read { input: path.display().to_string(), source };
+    let manifest = Manifest::read(output_dir);
+    let mut changes = BTreeMap::new();
+    for (path, contents) in files {
+        let full_path = output_dir.join(path);
+        let change = match fs::read_to_string(&full_path) {
+            Ok(current) => {
+                let contents = keep_custom_regions(&full_path, &current, contents);
+                if is_unchanged(&current, &contents) {
+                    Change::Unchanged
+                } else if !force && manifest.is_edited(path, &current) {
+                    Change::Keep
+                } else {
+                    let name = full_path.display().to_string();
+                    let diff = TextDiff::from_lines(&current, &contents);
+                    Change::Modify(diff.unified_diff().header(&name, &name).to_string())
+                }
+            }
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Change::Create,
+            Err(error) => return Err(read(&full_path, error)),
+        };
+        changes.insert(path.clone(), change);
+    }
+    let orphans: BTreeSet<PathBuf> = manifest.orphans(output_dir, files).into_iter().map(|(path, _)| path).collect();
+    for path in existing_files(output_dir, Path::new("")).map_err(|error| read(output_dir, error))? {
+        if files.contains_key(&path) {
+            continue;
+        }
+        // Files that are not text are not generated either.
+        let contents = fs::read_to_string(output_dir.join(&path)).unwrap_or_default();
+        if orphans.contains(&path) {
+            let deleted = clean && !manifest.is_edited(&path, &contents) && !has_custom_code(&contents);
+            changes.insert(path, if deleted { Change::Delete } else { Change::Stale });
+        } else if contents.lines().take(5).any(|line| GENERATED_MARKERS.iter().any(|marker| line.contains(marker))) {
+            changes.insert(path, Change::Stale);
+        }
+    }
+    Ok(changes)
+}
+
+/// The files under `dir`, relative to it and then prefixed with `prefix`.
+fn existing_files(dir: &Path, prefix: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let path = prefix.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            files.extend(existing_files(&entry.path(), &path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/// Prints which files writing `files` to `output_dir` would create or modify, with the diff of
+/// each modified one, and which generated files it would leave stale, keep since they were edited
+/// or, with `clean`, delete.
+pub fn write_preview(output_dir: &Path, files: &GeneratedFiles, clean: bool, force: bool) -> Result<()> {
+    let changes = changes(output_dir, files, clean, force)?;
+    print_changes(output_dir, changes).map_err(stdout_error)
+}
+
+fn print_changes(output_dir: &Path, changes: BTreeMap<PathBuf, Change>) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    let mut counts = [0; 6];
+    for (path, change) in changes {
+        let path = output_dir.join(path);
+        match change {
+            Change::Create => {
+                counts[0] += 1;
+                writeln!(stdout, "create {}", path.display())?;
+            }
+            Change::Modify(diff) => {
+                counts[1] += 1;
+                writeln!(stdout, "modify {}", path.display())?;
+                stdout.write_all(diff.as_bytes())?;
+            }
+            Change::Unchanged => counts[2] += 1,
+            Change::Stale => {
+                counts[3] += 1;
+                writeln!(stdout, "stale  {}", path.display())?;
+            }
+            Change::Keep => {
+                counts[4] += 1;
+                writeln!(stdout, "keep   {}", path.display())?;
+            }
+            Change::Delete => {
+                counts[5] += 1;
+                writeln!(stdout, "delete {}", path.display())?;
+            }
+        }
+    }
+    let [create, modify, unchanged, stale, keep, delete] = counts;
+    eprintln!(
+        "{} to create, {} to modify, {} unchanged, {} stale, {} edited to keep, {} to delete",
+        create, modify, unchanged, stale, keep, delete
+    );
+    Ok(())
+}
+
+/// Writes `files` to `output_dir`, skipping the ones it already holds so that their modification
+/// times only move when they change and watchers and incremental builds downstream stay idle.
+///
+/// The manifest of the directory records the files written, with the hash in `specs` of the spec
+/// each came from. Those an earlier generation wrote that are no longer generated are removed
+/// when `clean`, and otherwise kept in the manifest so that a later clean still removes them.
+/// Generated files edited since are left as they are with a warning unless `force`, and never
+/// removed. What their custom regions hold is kept, and editing it does not count as a change,
+/// though it keeps a file that is no longer generated from being removed.
+pub fn write_to_dir(
+    output_dir: &Path,
+    files: &GeneratedFiles,
+    specs: &SpecHashes,
+    clean: bool,
+    force: bool,
+) -> Result<()> {
+    let previous = Manifest::read(output_dir);
+    let written = files
+        .par_iter()
+        .map(|(path, contents)| {
+            let full_path = output_dir.join(path);
+            let spec = manifest::spec_of(specs, path);
+            // Anything in the way of reading it is reported by the write.
+            let current = fs::read_to_string(&full_path).ok();
+            let kept = current.as_deref().map(|current| keep_custom_regions(&full_path, current, contents));
+            let contents = kept.as_deref().unwrap_or(contents);
+            if let Some(current) = current.as_deref().filter(|current| is_unchanged(current, contents)) {
+                log::info!("unchanged {}", full_path.display());
+                return Ok((path.clone(), Entry { spec, content: manifest::content_hash(current) }));
+            }
+            let edited = previous.files.get(path).filter(|_| {
+                !force && current.as_deref().is_some_and(|current| previous.is_edited(path, current))
+            });
+            if let Some(entry) = edited {
+                let name = full_path.display();
+                log::warn!("keeping {}, which was changed since it was generated; --force overwrites it", name);
+                return Ok((path.clone(), entry.clone()));
+            }
+            write_file(&full_path, contents)
+                .map_err(|source| GeneratorError::Write { path: full_path.clone(), source })?;
+            log::info!("wrote {}", full_path.display());
+            Ok((path.clone(), Entry { spec, content: manifest::content_hash(contents) }))
+        })
+        .collect::<Result<_>>()?;
+
+    let mut manifest = Manifest { files: written };
+    for (orphan, entry) in previous.orphans(output_dir, files) {
+        let full_path = output_dir.join(&orphan);
+        let edited = fs::read_to_string(&full_path)
+            .map_or(true, |current| previous.is_edited(&orphan, &current) || has_custom_code(&current));
+        if clean && edited {
+            let name = full_path.display();
+            log::warn!("keeping {}, which was changed since it was generated or holds custom code", name);
+        } else if clean {
+            remove_file(output_dir, &full_path)?;
+            continue;
+        }
+        manifest.files.insert(orphan, entry);
+    }
+    manifest.write(output_dir)
+}
+
+/// Removes the file at `path` and the directories under `output_dir` it leaves empty.
+fn remove_file(output_dir: &Path, path: &Path) -> Result<()> {
+    fs::remove_file(path).map_err(|source| GeneratorError::Write { path: path.to_path_buf(), source })?;
+    log::info!("removed {}", path.display());
+    let mut dir = path.parent();
+    while let Some(parent) = dir.filter(|parent| *parent != output_dir) {
+        // Fails for the first directory that still holds something.
+        if fs::remove_dir(parent).is_err() {
+            break;
+        }
+        dir = parent.parent();
+    }
+    Ok(())
+}
+
+/// Whether `path` stays inside the directory it is joined to.
+pub fn is_relative(path: &Path) -> bool {
+    path.components().all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        && path.components().next().is_some()
+}
+
+pub(crate) fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    let mut file = File::create(path)?;
+    file.write_all(contents.as_bytes())
+}
+
+fn stdout_error(source: io::Error) -> GeneratorError {
+    GeneratorError::Write { path: PathBuf::from("stdout"), source }
+}
+
+pub fn write_to_stdout(files: &GeneratedFiles) -> Result<()> {
+    print_files(files).map_err(stdout_error)
+}
+
+fn print_files(files: &GeneratedFiles) -> io::Result<()> {
+    let mut stdout = io::stdout().lock();
+    for (path, contents) in files {
+        writeln!(stdout, "// ===== {} =====", path.display())?;
+        stdout.write_all(contents.as_bytes())?;
+        writeln!(stdout)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_relative, keep_custom_regions, without_custom_regions, write_to_dir, GeneratedFiles};
+    use crate::manifest::{Manifest, SpecHashes};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    /// An empty directory of its own for the test `name`.
+    fn output_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("swagger-generator-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn files(entries: &[(&str, &str)]) -> GeneratedFiles {
+        entries.iter().map(|(path, contents)| (PathBuf::from(path), contents.to_string())).collect()
+    }
+
+    #[test]
+    fn edited_files_survive_regeneration_unless_forced() {
+        let dir = output_dir("edited");
+        write_to_dir(&dir, &files(&[("service.ts", "v1\n")]), &SpecHashes::new(), false, false).unwrap();
+        fs::write(dir.join("service.ts"), "edited\n").unwrap();
+
+        write_to_dir(&dir, &files(&[("service.ts", "v2\n")]), &SpecHashes::new(), false, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("service.ts")).unwrap(), "edited\n");
+        // Still an edit of what was generated, so a second run keeps it as well.
+        write_to_dir(&dir, &files(&[("service.ts", "v3\n")]), &SpecHashes::new(), false, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("service.ts")).unwrap(), "edited\n");
+
+        write_to_dir(&dir, &files(&[("service.ts", "v3\n")]), &SpecHashes::new(), false, true).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("service.ts")).unwrap(), "v3\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clean_removes_orphans_but_not_edited_ones() {
+        let dir = output_dir("clean");
+        let first = files(&[("service.ts", "s\n"), ("interfaces/Old.ts", "old\n"), ("Edited.ts", "e\n")]);
+        write_to_dir(&dir, &first, &SpecHashes::new(), false, false).unwrap();
+        fs::write(dir.join("Edited.ts"), "mine\n").unwrap();
+        fs::write(dir.join("notes.md"), "by hand\n").unwrap();
+
+        // Without clean the orphans stay, and so does their place in the manifest.
+        write_to_dir(&dir, &files(&[("service.ts", "s\n")]), &SpecHashes::new(), false, false).unwrap();
+        assert!(dir.join("interfaces/Old.ts").is_file());
+        assert!(Manifest::read(&dir).files.contains_key(Path::new("interfaces/Old.ts")));
+
+        write_to_dir(&dir, &files(&[("service.ts", "s\n")]), &SpecHashes::new(), true, false).unwrap();
+        assert!(!dir.join("interfaces").exists());
+        assert_eq!(fs::read_to_string(dir.join("Edited.ts")).unwrap(), "mine\n");
+        assert_eq!(fs::read_to_string(dir.join("notes.md")).unwrap(), "by hand\n");
+        let manifest = Manifest::read(&dir);
+        assert!(!manifest.files.contains_key(Path::new("interfaces/Old.ts")));
+        assert!(manifest.files.contains_key(Path::new("Edited.ts")));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn paths_leading_out_of_the_directory_are_not_relative() {
+        assert!(is_relative(Path::new("service.ts")));
+        assert!(is_relative(Path::new("./interfaces/Pet.ts")));
+        assert!(!is_relative(Path::new("")));
+        assert!(!is_relative(Path::new("../service.ts")));
+        assert!(!is_relative(Path::new("interfaces/../../service.ts")));
+        assert!(!is_relative(Path::new("/etc/passwd")));
+    }
+
+    const GENERATED: &str = "export const a = 1;\n\n// <custom>\n// </custom>\n";
+
+    #[test]
+    fn custom_regions_survive_regeneration() {
+        let current = "export const a = 1;\n\n// <custom>\nexport const b = a + 1;\n// </custom>\n";
+        let generated = GENERATED.replace("a = 1", "a = 2");
+        assert_eq!(
+            keep_custom_regions(Path::new("service.ts"), current, &generated),
+            "export const a = 2;\n\n// <custom>\nexport const b = a + 1;\n// </custom>\n"
+        );
+        assert_eq!(without_custom_regions(current), GENERATED);
+
+        let dir = output_dir("custom");
+        write_to_dir(&dir, &files(&[("service.ts", GENERATED)]), &SpecHashes::new(), false, false).unwrap();
+        fs::write(dir.join("service.ts"), current).unwrap();
+        write_to_dir(&dir, &files(&[("service.ts", &generated)]), &SpecHashes::new(), false, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("service.ts")).unwrap(), current.replace("a = 1", "a = 2"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn regions_are_filled_in_order_and_dropped_when_the_template_lost_them() {
+        let current = "# <custom>\none\n# </custom>\n# <custom>\ntwo\n# </custom>\n";
+        assert_eq!(
+            keep_custom_regions(Path::new("client.py"), current, "x\n# <custom>\n# </custom>\n"),
+            "x\n# <custom>\none\n# </custom>\n"
+        );
+        assert_eq!(keep_custom_regions(Path::new("client.py"), current, "x\n"), "x\n");
+    }
+
+    #[test]
+    fn unbalanced_markers_are_not_regions() {
+        let unclosed = "a\n// <custom>\nmine\n";
+        assert_eq!(without_custom_regions(unclosed), unclosed);
+        assert_eq!(keep_custom_regions(Path::new("a.ts"), unclosed, GENERATED), GENERATED);
+        let unopened = "a\nmine\n// </custom>\n";
+        assert_eq!(without_custom_regions(unopened), unopened);
+
+        // Losing its end marker makes the region an edit of the file, which is then kept.
+        let dir = output_dir("unbalanced");
+        write_to_dir(&dir, &files(&[("a.ts", GENERATED)]), &SpecHashes::new(), false, false).unwrap();
+        fs::write(dir.join("a.ts"), "export const a = 1;\n\n// <custom>\nmine\n").unwrap();
+        write_to_dir(&dir, &files(&[("a.ts", GENERATED)]), &SpecHashes::new(), false, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("a.ts")).unwrap(), "export const a = 1;\n\n// <custom>\nmine\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn clean_keeps_renamed_files_holding_custom_code() {
+        let dir = output_dir("renamed");
+        let first = files(&[("PetService.ts", GENERATED), ("StoreService.ts", GENERATED)]);
+        write_to_dir(&dir, &first, &SpecHashes::new(), false, false).unwrap();
+        let custom = GENERATED.replace("// <custom>\n", "// <custom>\nexport const mine = 1;\n");
+        fs::write(dir.join("PetService.ts"), &custom).unwrap();
+
+        let renamed = files(&[("PetsService.ts", GENERATED), ("StoresService.ts", GENERATED)]);
+        write_to_dir(&dir, &renamed, &SpecHashes::new(), true, false).unwrap();
+        assert_eq!(fs::read_to_string(dir.join("PetService.ts")).unwrap(), custom);
+        assert!(!dir.join("StoreService.ts").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
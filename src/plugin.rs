@@ -0,0 +1,117 @@
+use crate::cli::Language;
+use crate::error::{GeneratorError, Result};
+use crate::output::{is_relative, GeneratedFiles};
+use crate::spec::Swagger;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fmt::Debug;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Version of the JSON exchanged with external plugins, sent so that they can reject requests
+/// they do not understand.
+const PROTOCOL_VERSION: u32 = 1;
+
+/// A step run on the generated files before they are written. It receives the document the files
+/// were generated from and returns the files to write in their place, so it can rewrite, add or
+/// drop files, or emit a language of its own from the document alone.
+pub trait Plugin: Debug {
+    fn name(&self) -> &str;
+
+    fn run(&self, swagger: &Swagger, language: Language, files: GeneratedFiles) -> Result<GeneratedFiles>;
+}
+
+/// A plugin that is a separate program: it reads a request from stdin and writes its reply to
+/// stdout, both JSON:
+///
+/// ```json
+/// { "version": 1, "language": "typescript", "spec": { "paths": {}, "definitions": {} }, "files": { "service.ts": "..." } }
+/// { "files": { "service.ts": "...", "README.md": "..." } }
+/// ```
+///
+/// `spec` is the document after filtering and naming, shaped like Swagger 2.0 whatever the input
+/// was. Its stderr is passed through, and exiting with a non-zero status fails the generation.
+#[derive(Debug, Clone)]
+pub struct ExternalPlugin {
+    name: String,
+}
+
+#[derive(Serialize)]
+struct Request<'a> {
+    version: u32,
+    language: &'a str,
+    spec: &'a Swagger,
+    files: &'a GeneratedFiles,
+}
+
+#[derive(Deserialize)]
+struct Reply {
+    files: GeneratedFiles,
+}
+
+impl ExternalPlugin {
+    /// The plugin `name`, which is the path of its program when it has a directory in it and is
+    /// otherwise looked up on `PATH` as the program `swagger-generator-<name>`.
+    pub fn new(name: &str) -> ExternalPlugin {
+        ExternalPlugin { name: name.to_string() }
+    }
+
+    fn program(&self) -> Result<PathBuf> {
+        let path = Path::new(&self.name);
+        if path.components().count() > 1 {
+            return Ok(path.to_path_buf());
+        }
+        let program = format!("swagger-generator-{}{}", self.name, env::consts::EXE_SUFFIX);
+        env::var_os("PATH")
+            .iter()
+            .flat_map(env::split_paths)
+            .map(|dir| dir.join(&program))
+            .find(|candidate| candidate.is_file())
+            .ok_or_else(|| self.error(format!("{} is not on PATH", program)))
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> GeneratorError {
+        GeneratorError::Plugin { name: self.name.clone(), message: message.to_string() }
+    }
+}
+
+impl Plugin for ExternalPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&self, swagger: &Swagger, language: Language, files: GeneratedFiles) -> Result<GeneratedFiles> {
+        let language = language.to_possible_value().map(|value| value.get_name().to_string()).unwrap_or_default();
+        let request = Request { version: PROTOCOL_VERSION, language: &language, spec: swagger, files: &files };
+        let request = serde_json::to_vec(&request).map_err(|e| self.error(e))?;
+
+        let mut child = Command::new(self.program()?)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|e| self.error(format!("cannot start: {}", e)))?;
+        // Written from another thread so that a plugin replying before it has read everything
+        // cannot block both processes on full pipes.
+        let mut stdin = child.stdin.take().ok_or_else(|| self.error("stdin is not piped"))?;
+        let writer = thread::spawn(move || stdin.write_all(&request));
+        let output = child.wait_with_output().map_err(|e| self.error(e))?;
+        match writer.join().map_err(|_| self.error("writing the request panicked"))? {
+            // A plugin may reply without reading all of its input.
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {}
+            result => result.map_err(|e| self.error(format!("cannot send the request: {}", e)))?,
+        }
+        if !output.status.success() {
+            return Err(self.error(format!("failed with {}", output.status)));
+        }
+
+        let reply: Reply = serde_json::from_slice(&output.stdout).map_err(|e| self.error(e))?;
+        if let Some(path) = reply.files.keys().find(|path| !is_relative(path)) {
+            return Err(self.error(format!("{} is outside the output directory", path.display())));
+        }
+        Ok(reply.files)
+    }
+}
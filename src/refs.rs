@@ -0,0 +1,219 @@
+use crate::error::{GeneratorError, Result};
+use crate::loader;
+use crate::spec::parse_document;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+const SCHEMA_KEYS: &[&str] = &[
+    "schema",
+    "schemas",
+    "definitions",
+    "properties",
+    "items",
+    "allOf",
+    "oneOf",
+    "anyOf",
+    "not",
+    "additionalProperties",
+];
+
+struct Resolver<'a> {
+    /// The `-H` headers, sent only to the origin of the input they were given for.
+    headers: &'a [String],
+    root: String,
+    definitions_pointer: &'static str,
+    documents: HashMap<String, Value>,
+    existing: HashSet<String>,
+    hoisted: HashMap<String, String>,
+    definitions: Map<String, Value>,
+    inlining: Vec<String>,
+}
+
+pub fn resolve_external_refs(document: &mut Value, input: &str, headers: &[String]) -> Result<()> {
+    let definitions_pointer = if document.get("openapi").is_some() {
+        "/components/schemas"
+    } else {
+        "/definitions"
+    };
+    let existing = document
+        .pointer(definitions_pointer)
+        .and_then(Value::as_object)
+        .map(|definitions| definitions.keys().cloned().collect())
+        .unwrap_or_default();
+
+    let mut resolver = Resolver {
+        headers,
+        root: canonical_location(input),
+        definitions_pointer,
+        documents: HashMap::new(),
+        existing,
+        hoisted: HashMap::new(),
+        definitions: Map::new(),
+        inlining: Vec::new(),
+    };
+    resolver.resolve(document, input, true, false)?;
+
+    if !resolver.definitions.is_empty() {
+        let mut target = &mut *document;
+        for segment in definitions_pointer.split('/').skip(1) {
+            let Some(object) = target.as_object_mut() else {
+                return Err(GeneratorError::Document {
+                    input: input.to_string(),
+                    message: format!("expected an object to hold the referenced schemas at {}", definitions_pointer),
+                });
+            };
+            target = object.entry(segment).or_insert_with(|| Value::Object(Map::new()));
+        }
+        if let Some(definitions) = target.as_object_mut() {
+            definitions.extend(resolver.definitions);
+        }
+    }
+    Ok(())
+}
+
+fn join_location(base: &str, location: &str) -> String {
+    if loader::is_remote(location) {
+        return location.to_string();
+    }
+    if loader::is_remote(base) {
+        return reqwest::Url::parse(base)
+            .and_then(|url| url.join(location))
+            .map(|url| url.to_string())
+            .unwrap_or_else(|_| location.to_string());
+    }
+    let parent = Path::new(base).parent().unwrap_or_else(|| Path::new(""));
+    canonical_location(&parent.join(location).to_string_lossy())
+}
+
+/// Whether two remote locations share a scheme, host and port, so that the credentials sent to
+/// one may be sent to the other.
+fn same_origin(a: &str, b: &str) -> bool {
+    let origin = |location: &str| {
+        reqwest::Url::parse(location).ok().filter(|url| url.has_host()).map(|url| {
+            (url.scheme().to_string(), url.host_str().map(str::to_string), url.port_or_known_default())
+        })
+    };
+    matches!((origin(a), origin(b)), (Some(a), Some(b)) if a == b)
+}
+
+fn canonical_location(location: &str) -> String {
+    if loader::is_remote(location) {
+        return location.to_string();
+    }
+    fs::canonicalize(location)
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| location.to_string())
+}
+
+impl Resolver<'_> {
+    fn resolve(&mut self, node: &mut Value, base: &str, in_root: bool, in_schema: bool) -> Result<()> {
+        match node {
+            Value::Object(map) => {
+                if let Some(Value::String(reference)) = map.get("$ref") {
+                    if !(in_root && reference.starts_with('#')) {
+                        let (location, fragment) = reference.split_once('#').unwrap_or((reference, ""));
+                        let fragment = fragment.to_string();
+                        let location = if location.is_empty() {
+                            base.to_string()
+                        } else {
+                            join_location(base, location)
+                        };
+
+                        if location == self.root {
+                            map.insert("$ref".to_string(), Value::String(format!("#{}", fragment)));
+                        } else if in_schema {
+                            let name = self.hoist(&location, &fragment)?;
+                            let reference = format!("#{}/{}", self.definitions_pointer, name);
+                            map.insert("$ref".to_string(), Value::String(reference));
+                        } else {
+                            let key = format!("{}#{}", location, fragment);
+                            if self.inlining.contains(&key) {
+                                return Ok(());
+                            }
+                            let mut target = self.target(&location, &fragment)?;
+                            self.inlining.push(key);
+                            self.resolve(&mut target, &location, false, false)?;
+                            self.inlining.pop();
+                            *node = target;
+                        }
+                        return Ok(());
+                    }
+                }
+
+                for (key, child) in map.iter_mut() {
+                    let in_schema = in_schema || SCHEMA_KEYS.contains(&key.as_str());
+                    self.resolve(child, base, in_root, in_schema)?;
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    self.resolve(item, base, in_root, in_schema)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn hoist(&mut self, location: &str, fragment: &str) -> Result<String> {
+        let key = format!("{}#{}", location, fragment);
+        if let Some(name) = self.hoisted.get(&key) {
+            return Ok(name.clone());
+        }
+
+        let base_name = fragment
+            .rsplit('/')
+            .find(|segment| !segment.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| loader::input_name(location));
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while self.existing.contains(&name) || self.definitions.contains_key(&name) {
+            name = format!("{}{}", base_name, suffix);
+            suffix += 1;
+        }
+        self.hoisted.insert(key, name.clone());
+        self.definitions.insert(name.clone(), Value::Null);
+
+        let mut target = self.target(location, fragment)?;
+        self.resolve(&mut target, location, false, true)?;
+        self.definitions.insert(name.clone(), target);
+        Ok(name)
+    }
+
+    fn target(&mut self, location: &str, fragment: &str) -> Result<Value> {
+        if !self.documents.contains_key(location) {
+            let headers = if same_origin(&self.root, location) { self.headers } else { &[] };
+            if headers.len() < self.headers.len() && loader::is_remote(location) {
+                log::info!("not sending the -H headers to {}, which is not the origin of the input", location);
+            }
+            let data = loader::read_input(location, headers)?;
+            log::info!("read {} for the references to it", location);
+            let format = loader::detect_format(location, &data);
+            self.documents.insert(location.to_string(), parse_document(location, &data, format)?);
+        }
+
+        self.documents[location].pointer(fragment).cloned().ok_or_else(|| GeneratorError::Document {
+            input: location.to_string(),
+            message: format!("unresolved reference '#{}'", fragment),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::same_origin;
+
+    #[test]
+    fn same_origin_compares_scheme_host_and_port() {
+        assert!(same_origin("https://api.example.com/spec.json", "https://api.example.com/common/pet.yaml"));
+        assert!(same_origin("https://api.example.com/spec.json", "https://api.example.com:443/pet.yaml"));
+        assert!(!same_origin("https://api.example.com/spec.json", "https://schemas.example.org/pet.yaml"));
+        assert!(!same_origin("https://api.example.com/spec.json", "http://api.example.com/pet.yaml"));
+        assert!(!same_origin("https://api.example.com/spec.json", "https://api.example.com:8443/pet.yaml"));
+        assert!(!same_origin("/home/me/spec.json", "https://api.example.com/pet.yaml"));
+        assert!(!same_origin("/home/me/spec.json", "/home/me/pet.yaml"));
+    }
+}
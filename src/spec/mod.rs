@@ -0,0 +1,510 @@
+mod openapi3;
+mod openapi31;
+mod postman;
+
+use crate::error::{GeneratorError, Result};
+use clap::ValueEnum;
+use indexmap::IndexMap;
+use openapi3::OpenApi3;
+use openapi31::OpenApi31;
+use postman::PostmanCollection;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SpecFormat {
+    Json,
+    Yaml,
+}
+
+impl SpecFormat {
+    pub fn detect(path: &Path, data: &str) -> SpecFormat {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => SpecFormat::Json,
+            Some("yaml") | Some("yml") => SpecFormat::Yaml,
+            _ if data.trim_start().starts_with('{') => SpecFormat::Json,
+            _ => SpecFormat::Yaml,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SpecVersion {
+    Swagger2,
+    OpenApi3,
+    OpenApi31,
+    Postman,
+}
+
+impl SpecVersion {
+    fn kind(self) -> &'static str {
+        match self {
+            SpecVersion::Swagger2 => "Swagger 2.0 document",
+            SpecVersion::OpenApi3 => "OpenAPI 3.0 document",
+            SpecVersion::OpenApi31 => "OpenAPI 3.1 document",
+            SpecVersion::Postman => "Postman collection",
+        }
+    }
+
+    fn detect(document: &Value) -> SpecVersion {
+        let postman_schema = document.pointer("/info/schema").and_then(Value::as_str);
+        if postman_schema.is_some_and(|schema| schema.contains("getpostman.com")) {
+            return SpecVersion::Postman;
+        }
+
+        match document.get("openapi").and_then(Value::as_str) {
+            Some(version) if version.starts_with("3.1") => SpecVersion::OpenApi31,
+            Some(version) if version.starts_with("3.") => SpecVersion::OpenApi3,
+            _ => SpecVersion::Swagger2,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Swagger {
+    pub info: IndexMap<String, Value>,
+    #[serde(default)]
+    pub definitions: IndexMap<String, Definition>,
+    pub paths: IndexMap<String, PathItem>,
+    pub schemes: Option<Vec<String>>,
+    pub host: Option<String>,
+    #[serde(rename = "basePath")]
+    pub base_path: Option<String>,
+    #[serde(default)]
+    pub parameters: IndexMap<String, Parameter>,
+    #[serde(default)]
+    pub consumes: Vec<String>,
+    #[serde(default)]
+    pub produces: Vec<String>,
+    #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(rename = "securityDefinitions", default)]
+    pub security_definitions: IndexMap<String, SecurityScheme>,
+    #[serde(default)]
+    pub security: Vec<SecurityRequirement>,
+}
+
+pub type SecurityRequirement = IndexMap<String, Vec<String>>;
+
+/// A server of an OpenAPI 3 document, whose URL may be templated with `{variables}`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Server {
+    pub url: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub variables: IndexMap<String, ServerVariable>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ServerVariable {
+    pub default: String,
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<String>>,
+    pub description: Option<String>,
+}
+
+impl Server {
+    /// The URL with every variable replaced by its default value.
+    pub fn default_url(&self) -> String {
+        self.variables.iter().fold(self.url.clone(), |url, (name, variable)| {
+            url.replace(&format!("{{{}}}", name), &variable.default)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SecurityScheme {
+    #[serde(rename = "type")]
+    pub scheme_type: String,
+    pub name: Option<String>,
+    #[serde(rename = "in")]
+    pub location: Option<String>,
+    pub scheme: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Definition {
+    #[serde(rename = "type")]
+    pub definition_type: Option<String>,
+    pub properties: Option<IndexMap<String, Property>>,
+    pub required: Option<Vec<String>>,
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<Value>>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+    #[serde(rename = "allOf")]
+    pub all_of: Option<Vec<Definition>>,
+    #[serde(rename = "oneOf")]
+    pub one_of: Option<Vec<Definition>>,
+    #[serde(rename = "anyOf")]
+    pub any_of: Option<Vec<Definition>>,
+    pub discriminator: Option<Value>,
+    #[serde(rename = "additionalProperties")]
+    pub additional_properties: Option<Value>,
+    pub description: Option<String>,
+    pub example: Option<Value>,
+    #[serde(default)]
+    pub deprecated: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Property {
+    #[serde(rename = "type")]
+    pub property_type: Option<String>,
+    pub format: Option<String>,
+    #[serde(flatten)]
+    pub additional: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct PathItem {
+    pub get: Option<Operation>,
+    pub post: Option<Operation>,
+    pub put: Option<Operation>,
+    pub patch: Option<Operation>,
+    pub delete: Option<Operation>,
+    pub head: Option<Operation>,
+    pub options: Option<Operation>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+}
+
+impl PathItem {
+    pub fn operations(&self) -> Vec<(&'static str, &Operation)> {
+        [
+            ("get", &self.get),
+            ("post", &self.post),
+            ("put", &self.put),
+            ("patch", &self.patch),
+            ("delete", &self.delete),
+            ("head", &self.head),
+            ("options", &self.options),
+        ]
+        .into_iter()
+            .filter_map(|(method, operation)| operation.as_ref().map(|operation| (method, operation)))
+            .collect()
+    }
+
+    pub fn slots_mut(&mut self) -> [(&'static str, &mut Option<Operation>); 7] {
+        [
+            ("get", &mut self.get),
+            ("post", &mut self.post),
+            ("put", &mut self.put),
+            ("patch", &mut self.patch),
+            ("delete", &mut self.delete),
+            ("head", &mut self.head),
+            ("options", &mut self.options),
+        ]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operations().is_empty()
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Operation {
+    #[serde(rename = "operationId")]
+    pub operation_id: Option<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub deprecated: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub parameters: Vec<Parameter>,
+    pub consumes: Option<Vec<String>>,
+    pub produces: Option<Vec<String>>,
+    pub security: Option<Vec<SecurityRequirement>>,
+    pub responses: IndexMap<String, Response>,
+    #[serde(rename = "x-pagination")]
+    pub pagination: Option<Value>,
+    /// The name the generators give the operation, settled before generation; the document only
+    /// has the `operation_id`, if any.
+    #[serde(skip)]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Parameter {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "in", default)]
+    pub location: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(rename = "type")]
+    pub parameter_type: Option<String>,
+    pub format: Option<String>,
+    pub schema: Option<Schema>,
+    pub items: Option<Schema>,
+    /// How the values of an array are sent: `csv` (the default), `ssv`, `tsv`, `pipes` or `multi`.
+    #[serde(rename = "collectionFormat", skip_serializing_if = "Option::is_none")]
+    pub collection_format: Option<String>,
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<Value>>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Response {
+    pub description: String,
+    #[serde(rename = "schema")]
+    pub response_schema: Option<Schema>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Schema {
+    #[serde(rename = "type")]
+    pub schema_type: Option<String>,
+    pub format: Option<String>,
+    pub items: Option<Box<Schema>>,
+    #[serde(rename = "enum")]
+    pub enum_values: Option<Vec<Value>>,
+    pub description: Option<String>,
+    pub properties: Option<IndexMap<String, Schema>>,
+    pub required: Option<Vec<String>>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+/// Parses the document read from `input`, which names it in errors.
+pub fn parse_document(input: &str, data: &str, format: SpecFormat) -> Result<Value> {
+    let syntax = |format, message: String| GeneratorError::Syntax { input: input.to_string(), format, message };
+    match format {
+        SpecFormat::Json => serde_json::from_str(data).map_err(|e| syntax("JSON", e.to_string())),
+        SpecFormat::Yaml => serde_yaml::from_str(data).map_err(|e| syntax("YAML", e.to_string())),
+    }
+}
+
+/// Writes `document` in `format` for the file at `output`, which names it in errors.
+pub fn write_document(output: &Path, document: &Value, format: SpecFormat) -> Result<String> {
+    let write =
+        |message: String| GeneratorError::Write { path: output.to_path_buf(), source: std::io::Error::other(message) };
+    match format {
+        SpecFormat::Json => {
+            serde_json::to_string_pretty(document).map(|json| json + "\n").map_err(|e| write(e.to_string()))
+        }
+        SpecFormat::Yaml => serde_yaml::to_string(document).map_err(|e| write(e.to_string())),
+    }
+}
+
+impl Swagger {
+    /// Resolves `$ref` parameters and copies path-level parameters into each operation. A reference
+    /// to a parameter the document does not define is an error of the document `input` of `kind`.
+    fn merge_shared_parameters(&mut self, input: &str, kind: &'static str) -> Result<()> {
+        let shared = &self.parameters;
+        let resolve = |parameters: Vec<Parameter>, path: String| -> Result<Vec<Parameter>> {
+            parameters
+                .into_iter()
+                .map(|parameter| match &parameter.reference {
+                    Some(reference) => shared.get(ref_name(reference)).cloned().ok_or_else(|| GeneratorError::Spec {
+                        input: input.to_string(),
+                        kind,
+                        path: path.clone(),
+                        message: format!("the parameter {} is not defined", reference),
+                    }),
+                    None => Ok(parameter),
+                })
+                .collect()
+        };
+
+        for (path, path_item) in self.paths.iter_mut() {
+            let path_parameters =
+                resolve(std::mem::take(&mut path_item.parameters), format!("paths.{}.parameters", path))?;
+            for (method, slot) in path_item.slots_mut() {
+                let Some(operation) = slot else {
+                    continue;
+                };
+                let location = format!("paths.{}.{}.parameters", path, method);
+                let mut parameters = resolve(std::mem::take(&mut operation.parameters), location)?;
+                for parameter in &path_parameters {
+                    let overridden = parameters
+                        .iter()
+                        .any(|p| p.name == parameter.name && p.location == parameter.location);
+                    if !overridden {
+                        parameters.push(parameter.clone());
+                    }
+                }
+                operation.parameters = parameters;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn operation_count(&self) -> usize {
+        self.paths.values().map(|item| item.operations().len()).sum()
+    }
+
+    /// Where the document has schemas that say nothing about their values, which the clients
+    /// type as any, as paths into the document such as `definitions.Pet.properties.tags.items`.
+    pub fn untyped_schemas(&self) -> Vec<String> {
+        let mut untyped = Vec::new();
+        for (name, definition) in &self.definitions {
+            if let Ok(schema) = serde_json::to_value(definition) {
+                find_untyped(&schema, format!("definitions.{}", name), &mut untyped);
+            }
+        }
+        for (path, item) in &self.paths {
+            for (method, operation) in item.operations() {
+                let location = format!("paths.{}.{}", path, method);
+                for parameter in &operation.parameters {
+                    if let Some(Ok(schema)) = parameter.schema.as_ref().map(serde_json::to_value) {
+                        let location = format!("{}.parameters.{}.schema", location, parameter.name);
+                        find_untyped(&schema, location, &mut untyped);
+                    }
+                }
+                for (status, response) in &operation.responses {
+                    if let Some(Ok(schema)) = response.response_schema.as_ref().map(serde_json::to_value) {
+                        find_untyped(&schema, format!("{}.responses.{}.schema", location, status), &mut untyped);
+                    }
+                }
+            }
+        }
+        untyped
+    }
+}
+
+/// The keywords of which a schema needs one for its values to have a type.
+const TYPING_KEYWORDS: &[&str] =
+    &["type", "$ref", "allOf", "oneOf", "anyOf", "enum", "properties", "additionalProperties", "items"];
+
+fn find_untyped(schema: &Value, location: String, untyped: &mut Vec<String>) {
+    let Value::Object(schema) = schema else {
+        return;
+    };
+    if TYPING_KEYWORDS.iter().all(|keyword| schema.get(*keyword).is_none_or(Value::is_null)) {
+        untyped.push(location);
+        return;
+    }
+    if let Some(Value::Object(properties)) = schema.get("properties") {
+        for (name, property) in properties {
+            find_untyped(property, format!("{}.properties.{}", location, name), untyped);
+        }
+    }
+    if let Some(items) = schema.get("items") {
+        find_untyped(items, format!("{}.items", location), untyped);
+    }
+    for keyword in ["allOf", "oneOf", "anyOf"] {
+        if let Some(Value::Array(members)) = schema.get(keyword) {
+            for (index, member) in members.iter().enumerate() {
+                find_untyped(member, format!("{}.{}.{}", location, keyword, index), untyped);
+            }
+        }
+    }
+}
+
+/// The first local `$ref` of `value` that points at nothing in `document`, with where it is.
+/// Examples and extensions hold free-form values, whose `$ref`s are not references.
+fn dangling_ref(document: &Value, value: &Value, location: String) -> Option<(String, String)> {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::String(reference)) = map.get("$ref") {
+                let target = reference.strip_prefix('#').map(|pointer| document.pointer(pointer));
+                if matches!(target, Some(None)) {
+                    return Some((location, reference.clone()));
+                }
+            }
+            map.iter()
+                .filter(|(key, _)| !matches!(key.as_str(), "example" | "examples") && !key.starts_with("x-"))
+                .find_map(|(key, child)| {
+                    let location = if location.is_empty() { key.clone() } else { format!("{}.{}", location, key) };
+                    dangling_ref(document, child, location)
+                })
+        }
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .find_map(|(index, item)| dangling_ref(document, item, format!("{}[{}]", location, index))),
+        _ => None,
+    }
+}
+
+/// Reads a parsed document of any of the supported kinds into the Swagger 2.0 model the
+/// generators work from. `input` names the document in errors.
+pub fn from_document(input: &str, document: Value) -> Result<Swagger> {
+    let version = SpecVersion::detect(&document);
+    let kind = version.kind();
+    let dangling = dangling_ref(&document, &document, String::new());
+    let mut swagger: Swagger = match version {
+        SpecVersion::Swagger2 => {
+            warn_absent_sections(input, &document);
+            deserialize(input, kind, document)?
+        }
+        SpecVersion::OpenApi3 => {
+            warn_ignored_sections(input, &document);
+            let mut openapi = deserialize::<OpenApi3>(input, kind, document)?;
+            openapi.resolve_components(input);
+            openapi.into()
+        }
+        SpecVersion::OpenApi31 => {
+            warn_ignored_sections(input, &document);
+            let mut openapi = deserialize::<OpenApi31>(input, kind, document)?;
+            openapi.resolve_components(input);
+            openapi.into()
+        }
+        SpecVersion::Postman => deserialize::<PostmanCollection>(input, kind, document)?.into(),
+    };
+    swagger.merge_shared_parameters(input, kind)?;
+    if let Some((path, reference)) = dangling {
+        return Err(GeneratorError::Spec {
+            input: input.to_string(),
+            kind,
+            path,
+            message: format!("{} does not resolve to anything in the document", reference),
+        });
+    }
+    log::info!(
+        "parsed {} as {} {}: {} definitions, {} operations",
+        input,
+        if kind.starts_with('O') { "an" } else { "a" },
+        kind,
+        swagger.definitions.len(),
+        swagger.operation_count()
+    );
+    Ok(swagger)
+}
+
+/// Warns about the optional sections of a Swagger 2.0 document that the clients fall back on
+/// defaults for.
+fn warn_absent_sections(input: &str, document: &Value) {
+    let present = |section: &str| document.get(section).is_some_and(|value| !value.is_null());
+    if !present("host") {
+        log::warn!("{} has no host, the clients request URLs relative to where they run", input);
+    } else if !present("schemes") {
+        log::warn!("{} has no schemes, the clients use https", input);
+    }
+    if !present("definitions") {
+        log::warn!("{} has no definitions, only the operations are generated", input);
+    }
+}
+
+/// Warns about the sections of an OpenAPI 3.x document that are read but not generated: webhooks
+/// are requests the API sends, which a client does not call.
+fn warn_ignored_sections(input: &str, document: &Value) {
+    let webhooks = document.get("webhooks").and_then(Value::as_object);
+    if let Some(webhooks) = webhooks.filter(|webhooks| !webhooks.is_empty()) {
+        let names: Vec<&str> = webhooks.keys().map(String::as_str).collect();
+        log::warn!("{} has webhooks, which are not generated: {}", input, names.join(", "));
+    }
+}
+
+/// Deserializes a document of `kind`, locating the value that does not fit in the error.
+fn deserialize<T: DeserializeOwned>(input: &str, kind: &'static str, document: Value) -> Result<T> {
+    serde_path_to_error::deserialize(document).map_err(|error| GeneratorError::Spec {
+        input: input.to_string(),
+        kind,
+        path: error.path().to_string(),
+        message: error.inner().to_string(),
+    })
+}
+
+pub fn ref_name(reference: &str) -> &str {
+    reference.rsplit('/').next().unwrap_or(reference)
+}
@@ -0,0 +1,389 @@
+use super::{
+    Definition, Operation, Parameter, PathItem, Response, Schema, SecurityRequirement, SecurityScheme, Server, Swagger,
+};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::Value;
+
+#[derive(Debug, Deserialize)]
+pub struct OpenApi3<S = Schema, D = Definition> {
+    pub info: IndexMap<String, Value>,
+    #[serde(default)]
+    pub servers: Vec<Server>,
+    #[serde(default = "IndexMap::new")]
+    pub paths: IndexMap<String, PathItem3<S>>,
+    pub components: Option<Components<S, D>>,
+    #[serde(default)]
+    pub security: Vec<SecurityRequirement>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Components<S, D> {
+    #[serde(default = "IndexMap::new")]
+    pub schemas: IndexMap<String, D>,
+    #[serde(default = "IndexMap::new")]
+    pub parameters: IndexMap<String, Parameter3<S>>,
+    #[serde(rename = "securitySchemes", default)]
+    pub security_schemes: IndexMap<String, SecurityScheme>,
+    #[serde(rename = "requestBodies", default = "IndexMap::new")]
+    pub request_bodies: IndexMap<String, RequestBody<S>>,
+    #[serde(default = "IndexMap::new")]
+    pub responses: IndexMap<String, Response3<S>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PathItem3<S> {
+    pub get: Option<Operation3<S>>,
+    pub post: Option<Operation3<S>>,
+    pub put: Option<Operation3<S>>,
+    pub patch: Option<Operation3<S>>,
+    pub delete: Option<Operation3<S>>,
+    pub head: Option<Operation3<S>>,
+    pub options: Option<Operation3<S>>,
+    #[serde(default = "Vec::new")]
+    pub parameters: Vec<Parameter3<S>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Operation3<S> {
+    #[serde(rename = "operationId")]
+    pub operation_id: Option<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub deprecated: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default = "Vec::new")]
+    pub parameters: Vec<Parameter3<S>>,
+    #[serde(rename = "requestBody")]
+    pub request_body: Option<RequestBody<S>>,
+    pub security: Option<Vec<SecurityRequirement>>,
+    #[serde(default = "IndexMap::new")]
+    pub responses: IndexMap<String, Response3<S>>,
+    #[serde(rename = "x-pagination")]
+    pub pagination: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Parameter3<S> {
+    #[serde(default)]
+    pub name: String,
+    #[serde(rename = "in", default)]
+    pub location: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    pub schema: Option<S>,
+    pub style: Option<String>,
+    pub explode: Option<bool>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RequestBody<S> {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default = "IndexMap::new")]
+    pub content: IndexMap<String, MediaType<S>>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Response3<S> {
+    #[serde(default)]
+    pub description: String,
+    #[serde(default = "IndexMap::new")]
+    pub content: IndexMap<String, MediaType<S>>,
+    #[serde(rename = "$ref")]
+    pub reference: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MediaType<S> {
+    pub schema: Option<S>,
+}
+
+impl<S: Clone, D> OpenApi3<S, D> {
+    /// Replaces the request bodies and responses that are `$ref`s with the components they point
+    /// to. A reference leading nowhere is warned about, as its operation then has no typed body.
+    pub fn resolve_components(&mut self, input: &str) {
+        let (request_bodies, responses) = match &self.components {
+            Some(components) => (&components.request_bodies, &components.responses),
+            None => (&IndexMap::new(), &IndexMap::new()),
+        };
+        for item in self.paths.values_mut() {
+            let operations = [
+                &mut item.get,
+                &mut item.post,
+                &mut item.put,
+                &mut item.patch,
+                &mut item.delete,
+                &mut item.head,
+                &mut item.options,
+            ];
+            for operation in operations.into_iter().flatten() {
+                if let Some(request_body) = &mut operation.request_body {
+                    resolve_component(input, request_body, request_bodies, "requestBodies", |body| {
+                        body.reference.as_deref()
+                    });
+                }
+                for response in operation.responses.values_mut() {
+                    resolve_component(input, response, responses, "responses", |response| {
+                        response.reference.as_deref()
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Replaces `value` with the component of `section` its `reference` points to, following
+/// references between components, unless one leads nowhere or back to an earlier one.
+fn resolve_component<T: Clone>(
+    input: &str,
+    value: &mut T,
+    components: &IndexMap<String, T>,
+    section: &str,
+    reference: fn(&T) -> Option<&str>,
+) {
+    let prefix = format!("#/components/{}/", section);
+    let mut seen = Vec::new();
+    while let Some(pointer) = reference(value).map(str::to_string) {
+        let component = pointer.strip_prefix(&prefix).and_then(|name| components.get(name));
+        match component {
+            Some(component) if !seen.contains(&pointer) => {
+                *value = component.clone();
+                seen.push(pointer);
+            }
+            _ => {
+                log::warn!("{}: {} does not resolve to one of components.{}, typing it as any", input, pointer, section);
+                return;
+            }
+        }
+    }
+}
+
+fn content_schema<S: Into<Schema>>(mut content: IndexMap<String, MediaType<S>>) -> Option<Schema> {
+    let media_type = body_media_type(&content)?;
+    content.shift_remove(&media_type).and_then(|media| media.schema).map(Into::into)
+}
+
+/// The media type whose schema describes a body: the first JSON one, or else any.
+fn body_media_type<S>(content: &IndexMap<String, MediaType<S>>) -> Option<String> {
+    content.keys().find(|key| key.contains("json")).or_else(|| content.keys().next()).cloned()
+}
+
+const FORM_MEDIA_TYPES: [&str; 2] = ["multipart/form-data", "application/x-www-form-urlencoded"];
+
+/// Takes the form schema out of a request body, multipart first, unless a JSON body is offered too.
+fn form_schema<S: Into<Schema>>(content: &mut IndexMap<String, MediaType<S>>) -> Option<(&'static str, Schema)> {
+    if content.keys().any(|key| key.contains("json")) {
+        return None;
+    }
+    let media_type = FORM_MEDIA_TYPES.into_iter().find(|media_type| content.contains_key(*media_type))?;
+    let schema = content.shift_remove(media_type).and_then(|media| media.schema)?;
+    Some((media_type, schema.into()))
+}
+
+pub(super) fn split_server_url(url: &str) -> (Option<Vec<String>>, Option<String>, Option<String>) {
+    match url.split_once("://") {
+        Some((scheme, rest)) => {
+            let (host, base_path) = match rest.find('/') {
+                Some(index) => (&rest[..index], &rest[index..]),
+                None => (rest, ""),
+            };
+            (
+                Some(vec![scheme.to_string()]),
+                Some(host.to_string()),
+                Some(base_path.trim_end_matches('/').to_string()),
+            )
+        }
+        None => (None, None, Some(url.trim_end_matches('/').to_string())),
+    }
+}
+
+fn normalize_security_scheme(mut scheme: SecurityScheme) -> SecurityScheme {
+    if scheme.scheme_type == "http" {
+        let http_scheme = scheme.scheme.as_deref().unwrap_or("bearer").to_lowercase();
+        scheme.scheme_type = if http_scheme == "basic" { "basic" } else { "bearer" }.to_string();
+    }
+    scheme
+}
+
+impl<S: Into<Schema>> From<Parameter3<S>> for Parameter {
+    fn from(parameter: Parameter3<S>) -> Parameter {
+        let schema: Option<Schema> = parameter.schema.map(Into::into);
+        // The `style` and `explode` of an array as the Swagger 2.0 `collectionFormat` sending it alike.
+        let array = schema.as_ref().is_some_and(|schema| schema.schema_type.as_deref() == Some("array"));
+        let form = matches!(parameter.location.as_str(), "query" | "cookie" | "formData");
+        let collection_format = match parameter.style.as_deref() {
+            _ if !array => None,
+            Some("spaceDelimited") => Some("ssv"),
+            Some("pipeDelimited") => Some("pipes"),
+            Some("form") | None if form && parameter.explode.unwrap_or(true) => Some("multi"),
+            _ => Some("csv"),
+        };
+        Parameter {
+            name: parameter.name,
+            location: parameter.location,
+            description: parameter.description,
+            required: parameter.required,
+            parameter_type: schema.as_ref().and_then(|s| s.schema_type.clone()),
+            format: schema.as_ref().and_then(|s| s.format.clone()),
+            items: schema.as_ref().and_then(|s| s.items.as_deref().cloned()),
+            collection_format: collection_format.map(str::to_string),
+            enum_values: schema.as_ref().and_then(|s| s.enum_values.clone()),
+            schema,
+            reference: parameter.reference,
+        }
+    }
+}
+
+impl<S: Into<Schema>> From<Operation3<S>> for Operation {
+    fn from(operation: Operation3<S>) -> Operation {
+        let mut parameters: Vec<Parameter> = operation.parameters.into_iter().map(Parameter::from).collect();
+
+        let mut consumes = None;
+        if let Some(mut request_body) = operation.request_body {
+            let form = form_schema(&mut request_body.content);
+            consumes = match &form {
+                Some((media_type, _)) => Some(vec![media_type.to_string()]),
+                None => body_media_type(&request_body.content).map(|media_type| vec![media_type]),
+            };
+            match form.map(|(_, schema)| schema) {
+                // Inline form schemas become one `formData` parameter per field, as in Swagger 2.0.
+                Some(Schema { properties: Some(properties), required, .. }) => {
+                    let required = required.unwrap_or_default();
+                    for (name, schema) in properties {
+                        let mut parameter = Parameter::from(Parameter3 {
+                            required: required.contains(&name),
+                            name,
+                            location: "formData".to_string(),
+                            description: schema.description.clone(),
+                            schema: Some(schema),
+                            style: None,
+                            explode: None,
+                            reference: None,
+                        });
+                        // A binary string is the OpenAPI 3 spelling of a Swagger 2.0 `file` field.
+                        if parameter.parameter_type.as_deref() == Some("string")
+                            && parameter.format.as_deref() == Some("binary")
+                        {
+                            parameter.parameter_type = Some("file".to_string());
+                        }
+                        parameters.push(parameter);
+                    }
+                }
+                schema => parameters.push(Parameter {
+                    name: "body".to_string(),
+                    location: "body".to_string(),
+                    description: request_body.description,
+                    required: request_body.required,
+                    parameter_type: None,
+                    format: None,
+                    schema: schema.or_else(|| content_schema(request_body.content)),
+                    items: None,
+                    collection_format: None,
+                    enum_values: None,
+                    reference: None,
+                }),
+            }
+        }
+
+        let mut produces: Vec<String> = operation
+            .responses
+            .iter()
+            .filter(|(status, _)| status.starts_with('2'))
+            .flat_map(|(_, response)| response.content.keys().cloned())
+            .collect();
+        produces.sort();
+        produces.dedup();
+
+        let responses = operation
+            .responses
+            .into_iter()
+            .map(|(status, response)| {
+                let response = Response {
+                    description: response.description,
+                    response_schema: content_schema(response.content),
+                };
+                (status, response)
+            })
+            .collect();
+
+        Operation {
+            operation_id: operation.operation_id,
+            summary: operation.summary,
+            description: operation.description,
+            deprecated: operation.deprecated,
+            tags: operation.tags,
+            parameters,
+            consumes,
+            produces: (!produces.is_empty()).then_some(produces),
+            security: operation.security,
+            responses,
+            pagination: operation.pagination,
+            name: None,
+        }
+    }
+}
+
+impl<S: Into<Schema>, D: Into<Definition>> From<OpenApi3<S, D>> for Swagger {
+    fn from(openapi: OpenApi3<S, D>) -> Swagger {
+        let (schemes, host, base_path) = match openapi.servers.first() {
+            Some(server) => split_server_url(&server.default_url()),
+            None => (None, None, None),
+        };
+
+        let paths = openapi
+            .paths
+            .into_iter()
+            .map(|(path, item)| {
+                let item = PathItem {
+                    get: item.get.map(Operation::from),
+                    post: item.post.map(Operation::from),
+                    put: item.put.map(Operation::from),
+                    patch: item.patch.map(Operation::from),
+                    delete: item.delete.map(Operation::from),
+                    head: item.head.map(Operation::from),
+                    options: item.options.map(Operation::from),
+                    parameters: item.parameters.into_iter().map(Parameter::from).collect(),
+                };
+                (path, item)
+            })
+            .collect();
+
+        let (definitions, parameters, security_definitions) = match openapi.components {
+            Some(components) => {
+                let definitions = components.schemas.into_iter().map(|(name, d)| (name, d.into())).collect();
+                let parameters = components.parameters.into_iter().map(|(name, p)| (name, p.into())).collect();
+                let security_definitions = components
+                    .security_schemes
+                    .into_iter()
+                    .map(|(name, scheme)| (name, normalize_security_scheme(scheme)))
+                    .collect();
+                (definitions, parameters, security_definitions)
+            }
+            None => Default::default(),
+        };
+
+        Swagger {
+            info: openapi.info,
+            definitions,
+            paths,
+            schemes,
+            host,
+            base_path,
+            parameters,
+            consumes: Vec::new(),
+            produces: Vec::new(),
+            servers: openapi.servers,
+            security_definitions,
+            security: openapi.security,
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use super::openapi3::OpenApi3;
+use super::{Definition, Schema};
+use indexmap::IndexMap;
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+pub type OpenApi31 = OpenApi3<Normalized<Schema>, Normalized<Definition>>;
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SchemaType {
+    Single(String),
+    Multiple(Vec<String>),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum SchemaOrBool {
+    Bool(bool),
+    Schema(Box<JsonSchema>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct JsonSchema {
+    #[serde(rename = "type")]
+    pub schema_type: Option<SchemaType>,
+    #[serde(rename = "const")]
+    pub const_value: Option<Value>,
+    #[serde(rename = "prefixItems", default)]
+    pub prefix_items: Vec<JsonSchema>,
+    pub items: Option<SchemaOrBool>,
+    #[serde(default)]
+    pub properties: IndexMap<String, JsonSchema>,
+    #[serde(rename = "additionalProperties")]
+    pub additional_properties: Option<SchemaOrBool>,
+    #[serde(rename = "allOf", default)]
+    pub all_of: Vec<JsonSchema>,
+    #[serde(rename = "oneOf", default)]
+    pub one_of: Vec<JsonSchema>,
+    #[serde(rename = "anyOf", default)]
+    pub any_of: Vec<JsonSchema>,
+    pub examples: Option<Vec<Value>>,
+    #[serde(flatten)]
+    pub additional: Map<String, Value>,
+}
+
+fn value_type(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::String(_) => Some("string"),
+        Value::Bool(_) => Some("boolean"),
+        Value::Number(n) if n.is_i64() || n.is_u64() => Some("integer"),
+        Value::Number(_) => Some("number"),
+        Value::Array(_) => Some("array"),
+        Value::Object(_) => Some("object"),
+        Value::Null => None,
+    }
+}
+
+fn normalize_all(schemas: Vec<JsonSchema>) -> Value {
+    Value::Array(schemas.into_iter().map(JsonSchema::normalize).collect())
+}
+
+impl JsonSchema {
+    /// Rewrites the schema into the Swagger 2.0 / OpenAPI 3.0 dialect the generators understand.
+    pub fn normalize(self) -> Value {
+        let mut schema = self.additional;
+
+        match self.schema_type {
+            Some(SchemaType::Single(schema_type)) if schema_type == "null" => {
+                schema.insert("nullable".to_string(), Value::Bool(true));
+            }
+            Some(SchemaType::Single(schema_type)) => {
+                schema.insert("type".to_string(), Value::String(schema_type));
+            }
+            Some(SchemaType::Multiple(types)) => {
+                let (nulls, types): (Vec<String>, Vec<String>) =
+                    types.into_iter().partition(|t| t == "null");
+                if !nulls.is_empty() {
+                    schema.insert("nullable".to_string(), Value::Bool(true));
+                }
+                if let [schema_type] = types.as_slice() {
+                    schema.insert("type".to_string(), Value::String(schema_type.clone()));
+                }
+            }
+            None => {}
+        }
+
+        if let Some(const_value) = self.const_value {
+            if let Some(schema_type) = value_type(&const_value) {
+                schema
+                    .entry("type")
+                    .or_insert_with(|| Value::String(schema_type.to_string()));
+            }
+            schema.insert("enum".to_string(), Value::Array(vec![const_value]));
+        }
+
+        if let Some(example) = self.examples.and_then(|e| e.into_iter().next()) {
+            schema.entry("example").or_insert(example);
+        }
+
+        let items = match self.items {
+            Some(SchemaOrBool::Schema(items)) => Some(items.normalize()),
+            _ => None,
+        };
+        if !self.prefix_items.is_empty() {
+            schema
+                .entry("type")
+                .or_insert_with(|| Value::String("array".to_string()));
+            let prefix_items: Vec<Value> =
+                self.prefix_items.into_iter().map(JsonSchema::normalize).collect();
+            let same_items = prefix_items.windows(2).all(|pair| pair[0] == pair[1]);
+            let items = match items {
+                Some(items) => items,
+                None if same_items => prefix_items[0].clone(),
+                None => Value::Object(Map::new()),
+            };
+            schema.insert("items".to_string(), items);
+            schema.insert("x-prefix-items".to_string(), Value::Array(prefix_items));
+        } else if let Some(items) = items {
+            schema.insert("items".to_string(), items);
+        }
+
+        if !self.properties.is_empty() {
+            let properties = self
+                .properties
+                .into_iter()
+                .map(|(name, property)| (name, property.normalize()))
+                .collect();
+            schema.insert("properties".to_string(), Value::Object(properties));
+        }
+
+        match self.additional_properties {
+            Some(SchemaOrBool::Bool(allowed)) => {
+                schema.insert("additionalProperties".to_string(), Value::Bool(allowed));
+            }
+            Some(SchemaOrBool::Schema(additional)) => {
+                schema.insert("additionalProperties".to_string(), additional.normalize());
+            }
+            None => {}
+        }
+
+        for (key, schemas) in [("allOf", self.all_of), ("oneOf", self.one_of), ("anyOf", self.any_of)] {
+            if !schemas.is_empty() {
+                schema.insert(key.to_string(), normalize_all(schemas));
+            }
+        }
+
+        Value::Object(schema)
+    }
+}
+
+/// A JSON Schema, read as the OpenAPI 3.0 schema `T` it normalizes to, so that a schema that does
+/// not fit is reported where it is in the document.
+#[derive(Debug, Clone)]
+pub struct Normalized<T>(T);
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Normalized<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let schema = JsonSchema::deserialize(deserializer)?;
+        serde_json::from_value(schema.normalize()).map(Normalized).map_err(de::Error::custom)
+    }
+}
+
+impl From<Normalized<Definition>> for Definition {
+    fn from(schema: Normalized<Definition>) -> Definition {
+        schema.0
+    }
+}
+
+impl From<Normalized<Schema>> for Schema {
+    fn from(schema: Normalized<Schema>) -> Schema {
+        schema.0
+    }
+}
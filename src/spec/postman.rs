@@ -0,0 +1,443 @@
+use super::openapi3::split_server_url;
+use super::{Operation, Parameter, PathItem, Response, Schema, Swagger};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, Deserialize)]
+pub struct PostmanCollection {
+    pub info: PostmanInfo,
+    #[serde(default)]
+    pub item: Vec<Item>,
+    #[serde(default)]
+    pub variable: Vec<KeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PostmanInfo {
+    pub name: String,
+    pub description: Option<Description>,
+    pub version: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Description {
+    Text(String),
+    Object { content: String },
+}
+
+impl Description {
+    fn text(&self) -> &str {
+        match self {
+            Description::Text(text) => text,
+            Description::Object { content } => content,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Item {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub item: Vec<Item>,
+    pub request: Option<Request>,
+    #[serde(default)]
+    pub response: Vec<ExampleResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Request {
+    Url(String),
+    Detailed(Box<RequestDetail>),
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct RequestDetail {
+    pub method: Option<String>,
+    pub url: Option<Url>,
+    #[serde(default)]
+    pub header: Vec<KeyValue>,
+    pub body: Option<Body>,
+    pub description: Option<Description>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Url {
+    Raw(String),
+    Detailed(UrlDetail),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UrlDetail {
+    pub raw: Option<String>,
+    pub host: Option<Segments>,
+    pub path: Option<Segments>,
+    #[serde(default)]
+    pub query: Vec<KeyValue>,
+    #[serde(default)]
+    pub variable: Vec<KeyValue>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Segments {
+    Joined(String),
+    Split(Vec<Value>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeyValue {
+    pub key: Option<String>,
+    pub value: Option<Value>,
+    pub description: Option<Description>,
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Body {
+    pub mode: Option<String>,
+    pub raw: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExampleResponse {
+    pub name: Option<String>,
+    pub code: Option<u16>,
+    pub status: Option<String>,
+}
+
+struct Endpoint {
+    host: String,
+    path: String,
+    query: Vec<(String, Option<String>)>,
+    variables: IndexMap<String, String>,
+}
+
+fn segment_text(segment: &Value) -> String {
+    match segment {
+        Value::String(text) => text.clone(),
+        other => other.get("value").and_then(Value::as_str).unwrap_or_default().to_string(),
+    }
+}
+
+fn split_raw_url(raw: &str) -> Endpoint {
+    let (without_query, query) = raw.split_once('?').unwrap_or((raw, ""));
+    let (scheme, rest) = match without_query.split_once("://") {
+        Some((scheme, rest)) => (format!("{}://", scheme), rest),
+        None => (String::new(), without_query),
+    };
+    let (host, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, ""),
+    };
+    let query = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (key.to_string(), Some(value.to_string())),
+            None => (pair.to_string(), None),
+        })
+        .collect();
+    Endpoint {
+        host: format!("{}{}", scheme, host),
+        path: path.to_string(),
+        query,
+        variables: IndexMap::new(),
+    }
+}
+
+fn endpoint(url: &Url) -> Endpoint {
+    match url {
+        Url::Raw(raw) => split_raw_url(raw),
+        Url::Detailed(detail) => {
+            let mut endpoint = split_raw_url(detail.raw.as_deref().unwrap_or_default());
+            if detail.raw.is_none() {
+                match &detail.host {
+                    Some(Segments::Joined(host)) => endpoint.host = host.clone(),
+                    Some(Segments::Split(host)) => {
+                        endpoint.host = host.iter().map(segment_text).collect::<Vec<String>>().join(".")
+                    }
+                    None => {}
+                }
+                match &detail.path {
+                    Some(Segments::Joined(path)) => endpoint.path = format!("/{}", path.trim_start_matches('/')),
+                    Some(Segments::Split(path)) => {
+                        endpoint.path = path.iter().map(|s| format!("/{}", segment_text(s))).collect()
+                    }
+                    None => {}
+                }
+            }
+            if !detail.query.is_empty() {
+                endpoint.query = detail
+                    .query
+                    .iter()
+                    .filter(|query| !query.disabled)
+                    .filter_map(|query| {
+                        let key = query.key.clone()?;
+                        Some((key, query.description.as_ref().map(|d| d.text().to_string())))
+                    })
+                    .collect();
+            }
+            endpoint.variables = detail
+                .variable
+                .iter()
+                .filter_map(|variable| {
+                    let description = variable.description.as_ref()?.text().to_string();
+                    Some((variable.key.clone()?, description))
+                })
+                .collect();
+            endpoint
+        }
+    }
+}
+
+fn substitute_variables(text: &str, variables: &IndexMap<String, String>) -> String {
+    let mut result = text.to_string();
+    for (key, value) in variables {
+        result = result.replace(&format!("{{{{{}}}}}", key), value);
+    }
+    result
+}
+
+fn normalize_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if let Some(name) = segment.strip_prefix(':') {
+                format!("{{{}}}", name)
+            } else if segment.starts_with("{{") && segment.ends_with("}}") {
+                format!("{{{}}}", &segment[2..segment.len() - 2])
+            } else {
+                segment.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+fn body_schema(body: &Body) -> Schema {
+    let sample = body
+        .raw
+        .as_deref()
+        .filter(|_| body.mode.as_deref() == Some("raw"))
+        .and_then(|raw| serde_json::from_str::<Value>(raw).ok());
+    let schema_type = match sample {
+        Some(Value::Array(_)) => "array",
+        Some(Value::Object(_)) | None => "object",
+        Some(Value::String(_)) => "string",
+        Some(Value::Number(_)) => "number",
+        Some(Value::Bool(_)) => "boolean",
+        Some(Value::Null) => "object",
+    };
+    Schema {
+        schema_type: Some(schema_type.to_string()),
+        format: None,
+        items: None,
+        enum_values: None,
+        description: None,
+        properties: None,
+        required: None,
+        reference: None,
+    }
+}
+
+fn parameter(name: String, location: &str, required: bool, description: Option<String>) -> Parameter {
+    Parameter {
+        name,
+        location: location.to_string(),
+        description,
+        required,
+        parameter_type: Some("string".to_string()),
+        format: None,
+        schema: None,
+        items: None,
+        collection_format: None,
+        enum_values: None,
+        reference: None,
+    }
+}
+
+fn operation(item: &Item, folder: Option<&str>, detail: &RequestDetail, endpoint: &Endpoint, path: &str) -> Operation {
+    let mut parameters: Vec<Parameter> = path
+        .split('/')
+        .filter(|segment| segment.starts_with('{') && segment.ends_with('}'))
+        .map(|segment| {
+            let name = &segment[1..segment.len() - 1];
+            parameter(name.to_string(), "path", true, endpoint.variables.get(name).cloned())
+        })
+        .collect();
+
+    for (name, description) in &endpoint.query {
+        parameters.push(parameter(name.clone(), "query", false, description.clone()));
+    }
+    for header in detail.header.iter().filter(|header| !header.disabled) {
+        if let Some(key) = &header.key {
+            let description = header.description.as_ref().map(|d| d.text().to_string());
+            parameters.push(parameter(key.clone(), "header", false, description));
+        }
+    }
+    if let Some(body) = &detail.body {
+        parameters.push(Parameter {
+            name: "body".to_string(),
+            location: "body".to_string(),
+            description: None,
+            required: false,
+            parameter_type: None,
+            format: None,
+            schema: Some(body_schema(body)),
+            items: None,
+            collection_format: None,
+            enum_values: None,
+            reference: None,
+        });
+    }
+
+    let mut responses: IndexMap<String, Response> = item
+        .response
+        .iter()
+        .map(|example| {
+            let status = example.code.unwrap_or(200).to_string();
+            let description = example
+                .name
+                .clone()
+                .or_else(|| example.status.clone())
+                .unwrap_or_default();
+            let response = Response {
+                description,
+                response_schema: None,
+            };
+            (status, response)
+        })
+        .collect();
+    if responses.is_empty() {
+        let response = Response {
+            description: "OK".to_string(),
+            response_schema: None,
+        };
+        responses.insert("200".to_string(), response);
+    }
+
+    Operation {
+        operation_id: None,
+        summary: Some(item.name.clone()),
+        description: detail.description.as_ref().map(|d| d.text().to_string()),
+        deprecated: false,
+        tags: folder.map(str::to_string).into_iter().collect(),
+        parameters,
+        consumes: None,
+        produces: None,
+        security: None,
+        responses,
+        pagination: None,
+        name: None,
+    }
+}
+
+fn expand_short_requests(items: &mut [Item]) {
+    for item in items {
+        if let Some(Request::Url(url)) = &item.request {
+            let detail = RequestDetail {
+                url: Some(Url::Raw(url.clone())),
+                ..Default::default()
+            };
+            item.request = Some(Request::Detailed(Box::new(detail)));
+        }
+        expand_short_requests(&mut item.item);
+    }
+}
+
+type FolderRequest<'a> = (&'a Item, Option<&'a str>, &'a RequestDetail);
+
+fn collect_requests<'a>(items: &'a [Item], folder: Option<&'a str>, requests: &mut Vec<FolderRequest<'a>>) {
+    for item in items {
+        if let Some(Request::Detailed(detail)) = &item.request {
+            requests.push((item, folder, detail));
+        }
+        if !item.item.is_empty() {
+            collect_requests(&item.item, Some(&item.name), requests);
+        }
+    }
+}
+
+impl From<PostmanCollection> for Swagger {
+    fn from(mut collection: PostmanCollection) -> Swagger {
+        expand_short_requests(&mut collection.item);
+
+        let variables: IndexMap<String, String> = collection
+            .variable
+            .iter()
+            .filter_map(|variable| {
+                let value = match variable.value.as_ref()? {
+                    Value::String(value) => value.clone(),
+                    other => other.to_string(),
+                };
+                Some((variable.key.clone()?, value))
+            })
+            .collect();
+
+        let mut requests = Vec::new();
+        collect_requests(&collection.item, None, &mut requests);
+
+        let mut server = None;
+        let mut paths: IndexMap<String, PathItem> = IndexMap::new();
+        for (item, folder, detail) in requests {
+            let Some(url) = &detail.url else {
+                continue;
+            };
+            let endpoint = endpoint(url);
+            server.get_or_insert_with(|| substitute_variables(&endpoint.host, &variables));
+
+            let path = normalize_path(&substitute_variables(&endpoint.path, &variables));
+            let path = if path.is_empty() { "/".to_string() } else { path };
+            let operation = operation(item, folder, detail, &endpoint, &path);
+
+            let path_item = paths.entry(path).or_default();
+            let slot = match detail.method.as_deref().unwrap_or("GET").to_lowercase().as_str() {
+                "get" => &mut path_item.get,
+                "post" => &mut path_item.post,
+                "put" => &mut path_item.put,
+                "patch" => &mut path_item.patch,
+                "delete" => &mut path_item.delete,
+                "head" => &mut path_item.head,
+                "options" => &mut path_item.options,
+                _ => continue,
+            };
+            slot.get_or_insert(operation);
+        }
+
+        let (schemes, host, base_path) = match server {
+            Some(server) if server.contains("://") => split_server_url(&server),
+            _ => (None, None, None),
+        };
+
+        let mut info = IndexMap::new();
+        info.insert("title".to_string(), json!(collection.info.name));
+        let description = collection.info.description.as_ref().map(Description::text).unwrap_or_default();
+        info.insert("description".to_string(), json!(description));
+        let version = collection.info.version.unwrap_or_else(|| json!("1.0.0"));
+        let version = match version {
+            Value::String(version) => version,
+            other => other.to_string(),
+        };
+        info.insert("version".to_string(), json!(version));
+
+        Swagger {
+            info,
+            definitions: IndexMap::new(),
+            paths,
+            schemes,
+            host,
+            base_path,
+            parameters: IndexMap::new(),
+            consumes: Vec::new(),
+            produces: Vec::new(),
+            servers: Vec::new(),
+            security_definitions: IndexMap::new(),
+            security: Vec::new(),
+        }
+    }
+}
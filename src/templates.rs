@@ -0,0 +1,144 @@
+use crate::error::{GeneratorError, Result};
+use handlebars::Handlebars;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// The templates built into the binary, by name. A `<name>.hbs` file in the `--templates`
+/// directory replaces the one of that name.
+const BUILTIN: &[(&str, &str)] = &[
+    ("header", include_str!("../templates/header.hbs")),
+    ("python/method", include_str!("../templates/python/method.hbs")),
+    ("typescript/axios-method", include_str!("../templates/typescript/axios-method.hbs")),
+    ("typescript/fetch-method", include_str!("../templates/typescript/fetch-method.hbs")),
+    ("typescript/service", include_str!("../templates/typescript/service.hbs")),
+];
+
+/// The Handlebars templates the emitters render parts of the generated code from.
+///
+/// Rendering happens deep inside the emitters, which cannot fail, so the first failure is kept
+/// instead, shared by all the copies of the templates, for `take_failure` to report.
+#[derive(Debug, Clone)]
+pub struct Templates {
+    registry: Handlebars<'static>,
+    failure: Arc<Mutex<Option<GeneratorError>>>,
+    /// The date the banners give as the one of generation, taken once for all the files.
+    generated_on: Option<String>,
+}
+
+impl Templates {
+    pub fn builtin() -> Templates {
+        let mut registry = Handlebars::new();
+        // The output is code, not HTML.
+        registry.register_escape_fn(handlebars::no_escape);
+        let generated_on = Some(chrono::Local::now().format("%Y-%m-%d").to_string());
+        let templates = Templates { registry, failure: Arc::default(), generated_on };
+        BUILTIN.iter().fold(templates, |mut templates, (name, template)| {
+            if let Err(error) = templates.registry.register_template_string(name, template) {
+                templates.fail(GeneratorError::Template { name: name.to_string(), message: error.to_string() });
+            }
+            templates
+        })
+    }
+
+    /// The built-in templates, with the ones `dir` holds under the same names in their place.
+    /// The other `.hbs` files of `dir` replace nothing, which is warned about.
+    pub fn load(dir: &Path) -> Result<Templates> {
+        let mut templates = Templates::builtin();
+        for (name, _) in BUILTIN {
+            let path = dir.join(format!("{}.hbs", name));
+            if path.is_file() {
+                templates.register_file(name, &path)?;
+            }
+        }
+        for name in template_names(dir, "") {
+            if !BUILTIN.iter().any(|(builtin, _)| *builtin == name) {
+                log::warn!("{}/{}.hbs replaces no built-in template and is not used", dir.display(), name);
+            }
+        }
+        Ok(templates)
+    }
+
+    /// The templates with the banner of the generated files rendered from the file at `path`
+    /// instead, whatever the templates directory holds.
+    pub fn with_banner(mut self, path: &Path) -> Result<Templates> {
+        self.register_file("header", path)?;
+        Ok(self)
+    }
+
+    fn register_file(&mut self, name: &str, path: &Path) -> Result<()> {
+        let template = fs::read_to_string(path)
+            .map_err(|source| GeneratorError::Read { input: path.display().to_string(), source })?;
+        self.registry
+            .register_template_string(name, template)
+            .map_err(|e| GeneratorError::Template { name: path.display().to_string(), message: e.to_string() })
+    }
+
+    /// The templates with no date of generation for the banners, so that generating the same
+    /// document gives the same files whenever it happens.
+    pub fn without_timestamp(mut self) -> Templates {
+        self.generated_on = None;
+        self
+    }
+
+    /// The first template that failed to register or render since the last call, if any.
+    pub fn take_failure(&self) -> Option<GeneratorError> {
+        self.failure.lock().ok().and_then(|mut failure| failure.take())
+    }
+
+    fn fail(&self, error: GeneratorError) {
+        if let Ok(mut failure) = self.failure.lock() {
+            failure.get_or_insert(error);
+        }
+    }
+}
+
+/// The names of the `.hbs` files under `dir`, relative to it and without the extension, e.g.
+/// `typescript/axios-method`.
+fn template_names(dir: &Path, prefix: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if path.is_dir() {
+            names.extend(template_names(&path, &format!("{}{}/", prefix, file_name)));
+        } else if let Some(name) = file_name.strip_suffix(".hbs") {
+            names.push(format!("{}{}", prefix, name));
+        }
+    }
+    names.sort();
+    names
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Templates> = RefCell::new(Templates::builtin());
+}
+
+/// Makes `templates` the ones `render` uses on this thread, such as each of the threads that
+/// generate a document.
+pub fn install(templates: Templates) {
+    ACTIVE.with(|active| active.replace(templates));
+}
+
+/// The date of generation the templates installed on this thread give, if they give one.
+pub fn generated_on() -> Option<String> {
+    ACTIVE.with(|active| active.borrow().generated_on.clone())
+}
+
+/// Renders the template `name` with `context`, or nothing when it fails, which the templates
+/// installed on this thread keep.
+pub fn render(name: &str, context: &impl Serialize) -> String {
+    ACTIVE.with(|active| {
+        let templates = active.borrow();
+        templates.registry.render(name, context).unwrap_or_else(|error| {
+            templates.fail(GeneratorError::Template { name: name.to_string(), message: error.to_string() });
+            String::new()
+        })
+    })
+}
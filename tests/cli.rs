@@ -0,0 +1,76 @@
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
+use std::{fs, thread};
+
+/// A document without schemes or definitions, both of which the generator warns about.
+const SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": { "/pets": { "get": { "operationId": "listPets", "responses": { "200": { "description": "ok" } } } } }
+}"##;
+
+/// An empty directory of the test `name` holding `SPEC` as `swagger.json`.
+fn workspace(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("swagger-generator-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("swagger.json"), SPEC).unwrap();
+    dir
+}
+
+fn generator(dir: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_swagger-generator"));
+    command.current_dir(dir).args(["-i", "swagger.json", "-o", "out", "-l", "typescript"]);
+    command
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn verbosity_flags_choose_the_records_printed() {
+    let dir = workspace("logging");
+    let default = generator(&dir).output().unwrap();
+    assert!(default.status.success());
+    assert_eq!(
+        stderr(&default),
+        "warning: swagger.json has no schemes, the clients use https\n\
+         warning: swagger.json has no definitions, only the operations are generated\n"
+    );
+
+    let verbose = generator(&dir).arg("-v").output().unwrap();
+    let verbose = stderr(&verbose);
+    assert!(verbose.contains("info: parsed swagger.json as a Swagger 2.0 document: 0 definitions, 1 operations\n"));
+    assert!(verbose.contains("info: generated "), "{}", verbose);
+
+    let quiet = generator(&dir).arg("-q").output().unwrap();
+    assert!(quiet.status.success());
+    assert_eq!(stderr(&quiet), "");
+
+    let missing = generator(&dir).args(["-q", "-i", "missing.json"]).output().unwrap();
+    assert_eq!(missing.status.code(), Some(66));
+    assert!(stderr(&missing).starts_with("error: cannot read missing.json: "), "{}", stderr(&missing));
+}
+
+#[test]
+fn watch_mode_regenerates_when_the_spec_changes() {
+    let dir = workspace("watch");
+    let mut child = generator(&dir).args(["--watch", "-q"]).stderr(Stdio::piped()).spawn().unwrap();
+    let mut lines = BufReader::new(child.stderr.take().unwrap()).lines();
+    assert_eq!(lines.next().unwrap().unwrap(), "Watching for changes...");
+    let service = dir.join("out").join("service.ts");
+    assert!(fs::read_to_string(&service).unwrap().contains("export async function listPets("));
+
+    fs::write(dir.join("swagger.json"), SPEC.replace("listPets", "findPets")).unwrap();
+    let deadline = Instant::now() + Duration::from_secs(20);
+    while !fs::read_to_string(&service).unwrap_or_default().contains("export async function findPets(") {
+        assert!(Instant::now() < deadline, "service.ts was not regenerated");
+        thread::sleep(Duration::from_millis(100));
+    }
+    child.kill().unwrap();
+    child.wait().unwrap();
+}
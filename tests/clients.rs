@@ -0,0 +1,1299 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, Generator};
+
+/// An operation taking an optional query parameter and a required header.
+const PARAMS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "limit", "in": "query", "type": "integer", "format": "int32" },
+                    { "name": "X-Trace", "in": "header", "type": "string", "required": true }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// A string enum, a model extending another through `allOf`, and operations returning an array,
+/// taking a body and uploading a file.
+const MODELS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "responses": { "200": { "description": "ok", "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } } } }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [{ "name": "pet", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Animal" } }],
+                "responses": { "201": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        },
+        "/pets/{id}/photo": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "consumes": ["multipart/form-data"],
+                "parameters": [
+                    { "name": "id", "in": "path", "required": true, "type": "string" },
+                    { "name": "caption", "in": "formData", "type": "string" },
+                    { "name": "file", "in": "formData", "required": true, "type": "file" }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "definitions": {
+        "Status": { "type": "string", "enum": ["available", "in-stock", "in_stock"] },
+        "Animal": {
+            "type": "object",
+            "required": ["name"],
+            "properties": { "name": { "type": "string" } }
+        },
+        "Pet": {
+            "allOf": [
+                { "$ref": "#/definitions/Animal" },
+                { "type": "object", "required": ["status"], "properties": { "status": { "$ref": "#/definitions/Status" } } }
+            ]
+        }
+    }
+}"##;
+
+/// An operation taking a string and an integer path parameter.
+const PATH_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/owners/{owner}/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "owner", "in": "path", "required": true, "type": "string" },
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// An OpenAPI 3 upload whose file is a binary string field of a `multipart/form-data` body.
+const MULTIPART_SPEC: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Pets", "version": "1.0" },
+    "servers": [{ "url": "https://api.example.com" }],
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["photo"],
+                                "properties": {
+                                    "caption": { "type": "string" },
+                                    "photo": { "type": "string", "format": "binary" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// A multipart upload and a URL-encoded login, both sending form fields rather than JSON.
+const FORMS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "consumes": ["multipart/form-data"],
+                "parameters": [
+                    { "name": "caption", "in": "formData", "type": "string" },
+                    { "name": "file", "in": "formData", "type": "file", "required": true }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/login": {
+            "post": {
+                "operationId": "login",
+                "consumes": ["application/x-www-form-urlencoded"],
+                "parameters": [{ "name": "user", "in": "formData", "type": "string", "required": true }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// Form uploads next to a plain text body, each of which goes with its own `Content-Type`.
+const MEDIA_TYPES_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "consumes": ["multipart/form-data"],
+                "parameters": [{ "name": "file", "in": "formData", "type": "file", "required": true }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/login": {
+            "post": {
+                "operationId": "login",
+                "consumes": ["application/x-www-form-urlencoded"],
+                "parameters": [{ "name": "user", "in": "formData", "type": "string", "required": true }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/notes": {
+            "post": {
+                "operationId": "addNote",
+                "consumes": ["text/plain"],
+                "parameters": [{ "name": "body", "in": "body", "schema": { "type": "string" } }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// Array query parameters sent as `csv` by default, repeated with `multi`, and joined by pipes and
+/// spaces, and an array header.
+const COLLECTION_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "findPets",
+                "parameters": [
+                    { "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } },
+                    {
+                        "name": "ids",
+                        "in": "query",
+                        "type": "array",
+                        "required": true,
+                        "collectionFormat": "multi",
+                        "items": { "type": "integer", "format": "int64" }
+                    },
+                    {
+                        "name": "names",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "pipes",
+                        "items": { "type": "string" }
+                    },
+                    {
+                        "name": "sizes",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "ssv",
+                        "items": { "type": "integer", "format": "int32" }
+                    },
+                    { "name": "X-Ids", "in": "header", "type": "array", "items": { "type": "string" } }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// A model extending another through `allOf` whose inline part refers back to it by value.
+const RECURSIVE_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {},
+    "definitions": {
+        "Animal": { "type": "object", "required": ["name"], "properties": { "name": { "type": "string" } } },
+        "Pet": {
+            "allOf": [
+                { "$ref": "#/definitions/Animal" },
+                { "type": "object", "properties": { "parent": { "$ref": "#/definitions/Pet" } } }
+            ]
+        }
+    }
+}"##;
+
+/// Operations returning a list of models, taking a model and a list of them as bodies and an integer
+/// path parameter, and a model with a required field that may be null.
+const TYPED_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "responses": {
+                    "200": { "description": "ok", "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } } }
+                }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [{ "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }],
+                "responses": { "201": { "description": "created", "schema": { "$ref": "#/definitions/Pet" } } }
+            },
+            "put": {
+                "operationId": "replacePets",
+                "parameters": [
+                    { "name": "body", "in": "body", "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } } }
+                ],
+                "responses": { "204": { "description": "replaced" } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [{ "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }],
+                "responses": { "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name", "tag"],
+            "properties": { "name": { "type": "string" }, "tag": { "type": "string", "x-nullable": true } }
+        }
+    }
+}"##;
+
+fn generate_spec(spec: &str, language: Language, file: &str) -> String {
+    let files = Generator::new(parse_spec(spec, None).unwrap()).language(language).timestamp(false).generate().unwrap();
+    files[Path::new(file)].clone()
+}
+
+fn generate(language: Language, file: &str) -> String {
+    generate_spec(PARAMS_SPEC, language, file)
+}
+
+#[test]
+fn rust_client_sends_query_and_header_parameters() {
+    let lib = generate(Language::Rust, "src/lib.rs");
+    assert!(
+        lib.contains("pub struct ListPetsParams {\n    pub limit: Option<i32>,\n    pub x_trace: String,\n}\n"),
+        "{}",
+        lib
+    );
+    assert!(lib.contains("pub async fn list_pets(&self, params: &ListPetsParams)"), "{}", lib);
+    assert!(lib.contains("            request = request.query(&[(\"limit\", value)]);\n"), "{}", lib);
+    assert!(lib.contains("        request = request.header(\"X-Trace\", &params.x_trace);\n"), "{}", lib);
+}
+
+#[test]
+fn rust_client_types_models_bodies_and_forms() {
+    let lib = generate_spec(MODELS_SPEC, Language::Rust, "src/lib.rs");
+    assert!(
+        lib.contains(
+            "pub enum Status {\n    #[serde(rename = \"available\")]\n    Available,\n    #[serde(rename = \"in-stock\")]\n    \
+             InStock,\n    #[serde(rename = \"in_stock\")]\n    InStock2,\n}\n"
+        ),
+        "{}",
+        lib
+    );
+    assert!(lib.contains("pub struct Pet {\n    pub name: String,\n    pub status: Status,\n}\n"), "{}", lib);
+    assert!(lib.contains("pub async fn list_pets(&self) -> Result<Vec<Pet>, ApiError>"), "{}", lib);
+    assert!(lib.contains("pub async fn create_pet(&self, body: &Animal) -> Result<Pet, ApiError>"), "{}", lib);
+    assert!(lib.contains("    pub file: Vec<u8>,\n"), "{}", lib);
+    assert!(lib.contains("        let mut form = reqwest::multipart::Form::new();\n"), "{}", lib);
+    assert!(
+        lib.contains("form.part(\"file\", reqwest::multipart::Part::bytes(params.file.clone()).file_name(\"file\"));"),
+        "{}",
+        lib
+    );
+    assert!(lib.contains("        let request = request.multipart(form);\n"), "{}", lib);
+}
+
+#[test]
+fn rust_client_types_and_encodes_path_parameters() {
+    let lib = generate_spec(PATH_SPEC, Language::Rust, "src/lib.rs");
+    assert!(lib.contains("use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};\n"), "{}", lib);
+    assert!(lib.contains("pub async fn get_pet(&self, owner: &str, pet_id: i64)"), "{}", lib);
+    assert!(
+        lib.contains(
+            "self.client.get(format!(\"{}/owners/{}/pets/{}\", self.base_url, \
+             utf8_percent_encode(owner, NON_ALPHANUMERIC), pet_id));\n"
+        ),
+        "{}",
+        lib
+    );
+    let manifest = generate_spec(PATH_SPEC, Language::Rust, "Cargo.toml");
+    assert!(manifest.contains("percent-encoding = \"2\"\n"), "{}", manifest);
+
+    let manifest = generate(Language::Rust, "Cargo.toml");
+    assert!(!manifest.contains("percent-encoding"), "{}", manifest);
+}
+
+#[test]
+fn rust_models_make_nullable_required_fields_options() {
+    for language in [Language::Rust, Language::Axum, Language::ActixWeb] {
+        let lib = generate_spec(TYPED_SPEC, language, "src/lib.rs");
+        assert!(lib.contains("pub struct Pet {\n    pub name: String,\n    pub tag: Option<String>,\n}\n"), "{}", lib);
+    }
+}
+
+#[test]
+fn rust_client_uploads_binary_form_fields_of_openapi_3_as_files() {
+    let lib = generate_spec(MULTIPART_SPEC, Language::Rust, "src/lib.rs");
+    assert!(lib.contains("    pub photo: Vec<u8>,\n"), "{}", lib);
+    assert!(
+        lib.contains("form.part(\"photo\", reqwest::multipart::Part::bytes(params.photo.clone()).file_name(\"photo\"));"),
+        "{}",
+        lib
+    );
+}
+
+#[test]
+fn rust_models_box_fields_recursing_through_all_of() {
+    let swagger = parse_spec(RECURSIVE_SPEC, None).unwrap();
+    let mut code = String::new();
+    for (name, definition) in &swagger.definitions {
+        code.push_str(&swagger_generator::generators::rust::generate_struct(&swagger, name, definition));
+    }
+    assert!(code.contains("    pub parent: Option<Box<Pet>>,\n"), "{}", code);
+
+    // Serde is not at hand for rustc, and does not change whether the structs have a size.
+    let code: String = code
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("#["))
+        .map(|line| line.to_string() + "\n")
+        .collect();
+    let dir = std::env::temp_dir().join(format!("swagger-generator-recursive-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("models.rs");
+    std::fs::write(&source, &code).unwrap();
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let output = std::process::Command::new(rustc)
+        .args(["--crate-type", "lib", "--emit", "metadata", "--edition", "2021", "--out-dir"])
+        .arg(&dir)
+        .arg(&source)
+        .output()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(output.status.success(), "{}\n{}", String::from_utf8_lossy(&output.stderr), code);
+}
+
+#[test]
+fn java_service_sends_query_and_header_parameters() {
+    let service = generate(Language::Java, "src/main/java/pets/defaultapi/DefaultService.java");
+    assert!(service.contains("public JsonNode listPets(Integer limit, String xTrace) {"), "{}", service);
+    assert!(
+        service.contains(
+            "client.send(\"GET\", \"/pets\" + ApiClient.query(\"limit\", limit), ApiClient.headers(\"X-Trace\", xTrace), null,"
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn java_service_returns_array_responses_as_lists() {
+    let service = generate_spec(MODELS_SPEC, Language::Java, "src/main/java/pets/defaultapi/DefaultService.java");
+    assert!(service.contains("import com.fasterxml.jackson.core.type.TypeReference;\n"), "{}", service);
+    assert!(service.contains("import pets.model.Pet;\n"), "{}", service);
+    assert!(
+        service.contains(
+            "    public List<Pet> listPets() {\n        \
+             return client.send(\"GET\", \"/pets\", null, new TypeReference<List<Pet>>() {}, List.of());\n"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("return client.send(\"POST\", \"/pets\", body, Pet.class, List.of());"), "{}", service);
+
+    let client = generate_spec(MODELS_SPEC, Language::Java, "src/main/java/pets/ApiClient.java");
+    assert!(client.contains("            request.uri(URI.create(baseUrl + path));\n"), "{}", client);
+    assert!(!client.contains("String query"), "{}", client);
+}
+
+#[test]
+fn java_service_types_bodies_and_path_parameters() {
+    let service = generate_spec(TYPED_SPEC, Language::Java, "src/main/java/pets/defaultapi/DefaultService.java");
+    assert!(service.contains("    public Pet createPet(Pet body) {\n"), "{}", service);
+    assert!(service.contains("    public JsonNode replacePets(List<Pet> body) {\n"), "{}", service);
+    assert!(
+        service.contains(
+            "    public Pet getPet(Long petId) {\n        \
+             return client.send(\"GET\", \"/pets/\" + petId, null, Pet.class, List.of());\n"
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn java_service_sends_form_fields_as_forms() {
+    let service = generate_spec(FORMS_SPEC, Language::Java, "src/main/java/pets/defaultapi/DefaultService.java");
+    assert!(service.contains("public JsonNode uploadPhoto(String caption, byte[] file) {"), "{}", service);
+    assert!(
+        service.contains("client.send(\"POST\", \"/photos\", ApiClient.multipartForm(\"caption\", caption, \"file\", file),"),
+        "{}",
+        service
+    );
+    assert!(service.contains("public JsonNode login(String user) {"), "{}", service);
+    assert!(service.contains("client.send(\"POST\", \"/login\", ApiClient.urlEncodedForm(\"user\", user),"), "{}", service);
+
+    let client = generate_spec(FORMS_SPEC, Language::Java, "src/main/java/pets/ApiClient.java");
+    assert!(
+        client.contains("return new EncodedBody(\"multipart/form-data; boundary=\" + boundary, body.toByteArray());"),
+        "{}",
+        client
+    );
+    assert!(
+        client.contains("request.header(\"Content-Type\", body instanceof EncodedBody encoded ? encoded.contentType() : \"application/json\");"),
+        "{}",
+        client
+    );
+}
+
+#[test]
+fn java_models_flatten_all_of_and_map_enums() {
+    let status = generate_spec(MODELS_SPEC, Language::Java, "src/main/java/pets/model/Status.java");
+    assert!(
+        status.contains(
+            "public enum Status {\n    @JsonProperty(\"available\") AVAILABLE,\n    @JsonProperty(\"in-stock\") IN_STOCK,\n    \
+             @JsonProperty(\"in_stock\") IN_STOCK2\n}\n"
+        ),
+        "{}",
+        status
+    );
+    let pet = generate_spec(MODELS_SPEC, Language::Java, "src/main/java/pets/model/Pet.java");
+    assert!(
+        pet.contains("public record Pet(\n    @JsonProperty(\"name\") String name,\n    @JsonProperty(\"status\") Status status\n)"),
+        "{}",
+        pet
+    );
+}
+
+#[test]
+fn csharp_service_sends_form_fields_as_forms() {
+    let service = generate_spec(FORMS_SPEC, Language::CSharp, "Pets/ApiService.cs");
+    assert!(
+        service.contains(
+            "public Task<JsonElement> UploadPhotoAsync(Stream file, string? caption = null, \
+             CancellationToken cancellationToken = default) =>"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("\"/photos\", MultipartForm((\"caption\", caption), (\"file\", file)), cancellationToken);"), "{}", service);
+    assert!(service.contains("\"/login\", UrlEncodedForm((\"user\", user)), cancellationToken);"), "{}", service);
+    assert!(service.contains("content.Add(new StreamContent(stream), name, name);"), "{}", service);
+    assert!(
+        service.contains("request.Content = body is Func<HttpContent> factory ? factory() : JsonContent.Create(body);"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn csharp_service_sends_query_and_header_parameters() {
+    let service = generate(Language::CSharp, "Pets/ApiService.cs");
+    assert!(
+        service.contains(
+            "public Task<JsonElement> ListPetsAsync(string xTrace, int? limit = null, CancellationToken cancellationToken = default) =>"
+        ),
+        "{}",
+        service
+    );
+    assert!(
+        service.contains(
+            "\"/pets\" + Query((\"limit\", limit)), new (string, object?)[] { (\"X-Trace\", xTrace) }, null,"
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn csharp_service_types_bodies_list_responses_and_path_parameters() {
+    let service = generate_spec(TYPED_SPEC, Language::CSharp, "Pets/ApiService.cs");
+    assert!(
+        service.contains(
+            "    public Task<List<Pet>?> ListPetsAsync(CancellationToken cancellationToken = default) =>
+        SendAsync<List<Pet>>(HttpMethod.Get, \"/pets\", null, cancellationToken);"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("public Task<Pet?> CreatePetAsync(Pet body, CancellationToken"), "{}", service);
+    assert!(service.contains("public Task<JsonElement> ReplacePetsAsync(List<Pet>? body, CancellationToken"), "{}", service);
+    assert!(
+        service.contains(
+            "    public Task<Pet?> GetPetAsync(long petId, CancellationToken cancellationToken = default) =>
+        SendAsync<Pet>(HttpMethod.Get, $\"/pets/{FormatValue(petId)}\", null, cancellationToken);"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("private static string FormatValue(object value)"), "{}", service);
+
+    let model = generate_spec(TYPED_SPEC, Language::CSharp, "Pets/Models/Pet.cs");
+    assert!(model.contains("    [JsonRequired]\n    public string? Tag { get; set; }\n"), "{}", model);
+}
+
+#[test]
+fn csharp_models_flatten_all_of_and_convert_enums() {
+    let status = generate_spec(MODELS_SPEC, Language::CSharp, "Pets/Models/Status.cs");
+    assert!(
+        status.contains(
+            "[JsonConverter(typeof(StatusConverter))]\npublic enum Status\n{\n    Available,\n    InStock,\n    InStock2,\n}\n"
+        ),
+        "{}",
+        status
+    );
+    assert!(status.contains("            \"in-stock\" => Status.InStock,\n"), "{}", status);
+    assert!(status.contains("            Status.InStock2 => \"in_stock\",\n"), "{}", status);
+    let pet = generate_spec(MODELS_SPEC, Language::CSharp, "Pets/Models/Pet.cs");
+    assert!(pet.contains("    public string Name { get; set; } = default!;\n"), "{}", pet);
+    assert!(pet.contains("    public Status Status { get; set; } = default!;\n"), "{}", pet);
+}
+
+#[test]
+fn kotlin_api_takes_query_and_header_parameters() {
+    let api = generate(Language::Kotlin, "src/main/kotlin/pets/api/DefaultApi.kt");
+    assert!(
+        api.contains(
+            "suspend fun listPets(@Header(\"X-Trace\") xTrace: String, @Query(\"limit\") limit: Int? = null): JsonElement"
+        ),
+        "{}",
+        api
+    );
+}
+
+#[test]
+fn kotlin_api_sends_form_fields_as_parts_and_fields() {
+    let api = generate_spec(FORMS_SPEC, Language::Kotlin, "src/main/kotlin/pets/api/DefaultApi.kt");
+    assert!(
+        api.contains(
+            "    @POST(\"photos\")\n    @Multipart\n    suspend fun uploadPhoto(@Part file: MultipartBody.Part, \
+             @Part(\"caption\") caption: String? = null): JsonElement\n"
+        ),
+        "{}",
+        api
+    );
+    assert!(
+        api.contains("    @POST(\"login\")\n    @FormUrlEncoded\n    suspend fun login(@Field(\"user\") user: String): JsonElement\n"),
+        "{}",
+        api
+    );
+    let client = generate_spec(FORMS_SPEC, Language::Kotlin, "src/main/kotlin/pets/ApiClient.kt");
+    assert!(client.contains("        .addConverterFactory(ScalarsConverterFactory.create())\n"), "{}", client);
+}
+
+#[test]
+fn kotlin_models_flatten_all_of_and_name_enum_values() {
+    let status = generate_spec(MODELS_SPEC, Language::Kotlin, "src/main/kotlin/pets/model/Status.kt");
+    assert!(
+        status.contains(
+            "@Serializable\nenum class Status {\n    @SerialName(\"available\") AVAILABLE,\n    \
+             @SerialName(\"in-stock\") IN_STOCK,\n    @SerialName(\"in_stock\") IN_STOCK2,\n}\n"
+        ),
+        "{}",
+        status
+    );
+    let pet = generate_spec(MODELS_SPEC, Language::Kotlin, "src/main/kotlin/pets/model/Pet.kt");
+    assert!(
+        pet.contains("data class Pet(\n    @SerialName(\"name\") val name: String,\n    @SerialName(\"status\") val status: Status,\n)"),
+        "{}",
+        pet
+    );
+}
+
+#[test]
+fn kotlin_api_types_bodies_list_responses_and_path_parameters() {
+    let api = generate_spec(TYPED_SPEC, Language::Kotlin, "src/main/kotlin/pets/api/DefaultApi.kt");
+    assert!(api.contains("import pets.model.Pet\n"), "{}", api);
+    assert!(api.contains("    suspend fun listPets(): List<Pet>\n"), "{}", api);
+    assert!(api.contains("    suspend fun createPet(@Body body: Pet): Pet\n"), "{}", api);
+    assert!(api.contains("    suspend fun replacePets(@Body body: List<Pet>): JsonElement\n"), "{}", api);
+    assert!(api.contains("    suspend fun getPet(@Path(\"petId\") petId: Long): Pet\n"), "{}", api);
+
+    let model = generate_spec(TYPED_SPEC, Language::Kotlin, "src/main/kotlin/pets/model/Pet.kt");
+    assert!(
+        model.contains("    @SerialName(\"name\") val name: String,\n    @SerialName(\"tag\") val tag: String?,\n"),
+        "{}",
+        model
+    );
+}
+
+#[test]
+fn swift_client_types_list_responses_and_path_parameters() {
+    let client = generate_spec(TYPED_SPEC, Language::Swift, "Sources/Pets/APIClient.swift");
+    assert!(client.contains("    public func listPets() async throws -> [Pet] {\n"), "{}", client);
+    assert!(
+        client.contains(
+            "    public func getPet(petId: Int) async throws -> Pet {\n        try await send(\"GET\", \"/pets/\\(petId)\")\n"
+        ),
+        "{}",
+        client
+    );
+    // Only string path parameters are percent-encoded.
+    assert!(!client.contains("private func encode(_ value: String)"), "{}", client);
+
+    let model = generate_spec(TYPED_SPEC, Language::Swift, "Sources/Pets/Models/Pet.swift");
+    assert!(model.contains("    public var tag: String?\n"), "{}", model);
+    assert!(model.contains("    public init(name: String, tag: String?) {\n"), "{}", model);
+}
+
+#[test]
+fn swift_models_flatten_all_of_and_map_enums() {
+    let status = generate_spec(MODELS_SPEC, Language::Swift, "Sources/Pets/Models/Status.swift");
+    assert!(
+        status.contains(
+            "public enum Status: String, Codable {\n    case available\n    case inStock = \"in-stock\"\n    \
+             case inStock2 = \"in_stock\"\n}\n"
+        ),
+        "{}",
+        status
+    );
+    let pet = generate_spec(MODELS_SPEC, Language::Swift, "Sources/Pets/Models/Pet.swift");
+    assert!(pet.contains("    public var name: String\n    public var status: Status\n"), "{}", pet);
+    assert!(pet.contains("    public init(name: String, status: Status) {\n"), "{}", pet);
+}
+
+#[test]
+fn swift_models_recursing_through_all_of_are_classes() {
+    let pet = generate_spec(RECURSIVE_SPEC, Language::Swift, "Sources/Pets/Models/Pet.swift");
+    assert!(pet.contains("public final class Pet: Codable {\n"), "{}", pet);
+    assert!(pet.contains("    public var parent: Pet?\n"), "{}", pet);
+}
+
+#[test]
+fn swift_client_sends_form_fields_as_forms() {
+    let client = generate_spec(FORMS_SPEC, Language::Swift, "Sources/Pets/APIClient.swift");
+    assert!(
+        client.contains(
+            "    public func uploadPhoto(file: Data, caption: String? = nil) async throws -> JSONValue {\n        \
+             let (form, contentType) = multipartForm([(\"caption\", caption), (\"file\", file)])\n        \
+             return try await send(\"POST\", \"/photos\", body: form, contentType: contentType)\n"
+        ),
+        "{}",
+        client
+    );
+    assert!(
+        client.contains("        let (form, contentType) = urlEncodedForm([(\"user\", user)])\n"),
+        "{}",
+        client
+    );
+    assert!(client.contains("            request.setValue(contentType, forHTTPHeaderField: \"Content-Type\")\n"));
+}
+
+#[test]
+fn swift_client_sends_query_and_header_parameters() {
+    let client = generate(Language::Swift, "Sources/Pets/APIClient.swift");
+    assert!(
+        client.contains("public func listPets(xTrace: String, limit: Int? = nil) async throws -> JSONValue {"),
+        "{}",
+        client
+    );
+    assert!(
+        client.contains(
+            "try await send(\"GET\", \"/pets\", query: [(\"limit\", limit)], headers: [(\"X-Trace\", xTrace)])"
+        ),
+        "{}",
+        client
+    );
+    assert!(
+        client.contains("private func queryItems(_ parameters: [(String, Any?)]) -> [URLQueryItem] {"),
+        "{}",
+        client
+    );
+}
+
+#[test]
+fn dart_client_types_bodies_list_responses_and_path_parameters() {
+    let client = generate_spec(TYPED_SPEC, Language::Dart, "lib/src/api_client.dart");
+    assert!(client.contains("import 'models/pet.dart';\n"), "{}", client);
+    assert!(
+        client.contains(
+            "  Future<List<Pet>> listPets() async {
+    final data = await _request('GET', '/pets');
+    return (data as List<dynamic>).map((e) => Pet.fromJson(e as Map<String, dynamic>)).toList();
+  }"
+        ),
+        "{}",
+        client
+    );
+    assert!(client.contains("Future<Pet> createPet(Pet body) async {\n"), "{}", client);
+    assert!(client.contains("_request('POST', '/pets', body: body.toJson());\n"), "{}", client);
+    assert!(
+        client.contains("replacePets(List<Pet>? body) => _request('PUT', '/pets', body: body == null ? null : "),
+        "{}",
+        client
+    );
+    assert!(client.contains("Future<Pet> getPet(int petId) async {\n"), "{}", client);
+    assert!(client.contains("_request('GET', '/pets/${petId}');\n"), "{}", client);
+
+    let model = generate_spec(TYPED_SPEC, Language::Dart, "lib/src/models/pet.dart");
+    assert!(model.contains("        tag: json['tag'] as String?,\n"), "{}", model);
+    assert!(model.contains("  final String? tag;\n"), "{}", model);
+    assert!(model.contains("        'tag': tag,\n"), "{}", model);
+}
+
+#[test]
+fn dart_models_flatten_all_of_and_decode_enums() {
+    let status = generate_spec(MODELS_SPEC, Language::Dart, "lib/src/models/status.dart");
+    assert!(
+        status.contains("enum Status {\n  available('available'),\n  inStock('in-stock'),\n  inStock2('in_stock');\n"),
+        "{}",
+        status
+    );
+    assert!(status.contains("  factory Status.fromJson(dynamic json) => values.firstWhere(\n"), "{}", status);
+    let pet = generate_spec(MODELS_SPEC, Language::Dart, "lib/src/models/pet.dart");
+    assert!(pet.contains("  Pet({required this.name, required this.status});\n"), "{}", pet);
+    assert!(pet.contains("        status: Status.fromJson(json['status']),\n"), "{}", pet);
+    assert!(pet.contains("        'status': status.toJson(),\n"), "{}", pet);
+}
+
+#[test]
+fn dart_client_sends_form_fields_as_forms() {
+    let client = generate_spec(FORMS_SPEC, Language::Dart, "lib/src/api_client.dart");
+    assert!(
+        client.contains(
+            "  Future<dynamic> uploadPhoto({required MultipartFile file, String? caption}) => _request('POST', \
+             '/photos', body: FormData.fromMap({if (caption != null) 'caption': caption, 'file': file}, \
+             ListFormat.multi));\n"
+        ),
+        "{}",
+        client
+    );
+    assert!(
+        client.contains(
+            "  Future<dynamic> login({required String user}) => _request('POST', '/login', \
+             body: <String, dynamic>{'user': user}, contentType: Headers.formUrlEncodedContentType);\n"
+        ),
+        "{}",
+        client
+    );
+    assert!(client.contains("      options: Options(method: method, headers: headers, contentType: contentType),\n"));
+}
+
+#[test]
+fn dart_client_sends_query_and_header_parameters() {
+    let client = generate(Language::Dart, "lib/src/api_client.dart");
+    assert!(
+        client.contains(
+            "Future<dynamic> listPets({required String xTrace, int? limit}) => _request('GET', '/pets', queryParameters: {'limit': limit}, headerParameters: {'X-Trace': xTrace});"
+        ),
+        "{}",
+        client
+    );
+    assert!(client.contains("    Map<String, dynamic> queryParameters = const {},\n"), "{}", client);
+}
+
+#[test]
+fn php_models_flatten_all_of_and_back_enums() {
+    let status = generate_spec(MODELS_SPEC, Language::Php, "src/Model/Status.php");
+    assert!(
+        status.contains(
+            "enum Status: string\n{\n    case Available = 'available';\n    case InStock = 'in-stock';\n    \
+             case InStock2 = 'in_stock';\n}\n"
+        ),
+        "{}",
+        status
+    );
+    let pet = generate_spec(MODELS_SPEC, Language::Php, "src/Model/Pet.php");
+    assert!(pet.contains("        public string $name,\n        public Status $status,\n"), "{}", pet);
+    assert!(pet.contains("            status: Status::from($data['status']),\n"), "{}", pet);
+    assert!(pet.contains("            'status' => $this->status->value,\n"), "{}", pet);
+}
+
+#[test]
+fn php_service_sends_form_fields_as_request_options() {
+    let service = generate_spec(FORMS_SPEC, Language::Php, "src/ApiService.php");
+    assert!(
+        service.contains(
+            "    public function uploadPhoto(mixed $file, ?string $caption = null): mixed\n    {\n        \
+             return $this->request('POST', '/photos', form: ['caption' => $caption, 'file' => $file], multipart: true);\n"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("        return $this->request('POST', '/login', form: ['user' => $user]);\n"), "{}", service);
+    assert!(service.contains("                        : ['name' => $name, 'contents' => $item, 'filename' => $name];\n"));
+    assert!(service.contains("            $options['form_params'] = $form;\n"));
+}
+
+#[test]
+fn php_service_sends_query_and_header_parameters() {
+    let service = generate(Language::Php, "src/ApiService.php");
+    assert!(service.contains("public function listPets(string $xTrace, ?int $limit = null): mixed"), "{}", service);
+    assert!(
+        service
+            .contains("$this->request('GET', '/pets', query: ['limit' => $limit], headers: ['X-Trace' => $xTrace]);"),
+        "{}",
+        service
+    );
+    assert!(service.contains("$options['query'] = Query::build(($options['query'] ?? []) + $query);"), "{}", service);
+}
+
+#[test]
+fn php_service_types_bodies_list_responses_and_path_parameters() {
+    let service = generate_spec(TYPED_SPEC, Language::Php, "src/ApiService.php");
+    assert!(
+        service.contains(
+            "    /**
+     * @return Pet[]
+     */
+    public function listPets(): array
+    {
+        return array_map(fn ($item) => Pet::fromArray($item), $this->request('GET', '/pets'));
+    }"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("public function createPet(?Pet $body = null): Pet\n"), "{}", service);
+    assert!(
+        service.contains("$this->request('POST', '/pets', $body === null ? null : $body->toArray())"),
+        "{}",
+        service
+    );
+    assert!(service.contains("     * @param Pet[]|null $body\n"), "{}", service);
+    assert!(service.contains("array_map(fn ($item) => $item->toArray(), $body)"), "{}", service);
+    assert!(service.contains("public function getPet(int $petId): Pet\n"), "{}", service);
+    assert!(service.contains("$this->request('GET', '/pets/' . $petId)"), "{}", service);
+
+    let model = generate_spec(TYPED_SPEC, Language::Php, "src/Model/Pet.php");
+    assert!(model.contains("        public ?string $tag,\n"), "{}", model);
+    assert!(model.contains("            tag: $data['tag'] ?? null,\n"), "{}", model);
+    assert!(model.contains("in_array($key, ['tag'], true), ARRAY_FILTER_USE_BOTH);\n"), "{}", model);
+}
+
+#[test]
+fn ruby_models_flatten_all_of_and_name_enum_values() {
+    let status = generate_spec(MODELS_SPEC, Language::Ruby, "lib/pets/models/status.rb");
+    assert!(
+        status.contains(
+            "  module Status\n    AVAILABLE = \"available\"\n    IN_STOCK = \"in-stock\"\n    IN_STOCK2 = \"in_stock\"\n  end\n"
+        ),
+        "{}",
+        status
+    );
+    let pet = generate_spec(MODELS_SPEC, Language::Ruby, "lib/pets/models/pet.rb");
+    assert!(pet.contains("    def initialize(name:, status:)\n"), "{}", pet);
+    assert!(pet.contains("        status: hash[\"status\"],\n"), "{}", pet);
+}
+
+#[test]
+fn ruby_client_sends_form_fields_as_forms() {
+    let api = generate_spec(FORMS_SPEC, Language::Ruby, "lib/pets/api/default_api.rb");
+    assert!(
+        api.contains(
+            "    def upload_photo(file:, caption: nil)\n      \
+             request(:post, \"/photos\", form: { \"caption\" => caption, \"file\" => file }, multipart: true)\n"
+        ),
+        "{}",
+        api
+    );
+    assert!(api.contains("      request(:post, \"/login\", form: { \"user\" => user })\n"), "{}", api);
+    let client = generate_spec(FORMS_SPEC, Language::Ruby, "lib/pets/client.rb");
+    assert!(client.contains("        faraday.request :multipart\n        faraday.request :json\n"), "{}", client);
+    assert!(client.contains("            req.body = URI.encode_www_form(fields)\n"), "{}", client);
+    let gemspec = generate_spec(FORMS_SPEC, Language::Ruby, "pets.gemspec");
+    assert!(gemspec.contains("  spec.add_dependency \"faraday-multipart\", \"~> 1.0\"\n"), "{}", gemspec);
+}
+
+#[test]
+fn ruby_client_sends_query_and_header_parameters() {
+    let files = Generator::new(parse_spec(PARAMS_SPEC, None).unwrap())
+        .language(Language::Ruby)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    let api = &files[Path::new("lib/pets/api/default_api.rb")];
+    assert!(api.contains("    def list_pets(x_trace:, limit: nil)\n"), "{}", api);
+    assert!(
+        api.contains("request(:get, \"/pets\", query: { \"limit\" => limit }, headers: { \"X-Trace\" => x_trace })"),
+        "{}",
+        api
+    );
+    let client = &files[Path::new("lib/pets/client.rb")];
+    assert!(client.contains("    def request(method, path, body: nil, query: {}, headers: {})\n"), "{}", client);
+}
+
+#[test]
+fn ruby_names_do_not_override_object_and_client_methods() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/a": {
+                "get": {
+                    "operationId": "class",
+                    "parameters": [{ "name": "hash", "in": "query", "type": "string" }],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            },
+            "/b": { "get": { "operationId": "request", "responses": { "200": { "description": "ok" } } } }
+        },
+        "definitions": {
+            "Pet": {
+                "type": "object",
+                "properties": { "hash": { "type": "string" }, "object_id": { "type": "integer" } }
+            }
+        }
+    }"##;
+    let api = generate_spec(spec, Language::Ruby, "lib/pets/api/default_api.rb");
+    assert!(api.contains("    def class_(hash_: nil)
+"), "{}", api);
+    assert!(api.contains("query: { \"hash\" => hash_ }"), "{}", api);
+    assert!(api.contains("    def request_\n"), "{}", api);
+
+    let pet = generate_spec(spec, Language::Ruby, "lib/pets/models/pet.rb");
+    assert!(pet.contains("    attr_accessor :hash_, :object_id_\n"), "{}", pet);
+    assert!(pet.contains("        \"hash\" => @hash_,\n"), "{}", pet);
+}
+
+#[test]
+fn clients_send_each_body_with_the_media_type_of_its_operation() {
+    // The multipart, URL-encoded and plain text request of each target, in that order.
+    let targets = [
+        (
+            Language::TypeScript,
+            "service.ts",
+            [
+                "client.post(`/photos`, formData(data), config)",
+                "urlEncoded(data), { ...config, headers: { 'Content-Type': 'application/x-www-form-urlencoded',",
+                "blobBody(data, 'text/plain'), { ...config, headers: { 'Content-Type': 'text/plain',",
+            ],
+        ),
+        (
+            Language::JavaScript,
+            "service.js",
+            [
+                "request('POST', `/photos`, formData(data), config)",
+                "urlEncoded(data), { ...config, headers: { 'Content-Type': 'application/x-www-form-urlencoded',",
+                "blobBody(data, 'text/plain'), { ...config, headers: { 'Content-Type': 'text/plain',",
+            ],
+        ),
+        (
+            Language::Python,
+            "service.py",
+            [
+                "_with_params(kwargs, files={\"file\": file})",
+                "_with_params(kwargs, data={\"user\": user})",
+                "data=data, **_with_params(kwargs, headers={\"Content-Type\": \"text/plain\"})",
+            ],
+        ),
+        (
+            Language::Go,
+            "go/client.go",
+            [
+                "&multipartBody{fields: form, files: files}",
+                "contentType = \"application/x-www-form-urlencoded\"",
+                "header.Set(\"Content-Type\", \"text/plain\")",
+            ],
+        ),
+        (
+            Language::Rust,
+            "src/lib.rs",
+            [
+                "request.multipart(form)",
+                "request.form(&form)",
+                ".header(reqwest::header::CONTENT_TYPE, \"text/plain\").body(body.clone())",
+            ],
+        ),
+        (
+            Language::Java,
+            "src/main/java/pets/defaultapi/DefaultService.java",
+            [
+                "ApiClient.multipartForm(\"file\", file)",
+                "ApiClient.urlEncodedForm(\"user\", user)",
+                "client.encodeBody(\"text/plain\", body)",
+            ],
+        ),
+        (
+            Language::Kotlin,
+            "src/main/kotlin/pets/api/DefaultApi.kt",
+            ["@Multipart\n", "@FormUrlEncoded\n", "@Headers(\"Content-Type: text/plain\")\n"],
+        ),
+        (
+            Language::CSharp,
+            "Pets/ApiService.cs",
+            ["MultipartForm((\"file\", file))", "UrlEncodedForm((\"user\", user))", "MediaBody(body, \"text/plain\")"],
+        ),
+        (
+            Language::Swift,
+            "Sources/Pets/APIClient.swift",
+            [
+                "multipartForm([(\"file\", file)])",
+                "urlEncodedForm([(\"user\", user)])",
+                "body: Data(body.utf8), contentType: \"text/plain\")",
+            ],
+        ),
+        (
+            Language::Dart,
+            "lib/src/api_client.dart",
+            [
+                "body: FormData.fromMap({'file': file}, ListFormat.multi)",
+                "contentType: Headers.formUrlEncodedContentType",
+                "body: body, contentType: 'text/plain'",
+            ],
+        ),
+        (
+            Language::Php,
+            "src/ApiService.php",
+            ["form: ['file' => $file], multipart: true", "form: ['user' => $user])", "$body, contentType: 'text/plain')"],
+        ),
+        (
+            Language::Ruby,
+            "lib/pets/api/default_api.rb",
+            [
+                "form: { \"file\" => file }, multipart: true",
+                "form: { \"user\" => user })",
+                "body: body, content_type: \"text/plain\")",
+            ],
+        ),
+    ];
+    for (language, file, requests) in targets {
+        let code = generate_spec(MEDIA_TYPES_SPEC, language, file);
+        for request in requests {
+            assert!(code.contains(request), "{:?} lacks {}:\n{}", language, request, code);
+        }
+    }
+}
+
+#[test]
+fn clients_join_array_parameters_by_their_collection_format() {
+    // The `csv`, `pipes` and `ssv` arrays of each target, and how it sends the repeated `multi` one.
+    let targets = [
+        (
+            Language::TypeScript,
+            "service.ts",
+            vec!["tags: params?.tags?.join(','), names: params?.names?.join('|'), sizes: params?.sizes?.join(' ') }"],
+        ),
+        (
+            Language::JavaScript,
+            "service.js",
+            vec!["tags: params?.tags?.join(','), names: params?.names?.join('|'), sizes: params?.sizes?.join(' ') }"],
+        ),
+        (
+            Language::Angular,
+            "services/default.service.ts",
+            vec!["...queryParams({ ...params, tags: params?.tags?.join(','), names: params?.names?.join('|'),"],
+        ),
+        (
+            Language::RtkQuery,
+            "api.ts",
+            vec![
+                "params: { ...params, tags: params?.tags?.join(','), names: params?.names?.join('|'),",
+                "fetchBaseQuery({ baseUrl: 'https://api.example.com', paramsSerializer: searchParams })",
+            ],
+        ),
+        (
+            Language::Python,
+            "service.py",
+            vec![
+                "params={\"tags\": None if tags is None else \",\".join(map(str, tags)), \"ids\": ids, ",
+                "\"sizes\": None if sizes is None else \" \".join(map(str, sizes))}",
+                "headers={\"X-Ids\": None if x_ids is None else \",\".join(map(str, x_ids))}",
+            ],
+        ),
+        (
+            Language::Go,
+            "go/client.go",
+            vec![
+                "query.Set(\"tags\", strings.Join(params.Tags, \",\"))",
+                "query.Add(\"ids\", fmt.Sprint(value))",
+                "query.Set(\"names\", strings.Join(params.Names, \"|\"))",
+                "query.Set(\"sizes\", strings.Join(values, \" \"))",
+            ],
+        ),
+        (
+            Language::Rust,
+            "src/lib.rs",
+            vec![
+                "let value = params.tags.iter().map(ToString::to_string).collect::<Vec<_>>().join(\",\");",
+                "        for value in &params.ids {\n            request = request.query(&[(\"ids\", value)]);",
+                "let value = params.sizes.iter().map(ToString::to_string).collect::<Vec<_>>().join(\" \");",
+            ],
+        ),
+        (
+            Language::Java,
+            "src/main/java/pets/defaultapi/DefaultService.java",
+            vec![
+                "ApiClient.query(\"tags\", ApiClient.join(\",\", tags), \"ids\", ids, \"names\", ApiClient.join(\"|\", names),",
+                "ApiClient.headers(\"X-Ids\", ApiClient.join(\",\", xIds))",
+            ],
+        ),
+        (
+            Language::Kotlin,
+            "src/main/kotlin/pets/api/DefaultApi.kt",
+            vec![
+                "@Query(\"ids\") ids: List<Long>, @Query(\"tags\") tags: CsvList<String>? = null",
+                "@Query(\"names\") names: PipesList<String>? = null, @Query(\"sizes\") sizes: SsvList<Int>? = null",
+                "@Header(\"X-Ids\") xIds: CsvList<String>? = null",
+            ],
+        ),
+        (
+            Language::CSharp,
+            "Pets/ApiService.cs",
+            vec![
+                "Query((\"tags\", Join(\",\", tags)), (\"ids\", ids), (\"names\", Join(\"|\", names))",
+                "{ (\"X-Ids\", Join(\",\", xIds)) }",
+            ],
+        ),
+        (
+            Language::Swift,
+            "Sources/Pets/APIClient.swift",
+            vec![
+                "query: [(\"tags\", tags?.map { \"\\($0)\" }.joined(separator: \",\")), (\"ids\", ids),",
+                "(\"sizes\", sizes?.map { \"\\($0)\" }.joined(separator: \" \"))",
+            ],
+        ),
+        (
+            Language::Dart,
+            "lib/src/api_client.dart",
+            vec!["{'tags': tags?.join(','), 'ids': ids, 'names': names?.join('|'), 'sizes': sizes?.join(' ')}"],
+        ),
+        (
+            Language::Php,
+            "src/ApiService.php",
+            vec!["['tags' => $tags === null ? null : implode(',', $tags), 'ids' => $ids, "],
+        ),
+        (
+            Language::Ruby,
+            "lib/pets/api/default_api.rb",
+            vec!["{ \"tags\" => tags&.join(\",\"), \"ids\" => ids, \"names\" => names&.join(\"|\"), "],
+        ),
+    ];
+    for (language, file, requests) in targets {
+        let code = generate_spec(COLLECTION_SPEC, language, file);
+        for request in requests {
+            assert!(code.contains(request), "{:?} lacks {}:\n{}", language, request, code);
+        }
+    }
+}
+
+#[test]
+fn openapi_3_styles_choose_how_arrays_are_sent() {
+    let spec = r#"{
+        "openapi": "3.0.3",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "operationId": "findPets",
+                    "parameters": [
+                        { "name": "tags", "in": "query", "schema": { "type": "array", "items": { "type": "string" } } },
+                        {
+                            "name": "ids",
+                            "in": "query",
+                            "explode": false,
+                            "schema": { "type": "array", "items": { "type": "string" } }
+                        },
+                        {
+                            "name": "names",
+                            "in": "query",
+                            "style": "pipeDelimited",
+                            "schema": { "type": "array", "items": { "type": "string" } }
+                        },
+                        { "name": "X-Ids", "in": "header", "schema": { "type": "array", "items": { "type": "string" } } }
+                    ],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"#;
+    let service = generate_spec(spec, Language::Python, "service.py");
+    assert!(
+        service.contains(
+            "params={\"tags\": tags, \"ids\": None if ids is None else \",\".join(map(str, ids)), \
+             \"names\": None if names is None else \"|\".join(map(str, names))}, \
+             headers={\"X-Ids\": None if x_ids is None else \",\".join(map(str, x_ids))}"
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn block_comment_banners_escape_comment_markers() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0", "description": "Matches src/**/*.json, see /* notes */" },
+        "paths": {},
+        "definitions": { "Pet": { "type": "object", "properties": { "name": { "type": "string" } } } }
+    }"#;
+    for (language, file) in [
+        (Language::TypeScript, "interfaces/Pet.ts"),
+        (Language::Java, "src/main/java/pets/model/Pet.java"),
+        (Language::Kotlin, "src/main/kotlin/pets/model/Pet.kt"),
+        (Language::Php, "src/Model/Pet.php"),
+    ] {
+        let code = generate_spec(spec, language, file);
+        assert!(code.contains(" * Description: Matches src/\\**\\/\\*.json, see /\\* notes *\\/\n */\n"), "{}", code);
+    }
+}
+
+#[test]
+fn settled_operation_names_leave_the_documented_operation_ids_alone() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Items", "version": "1.0" },
+        "paths": {
+            "/a": { "get": { "operationId": "getItem", "responses": { "200": { "description": "ok" } } } },
+            "/b": { "get": { "operationId": "get_item", "responses": { "200": { "description": "ok" } } } },
+            "/c": { "get": { "responses": { "200": { "description": "ok" } } } }
+        }
+    }"##;
+    let docs = generate_spec(spec, Language::Docs, "API.md");
+    assert!(docs.contains("- **Operation ID:** `getItem`\n"), "{}", docs);
+    assert!(docs.contains("- **Operation ID:** `get_item`\n"), "{}", docs);
+    assert_eq!(docs.matches("Operation ID").count(), 2, "{}", docs);
+
+    let service = generate_spec(spec, Language::TypeScript, "service.ts");
+    assert!(service.contains("getItem2("), "{}", service);
+    assert!(service.contains("getC("), "{}", service);
+}
+
+#[test]
+fn client_retries_wait_for_a_retry_after_date() {
+    let generate = |language: Language, file: &str| {
+        let files = Generator::new(parse_spec(PARAMS_SPEC, None).unwrap())
+            .language(language)
+            .retries(Some(2))
+            .timestamp(false)
+            .generate()
+            .unwrap();
+        files[Path::new(file)].clone()
+    };
+    let expected = [
+        (Language::Java, "src/main/java/pets/ApiClient.java", "ZonedDateTime.parse(retryAfter, DateTimeFormatter"),
+        (Language::CSharp, "Pets/ApiService.cs", "else if (retryAfter?.Date is DateTimeOffset date)"),
+        (Language::Kotlin, "src/main/kotlin/pets/ApiClient.kt", "?: response.headers.getDate(\"Retry-After\")"),
+        (Language::Swift, "Sources/Pets/APIClient.swift", "let date = APIClient.httpDate.date(from: retryAfter)"),
+        (Language::Dart, "lib/src/api_client.dart", "parseHttpDate(retryAfter ?? '').difference(DateTime.now())"),
+        (Language::Php, "src/ApiService.php", "$date !== false => max($date - time(), 0) * 1000000,"),
+        (Language::Rust, "src/lib.rs", "let date = httpdate::parse_http_date(value).ok()?;"),
+    ];
+    for (language, file, delay) in expected {
+        let code = generate(language, file);
+        assert!(code.contains(delay), "{}", code);
+    }
+    assert!(generate(Language::Dart, "pubspec.yaml").contains("  http_parser: ^4.0.0\n"));
+    assert!(generate(Language::Rust, "Cargo.toml").contains("httpdate = \"1\"\n"));
+}
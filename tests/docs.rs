@@ -0,0 +1,88 @@
+use std::path::Path;
+use swagger_generator::cli::{DocsFormat, Language};
+use swagger_generator::{parse_spec, Generator};
+
+/// A model whose enum mixes a string with markup, a number and an object with markup.
+const ENUM_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {},
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "properties": { "kind": { "enum": ["<cat>", 2, { "tag": "<dog>" }] } }
+        }
+    }
+}"##;
+
+/// A list filtered by an array query parameter and a get by an integer ID answering a model or an
+/// error.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "basePath": "/v1",
+    "schemes": ["https"],
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [{ "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": {
+                    "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } },
+                    "404": { "description": "missing" }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name"],
+            "properties": { "name": { "type": "string" }, "kind": { "type": "string", "enum": ["cat", "dog"] } }
+        }
+    }
+}"##;
+
+fn generate(spec: &str, docs_format: DocsFormat, file: &str) -> String {
+    let files = Generator::new(parse_spec(spec, None).unwrap())
+        .language(Language::Docs)
+        .docs_format(docs_format)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    files[Path::new(file)].clone()
+}
+
+#[test]
+fn html_escapes_every_enum_value() {
+    let html = generate(ENUM_SPEC, DocsFormat::Html, "index.html");
+    assert!(
+        html.contains(
+            "One of <code>&lt;cat&gt;</code>, <code>2</code>, <code>{&quot;tag&quot;:&quot;&lt;dog&gt;&quot;}</code>."
+        ),
+        "{}",
+        html
+    );
+}
+
+#[test]
+fn markdown_lists_operations_parameters_responses_and_schemas() {
+    let markdown = generate(PETS_SPEC, DocsFormat::Markdown, "API.md");
+    assert!(markdown.starts_with("# Pets\n\n- **Version:** 1.0\n- **Base URL:** `https://api.example.com/v1`\n"));
+    assert!(markdown.contains("  - [`GET /pets/{petId}`](#get-petspetid)\n"), "{}", markdown);
+    assert!(markdown.contains("| `tags` | query | array of string | no |  |\n"), "{}", markdown);
+    assert!(markdown.contains("| `petId` | path | integer (int64) | yes |  |\n"), "{}", markdown);
+    assert!(markdown.contains("| 200 | ok | [Pet](#pet) |\n| 404 | missing |  |\n"), "{}", markdown);
+    assert!(markdown.contains("Example `200` response:\n\n```json\n{\n    \"name\": \"string\",\n"), "{}", markdown);
+    assert!(markdown.contains("| `kind` | string | no | One of `cat`, `dog`. |\n"), "{}", markdown);
+}
@@ -0,0 +1,123 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, Generator};
+
+/// A list filtered by array query parameters, a create taking a model and a get by an integer ID.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "basePath": "/v1",
+    "schemes": ["https"],
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } },
+                    {
+                        "name": "ids",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "multi",
+                        "items": { "type": "integer" }
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "ok",
+                        "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } }
+                    }
+                }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [
+                    { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                ],
+                "responses": { "201": { "description": "created", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": {
+                    "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } },
+                    "404": { "description": "missing", "schema": { "$ref": "#/definitions/Error" } }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string", "minLength": 3 },
+                "kind": { "type": "string", "enum": ["cat", "dog"] }
+            }
+        },
+        "Error": { "type": "object", "properties": { "message": { "type": "string" } } }
+    }
+}"##;
+
+fn generate(language: Language, file: &str) -> String {
+    let generator = Generator::new(parse_spec(PETS_SPEC, None).unwrap()).language(language);
+    let files = generator.timestamp(false).generate().unwrap();
+    files[Path::new(file)].clone()
+}
+
+#[test]
+fn angular_service_returns_typed_observables() {
+    let service = generate(Language::Angular, "services/default.service.ts");
+    assert!(service.contains("@Injectable()\nexport class DefaultService {\n"), "{}", service);
+    assert!(service.contains("export type GetPetError = HttpErrorResponse & ({ status: 404; error: Error });\n"));
+    assert!(service.contains("    listPets(params?: ListPetsParams, options?: RequestOptions): Observable<Pet[]> {\n"));
+    assert!(service.contains("queryParams({ ...params, tags: params?.tags?.join(',') })"), "{}", service);
+    assert!(
+        service.contains(
+            "        return this.http.request<Pet>('POST', `${this.basePath}/pets`, { ...options, body: data });\n"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("    getPet(petId: number, options?: RequestOptions): Observable<Pet> {\n"), "{}", service);
+
+    let module = generate(Language::Angular, "api.module.ts");
+    assert!(module.contains("DefaultService"), "{}", module);
+}
+
+#[test]
+fn vue_composables_wrap_every_service_function() {
+    let composables = generate(Language::Vue, "composables.ts");
+    assert!(composables.contains("import * as service from './service';\n"), "{}", composables);
+    assert!(
+        composables.contains("function useRequest<T, A extends unknown[]>(request: (...args: A) => Promise<T>)"),
+        "{}",
+        composables
+    );
+    for name in ["ListPets", "CreatePet", "GetPet"] {
+        let composable = format!("export function use{}() {{\n    return useRequest(service.", name);
+        assert!(composables.contains(&composable), "{}", composables);
+    }
+    assert!(generate(Language::Vue, "service.ts").contains("export async function getPet(petId: number"));
+}
+
+#[test]
+fn rtk_query_slice_has_queries_and_mutations_with_hooks() {
+    let api = generate(Language::RtkQuery, "api.ts");
+    assert!(
+        api.contains("fetchBaseQuery({ baseUrl: 'https://api.example.com/v1', paramsSerializer: searchParams }),"),
+        "{}",
+        api
+    );
+    assert!(api.contains("        listPets: builder.query<Pet[], { params?: ListPetsParams }>({\n"), "{}", api);
+    assert!(api.contains("        createPet: builder.mutation<Pet, { body: Pet }>({\n"), "{}", api);
+    assert!(api.contains("            invalidatesTags: ['default'],\n"), "{}", api);
+    assert!(api.contains("        getPet: builder.query<Pet, { petId: number }>({\n"), "{}", api);
+    assert!(api.contains("    useListPetsQuery,\n    useCreatePetMutation,\n    useGetPetQuery,\n} = api;\n"));
+}
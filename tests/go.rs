@@ -0,0 +1,268 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, GeneratedFiles, Generator};
+
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {},
+    "definitions": {
+        "Status": { "type": "string", "enum": ["available", "sold"] },
+        "Name": { "type": "string" },
+        "Animal": {
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" } }
+        },
+        "Pet": {
+            "allOf": [
+                { "$ref": "#/definitions/Animal" },
+                {
+                    "type": "object",
+                    "required": ["status"],
+                    "properties": { "status": { "$ref": "#/definitions/Status" }, "name": { "type": "string" } }
+                }
+            ]
+        }
+    }
+}"##;
+
+/// Operations taking query, header and form parameters.
+const PARAMS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "limit", "in": "query", "type": "integer", "format": "int32" },
+                    { "name": "X-Trace", "in": "header", "type": "string", "required": true }
+                ],
+                "responses": {
+                    "200": { "description": "ok", "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } } }
+                }
+            }
+        },
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "parameters": [
+                    { "name": "caption", "in": "formData", "type": "string" },
+                    { "name": "file", "in": "formData", "type": "file", "required": true }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/login": {
+            "post": {
+                "operationId": "login",
+                "consumes": ["application/x-www-form-urlencoded"],
+                "parameters": [{ "name": "user", "in": "formData", "type": "string", "required": true }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": { "type": "object", "properties": { "name": { "type": "string" } } }
+    }
+}"##;
+
+/// An OpenAPI 3 upload whose file is a binary string field of a `multipart/form-data` body.
+const MULTIPART_SPEC: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Pets", "version": "1.0" },
+    "servers": [{ "url": "https://api.example.com" }],
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["photo"],
+                                "properties": {
+                                    "caption": { "type": "string" },
+                                    "photo": { "type": "string", "format": "binary" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// Operations taking a required model body, an optional model body, an optional array body and an
+/// integer path parameter.
+const TYPED_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {
+        "/pets": {
+            "post": {
+                "operationId": "createPet",
+                "parameters": [
+                    { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            },
+            "patch": {
+                "operationId": "updatePet",
+                "parameters": [{ "name": "body", "in": "body", "schema": { "$ref": "#/definitions/Pet" } }],
+                "responses": { "200": { "description": "ok" } }
+            },
+            "put": {
+                "operationId": "replacePets",
+                "parameters": [
+                    {
+                        "name": "body",
+                        "in": "body",
+                        "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } }
+                    }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "definitions": { "Pet": { "type": "object", "properties": { "name": { "type": "string" } } } }
+}"##;
+
+fn generate(spec: &str) -> GeneratedFiles {
+    Generator::new(parse_spec(spec, None).unwrap()).language(Language::Go).timestamp(false).generate().unwrap()
+}
+
+#[test]
+fn enums_and_scalars_become_named_types() {
+    let files = generate(PETS_SPEC);
+    let models = &files[Path::new("go/models.go")];
+    assert!(
+        models.contains(
+            "type Status string\n\nconst (\n\tStatusAvailable Status = \"available\"\n\tStatusSold      Status = \"sold\"\n)\n"
+        ),
+        "{}",
+        models
+    );
+    assert!(models.contains("type Name string\n"), "{}", models);
+}
+
+#[test]
+fn enum_constants_are_unique() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {},
+        "definitions": {
+            "Mode": { "type": "string", "enum": ["a-b", "a_b", ""] },
+            "Num": { "type": "integer", "enum": [-1, 1] }
+        }
+    }"#;
+    let files = generate(spec);
+    let models = &files[Path::new("go/models.go")];
+    let mode = "\tModeAB    Mode = \"a-b\"\n\tModeAB2   Mode = \"a_b\"\n\tModeValue Mode = \"\"\n";
+    assert!(models.contains(mode), "{}", models);
+    assert!(models.contains("\tNumNeg1 Num = -1\n\tNum1    Num = 1\n"), "{}", models);
+}
+
+#[test]
+fn all_of_embeds_its_references_and_flattens_its_inline_parts() {
+    let files = generate(PETS_SPEC);
+    let models = &files[Path::new("go/models.go")];
+    assert!(
+        models.contains(
+            "type Pet struct {\n\tAnimal\n\tStatus Status  `json:\"status\"`\n\tName   *string `json:\"name,omitempty\"`\n}\n"
+        ),
+        "{}",
+        models
+    );
+}
+
+#[test]
+fn query_and_header_parameters_come_in_a_params_struct() {
+    let files = generate(PARAMS_SPEC);
+    let client = &files[Path::new("go/client.go")];
+    assert!(client.contains("type ListPetsParams struct {\n\tLimit  *int32\n\tXTrace string\n}\n"), "{}", client);
+    assert!(
+        client.contains("func (c *Client) ListPets(ctx context.Context, params ListPetsParams) ([]Pet, error) {"),
+        "{}",
+        client
+    );
+    assert!(client.contains("\t\tquery.Set(\"limit\", fmt.Sprint(*params.Limit))\n"), "{}", client);
+    assert!(client.contains("\theader.Set(\"X-Trace\", params.XTrace)\n"), "{}", client);
+    assert!(client.contains("c.do(ctx, \"GET\", \"/pets\", query, header, nil, &result)"), "{}", client);
+}
+
+#[test]
+fn form_parameters_are_not_sent_as_json() {
+    let files = generate(PARAMS_SPEC);
+    let client = &files[Path::new("go/client.go")];
+    assert!(client.contains("\t\"mime/multipart\"\n"), "{}", client);
+    assert!(client.contains("\tfiles[\"file\"] = params.File\n"), "{}", client);
+    assert!(client.contains("&multipartBody{fields: form, files: files}, &result)"), "{}", client);
+    assert!(client.contains("\tform.Set(\"user\", params.User)\n"), "{}", client);
+    assert!(client.contains("c.do(ctx, \"POST\", \"/login\", nil, nil, form, &result)"), "{}", client);
+    assert!(client.contains("contentType = \"application/x-www-form-urlencoded\""), "{}", client);
+}
+
+#[test]
+fn binary_form_fields_of_openapi_3_are_uploaded_as_files() {
+    let files = generate(MULTIPART_SPEC);
+    let client = &files[Path::new("go/client.go")];
+    assert!(client.contains("\tPhoto   []byte\n"), "{}", client);
+    assert!(client.contains("\tfiles[\"photo\"] = params.Photo\n"), "{}", client);
+    assert!(client.contains("&multipartBody{fields: form, files: files}, &result)"), "{}", client);
+}
+
+#[test]
+fn bodies_and_path_parameters_are_typed_from_their_schemas() {
+    let files = generate(TYPED_SPEC);
+    let client = &files[Path::new("go/client.go")];
+    assert!(client.contains("func (c *Client) CreatePet(ctx context.Context, body Pet) ("), "{}", client);
+    assert!(client.contains("c.do(ctx, \"POST\", \"/pets\", nil, nil, body, &result)"), "{}", client);
+    assert!(
+        client.contains(
+            "func (c *Client) UpdatePet(ctx context.Context, body *Pet) (interface{}, error) {
+\tvar payload interface{}
+\tif body != nil {
+\t\tpayload = body
+\t}
+"
+        ),
+        "{}",
+        client
+    );
+    assert!(client.contains("c.do(ctx, \"PATCH\", \"/pets\", nil, nil, payload, &result)"), "{}", client);
+    assert!(client.contains("func (c *Client) ReplacePets(ctx context.Context, body []Pet) ("), "{}", client);
+    assert!(client.contains("func (c *Client) GetPet(ctx context.Context, petID int64) ("), "{}", client);
+    assert!(client.contains("fmt.Sprintf(\"/pets/%v\", petID)"), "{}", client);
+}
+
+#[test]
+fn retries_wait_for_a_retry_after_date() {
+    let spec = parse_spec(PETS_SPEC, None).unwrap();
+    let files = Generator::new(spec).language(Language::Go).retries(Some(2)).timestamp(false).generate().unwrap();
+    let client = &files[Path::new("go/client.go")];
+    assert!(
+        client.contains(
+            "\t\t\t} else if date, err := http.ParseTime(retryAfter); err == nil {\n\t\t\t\t\
+             delay = max(time.Until(date), 0)\n"
+        ),
+        "{}",
+        client
+    );
+}
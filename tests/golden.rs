@@ -0,0 +1,117 @@
+//! Compares generated output with the files under `tests/golden`. After a change to the output,
+//! check the new files in with
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test golden
+//! ```
+use serde_json::Value;
+use similar::TextDiff;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use swagger_generator::cli::{HttpClient, Language};
+use swagger_generator::{convert, loader, parse_spec, GeneratedFiles, Generator};
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden")
+}
+
+fn spec(name: &str) -> String {
+    fs::read_to_string(golden_dir().join("specs").join(name)).unwrap()
+}
+
+fn generate(spec: &str, http_client: HttpClient, single_file: bool) -> GeneratedFiles {
+    Generator::new(parse_spec(spec, None).unwrap())
+        .language(Language::TypeScript)
+        .http_client(http_client)
+        .single_file(single_file)
+        .timestamp(false)
+        .generate()
+        .unwrap()
+}
+
+fn document(value: &Value) -> String {
+    serde_json::to_string_pretty(value).unwrap() + "\n"
+}
+
+/// The files under `dir`, by their path relative to `root`.
+fn read_files(root: &Path, dir: &Path, files: &mut GeneratedFiles) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_files(root, &path, files);
+        } else {
+            files.insert(path.strip_prefix(root).unwrap().to_path_buf(), fs::read_to_string(&path).unwrap());
+        }
+    }
+}
+
+/// Checks `files` against the golden directory `name`, or writes them there when UPDATE_GOLDEN is set.
+fn assert_golden(name: &str, files: &GeneratedFiles) {
+    let dir = golden_dir().join(name);
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        let _ = fs::remove_dir_all(&dir);
+        for (path, contents) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+        return;
+    }
+
+    let mut expected = GeneratedFiles::new();
+    if dir.is_dir() {
+        read_files(&dir, &dir, &mut expected);
+    }
+    let expected_paths: Vec<&PathBuf> = expected.keys().collect();
+    let actual_paths: Vec<&PathBuf> = files.keys().collect();
+    assert_eq!(expected_paths, actual_paths, "the files of {} differ", name);
+    for (path, contents) in files {
+        let golden = &expected[path];
+        if golden != contents {
+            let diff = TextDiff::from_lines(golden.as_str(), contents.as_str());
+            panic!("{}/{} differs:\n{}", name, path.display(), diff.unified_diff());
+        }
+    }
+}
+
+#[test]
+fn typescript_axios() {
+    assert_golden("typescript-axios", &generate(&spec("petstore.json"), HttpClient::Axios, false));
+}
+
+#[test]
+fn typescript_fetch() {
+    assert_golden("typescript-fetch", &generate(&spec("petstore.json"), HttpClient::Fetch, false));
+}
+
+#[test]
+fn typescript_single_file() {
+    assert_golden("single-file-axios", &generate(&spec("petstore.json"), HttpClient::Axios, true));
+    assert_golden("single-file-fetch", &generate(&spec("petstore.json"), HttpClient::Fetch, true));
+}
+
+#[test]
+fn external_refs_are_resolved() {
+    let input = golden_dir().join("specs/refs/spec.yaml");
+    let resolved = loader::load_document(&input.to_string_lossy(), None, &[]).unwrap();
+    let files = GeneratedFiles::from([(PathBuf::from("spec.json"), document(&resolved))]);
+    assert_golden("refs", &files);
+}
+
+#[test]
+fn swagger2_converts_to_openapi3() {
+    let swagger = serde_json::from_str(&spec("petstore.json")).unwrap();
+    let converted = convert::swagger2_to_openapi3("petstore.json", swagger).unwrap();
+    assert_golden("convert", &GeneratedFiles::from([(PathBuf::from("openapi3.json"), document(&converted))]));
+
+    // The converted document describes the same API, so it generates the same client.
+    let files = generate(&document(&converted), HttpClient::Axios, false);
+    assert!(files == generate(&spec("petstore.json"), HttpClient::Axios, false), "{:#?}", files);
+}
+
+#[test]
+fn openapi31_and_postman_are_imported() {
+    assert_golden("openapi31", &generate(&spec("openapi31.yaml"), HttpClient::Axios, false));
+    assert_golden("postman", &generate(&spec("postman.json"), HttpClient::Axios, false));
+}
@@ -0,0 +1,68 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0
+ * Title: Pets
+ */
+
+import axios from 'axios';
+import type { AxiosResponse, InternalAxiosRequestConfig } from 'axios';
+
+export const client = axios.create({ baseURL: 'https://eu.petstore.example.com/v1', paramsSerializer: { indexes: null } });
+
+/** Called with the config of every request before it is sent, which it can change in place. */
+export type RequestHook = (config: InternalAxiosRequestConfig) => void | Promise<void>;
+
+/** Called with every successful response. */
+export type ResponseHook = (response: AxiosResponse) => void | Promise<void>;
+
+/** Called with every error a request fails with; returning another error rejects with that one instead. */
+export type ErrorHook = (error: unknown) => unknown;
+
+/** Registers a hook called before every request is sent. Returns a function removing it. */
+export function onRequest(hook: RequestHook): () => void {
+    const id = client.interceptors.request.use(async config => {
+        await hook(config);
+        return config;
+    });
+    return () => client.interceptors.request.eject(id);
+}
+
+/** Registers a hook called with every successful response. Returns a function removing it. */
+export function onResponse(hook: ResponseHook): () => void {
+    const id = client.interceptors.response.use(async response => {
+        await hook(response);
+        return response;
+    });
+    return () => client.interceptors.response.eject(id);
+}
+
+/** Registers a hook called with every error a request fails with. Returns a function removing it. */
+export function onError(hook: ErrorHook): () => void {
+    const id = client.interceptors.response.use(undefined, async error => {
+        throw (await hook(error)) ?? error;
+    });
+    return () => client.interceptors.response.eject(id);
+}
+
+/** The variables of each documented server, by its URL. */
+export interface ServerVariables {
+    'https://{region}.petstore.example.com/v1': {
+        region?: 'eu' | 'us';
+    };
+}
+
+const SERVER_DEFAULTS: Record<keyof ServerVariables, Record<string, string>> = {
+    'https://{region}.petstore.example.com/v1': { region: 'eu' },
+};
+
+/** The URL of a documented server, its `{variables}` filled in from `variables` or their defaults. */
+export function serverUrl<Url extends keyof ServerVariables>(url: Url, variables?: ServerVariables[Url]): string {
+    const values: Record<string, string | undefined> = { ...SERVER_DEFAULTS[url], ...variables };
+    return url.replace(/\{([^}]+)\}/g, (_, name: string) => values[name] ?? '');
+}
+
+/** Points `client` at one of the documented servers. */
+export function selectServer<Url extends keyof ServerVariables>(url: Url, variables?: ServerVariables[Url]) {
+    client.defaults.baseURL = serverUrl(url, variables);
+}
@@ -0,0 +1,13 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0
+ * Title: Pets
+ */
+
+export interface Pet {
+    id: number;
+    name?: string | null;
+    tags?: string[];
+    kind?: 'pet';
+}
@@ -0,0 +1,8 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0
+ * Title: Pets
+ */
+
+export * from './Pet';
@@ -0,0 +1,34 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0
+ * Title: Pets
+ */
+
+import { client } from './client';
+
+import { Pet } from './interfaces';
+
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+export interface ListPetsParams {
+    limit?: number;
+}
+
+export async function listPets(params?: ListPetsParams, config?: RequestOptions): Promise<Pet[]> {
+    const response = await client.get(`/pets`, { ...config, params: { ...config?.params, ...params } });
+    return response.data;
+}
+
+// <custom>
+// </custom>
@@ -0,0 +1,70 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Pets Collection
+ * Description: Requests of the pet store.
+ */
+
+import { client } from './client';
+
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+function requestHeaders(values: object = {}, cookies: string[] = []): Record<string, string> {
+    const headers: Record<string, string> = {};
+    const cookie: string[] = [];
+    for (const [name, value] of Object.entries(values)) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        if (cookies.includes(name)) {
+            cookie.push(`${name}=${encodeURIComponent(String(value))}`);
+        } else {
+            headers[name] = String(value);
+        }
+    }
+    if (cookie.length > 0) {
+        headers['Cookie'] = cookie.join('; ');
+    }
+    return headers;
+}
+
+export interface GetPetsParams {
+    /** How many pets to return */
+    limit?: string;
+}
+
+/** List pets */
+export async function getPets(params?: GetPetsParams, config?: RequestOptions): Promise<any> {
+    const response = await client.get(`/pets`, { ...config, params: { ...config?.params, ...params } });
+    return response.data;
+}
+
+/** Create pet */
+export async function postPets(data?: Record<string, unknown>, config?: RequestOptions): Promise<any> {
+    const response = await client.post(`/pets`, data, config);
+    return response.data;
+}
+
+export interface GetPetsByPetIdHeaders {
+    'X-Trace'?: string;
+}
+
+/** Get pet */
+export async function getPetsByPetId(petId: string, headers?: GetPetsByPetIdHeaders, config?: RequestOptions): Promise<any> {
+    const response = await client.get(`/pets/${encodeURIComponent(petId)}`, { ...config, headers: { ...config?.headers, ...requestHeaders(headers) } });
+    return response.data;
+}
+
+// <custom>
+// </custom>
@@ -0,0 +1,151 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+import axios, { type AxiosResponse, type InternalAxiosRequestConfig, type AxiosError } from 'axios';
+
+export interface Error {
+    code: number;
+    message: string;
+}
+
+export interface NewPet {
+    name: string;
+    tag?: string;
+    status?: Status;
+}
+
+export interface Pet extends NewPet {
+    id: number;
+}
+
+export type Status = 'available' | 'pending' | 'sold';
+
+export const client = axios.create({ baseURL: 'https://petstore.example.com/v1', paramsSerializer: { indexes: null } });
+
+/** Called with the config of every request before it is sent, which it can change in place. */
+export type RequestHook = (config: InternalAxiosRequestConfig) => void | Promise<void>;
+
+/** Called with every successful response. */
+export type ResponseHook = (response: AxiosResponse) => void | Promise<void>;
+
+/** Called with every error a request fails with; returning another error rejects with that one instead. */
+export type ErrorHook = (error: unknown) => unknown;
+
+/** Registers a hook called before every request is sent. Returns a function removing it. */
+export function onRequest(hook: RequestHook): () => void {
+    const id = client.interceptors.request.use(async config => {
+        await hook(config);
+        return config;
+    });
+    return () => client.interceptors.request.eject(id);
+}
+
+/** Registers a hook called with every successful response. Returns a function removing it. */
+export function onResponse(hook: ResponseHook): () => void {
+    const id = client.interceptors.response.use(async response => {
+        await hook(response);
+        return response;
+    });
+    return () => client.interceptors.response.eject(id);
+}
+
+/** Registers a hook called with every error a request fails with. Returns a function removing it. */
+export function onError(hook: ErrorHook): () => void {
+    const id = client.interceptors.response.use(undefined, async error => {
+        throw (await hook(error)) ?? error;
+    });
+    return () => client.interceptors.response.eject(id);
+}
+
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+/** An axios error whose response has the given status and body. */
+export type ResponseError<Status extends number, Body> = AxiosError<Body> & {
+    response: AxiosResponse<Body> & { status: Status };
+};
+
+export interface AuthConfig {
+    'api_key'?: string;
+}
+
+let auth: AuthConfig = {};
+
+export function setAuth(config: AuthConfig) {
+    auth = config;
+}
+
+function withAuth(requirements: (keyof AuthConfig)[][], config: any = {}): any {
+    const requirement = requirements.find(names => names.every(name => auth[name] !== undefined));
+    if (!requirement) {
+        return config;
+    }
+    const headers = { ...config.headers };
+    const params = { ...config.params };
+    for (const name of requirement) {
+        switch (name) {
+            case 'api_key':
+                headers['X-API-Key'] = auth['api_key'];
+                break;
+        }
+    }
+    return { ...config, headers, params };
+}
+
+export interface ListPetsParams {
+    limit?: number;
+    status?: 'available' | 'sold';
+}
+
+export type ListPetsError = ResponseError<number, Error>;
+
+/** List all pets */
+export async function listPets(params?: ListPetsParams, config?: RequestOptions): Promise<Pet[]> {
+    const response = await client.get(`/pets`, withAuth([['api_key']], { ...config, params: { ...config?.params, ...params } }));
+    return response.data;
+}
+
+export type CreatePetError = ResponseError<400, Error>;
+
+/** Create a pet */
+export async function createPet(data: NewPet, config?: RequestOptions): Promise<Pet> {
+    const response = await client.post(`/pets`, data, withAuth([['api_key']], config));
+    return response.data;
+}
+
+export type ShowPetByIdError = ResponseError<404, Error>;
+
+/** Info for a specific pet */
+export async function showPetById(petId: string, config?: RequestOptions): Promise<Pet> {
+    const response = await client.get(`/pets/${encodeURIComponent(petId)}`, withAuth([['api_key']], config));
+    return response.data;
+}
+
+/** @deprecated */
+export async function deletePet(petId: string, config?: RequestOptions): Promise<void> {
+    const response = await client.delete(`/pets/${encodeURIComponent(petId)}`, withAuth([['api_key']], config));
+    return response.data;
+}
+
+/** Pet counts by status */
+export async function getInventory(config?: RequestOptions): Promise<Record<string, unknown>> {
+    const response = await client.get(`/store/inventory`, config);
+    return response.data;
+}
+
+// <custom>
+// </custom>
@@ -0,0 +1,227 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+export interface Error {
+    code: number;
+    message: string;
+}
+
+export interface NewPet {
+    name: string;
+    tag?: string;
+    status?: Status;
+}
+
+export interface Pet extends NewPet {
+    id: number;
+}
+
+export type Status = 'available' | 'pending' | 'sold';
+
+export const BASE_URL = 'https://petstore.example.com/v1';
+
+export class ApiError<Status extends number = number, Body = unknown> extends globalThis.Error {
+    constructor(
+        readonly status: Status,
+        readonly body: Body,
+        message: string,
+    ) {
+        super(message);
+        this.name = 'ApiError';
+    }
+}
+
+export interface AuthConfig {
+    'api_key'?: string;
+}
+
+let auth: AuthConfig = {};
+
+export function setAuth(config: AuthConfig) {
+    auth = config;
+}
+
+function withAuth(requirements: (keyof AuthConfig)[][], config: any = {}): any {
+    const requirement = requirements.find(names => names.every(name => auth[name] !== undefined));
+    if (!requirement) {
+        return config;
+    }
+    const headers = { ...config.headers };
+    const params = { ...config.params };
+    for (const name of requirement) {
+        switch (name) {
+            case 'api_key':
+                headers['X-API-Key'] = auth['api_key'];
+                break;
+        }
+    }
+    return { ...config, headers, params };
+}
+
+/** Called before every request is sent with its URL and init, which it can change in place. */
+export type RequestHook = (url: URL, init: RequestInit) => void | Promise<void>;
+
+/** Called with every response before its body is read. */
+export type ResponseHook = (response: Response) => void | Promise<void>;
+
+/** Called with every error a request fails with; returning another error rejects with that one instead. */
+export type ErrorHook = (error: unknown) => unknown;
+
+interface Hooks {
+    request: RequestHook[];
+    response: ResponseHook[];
+    error: ErrorHook[];
+}
+
+/** Adds `hook` to `hooks`, returning a function removing it again. */
+function addHook<Hook>(hooks: Hook[], hook: Hook): () => void {
+    hooks.push(hook);
+    return () => {
+        const index = hooks.indexOf(hook);
+        if (index >= 0) {
+            hooks.splice(index, 1);
+        }
+    };
+}
+
+/** Passes `error` through the error `hooks`, each of which can replace it. */
+async function applyErrorHooks(hooks: ErrorHook[], error: unknown): Promise<unknown> {
+    for (const hook of hooks) {
+        error = (await hook(error)) ?? error;
+    }
+    return error;
+}
+
+const hooks: Hooks = { request: [], response: [], error: [] };
+
+/** Registers a hook called before every request is sent. Returns a function removing it. */
+export function onRequest(hook: RequestHook): () => void {
+    return addHook(hooks.request, hook);
+}
+
+/** Registers a hook called with every response. Returns a function removing it. */
+export function onResponse(hook: ResponseHook): () => void {
+    return addHook(hooks.response, hook);
+}
+
+/** Registers a hook called with every error a request fails with. Returns a function removing it. */
+export function onError(hook: ErrorHook): () => void {
+    return addHook(hooks.error, hook);
+}
+
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+async function request<T>(method: string, path: string, body?: unknown, init: RequestOptions = {}): Promise<T> {
+    const { params, timeout, ...options } = init;
+    const url = new URL(BASE_URL + path, globalThis.location?.href);
+    for (const [name, value] of Object.entries(params ?? {})) {
+        if (value === undefined || value === null) {
+            continue;
+        }
+        for (const item of Array.isArray(value) ? value : [value]) {
+            url.searchParams.append(name, String(item));
+        }
+    }
+    // Aborts on the caller's signal or once the timeout passes, whichever comes first.
+    const signal = timeout === undefined ? options.signal : AbortSignal.any([AbortSignal.timeout(timeout), ...(options.signal ? [options.signal] : [])]);
+    const encoded = body instanceof FormData || body instanceof URLSearchParams || body instanceof Blob;
+    const requestInit: RequestInit = {
+        ...options,
+        method,
+        signal,
+        // Leaves the Content-Type of encoded bodies, a multipart boundary included, to them and fetch,
+        // and sends none without a body, which would make every cross-origin GET preflighted.
+        headers: encoded || body === undefined ? options.headers : { 'Content-Type': 'application/json', ...options.headers },
+        body: encoded || body === undefined ? body : JSON.stringify(body),
+    };
+    for (const hook of hooks.request) {
+        await hook(url, requestInit);
+    }
+    let response: Response;
+    try {
+        response = await fetch(url, requestInit);
+    } catch (error) {
+        throw await applyErrorHooks(hooks.error, error);
+    }
+    for (const hook of hooks.response) {
+        await hook(response);
+    }
+    const text = await response.text();
+    if (!response.ok) {
+        let data = text;
+        try {
+            data = JSON.parse(text);
+        } catch {
+            // Keeps bodies that are not JSON, such as proxy error pages, as text.
+        }
+        throw await applyErrorHooks(
+            hooks.error,
+            new ApiError(response.status, data, `${method} ${path} failed with status ${response.status}`),
+        );
+    }
+    return text ? JSON.parse(text) : undefined;
+}
+
+export interface ListPetsParams {
+    limit?: number;
+    status?: 'available' | 'sold';
+}
+
+export type ListPetsError = ApiError<number, Error>;
+
+/**
+ * List all pets
+ */
+export async function listPets(params?: ListPetsParams, config?: RequestOptions): Promise<Pet[]> {
+    return request<Pet[]>('GET', `/pets`, undefined, withAuth([['api_key']], { ...config, params: { ...config?.params, ...params } }));
+}
+
+export type CreatePetError = ApiError<400, Error>;
+
+/**
+ * Create a pet
+ */
+export async function createPet(data: NewPet, config?: RequestOptions): Promise<Pet> {
+    return request<Pet>('POST', `/pets`, data, withAuth([['api_key']], config));
+}
+
+export type ShowPetByIdError = ApiError<404, Error>;
+
+/**
+ * Info for a specific pet
+ */
+export async function showPetById(petId: string, config?: RequestOptions): Promise<Pet> {
+    return request<Pet>('GET', `/pets/${encodeURIComponent(petId)}`, undefined, withAuth([['api_key']], config));
+}
+
+/**
+ * @deprecated
+ */
+export async function deletePet(petId: string, config?: RequestOptions): Promise<void> {
+    return request<void>('DELETE', `/pets/${encodeURIComponent(petId)}`, undefined, withAuth([['api_key']], config));
+}
+
+/**
+ * Pet counts by status
+ */
+export async function getInventory(config?: RequestOptions): Promise<Record<string, unknown>> {
+    return request<Record<string, unknown>>('GET', `/store/inventory`, undefined, config);
+}
+
+// <custom>
+// </custom>
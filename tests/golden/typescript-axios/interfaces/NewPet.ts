@@ -0,0 +1,15 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+import type { Status } from './Status';
+
+export interface NewPet {
+    name: string;
+    tag?: string;
+    status?: Status;
+}
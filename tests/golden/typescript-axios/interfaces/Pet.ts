@@ -0,0 +1,13 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+import type { NewPet } from './NewPet';
+
+export interface Pet extends NewPet {
+    id: number;
+}
@@ -0,0 +1,100 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+import type { AxiosError, AxiosResponse } from 'axios';
+import { client } from './client';
+
+import { Error, NewPet, Pet, Status } from './interfaces';
+
+/** Options every service function takes after its parameters. */
+export interface RequestOptions {
+    /** Headers sent with the request, merged with the ones the operation sets. */
+    headers?: Record<string, string>;
+    /** Aborts the request when it fires. */
+    signal?: AbortSignal;
+    /** Milliseconds to wait for the response before the request is aborted. */
+    timeout?: number;
+    /** Query parameters sent with the request, merged with the operation's. */
+    params?: Record<string, unknown>;
+}
+
+/** An axios error whose response has the given status and body. */
+export type ResponseError<Status extends number, Body> = AxiosError<Body> & {
+    response: AxiosResponse<Body> & { status: Status };
+};
+
+export interface AuthConfig {
+    'api_key'?: string;
+}
+
+let auth: AuthConfig = {};
+
+export function setAuth(config: AuthConfig) {
+    auth = config;
+}
+
+function withAuth(requirements: (keyof AuthConfig)[][], config: any = {}): any {
+    const requirement = requirements.find(names => names.every(name => auth[name] !== undefined));
+    if (!requirement) {
+        return config;
+    }
+    const headers = { ...config.headers };
+    const params = { ...config.params };
+    for (const name of requirement) {
+        switch (name) {
+            case 'api_key':
+                headers['X-API-Key'] = auth['api_key'];
+                break;
+        }
+    }
+    return { ...config, headers, params };
+}
+
+export interface ListPetsParams {
+    limit?: number;
+    status?: 'available' | 'sold';
+}
+
+export type ListPetsError = ResponseError<number, Error>;
+
+/** List all pets */
+export async function listPets(params?: ListPetsParams, config?: RequestOptions): Promise<Pet[]> {
+    const response = await client.get(`/pets`, withAuth([['api_key']], { ...config, params: { ...config?.params, ...params } }));
+    return response.data;
+}
+
+export type CreatePetError = ResponseError<400, Error>;
+
+/** Create a pet */
+export async function createPet(data: NewPet, config?: RequestOptions): Promise<Pet> {
+    const response = await client.post(`/pets`, data, withAuth([['api_key']], config));
+    return response.data;
+}
+
+export type ShowPetByIdError = ResponseError<404, Error>;
+
+/** Info for a specific pet */
+export async function showPetById(petId: string, config?: RequestOptions): Promise<Pet> {
+    const response = await client.get(`/pets/${encodeURIComponent(petId)}`, withAuth([['api_key']], config));
+    return response.data;
+}
+
+/** @deprecated */
+export async function deletePet(petId: string, config?: RequestOptions): Promise<void> {
+    const response = await client.delete(`/pets/${encodeURIComponent(petId)}`, withAuth([['api_key']], config));
+    return response.data;
+}
+
+/** Pet counts by status */
+export async function getInventory(config?: RequestOptions): Promise<Record<string, unknown>> {
+    const response = await client.get(`/store/inventory`, config);
+    return response.data;
+}
+
+// <custom>
+// </custom>
@@ -0,0 +1,12 @@
+/*
+ * This file was generated by swagger-generator
+ * Do not modify this file manually.
+ * Version: 1.0.0
+ * Title: Petstore
+ * Description: A sample store of pets.
+ */
+
+export * from './Error';
+export * from './NewPet';
+export * from './Pet';
+export * from './Status';
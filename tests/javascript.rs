@@ -0,0 +1,76 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, GeneratedFiles, Generator};
+
+/// An operation taking a hyphenated header and a required query parameter, returning a model with
+/// a hyphenated property.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "X-Trace", "in": "header", "type": "string", "description": "Traces the request" },
+                    { "name": "limit", "in": "query", "type": "integer", "required": true }
+                ],
+                "responses": { "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name"],
+            "properties": { "name": { "type": "string" }, "birth-date": { "type": "string" } }
+        }
+    }
+}"##;
+
+fn generate() -> GeneratedFiles {
+    Generator::new(parse_spec(PETS_SPEC, None).unwrap())
+        .language(Language::JavaScript)
+        .timestamp(false)
+        .generate()
+        .unwrap()
+}
+
+#[test]
+fn request_helper_encodes_only_json_bodies_and_leaves_other_content_types() {
+    let service = &generate()[Path::new("service.js")];
+    assert!(service.contains("async function request(method, path, body, init = {}) {\n"), "{}", service);
+    assert!(
+        service.contains(
+            "    const encoded = body instanceof FormData || body instanceof URLSearchParams || body instanceof Blob;\n"
+        ),
+        "{}",
+        service
+    );
+    assert!(
+        service.contains(
+            "        headers: encoded || body === undefined ? options.headers : \
+             { 'Content-Type': 'application/json', ...options.headers },\n        \
+             body: encoded || body === undefined ? body : JSON.stringify(body),\n"
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn typedefs_quote_names_that_are_not_identifiers() {
+    let files = generate();
+    let types = &files[Path::new("types.js")];
+    assert!(
+        types.contains(" * @typedef {Object} Pet\n * @property {string} name\n * @property {string} ['birth-date']\n"),
+        "{}",
+        types
+    );
+
+    let service = &files[Path::new("service.js")];
+    assert!(service.contains(" * @typedef {Object} ListPetsParams\n * @property {number} limit\n"), "{}", service);
+    assert!(service.contains(" * @property {string} ['X-Trace'] - Traces the request\n"), "{}", service);
+    assert!(service.contains("/** @typedef {import('./types.js').Pet} Pet */\n"), "{}", service);
+}
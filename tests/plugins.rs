@@ -0,0 +1,74 @@
+use std::path::{Path, PathBuf};
+use swagger_generator::cli::Language;
+use swagger_generator::error::Result;
+use swagger_generator::plugin::{ExternalPlugin, Plugin};
+use swagger_generator::spec::Swagger;
+use swagger_generator::{parse_spec, GeneratedFiles, Generator, GeneratorError};
+
+const SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": { "/pets": { "get": { "operationId": "listPets", "responses": { "200": { "description": "ok" } } } } }
+}"##;
+
+/// Adds a file listing the operations of the document next to the generated ones.
+#[derive(Debug)]
+struct Operations;
+
+impl Plugin for Operations {
+    fn name(&self) -> &str {
+        "operations"
+    }
+
+    fn run(&self, swagger: &Swagger, language: Language, mut files: GeneratedFiles) -> Result<GeneratedFiles> {
+        let operations = swagger.paths.values().flat_map(|path_item| path_item.operations());
+        let ids: Vec<String> = operations.filter_map(|(_, operation)| operation.operation_id.clone()).collect();
+        files.insert(PathBuf::from("operations.txt"), format!("{:?}: {}", language, ids.join(", ")));
+        Ok(files)
+    }
+}
+
+fn generator() -> Generator {
+    Generator::new(parse_spec(SPEC, None).unwrap()).language(Language::TypeScript).timestamp(false)
+}
+
+#[test]
+fn plugins_see_the_document_and_change_the_files() {
+    let files = generator().plugin(Operations).generate().unwrap();
+    assert_eq!(files[Path::new("operations.txt")], "TypeScript: listPets");
+    assert!(files.contains_key(Path::new("service.ts")));
+}
+
+/// A program in a temporary directory running `script` with `sh`.
+#[cfg(unix)]
+fn external_plugin(name: &str, script: &str) -> ExternalPlugin {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("swagger-generator-plugins-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let program = dir.join(name);
+    std::fs::write(&program, format!("#!/bin/sh\n{}\n", script)).unwrap();
+    std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+    ExternalPlugin::new(&program.to_string_lossy())
+}
+
+#[cfg(unix)]
+#[test]
+fn external_plugins_reply_with_the_files_to_write() {
+    let plugin = external_plugin(
+        "replace",
+        r#"case "$(cat)" in '{"version":1,"language":"typescript",'*) ;; *) exit 3 ;; esac
+echo '{ "files": { "README.md": "replaced" } }'"#,
+    );
+    let files = generator().plugin(plugin).generate().unwrap();
+    assert_eq!(files.keys().collect::<Vec<_>>(), [Path::new("README.md")]);
+
+    let failing = external_plugin("fail", "exit 2");
+    let error = generator().plugin(failing).generate().unwrap_err();
+    assert!(matches!(error, GeneratorError::Plugin { ref message, .. } if message.contains("exit status: 2")));
+    assert_eq!(error.exit_code(), 69);
+
+    let escaping = external_plugin("escape", r#"echo '{ "files": { "../outside.ts": "" } }'"#);
+    let error = generator().plugin(escaping).generate().unwrap_err();
+    assert!(error.to_string().contains("../outside.ts is outside the output directory"), "{}", error);
+}
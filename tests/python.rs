@@ -0,0 +1,288 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, Generator};
+
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "limit", "in": "query", "type": "integer" },
+                    { "name": "X-Trace", "in": "header", "type": "string", "required": true }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/login": {
+            "post": {
+                "operationId": "login",
+                "consumes": ["application/x-www-form-urlencoded"],
+                "parameters": [{ "name": "user", "in": "formData", "type": "string", "required": true }],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "allOf": [
+                { "$ref": "#/definitions/Animal" },
+                { "type": "object", "properties": { "status": { "$ref": "#/definitions/Status" } } }
+            ]
+        },
+        "Animal": { "type": "object", "required": ["id"], "properties": { "id": { "type": "integer" } } },
+        "Status": { "type": "string", "enum": ["available", "sold"] }
+    }
+}"##;
+
+/// An OpenAPI 3 upload whose file is a binary string field of a `multipart/form-data` body.
+const MULTIPART_SPEC: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Pets", "version": "1.0" },
+    "servers": [{ "url": "https://api.example.com" }],
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["photo"],
+                                "properties": {
+                                    "caption": { "type": "string" },
+                                    "photo": { "type": "string", "format": "binary" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+fn service() -> String {
+    let files = Generator::new(parse_spec(PETS_SPEC, None).unwrap())
+        .language(Language::Python)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    files[Path::new("service.py")].clone()
+}
+
+#[test]
+fn enums_become_enum_classes() {
+    let service = service();
+    assert!(
+        service.contains("class Status(str, Enum):\n    AVAILABLE = \"available\"\n    SOLD = \"sold\"\n"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn enum_members_are_unique() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {},
+        "definitions": {
+            "Mode": { "type": "string", "enum": ["", "a-b", "a_b", "1st"] },
+            "Level": { "type": "integer", "enum": [-1, 1] }
+        }
+    }"#;
+    let files =
+        Generator::new(parse_spec(spec, None).unwrap()).language(Language::Python).timestamp(false).generate().unwrap();
+    let service = &files[Path::new("service.py")];
+    let mode =
+        "class Mode(str, Enum):\n    VALUE = \"\"\n    A_B = \"a-b\"\n    A_B2 = \"a_b\"\n    VALUE_1ST = \"1st\"\n";
+    assert!(service.contains(mode), "{}", service);
+    assert!(service.contains("class Level(int, Enum):\n    NEG_1 = -1\n    VALUE_1 = 1\n"), "{}", service);
+}
+
+#[test]
+fn all_of_subclasses_its_bases_defined_first() {
+    let service = service();
+    let base = service.find("class Animal:").expect("Animal is generated");
+    let class = service.find("@dataclass\nclass Pet(Animal):\n    status: Optional[Status] = None\n");
+    assert!(class.is_some_and(|class| base < class), "{}", service);
+    assert!(service.contains("            id=data.get(\"id\"),\n            status=_parse("), "{}", service);
+}
+
+#[test]
+fn query_and_header_parameters_are_keyword_arguments() {
+    let service = service();
+    assert!(
+        service.contains("def list_pets(*, x_trace: str, limit: Optional[int] = None, **kwargs: Any) -> Any:"),
+        "{}",
+        service
+    );
+    assert!(
+        service.contains("**_with_params(kwargs, params={\"limit\": limit}, headers={\"X-Trace\": x_trace}))"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn form_parameters_are_sent_as_form_data() {
+    let service = service();
+    assert!(
+        service.contains("_request(\"post\", \"/login\", **_with_params(kwargs, data={\"user\": user}))"),
+        "{}",
+        service
+    );
+    assert!(!service.contains("json="), "{}", service);
+}
+
+#[test]
+fn responses_and_bodies_are_typed() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "host": "api.example.com",
+        "basePath": "/v1/",
+        "paths": {
+            "/pets": {
+                "get": {
+                    "operationId": "listPets",
+                    "responses": { "200": { "description": "ok", "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } } } }
+                },
+                "post": {
+                    "operationId": "createPet",
+                    "parameters": [{ "name": "pet", "in": "body", "required": true, "schema": { "$ref": "#/definitions/NewPet" } }],
+                    "responses": { "201": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } } }
+                }
+            }
+        },
+        "definitions": {
+            "NewPet": { "type": "object", "properties": { "name": { "type": "string" } } },
+            "Pet": { "type": "object", "properties": { "id": { "type": "integer" } } }
+        }
+    }"##;
+    let files =
+        Generator::new(parse_spec(spec, None).unwrap()).language(Language::Python).timestamp(false).generate().unwrap();
+    let service = &files[Path::new("service.py")];
+    assert!(service.contains("BASE_URL = \"https://api.example.com/v1\"\n"), "{}", service);
+    assert!(service.contains("def list_pets(**kwargs: Any) -> List[Pet]:\n    return _parse(Pet, "), "{}", service);
+    assert!(service.contains("def create_pet(data: NewPet, **kwargs: Any) -> Pet:\n"), "{}", service);
+}
+
+#[test]
+fn service_functions_render_from_the_templates_directory() {
+    let dir = std::env::temp_dir().join(format!("swagger-generator-templates-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("python")).unwrap();
+    std::fs::write(
+        dir.join("python/method.hbs"),
+        "def {{name}}({{arguments}}) -> {{return_type}}:\n    log(\"{{name}}\")\n    return {{request}}\n",
+    )
+    .unwrap();
+    let files = Generator::new(parse_spec(PETS_SPEC, None).unwrap())
+        .language(Language::Python)
+        .templates(Some(dir.clone()))
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    let service = &files[Path::new("service.py")];
+    let login = "def login(*, user: str, **kwargs: Any) -> Any:\n    log(\"login\")\n    return _request(\"post\", \"/login\", ";
+    assert!(service.contains(login), "{}", service);
+}
+
+#[test]
+fn binary_form_fields_of_openapi_3_are_uploaded_as_files() {
+    let files = Generator::new(parse_spec(MULTIPART_SPEC, None).unwrap())
+        .language(Language::Python)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    let service = &files[Path::new("service.py")];
+    assert!(
+        service.contains("data={\"caption\": caption}, files={\"photo\": photo}"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn path_parameters_are_typed_and_quoted() {
+    let without_path_parameters = service();
+    assert!(!without_path_parameters.contains("urllib"), "{}", without_path_parameters);
+
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets/{petId}/photos/{name}": {
+                "get": {
+                    "operationId": "getPhoto",
+                    "parameters": [
+                        { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" },
+                        { "name": "name", "in": "path", "required": true, "type": "string" }
+                    ],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"##;
+    let files =
+        Generator::new(parse_spec(spec, None).unwrap()).language(Language::Python).timestamp(false).generate().unwrap();
+    let service = &files[Path::new("service.py")];
+    assert!(service.contains("import urllib.parse\n\nimport requests\n"), "{}", service);
+    assert!(service.contains("def get_photo(petId: int, name: str, **kwargs: Any) -> Any:\n"), "{}", service);
+    assert!(
+        service.contains(
+            "f\"/pets/{urllib.parse.quote(str(petId), safe='')}/photos/{urllib.parse.quote(str(name), safe='')}\""
+        ),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn required_nullable_fields_are_optional_without_a_default() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {},
+        "definitions": {
+            "Pet": {
+                "type": "object",
+                "required": ["name", "tag"],
+                "properties": { "name": { "type": "string" }, "tag": { "type": "string", "x-nullable": true } }
+            }
+        }
+    }"##;
+    let files =
+        Generator::new(parse_spec(spec, None).unwrap()).language(Language::Python).timestamp(false).generate().unwrap();
+    let service = &files[Path::new("service.py")];
+    assert!(service.contains("class Pet:\n    name: str\n    tag: Optional[str]\n"), "{}", service);
+    assert!(service.contains("if value is not None or key in (\"tag\",)}\n"), "{}", service);
+}
+
+#[test]
+fn retries_wait_for_a_retry_after_date() {
+    let files = Generator::new(parse_spec(PETS_SPEC, None).unwrap())
+        .language(Language::Python)
+        .retries(Some(2))
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    let service = &files[Path::new("service.py")];
+    assert!(service.contains("import datetime\nimport email.utils\nimport time\n"), "{}", service);
+    assert!(
+        service.contains(
+            "        date = email.utils.parsedate_to_datetime(retry_after or \"\")\n    \
+             except (TypeError, ValueError):\n        return 0.5 * 2**attempt\n    \
+             return max((date - datetime.datetime.now(datetime.timezone.utc)).total_seconds(), 0.0)\n"
+        ),
+        "{}",
+        service
+    );
+}
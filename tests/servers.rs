@@ -0,0 +1,315 @@
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::{parse_spec, Generator};
+
+/// Operations taking array query parameters, optional and required bodies, and URL-encoded and
+/// multipart form fields.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "findPets",
+                "parameters": [
+                    {
+                        "name": "tags",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "multi",
+                        "items": { "type": "string" }
+                    },
+                    {
+                        "name": "ids",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "multi",
+                        "required": true,
+                        "items": { "type": "integer", "format": "int64" }
+                    }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [{ "name": "body", "in": "body", "schema": { "$ref": "#/definitions/Pet" } }],
+                "responses": { "201": { "description": "created" } }
+            },
+            "put": {
+                "operationId": "replacePet",
+                "parameters": [
+                    { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/login": {
+            "post": {
+                "operationId": "login",
+                "consumes": ["application/x-www-form-urlencoded"],
+                "parameters": [
+                    { "name": "user", "in": "formData", "type": "string", "required": true },
+                    { "name": "scopes", "in": "formData", "type": "array", "items": { "type": "string" } }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "consumes": ["multipart/form-data"],
+                "parameters": [
+                    { "name": "caption", "in": "formData", "type": "string" },
+                    { "name": "file", "in": "formData", "type": "file", "required": true }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "definitions": { "Pet": { "type": "object", "properties": { "name": { "type": "string" } } } }
+}"##;
+
+fn generate_spec(spec: &str, language: Language, file: &str) -> String {
+    let files = Generator::new(parse_spec(spec, None).unwrap()).language(language).timestamp(false).generate().unwrap();
+    files[Path::new(file)].clone()
+}
+
+fn generate(language: Language, file: &str) -> String {
+    generate_spec(PETS_SPEC, language, file)
+}
+
+#[test]
+fn axum_server_reads_repeated_query_parameters_as_vectors() {
+    let lib = generate(Language::Axum, "src/lib.rs");
+    assert!(lib.contains("    pub tags: Option<Vec<String>>,\n    pub ids: Vec<i64>,\n"), "{}", lib);
+    assert!(
+        lib.contains("axum_extra::extract::Query(query): axum_extra::extract::Query<FindPetsQuery>"),
+        "{}",
+        lib
+    );
+
+    let manifest = generate(Language::Axum, "Cargo.toml");
+    assert!(manifest.contains("axum = { version = \"0.8\", features = [\"multipart\"] }\n"), "{}", manifest);
+    assert!(manifest.contains("features = [\"form\", \"query\"] }\n"), "{}", manifest);
+}
+
+#[test]
+fn axum_server_extracts_form_fields_and_optional_bodies() {
+    let lib = generate(Language::Axum, "src/lib.rs");
+    assert!(
+        lib.contains("pub struct LoginForm {\n    pub user: String,\n    pub scopes: Option<Vec<String>>,\n"),
+        "{}",
+        lib
+    );
+    assert!(lib.contains("fn login(&self, form: LoginForm)"), "{}", lib);
+    assert!(lib.contains("axum_extra::extract::Form(form): axum_extra::extract::Form<LoginForm>"), "{}", lib);
+    assert!(lib.contains("    /// The `multipart` body holds the fields `caption`, `file`.\n"), "{}", lib);
+    assert!(lib.contains("fn upload_photo(&self, multipart: extract::Multipart)"), "{}", lib);
+
+    assert!(lib.contains("fn create_pet(&self, body: Option<Pet>)"), "{}", lib);
+    assert!(lib.contains("body: Option<Json<Pet>>) -> CreatePetResponse {\n"), "{}", lib);
+    assert!(lib.contains("    api.create_pet(body.map(|Json(body)| body)).await\n"), "{}", lib);
+    assert!(lib.contains("fn replace_pet(&self, body: Pet)"), "{}", lib);
+    assert!(lib.contains("Json(body): Json<Pet>) -> ReplacePetResponse {\n"), "{}", lib);
+}
+
+#[test]
+fn actix_server_parses_repeated_query_parameters_and_forms() {
+    let lib = generate(Language::ActixWeb, "src/lib.rs");
+    assert!(lib.contains("    pub tags: Option<Vec<String>>,\n    pub ids: Vec<i64>,\n"), "{}", lib);
+    assert!(
+        lib.contains(
+            "request: HttpRequest) -> actix_web::Result<FindPetsResponse> {
+    let query = serde_html_form::from_str(request.query_string()).map_err(ErrorBadRequest)?;
+    Ok(api.find_pets(query).await)
+}"
+        ),
+        "{}",
+        lib
+    );
+    assert!(
+        lib.contains(
+            "form: web::Bytes) -> actix_web::Result<LoginResponse> {
+    let form = serde_html_form::from_bytes(&form).map_err(ErrorBadRequest)?;
+    Ok(api.login(form).await)
+}"
+        ),
+        "{}",
+        lib
+    );
+    assert!(lib.contains("fn upload_photo(&self, multipart: actix_multipart::Multipart)"), "{}", lib);
+
+    let manifest = generate(Language::ActixWeb, "Cargo.toml");
+    assert!(manifest.contains("actix-multipart = \"0.7\"\nserde_html_form = \"0.2\"\n"), "{}", manifest);
+}
+
+#[test]
+fn actix_server_extracts_optional_bodies() {
+    let lib = generate(Language::ActixWeb, "src/lib.rs");
+    assert!(lib.contains("fn create_pet(&self, body: Option<Pet>)"), "{}", lib);
+    assert!(lib.contains("body: Option<web::Json<Pet>>) -> CreatePetResponse {\n"), "{}", lib);
+    assert!(lib.contains("    api.create_pet(body.map(web::Json::into_inner)).await\n"), "{}", lib);
+    assert!(lib.contains("body: web::Json<Pet>) -> ReplacePetResponse {\n"), "{}", lib);
+}
+
+/// An operation taking an array query parameter joined by commas, the default, and one joined by
+/// spaces.
+const DELIMITED_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "findPets",
+                "parameters": [
+                    { "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } },
+                    {
+                        "name": "sizes",
+                        "in": "query",
+                        "type": "array",
+                        "required": true,
+                        "collectionFormat": "ssv",
+                        "items": { "type": "integer", "format": "int32" }
+                    }
+                ],
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+#[test]
+fn rust_servers_split_query_parameters_by_their_collection_format() {
+    for language in [Language::Axum, Language::ActixWeb] {
+        let lib = generate_spec(DELIMITED_SPEC, language, "src/lib.rs");
+        assert!(
+            lib.contains(
+                "    #[serde(default, deserialize_with = \"delimited_option::<',', _, _>\")]
+    pub tags: Option<Vec<String>>,
+    #[serde(deserialize_with = \"delimited::<' ', _, _>\")]
+    pub sizes: Vec<i32>,
+"
+            ),
+            "{}",
+            lib
+        );
+        assert!(lib.contains("fn delimited<'de, const DELIMITER: char, D, T>(deserializer: D)"), "{}", lib);
+    }
+    assert!(!generate(Language::Axum, "src/lib.rs").contains("fn delimited"));
+}
+
+#[test]
+fn typescript_servers_split_query_parameters_by_their_collection_format() {
+    let server = generate_spec(DELIMITED_SPEC, Language::Express, "server.ts");
+    assert!(
+        server.contains("{ name: 'tags', in: 'query', type: 'array', items: 'string', delimiter: ',', required: false },"),
+        "{}",
+        server
+    );
+    assert!(server.contains("items: 'integer', delimiter: ' ', required: true },"), "{}", server);
+    assert!(!generate(Language::Express, "server.ts").contains("delimiter: ','"));
+
+    let dto = generate_spec(DELIMITED_SPEC, Language::Nest, "dto.ts");
+    assert!(dto.contains("(Array.isArray(value) ? value : String(value).split(','))))\n"), "{}", dto);
+    assert!(dto.contains("(Array.isArray(value) ? value : String(value).split(' ')).map("), "{}", dto);
+}
+
+#[test]
+fn fastapi_routes_split_query_parameters_by_their_collection_format() {
+    let routers = generate_spec(DELIMITED_SPEC, Language::FastApi, "routers.py");
+    assert!(
+        routers.contains(
+            "    def find_pets(sizes: Annotated[str, Query()], tags: Annotated[Optional[str], Query()] = None) -> None:
+        return api.find_pets(_split(sizes, \" \", int), _split(tags, \",\", str))
+"
+        ),
+        "{}",
+        routers
+    );
+    assert!(routers.contains("    def find_pets(self, sizes: List[int], tags: Optional[List[str]] = None) -> None:"));
+    assert!(routers.contains("raise HTTPException(status_code=422, detail=str(error)) from error"), "{}", routers);
+}
+
+/// An OpenAPI 3 operation taking header, cookie and form parameters, an upload, and a model with a
+/// required field that may be null.
+const FASTAPI_SPEC: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {
+        "/pets": {
+            "post": {
+                "operationId": "createPet",
+                "parameters": [
+                    { "name": "X-Trace", "in": "header", "schema": { "type": "string" } },
+                    { "name": "session", "in": "cookie", "required": true, "schema": { "type": "string" } }
+                ],
+                "requestBody": {
+                    "content": {
+                        "application/x-www-form-urlencoded": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["name"],
+                                "properties": { "name": { "type": "string" }, "tag": { "type": "string" } }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        },
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["photo"],
+                                "properties": { "photo": { "type": "string", "format": "binary" } }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    },
+    "components": {
+        "schemas": {
+            "Pet": {
+                "type": "object",
+                "required": ["name", "tag"],
+                "properties": { "name": { "type": "string" }, "tag": { "type": "string", "nullable": true } }
+            }
+        }
+    }
+}"##;
+
+#[test]
+fn fastapi_routes_take_header_cookie_and_form_parameters() {
+    let routers = generate_spec(FASTAPI_SPEC, Language::FastApi, "routers.py");
+    assert!(
+        routers.contains("from fastapi import APIRouter, Cookie, FastAPI, File, Form, Header, UploadFile\n"),
+        "{}",
+        routers
+    );
+    assert!(
+        routers.contains(
+            "    def create_pet(session: Annotated[str, Cookie()], name: Annotated[str, Form()], \
+             x_trace: Annotated[Optional[str], Header(alias=\"X-Trace\")] = None, \
+             tag: Annotated[Optional[str], Form()] = None) -> None:\n        \
+             return api.create_pet(session, name, x_trace, tag)\n"
+        ),
+        "{}",
+        routers
+    );
+    assert!(routers.contains("def upload_photo(photo: Annotated[UploadFile, File()]) -> None:"), "{}", routers);
+    assert!(routers.contains("    def upload_photo(self, photo: UploadFile) -> None:\n"), "{}", routers);
+}
+
+#[test]
+fn fastapi_models_keep_nullable_fields_required() {
+    let models = generate_spec(FASTAPI_SPEC, Language::FastApi, "models.py");
+    assert!(models.contains("class Pet(BaseModel):\n    name: str\n    tag: Optional[str]\n"), "{}", models);
+}
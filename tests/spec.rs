@@ -0,0 +1,98 @@
+use swagger_generator::{parse_spec, GeneratorError};
+
+#[test]
+fn parameter_references_leading_nowhere_are_spec_errors() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "parameters": { "limit": { "name": "limit", "in": "query", "type": "integer" } },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "parameters": [{ "$ref": "#/parameters/limit" }, { "$ref": "#/parameters/offset" }],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"##;
+    let error = parse_spec(spec, None).unwrap_err();
+    assert!(matches!(error, GeneratorError::Spec { ref path, .. } if path == "paths./pets.get.parameters"), "{}", error);
+    assert!(error.to_string().ends_with("the parameter #/parameters/offset is not defined"), "{}", error);
+    assert_eq!(error.exit_code(), 65);
+
+    let resolved = spec.replace("#/parameters/offset", "#/parameters/limit");
+    assert!(parse_spec(&resolved, None).is_ok());
+}
+
+#[test]
+fn references_leading_nowhere_are_spec_errors() {
+    let spec = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "responses": {
+                        "200": {
+                            "description": "ok",
+                            "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Nope" } } }
+                        }
+                    }
+                }
+            }
+        },
+        "components": {
+            "schemas": {
+                "Pet": { "type": "object", "example": { "$ref": "#/components/schemas/Nope" } }
+            }
+        }
+    }"##;
+    let error = parse_spec(spec, None).unwrap_err();
+    let path = "paths./pets.get.responses.200.content.application/json.schema";
+    assert!(matches!(error, GeneratorError::Spec { path: ref at, .. } if at == path), "{}", error);
+    assert!(error.to_string().contains("#/components/schemas/Nope does not resolve"), "{}", error);
+    assert_eq!(error.exit_code(), 65);
+
+    let resolved = spec.replacen("#/components/schemas/Nope", "#/components/schemas/Pet", 1);
+    assert!(parse_spec(&resolved, None).is_ok());
+}
+
+#[test]
+fn openapi_3_security_schemes_become_security_definitions() {
+    let spec = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Pets", "version": "1.0" },
+        "security": [{ "token": [] }],
+        "paths": {
+            "/pets": {
+                "get": { "security": [{ "session": [] }], "responses": { "200": { "description": "ok" } } }
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "token": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+                "login": { "type": "http", "scheme": "basic" },
+                "session": { "type": "apiKey", "in": "cookie", "name": "SESSION" }
+            }
+        }
+    }"##;
+    let swagger = parse_spec(spec, None).unwrap();
+    let schemes: Vec<_> = swagger
+        .security_definitions
+        .iter()
+        .map(|(name, scheme)| {
+            (name.as_str(), scheme.scheme_type.as_str(), scheme.location.as_deref(), scheme.name.as_deref())
+        })
+        .collect();
+    assert_eq!(
+        schemes,
+        [
+            ("token", "bearer", None, None),
+            ("login", "basic", None, None),
+            ("session", "apiKey", Some("cookie"), Some("SESSION"))
+        ]
+    );
+    assert!(swagger.security[0].contains_key("token"));
+    let operation = swagger.paths["/pets"].get.as_ref().unwrap();
+    assert!(operation.security.as_ref().unwrap()[0].contains_key("session"));
+}
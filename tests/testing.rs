@@ -0,0 +1,149 @@
+use serde_json::json;
+use std::path::Path;
+use swagger_generator::cli::Language;
+use swagger_generator::generators::http::query_texts;
+use swagger_generator::spec::Parameter;
+use swagger_generator::{parse_spec, Generator};
+
+/// A list filtered by array query parameters, a create taking a model and a get by an integer ID.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "basePath": "/v1",
+    "schemes": ["https"],
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } },
+                    {
+                        "name": "ids",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "multi",
+                        "items": { "type": "integer" }
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "ok",
+                        "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } }
+                    }
+                }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [
+                    { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                ],
+                "responses": { "201": { "description": "created", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": {
+                    "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } },
+                    "404": { "description": "missing", "schema": { "$ref": "#/definitions/Error" } }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string", "minLength": 3 },
+                "kind": { "type": "string", "enum": ["cat", "dog"] }
+            }
+        },
+        "Error": { "type": "object", "properties": { "message": { "type": "string" } } }
+    }
+}"##;
+
+fn generate(language: Language, file: &str) -> String {
+    let generator = Generator::new(parse_spec(PETS_SPEC, None).unwrap()).language(language);
+    let files = generator.timestamp(false).generate().unwrap();
+    files[Path::new(file)].clone()
+}
+
+#[test]
+fn mock_server_answers_every_operation_under_the_base_path() {
+    let server = generate(Language::Mock, "server.js");
+    assert!(server.contains("const BASE_PATH = '/v1';\n"), "{}", server);
+    assert!(server.contains("app.use(BASE_PATH || '/', router);\n"), "{}", server);
+    assert!(server.contains("router.get('/pets', (req, res) => {\n    res.status(200).json([\n"), "{}", server);
+    assert!(server.contains("router.post('/pets', (req, res) => {\n    res.status(201).json({\n"), "{}", server);
+    assert!(server.contains("router.get('/pets/:petId', (req, res) => {\n"), "{}", server);
+
+    let package = generate(Language::Mock, "package.json");
+    assert!(package.contains("\"express\""), "{}", package);
+}
+
+#[test]
+fn http_requests_fill_in_examples_and_join_query_arrays_by_their_collection_format() {
+    let requests = generate(Language::Http, "requests.http");
+    assert!(requests.contains("@baseUrl = https://api.example.com/v1\n"), "{}", requests);
+    assert!(requests.contains("# @name listPets\nGET {{baseUrl}}/pets\n    ?tags=string\n    &ids=0\n"), "{}", requests);
+    assert!(
+        requests.contains(
+            "# @name createPet\nPOST {{baseUrl}}/pets\nContent-Type: application/json\n\n{\n    \"id\": 0,\n"
+        ),
+        "{}",
+        requests
+    );
+    assert!(requests.contains("# @name getPet\nGET {{baseUrl}}/pets/0\n"), "{}", requests);
+
+    let parameter = |collection_format: Option<&str>| -> Parameter {
+        let parameter = json!({ "name": "tags", "in": "query", "type": "array", "collectionFormat": collection_format });
+        serde_json::from_value(parameter).unwrap()
+    };
+    let tags = json!(["a", "b"]);
+    assert_eq!(query_texts(&parameter(None), &tags), ["a,b"]);
+    assert_eq!(query_texts(&parameter(Some("pipes")), &tags), ["a|b"]);
+    assert_eq!(query_texts(&parameter(Some("ssv")), &tags), ["a b"]);
+    assert_eq!(query_texts(&parameter(Some("multi")), &tags), ["a", "b"]);
+    assert_eq!(query_texts(&parameter(None), &json!(3)), ["3"]);
+}
+
+#[test]
+fn k6_script_checks_the_success_status_of_every_operation() {
+    let script = generate(Language::K6, "script.js");
+    assert!(script.contains("const BASE_URL = __ENV.BASE_URL || 'https://api.example.com/v1';\n"), "{}", script);
+    assert!(script.contains("exec: 'defaultScenario', vus: VUS, duration: DURATION }"), "{}", script);
+    assert!(script.contains("http.request('GET', `${BASE_URL}/pets?tags=string&ids=0`, null, {\n"), "{}", script);
+    assert!(script.contains("headers: { 'Content-Type': 'application/json' },\n"), "{}", script);
+    assert!(script.contains("check(res, { 'createPet answers 201': (r) => r.status === 201 });\n"), "{}", script);
+    assert!(script.contains("check(res, { 'getPet answers 200': (r) => r.status === 200 });\n"), "{}", script);
+}
+
+#[test]
+fn pact_tests_match_responses_by_type() {
+    let pact = generate(Language::Pact, "default.pact.test.ts");
+    assert!(pact.contains("const { eachLike, integer, regex, string } = MatchersV3;\n"), "{}", pact);
+    assert!(pact.contains("path: '/v1/pets',\n                query: { tags: ['string'], ids: ['0'] },\n"), "{}", pact);
+    assert!(
+        pact.contains(
+            "                body: eachLike({
+                    id: integer(0),
+                    name: string(\"string\"),
+                    kind: regex('^(cat|dog)$', \"cat\"),
+                }),
+"
+        ),
+        "{}",
+        pact
+    );
+    assert!(pact.contains("const response = await fetch(`${mockServer.url}/v1/pets/0`, {\n"), "{}", pact);
+    assert!(pact.contains("expect(response.status).toBe(201);\n"), "{}", pact);
+
+    let package = generate(Language::Pact, "package.json");
+    assert!(package.contains("\"@pact-foundation/pact\""), "{}", package);
+}
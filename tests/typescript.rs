@@ -0,0 +1,716 @@
+use std::path::Path;
+use swagger_generator::cli::{DateType, EnumStyle, HttpClient, Int64Type, Language};
+use swagger_generator::{parse_spec, GeneratedFiles, Generator};
+
+/// A document with a definition named `Error`, which shadows the global `Error` class in the
+/// files importing it.
+const ERROR_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Errors", "version": "1.0" },
+    "host": "api.example.com",
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "responses": {
+                    "200": { "description": "ok", "schema": { "type": "array", "items": { "type": "string" } } },
+                    "default": { "description": "error", "schema": { "$ref": "#/definitions/Error" } }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "Error": {
+            "type": "object",
+            "properties": { "code": { "type": "integer" }, "message": { "type": "string" } }
+        }
+    }
+}"##;
+
+/// An OpenAPI 3 upload whose file is a binary string field of a `multipart/form-data` body.
+const MULTIPART_SPEC: &str = r##"{
+    "openapi": "3.0.3",
+    "info": { "title": "Pets", "version": "1.0" },
+    "servers": [{ "url": "https://api.example.com" }],
+    "paths": {
+        "/photos": {
+            "post": {
+                "operationId": "uploadPhoto",
+                "requestBody": {
+                    "content": {
+                        "multipart/form-data": {
+                            "schema": {
+                                "type": "object",
+                                "required": ["photo"],
+                                "properties": {
+                                    "caption": { "type": "string" },
+                                    "photo": { "type": "string", "format": "binary" }
+                                }
+                            }
+                        }
+                    }
+                },
+                "responses": { "200": { "description": "ok" } }
+            }
+        }
+    }
+}"##;
+
+/// A list filtered by array query parameters, a create taking a model and a get by an integer ID.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "host": "api.example.com",
+    "basePath": "/v1",
+    "schemes": ["https"],
+    "paths": {
+        "/pets": {
+            "get": {
+                "operationId": "listPets",
+                "parameters": [
+                    { "name": "tags", "in": "query", "type": "array", "items": { "type": "string" } },
+                    {
+                        "name": "ids",
+                        "in": "query",
+                        "type": "array",
+                        "collectionFormat": "multi",
+                        "items": { "type": "integer" }
+                    }
+                ],
+                "responses": {
+                    "200": {
+                        "description": "ok",
+                        "schema": { "type": "array", "items": { "$ref": "#/definitions/Pet" } }
+                    }
+                }
+            },
+            "post": {
+                "operationId": "createPet",
+                "parameters": [
+                    { "name": "body", "in": "body", "required": true, "schema": { "$ref": "#/definitions/Pet" } }
+                ],
+                "responses": { "201": { "description": "created", "schema": { "$ref": "#/definitions/Pet" } } }
+            }
+        },
+        "/pets/{petId}": {
+            "get": {
+                "operationId": "getPet",
+                "parameters": [
+                    { "name": "petId", "in": "path", "required": true, "type": "integer", "format": "int64" }
+                ],
+                "responses": {
+                    "200": { "description": "ok", "schema": { "$ref": "#/definitions/Pet" } },
+                    "404": { "description": "missing", "schema": { "$ref": "#/definitions/Error" } }
+                }
+            }
+        }
+    },
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64" },
+                "name": { "type": "string", "minLength": 3 },
+                "kind": { "type": "string", "enum": ["cat", "dog"] }
+            }
+        },
+        "Error": { "type": "object", "properties": { "message": { "type": "string" } } }
+    }
+}"##;
+
+
+fn generate(spec: &str, http_client: HttpClient) -> GeneratedFiles {
+    Generator::new(parse_spec(spec, None).unwrap())
+        .language(Language::TypeScript)
+        .http_client(http_client)
+        .timestamp(false)
+        .generate()
+        .unwrap()
+}
+
+#[test]
+fn fetch_service_does_not_use_an_imported_error_as_a_value() {
+    let files = generate(ERROR_SPEC, HttpClient::Fetch);
+    let service = &files[Path::new("service.ts")];
+    assert!(service.contains("import { Error } from './interfaces';"), "{}", service);
+    assert!(service.contains("extends globalThis.Error {"), "{}", service);
+    for value in ["extends Error", "new Error(", "instanceof Error"] {
+        assert!(!service.contains(value), "service.ts uses the `Error` interface as a value: {}", value);
+    }
+}
+
+#[test]
+fn single_file_imports_each_module_once() {
+    for http_client in [HttpClient::Axios, HttpClient::Fetch] {
+        let files = Generator::new(parse_spec(ERROR_SPEC, None).unwrap())
+            .http_client(http_client)
+            .single_file(true)
+            .timestamp(false)
+            .generate()
+            .unwrap();
+        let api = &files[Path::new("api.ts")];
+        let imports: Vec<&str> = api.lines().filter(|line| line.starts_with("import ")).collect();
+        let mut modules: Vec<&str> =
+            imports.iter().filter_map(|line| line.rsplit_once(" from ")).map(|(_, m)| m).collect();
+        modules.sort();
+        modules.dedup();
+        assert_eq!(modules.len(), imports.len(), "{:?}", imports);
+
+        let mut names: Vec<&str> = imports
+            .iter()
+            .filter_map(|line| line.split_once('{').and_then(|(_, rest)| rest.split_once('}')))
+            .flat_map(|(named, _)| named.split(',').map(|name| name.trim().trim_start_matches("type ")))
+            .collect();
+        let count = names.len();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), count, "{:?}", imports);
+    }
+}
+
+#[test]
+fn single_file_merges_the_axios_imports_of_the_client_and_service() {
+    let files =
+        Generator::new(parse_spec(ERROR_SPEC, None).unwrap()).single_file(true).timestamp(false).generate().unwrap();
+    let api = &files[Path::new("api.ts")];
+    assert!(
+        api.contains(
+            "import axios, { type AxiosResponse, type InternalAxiosRequestConfig, type AxiosError } from 'axios';\n"
+        ),
+        "{}",
+        api
+    );
+}
+
+#[test]
+fn server_variables_and_security_schemes_keep_the_order_of_the_document() {
+    let spec = r##"{
+        "openapi": "3.0.3",
+        "info": { "title": "Order", "version": "1.0" },
+        "servers": [
+            {
+                "url": "https://{zone}.example.com/{api}",
+                "variables": { "zone": { "default": "eu" }, "api": { "default": "v1" } }
+            },
+            { "url": "https://example.org" }
+        ],
+        "paths": {},
+        "components": {
+            "securitySchemes": {
+                "token": { "type": "http", "scheme": "bearer" },
+                "apiKey": { "type": "apiKey", "in": "header", "name": "X-Key" }
+            }
+        }
+    }"##;
+    let files = generate(spec, HttpClient::Fetch);
+    let client = files.values().find(|contents| contents.contains("SERVER_DEFAULTS")).expect("a server helper");
+    assert!(client.contains("'https://{zone}.example.com/{api}': { zone: 'eu', api: 'v1' },"), "{}", client);
+    let token = client.find("'token'?: string;").expect("the token scheme");
+    let api_key = client.find("'apiKey'?: string;").expect("the apiKey scheme");
+    assert!(token < api_key, "{}", client);
+}
+
+#[test]
+fn path_parameters_are_camel_case_typed_and_encoded() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets/{pet-id}/photos/{photo_id}": {
+                "get": {
+                    "operationId": "getPhoto",
+                    "parameters": [
+                        { "name": "pet-id", "in": "path", "required": true, "type": "integer" },
+                        { "name": "photo_id", "in": "path", "required": true, "type": "string" }
+                    ],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"#;
+    let url = "/pets/${encodeURIComponent(petId)}/photos/${encodeURIComponent(photoId)}`";
+    for http_client in [HttpClient::Axios, HttpClient::Fetch] {
+        let files = generate(spec, http_client);
+        let service = &files[Path::new("service.ts")];
+        assert!(service.contains("getPhoto(petId: number, photoId: string, "), "{}", service);
+        assert!(service.contains(url), "{}", service);
+    }
+    for language in [Language::Angular, Language::RtkQuery] {
+        let files =
+            Generator::new(parse_spec(spec, None).unwrap()).language(language).timestamp(false).generate().unwrap();
+        let code: String = files.values().cloned().collect();
+        assert!(code.contains("petId: number"), "{}", code);
+        assert!(code.contains(url), "{}", code);
+    }
+}
+
+#[test]
+fn definition_and_property_names_are_identifiers() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "operationId": "listPets",
+                    "responses": { "200": { "description": "ok", "schema": { "$ref": "#/definitions/pet-list" } } }
+                }
+            }
+        },
+        "definitions": {
+            "pet-list": {
+                "type": "object",
+                "properties": {
+                    "items": { "type": "array", "items": { "$ref": "#/definitions/Pet.Response" } },
+                    "x-rate": { "type": "integer" }
+                }
+            },
+            "Pet.Response": { "type": "object", "properties": { "id": { "type": "integer" } } },
+            "PetList": { "type": "object" }
+        }
+    }"##;
+    let files = generate(spec, HttpClient::Axios);
+    let pet_list = &files[Path::new("interfaces/PetList2.ts")];
+    assert!(pet_list.contains("import type { PetResponse } from './PetResponse';"), "{}", pet_list);
+    assert!(pet_list.contains("export interface PetList2 {\n"), "{}", pet_list);
+    assert!(pet_list.contains("    'x-rate'"), "{}", pet_list);
+    assert!(files[Path::new("interfaces/PetList.ts")].contains("export interface PetList {\n"));
+    let index = &files[Path::new("interfaces/index.ts")];
+    assert!(index.contains("export * from './PetResponse';\n"), "{}", index);
+    let service = &files[Path::new("service.ts")];
+    assert!(service.contains("Promise<PetList2>"), "{}", service);
+}
+
+#[test]
+fn enum_members_are_unique() {
+    let spec = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {},
+        "definitions": { "Mode": { "type": "string", "enum": ["in-stock", "in_stock", "InStock", "", "1st"] } }
+    }"#;
+    let files = Generator::new(parse_spec(spec, None).unwrap())
+        .enum_style(EnumStyle::Enum)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    let mode = &files[Path::new("interfaces/Mode.ts")];
+    let members = "    InStock = 'in-stock',\n    InStock2 = 'in_stock',\n    InStock3 = 'InStock',\n    Value = '',\n";
+    assert!(mode.contains(members), "{}", mode);
+    assert!(mode.contains("    Value1st = '1st',\n"), "{}", mode);
+}
+
+#[test]
+fn dates_and_int64_values_are_decoded_and_encoded() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Events", "version": "1.0" },
+        "paths": {
+            "/events": {
+                "get": {
+                    "operationId": "listEvents",
+                    "responses": {
+                        "200": { "description": "ok", "schema": { "type": "array", "items": { "$ref": "#/definitions/Event" } } }
+                    }
+                },
+                "post": {
+                    "operationId": "createEvent",
+                    "parameters": [{ "in": "body", "name": "body", "schema": { "$ref": "#/definitions/Event" } }],
+                    "responses": { "204": { "description": "created" } }
+                }
+            }
+        },
+        "definitions": {
+            "Event": {
+                "type": "object",
+                "properties": {
+                    "id": { "type": "integer", "format": "int64" },
+                    "day": { "type": "string", "format": "date" },
+                    "owner": { "$ref": "#/definitions/Owner" }
+                }
+            },
+            "Owner": { "type": "object", "properties": { "joined": { "type": "string", "format": "date-time" } } },
+            "Tag": { "type": "object", "properties": { "name": { "type": "string" } } }
+        }
+    }"##;
+    let files = Generator::new(parse_spec(spec, None).unwrap())
+        .date_type(DateType::Date)
+        .int64_type(Int64Type::Bigint)
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    let codecs = &files[Path::new("codecs.ts")];
+    assert!(codecs.contains("    return mapFields({ id: decodeInt64, day: decodeDate, owner: decodeOwner })(value);"));
+    assert!(codecs.contains("    return mapFields({ id: encodeInt64, day: encodeDay, owner: encodeOwner })(value);"));
+    assert!(codecs.contains("export function decodeOwner(value: any): Owner {"), "{}", codecs);
+    assert!(!codecs.contains("Tag"), "{}", codecs);
+
+    let service = &files[Path::new("service.ts")];
+    assert!(service.contains("import { decodeEvent, encodeEvent, mapItems } from './codecs';\n"), "{}", service);
+    assert!(service.contains("    return mapItems(decodeEvent)(response.data);\n"), "{}", service);
+    assert!(service.contains("client.post(`/events`, encodeEvent(data), config);\n"), "{}", service);
+
+    // Without the options the values are typed as JSON carries them, so nothing is converted.
+    let files = generate(spec, HttpClient::Axios);
+    assert!(!files.contains_key(Path::new("codecs.ts")));
+}
+
+#[test]
+fn service_layout_renders_from_the_templates_directory() {
+    let dir = std::env::temp_dir().join(format!("swagger-generator-layout-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("typescript")).unwrap();
+    let layout = "{{banner}}\n\n{{imports}}{{#each methods}}// {{name}}: {{method}} {{path}}\n{{code}}{{/each}}";
+    std::fs::write(dir.join("typescript/service.hbs"), layout).unwrap();
+    let files = Generator::new(parse_spec(ERROR_SPEC, None).unwrap())
+        .http_client(HttpClient::Fetch)
+        .templates(Some(dir.clone()))
+        .timestamp(false)
+        .generate()
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    let service = &files[Path::new("service.ts")];
+    let layout = " */\n\nimport { Error } from './interfaces';\n\n// listPets: get /pets\nexport type ListPetsError";
+    assert!(service.contains(layout), "{}", service);
+    assert!(!service.contains("function request"), "{}", service);
+}
+
+#[test]
+fn pages_are_walked_only_when_their_items_are_typed() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "get": {
+                    "operationId": "listPets",
+                    "parameters": [{ "name": "page", "in": "query", "type": "integer" }],
+                    "responses": { "200": { "description": "ok", "schema": { "$ref": "#/definitions/PetPage" } } }
+                }
+            },
+            "/owners": {
+                "get": {
+                    "operationId": "listOwners",
+                    "parameters": [{ "name": "page", "in": "query", "type": "integer" }],
+                    "responses": {
+                        "200": {
+                            "description": "ok",
+                            "schema": {
+                                "type": "object",
+                                "properties": {
+                                    "items": { "type": "array", "items": { "type": "string" } },
+                                    "total": { "type": "integer" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+        "definitions": {
+            "PetPage": {
+                "type": "object",
+                "properties": { "items": { "type": "array", "items": { "type": "string" } } }
+            }
+        }
+    }"##;
+    let service = &generate(spec, HttpClient::Axios)[Path::new("service.ts")];
+    assert!(
+        service.contains("export async function* listPetsAll(params?: ListPetsParams, config?: RequestOptions): \
+             AsyncGenerator<NonNullable<PetPage['items']>[number]> {"),
+        "{}",
+        service
+    );
+    assert!(!service.contains("listOwnersAll"), "{}", service);
+}
+
+#[test]
+fn request_bodies_and_responses_resolve_from_the_components() {
+    let spec = r##"{
+        "openapi": "3.0.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/pets": {
+                "post": {
+                    "operationId": "addPet",
+                    "requestBody": { "$ref": "#/components/requestBodies/PetBody" },
+                    "responses": { "200": { "$ref": "#/components/responses/PetResponse" } }
+                }
+            }
+        },
+        "components": {
+            "requestBodies": {
+                "PetBody": {
+                    "required": true,
+                    "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } }
+                }
+            },
+            "responses": {
+                "PetResponse": {
+                    "description": "the pet",
+                    "content": { "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } } }
+                }
+            },
+            "schemas": { "Pet": { "type": "object", "properties": { "name": { "type": "string" } } } }
+        }
+    }"##;
+    let service = &generate(spec, HttpClient::Axios)[Path::new("service.ts")];
+    assert!(
+        service.contains("export async function addPet(data: Pet, config?: RequestOptions): Promise<Pet> {"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn one_of_and_any_of_properties_are_unions() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {},
+        "definitions": {
+            "Cat": { "type": "object", "properties": { "kind": { "type": "string" } } },
+            "Dog": { "type": "object", "properties": { "kind": { "type": "string" } } },
+            "Owner": {
+                "type": "object",
+                "required": ["pet"],
+                "properties": {
+                    "pet": {
+                        "oneOf": [{ "$ref": "#/definitions/Cat" }, { "$ref": "#/definitions/Dog" }],
+                        "discriminator": { "propertyName": "kind", "mapping": { "cat": "#/definitions/Cat" } }
+                    },
+                    "pets": { "type": "array", "items": { "anyOf": [{ "$ref": "#/definitions/Cat" }, { "type": "string" }] } },
+                    "id": { "anyOf": [{ "type": "string" }, { "type": "integer" }] }
+                }
+            }
+        }
+    }"##;
+    let owner = &generate(spec, HttpClient::Axios)[Path::new("interfaces/Owner.ts")];
+    assert!(owner.contains("import type { Cat } from './Cat';\nimport type { Dog } from './Dog';\n"), "{}", owner);
+    assert!(owner.contains("    pet: (Cat & { kind: 'cat' }) | (Dog & { kind: 'Dog' });\n"), "{}", owner);
+    assert!(owner.contains("    pets?: (Cat | string)[];\n"), "{}", owner);
+    assert!(owner.contains("    id?: string | number;\n"), "{}", owner);
+}
+
+#[test]
+fn form_fields_keep_the_order_of_the_document() {
+    let spec = r##"{
+        "openapi": "3.0.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/photos": {
+                "post": {
+                    "operationId": "uploadPhoto",
+                    "requestBody": {
+                        "content": {
+                            "multipart/form-data": {
+                                "schema": {
+                                    "type": "object",
+                                    "required": ["file"],
+                                    "properties": {
+                                        "file": { "type": "string", "format": "binary" },
+                                        "caption": { "type": "string" }
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"##;
+    let service = &generate(spec, HttpClient::Axios)[Path::new("service.ts")];
+    assert!(
+        service.contains("export interface UploadPhotoForm {\n    file: Blob;\n    caption?: string;\n}\n"),
+        "{}",
+        service
+    );
+}
+
+#[test]
+fn binary_form_fields_of_openapi_3_are_uploaded_as_files() {
+    let service = &generate(MULTIPART_SPEC, HttpClient::Fetch)[Path::new("service.ts")];
+    assert!(service.contains("    photo: Blob;\n"), "{}", service);
+    assert!(service.contains("formData(data)"), "{}", service);
+}
+
+#[test]
+fn fetch_requests_without_a_body_send_no_content_type() {
+    let urlencoded = r#"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "paths": {
+            "/login": {
+                "post": {
+                    "operationId": "login",
+                    "consumes": ["application/x-www-form-urlencoded"],
+                    "parameters": [{ "name": "user", "in": "formData", "type": "string" }],
+                    "responses": { "200": { "description": "ok" } }
+                }
+            }
+        }
+    }"#;
+    for spec in [MULTIPART_SPEC, urlencoded] {
+        let service = &generate(spec, HttpClient::Fetch)[Path::new("service.ts")];
+        assert!(
+            service.contains(
+                "        headers: encoded || body === undefined ? options.headers : \
+                 { 'Content-Type': 'application/json', ...options.headers },\n"
+            ),
+            "{}",
+            service
+        );
+    }
+}
+
+fn generate_with_retries(language: Language, http_client: HttpClient) -> GeneratedFiles {
+    Generator::new(parse_spec(ERROR_SPEC, None).unwrap())
+        .language(language)
+        .http_client(http_client)
+        .retries(Some(2))
+        .timestamp(false)
+        .generate()
+        .unwrap()
+}
+
+#[test]
+fn axios_retries_loop_in_the_adapter_so_hooks_run_once() {
+    let client = &generate_with_retries(Language::TypeScript, HttpClient::Axios)[Path::new("client.ts")];
+    assert!(client.contains("const send = axios.getAdapter(client.defaults.adapter);\n"), "{}", client);
+    assert!(client.contains("            return await send(config);\n"), "{}", client);
+    assert!(client.contains("await sleep(delay, config.signal as AbortSignal | undefined);\n"), "{}", client);
+    assert!(!client.contains("interceptors.response.use(undefined, async error => {\n    const config"), "{}", client);
+}
+
+#[test]
+fn retries_wait_for_a_retry_after_date_and_stop_waiting_on_abort() {
+    let fetch = &generate_with_retries(Language::TypeScript, HttpClient::Fetch)[Path::new("service.ts")];
+    let rtk = &generate_with_retries(Language::RtkQuery, HttpClient::Fetch)[Path::new("api.ts")];
+    for code in [fetch, rtk] {
+        assert!(code.contains("} else if (!Number.isNaN(date)) {\n            return Math.max(date - Date.now(), 0);"));
+        assert!(code.contains("function sleep(ms: number, signal?: AbortSignal | null): Promise<void> {\n"));
+        assert!(code.contains("            clearTimeout(timer);\n            reject(signal?.reason);\n"), "{}", code);
+    }
+    assert!(fetch.contains("await sleep(retryDelay(attempt, response.headers.get('Retry-After')), signal);\n"));
+    assert!(rtk.contains("            await sleep(retryDelay(attempt, retryAfter), api.signal);\n"), "{}", rtk);
+
+    let angular = &generate_with_retries(Language::Angular, HttpClient::Fetch)[Path::new("variables.ts")];
+    assert!(angular.contains("return Math.max(date - Date.now(), 0);\n"), "{}", angular);
+    assert!(!angular.contains("function sleep("), "{}", angular);
+}
+
+fn generate_extras(spec: &str) -> GeneratedFiles {
+    let generator = Generator::new(parse_spec(spec, None).unwrap()).msw(true).tests(true).factories(true);
+    generator.timestamp(false).generate().unwrap()
+}
+
+#[test]
+fn jest_tests_call_the_service_with_its_argument_types() {
+    let tests = &generate_extras(PETS_SPEC)[Path::new("__tests__/service.test.ts")];
+    assert!(tests.contains("        await api.getPet(0);\n"), "{}", tests);
+    assert!(tests.contains("        expect(url.pathname).toBe('/v1/pets/0');\n"), "{}", tests);
+    assert!(tests.contains("        await api.listPets({ tags: [\"string\"], ids: [0] });\n"), "{}", tests);
+    assert!(tests.contains("        expect(url.searchParams.get('tags')).toBe('string');\n"), "{}", tests);
+    assert!(tests.contains("        expect(url.searchParams.getAll('ids')).toEqual(['0']);\n"), "{}", tests);
+}
+
+#[test]
+fn msw_handlers_answer_every_operation_with_typed_examples() {
+    let handlers = &generate_extras(PETS_SPEC)[Path::new("handlers.ts")];
+    assert!(handlers.contains("import { http, HttpResponse } from 'msw';\n\nimport { Pet } from './interfaces';\n"));
+    assert!(handlers.contains("export const BASE_URL = 'https://api.example.com/v1';\n"), "{}", handlers);
+    assert!(handlers.contains("        http.get<never, never, Pet[]>(`${baseUrl}/pets`, () =>\n"), "{}", handlers);
+    assert!(handlers.contains("        http.post<never, Pet, Pet>(`${baseUrl}/pets`, () =>\n"), "{}", handlers);
+    assert!(
+        handlers.contains(
+            "        http.get<{ petId: string }, never, Pet>(`${baseUrl}/pets/:petId`, () =>
+            HttpResponse.json<Pet>({
+                \"id\": 0,
+                \"name\": \"string\",
+                \"kind\": \"cat\"
+            }, { status: 200 }),
+"
+        ),
+        "{}",
+        handlers
+    );
+    assert!(handlers.contains("export const handlers = createHandlers();\n"), "{}", handlers);
+}
+
+#[test]
+fn factories_make_up_values_within_the_schema() {
+    let factories = &generate_extras(PETS_SPEC)[Path::new("factories.ts")];
+    assert!(factories.contains("import { Error, Pet } from './interfaces';\n"), "{}", factories);
+    assert!(factories.contains("export function seedMocks(value: number) {\n"), "{}", factories);
+    assert!(
+        factories.contains(
+            "export function mockPet(overrides: Partial<Pet> = {}): Pet {
+    return {
+        id: integer(1, 1000),
+        name: text(3, 24),
+        kind: pick(['cat', 'dog'] as const),
+        ...overrides,
+    };
+}
+"
+        ),
+        "{}",
+        factories
+    );
+}
+
+#[test]
+fn security_requirements_send_the_configured_credentials() {
+    let spec = r##"{
+        "swagger": "2.0",
+        "info": { "title": "Pets", "version": "1.0" },
+        "host": "api.example.com",
+        "schemes": ["https"],
+        "securityDefinitions": {
+            "basic": { "type": "basic" },
+            "key": { "type": "apiKey", "in": "header", "name": "X-Key" },
+            "token": { "type": "apiKey", "in": "query", "name": "token" },
+            "oauth": {
+                "type": "oauth2",
+                "flow": "implicit",
+                "authorizationUrl": "https://example.com/auth",
+                "scopes": {}
+            }
+        },
+        "security": [{ "key": [] }],
+        "paths": {
+            "/pets": {
+                "get": { "operationId": "listPets", "responses": { "200": { "description": "ok" } } },
+                "post": {
+                    "operationId": "createPet",
+                    "security": [{ "oauth": [] }, { "basic": [] }],
+                    "responses": { "201": { "description": "ok" } }
+                }
+            },
+            "/health": {
+                "get": { "operationId": "health", "security": [], "responses": { "200": { "description": "ok" } } }
+            }
+        }
+    }"##;
+    let service = &generate(spec, HttpClient::Fetch)[Path::new("service.ts")];
+    assert!(
+        service.contains(
+            "export interface AuthConfig {
+    'basic'?: { username: string; password: string };
+    'key'?: string;
+    'token'?: string;
+    'oauth'?: string;
+}
+"
+        ),
+        "{}",
+        service
+    );
+    assert!(service.contains("                headers['X-Key'] = auth['key'];\n"), "{}", service);
+    assert!(service.contains("                params['token'] = auth['token'];\n"), "{}", service);
+    assert!(service.contains("                headers['Authorization'] = `Bearer ${auth['oauth']}`;\n"), "{}", service);
+    assert!(service.contains("request<any>('GET', `/pets`, undefined, withAuth([['key']], config));"), "{}", service);
+    assert!(service.contains("request<any>('POST', `/pets`, data, withAuth([['oauth'], ['basic']], config));"));
+    assert!(service.contains("return request<any>('GET', `/health`, undefined, config);\n"), "{}", service);
+}
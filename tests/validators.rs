@@ -0,0 +1,86 @@
+use serde_json::{json, Value};
+use std::path::Path;
+use swagger_generator::cli::{Language, Validators};
+use swagger_generator::{parse_spec, GeneratedFiles, Generator};
+
+/// A model with constrained, optional, enum, array and nullable reference properties.
+const PETS_SPEC: &str = r##"{
+    "swagger": "2.0",
+    "info": { "title": "Pets", "version": "1.0" },
+    "paths": {},
+    "definitions": {
+        "Pet": {
+            "type": "object",
+            "required": ["name", "owner"],
+            "properties": {
+                "name": { "type": "string", "minLength": 3, "pattern": "^[a-z]+$" },
+                "price": { "type": "number", "minimum": 0 },
+                "kind": { "type": "string", "enum": ["cat", "dog"] },
+                "tags": { "type": "array", "maxItems": 5, "items": { "type": "string" } },
+                "owner": { "$ref": "#/definitions/Owner", "x-nullable": true }
+            }
+        },
+        "Owner": {
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer", "format": "int64" } }
+        }
+    }
+}"##;
+
+fn generate(language: Language, validators: Option<Validators>) -> GeneratedFiles {
+    let generator = Generator::new(parse_spec(PETS_SPEC, None).unwrap()).language(language).validators(validators);
+    generator.timestamp(false).generate().unwrap()
+}
+
+#[test]
+fn zod_schemas_follow_constraints_and_come_after_their_references() {
+    let schemas = &generate(Language::TypeScript, Some(Validators::Zod))[Path::new("schemas.ts")];
+    assert!(
+        schemas.contains(
+            "export const OwnerSchema = z.object({
+    id: z.number().int(),
+});
+export type Owner = z.infer<typeof OwnerSchema>;
+
+export const PetSchema = z.object({
+    name: z.string().min(3).regex(new RegExp('^[a-z]+$')),
+    price: z.number().min(0).optional(),
+    kind: z.enum(['cat', 'dog']).optional(),
+    tags: z.array(z.string()).max(5).optional(),
+    owner: OwnerSchema.nullable(),
+});
+"
+        ),
+        "{}",
+        schemas
+    );
+}
+
+#[test]
+fn type_guards_check_required_optional_and_nullable_fields() {
+    let guards = &generate(Language::TypeScript, Some(Validators::Guards))[Path::new("guards.ts")];
+    assert!(guards.contains("import { Owner, Pet } from './interfaces';\n"), "{}", guards);
+    assert!(guards.contains("export function isPet(value: unknown): value is Pet {\n"), "{}", guards);
+    assert!(guards.contains("        typeof value.name === 'string' &&\n"), "{}", guards);
+    assert!(guards.contains("(value.price === undefined || typeof value.price === 'number') &&\n"), "{}", guards);
+    assert!(guards.contains("(value.kind === undefined || (['cat', 'dog'] as unknown[]).includes(value.kind)) &&\n"));
+    assert!(guards.contains("value.tags.every((item) => typeof item === 'string'))) &&\n"), "{}", guards);
+    assert!(guards.contains("        (value.owner === null || isOwner(value.owner))\n"), "{}", guards);
+    assert!(!generate(Language::TypeScript, None).contains_key(Path::new("guards.ts")));
+}
+
+#[test]
+fn json_schemas_embed_the_definitions_they_reference() {
+    let files = generate(Language::JsonSchema, None);
+    let pet: Value = serde_json::from_str(&files[Path::new("Pet.schema.json")]).unwrap();
+    assert_eq!(pet["$schema"], "http://json-schema.org/draft-07/schema#");
+    assert_eq!(pet["title"], "Pet");
+    assert_eq!(pet["required"], json!(["name", "owner"]));
+    assert_eq!(pet["properties"]["name"], json!({ "minLength": 3, "pattern": "^[a-z]+$", "type": "string" }));
+    assert_eq!(pet["properties"]["owner"], json!({ "anyOf": [{ "$ref": "#/definitions/Owner" }, { "type": "null" }] }));
+    assert_eq!(pet["definitions"]["Owner"]["required"], json!(["id"]));
+
+    let owner: Value = serde_json::from_str(&files[Path::new("Owner.schema.json")]).unwrap();
+    assert!(owner.get("definitions").is_none(), "{}", owner);
+}